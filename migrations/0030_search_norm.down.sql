@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS notes_household_text_norm_idx;
+DROP INDEX IF EXISTS events_household_title_norm_idx;
+DROP TRIGGER IF EXISTS notes_text_norm_after_insert;
+DROP TRIGGER IF EXISTS notes_text_norm_after_update;
+DROP TRIGGER IF EXISTS events_title_norm_after_insert;
+DROP TRIGGER IF EXISTS events_title_norm_after_update;
+-- SQLite cannot drop the text_norm/title_norm columns without a table rebuild;
+-- they are left in place and ignored.
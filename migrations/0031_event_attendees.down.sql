@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS event_attendees_member_idx;
+DROP TABLE IF EXISTS event_attendees;
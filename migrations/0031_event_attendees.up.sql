@@ -0,0 +1,12 @@
+-- Family members attending an event. Replaces inferring attendance from
+-- note_links so calendar views and free/busy can filter by member directly.
+CREATE TABLE IF NOT EXISTS event_attendees (
+  household_id TEXT NOT NULL REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  member_id TEXT NOT NULL REFERENCES family_members(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  created_at INTEGER NOT NULL,
+  PRIMARY KEY (event_id, member_id)
+);
+
+CREATE INDEX IF NOT EXISTS event_attendees_member_idx
+  ON event_attendees (household_id, member_id);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS notes_household_pinned_created_idx;
+-- SQLite cannot drop the pinned column without a table rebuild; it is left in
+-- place and ignored.
@@ -0,0 +1,5 @@
+-- Pinned notes sort ahead of everything else in the notes list.
+ALTER TABLE notes ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0 CHECK (pinned IN (0, 1));
+
+CREATE INDEX IF NOT EXISTS notes_household_pinned_created_idx
+  ON notes(household_id, pinned DESC, created_at, id);
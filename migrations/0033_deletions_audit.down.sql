@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS deletions_audit_household_deleted_idx;
+DROP TABLE IF EXISTS deletions_audit;
@@ -0,0 +1,14 @@
+-- Append-only record of soft-deletes so missing rows can be traced back to
+-- the table, id and time they were removed. household_id carries no foreign
+-- key so the log outlives a hard-deleted household.
+CREATE TABLE IF NOT EXISTS deletions_audit (
+  id INTEGER PRIMARY KEY,
+  household_id TEXT NOT NULL,
+  table_name TEXT NOT NULL,
+  row_id TEXT NOT NULL,
+  operation TEXT NOT NULL,
+  deleted_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS deletions_audit_household_deleted_idx
+  ON deletions_audit (household_id, deleted_at);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS expense_template_runs;
+DROP INDEX IF EXISTS expense_templates_household_idx;
+DROP TABLE IF EXISTS expense_templates;
@@ -0,0 +1,27 @@
+-- Recurring expenses (rent, subscriptions). Each template materialises one
+-- expense per month; expense_template_runs records which periods have been
+-- generated so re-running a month never duplicates entries.
+CREATE TABLE IF NOT EXISTS expense_templates (
+  id TEXT PRIMARY KEY,
+  household_id TEXT NOT NULL REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  category_id TEXT NOT NULL REFERENCES budget_categories(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  amount INTEGER NOT NULL,
+  description TEXT,
+  day_of_month INTEGER NOT NULL DEFAULT 1 CHECK (day_of_month BETWEEN 1 AND 31),
+  active INTEGER NOT NULL DEFAULT 1 CHECK (active IN (0, 1)),
+  created_at INTEGER NOT NULL,
+  updated_at INTEGER NOT NULL,
+  deleted_at INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS expense_templates_household_idx
+  ON expense_templates (household_id, active);
+
+CREATE TABLE IF NOT EXISTS expense_template_runs (
+  household_id TEXT NOT NULL REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  template_id TEXT NOT NULL REFERENCES expense_templates(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  period_key TEXT NOT NULL,
+  expense_id TEXT NOT NULL,
+  created_at INTEGER NOT NULL,
+  PRIMARY KEY (template_id, period_key)
+);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS shopping_items_household_completed_idx;
+-- SQLite cannot drop the completed columns without a table rebuild; they are
+-- left in place and ignored.
@@ -0,0 +1,7 @@
+-- Checked-off shopping items. Completing a shop archives items by setting
+-- completed rather than deleting them, so they can still be listed on request.
+ALTER TABLE shopping_items ADD COLUMN completed INTEGER NOT NULL DEFAULT 0 CHECK (completed IN (0, 1));
+ALTER TABLE shopping_items ADD COLUMN completed_at INTEGER;
+
+CREATE INDEX IF NOT EXISTS shopping_items_household_completed_idx
+  ON shopping_items(household_id, completed);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS policies_household_renewal_idx;
+-- SQLite cannot drop the added policy columns without a table rebuild; they
+-- are left in place and ignored.
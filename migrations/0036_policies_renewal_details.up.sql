@@ -0,0 +1,9 @@
+-- Display and renewal details for insurance policies. `due_date` remains the
+-- premium due date; `renewal_date` is when cover lapses unless renewed and is
+-- optional because older policies were recorded without one.
+ALTER TABLE policies ADD COLUMN name TEXT;
+ALTER TABLE policies ADD COLUMN provider TEXT;
+ALTER TABLE policies ADD COLUMN renewal_date INTEGER;
+
+CREATE INDEX IF NOT EXISTS policies_household_renewal_idx
+  ON policies(household_id, renewal_date);
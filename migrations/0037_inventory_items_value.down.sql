@@ -0,0 +1,2 @@
+-- SQLite cannot drop estimated_value without a table rebuild; the column is
+-- left in place and ignored.
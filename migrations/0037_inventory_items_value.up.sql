@@ -0,0 +1,3 @@
+-- Estimated replacement value of an inventory item in minor currency units,
+-- used for the insured-value report. Unknown values are left NULL.
+ALTER TABLE inventory_items ADD COLUMN estimated_value INTEGER;
@@ -0,0 +1,4 @@
+-- Links hidden by an entity delete are removed outright, matching the
+-- behaviour before they could be restored. SQLite cannot drop deleted_at
+-- without a table rebuild; the column is left in place and ignored.
+DELETE FROM note_links WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,13 @@
+-- Links to a soft-deleted entity are soft-deleted alongside it and brought
+-- back when the entity is restored. Links already pointing at deleted events
+-- are hidden as of the event's deletion.
+ALTER TABLE note_links ADD COLUMN deleted_at INTEGER;
+
+UPDATE note_links
+   SET deleted_at = (SELECT e.deleted_at FROM events e WHERE e.id = note_links.entity_id)
+ WHERE entity_type = 'event'
+   AND EXISTS (
+         SELECT 1 FROM events e
+          WHERE e.id = note_links.entity_id
+            AND e.deleted_at IS NOT NULL
+       );
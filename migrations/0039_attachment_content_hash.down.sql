@@ -0,0 +1,2 @@
+-- SQLite cannot drop content_sha256 or content_hashed_at without rebuilding
+-- the attachment tables; the columns are left in place and ignored.
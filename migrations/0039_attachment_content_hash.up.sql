@@ -0,0 +1,18 @@
+-- SHA-256 of each attachment's vault file, hex encoded, and when it was
+-- computed (ms). Rows created before this migration are hashed by the
+-- backfill or the first time their hash is needed; a file modified after
+-- content_hashed_at is rehashed on next use.
+ALTER TABLE bills ADD COLUMN content_sha256 TEXT;
+ALTER TABLE bills ADD COLUMN content_hashed_at INTEGER;
+ALTER TABLE policies ADD COLUMN content_sha256 TEXT;
+ALTER TABLE policies ADD COLUMN content_hashed_at INTEGER;
+ALTER TABLE property_documents ADD COLUMN content_sha256 TEXT;
+ALTER TABLE property_documents ADD COLUMN content_hashed_at INTEGER;
+ALTER TABLE inventory_items ADD COLUMN content_sha256 TEXT;
+ALTER TABLE inventory_items ADD COLUMN content_hashed_at INTEGER;
+ALTER TABLE pet_medical ADD COLUMN content_sha256 TEXT;
+ALTER TABLE pet_medical ADD COLUMN content_hashed_at INTEGER;
+ALTER TABLE vehicle_maintenance ADD COLUMN content_sha256 TEXT;
+ALTER TABLE vehicle_maintenance ADD COLUMN content_hashed_at INTEGER;
+ALTER TABLE member_attachments ADD COLUMN content_sha256 TEXT;
+ALTER TABLE member_attachments ADD COLUMN content_hashed_at INTEGER;
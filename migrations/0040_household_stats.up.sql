@@ -0,0 +1,9 @@
+-- Cached per-household statistics written by household_stats_refresh.
+-- counts_json holds the diagnostics row counts keyed by alias;
+-- attachment_bytes sums the vault files referenced by live rows.
+CREATE TABLE household_stats (
+  household_id TEXT PRIMARY KEY REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  counts_json TEXT NOT NULL,
+  attachment_bytes INTEGER NOT NULL DEFAULT 0,
+  computed_at INTEGER NOT NULL
+);
@@ -0,0 +1,2 @@
+-- Nothing to undo: 0041 only completes what 0023_vault_categories describes,
+-- and 0023's own down migration reverses that.
@@ -1,6 +1,8 @@
 -- Migration template (do NOT include BEGIN/COMMIT/PRAGMA; runner owns TX/FKs)
 -- Name your objects explicitly; always create/drop indexes/triggers by name.
 -- For foreign keys, specify ON DELETE/ON UPDATE actions.
+-- Optionally declare `-- destructive: yes` or `-- destructive: no` to override
+-- the DROP/DELETE heuristic used by the pending-migrations preview.
 
 -- up
 -- Put forward-only statements here.
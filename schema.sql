@@ -31,7 +31,8 @@ CREATE TABLE events (
   start_at_utc INTEGER NOT NULL,
   end_at_utc INTEGER,
   rrule TEXT,
-  exdates TEXT
+  exdates TEXT,
+  title_norm TEXT
 );
 CREATE TABLE notes (
   id TEXT PRIMARY KEY,
@@ -48,7 +49,9 @@ CREATE TABLE notes (
   y REAL NOT NULL DEFAULT 0,
   deadline INTEGER,
   deadline_tz TEXT,
-  member_id TEXT
+  member_id TEXT,
+  text_norm TEXT,
+  pinned INTEGER NOT NULL DEFAULT 0 CHECK (pinned IN (0, 1))
 );
 CREATE TABLE files_index (
   id INTEGER PRIMARY KEY,
@@ -91,7 +94,8 @@ CREATE TABLE bills (
   root_key TEXT,
   relative_path TEXT
 , category TEXT NOT NULL DEFAULT 'bills'
-        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc')));
+        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc'))
+, content_sha256 TEXT, content_hashed_at INTEGER);
 CREATE TABLE budget_categories (
   id TEXT PRIMARY KEY,
   name TEXT NOT NULL,
@@ -169,7 +173,7 @@ CREATE TABLE member_attachments (
   added_at INTEGER NOT NULL,
   FOREIGN KEY(household_id) REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
   FOREIGN KEY(member_id) REFERENCES family_members(id) ON DELETE CASCADE ON UPDATE CASCADE
-);
+, content_sha256 TEXT, content_hashed_at INTEGER);
 CREATE TABLE member_renewals (
   id TEXT PRIMARY KEY,
   household_id TEXT NOT NULL,
@@ -206,7 +210,9 @@ CREATE TABLE inventory_items (
   root_key TEXT,
   relative_path TEXT
 , category TEXT NOT NULL DEFAULT 'inventory_items'
-        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc')));
+        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc'))
+, estimated_value INTEGER
+, content_sha256 TEXT, content_hashed_at INTEGER);
 CREATE TABLE pets (
   id TEXT PRIMARY KEY,
   name TEXT NOT NULL,
@@ -232,7 +238,8 @@ CREATE TABLE pet_medical (
   root_key TEXT,
   relative_path TEXT
 , category TEXT NOT NULL DEFAULT 'pet_medical'
-        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc')));
+        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc'))
+, content_sha256 TEXT, content_hashed_at INTEGER);
 CREATE TABLE policies (
   id TEXT PRIMARY KEY,
   amount INTEGER NOT NULL,
@@ -247,7 +254,11 @@ CREATE TABLE policies (
   root_key TEXT,
   relative_path TEXT
 , category TEXT NOT NULL DEFAULT 'policies'
-        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc')));
+        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc'))
+, name TEXT
+, provider TEXT
+, renewal_date INTEGER
+, content_sha256 TEXT, content_hashed_at INTEGER);
 CREATE TABLE property_documents (
   id TEXT PRIMARY KEY,
   description TEXT NOT NULL,
@@ -262,7 +273,8 @@ CREATE TABLE property_documents (
   root_key TEXT,
   relative_path TEXT
 , category TEXT NOT NULL DEFAULT 'property_documents'
-        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc')));
+        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc'))
+, content_sha256 TEXT, content_hashed_at INTEGER);
 CREATE TABLE shadow_read_audit (
   id INTEGER PRIMARY KEY CHECK (id = 1),
   total_rows INTEGER NOT NULL DEFAULT 0,
@@ -284,7 +296,9 @@ CREATE TABLE shopping_items (
   position INTEGER NOT NULL DEFAULT 0,
   created_at INTEGER NOT NULL,
   updated_at INTEGER NOT NULL,
-  deleted_at INTEGER
+  deleted_at INTEGER,
+  completed INTEGER NOT NULL DEFAULT 0 CHECK (completed IN (0, 1)),
+  completed_at INTEGER
 );
 CREATE TABLE vehicles (
   id TEXT PRIMARY KEY,
@@ -319,7 +333,8 @@ CREATE TABLE vehicle_maintenance (
   root_key TEXT,
   relative_path TEXT
 , category TEXT NOT NULL DEFAULT 'vehicle_maintenance'
-        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc')));
+        CHECK (category IN ('bills','policies','property_documents','inventory_items','pet_medical','vehicles','vehicle_maintenance','notes','misc'))
+, content_sha256 TEXT, content_hashed_at INTEGER);
 CREATE UNIQUE INDEX bills_household_position_idx ON bills(household_id, position) WHERE deleted_at IS NULL;
 CREATE INDEX bills_household_updated_idx ON bills(household_id, updated_at);
 CREATE UNIQUE INDEX budget_categories_household_position_idx ON budget_categories(household_id, position) WHERE deleted_at IS NULL;
@@ -379,7 +394,8 @@ CREATE TABLE note_links (
   entity_id TEXT NOT NULL,
   relation TEXT NOT NULL DEFAULT 'attached_to',
   created_at INTEGER NOT NULL,
-  updated_at INTEGER NOT NULL
+  updated_at INTEGER NOT NULL,
+  deleted_at INTEGER
 );
 CREATE UNIQUE INDEX note_links_unique
   ON note_links (household_id, note_id, entity_type, entity_id);
@@ -445,3 +461,188 @@ CREATE UNIQUE INDEX property_documents_household_category_path_idx
 CREATE UNIQUE INDEX vehicle_maintenance_household_category_path_idx
     ON vehicle_maintenance(household_id, category, relative_path)
     WHERE deleted_at IS NULL AND relative_path IS NOT NULL;
+CREATE TRIGGER notes_text_norm_after_update
+AFTER UPDATE OF text ON notes
+BEGIN
+  UPDATE notes SET text_norm = lower(NEW.text) WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      char(768), ''), char(769), ''), char(770), ''), char(771), ''), char(772), ''), char(774), ''), char(775), ''), char(776), ''),
+      char(778), ''), char(779), ''), char(780), ''), char(807), ''), char(808), ''), 'À', 'a'), 'Á', 'a'), 'Â', 'a')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'Ã', 'a'), 'Ä', 'a'), 'Å', 'a'), 'Ç', 'c'), 'È', 'e'), 'É', 'e'), 'Ê', 'e'), 'Ë', 'e'),
+      'Ì', 'i'), 'Í', 'i'), 'Î', 'i'), 'Ï', 'i'), 'Ñ', 'n'), 'Ò', 'o'), 'Ó', 'o'), 'Ô', 'o')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'Õ', 'o'), 'Ö', 'o'), 'Ù', 'u'), 'Ú', 'u'), 'Û', 'u'), 'Ü', 'u'), 'Ý', 'y'), 'à', 'a'),
+      'á', 'a'), 'â', 'a'), 'ã', 'a'), 'ä', 'a'), 'å', 'a'), 'ç', 'c'), 'è', 'e'), 'é', 'e')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'ê', 'e'), 'ë', 'e'), 'ì', 'i'), 'í', 'i'), 'î', 'i'), 'ï', 'i'), 'ñ', 'n'), 'ò', 'o'),
+      'ó', 'o'), 'ô', 'o'), 'õ', 'o'), 'ö', 'o'), 'ù', 'u'), 'ú', 'u'), 'û', 'u'), 'ü', 'u')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'ý', 'y'), 'ÿ', 'y'), 'Ā', 'a'), 'ā', 'a'), 'Ă', 'a'), 'ă', 'a'), 'Ą', 'a'), 'ą', 'a'),
+      'Ć', 'c'), 'ć', 'c'), 'Ĉ', 'c'), 'ĉ', 'c'), 'Ċ', 'c'), 'ċ', 'c'), 'Č', 'c'), 'č', 'c')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'Ď', 'd'), 'ď', 'd'), 'Ē', 'e'), 'ē', 'e'), 'Ĕ', 'e'), 'ĕ', 'e'), 'Ė', 'e'), 'ė', 'e'),
+      'Ę', 'e'), 'ę', 'e'), 'Ě', 'e'), 'ě', 'e'), 'Ĝ', 'g'), 'ĝ', 'g'), 'Ğ', 'g'), 'ğ', 'g')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'Ġ', 'g'), 'ġ', 'g'), 'Ģ', 'g'), 'ģ', 'g'), 'Ĥ', 'h'), 'ĥ', 'h'), 'Ĩ', 'i'), 'ĩ', 'i'),
+      'Ī', 'i'), 'ī', 'i'), 'Ĭ', 'i'), 'ĭ', 'i'), 'Į', 'i'), 'į', 'i'), 'İ', 'i'), 'Ĵ', 'j')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'ĵ', 'j'), 'Ķ', 'k'), 'ķ', 'k'), 'Ĺ', 'l'), 'ĺ', 'l'), 'Ļ', 'l'), 'ļ', 'l'), 'Ľ', 'l'),
+      'ľ', 'l'), 'Ń', 'n'), 'ń', 'n'), 'Ņ', 'n'), 'ņ', 'n'), 'Ň', 'n'), 'ň', 'n'), 'Ō', 'o')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'ō', 'o'), 'Ŏ', 'o'), 'ŏ', 'o'), 'Ő', 'o'), 'ő', 'o'), 'Ŕ', 'r'), 'ŕ', 'r'), 'Ŗ', 'r'),
+      'ŗ', 'r'), 'Ř', 'r'), 'ř', 'r'), 'Ś', 's'), 'ś', 's'), 'Ŝ', 's'), 'ŝ', 's'), 'Ş', 's')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'ş', 's'), 'Š', 's'), 'š', 's'), 'Ţ', 't'), 'ţ', 't'), 'Ť', 't'), 'ť', 't'), 'Ũ', 'u'),
+      'ũ', 'u'), 'Ū', 'u'), 'ū', 'u'), 'Ŭ', 'u'), 'ŭ', 'u'), 'Ů', 'u'), 'ů', 'u'), 'Ű', 'u')
+  WHERE id = NEW.id;
+  UPDATE notes SET text_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(text_norm,
+      'ű', 'u'), 'Ų', 'u'), 'ų', 'u'), 'Ŵ', 'w'), 'ŵ', 'w'), 'Ŷ', 'y'), 'ŷ', 'y'), 'Ÿ', 'y'),
+      'Ź', 'z'), 'ź', 'z'), 'Ż', 'z'), 'ż', 'z'), 'Ž', 'z'), 'ž', 'z')
+  WHERE id = NEW.id;
+END;
+CREATE TRIGGER notes_text_norm_after_insert
+AFTER INSERT ON notes
+BEGIN
+  UPDATE notes SET text = NEW.text WHERE id = NEW.id;
+END;
+CREATE TRIGGER events_title_norm_after_update
+AFTER UPDATE OF title ON events
+BEGIN
+  UPDATE events SET title_norm = lower(NEW.title) WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      char(768), ''), char(769), ''), char(770), ''), char(771), ''), char(772), ''), char(774), ''), char(775), ''), char(776), ''),
+      char(778), ''), char(779), ''), char(780), ''), char(807), ''), char(808), ''), 'À', 'a'), 'Á', 'a'), 'Â', 'a')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'Ã', 'a'), 'Ä', 'a'), 'Å', 'a'), 'Ç', 'c'), 'È', 'e'), 'É', 'e'), 'Ê', 'e'), 'Ë', 'e'),
+      'Ì', 'i'), 'Í', 'i'), 'Î', 'i'), 'Ï', 'i'), 'Ñ', 'n'), 'Ò', 'o'), 'Ó', 'o'), 'Ô', 'o')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'Õ', 'o'), 'Ö', 'o'), 'Ù', 'u'), 'Ú', 'u'), 'Û', 'u'), 'Ü', 'u'), 'Ý', 'y'), 'à', 'a'),
+      'á', 'a'), 'â', 'a'), 'ã', 'a'), 'ä', 'a'), 'å', 'a'), 'ç', 'c'), 'è', 'e'), 'é', 'e')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'ê', 'e'), 'ë', 'e'), 'ì', 'i'), 'í', 'i'), 'î', 'i'), 'ï', 'i'), 'ñ', 'n'), 'ò', 'o'),
+      'ó', 'o'), 'ô', 'o'), 'õ', 'o'), 'ö', 'o'), 'ù', 'u'), 'ú', 'u'), 'û', 'u'), 'ü', 'u')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'ý', 'y'), 'ÿ', 'y'), 'Ā', 'a'), 'ā', 'a'), 'Ă', 'a'), 'ă', 'a'), 'Ą', 'a'), 'ą', 'a'),
+      'Ć', 'c'), 'ć', 'c'), 'Ĉ', 'c'), 'ĉ', 'c'), 'Ċ', 'c'), 'ċ', 'c'), 'Č', 'c'), 'č', 'c')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'Ď', 'd'), 'ď', 'd'), 'Ē', 'e'), 'ē', 'e'), 'Ĕ', 'e'), 'ĕ', 'e'), 'Ė', 'e'), 'ė', 'e'),
+      'Ę', 'e'), 'ę', 'e'), 'Ě', 'e'), 'ě', 'e'), 'Ĝ', 'g'), 'ĝ', 'g'), 'Ğ', 'g'), 'ğ', 'g')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'Ġ', 'g'), 'ġ', 'g'), 'Ģ', 'g'), 'ģ', 'g'), 'Ĥ', 'h'), 'ĥ', 'h'), 'Ĩ', 'i'), 'ĩ', 'i'),
+      'Ī', 'i'), 'ī', 'i'), 'Ĭ', 'i'), 'ĭ', 'i'), 'Į', 'i'), 'į', 'i'), 'İ', 'i'), 'Ĵ', 'j')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'ĵ', 'j'), 'Ķ', 'k'), 'ķ', 'k'), 'Ĺ', 'l'), 'ĺ', 'l'), 'Ļ', 'l'), 'ļ', 'l'), 'Ľ', 'l'),
+      'ľ', 'l'), 'Ń', 'n'), 'ń', 'n'), 'Ņ', 'n'), 'ņ', 'n'), 'Ň', 'n'), 'ň', 'n'), 'Ō', 'o')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'ō', 'o'), 'Ŏ', 'o'), 'ŏ', 'o'), 'Ő', 'o'), 'ő', 'o'), 'Ŕ', 'r'), 'ŕ', 'r'), 'Ŗ', 'r'),
+      'ŗ', 'r'), 'Ř', 'r'), 'ř', 'r'), 'Ś', 's'), 'ś', 's'), 'Ŝ', 's'), 'ŝ', 's'), 'Ş', 's')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'ş', 's'), 'Š', 's'), 'š', 's'), 'Ţ', 't'), 'ţ', 't'), 'Ť', 't'), 'ť', 't'), 'Ũ', 'u'),
+      'ũ', 'u'), 'Ū', 'u'), 'ū', 'u'), 'Ŭ', 'u'), 'ŭ', 'u'), 'Ů', 'u'), 'ů', 'u'), 'Ű', 'u')
+  WHERE id = NEW.id;
+  UPDATE events SET title_norm =
+    replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(replace(title_norm,
+      'ű', 'u'), 'Ų', 'u'), 'ų', 'u'), 'Ŵ', 'w'), 'ŵ', 'w'), 'Ŷ', 'y'), 'ŷ', 'y'), 'Ÿ', 'y'),
+      'Ź', 'z'), 'ź', 'z'), 'Ż', 'z'), 'ż', 'z'), 'Ž', 'z'), 'ž', 'z')
+  WHERE id = NEW.id;
+END;
+CREATE TRIGGER events_title_norm_after_insert
+AFTER INSERT ON events
+BEGIN
+  UPDATE events SET title = NEW.title WHERE id = NEW.id;
+END;
+CREATE INDEX notes_household_text_norm_idx ON notes(household_id, text_norm);
+CREATE INDEX events_household_title_norm_idx ON events(household_id, title_norm);
+CREATE TABLE event_attendees (
+  household_id TEXT NOT NULL REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  member_id TEXT NOT NULL REFERENCES family_members(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  created_at INTEGER NOT NULL,
+  PRIMARY KEY (event_id, member_id)
+);
+CREATE INDEX event_attendees_member_idx
+  ON event_attendees (household_id, member_id);
+CREATE INDEX notes_household_pinned_created_idx
+  ON notes(household_id, pinned DESC, created_at, id);
+CREATE TABLE deletions_audit (
+  id INTEGER PRIMARY KEY,
+  household_id TEXT NOT NULL,
+  table_name TEXT NOT NULL,
+  row_id TEXT NOT NULL,
+  operation TEXT NOT NULL,
+  deleted_at INTEGER NOT NULL
+);
+CREATE INDEX deletions_audit_household_deleted_idx
+  ON deletions_audit (household_id, deleted_at);
+CREATE TABLE expense_templates (
+  id TEXT PRIMARY KEY,
+  household_id TEXT NOT NULL REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  category_id TEXT NOT NULL REFERENCES budget_categories(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  amount INTEGER NOT NULL,
+  description TEXT,
+  day_of_month INTEGER NOT NULL DEFAULT 1 CHECK (day_of_month BETWEEN 1 AND 31),
+  active INTEGER NOT NULL DEFAULT 1 CHECK (active IN (0, 1)),
+  created_at INTEGER NOT NULL,
+  updated_at INTEGER NOT NULL,
+  deleted_at INTEGER
+);
+CREATE INDEX expense_templates_household_idx
+  ON expense_templates (household_id, active);
+CREATE TABLE expense_template_runs (
+  household_id TEXT NOT NULL REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  template_id TEXT NOT NULL REFERENCES expense_templates(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  period_key TEXT NOT NULL,
+  expense_id TEXT NOT NULL,
+  created_at INTEGER NOT NULL,
+  PRIMARY KEY (template_id, period_key)
+);
+CREATE INDEX shopping_items_household_completed_idx
+  ON shopping_items(household_id, completed);
+CREATE INDEX policies_household_renewal_idx
+  ON policies(household_id, renewal_date);
+CREATE TABLE household_stats (
+  household_id TEXT PRIMARY KEY REFERENCES household(id) ON DELETE CASCADE ON UPDATE CASCADE,
+  counts_json TEXT NOT NULL,
+  attachment_bytes INTEGER NOT NULL DEFAULT 0,
+  computed_at INTEGER NOT NULL
+);
@@ -8,8 +8,22 @@ const srcDir = join(root, 'src-tauri', 'src');
 const allowlist = new Set([
   'vehicles_list',
   'vehicles_get',
+  'shopping_items_list',
   'events_list_range',
+  'events_find_conflicts',
+  'family_member_busy',
+  'event_attendees_list',
+  'deletions_audit_list',
   'bills_list_due_between',
+  'vehicles_due_soon',
+  'vehicle_maintenance_summary',
+  'inventory_report',
+  'rrule_validate',
+  'day_bounds_utc',
+  'period_bounds_utc',
+  'pet_medical_due',
+  'policies_renewal_due',
+  'upcoming_summary',
   'household_get_active',
   'household_set_active',
   'db_table_exists',
@@ -19,21 +33,36 @@ const allowlist = new Set([
   'db_has_pet_columns',
   'db_get_health_report',
   'db_recheck',
+  'household_cascade_reconcile',
+  'household_cascade_counts',
+  'db_schema_info',
+  'db_path_info',
+  'db_migrations_pending',
   'db_import_preview',
+  'db_export_diff',
   'db_backup_overview',
-  'db_backup_create',
   'db_backup_reveal_root',
   'db_backup_reveal',
-  'db_export_run',
-  'search_entities',
+  'db_backup_verify',
+  'db_backup_restore',
   'attachment_open',
   'attachment_reveal',
   'open_path',
   'diagnostics_summary',
+  'logging_set_filter',
+  'logs_status',
+  'settings_get',
+  'settings_update',
   'about_metadata',
   'diagnostics_doc_path',
   'open_diagnostics_doc',
+  'open_logs_dir',
+  'diagnostics_export_bundle',
   'time_invariants_check',
+  'maintenance_begin',
+  'maintenance_end',
+  'timezones_list',
+  'bindings_emit_json_schema',
   'events_backfill_timezone_cancel',
   'events_backfill_timezone_status',
 ]);
@@ -118,7 +147,8 @@ for (const file of files) {
     }
     if (
       command.body.includes('ensure_db_writable') ||
-      command.body.includes('begin_maintenance')
+      command.body.includes('begin_maintenance') ||
+      command.body.includes('allow_recovery_read')
     ) {
       continue;
     }
@@ -8,6 +8,9 @@ use sqlx::Row;
 use std::path::Path;
 use std::str::FromStr;
 
+/// Returned when the attachment row exists but has been soft-deleted.
+pub const ERR_ATTACHMENT_DELETED: &str = "ATTACHMENT/DELETED";
+
 #[derive(Debug, Clone)]
 pub struct AttachmentDescriptor {
     pub household_id: String,
@@ -180,6 +183,32 @@ pub async fn load_attachment_descriptor(
     })
 }
 
+/// Whether `id` names a soft-deleted row in `table`. Tables without a
+/// `deleted_at` column never report deleted rows.
+pub async fn is_soft_deleted(
+    pool: &sqlx::SqlitePool,
+    table: &str,
+    id: &str,
+) -> Result<bool, AppError> {
+    repo::ensure_table(table)
+        .map_err(|err| AppError::from(err).with_context("operation", "attachment_is_deleted"))?;
+    if table == "member_attachments" {
+        return Ok(false);
+    }
+    let sql = format!("SELECT 1 FROM {table} WHERE id = ?1 AND deleted_at IS NOT NULL");
+    let row = sqlx::query(&sql)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "attachment_is_deleted")
+                .with_context("table", table.to_string())
+                .with_context("id", id.to_string())
+        })?;
+    Ok(row.is_some())
+}
+
 /// Open the file with the OS.
 #[allow(clippy::result_large_err)]
 pub fn open_with_os(path: &Path) -> Result<(), AppError> {
@@ -0,0 +1,256 @@
+//! UTC bounds of local calendar periods.
+//!
+//! Calendar views ask `events_list_range` for a UTC window, but a local day is
+//! not always 24 hours long: it is 23 hours on a spring-forward day and 25 on
+//! a fall-back day. Bounds are resolved here, in the household's timezone, so
+//! the views do not repeat the DST arithmetic.
+
+use chrono::{Datelike, Duration, LocalResult, Months, NaiveDate, TimeZone};
+use chrono_tz::Tz as ChronoTz;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::State;
+use ts_rs::TS;
+
+use crate::{
+    repo, state::AppState, time_errors::TimeErrorCode, util::dispatch_async_app_result, AppError,
+    AppResult,
+};
+
+pub const INVALID_LOCAL_DATE_CODE: &str = "CALENDAR/INVALID_LOCAL_DATE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum PeriodKind {
+    Week,
+    Month,
+}
+
+/// First day of a calendar week.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<Weekday> for chrono::Weekday {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Monday => chrono::Weekday::Mon,
+            Weekday::Tuesday => chrono::Weekday::Tue,
+            Weekday::Wednesday => chrono::Weekday::Wed,
+            Weekday::Thursday => chrono::Weekday::Thu,
+            Weekday::Friday => chrono::Weekday::Fri,
+            Weekday::Saturday => chrono::Weekday::Sat,
+            Weekday::Sunday => chrono::Weekday::Sun,
+        }
+    }
+}
+
+/// Half-open `[start_at_utc, end_at_utc)` window in UTC milliseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct UtcRange {
+    #[ts(type = "number")]
+    pub start_at_utc: i64,
+    #[ts(type = "number")]
+    pub end_at_utc: i64,
+    /// Timezone the local period was resolved in.
+    pub tz: String,
+}
+
+/// Parse a `YYYY-MM-DD` local calendar date.
+#[allow(clippy::result_large_err)]
+pub fn parse_local_date(value: &str) -> AppResult<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|_| {
+        AppError::new(
+            INVALID_LOCAL_DATE_CODE,
+            "Local date must be formatted as YYYY-MM-DD",
+        )
+        .with_context("local_date", value.to_string())
+    })
+}
+
+/// UTC instant at which `date` begins in `tz`. When local midnight is skipped
+/// by a transition the day starts at the first wall-clock minute that exists;
+/// when it is repeated the earlier instant wins.
+pub fn local_day_start_utc(tz: &ChronoTz, date: NaiveDate) -> i64 {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+    let mut probe = midnight;
+    for _ in 0..=24 * 60 {
+        match tz.from_local_datetime(&probe) {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => {
+                return dt.timestamp_millis();
+            }
+            LocalResult::None => probe += Duration::minutes(1),
+        }
+    }
+    midnight.and_utc().timestamp_millis()
+}
+
+/// Bounds of the local calendar `[from, until)` dates in `tz`.
+pub fn local_dates_bounds(tz: &ChronoTz, from: NaiveDate, until: NaiveDate) -> UtcRange {
+    UtcRange {
+        start_at_utc: local_day_start_utc(tz, from),
+        end_at_utc: local_day_start_utc(tz, until),
+        tz: tz.name().to_string(),
+    }
+}
+
+/// Bounds of a single local calendar day in `tz`.
+#[allow(clippy::result_large_err)]
+pub fn day_bounds(tz: &ChronoTz, date: NaiveDate) -> AppResult<UtcRange> {
+    let next = date.succ_opt().ok_or_else(|| {
+        AppError::new(INVALID_LOCAL_DATE_CODE, "Local date is out of range")
+            .with_context("local_date", date.to_string())
+    })?;
+    Ok(local_dates_bounds(tz, date, next))
+}
+
+/// Bounds of the week or month containing `anchor` in `tz`. Weeks begin on
+/// `week_start`; months run from the 1st to the 1st of the next month.
+#[allow(clippy::result_large_err)]
+pub fn period_bounds(
+    tz: &ChronoTz,
+    kind: PeriodKind,
+    anchor: NaiveDate,
+    week_start: Weekday,
+) -> AppResult<UtcRange> {
+    let out_of_range = || {
+        AppError::new(INVALID_LOCAL_DATE_CODE, "Local date is out of range")
+            .with_context("local_date", anchor.to_string())
+    };
+    let (from, until) = match kind {
+        PeriodKind::Week => {
+            let from = anchor.week(week_start.into()).first_day();
+            let until = from
+                .checked_add_signed(Duration::days(7))
+                .ok_or_else(out_of_range)?;
+            (from, until)
+        }
+        PeriodKind::Month => {
+            let from = anchor.with_day(1).ok_or_else(out_of_range)?;
+            let until = from
+                .checked_add_months(Months::new(1))
+                .ok_or_else(out_of_range)?;
+            (from, until)
+        }
+    };
+    Ok(local_dates_bounds(tz, from, until))
+}
+
+/// Timezone configured for `household_id`, falling back to UTC when unset.
+pub async fn household_timezone(pool: &SqlitePool, household_id: &str) -> AppResult<ChronoTz> {
+    let row: Option<(Option<String>,)> =
+        sqlx::query_as("SELECT tz FROM household WHERE id = ?1 AND deleted_at IS NULL")
+            .bind(household_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "household_timezone")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+    let Some((tz,)) = row else {
+        return Err(AppError::new("HOUSEHOLD_NOT_FOUND", "Household not found.")
+            .with_context("household_id", household_id.to_string()));
+    };
+    let name = tz
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("UTC");
+    name.parse().map_err(|_| {
+        TimeErrorCode::TimezoneUnknown
+            .into_error()
+            .with_context("household_id", household_id.to_string())
+            .with_context("timezone", name.to_string())
+    })
+}
+
+/// Bounds of `local_date` in the household's timezone.
+pub async fn day_bounds_for_household(
+    pool: &SqlitePool,
+    household_id: &str,
+    local_date: &str,
+) -> AppResult<UtcRange> {
+    let date = parse_local_date(local_date)?;
+    let tz = household_timezone(pool, household_id).await?;
+    day_bounds(&tz, date)
+}
+
+/// Bounds of the week or month containing `anchor_local_date` in the
+/// household's timezone.
+pub async fn period_bounds_for_household(
+    pool: &SqlitePool,
+    household_id: &str,
+    kind: PeriodKind,
+    anchor_local_date: &str,
+    week_start: Weekday,
+) -> AppResult<UtcRange> {
+    let anchor = parse_local_date(anchor_local_date)?;
+    let tz = household_timezone(pool, household_id).await?;
+    period_bounds(&tz, kind, anchor, week_start)
+}
+
+#[tauri::command]
+pub async fn day_bounds_utc(
+    state: State<'_, AppState>,
+    household_id: String,
+    local_date: String,
+) -> AppResult<UtcRange> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let local_date = local_date.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "day_bounds_utc")
+                    .with_context("household_id", household_id.clone())
+            })?;
+            day_bounds_for_household(&pool, &household_id, &local_date).await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn period_bounds_utc(
+    state: State<'_, AppState>,
+    household_id: String,
+    kind: PeriodKind,
+    anchor_local_date: String,
+    week_start: Option<Weekday>,
+) -> AppResult<UtcRange> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let anchor_local_date = anchor_local_date.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "period_bounds_utc")
+                    .with_context("household_id", household_id.clone())
+            })?;
+            period_bounds_for_household(
+                &pool,
+                &household_id,
+                kind,
+                &anchor_local_date,
+                week_start.unwrap_or_default(),
+            )
+            .await
+        }
+    })
+    .await
+}
@@ -320,6 +320,7 @@ pub async fn categories_update(
                 data,
                 Some(household_id.as_str()),
                 None,
+                None,
             )
             .await?;
             get_category(pool.clone(), Some(household_id.clone()), id_clone)
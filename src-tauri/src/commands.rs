@@ -9,9 +9,11 @@ use crate::vault_migration::ATTACHMENT_TABLES;
 use std::path::{Path, PathBuf};
 
 use crate::{
+    deletions_audit::{record_deletion, OPERATION_SOFT_DELETE},
     exdate::{inspect_exdates, parse_rrule_until, split_csv_exdates, ExdateContext},
     family_logging::LogScope,
     id::new_uuid_v7,
+    note_links::{self, NoteLinkEntityType},
     repo,
     time::now_ms,
     time_errors::TimeErrorCode,
@@ -20,11 +22,29 @@ use crate::{
 };
 use chrono::{DateTime, Duration, Utc};
 use chrono_tz::Tz as ChronoTz;
+use once_cell::sync::OnceCell;
 use rrule::{RRule, RRuleSet, Tz, Unvalidated};
 use tokio::fs;
 
+pub use crate::repo::ListDateRange;
+
 pub const EVENTS_LIST_RANGE_PER_SERIES_LIMIT: usize = 500;
 pub const EVENTS_LIST_RANGE_TOTAL_LIMIT: usize = 10_000;
+/// Returned by guarded updates when the row changed since the caller read it.
+pub const STALE_WRITE_CODE: &str = "CONFLICT/STALE_WRITE";
+/// Returned by creates that collide with a soft-deleted row's unique key; the
+/// existing row's id is in the `id` context so callers can restore it.
+pub const SOFT_DELETED_EXISTS_CODE: &str = "CONFLICT/SOFT_DELETED_EXISTS";
+pub const EVENTS_HOUSEHOLD_MISMATCH_CODE: &str = "EVENTS/HOUSEHOLD_MISMATCH";
+pub const EVENTS_SHIFT_OUT_OF_RANGE_CODE: &str = "EVENTS/SHIFT_OUT_OF_RANGE";
+pub const VALIDATION_REQUIRED_FIELD_CODE: &str = "VALIDATION/REQUIRED_FIELD";
+pub const VALIDATION_TIMESTAMP_RANGE_CODE: &str = "VALIDATION/TIMESTAMP_RANGE";
+pub const VALIDATION_DATE_FIELD_CODE: &str = "VALIDATION/DATE_FIELD";
+pub const CHANGES_SINCE_INVALID_TABLE_CODE: &str = "CHANGES/INVALID_TABLE";
+pub const TRASH_UNSUPPORTED_TABLE_CODE: &str = "TRASH/UNSUPPORTED_TABLE";
+pub const TRASH_NOT_DELETED_CODE: &str = "TRASH/NOT_DELETED";
+/// Upper bound on rows returned by one [`changes_since`] call, across tables.
+pub const CHANGES_SINCE_ROW_LIMIT: usize = 1_000;
 
 #[allow(clippy::result_large_err)]
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -669,6 +689,21 @@ async fn normalize_event_exdates_for_update(
     Ok(())
 }
 
+/// Only the declared timestamp columns may be range-filtered; the field name
+/// ends up in the SQL text.
+#[allow(clippy::result_large_err)]
+fn ensure_date_range_field(table: &str, range: &ListDateRange) -> AppResult<()> {
+    if timestamp_fields(table).contains(&range.field.as_str()) {
+        return Ok(());
+    }
+    Err(AppError::new(
+        VALIDATION_DATE_FIELD_CODE,
+        "Field is not a timestamp column that can be filtered by date",
+    )
+    .with_context("table", table.to_string())
+    .with_context("field", range.field.clone()))
+}
+
 async fn list(
     pool: &SqlitePool,
     table: &str,
@@ -676,13 +711,25 @@ async fn list(
     order_by: Option<&str>,
     limit: Option<i64>,
     offset: Option<i64>,
+    date_range: Option<&ListDateRange>,
 ) -> AppResult<Vec<Value>> {
+    if let Some(range) = date_range {
+        ensure_date_range_field(table, range)?;
+    }
     if table == "vehicles" {
         return list_vehicles(pool, household_id).await;
     }
-    let rows = repo::list_active(pool, table, household_id, order_by, limit, offset)
-        .await
-        .map_err(AppError::from)?;
+    let rows = repo::list_active_in_range(
+        pool,
+        table,
+        household_id,
+        order_by,
+        limit,
+        offset,
+        date_range,
+    )
+    .await
+    .map_err(AppError::from)?;
     Ok(rows.into_iter().map(row_to_value).collect())
 }
 
@@ -1737,6 +1784,21 @@ pub async fn vehicles_delete(pool: &SqlitePool, household_id: &str, id: &str) ->
                 .with_context("household_id", household_id.to_string())
         })?;
 
+    record_deletion(
+        &mut *tx,
+        "vehicles",
+        household_id,
+        id,
+        OPERATION_SOFT_DELETE,
+        now,
+    )
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "vehicles_delete")
+            .with_context("household_id", household_id.to_string())
+    })?;
+
     tx.commit()
         .await
         .map_err(|err| AppError::from(err).with_context("operation", "vehicles_delete"))?;
@@ -1796,6 +1858,218 @@ pub async fn vehicles_restore(pool: &SqlitePool, household_id: &str, id: &str) -
     })
 }
 
+/// Columns a create payload must carry for `table`: the schema's `NOT NULL`
+/// columns without a default, less the ones `create` fills in itself.
+fn required_create_fields(table: &str) -> &'static [&'static str] {
+    match table {
+        "household" => &["name"],
+        "categories" => &["household_id", "name", "slug", "color"],
+        "events" => &["household_id", "title", "start_at_utc"],
+        "notes" | "shopping_items" => &["household_id"],
+        "bills" | "policies" => &["household_id", "amount", "due_date"],
+        "property_documents" => &["household_id", "description", "renewal_date"],
+        "budget_categories" | "family_members" | "inventory_items" | "vehicles" => {
+            &["household_id", "name"]
+        }
+        "expenses" => &["household_id", "category_id", "amount", "date"],
+        "expense_templates" => &["household_id", "category_id", "amount"],
+        "pets" => &["household_id", "name", "type"],
+        "pet_medical" => &["household_id", "pet_id", "date", "description"],
+        "vehicle_maintenance" => &["household_id", "vehicle_id", "date", "type"],
+        _ => &[],
+    }
+}
+
+/// Reject a create payload that is missing a required column, or sets it to
+/// null, before SQLite reports an opaque `NOT NULL` failure.
+#[allow(clippy::result_large_err)]
+fn ensure_required_fields(table: &str, data: &Map<String, Value>) -> AppResult<()> {
+    for field in required_create_fields(table) {
+        if matches!(data.get(*field), None | Some(Value::Null)) {
+            return Err(AppError::new(
+                VALIDATION_REQUIRED_FIELD_CODE,
+                format!("{field} is required"),
+            )
+            .with_context("field", field.to_string()));
+        }
+    }
+    Ok(())
+}
+
+static TIMESTAMP_RANGE: OnceCell<TimestampRange> = OnceCell::new();
+
+/// Window that epoch-millisecond columns must fall in on create and update.
+///
+/// Defaults to 2000-01-01..2100-01-01 UTC; `ARK_TIMESTAMP_MIN_MS` and
+/// `ARK_TIMESTAMP_MAX_MS` override either bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampRange {
+    pub min_ms: i64,
+    pub max_ms: i64,
+}
+
+impl Default for TimestampRange {
+    fn default() -> Self {
+        Self {
+            min_ms: 946_684_800_000,
+            max_ms: 4_102_444_800_000,
+        }
+    }
+}
+
+impl TimestampRange {
+    /// The window in force for this process, read from the environment on
+    /// first use.
+    pub fn configured() -> Self {
+        *TIMESTAMP_RANGE.get_or_init(Self::from_env)
+    }
+
+    /// Read the overrides, keeping the default for any bound that does not
+    /// parse and for both when they are inverted. Rejected overrides are
+    /// logged so a misconfiguration is visible.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let bound = |name: &str, default: i64| {
+            let Ok(raw) = std::env::var(name) else {
+                return default;
+            };
+            raw.trim().parse::<i64>().unwrap_or_else(|_| {
+                tracing::warn!(
+                    target: "arklowdun",
+                    event = "timestamp_range_override_rejected",
+                    var = name,
+                    value = %raw,
+                    reason = "not an integer"
+                );
+                default
+            })
+        };
+        let range = Self {
+            min_ms: bound("ARK_TIMESTAMP_MIN_MS", defaults.min_ms),
+            max_ms: bound("ARK_TIMESTAMP_MAX_MS", defaults.max_ms),
+        };
+        if range.min_ms < range.max_ms {
+            range
+        } else {
+            tracing::warn!(
+                target: "arklowdun",
+                event = "timestamp_range_override_rejected",
+                min_ms = range.min_ms,
+                max_ms = range.max_ms,
+                reason = "min is not below max"
+            );
+            defaults
+        }
+    }
+
+    /// Reject `value` for `field` when it lies outside the window, hinting at
+    /// a seconds/milliseconds mix-up when the value scaled by 1000 would fit.
+    #[allow(clippy::result_large_err)]
+    pub fn check(&self, field: &str, value: i64) -> AppResult<()> {
+        if (self.min_ms..=self.max_ms).contains(&value) {
+            return Ok(());
+        }
+        let mut err = AppError::new(
+            VALIDATION_TIMESTAMP_RANGE_CODE,
+            format!("{field} is outside the supported date range"),
+        )
+        .with_context("field", field.to_string())
+        .with_context("value", value.to_string())
+        .with_context("min_ms", self.min_ms.to_string())
+        .with_context("max_ms", self.max_ms.to_string());
+        let looks_like_seconds = value
+            .checked_mul(1000)
+            .is_some_and(|scaled| (self.min_ms..=self.max_ms).contains(&scaled));
+        if looks_like_seconds {
+            err = err.with_context("hint", "value looks like seconds; send milliseconds");
+        }
+        Err(err)
+    }
+}
+
+/// Epoch-millisecond columns of `table` that hold calendar dates.
+fn timestamp_fields(table: &str) -> &'static [&'static str] {
+    match table {
+        "events" => &["start_at_utc", "end_at_utc"],
+        "bills" | "policies" => &["due_date"],
+        "property_documents" => &["renewal_date"],
+        "expenses" | "pet_medical" | "vehicle_maintenance" => &["date"],
+        "notes" => &["deadline"],
+        _ => &[],
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn ensure_timestamp_fields_in_range(table: &str, data: &Map<String, Value>) -> AppResult<()> {
+    let fields = timestamp_fields(table);
+    if fields.is_empty() {
+        return Ok(());
+    }
+    let range = TimestampRange::configured();
+    for field in fields {
+        if let Some(value) = value_to_i64(data.get(*field)) {
+            range.check(field, value)?;
+        }
+    }
+    Ok(())
+}
+
+/// Columns unique per household among live rows. A soft-deleted row keeps
+/// its key, so re-creating it should restore the old row instead.
+fn soft_unique_key(table: &str) -> &'static [&'static str] {
+    match table {
+        "categories" => &["slug"],
+        _ => &[],
+    }
+}
+
+async fn ensure_no_soft_deleted_twin(
+    pool: &SqlitePool,
+    table: &str,
+    data: &Map<String, Value>,
+) -> AppResult<()> {
+    let key = soft_unique_key(table);
+    if key.is_empty() {
+        return Ok(());
+    }
+    let Some(household_id) = data.get("household_id").and_then(Value::as_str) else {
+        return Ok(());
+    };
+    let mut values = Vec::with_capacity(key.len());
+    for column in key {
+        match data.get(*column).and_then(Value::as_str) {
+            Some(value) => values.push(value),
+            None => return Ok(()),
+        }
+    }
+
+    let mut sql = format!("SELECT id, deleted_at FROM {table} WHERE household_id = ?1");
+    for (idx, column) in key.iter().enumerate() {
+        sql.push_str(&format!(" AND {column} = ?{}", idx + 2));
+    }
+    // A live row wins; the unique index reports that collision as usual.
+    sql.push_str(" ORDER BY deleted_at IS NOT NULL, deleted_at DESC LIMIT 1");
+    let mut query = sqlx::query_as::<_, (String, Option<i64>)>(&sql).bind(household_id);
+    for value in &values {
+        query = query.bind(*value);
+    }
+    let existing = query.fetch_optional(pool).await.map_err(AppError::from)?;
+
+    let Some((id, Some(_))) = existing else {
+        return Ok(());
+    };
+    let mut err = AppError::new(
+        SOFT_DELETED_EXISTS_CODE,
+        "A deleted record with the same name exists. Restore it instead.",
+    )
+    .with_context("table", table.to_string())
+    .with_context("id", id);
+    for (column, value) in key.iter().zip(values) {
+        err = err.with_context(*column, value.to_string());
+    }
+    Err(err)
+}
+
 // TXN: domain=OUT OF SCOPE tables=*
 async fn create<'a, E>(
     pool: &SqlitePool,
@@ -1811,7 +2085,14 @@ where
         return create_event(pool, data).await;
     }
 
+    if table == "categories" {
+        normalize_category_color(&mut data)?;
+    }
     prepare_attachment_create(table, &mut data, attachment)?;
+    ensure_required_fields(table, &data)?;
+    ensure_timestamp_fields_in_range(table, &data)?;
+    ensure_no_soft_deleted_twin(pool, table, &data).await?;
+    record_attachment_content_hash(table, &mut data, attachment, true).await?;
     let id = data
         .get("id")
         .and_then(|v| v.as_str())
@@ -1843,6 +2124,8 @@ where
 }
 
 async fn create_event(pool: &SqlitePool, mut data: Map<String, Value>) -> AppResult<Value> {
+    ensure_required_fields("events", &data)?;
+    ensure_timestamp_fields_in_range("events", &data)?;
     let id = data
         .get("id")
         .and_then(|v| v.as_str())
@@ -1924,6 +2207,787 @@ async fn create_event(pool: &SqlitePool, mut data: Map<String, Value>) -> AppRes
     Ok(Value::Object(data))
 }
 
+/// Copy an active event under a fresh id so it starts at `new_start_utc`.
+///
+/// The end, reminder and exclusion dates move by the same delta as the start;
+/// title, timezone and recurrence rule are kept. Exclusions that land outside
+/// the series after the shift are dropped. Stored rows never carry a
+/// `series_parent_id` (only expanded instances do), so the copy is always a
+/// standalone event.
+pub async fn duplicate_event(
+    pool: &SqlitePool,
+    household_id: &str,
+    id: &str,
+    new_start_utc: i64,
+) -> AppResult<Value> {
+    let row = repo::get_active(pool, "events", Some(household_id), id)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "event_duplicate")
+                .with_context("id", id.to_string())
+        })?
+        .ok_or_else(|| {
+            AppError::new("DB/NOT_FOUND", "Record not found")
+                .with_context("operation", "event_duplicate")
+                .with_context("table", "events")
+                .with_context("id", id.to_string())
+        })?;
+
+    let start_at_utc: i64 = row.try_get("start_at_utc").map_err(AppError::from)?;
+    let end_at_utc: Option<i64> = row.try_get("end_at_utc").map_err(AppError::from)?;
+    let reminder: Option<i64> = row.try_get("reminder").map_err(AppError::from)?;
+    let rrule: Option<String> = row.try_get("rrule").map_err(AppError::from)?;
+    let exdates: Option<String> = row.try_get("exdates").map_err(AppError::from)?;
+    let delta = new_start_utc - start_at_utc;
+    let rrule = rrule.map(|rule| shift_rrule_until(&rule, delta));
+
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from(household_id));
+    data.insert(
+        "title".into(),
+        Value::from(row.try_get::<String, _>("title").map_err(AppError::from)?),
+    );
+    data.insert(
+        "tz".into(),
+        row.try_get::<Option<String>, _>("tz")
+            .map_err(AppError::from)?
+            .map(Value::from)
+            .unwrap_or(Value::Null),
+    );
+    data.insert("start_at_utc".into(), Value::from(new_start_utc));
+    if let Some(end) = end_at_utc {
+        data.insert("end_at_utc".into(), Value::from(end + delta));
+    }
+    if let Some(reminder) = reminder {
+        data.insert("reminder".into(), Value::from(reminder + delta));
+    }
+    if let Some(exdates) = exdates.as_deref() {
+        let shifted = split_csv_exdates(exdates)
+            .into_iter()
+            .filter_map(|raw| DateTime::parse_from_rfc3339(&raw).ok())
+            .map(|dt| {
+                (dt.with_timezone(&Utc) + Duration::milliseconds(delta))
+                    .format("%Y-%m-%dT%H:%M:%SZ")
+                    .to_string()
+            });
+        let context = ExdateContext {
+            start: DateTime::<Utc>::from_timestamp_millis(new_start_utc),
+            until: rrule.as_deref().and_then(parse_rrule_until),
+        };
+        let canonical = inspect_exdates(shifted, &context).canonical;
+        data.insert(
+            "exdates".into(),
+            canonical.map(Value::from).unwrap_or(Value::Null),
+        );
+    }
+    if let Some(rrule) = rrule {
+        data.insert("rrule".into(), Value::from(rrule));
+    }
+
+    create_event(pool, data).await.map_err(|err| {
+        err.with_context("operation", "event_duplicate")
+            .with_context("source_id", id.to_string())
+    })
+}
+
+/// Moves an RRULE `UNTIL` by `delta_ms` so a shifted series keeps the same
+/// number of occurrences. Date-only values move by whole days; other parts of
+/// the rule, and values that do not parse, are kept as written.
+fn shift_rrule_until(rrule: &str, delta_ms: i64) -> String {
+    const UTC_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+    const LOCAL_FORMAT: &str = "%Y%m%dT%H%M%S";
+    const DATE_FORMAT: &str = "%Y%m%d";
+    let delta = Duration::milliseconds(delta_ms);
+
+    rrule
+        .split(';')
+        .map(|part| {
+            let Some((key, value)) = part.split_once('=') else {
+                return part.to_string();
+            };
+            if !key.trim().eq_ignore_ascii_case("UNTIL") {
+                return part.to_string();
+            }
+            let value = value.trim();
+            let shifted = if let Some(local) = value.strip_suffix('Z') {
+                chrono::NaiveDateTime::parse_from_str(local, LOCAL_FORMAT)
+                    .ok()
+                    .and_then(|until| until.checked_add_signed(delta))
+                    .map(|until| until.format(UTC_FORMAT).to_string())
+            } else if value.len() == 8 {
+                chrono::NaiveDate::parse_from_str(value, DATE_FORMAT)
+                    .ok()
+                    .and_then(|until| until.checked_add_signed(delta))
+                    .map(|until| until.format(DATE_FORMAT).to_string())
+            } else {
+                chrono::NaiveDateTime::parse_from_str(value, LOCAL_FORMAT)
+                    .ok()
+                    .and_then(|until| until.checked_add_signed(delta))
+                    .map(|until| until.format(LOCAL_FORMAT).to_string())
+            };
+            match shifted {
+                Some(until) => format!("{key}={until}"),
+                None => part.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Format tag written by [`export_event`] and required by [`import_event`].
+pub const EVENT_SHARE_FORMAT: &str = "arklowdun.event/1";
+pub const EVENT_SHARE_INVALID_CODE: &str = "EVENT_SHARE/INVALID";
+pub const EVENT_SHARE_UNSUPPORTED_FORMAT_CODE: &str = "EVENT_SHARE/UNSUPPORTED_FORMAT";
+
+/// A single event in a form that can be sent to another household. Times
+/// are UTC milliseconds; `tz` is the IANA zone the series recurs in.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SharedEvent {
+    pub format: String,
+    pub title: String,
+    #[serde(default)]
+    pub tz: Option<String>,
+    pub start_at_utc: i64,
+    #[serde(default)]
+    pub end_at_utc: Option<i64>,
+    #[serde(default)]
+    pub reminder: Option<i64>,
+    /// Normalised rule without an `RRULE:` prefix.
+    #[serde(default)]
+    pub rrule: Option<String>,
+    /// Canonical comma-separated UTC exclusion dates.
+    #[serde(default)]
+    pub exdates: Option<String>,
+}
+
+/// Serialise an active event of `household_id` as a [`SharedEvent`] JSON
+/// document. The recurrence rule is normalised the same way the editor
+/// stores it; ids, household and audit timestamps are left out.
+pub async fn export_event(pool: &SqlitePool, household_id: &str, id: &str) -> AppResult<String> {
+    let context = |err: AppError| {
+        err.with_context("operation", "event_export_one")
+            .with_context("household_id", household_id.to_string())
+            .with_context("id", id.to_string())
+    };
+    let row = sqlx::query(
+        "SELECT title, tz, start_at_utc, end_at_utc, reminder, rrule, exdates
+           FROM events WHERE id = ?1 AND household_id = ?2 AND deleted_at IS NULL",
+    )
+    .bind(id)
+    .bind(household_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| context(AppError::from(err)))?
+    .ok_or_else(|| {
+        context(AppError::new("DB/NOT_FOUND", "Record not found").with_context("table", "events"))
+    })?;
+
+    let start_at_utc: i64 = row.try_get("start_at_utc").map_err(AppError::from)?;
+    let tz: Option<String> = row.try_get("tz").map_err(AppError::from)?;
+    let rrule: Option<String> = row.try_get("rrule").map_err(AppError::from)?;
+    let rrule = match rrule.filter(|rule| !rule.trim().is_empty()) {
+        Some(rule) => Some(
+            crate::recurrence::validate_rrule(&rule, start_at_utc, tz.as_deref(), 1)
+                .map_err(context)?
+                .rrule,
+        ),
+        None => None,
+    };
+    let exdates: Option<String> = row.try_get("exdates").map_err(AppError::from)?;
+    let exdates = exdates.and_then(|raw| {
+        let context = ExdateContext {
+            start: DateTime::<Utc>::from_timestamp_millis(start_at_utc),
+            until: rrule.as_deref().and_then(parse_rrule_until),
+        };
+        inspect_exdates(split_csv_exdates(&raw), &context).canonical
+    });
+
+    let shared = SharedEvent {
+        format: EVENT_SHARE_FORMAT.to_string(),
+        title: row.try_get("title").map_err(AppError::from)?,
+        tz,
+        start_at_utc,
+        end_at_utc: row.try_get("end_at_utc").map_err(AppError::from)?,
+        reminder: row.try_get("reminder").map_err(AppError::from)?,
+        rrule,
+        exdates,
+    };
+    serde_json::to_string_pretty(&shared).map_err(|err| context(AppError::from(err)))
+}
+
+/// Create an event in `household_id` from a [`SharedEvent`] JSON document.
+///
+/// An event without a timezone takes the household's (UTC when unset); the
+/// UTC instants are kept as-is, so the event lands at the same moment it had
+/// in the sender's calendar. The rule is validated and normalised, and the
+/// exclusion dates go through the same checks as [`create_event`]. With
+/// `dry_run` the prepared row is returned without an id and nothing is
+/// written.
+pub async fn import_event(
+    pool: &SqlitePool,
+    household_id: &str,
+    json: &str,
+    dry_run: bool,
+) -> AppResult<Value> {
+    let context = |err: AppError| {
+        err.with_context("operation", "event_import_one")
+            .with_context("household_id", household_id.to_string())
+    };
+    let shared: SharedEvent = serde_json::from_str(json).map_err(|err| {
+        context(
+            AppError::new(EVENT_SHARE_INVALID_CODE, "This is not a shared event.")
+                .with_context("detail", err.to_string()),
+        )
+    })?;
+    if shared.format != EVENT_SHARE_FORMAT {
+        return Err(context(
+            AppError::new(
+                EVENT_SHARE_UNSUPPORTED_FORMAT_CODE,
+                "This shared event was made by an unsupported version.",
+            )
+            .with_context("format", shared.format),
+        ));
+    }
+    if shared.title.trim().is_empty() {
+        return Err(context(
+            AppError::new(
+                VALIDATION_REQUIRED_FIELD_CODE,
+                "Events must include a title.",
+            )
+            .with_context("field", "title"),
+        ));
+    }
+
+    let household_tz = crate::calendar_range::household_timezone(pool, household_id)
+        .await
+        .map_err(context)?;
+    let tz_name = shared
+        .tz
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| household_tz.name().to_string());
+    let (_, tz) = canonicalize_timezone(Some(tz_name)).map_err(context)?;
+    let rrule = match shared
+        .rrule
+        .as_deref()
+        .filter(|rule| !rule.trim().is_empty())
+    {
+        Some(rule) => Some(
+            crate::recurrence::validate_rrule(rule, shared.start_at_utc, Some(&tz), 1)
+                .map_err(context)?
+                .rrule,
+        ),
+        None => None,
+    };
+
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from(household_id));
+    data.insert("title".into(), Value::from(shared.title));
+    data.insert("tz".into(), Value::from(tz));
+    data.insert("start_at_utc".into(), Value::from(shared.start_at_utc));
+    if let Some(end) = shared.end_at_utc {
+        data.insert("end_at_utc".into(), Value::from(end));
+    }
+    if let Some(reminder) = shared.reminder {
+        data.insert("reminder".into(), Value::from(reminder));
+    }
+    if let Some(rrule) = rrule {
+        data.insert("rrule".into(), Value::from(rrule));
+    }
+    if let Some(exdates) = shared.exdates {
+        data.insert("exdates".into(), Value::from(exdates));
+    }
+
+    if dry_run {
+        ensure_required_fields("events", &data).map_err(context)?;
+        ensure_timestamp_fields_in_range("events", &data).map_err(context)?;
+        derive_event_wall_clock_for_create(&mut data).map_err(context)?;
+        normalize_event_exdates_for_create(&mut data).map_err(context)?;
+        return Ok(Value::Object(data));
+    }
+    create_event(pool, data).await.map_err(context)
+}
+
+/// Move `start_at_utc`/`end_at_utc` of every listed event by `delta_ms` in one
+/// transaction. Every event must be active and owned by `household_id`; if any
+/// is not, or a shifted timestamp would be negative, nothing is changed.
+/// Returns the number of events moved.
+pub async fn shift_events(
+    pool: &SqlitePool,
+    household_id: &str,
+    ids: &[String],
+    delta_ms: i64,
+) -> AppResult<u64> {
+    let mut unique: Vec<&str> = Vec::new();
+    for id in ids {
+        if !unique.contains(&id.as_str()) {
+            unique.push(id.as_str());
+        }
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "events_shift"))?;
+    let now = now_ms();
+    for id in &unique {
+        let row: Option<(String, i64, Option<i64>)> = sqlx::query_as(
+            "SELECT household_id, start_at_utc, end_at_utc FROM events \
+             WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "events_shift")
+                .with_context("id", id.to_string())
+        })?;
+        let (owner, start, end) = match row {
+            Some(row) if row.0 == household_id => row,
+            Some(_) => {
+                return Err(AppError::new(
+                    EVENTS_HOUSEHOLD_MISMATCH_CODE,
+                    "Event belongs to another household",
+                )
+                .with_context("operation", "events_shift")
+                .with_context("id", id.to_string())
+                .with_context("household_id", household_id.to_string()));
+            }
+            None => {
+                return Err(AppError::new("DB/NOT_FOUND", "Record not found")
+                    .with_context("operation", "events_shift")
+                    .with_context("table", "events")
+                    .with_context("id", id.to_string()));
+            }
+        };
+
+        let start = shift_event_time(start, delta_ms, id, "start_at_utc")?;
+        let end = match end {
+            Some(end) => Some(shift_event_time(end, delta_ms, id, "end_at_utc")?),
+            None => None,
+        };
+
+        sqlx::query(
+            "UPDATE events SET start_at_utc = ?, end_at_utc = ?, updated_at = ? \
+             WHERE household_id = ? AND id = ?",
+        )
+        .bind(start)
+        .bind(end)
+        .bind(now)
+        .bind(&owner)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "events_shift")
+                .with_context("id", id.to_string())
+        })?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "events_shift"))?;
+    Ok(unique.len() as u64)
+}
+
+/// Move one event timestamp by `delta_ms`, refusing results before the epoch.
+#[allow(clippy::result_large_err)]
+fn shift_event_time(value: i64, delta_ms: i64, id: &str, field: &'static str) -> AppResult<i64> {
+    value
+        .checked_add(delta_ms)
+        .filter(|shifted| *shifted >= 0)
+        .ok_or_else(|| {
+            AppError::new(
+                EVENTS_SHIFT_OUT_OF_RANGE_CODE,
+                "Shifted event time would be out of range",
+            )
+            .with_context("operation", "events_shift")
+            .with_context("id", id.to_string())
+            .with_context("field", field)
+            .with_context("delta_ms", delta_ms.to_string())
+        })
+}
+
+/// Household-scoped tables reported by [`tables_last_modified`] and
+/// [`changes_since`].
+const LAST_MODIFIED_TABLES: &[&str] = &[
+    "events",
+    "bills",
+    "policies",
+    "property_documents",
+    "inventory_items",
+    "vehicles",
+    "vehicle_maintenance",
+    "pets",
+    "pet_medical",
+    "family_members",
+    "categories",
+    "budget_categories",
+    "expenses",
+    "notes",
+    "shopping_items",
+];
+
+/// Latest `updated_at` among live rows of each domain table for a household,
+/// keyed by table name; `None` when the table has no live rows. The
+/// `household` entry is the household row itself; `member_renewals` has no
+/// soft delete, and `member_attachments`, which is never updated in place,
+/// reports its latest `added_at`.
+pub async fn tables_last_modified(
+    pool: &SqlitePool,
+    household_id: &str,
+) -> AppResult<std::collections::BTreeMap<String, Option<i64>>> {
+    let mut parts = vec![
+        "SELECT 'household' AS tbl, MAX(updated_at) AS last_modified FROM household \
+         WHERE id = ?1 AND deleted_at IS NULL"
+            .to_string(),
+        "SELECT 'member_renewals', MAX(updated_at) FROM member_renewals \
+         WHERE household_id = ?1"
+            .to_string(),
+        "SELECT 'member_attachments', MAX(added_at) FROM member_attachments \
+         WHERE household_id = ?1"
+            .to_string(),
+    ];
+    parts.extend(LAST_MODIFIED_TABLES.iter().map(|table| {
+        format!(
+            "SELECT '{table}', MAX(updated_at) FROM {table} \
+             WHERE household_id = ?1 AND deleted_at IS NULL"
+        )
+    }));
+    let sql = parts.join(" UNION ALL ");
+
+    let rows: Vec<(String, Option<i64>)> = sqlx::query_as(&sql)
+        .bind(household_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "tables_last_modified")
+                .with_context("household_id", household_id.to_string())
+        })?;
+    Ok(rows.into_iter().collect())
+}
+
+/// A soft-deleted row as listed by [`trash_list`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct TrashEntry {
+    pub table: String,
+    pub id: String,
+    /// Best-effort human name for the row; `None` when the table has nothing
+    /// suitable or the row left it blank.
+    pub label: Option<String>,
+    pub deleted_at: i64,
+}
+
+/// SQL expression naming a row of `table` in the trash listing.
+fn trash_label_sql(table: &str) -> &'static str {
+    match table {
+        "events" => "title",
+        "bills" | "policies" => "COALESCE(NULLIF(relative_path, ''), document)",
+        "property_documents" | "pet_medical" | "expenses" => "description",
+        "vehicle_maintenance" => "type",
+        "family_members" => "COALESCE(NULLIF(nickname, ''), name)",
+        "notes" => "substr(text, 1, 80)",
+        "inventory_items" | "vehicles" | "pets" | "categories" | "budget_categories" => "name",
+        _ => "NULL",
+    }
+}
+
+/// Human name for a bill row as returned by [`bills_due_between`], following
+/// the `bills` arm of [`trash_label_sql`] and falling back to `"Bill"`.
+pub(crate) fn bill_label(row: &Value) -> String {
+    ["relative_path", "document"]
+        .iter()
+        .filter_map(|field| row.get(field).and_then(Value::as_str))
+        .map(str::trim)
+        .find(|label| !label.is_empty())
+        .unwrap_or("Bill")
+        .to_string()
+}
+
+/// Soft-deleted rows of every domain table in a household, most recently
+/// deleted first. `limit` and `offset` are ignored unless positive.
+pub async fn trash_list(
+    pool: &SqlitePool,
+    household_id: &str,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> AppResult<Vec<TrashEntry>> {
+    let parts: Vec<String> = LAST_MODIFIED_TABLES
+        .iter()
+        .map(|table| {
+            format!(
+                "SELECT '{table}' AS tbl, id, NULLIF(TRIM({label}), '') AS label, deleted_at \
+                 FROM {table} WHERE household_id = ?1 AND deleted_at IS NOT NULL",
+                label = trash_label_sql(table)
+            )
+        })
+        .collect();
+    let sql = format!(
+        "{} ORDER BY deleted_at DESC, tbl, id LIMIT ?2 OFFSET ?3",
+        parts.join(" UNION ALL ")
+    );
+
+    let rows: Vec<(String, String, Option<String>, i64)> = sqlx::query_as(&sql)
+        .bind(household_id)
+        .bind(limit.filter(|value| *value > 0).unwrap_or(-1))
+        .bind(offset.filter(|value| *value > 0).unwrap_or(0))
+        .fetch_all(pool)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "trash_list")
+                .with_context("household_id", household_id.to_string())
+        })?;
+    Ok(rows
+        .into_iter()
+        .map(|(table, id, label, deleted_at)| TrashEntry {
+            table,
+            id,
+            label,
+            deleted_at,
+        })
+        .collect())
+}
+
+/// Undelete a row listed by [`trash_list`] through [`restore_command`] and
+/// return it as stored after the restore. The row must be soft-deleted and
+/// its household must still be live.
+pub async fn trash_restore(pool: &SqlitePool, table: &str, id: &str) -> AppResult<Value> {
+    let context = |err: AppError| {
+        err.with_context("operation", "trash_restore")
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string())
+    };
+    if !LAST_MODIFIED_TABLES.contains(&table) {
+        return Err(context(AppError::new(
+            TRASH_UNSUPPORTED_TABLE_CODE,
+            "Rows of this table cannot be restored from the trash.",
+        )));
+    }
+
+    let row: Option<(String, Option<i64>, Option<i64>, bool)> = sqlx::query_as(&format!(
+        "SELECT t.household_id, t.deleted_at, h.deleted_at, h.id IS NOT NULL
+           FROM {table} t LEFT JOIN household h ON h.id = t.household_id
+          WHERE t.id = ?1"
+    ))
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| context(AppError::from(err)))?;
+    let Some((household_id, deleted_at, household_deleted_at, household_exists)) = row else {
+        return Err(context(AppError::new("DB/NOT_FOUND", "Record not found")));
+    };
+    if deleted_at.is_none() {
+        return Err(context(AppError::new(
+            TRASH_NOT_DELETED_CODE,
+            "This item is not in the trash.",
+        )));
+    }
+    if !household_exists {
+        return Err(context(
+            AppError::new("HOUSEHOLD_NOT_FOUND", "Household not found.")
+                .with_context("household_id", household_id),
+        ));
+    }
+    if household_deleted_at.is_some() {
+        return Err(context(
+            AppError::new("HOUSEHOLD_DELETED", "Household is deleted.")
+                .with_context("household_id", household_id),
+        ));
+    }
+
+    restore_command(pool, table, &household_id, id)
+        .await
+        .map_err(context)?;
+    let restored = repo::get_active(pool, table, Some(&household_id), id)
+        .await
+        .map_err(|err| context(AppError::from(err)))?
+        .ok_or_else(|| context(AppError::new("DB/NOT_FOUND", "Record not found")))?;
+    Ok(repo::row_to_json(restored))
+}
+
+/// Position of the last row a table has delivered to a [`changes_since`]
+/// caller. Rows sharing `updated_at` are ordered by `id`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChangesCursor {
+    pub updated_at: i64,
+    pub id: String,
+}
+
+/// Rows changed after a point in time, grouped by table.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ChangesSince {
+    pub tables: std::collections::BTreeMap<String, Vec<Value>>,
+    /// Set when [`CHANGES_SINCE_ROW_LIMIT`] cut the feed short; callers should
+    /// poll again with the same `since_ms` and these `cursors`.
+    pub truncated: bool,
+    /// Where each table that has delivered rows left off. Tables without an
+    /// entry start from `since_ms` on the next poll.
+    pub cursors: std::collections::BTreeMap<String, ChangesCursor>,
+}
+
+/// Rows of each requested table whose `updated_at` is after `since_ms`, or
+/// after the table's entry in `cursors`, oldest first. Soft-deleted rows are
+/// included so callers can drop them; they carry `deleted_at`. An empty
+/// `tables` selects every soft-deletable domain table.
+pub async fn changes_since(
+    pool: &SqlitePool,
+    household_id: &str,
+    since_ms: i64,
+    tables: &[String],
+    cursors: &std::collections::BTreeMap<String, ChangesCursor>,
+) -> AppResult<ChangesSince> {
+    let selected: Vec<&str> = if tables.is_empty() {
+        LAST_MODIFIED_TABLES.to_vec()
+    } else {
+        let mut selected = Vec::with_capacity(tables.len());
+        for table in tables {
+            let known = LAST_MODIFIED_TABLES
+                .iter()
+                .find(|known| **known == table.as_str())
+                .ok_or_else(|| {
+                    AppError::new(
+                        CHANGES_SINCE_INVALID_TABLE_CODE,
+                        "Table does not support change polling",
+                    )
+                    .with_context("table", table.clone())
+                })?;
+            if !selected.contains(known) {
+                selected.push(*known);
+            }
+        }
+        selected
+    };
+
+    let mut changes = ChangesSince::default();
+    let mut remaining = CHANGES_SINCE_ROW_LIMIT;
+    for table in selected {
+        let cursor = cursors.get(table);
+        let after = if cursor.is_some() {
+            "(updated_at > ?2 OR (updated_at = ?2 AND id > ?4))"
+        } else {
+            "updated_at > ?2"
+        };
+        let sql = format!(
+            "SELECT * FROM {table} WHERE household_id = ?1 AND {after} \
+             ORDER BY updated_at, id LIMIT ?3"
+        );
+        let mut query = sqlx::query(&sql)
+            .bind(household_id)
+            .bind(cursor.map_or(since_ms, |cursor| cursor.updated_at))
+            .bind((remaining + 1) as i64);
+        if let Some(cursor) = cursor {
+            query = query.bind(&cursor.id);
+        }
+        let rows = query.fetch_all(pool).await.map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "changes_since")
+                .with_context("table", table.to_string())
+                .with_context("household_id", household_id.to_string())
+        })?;
+        let mut values: Vec<Value> = rows.into_iter().map(row_to_value).collect();
+        if values.len() > remaining {
+            values.truncate(remaining);
+            changes.truncated = true;
+        }
+        remaining -= values.len();
+        let last = values.last().and_then(|row| {
+            Some(ChangesCursor {
+                updated_at: row.get("updated_at")?.as_i64()?,
+                id: row.get("id")?.as_str()?.to_string(),
+            })
+        });
+        if let Some(cursor) = last.or_else(|| cursor.cloned()) {
+            changes.cursors.insert(table.to_string(), cursor);
+        }
+        changes.tables.insert(table.to_string(), values);
+        if changes.truncated {
+            break;
+        }
+    }
+    Ok(changes)
+}
+
+/// One page of bills due in a window, with the size of the whole window.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct BillsDuePage {
+    pub items: Vec<Value>,
+    /// Bills matching the window regardless of `limit`/`offset`.
+    pub total: i64,
+    pub has_more: bool,
+}
+
+/// Live bills with `due_date` in `[from_ms, to_ms]`, soonest first. `limit`
+/// and `offset` are ignored unless positive.
+pub async fn bills_due_between(
+    pool: &SqlitePool,
+    household_id: &str,
+    from_ms: i64,
+    to_ms: i64,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> AppResult<BillsDuePage> {
+    const PREDICATE: &str = "WHERE household_id = ?1
+          AND deleted_at IS NULL
+          AND due_date >= ?2
+          AND due_date <= ?3";
+    let context = |err: sqlx::Error| {
+        AppError::from(err)
+            .with_context("operation", "bills_list_due_between")
+            .with_context("household_id", household_id.to_string())
+    };
+
+    let limit = limit.filter(|value| *value > 0);
+    let offset = offset.filter(|value| *value > 0).unwrap_or(0);
+    let sql = format!(
+        "SELECT * FROM bills {PREDICATE}
+        ORDER BY due_date ASC, created_at ASC, id ASC
+        LIMIT ?4 OFFSET ?5"
+    );
+    let rows = sqlx::query(&sql)
+        .bind(household_id)
+        .bind(from_ms)
+        .bind(to_ms)
+        .bind(limit.unwrap_or(-1))
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+        .map_err(context)?;
+    let total: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM bills {PREDICATE}"))
+        .bind(household_id)
+        .bind(from_ms)
+        .bind(to_ms)
+        .fetch_one(pool)
+        .await
+        .map_err(context)?;
+
+    let items: Vec<Value> = rows.into_iter().map(crate::repo::row_to_json).collect();
+    let has_more = offset + (items.len() as i64) < total;
+    Ok(BillsDuePage {
+        items,
+        total,
+        has_more,
+    })
+}
+
+/// Categories share the household colour rules: `#RRGGBB`, stored upper-case.
+#[allow(clippy::result_large_err)]
+fn normalize_category_color(data: &mut Map<String, Value>) -> AppResult<()> {
+    if let Some(Value::String(raw)) = data.get("color") {
+        let normalized = crate::util::normalize_hex_color(raw).ok_or_else(|| {
+            crate::util::invalid_color_error(raw).with_context("table", "categories")
+        })?;
+        data.insert("color".into(), Value::String(normalized));
+    }
+    Ok(())
+}
+
 fn prepare_attachment_create(
     table: &str,
     data: &mut Map<String, Value>,
@@ -2074,6 +3138,40 @@ pub(crate) async fn prepare_attachment_update(
     Ok(())
 }
 
+/// Keep `content_sha256` in step with the file a row points at. Callers never
+/// write the hash directly; when `file_changed` the vault file is hashed, or
+/// the hash is cleared if there is no file yet.
+async fn record_attachment_content_hash(
+    table: &str,
+    data: &mut Map<String, Value>,
+    guard: Option<&AttachmentMutationGuard>,
+    file_changed: bool,
+) -> AppResult<()> {
+    if !ATTACHMENT_TABLES.contains(&table) {
+        return Ok(());
+    }
+    data.remove("content_sha256");
+    data.remove("content_hashed_at");
+    if !file_changed {
+        return Ok(());
+    }
+    let hash = match guard.and_then(AttachmentMutationGuard::resolved_path) {
+        Some(path) if path.is_file() => Some(crate::file_ops::file_sha256(path).await?),
+        _ => None,
+    };
+    match hash {
+        Some(hash) => {
+            data.insert("content_sha256".into(), Value::String(hash));
+            data.insert("content_hashed_at".into(), Value::from(now_ms()));
+        }
+        None => {
+            data.insert("content_sha256".into(), Value::Null);
+            data.insert("content_hashed_at".into(), Value::Null);
+        }
+    }
+    Ok(())
+}
+
 // TXN: domain=OUT OF SCOPE tables=*
 async fn update<'a, E>(
     pool: &SqlitePool,
@@ -2083,10 +3181,12 @@ async fn update<'a, E>(
     mut data: Map<String, Value>,
     household_id: Option<&str>,
     attachment: Option<&AttachmentMutationGuard>,
+    expected_updated_at: Option<i64>,
 ) -> AppResult<()>
 where
     E: Executor<'a, Database = Sqlite>,
 {
+    ensure_timestamp_fields_in_range(table, &data)?;
     if table == "events" {
         let hh = household_id.ok_or_else(|| {
             AppError::new(
@@ -2097,14 +3197,19 @@ where
         normalize_event_exdates_for_update(pool, hh, id, &mut data).await?;
         derive_event_wall_clock_for_update(pool, hh, id, &mut data).await?;
     }
+    if table == "categories" {
+        normalize_category_color(&mut data)?;
+    }
     prepare_attachment_update(pool, table, id, &mut data, household_id, attachment).await?;
+    let file_changed = data.contains_key("relative_path") || data.contains_key("category");
+    record_attachment_content_hash(table, &mut data, attachment, file_changed).await?;
     data.remove("id");
     data.remove("created_at");
     let now = now_ms();
     data.insert("updated_at".into(), Value::from(now));
     let cols: Vec<String> = data.keys().cloned().collect();
     let set_clause: Vec<String> = cols.iter().map(|c| format!("{c} = ?")).collect();
-    let sql = if table == "household" {
+    let mut sql = if table == "household" {
         format!("UPDATE {table} SET {} WHERE id = ?", set_clause.join(","))
     } else {
         format!(
@@ -2112,6 +3217,9 @@ where
             set_clause.join(",")
         )
     };
+    if expected_updated_at.is_some() {
+        sql.push_str(" AND updated_at = ?");
+    }
     let mut query = sqlx::query::<Sqlite>(&sql);
     for c in &cols {
         let value = data.get(c).ok_or_else(|| {
@@ -2126,8 +3234,57 @@ where
         let hh = household_id.unwrap_or("");
         query = query.bind(hh).bind(id);
     }
-    query.execute(executor).await.map_err(AppError::from)?;
-    Ok(())
+    let Some(expected) = expected_updated_at else {
+        query.execute(executor).await.map_err(AppError::from)?;
+        return Ok(());
+    };
+    let res = query
+        .bind(expected)
+        .execute(executor)
+        .await
+        .map_err(AppError::from)?;
+    if res.rows_affected() > 0 {
+        return Ok(());
+    }
+    Err(stale_write_error(pool, table, id, household_id, expected).await)
+}
+
+/// Explain why a guarded update matched no rows: either the row is gone or
+/// someone else wrote it after the caller's read.
+async fn stale_write_error(
+    pool: &SqlitePool,
+    table: &str,
+    id: &str,
+    household_id: Option<&str>,
+    expected: i64,
+) -> AppError {
+    let current = if table == "household" {
+        let sql = format!("SELECT updated_at FROM {table} WHERE id = ?");
+        sqlx::query_scalar::<_, Option<i64>>(&sql)
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    } else {
+        let sql = format!("SELECT updated_at FROM {table} WHERE household_id = ? AND id = ?");
+        sqlx::query_scalar::<_, Option<i64>>(&sql)
+            .bind(household_id.unwrap_or(""))
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    };
+    match current {
+        Ok(Some(current)) => AppError::new(
+            STALE_WRITE_CODE,
+            "This record was changed elsewhere. Reload it and try again.",
+        )
+        .with_context("expected_updated_at", expected.to_string())
+        .with_context(
+            "current_updated_at",
+            current.map(|value| value.to_string()).unwrap_or_default(),
+        ),
+        Ok(None) => AppError::new("DB/NOT_FOUND", "Record not found"),
+        Err(err) => AppError::from(err),
+    }
 }
 
 fn bind_value<'q>(
@@ -2151,6 +3308,8 @@ fn bind_value<'q>(
     }
 }
 
+/// List live rows of `table` for a household. `date_range` narrows the
+/// result to one of the table's timestamp columns.
 pub async fn list_command(
     pool: &SqlitePool,
     table: &str,
@@ -2158,6 +3317,7 @@ pub async fn list_command(
     order_by: Option<&str>,
     limit: Option<i64>,
     offset: Option<i64>,
+    date_range: Option<&ListDateRange>,
 ) -> AppResult<Vec<Value>> {
     let scope = if table == "family_members" {
         Some(LogScope::new(
@@ -2169,7 +3329,17 @@ pub async fn list_command(
         None
     };
 
-    match list(pool, table, household_id, order_by, limit, offset).await {
+    match list(
+        pool,
+        table,
+        household_id,
+        order_by,
+        limit,
+        offset,
+        date_range,
+    )
+    .await
+    {
         Ok(rows) => {
             if let Some(scope) = scope.as_ref() {
                 scope.success(
@@ -2237,6 +3407,22 @@ pub async fn get_command(
     }
 }
 
+pub async fn get_many_command(
+    pool: &SqlitePool,
+    table: &str,
+    household_id: &str,
+    ids: &[String],
+) -> AppResult<Vec<Value>> {
+    match repo::get_many_active(pool, table, household_id, ids).await {
+        Ok(rows) => Ok(rows.into_iter().map(row_to_value).collect()),
+        Err(err) => Err(AppError::from(err)
+            .with_context("operation", "get_many")
+            .with_context("table", table.to_string())
+            .with_context("household_id", household_id.to_string())
+            .with_context("requested", ids.len().to_string())),
+    }
+}
+
 // TXN: domain=OUT OF SCOPE tables=*
 pub async fn create_command(
     pool: &SqlitePool,
@@ -2295,6 +3481,7 @@ pub async fn update_command(
     data: Map<String, Value>,
     household_id: Option<&str>,
     attachment: Option<AttachmentMutationGuard>,
+    expected_updated_at: Option<i64>,
 ) -> AppResult<()> {
     let scope = if table == "family_members" {
         let household = household_id.map(|value| value.to_string()).or_else(|| {
@@ -2319,6 +3506,7 @@ pub async fn update_command(
         data,
         household_id,
         attachment.as_ref(),
+        expected_updated_at,
     )
     .await
     {
@@ -2348,6 +3536,49 @@ pub async fn update_command(
     }
 }
 
+/// Note links follow the soft-delete state of the entity they point at.
+fn note_link_entity_type(table: &str) -> Option<NoteLinkEntityType> {
+    match table {
+        "events" => Some(NoteLinkEntityType::Event),
+        _ => None,
+    }
+}
+
+/// Soft-delete a row and hide the note links pointing at it in one
+/// transaction, so a failure leaves neither half applied.
+async fn soft_delete_with_links(
+    pool: &SqlitePool,
+    table: &str,
+    household_id: &str,
+    id: &str,
+) -> AppResult<()> {
+    let mut tx = pool.begin().await.map_err(AppError::from)?;
+    repo::set_deleted_at_in(&mut tx, table, household_id, id)
+        .await
+        .map_err(AppError::from)?;
+    if let Some(entity_type) = note_link_entity_type(table) {
+        note_links::soft_delete_links_for_entity(&mut tx, household_id, entity_type, id).await?;
+    }
+    tx.commit().await.map_err(AppError::from)
+}
+
+/// Counterpart of [`soft_delete_with_links`] for restores.
+async fn restore_with_links(
+    pool: &SqlitePool,
+    table: &str,
+    household_id: &str,
+    id: &str,
+) -> AppResult<()> {
+    let mut tx = pool.begin().await.map_err(AppError::from)?;
+    repo::clear_deleted_at_in(&mut tx, table, household_id, id)
+        .await
+        .map_err(AppError::from)?;
+    if let Some(entity_type) = note_link_entity_type(table) {
+        note_links::restore_links_for_entity(&mut tx, household_id, entity_type, id).await?;
+    }
+    tx.commit().await.map_err(AppError::from)
+}
+
 // TXN: domain=OUT OF SCOPE tables=*
 pub async fn delete_command(
     pool: &SqlitePool,
@@ -2392,7 +3623,7 @@ pub async fn delete_command(
                     .with_context("id", id.to_string())
             });
     }
-    match repo::set_deleted_at(pool, table, household_id, id).await {
+    match soft_delete_with_links(pool, table, household_id, id).await {
         Ok(()) => {
             if let Some(scope) = scope.as_ref() {
                 scope.success(
@@ -2406,7 +3637,7 @@ pub async fn delete_command(
             Ok(())
         }
         Err(err) => {
-            let app_err = AppError::from(err)
+            let app_err = err
                 .with_context("operation", "delete")
                 .with_context("table", table.to_string())
                 .with_context("household_id", household_id.to_string())
@@ -2447,7 +3678,7 @@ pub async fn restore_command(
                     .with_context("id", id.to_string())
             });
     }
-    match repo::clear_deleted_at(pool, table, household_id, id).await {
+    match restore_with_links(pool, table, household_id, id).await {
         Ok(()) => {
             if let Some(scope) = scope.as_ref() {
                 scope.success(
@@ -2461,7 +3692,7 @@ pub async fn restore_command(
             Ok(())
         }
         Err(err) => {
-            let app_err = AppError::from(err)
+            let app_err = err
                 .with_context("operation", "restore")
                 .with_context("table", table.to_string())
                 .with_context("household_id", household_id.to_string())
@@ -10,6 +10,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::str::FromStr;
+use std::time::Duration;
 use tauri::{AppHandle, Manager};
 
 #[cfg(test)]
@@ -24,6 +25,9 @@ pub mod manifest;
 #[path = "db/backup.rs"]
 pub mod backup;
 
+#[path = "db/backup_diff.rs"]
+pub mod backup_diff;
+
 #[path = "db/repair.rs"]
 pub mod repair;
 
@@ -36,6 +40,12 @@ pub mod swap;
 #[path = "db/schema_rebuild.rs"]
 pub mod schema_rebuild;
 
+#[path = "db/upkeep.rs"]
+pub mod upkeep;
+
+#[path = "db/footprint.rs"]
+pub mod footprint;
+
 #[allow(dead_code)]
 #[cfg(test)]
 pub(super) static WRITE_ATOMIC_CRASH_BEFORE_RENAME: AtomicBool = AtomicBool::new(false);
@@ -135,8 +145,86 @@ where
     }
 }
 
-// TXN: domain=OUT OF SCOPE tables=PRAGMA
+/// Overrides how many connections the main pool may open.
+pub const MAX_CONNECTIONS_ENV: &str = "ARK_DB_MAX_CONNECTIONS";
+/// Overrides how long a statement waits on a locked database, in milliseconds.
+pub const BUSY_TIMEOUT_ENV: &str = "ARK_DB_BUSY_TIMEOUT_MS";
+/// Overrides how long a caller waits for a free pool connection, in seconds.
+pub const ACQUIRE_TIMEOUT_ENV: &str = "ARK_DB_ACQUIRE_TIMEOUT_SECS";
+/// Pool size used when [`MAX_CONNECTIONS_ENV`] is unset or unparsable.
+pub const DEFAULT_MAX_CONNECTIONS: u32 = 8;
+pub const DEFAULT_BUSY_TIMEOUT: Duration = Duration::from_millis(5000);
+pub const DEFAULT_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_CONNECTIONS_LIMIT: u32 = 64;
+
+/// Connection limits applied when the main pool is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolSettings {
+    pub max_connections: u32,
+    /// SQLite `busy_timeout`: how long a statement retries against a lock
+    /// held by another connection before failing with `SQLITE_BUSY`.
+    pub busy_timeout: Duration,
+    /// How long a caller waits for a pooled connection before `DB_BUSY`.
+    pub acquire_timeout: Duration,
+}
+
+impl Default for PoolSettings {
+    fn default() -> Self {
+        Self {
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            busy_timeout: DEFAULT_BUSY_TIMEOUT,
+            acquire_timeout: DEFAULT_ACQUIRE_TIMEOUT,
+        }
+    }
+}
+
+impl PoolSettings {
+    /// Read each setting from its environment variable, falling back to the
+    /// default when unset or unparsable. The pool size is clamped to `1..=64`.
+    pub fn from_env() -> Self {
+        let read = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let defaults = Self::default();
+        Self {
+            max_connections: read(MAX_CONNECTIONS_ENV)
+                .map(|value| value.clamp(1, u64::from(MAX_CONNECTIONS_LIMIT)) as u32)
+                .unwrap_or(defaults.max_connections),
+            busy_timeout: read(BUSY_TIMEOUT_ENV)
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.busy_timeout),
+            acquire_timeout: read(ACQUIRE_TIMEOUT_ENV)
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.acquire_timeout),
+        }
+    }
+
+    /// Settings for reopening `pool` after a repair swap. The busy timeout
+    /// is not recoverable from an open pool and is re-read from the
+    /// environment.
+    pub fn matching(pool: &SqlitePool) -> Self {
+        let options = pool.options();
+        Self {
+            max_connections: options.get_max_connections(),
+            acquire_timeout: options.get_acquire_timeout(),
+            ..Self::from_env()
+        }
+    }
+}
+
 pub async fn connect_sqlite_pool(db_path: &Path) -> Result<Pool<Sqlite>> {
+    connect_sqlite_pool_with(db_path, PoolSettings::from_env()).await
+}
+
+// TXN: domain=OUT OF SCOPE tables=PRAGMA
+/// Like [`connect_sqlite_pool`], with explicit [`PoolSettings`]. Used when
+/// reopening after a repair swap so the new pool matches the one it replaces.
+pub async fn connect_sqlite_pool_with(
+    db_path: &Path,
+    settings: PoolSettings,
+) -> Result<Pool<Sqlite>> {
     let db_path_str = db_path.to_str().ok_or_else(|| {
         anyhow!(
             "Database path is not valid UTF-8: {}",
@@ -149,15 +237,14 @@ pub async fn connect_sqlite_pool(db_path: &Path) -> Result<Pool<Sqlite>> {
         .create_if_missing(true)
         .journal_mode(SqliteJournalMode::Wal)
         .synchronous(SqliteSynchronous::Full)
-        .foreign_keys(true);
+        .foreign_keys(true)
+        .busy_timeout(settings.busy_timeout);
 
     let pool = SqlitePoolOptions::new()
-        .max_connections(8)
+        .max_connections(settings.max_connections.max(1))
+        .acquire_timeout(settings.acquire_timeout)
         .after_connect(|conn, _| {
             Box::pin(async move {
-                sqlx::query("PRAGMA busy_timeout = 5000;")
-                    .execute(&mut *conn)
-                    .await?;
                 sqlx::query("PRAGMA wal_autocheckpoint = 1000;")
                     .execute(&mut *conn)
                     .await?;
@@ -230,7 +317,8 @@ async fn log_effective_pragmas(pool: &Pool<Sqlite>) {
         journal_mode = %jm.0,
         synchronous = %sync.0,
         foreign_keys = %fks.0,
-        busy_timeout_ms = %busy.0
+        busy_timeout_ms = %busy.0,
+        max_connections = pool.options().get_max_connections()
     );
 
     if !jm.0.eq_ignore_ascii_case("wal") {
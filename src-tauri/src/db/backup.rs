@@ -16,7 +16,8 @@ use ts_rs::TS;
 
 use crate::{attachments, db::manifest, AppError, AppResult};
 
-use super::manifest::BackupManifest;
+use super::backup_diff::{self, DIFF_FILE_NAME};
+use super::manifest::{BackupKind, BackupManifest};
 
 const DB_FILE_NAME: &str = "arklowdun.sqlite3";
 const BACKUP_DIR_NAME: &str = "backups";
@@ -39,6 +40,9 @@ pub struct BackupEntry {
     pub manifest: BackupManifest,
     #[ts(type = "number")]
     pub total_size_bytes: u64,
+    /// Whether the full backup a differential backup applies onto is still
+    /// on disk. Always true for full backups.
+    pub base_present: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -58,6 +62,36 @@ pub struct BackupOverview {
     pub backups: Vec<BackupEntry>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+#[serde(rename_all = "snake_case")]
+pub enum BackupVerifyStatus {
+    Ok,
+    Mismatch,
+}
+
+/// Outcome of re-checking a backup on disk against its manifest.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct BackupVerification {
+    pub status: BackupVerifyStatus,
+    pub kind: BackupKind,
+    pub sqlite_path: String,
+    pub manifest_path: String,
+    pub expected_sha256: String,
+    pub actual_sha256: String,
+    #[ts(type = "number")]
+    pub expected_size_bytes: u64,
+    #[ts(type = "number")]
+    pub actual_size_bytes: u64,
+    pub integrity_ok: bool,
+    /// First problem reported by `PRAGMA integrity_check`, if any.
+    pub integrity_details: Option<String>,
+    /// Why a differential backup's base could not be used, if it could not.
+    pub base_details: Option<String>,
+}
+
 struct RetentionConfig {
     max_count: usize,
     max_bytes: u64,
@@ -93,6 +127,7 @@ struct BackupRecord {
     manifest: BackupManifest,
     total_size_bytes: u64,
     created_at: DateTime<Utc>,
+    base_present: bool,
 }
 
 impl BackupRecord {
@@ -103,6 +138,7 @@ impl BackupRecord {
             manifest_path: self.manifest_path.to_string_lossy().into_owned(),
             manifest: self.manifest,
             total_size_bytes: self.total_size_bytes,
+            base_present: self.base_present,
         }
     }
 }
@@ -120,19 +156,67 @@ pub async fn overview(_pool: &SqlitePool, db_path: &Path) -> AppResult<BackupOve
 }
 
 pub async fn create_backup(pool: &SqlitePool, db_path: &Path) -> AppResult<BackupEntry> {
+    create_backup_with_mode(pool, db_path, false).await
+}
+
+/// Store only the pages that changed since the most recent full backup.
+/// Falls back to a full backup when there is no usable base.
+pub async fn create_differential_backup(
+    pool: &SqlitePool,
+    db_path: &Path,
+) -> AppResult<BackupEntry> {
+    create_backup_with_mode(pool, db_path, true).await
+}
+
+async fn create_backup_with_mode(
+    pool: &SqlitePool,
+    db_path: &Path,
+    differential: bool,
+) -> AppResult<BackupEntry> {
     let schema_hash = manifest::schema_hash(pool)
         .await
         .map_err(|err| AppError::from(err).with_context("operation", "schema_hash"))?;
     let db_path = db_path.to_path_buf();
     let retention = RetentionConfig::load();
     let schema = schema_hash.clone();
-    let record = task::spawn_blocking(move || create_backup_sync(&db_path, &schema, &retention))
+    let record = task::spawn_blocking(move || {
+        create_backup_sync(&db_path, &schema, &retention, differential)
+    })
+    .await
+    .map_err(|err| {
+        AppError::new("DB_BACKUP/TASK", "Backup task panicked")
+            .with_context("error", err.to_string())
+    })??;
+    Ok(record)
+}
+
+/// Recompute the sha256 of a backup's database file, compare it and the
+/// size against `manifest.json`, and run `PRAGMA integrity_check` on a
+/// read-only connection. `backup_path` may name the backup directory or the
+/// sqlite file inside it.
+pub async fn verify_backup(backup_path: &Path) -> AppResult<BackupVerification> {
+    let backup_path = backup_path.to_path_buf();
+    task::spawn_blocking(move || verify_backup_sync(&backup_path))
         .await
         .map_err(|err| {
-            AppError::new("DB_BACKUP/TASK", "Backup task panicked")
+            AppError::new("DB_BACKUP/TASK", "Backup verification task panicked")
                 .with_context("error", err.to_string())
-        })??;
-    Ok(record)
+        })?
+}
+
+/// Rebuild the database held by a backup directory at `dest`, applying a
+/// differential backup onto its base. The result is checked against the
+/// manifest digest before it is moved into place. An existing file at `dest`
+/// is never replaced, so the live database cannot be overwritten.
+pub async fn restore_backup_to(backup_dir: &Path, dest: &Path) -> AppResult<PathBuf> {
+    let backup_dir = backup_dir.to_path_buf();
+    let dest = dest.to_path_buf();
+    task::spawn_blocking(move || restore_backup_to_sync(&backup_dir, &dest))
+        .await
+        .map_err(|err| {
+            AppError::new("DB_BACKUP/TASK", "Backup restore task panicked")
+                .with_context("error", err.to_string())
+        })?
 }
 
 pub fn reveal_backup_root(db_path: &Path) -> AppResult<()> {
@@ -162,6 +246,238 @@ pub fn reveal_backup(db_path: &Path, sqlite_path: &Path) -> AppResult<()> {
     attachments::reveal_with_os(&canonical)
 }
 
+fn verify_backup_sync(backup_path: &Path) -> AppResult<BackupVerification> {
+    let dir = if backup_path.is_dir() {
+        backup_path.to_path_buf()
+    } else {
+        backup_path.parent().map(Path::to_path_buf).ok_or_else(|| {
+            AppError::new(
+                "DB_BACKUP/NO_PARENT",
+                "Backup path does not have a parent directory",
+            )
+            .with_context("path", backup_path.display().to_string())
+        })?
+    };
+    let manifest_path = dir.join(manifest::MANIFEST_FILE_NAME);
+    if !manifest_path.is_file() {
+        return Err(
+            AppError::new("DB_BACKUP/MISSING_MANIFEST", "Backup missing manifest")
+                .with_context("path", manifest_path.display().to_string()),
+        );
+    }
+    let manifest = manifest::read_manifest(&manifest_path).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "read_manifest")
+            .with_context("path", manifest_path.display().to_string())
+    })?;
+    // A differential backup directory has no database file of its own, so
+    // any path inside it names the diff.
+    let sqlite_path = if backup_path.is_dir() || manifest.kind == BackupKind::Differential {
+        dir.join(backup_payload_name(&manifest))
+    } else {
+        backup_path.to_path_buf()
+    };
+    if !sqlite_path.is_file() {
+        return Err(
+            AppError::new("DB_BACKUP/MISSING_DB", "Backup missing database file")
+                .with_context("path", sqlite_path.display().to_string()),
+        );
+    }
+
+    // Differential backups are checked on the rebuilt database, which is
+    // what the manifest digest describes. A missing or changed base makes the
+    // backup unrestorable, so it is reported as a mismatch.
+    let rebuilt = match manifest.kind {
+        BackupKind::Full => None,
+        BackupKind::Differential => {
+            let tmp = tempfile::NamedTempFile::new_in(&dir).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "create_verify_tempfile")
+                    .with_context("path", dir.display().to_string())
+            })?;
+            match rebuild_snapshot(&dir, &manifest, tmp.path()) {
+                Ok(()) => Some(tmp),
+                Err(err) if is_base_problem(&err) => {
+                    return Ok(BackupVerification {
+                        status: BackupVerifyStatus::Mismatch,
+                        kind: manifest.kind,
+                        sqlite_path: sqlite_path.to_string_lossy().into_owned(),
+                        manifest_path: manifest_path.to_string_lossy().into_owned(),
+                        expected_sha256: manifest.sha256,
+                        actual_sha256: String::new(),
+                        expected_size_bytes: manifest.db_size_bytes,
+                        actual_size_bytes: 0,
+                        integrity_ok: false,
+                        integrity_details: None,
+                        base_details: Some(err.message().to_string()),
+                    });
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    };
+    let checked_path = rebuilt
+        .as_ref()
+        .map(|tmp| tmp.path())
+        .unwrap_or(sqlite_path.as_path());
+
+    let actual_sha256 = manifest::file_sha256(checked_path)
+        .map_err(|err| AppError::from(err).with_context("operation", "hash_backup"))?;
+    let actual_size_bytes = fs::metadata(checked_path)
+        .map(|meta| meta.len())
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "backup_metadata")
+                .with_context("path", checked_path.display().to_string())
+        })?;
+    let integrity_details = backup_integrity_problem(checked_path);
+    let integrity_ok = integrity_details.is_none();
+
+    let matches = actual_sha256.eq_ignore_ascii_case(&manifest.sha256)
+        && actual_size_bytes == manifest.db_size_bytes;
+    let status = if matches && integrity_ok {
+        BackupVerifyStatus::Ok
+    } else {
+        BackupVerifyStatus::Mismatch
+    };
+
+    Ok(BackupVerification {
+        status,
+        kind: manifest.kind,
+        sqlite_path: sqlite_path.to_string_lossy().into_owned(),
+        manifest_path: manifest_path.to_string_lossy().into_owned(),
+        expected_sha256: manifest.sha256,
+        actual_sha256,
+        expected_size_bytes: manifest.db_size_bytes,
+        actual_size_bytes,
+        integrity_ok,
+        integrity_details,
+        base_details: None,
+    })
+}
+
+fn is_base_problem(err: &AppError) -> bool {
+    matches!(
+        err.code(),
+        "DB_BACKUP/MISSING_BASE" | "DB_BACKUP/BASE_MISMATCH"
+    )
+}
+
+fn backup_payload_name(manifest: &BackupManifest) -> &'static str {
+    match manifest.kind {
+        BackupKind::Full => DB_FILE_NAME,
+        BackupKind::Differential => DIFF_FILE_NAME,
+    }
+}
+
+/// Write the database described by `manifest` to `out` without checking the
+/// result. Differential backups require their base to still match the digest
+/// recorded when the diff was taken.
+fn rebuild_snapshot(dir: &Path, manifest: &BackupManifest, out: &Path) -> AppResult<()> {
+    match manifest.kind {
+        BackupKind::Full => {
+            let source = dir.join(DB_FILE_NAME);
+            fs::copy(&source, out).map(|_| ()).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "copy_backup")
+                    .with_context("from", source.display().to_string())
+                    .with_context("to", out.display().to_string())
+            })
+        }
+        BackupKind::Differential => {
+            let base_name = manifest.base_directory.as_deref().unwrap_or_default();
+            let base_dir = dir.parent().unwrap_or(dir).join(base_name);
+            let base_file = base_dir.join(DB_FILE_NAME);
+            if base_name.is_empty() || !base_file.is_file() {
+                return Err(AppError::new(
+                    "DB_BACKUP/MISSING_BASE",
+                    "The full backup this differential backup depends on is missing",
+                )
+                .with_context("path", base_dir.display().to_string()));
+            }
+            let base_sha = manifest::file_sha256(&base_file)
+                .map_err(|err| AppError::from(err).with_context("operation", "hash_base_backup"))?;
+            let expected = manifest.base_sha256.as_deref().unwrap_or_default();
+            if !base_sha.eq_ignore_ascii_case(expected) {
+                return Err(AppError::new(
+                    "DB_BACKUP/BASE_MISMATCH",
+                    "The base backup no longer matches this differential backup",
+                )
+                .with_context("path", base_file.display().to_string())
+                .with_context("expected_sha256", expected.to_string())
+                .with_context("actual_sha256", base_sha));
+            }
+            backup_diff::apply_page_diff(&base_file, &dir.join(DIFF_FILE_NAME), out).map(|_| ())
+        }
+    }
+}
+
+fn restore_backup_to_sync(backup_dir: &Path, dest: &Path) -> AppResult<PathBuf> {
+    if dest.exists() {
+        return Err(AppError::new(
+            "DB_BACKUP/RESTORE_TARGET_EXISTS",
+            "Restore target already exists",
+        )
+        .with_context("path", dest.display().to_string()));
+    }
+    let manifest_path = backup_dir.join(manifest::MANIFEST_FILE_NAME);
+    let manifest = manifest::read_manifest(&manifest_path).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "read_manifest")
+            .with_context("path", manifest_path.display().to_string())
+    })?;
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "create_restore_dir")
+                .with_context("path", parent.display().to_string())
+        })?;
+    }
+
+    let mut partial = OsString::from(dest.as_os_str());
+    partial.push(PARTIAL_SUFFIX);
+    let partial = PathBuf::from(partial);
+    let result = (|| -> AppResult<()> {
+        rebuild_snapshot(backup_dir, &manifest, &partial)?;
+        let sha = manifest::file_sha256(&partial)
+            .map_err(|err| AppError::from(err).with_context("operation", "hash_restored"))?;
+        if !sha.eq_ignore_ascii_case(&manifest.sha256) {
+            return Err(AppError::new(
+                "DB_BACKUP/RESTORE_MISMATCH",
+                "Restored database does not match the backup manifest",
+            )
+            .with_context("expected_sha256", manifest.sha256.clone())
+            .with_context("actual_sha256", sha));
+        }
+        fs::rename(&partial, dest).map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "finalize_restore")
+                .with_context("from", partial.display().to_string())
+                .with_context("to", dest.display().to_string())
+        })
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+    result?;
+    Ok(dest.to_path_buf())
+}
+
+/// `None` when the backup opens read-only and `integrity_check` reports ok.
+fn backup_integrity_problem(sqlite_path: &Path) -> Option<String> {
+    let conn = match Connection::open_with_flags(sqlite_path, OpenFlags::SQLITE_OPEN_READ_ONLY) {
+        Ok(conn) => conn,
+        Err(err) => return Some(format!("open failed: {err}")),
+    };
+    match conn.query_row("PRAGMA integrity_check(1);", [], |row| {
+        row.get::<_, String>(0)
+    }) {
+        Ok(result) if result.eq_ignore_ascii_case("ok") => None,
+        Ok(result) => Some(result),
+        Err(err) => Some(format!("integrity_check failed: {err}")),
+    }
+}
+
 fn overview_sync(db_path: &Path, retention: &RetentionConfig) -> AppResult<BackupOverview> {
     let root = backup_root(db_path)?;
     let db_size = fs::metadata(db_path).map(|meta| meta.len()).unwrap_or(0);
@@ -191,6 +507,7 @@ fn create_backup_sync(
     db_path: &Path,
     schema_hash: &str,
     retention: &RetentionConfig,
+    differential: bool,
 ) -> AppResult<BackupEntry> {
     let root = backup_root(db_path)?;
     fs::create_dir_all(&root).map_err(|err| {
@@ -207,15 +524,26 @@ fn create_backup_sync(
     let db_size = db_meta.len();
     let journal_bytes = journal_and_wal_bytes(db_path);
     let required = required_free_bytes(db_size.saturating_add(journal_bytes));
-    let available = free_disk_space(&root)?;
-    if available < required {
-        return Err(AppError::new(
-            "DB_BACKUP/LOW_DISK",
-            format!("Not enough disk space (need ~{}).", format_bytes(required)),
-        )
-        .with_context("available_bytes", available.to_string())
-        .with_context("required_bytes", required.to_string()));
+
+    let base = if differential {
+        latest_full_backup(&root)?
+    } else {
+        None
+    };
+    if differential && base.is_none() {
+        tracing::info!(target: "arklowdun", "differential_backup_without_base");
     }
+    // A differential backup only keeps its diff, so when the temp directory
+    // has room the snapshot is staged there and the backups volume only needs
+    // space for the diff (checked once its size is known).
+    let staging = match &base {
+        Some(_) => tempfile::tempdir()
+            .ok()
+            .filter(|dir| free_disk_space(dir.path()).is_ok_and(|available| available >= required)),
+        None => None,
+    };
+    let snapshot_dir = staging.as_ref().map(|dir| dir.path()).unwrap_or(&root);
+    ensure_free_space(snapshot_dir, required)?;
 
     let timestamp = Utc::now();
     let backup_dir = unique_backup_dir(&root, &timestamp)?;
@@ -226,30 +554,51 @@ fn create_backup_sync(
     }
     sync_dir(&root).ok();
 
-    let partial = backup_dir.join(format!("{DB_FILE_NAME}{PARTIAL_SUFFIX}"));
+    let partial = match &staging {
+        Some(dir) => dir.path().join(DB_FILE_NAME),
+        None => backup_dir.join(format!("{DB_FILE_NAME}{PARTIAL_SUFFIX}")),
+    };
     let final_path = backup_dir.join(DB_FILE_NAME);
     let result = (|| -> AppResult<BackupRecord> {
         run_sqlite_backup(db_path, &partial)?;
-        fs::rename(&partial, &final_path).map_err(|err| {
-            AppError::from(err)
-                .with_context("operation", "finalize_backup")
-                .with_context("from", partial.display().to_string())
-                .with_context("to", final_path.display().to_string())
-        })?;
-        sync_dir(&backup_dir).ok();
-        let file = fs::File::open(&final_path).map_err(|err| {
+        let file = fs::File::open(&partial).map_err(|err| {
             AppError::from(err)
                 .with_context("operation", "open_backup_file")
-                .with_context("path", final_path.display().to_string())
+                .with_context("path", partial.display().to_string())
         })?;
         file.sync_all().ok();
 
-        let sha = manifest::file_sha256(&final_path)
+        let sha = manifest::file_sha256(&partial)
             .map_err(|err| AppError::from(err).with_context("operation", "hash_backup"))?;
-        let size = fs::metadata(&final_path)
+        let size = fs::metadata(&partial)
             .map(|meta| meta.len())
             .unwrap_or(db_size);
-        let manifest = BackupManifest::new(env!("CARGO_PKG_VERSION"), schema_hash, size, sha);
+        let mut manifest = BackupManifest::new(env!("CARGO_PKG_VERSION"), schema_hash, size, sha);
+
+        let diff_base = match &base {
+            Some(base) if write_differential(&backup_dir, &partial, base)? => Some(base),
+            _ => None,
+        };
+        if let Some(base) = diff_base {
+            let base_name = base
+                .directory
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            manifest = manifest.with_base(base_name, base.manifest.sha256.clone());
+            fs::remove_file(&partial).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "remove_backup_snapshot")
+                    .with_context("path", partial.display().to_string())
+            })?;
+        } else {
+            if staging.is_some() {
+                ensure_free_space(&root, required)?;
+            }
+            place_snapshot(&partial, &final_path)?;
+        }
+        sync_dir(&backup_dir).ok();
+
         let manifest_path = backup_dir.join(manifest::MANIFEST_FILE_NAME);
         let payload = serde_json::to_vec_pretty(&manifest)
             .map_err(|err| AppError::from(err).with_context("operation", "serialize_manifest"))?;
@@ -269,11 +618,54 @@ fn create_backup_sync(
     }
 
     let record = result?;
-    apply_retention(&root, retention)?;
+    apply_retention(&root, retention, &record.directory)?;
     Ok(record.into_entry())
 }
 
-fn backup_root(db_path: &Path) -> AppResult<PathBuf> {
+/// Diff `snapshot` against `base` into the backup directory. Returns `false`
+/// when the snapshots are not comparable and a full copy should be kept.
+fn write_differential(backup_dir: &Path, snapshot: &Path, base: &BackupRecord) -> AppResult<bool> {
+    let diff_partial = backup_dir.join(format!("{DIFF_FILE_NAME}{PARTIAL_SUFFIX}"));
+    let stats = match backup_diff::page_diff_len(&base.sqlite_path, snapshot)? {
+        Some(diff_len) => {
+            ensure_free_space(backup_dir, required_free_bytes(diff_len))?;
+            backup_diff::write_page_diff(&base.sqlite_path, snapshot, &diff_partial)?
+        }
+        None => None,
+    };
+    let Some(stats) = stats else {
+        tracing::info!(
+            target: "arklowdun",
+            base = %base.directory.display(),
+            "differential_backup_page_size_changed"
+        );
+        return Ok(false);
+    };
+    let diff_path = backup_dir.join(DIFF_FILE_NAME);
+    fs::rename(&diff_partial, &diff_path).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "finalize_backup_diff")
+            .with_context("from", diff_partial.display().to_string())
+            .with_context("to", diff_path.display().to_string())
+    })?;
+    tracing::info!(
+        target: "arklowdun",
+        base = %base.directory.display(),
+        changed_pages = stats.changed_pages,
+        page_count = stats.page_count,
+        "differential_backup_written"
+    );
+    Ok(true)
+}
+
+fn latest_full_backup(root: &Path) -> AppResult<Option<BackupRecord>> {
+    Ok(collect_backups(root)?
+        .into_iter()
+        .filter(|record| record.manifest.kind == BackupKind::Full)
+        .max_by(|a, b| a.created_at.cmp(&b.created_at)))
+}
+
+pub(crate) fn backup_root(db_path: &Path) -> AppResult<PathBuf> {
     let parent = db_path.parent().ok_or_else(|| {
         AppError::new(
             "DB_BACKUP/NO_PARENT",
@@ -291,6 +683,36 @@ fn required_free_bytes(db_size: u64) -> u64 {
     ((db_size as f64 * REQUIRED_FREE_MULTIPLIER).ceil()) as u64
 }
 
+fn ensure_free_space(path: &Path, required: u64) -> AppResult<()> {
+    let available = free_disk_space(path)?;
+    if available < required {
+        return Err(AppError::new(
+            "DB_BACKUP/LOW_DISK",
+            format!("Not enough disk space (need ~{}).", format_bytes(required)),
+        )
+        .with_context("available_bytes", available.to_string())
+        .with_context("required_bytes", required.to_string()));
+    }
+    Ok(())
+}
+
+/// Move a finished snapshot into its backup directory, copying when it was
+/// staged on another volume.
+fn place_snapshot(from: &Path, to: &Path) -> AppResult<()> {
+    let context = |err: io::Error| {
+        AppError::from(err)
+            .with_context("operation", "finalize_backup")
+            .with_context("from", from.display().to_string())
+            .with_context("to", to.display().to_string())
+    };
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    fs::copy(from, to).map_err(context)?;
+    fs::File::open(to).and_then(|file| file.sync_all()).ok();
+    fs::remove_file(from).map_err(context)
+}
+
 fn free_disk_space(path: &Path) -> AppResult<u64> {
     if let Ok(fake) = env::var("ARK_BACKUP_FAKE_FREE_BYTES") {
         if let Ok(value) = fake.parse::<u64>() {
@@ -464,7 +886,7 @@ fn looks_like_backup_dir(name: &str) -> bool {
 }
 
 fn load_record(dir: &Path, manifest: BackupManifest) -> AppResult<BackupRecord> {
-    let sqlite_path = dir.join(DB_FILE_NAME);
+    let sqlite_path = dir.join(backup_payload_name(&manifest));
     let manifest_path = dir.join(manifest::MANIFEST_FILE_NAME);
     if !sqlite_path.exists() {
         return Err(
@@ -481,6 +903,20 @@ fn load_record(dir: &Path, manifest: BackupManifest) -> AppResult<BackupRecord>
 
     let total_size = dir_size(dir)?;
     let created_at = parse_created_at(&manifest).unwrap_or_else(|| fallback_created_at(dir));
+    let base_present = match manifest.kind {
+        BackupKind::Full => true,
+        BackupKind::Differential => manifest
+            .base_directory
+            .as_deref()
+            .filter(|name| !name.is_empty())
+            .is_some_and(|name| {
+                dir.parent()
+                    .unwrap_or(dir)
+                    .join(name)
+                    .join(DB_FILE_NAME)
+                    .is_file()
+            }),
+    };
 
     Ok(BackupRecord {
         directory: dir.to_path_buf(),
@@ -489,10 +925,11 @@ fn load_record(dir: &Path, manifest: BackupManifest) -> AppResult<BackupRecord>
         manifest,
         total_size_bytes: total_size,
         created_at,
+        base_present,
     })
 }
 
-fn dir_size(path: &Path) -> AppResult<u64> {
+pub(crate) fn dir_size(path: &Path) -> AppResult<u64> {
     let mut total = 0_u64;
     for entry in fs::read_dir(path).map_err(|err| {
         AppError::from(err)
@@ -529,27 +966,95 @@ fn fallback_created_at(path: &Path) -> DateTime<Utc> {
     DateTime::<Utc>::from(SystemTime::UNIX_EPOCH)
 }
 
-fn apply_retention(root: &Path, retention: &RetentionConfig) -> AppResult<()> {
+/// Prune old backups. A full backup and the differential backups built on it
+/// form one chain: `max_count` counts chains, and a base is only removed
+/// together with every differential that depends on it. The chain holding
+/// `keep` (the backup just written) is never removed; when it alone exceeds
+/// `max_bytes`, its oldest differentials go first.
+fn apply_retention(root: &Path, retention: &RetentionConfig, keep: &Path) -> AppResult<()> {
     let mut records = collect_backups(root)?;
     if records.is_empty() {
         return Ok(());
     }
-    records.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    records.sort_by(|a, b| {
+        a.created_at
+            .cmp(&b.created_at)
+            .then_with(|| a.directory.cmp(&b.directory))
+    });
     let mut total: u64 = records.iter().map(|r| r.total_size_bytes).sum();
 
-    while records.len() > retention.max_count || total > retention.max_bytes {
-        let record = records.remove(0);
-        let size = record.total_size_bytes;
-        if let Err(err) = fs::remove_dir_all(&record.directory) {
-            tracing::warn!(
-                target: "arklowdun",
-                error = %err,
-                path = %record.directory.display(),
-                "failed_to_remove_old_backup"
-            );
-            continue;
+    // Chains in creation order of their first member. Differentials whose
+    // base is gone form a chain of their own.
+    let mut chains: Vec<Vec<BackupRecord>> = Vec::new();
+    for record in records {
+        let base = match record.manifest.kind {
+            BackupKind::Full => None,
+            BackupKind::Differential => record.manifest.base_directory.clone(),
+        };
+        let chain = base.and_then(|base| {
+            chains.iter_mut().find(|chain| {
+                chain[0].manifest.kind == BackupKind::Full
+                    && chain[0]
+                        .directory
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        == Some(base.as_str())
+            })
+        });
+        match chain {
+            Some(chain) => chain.push(record),
+            None => chains.push(vec![record]),
+        }
+    }
+    let holds_keep = |chain: &[BackupRecord]| chain.iter().any(|r| r.directory == keep);
+
+    while chains.len() > retention.max_count || total > retention.max_bytes {
+        let Some(idx) = chains
+            .iter()
+            .position(|chain| !holds_keep(chain.as_slice()))
+        else {
+            break;
+        };
+        let chain = chains.remove(idx);
+        // Differentials first, so a failed removal never leaves them baseless.
+        for record in chain.into_iter().rev() {
+            if let Err(err) = fs::remove_dir_all(&record.directory) {
+                tracing::warn!(
+                    target: "arklowdun",
+                    error = %err,
+                    path = %record.directory.display(),
+                    "failed_to_remove_old_backup"
+                );
+                continue;
+            }
+            total = total.saturating_sub(record.total_size_bytes);
+        }
+    }
+
+    if total > retention.max_bytes {
+        if let Some(chain) = chains.iter_mut().find(|chain| holds_keep(chain.as_slice())) {
+            let mut idx = 0;
+            while total > retention.max_bytes && idx < chain.len() {
+                let record = &chain[idx];
+                let removable =
+                    record.manifest.kind == BackupKind::Differential && record.directory != keep;
+                if !removable {
+                    idx += 1;
+                    continue;
+                }
+                let record = chain.remove(idx);
+                if let Err(err) = fs::remove_dir_all(&record.directory) {
+                    tracing::warn!(
+                        target: "arklowdun",
+                        error = %err,
+                        path = %record.directory.display(),
+                        "failed_to_remove_old_backup"
+                    );
+                    continue;
+                }
+                total = total.saturating_sub(record.total_size_bytes);
+            }
         }
-        total = total.saturating_sub(size);
     }
 
     Ok(())
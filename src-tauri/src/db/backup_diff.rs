@@ -0,0 +1,289 @@
+//! Page-level diffs between two SQLite snapshots.
+//!
+//! A differential backup stores only the pages of a fresh snapshot that differ
+//! from the base snapshot, plus the target page count so the database can be
+//! truncated or extended when it is rebuilt. The file layout is:
+//!
+//! ```text
+//! magic "ARKPDIF1" | page_size u32 LE | page_count u64 LE | changed u64 LE
+//! changed × (page_index u64 LE | page bytes)
+//! ```
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::{AppError, AppResult};
+
+pub const DIFF_FILE_NAME: &str = "arklowdun.sqlite3.pagediff";
+
+const MAGIC: &[u8; 8] = b"ARKPDIF1";
+const HEADER_LEN: u64 = 8 + 4 + 8 + 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageDiffStats {
+    pub page_size: usize,
+    pub page_count: u64,
+    pub changed_pages: u64,
+}
+
+/// Page size recorded in the SQLite header (offset 16, big-endian; 1 means 65536).
+pub fn sqlite_page_size(path: &Path) -> io::Result<usize> {
+    let mut header = [0_u8; 18];
+    File::open(path)?.read_exact(&mut header)?;
+    match u16::from_be_bytes([header[16], header[17]]) {
+        1 => Ok(65_536),
+        size if size >= 512 && size.is_power_of_two() => Ok(size as usize),
+        size => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid sqlite page size {size}"),
+        )),
+    }
+}
+
+/// Write the pages of `target` that differ from `base` to `out`. Returns
+/// `None` without writing anything when the page sizes differ, in which case
+/// the caller should keep a full snapshot instead.
+#[allow(clippy::result_large_err)]
+pub fn write_page_diff(base: &Path, target: &Path, out: &Path) -> AppResult<Option<PageDiffStats>> {
+    let Some((page_size, page_count)) = diff_layout(base, target)? else {
+        return Ok(None);
+    };
+
+    let mut writer = BufWriter::new(File::create(out).map_err(io_err("create_page_diff", out))?);
+    let write_err = io_err("write_page_diff", out);
+    writer.write_all(MAGIC).map_err(&write_err)?;
+    writer
+        .write_all(&(page_size as u32).to_le_bytes())
+        .map_err(&write_err)?;
+    writer
+        .write_all(&page_count.to_le_bytes())
+        .map_err(&write_err)?;
+    writer.write_all(&0_u64.to_le_bytes()).map_err(&write_err)?;
+
+    let changed_pages = scan_changed_pages(base, target, page_size, page_count, |index, page| {
+        writer.write_all(&index.to_le_bytes()).map_err(&write_err)?;
+        writer.write_all(page).map_err(&write_err)
+    })?;
+
+    let mut file = writer
+        .into_inner()
+        .map_err(|err| write_err(err.into_error()))?;
+    file.seek(SeekFrom::Start(HEADER_LEN - 8))
+        .map_err(&write_err)?;
+    file.write_all(&changed_pages.to_le_bytes())
+        .map_err(&write_err)?;
+    file.sync_all().map_err(&write_err)?;
+
+    Ok(Some(PageDiffStats {
+        page_size,
+        page_count,
+        changed_pages,
+    }))
+}
+
+/// Size in bytes of the diff `write_page_diff` would write for these
+/// snapshots, or `None` when the page sizes differ. Lets callers check for
+/// free space before anything is written.
+#[allow(clippy::result_large_err)]
+pub fn page_diff_len(base: &Path, target: &Path) -> AppResult<Option<u64>> {
+    let Some((page_size, page_count)) = diff_layout(base, target)? else {
+        return Ok(None);
+    };
+    let changed_pages = scan_changed_pages(base, target, page_size, page_count, |_, _| Ok(()))?;
+    Ok(Some(HEADER_LEN + changed_pages * (8 + page_size as u64)))
+}
+
+fn io_err(operation: &'static str, path: &Path) -> impl Fn(io::Error) -> AppError {
+    let path = path.display().to_string();
+    move |err: io::Error| {
+        AppError::from(err)
+            .with_context("operation", operation)
+            .with_context("path", path.clone())
+    }
+}
+
+/// Page size and target page count, or `None` when the snapshots use
+/// different page sizes and cannot be diffed.
+#[allow(clippy::result_large_err)]
+fn diff_layout(base: &Path, target: &Path) -> AppResult<Option<(usize, u64)>> {
+    let base_page_size = sqlite_page_size(base).map_err(io_err("read_base_page_size", base))?;
+    let page_size = sqlite_page_size(target).map_err(io_err("read_target_page_size", target))?;
+    if base_page_size != page_size {
+        return Ok(None);
+    }
+    let target_len = fs::metadata(target)
+        .map_err(io_err("target_metadata", target))?
+        .len();
+    Ok(Some((page_size, target_len.div_ceil(page_size as u64))))
+}
+
+/// Call `visit` with the index and bytes of every `target` page that differs
+/// from `base`, returning how many there were.
+#[allow(clippy::result_large_err)]
+fn scan_changed_pages(
+    base: &Path,
+    target: &Path,
+    page_size: usize,
+    page_count: u64,
+    mut visit: impl FnMut(u64, &[u8]) -> AppResult<()>,
+) -> AppResult<u64> {
+    let mut base_reader =
+        BufReader::new(File::open(base).map_err(io_err("open_base_snapshot", base))?);
+    let mut target_reader =
+        BufReader::new(File::open(target).map_err(io_err("open_target_snapshot", target))?);
+
+    let mut base_page = vec![0_u8; page_size];
+    let mut target_page = vec![0_u8; page_size];
+    let mut changed_pages = 0_u64;
+    for index in 0..page_count {
+        target_page.fill(0);
+        read_page(&mut target_reader, &mut target_page)
+            .map_err(io_err("read_target_snapshot", target))?;
+        base_page.fill(0);
+        let base_read = read_page(&mut base_reader, &mut base_page)
+            .map_err(io_err("read_base_snapshot", base))?;
+        if base_read == page_size && base_page == target_page {
+            continue;
+        }
+        visit(index, &target_page)?;
+        changed_pages += 1;
+    }
+    Ok(changed_pages)
+}
+
+/// Rebuild the target snapshot at `out` by copying `base` and overlaying the
+/// pages stored in `diff`.
+#[allow(clippy::result_large_err)]
+pub fn apply_page_diff(base: &Path, diff: &Path, out: &Path) -> AppResult<PageDiffStats> {
+    let invalid = |message: &str| {
+        AppError::new("DB_BACKUP/INVALID_DIFF", message.to_string())
+            .with_context("path", diff.display().to_string())
+    };
+    let read_err = |err: io::Error| {
+        AppError::from(err)
+            .with_context("operation", "read_page_diff")
+            .with_context("path", diff.display().to_string())
+    };
+    let write_err = |err: io::Error| {
+        AppError::from(err)
+            .with_context("operation", "apply_page_diff")
+            .with_context("path", out.display().to_string())
+    };
+
+    let mut reader = BufReader::new(File::open(diff).map_err(read_err)?);
+    let mut magic = [0_u8; 8];
+    reader.read_exact(&mut magic).map_err(read_err)?;
+    if &magic != MAGIC {
+        return Err(invalid("Page diff has an unknown format"));
+    }
+    let page_size = read_u32(&mut reader).map_err(read_err)? as usize;
+    let page_count = read_u64(&mut reader).map_err(read_err)?;
+    let changed_pages = read_u64(&mut reader).map_err(read_err)?;
+    if page_size < 512 || !page_size.is_power_of_two() {
+        return Err(invalid("Page diff has an invalid page size"));
+    }
+
+    fs::copy(base, out).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "copy_base_snapshot")
+            .with_context("from", base.display().to_string())
+            .with_context("to", out.display().to_string())
+    })?;
+    let mut file = OpenOptions::new()
+        .write(true)
+        .open(out)
+        .map_err(write_err)?;
+    file.set_len(page_count * page_size as u64)
+        .map_err(write_err)?;
+
+    let mut page = vec![0_u8; page_size];
+    for _ in 0..changed_pages {
+        let index = read_u64(&mut reader).map_err(read_err)?;
+        if index >= page_count {
+            return Err(invalid("Page diff references a page past the end"));
+        }
+        reader.read_exact(&mut page).map_err(read_err)?;
+        file.seek(SeekFrom::Start(index * page_size as u64))
+            .map_err(write_err)?;
+        file.write_all(&page).map_err(write_err)?;
+    }
+    file.sync_all().map_err(write_err)?;
+
+    Ok(PageDiffStats {
+        page_size,
+        page_count,
+        changed_pages,
+    })
+}
+
+/// Fill `buf` from `reader`, returning how many bytes were read before EOF.
+fn read_page(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut buf = [0_u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut buf = [0_u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn fake_db(pages: &[u8], page_size: usize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for fill in pages {
+            bytes.extend(std::iter::repeat(*fill).take(page_size));
+        }
+        bytes[16..18].copy_from_slice(&(page_size as u16).to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn diff_roundtrips_changed_and_grown_pages() {
+        let tmp = tempdir().unwrap();
+        let base = tmp.path().join("base");
+        let target = tmp.path().join("target");
+        let diff = tmp.path().join("diff");
+        let out = tmp.path().join("out");
+        fs::write(&base, fake_db(&[1, 2, 3], 512)).unwrap();
+        fs::write(&target, fake_db(&[1, 9, 3, 4], 512)).unwrap();
+
+        let expected_len = page_diff_len(&base, &target).unwrap().unwrap();
+        let stats = write_page_diff(&base, &target, &diff).unwrap().unwrap();
+        assert_eq!(stats.page_count, 4);
+        assert_eq!(stats.changed_pages, 2);
+        assert_eq!(fs::metadata(&diff).unwrap().len(), expected_len);
+
+        apply_page_diff(&base, &diff, &out).unwrap();
+        assert_eq!(fs::read(&out).unwrap(), fs::read(&target).unwrap());
+    }
+
+    #[test]
+    fn diff_skips_mismatched_page_sizes() {
+        let tmp = tempdir().unwrap();
+        let base = tmp.path().join("base");
+        let target = tmp.path().join("target");
+        fs::write(&base, fake_db(&[1, 2], 512)).unwrap();
+        fs::write(&target, fake_db(&[1], 1024)).unwrap();
+        let diff = tmp.path().join("diff");
+        assert!(write_page_diff(&base, &target, &diff).unwrap().is_none());
+        assert!(!diff.exists());
+    }
+}
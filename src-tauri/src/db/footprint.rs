@@ -0,0 +1,81 @@
+//! Disk space used by the app, split by what it is used for.
+
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::{AppError, AppResult};
+
+use super::backup::{backup_root, dir_size};
+
+/// Bytes on disk for each area the app writes to. `total_bytes` is the sum of
+/// the other fields.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct StorageFootprint {
+    /// The SQLite file together with its `-wal` and `-shm` sidecars.
+    #[ts(type = "number")]
+    pub database_bytes: u64,
+    #[ts(type = "number")]
+    pub vault_bytes: u64,
+    #[ts(type = "number")]
+    pub backups_bytes: u64,
+    #[ts(type = "number")]
+    pub logs_bytes: u64,
+    #[ts(type = "number")]
+    pub total_bytes: u64,
+}
+
+/// Measure the database at `db_path`, the attachment vault, the backups kept
+/// next to the database and the log directory. Missing files and folders
+/// count as zero.
+#[allow(clippy::result_large_err)]
+pub fn storage_footprint(
+    db_path: &Path,
+    vault_root: &Path,
+    logs_dir: &Path,
+) -> AppResult<StorageFootprint> {
+    let mut database_bytes = 0_u64;
+    for suffix in ["", "-wal", "-shm"] {
+        let mut path = db_path.as_os_str().to_os_string();
+        path.push(suffix);
+        database_bytes = database_bytes.saturating_add(file_size(Path::new(&path))?);
+    }
+    let vault_bytes = existing_dir_size(vault_root)?;
+    let backups_bytes = existing_dir_size(&backup_root(db_path)?)?;
+    let logs_bytes = existing_dir_size(logs_dir)?;
+
+    Ok(StorageFootprint {
+        database_bytes,
+        vault_bytes,
+        backups_bytes,
+        logs_bytes,
+        total_bytes: database_bytes
+            .saturating_add(vault_bytes)
+            .saturating_add(backups_bytes)
+            .saturating_add(logs_bytes),
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn file_size(path: &Path) -> AppResult<u64> {
+    match fs::metadata(path) {
+        Ok(meta) => Ok(meta.len()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        Err(err) => Err(AppError::from(err)
+            .with_context("operation", "storage_footprint")
+            .with_context("path", path.display().to_string())),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn existing_dir_size(path: &Path) -> AppResult<u64> {
+    if path.is_dir() {
+        dir_size(path)
+    } else {
+        Ok(0)
+    }
+}
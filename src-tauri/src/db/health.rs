@@ -6,6 +6,7 @@ use std::time::Instant;
 use super::manifest;
 use anyhow::{Context, Result};
 use chrono::{SecondsFormat, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::sqlite::SqliteRow;
 use sqlx::{pool::PoolConnection, Row, Sqlite, SqlitePool};
@@ -17,7 +18,7 @@ const WAL_HEADER_MAGIC: &[u8; 4] = b"WAL\0";
 
 pub const STORAGE_SANITY_HEAL_NOTE: &str = "wal header healed after checkpoint";
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "../../src/bindings/")]
 #[serde(rename_all = "snake_case")]
 pub enum DbHealthStatus {
@@ -25,7 +26,7 @@ pub enum DbHealthStatus {
     Error,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct DbHealthCheck {
     pub name: String,
@@ -38,7 +39,7 @@ pub struct DbHealthCheck {
     pub details: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct DbHealthOffender {
     pub table: String,
@@ -47,7 +48,7 @@ pub struct DbHealthOffender {
     pub message: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct DbHealthReport {
     pub status: DbHealthStatus,
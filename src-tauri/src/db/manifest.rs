@@ -18,7 +18,25 @@ pub struct BackupManifest {
     #[ts(type = "number")]
     pub db_size_bytes: u64,
     pub created_at: String,
+    /// Digest of the full database, also for differential backups where it
+    /// is the digest of the rebuilt file.
     pub sha256: String,
+    #[serde(default)]
+    pub kind: BackupKind,
+    /// Directory name of the full backup a differential one applies onto.
+    #[serde(default)]
+    pub base_directory: Option<String>,
+    #[serde(default)]
+    pub base_sha256: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+#[serde(rename_all = "snake_case")]
+pub enum BackupKind {
+    #[default]
+    Full,
+    Differential,
 }
 
 impl BackupManifest {
@@ -34,8 +52,23 @@ impl BackupManifest {
             db_size_bytes,
             created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
             sha256: sha256.into(),
+            kind: BackupKind::Full,
+            base_directory: None,
+            base_sha256: None,
         }
     }
+
+    /// Mark the manifest as a differential backup on top of `base_directory`.
+    pub fn with_base(
+        mut self,
+        base_directory: impl Into<String>,
+        base_sha256: impl Into<String>,
+    ) -> Self {
+        self.kind = BackupKind::Differential;
+        self.base_directory = Some(base_directory.into());
+        self.base_sha256 = Some(base_sha256.into());
+        self
+    }
 }
 
 pub async fn schema_hash(pool: &SqlitePool) -> Result<String> {
@@ -114,6 +147,217 @@ pub fn normalize_schema_version_owned(version: String) -> String {
     }
 }
 
+/// Schema fingerprint plus the migrations recorded in `schema_migrations`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct SchemaInfo {
+    pub schema_hash: String,
+    /// Applied migration versions in ascending order.
+    pub applied_migrations: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub latest_version: Option<String>,
+}
+
+pub async fn schema_info(pool: &SqlitePool) -> Result<SchemaInfo> {
+    let schema_hash = schema_hash(pool).await?;
+    let has_table: Option<i64> = sqlx::query_scalar(
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'schema_migrations'",
+    )
+    .fetch_optional(pool)
+    .await
+    .context("check schema_migrations table")?;
+    let applied_migrations: Vec<String> = if has_table.is_some() {
+        sqlx::query_scalar("SELECT version FROM schema_migrations ORDER BY version ASC")
+            .fetch_all(pool)
+            .await
+            .context("list applied migrations")?
+    } else {
+        Vec::new()
+    };
+    let latest_version = applied_migrations.last().cloned();
+    Ok(SchemaInfo {
+        schema_hash,
+        applied_migrations,
+        latest_version,
+    })
+}
+
+/// DDL for every table, index and trigger in `sqlite_master`, excluding
+/// SQLite's internal `sqlite_*` objects and implicit autoindexes. Statements
+/// are ordered by type (tables, then indexes, then triggers) and name so the
+/// output is stable across runs.
+pub async fn schema_dump(pool: &SqlitePool) -> Result<String> {
+    let statements: Vec<String> = sqlx::query_scalar(
+        "SELECT sql FROM sqlite_master\n         WHERE type IN ('table','index','trigger')\n           AND name NOT LIKE 'sqlite_%'\n           AND sql IS NOT NULL\n  ORDER BY CASE type WHEN 'table' THEN 0 WHEN 'index' THEN 1 ELSE 2 END, name",
+    )
+    .fetch_all(pool)
+    .await
+    .context("read schema from sqlite_master")?;
+
+    let mut dump = String::new();
+    for statement in statements {
+        dump.push_str(statement.trim_end());
+        dump.push_str(";\n");
+    }
+    Ok(dump)
+}
+
+/// One index from `sqlite_master` with its shape, on-disk size and a
+/// best-effort redundancy hint for pruning.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct IndexStat {
+    pub name: String,
+    pub table: String,
+    pub columns: Vec<String>,
+    pub unique: bool,
+    pub partial: bool,
+    /// Bytes used by the index b-tree. `None` when the `dbstat` virtual table
+    /// is not compiled into SQLite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional, type = "number")]
+    pub size_bytes: Option<u64>,
+    /// Another index on the same table whose leading columns already cover
+    /// this one, making it a pruning candidate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub covered_by: Option<String>,
+}
+
+/// Placeholder column name for expression index terms.
+const EXPRESSION_COLUMN: &str = "<expr>";
+
+async fn index_size_bytes(pool: &SqlitePool, name: &str) -> Option<u64> {
+    sqlx::query_scalar::<_, i64>("SELECT COALESCE(SUM(pgsize), 0) FROM dbstat WHERE name = ?1")
+        .bind(name)
+        .fetch_one(pool)
+        .await
+        .ok()
+        .map(|size| size.max(0) as u64)
+}
+
+/// List every named index with its table, columns and size. SQLite keeps no
+/// record of which indexes queries actually use, so instead of usage this
+/// flags non-unique, non-partial indexes whose columns are a leading prefix of
+/// another full index on the same table.
+pub async fn index_stats(pool: &SqlitePool) -> Result<Vec<IndexStat>> {
+    let rows = sqlx::query(
+        "SELECT name, tbl_name, sql FROM sqlite_master\n         WHERE type = 'index' AND name NOT LIKE 'sqlite_%'\n  ORDER BY tbl_name, name",
+    )
+    .fetch_all(pool)
+    .await
+    .context("list indexes from sqlite_master")?;
+
+    let mut stats = Vec::with_capacity(rows.len());
+    for row in rows {
+        let name: String = row.try_get("name")?;
+        let table: String = row.try_get("tbl_name")?;
+        let sql: Option<String> = row.try_get("sql")?;
+
+        let columns: Vec<String> = sqlx::query_scalar(
+            "SELECT COALESCE(name, ?2) FROM pragma_index_info(?1) ORDER BY seqno",
+        )
+        .bind(&name)
+        .bind(EXPRESSION_COLUMN)
+        .fetch_all(pool)
+        .await
+        .with_context(|| format!("read columns for index {name}"))?;
+        let unique = sql
+            .as_deref()
+            .is_some_and(|sql| sql.to_ascii_uppercase().starts_with("CREATE UNIQUE"));
+        let partial = sql
+            .as_deref()
+            .is_some_and(|sql| sql.to_ascii_uppercase().contains(" WHERE "));
+        let size_bytes = index_size_bytes(pool, &name).await;
+
+        stats.push(IndexStat {
+            name,
+            table,
+            columns,
+            unique,
+            partial,
+            size_bytes,
+            covered_by: None,
+        });
+    }
+
+    for i in 0..stats.len() {
+        let candidate = &stats[i];
+        if candidate.unique
+            || candidate.partial
+            || candidate.columns.iter().any(|col| col == EXPRESSION_COLUMN)
+        {
+            continue;
+        }
+        let covered_by = stats.iter().enumerate().find_map(|(j, other)| {
+            let covers = j != i
+                && other.table == candidate.table
+                && !other.partial
+                && other.columns.len() >= candidate.columns.len()
+                && other.columns[..candidate.columns.len()] == candidate.columns[..]
+                // Identical pairs flag only the later name so one survives.
+                && (other.columns.len() > candidate.columns.len()
+                    || other.unique
+                    || other.name < candidate.name);
+            covers.then(|| other.name.clone())
+        });
+        stats[i].covered_by = covered_by;
+    }
+
+    Ok(stats)
+}
+
+/// Where the live database sits on disk, for support requests.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct DbPathInfo {
+    pub path: String,
+    /// `path` with the home directory replaced by `~`, safe for screenshots.
+    pub display_path: String,
+    #[ts(type = "number")]
+    pub size_bytes: u64,
+    #[ts(type = "number")]
+    pub wal_bytes: u64,
+    #[ts(type = "number")]
+    pub shm_bytes: u64,
+    pub writable: bool,
+}
+
+fn sidecar_len(db_path: &Path, suffix: &str) -> u64 {
+    let mut name = db_path.as_os_str().to_os_string();
+    name.push(suffix);
+    std::fs::metadata(Path::new(&name))
+        .map(|meta| meta.len())
+        .unwrap_or(0)
+}
+
+/// Size and writability of the database file and its WAL/SHM sidecars.
+/// Missing files report zero bytes rather than failing.
+pub fn path_info(db_path: &Path, home: Option<&Path>) -> DbPathInfo {
+    let meta = std::fs::metadata(db_path).ok();
+    let display_path = match home {
+        Some(home) if !home.as_os_str().is_empty() => match db_path.strip_prefix(home) {
+            Ok(rest) => Path::new("~").join(rest).display().to_string(),
+            Err(_) => db_path.display().to_string(),
+        },
+        _ => db_path.display().to_string(),
+    };
+    DbPathInfo {
+        path: db_path.display().to_string(),
+        display_path,
+        size_bytes: meta.as_ref().map(|meta| meta.len()).unwrap_or(0),
+        wal_bytes: sidecar_len(db_path, "-wal"),
+        shm_bytes: sidecar_len(db_path, "-shm"),
+        writable: meta
+            .as_ref()
+            .is_some_and(|meta| !meta.permissions().readonly()),
+    }
+}
+
 pub fn read_manifest(path: &Path) -> Result<BackupManifest> {
     let mut file =
         File::open(path).with_context(|| format!("open manifest file: {}", path.display()))?;
@@ -166,6 +410,17 @@ mod tests {
         assert_eq!(loaded.sha256, manifest.sha256);
     }
 
+    #[tokio::test]
+    async fn schema_info_without_migrations_table_is_empty() {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("connect in-memory");
+        let info = schema_info(&pool).await.unwrap();
+        assert!(info.applied_migrations.is_empty());
+        assert!(info.latest_version.is_none());
+        assert_eq!(info.schema_hash, schema_hash(&pool).await.unwrap());
+    }
+
     #[test]
     fn schema_version_normalization_strips_suffix() {
         let canonical = normalize_schema_version("20230101_add_table.up.sql");
@@ -116,7 +116,7 @@ pub struct DbRepairOptions {
     pub after_swap: Option<RepairHealthCallback>,
 }
 
-const REPAIR_STEPS: [DbRepairStep; 5] = [
+pub const REPAIR_STEPS: [DbRepairStep; 5] = [
     DbRepairStep::Backup,
     DbRepairStep::Checkpoint,
     DbRepairStep::Rebuild,
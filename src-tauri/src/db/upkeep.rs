@@ -0,0 +1,158 @@
+//! Periodic database upkeep.
+//!
+//! Without this, problems are only found when a user asks for a health
+//! check. The scheduler spawned in `run()` wakes on an interval and, when the
+//! app is idle, runs a passive WAL checkpoint and `PRAGMA optimize` followed by
+//! the health checks and refreshes the cached report. Ticks are skipped while maintenance mode is
+//! active or a household cascade is unfinished, since both own the cache.
+
+use std::time::{Duration, Instant};
+
+use sqlx::SqlitePool;
+
+use crate::{
+    db::health::{run_health_checks, DbHealthReport},
+    files_indexer::IndexerState,
+    state::AppState,
+    AppError, AppResult,
+};
+
+/// Event emitted with the fresh [`DbHealthReport`] after each completed tick.
+pub const DB_HEALTH_UPDATED_EVENT: &str = "db_health:updated";
+/// Overrides the interval between ticks, in seconds. `0` disables upkeep.
+pub const UPKEEP_INTERVAL_ENV: &str = "ARK_DB_UPKEEP_INTERVAL_SECS";
+/// Interval used by the app when [`UPKEEP_INTERVAL_ENV`] is unset.
+pub const DEFAULT_UPKEEP_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Scheduler configuration. The default is disabled so tests never start a
+/// background loop unless they ask for one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpkeepConfig {
+    pub interval: Duration,
+}
+
+impl UpkeepConfig {
+    /// Read the interval from [`UPKEEP_INTERVAL_ENV`], falling back to
+    /// [`DEFAULT_UPKEEP_INTERVAL`] when unset or unparsable.
+    pub fn from_env() -> Self {
+        let interval = std::env::var(UPKEEP_INTERVAL_ENV)
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_UPKEEP_INTERVAL);
+        Self { interval }
+    }
+
+    pub fn enabled(&self) -> bool {
+        !self.interval.is_zero()
+    }
+}
+
+/// Result of a single upkeep tick.
+#[derive(Debug, Clone)]
+pub enum UpkeepOutcome {
+    /// Checks ran and the cached report was replaced.
+    Refreshed(DbHealthReport),
+    SkippedMaintenance,
+    SkippedCascade,
+    SkippedBusy,
+}
+
+fn cascade_in_cache(state: &AppState) -> bool {
+    state
+        .db_health
+        .lock()
+        .map(|report| {
+            report
+                .checks
+                .iter()
+                .any(|check| check.name == crate::CASCADE_HEALTH_CHECK)
+        })
+        .unwrap_or(true)
+}
+
+fn indexer_busy(state: &AppState) -> bool {
+    let active = state
+        .active_household_id
+        .lock()
+        .map(|id| id.clone())
+        .unwrap_or_default();
+    !active.is_empty() && state.files_indexer().current_state(&active) != IndexerState::Idle
+}
+
+/// Run `ANALYZE` so the query planner has current table and index statistics.
+/// Returns how long it took.
+pub async fn analyze_database(pool: &SqlitePool) -> AppResult<Duration> {
+    let started = Instant::now();
+    sqlx::query("ANALYZE;")
+        .execute(pool)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "db_analyze"))?;
+    Ok(started.elapsed())
+}
+
+/// Run one upkeep pass against `state`, updating `db_health` when it completes.
+pub async fn run_upkeep_tick(state: &AppState) -> AppResult<UpkeepOutcome> {
+    if state.maintenance_active() {
+        return Ok(UpkeepOutcome::SkippedMaintenance);
+    }
+    if cascade_in_cache(state) {
+        return Ok(UpkeepOutcome::SkippedCascade);
+    }
+    if indexer_busy(state) {
+        return Ok(UpkeepOutcome::SkippedBusy);
+    }
+
+    let pool = state.pool_clone();
+    let pending = crate::household::pending_cascades(&pool)
+        .await
+        .map_err(crate::map_household_crud_error)?;
+    if !pending.is_empty() {
+        return Ok(UpkeepOutcome::SkippedCascade);
+    }
+
+    if let Err(err) = sqlx::query_as::<_, (i64, i64, i64)>("PRAGMA wal_checkpoint(PASSIVE);")
+        .fetch_one(&pool)
+        .await
+    {
+        tracing::warn!(
+            target: "arklowdun",
+            event = "db_upkeep_checkpoint_failed",
+            error = %err,
+        );
+    }
+
+    // Only re-analyzes tables whose statistics SQLite considers stale.
+    if let Err(err) = sqlx::query("PRAGMA optimize;").execute(&pool).await {
+        tracing::warn!(
+            target: "arklowdun",
+            event = "db_upkeep_optimize_failed",
+            error = %err,
+        );
+    }
+
+    let report = run_health_checks(&pool, &state.db_path)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "db_upkeep"))?;
+
+    // Maintenance or a cascade may have started while the checks ran; their
+    // owners write the cache, so leave it alone.
+    if state.maintenance_active() {
+        return Ok(UpkeepOutcome::SkippedMaintenance);
+    }
+    let mut guard = state.db_health.lock().map_err(|_| {
+        AppError::new(
+            "STATE/LOCK_POISONED",
+            "Failed to update database health cache",
+        )
+    })?;
+    if guard
+        .checks
+        .iter()
+        .any(|check| check.name == crate::CASCADE_HEALTH_CHECK)
+    {
+        return Ok(UpkeepOutcome::SkippedCascade);
+    }
+    *guard = report.clone();
+    Ok(UpkeepOutcome::Refreshed(report))
+}
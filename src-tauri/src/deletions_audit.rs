@@ -0,0 +1,104 @@
+//! Append-only log of soft-deletes.
+//!
+//! Every soft-delete issued through the domain commands writes a row into
+//! `deletions_audit` inside the same transaction as the `deleted_at` update,
+//! so a row can only disappear from the UI together with its audit entry.
+//! The log is never updated or pruned by the app and is left out of exports
+//! unless explicitly requested.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Executor, Sqlite, SqlitePool};
+use tauri::State;
+use ts_rs::TS;
+
+use crate::{repo, state::AppState, util::dispatch_async_app_result, AppError, AppResult};
+
+/// Operation recorded for a `deleted_at` soft-delete.
+pub const OPERATION_SOFT_DELETE: &str = "soft_delete";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, sqlx::FromRow)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct DeletionAuditEntry {
+    #[ts(type = "number")]
+    pub id: i64,
+    pub household_id: String,
+    pub table_name: String,
+    pub row_id: String,
+    pub operation: String,
+    #[ts(type = "number")]
+    pub deleted_at: i64,
+}
+
+/// Append an audit row. Callers pass their open transaction so the entry is
+/// committed or rolled back together with the delete itself.
+pub async fn record_deletion<'e, E>(
+    executor: E,
+    table: &str,
+    household_id: &str,
+    row_id: &str,
+    operation: &str,
+    deleted_at: i64,
+) -> sqlx::Result<()>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    sqlx::query(
+        "INSERT INTO deletions_audit (household_id, table_name, row_id, operation, deleted_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )
+    .bind(household_id)
+    .bind(table)
+    .bind(row_id)
+    .bind(operation)
+    .bind(deleted_at)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Audit entries for a household, oldest first. `since` restricts the result
+/// to deletions at or after the given timestamp (ms since epoch).
+pub async fn list_deletions(
+    pool: &SqlitePool,
+    household_id: &str,
+    since: Option<i64>,
+) -> AppResult<Vec<DeletionAuditEntry>> {
+    sqlx::query_as::<_, DeletionAuditEntry>(
+        "SELECT id, household_id, table_name, row_id, operation, deleted_at
+           FROM deletions_audit
+          WHERE household_id = ?1
+            AND (?2 IS NULL OR deleted_at >= ?2)
+          ORDER BY deleted_at, id",
+    )
+    .bind(household_id)
+    .bind(since)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "deletions_audit_list")
+            .with_context("household_id", household_id.to_string())
+    })
+}
+
+#[tauri::command]
+pub async fn deletions_audit_list(
+    state: State<'_, AppState>,
+    household_id: String,
+    since: Option<i64>,
+) -> AppResult<Vec<DeletionAuditEntry>> {
+    let pool = state.pool_clone();
+
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "deletions_audit_list")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            list_deletions(&pool, &household_id, since).await
+        }
+    })
+    .await
+}
@@ -1,11 +1,21 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sqlx::{Error as SqlxError, SqlitePool};
-use std::{collections::BTreeMap, env, fs, path::PathBuf, time::Instant};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Instant,
+};
 use tracing::{info, warn};
+use zip::{result::ZipError, write::FileOptions, CompressionMethod, ZipWriter};
 
 use crate::{
-    git_commit_hash, log_dropped_count, log_io_error_detected, resolve_logs_dir, AppError,
-    AppResult, LOG_FILE_NAME,
+    attachment_category::AttachmentCategory, db::health::DbHealthReport, flush_file_logs,
+    git_commit_hash, log_dropped_count, log_io_error_detected, resolve_logs_dir, time::now_ms,
+    vault::Vault, AppError, AppResult, LOG_FILE_NAME,
 };
 use tauri::Manager;
 
@@ -118,6 +128,16 @@ household_count_specs! {
     "note_links" => "noteLinks" => false,
 }
 
+/// Directory holding `arklowdun.log` and its rotations, honouring the
+/// `ARK_FAKE_APPDATA` override.
+#[allow(clippy::result_large_err)]
+pub fn logs_dir<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> AppResult<PathBuf> {
+    resolve_logs_dir(app).map_err(|err| {
+        AppError::new("DIAGNOSTICS/LOGS_DIR", "Failed to locate log directory")
+            .with_context("error", err.to_string())
+    })
+}
+
 #[allow(clippy::result_large_err)]
 pub fn gather_summary<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> AppResult<Summary> {
     let platform = env::consts::OS.to_string();
@@ -137,10 +157,7 @@ pub fn gather_summary<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> AppResult
             })
         });
 
-    let logs_dir = resolve_logs_dir(app).map_err(|err| {
-        AppError::new("DIAGNOSTICS/LOGS_DIR", "Failed to locate log directory")
-            .with_context("error", err.to_string())
-    })?;
+    let logs_dir = logs_dir(app)?;
 
     let log_path = logs_dir.join(LOG_FILE_NAME);
     let log_path_str = log_path.display().to_string();
@@ -447,6 +464,274 @@ async fn collect_family_diagnostics(
     })
 }
 
+/// Cached household statistics as stored in the `household_stats` table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedHouseholdStats {
+    pub household_id: String,
+    pub counts: BTreeMap<String, u64>,
+    /// Bytes of the vault files referenced by live attachment rows.
+    pub attachment_bytes: u64,
+    pub computed_at: i64,
+}
+
+/// Attachment tables that carry a `category` column alongside
+/// `relative_path`.
+const STATS_ATTACHMENT_TABLES: &[&str] = &[
+    "bills",
+    "policies",
+    "property_documents",
+    "inventory_items",
+    "pet_medical",
+    "vehicle_maintenance",
+];
+
+async fn attachment_bytes_by_household(
+    pool: &SqlitePool,
+    vault: &Vault,
+) -> AppResult<BTreeMap<String, u64>> {
+    let mut totals = BTreeMap::new();
+    for table in STATS_ATTACHMENT_TABLES {
+        let Some(category) = AttachmentCategory::for_table(table) else {
+            continue;
+        };
+        let sql = format!(
+            "SELECT household_id, relative_path FROM {table} \
+             WHERE deleted_at IS NULL AND relative_path IS NOT NULL"
+        );
+        let rows = sqlx::query_as::<_, (String, String)>(&sql)
+            .fetch_all(pool)
+            .await
+            .map_err(|err| AppError::from(err).with_context("table", table.to_string()))?;
+        for (household_id, relative_path) in rows {
+            let Ok(path) = vault.resolve(&household_id, category, &relative_path) else {
+                continue;
+            };
+            let size = fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+            let total = totals.entry(household_id).or_insert(0u64);
+            *total = total.saturating_add(size);
+        }
+    }
+    Ok(totals)
+}
+
+/// Recompute [`household_stats`] and attachment byte totals for every
+/// household and replace the `household_stats` cache with the result.
+pub async fn refresh_household_stats(
+    pool: &SqlitePool,
+    vault: &Vault,
+) -> AppResult<Vec<CachedHouseholdStats>> {
+    let stats = household_stats(pool).await?;
+    let mut bytes = attachment_bytes_by_household(pool, vault).await?;
+    let computed_at = now_ms();
+
+    let cached: Vec<CachedHouseholdStats> = stats
+        .into_iter()
+        .map(|entry| CachedHouseholdStats {
+            attachment_bytes: bytes.remove(&entry.id).unwrap_or(0),
+            household_id: entry.id,
+            counts: entry.counts,
+            computed_at,
+        })
+        .collect();
+
+    let mut tx = pool.begin().await.map_err(AppError::from)?;
+    sqlx::query("DELETE FROM household_stats")
+        .execute(&mut *tx)
+        .await
+        .map_err(AppError::from)?;
+    for entry in &cached {
+        let counts_json = serde_json::to_string(&entry.counts).map_err(|err| {
+            AppError::new(
+                "DIAGNOSTICS/STATS_ENCODE",
+                "Failed to encode household stats",
+            )
+            .with_context("error", err.to_string())
+        })?;
+        sqlx::query(
+            "INSERT INTO household_stats (household_id, counts_json, attachment_bytes, computed_at) \
+             VALUES (?1, ?2, ?3, ?4)",
+        )
+        .bind(&entry.household_id)
+        .bind(counts_json)
+        .bind(entry.attachment_bytes.min(i64::MAX as u64) as i64)
+        .bind(entry.computed_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            AppError::from(err).with_context("household_id", entry.household_id.clone())
+        })?;
+    }
+    tx.commit().await.map_err(AppError::from)?;
+
+    info!(
+        target: "arklowdun",
+        event = "household_stats_refreshed",
+        households = cached.len()
+    );
+    Ok(cached)
+}
+
+/// Read the `household_stats` cache without recomputing anything. Households
+/// that have never been refreshed are absent.
+pub async fn cached_household_stats(pool: &SqlitePool) -> AppResult<Vec<CachedHouseholdStats>> {
+    let rows = sqlx::query_as::<_, (String, String, i64, i64)>(
+        "SELECT household_id, counts_json, attachment_bytes, computed_at \
+         FROM household_stats ORDER BY household_id",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)?;
+
+    let mut cached = Vec::with_capacity(rows.len());
+    for (household_id, counts_json, attachment_bytes, computed_at) in rows {
+        let counts = serde_json::from_str(&counts_json).map_err(|err| {
+            AppError::new(
+                "DIAGNOSTICS/STATS_DECODE",
+                "Cached household stats are unreadable",
+            )
+            .with_context("household_id", household_id.clone())
+            .with_context("error", err.to_string())
+        })?;
+        cached.push(CachedHouseholdStats {
+            household_id,
+            counts,
+            attachment_bytes: attachment_bytes.max(0) as u64,
+            computed_at,
+        });
+    }
+    Ok(cached)
+}
+
+pub const BUNDLE_SUMMARY_ENTRY: &str = "summary.json";
+pub const BUNDLE_HEALTH_ENTRY: &str = "health.json";
+pub const BUNDLE_ABOUT_ENTRY: &str = "about.json";
+pub const BUNDLE_LOGS_DIR: &str = "logs";
+
+static EMAIL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").expect("email pattern")
+});
+static IPV4_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(?:\d{1,3}\.){3}\d{1,3}\b").expect("ipv4 pattern"));
+static SECRET_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)("?\b(?:api_key|token|password|secret)\b"?\s*[=:]\s*)"?[^\s",}]+"?"#)
+        .expect("secret pattern")
+});
+
+/// Apply the same redaction as `scripts/collect-diagnostics.*`: the home
+/// directory becomes `~`, and emails, IPv4 addresses and secret-like values
+/// are masked.
+pub fn redact_text(text: &str, home: Option<&Path>) -> String {
+    let mut out = match home.map(|dir| dir.to_string_lossy()) {
+        Some(home) if !home.is_empty() => text.replace(home.as_ref(), "~"),
+        _ => text.to_string(),
+    };
+    out = EMAIL_RE.replace_all(&out, "<redacted:email>").into_owned();
+    out = IPV4_RE.replace_all(&out, "<redacted:ip>").into_owned();
+    SECRET_RE
+        .replace_all(&out, "${1}\"<redacted:secret>\"")
+        .into_owned()
+}
+
+fn map_bundle_zip_error(err: ZipError, operation: &'static str) -> AppError {
+    AppError::new("DIAGNOSTICS/ZIP", "Failed to write diagnostics bundle.")
+        .with_context("operation", operation)
+        .with_context("error", err.to_string())
+}
+
+/// Collect the log files, summary, health report and about info into a
+/// redacted zip in `out_dir` and return its path. Only files in the log
+/// directory are read, so attachment contents never end up in the bundle.
+#[allow(clippy::result_large_err)]
+pub fn export_bundle<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    out_dir: &Path,
+    health: &DbHealthReport,
+) -> AppResult<PathBuf> {
+    flush_file_logs();
+    let summary = gather_summary(app)?;
+    let about = about_info(app);
+    let logs_dir = logs_dir(app)?;
+    let home = dirs::home_dir();
+    let redact = |text: &str| redact_text(text, home.as_deref());
+
+    fs::create_dir_all(out_dir).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "diagnostics_bundle_out_dir")
+            .with_context("path", out_dir.display().to_string())
+    })?;
+    let file_name = format!(
+        "arklowdun-diagnostics-{}.zip",
+        chrono::Utc::now().format("%Y%m%dT%H%M%SZ")
+    );
+    let zip_path = out_dir.join(file_name);
+    let file = fs::File::create(&zip_path).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "diagnostics_bundle_create")
+            .with_context("path", zip_path.display().to_string())
+    })?;
+
+    let mut writer = ZipWriter::new(file);
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    let json_entries = [
+        (BUNDLE_SUMMARY_ENTRY, serde_json::to_string_pretty(&summary)),
+        (BUNDLE_HEALTH_ENTRY, serde_json::to_string_pretty(health)),
+        (BUNDLE_ABOUT_ENTRY, serde_json::to_string_pretty(&about)),
+    ];
+    for (name, json) in json_entries {
+        let json = json.map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "diagnostics_bundle_serialize")
+                .with_context("entry", name)
+        })?;
+        writer
+            .start_file(name, options)
+            .map_err(|err| map_bundle_zip_error(err, "diagnostics_bundle_entry"))?;
+        writer.write_all(redact(&json).as_bytes()).map_err(|err| {
+            AppError::from(err).with_context("operation", "diagnostics_bundle_write")
+        })?;
+    }
+
+    let mut log_files: Vec<PathBuf> = fs::read_dir(&logs_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(LOG_FILE_NAME))
+        })
+        .collect();
+    log_files.sort();
+    for path in log_files {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let bytes = fs::read(&path).map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "diagnostics_bundle_read_log")
+                .with_context("path", path.display().to_string())
+        })?;
+        writer
+            .start_file(format!("{BUNDLE_LOGS_DIR}/{name}"), options)
+            .map_err(|err| map_bundle_zip_error(err, "diagnostics_bundle_entry"))?;
+        writer
+            .write_all(redact(&String::from_utf8_lossy(&bytes)).as_bytes())
+            .map_err(|err| {
+                AppError::from(err).with_context("operation", "diagnostics_bundle_write")
+            })?;
+    }
+
+    writer
+        .finish()
+        .map_err(|err| map_bundle_zip_error(err, "diagnostics_bundle_finish"))?;
+    Ok(zip_path)
+}
+
 #[allow(clippy::result_large_err)]
 pub fn resolve_doc_path<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> AppResult<String> {
     use tauri::path::BaseDirectory;
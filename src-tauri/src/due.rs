@@ -0,0 +1,314 @@
+//! "Needs attention" queries that surface records whose due dates fall inside
+//! a horizon starting now. Soft-deleted rows and rows without a due date are
+//! never reported.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use ts_rs::TS;
+
+use crate::{commands, schedule, AppError, AppResult};
+
+/// Error code returned when a negative horizon is requested.
+pub const DUE_INVALID_HORIZON_CODE: &str = "DUE/INVALID_HORIZON";
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, TS, Debug)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum VehicleDueKind {
+    Mot,
+    Service,
+}
+
+#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct VehicleDueItem {
+    pub vehicle_id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub reg: Option<String>,
+    pub kind: VehicleDueKind,
+    #[ts(type = "number")]
+    pub due_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct PetMedicalDueItem {
+    pub medical_id: String,
+    pub pet_id: String,
+    pub pet_name: String,
+    pub description: String,
+    #[ts(type = "number")]
+    pub due_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct PolicyRenewalDueItem {
+    pub policy_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub provider: Option<String>,
+    #[ts(type = "number")]
+    pub due_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, TS, Debug)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum UpcomingKind {
+    Bill,
+    Event,
+    VehicleMot,
+    VehicleService,
+    PetMedical,
+}
+
+#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct UpcomingItem {
+    pub kind: UpcomingKind,
+    pub id: String,
+    pub title: String,
+    #[ts(type = "number")]
+    pub at: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct UpcomingSummary {
+    pub items: Vec<UpcomingItem>,
+    /// Set when event expansion hit its cap and later occurrences were dropped.
+    pub truncated: bool,
+}
+
+#[allow(clippy::result_large_err)]
+pub(crate) fn horizon_window(
+    operation: &str,
+    now_ms: i64,
+    horizon_ms: i64,
+) -> AppResult<(i64, i64)> {
+    if horizon_ms < 0 {
+        return Err(
+            AppError::new(DUE_INVALID_HORIZON_CODE, "Horizon must not be negative.")
+                .with_context("operation", operation.to_string())
+                .with_context("horizon_ms", horizon_ms.to_string()),
+        );
+    }
+    Ok((now_ms, now_ms.saturating_add(horizon_ms)))
+}
+
+/// Vehicles whose MOT or service falls due within `[now_ms, now_ms + horizon_ms]`.
+///
+/// A vehicle due for both is reported once per item. Due dates fall back to the
+/// legacy `mot_date`/`service_date` columns, matching the vehicle list view.
+pub async fn vehicles_due_soon(
+    pool: &SqlitePool,
+    household_id: &str,
+    now_ms: i64,
+    horizon_ms: i64,
+) -> AppResult<Vec<VehicleDueItem>> {
+    let (from, to) = horizon_window("vehicles_due_soon", now_ms, horizon_ms)?;
+    let rows = sqlx::query(
+        "SELECT id, name, reg, kind, due_at FROM (
+             SELECT id, name, reg, 'mot' AS kind,
+                    COALESCE(next_mot_due, mot_date) AS due_at
+               FROM vehicles
+              WHERE household_id = ?1 AND deleted_at IS NULL
+             UNION ALL
+             SELECT id, name, reg, 'service' AS kind,
+                    COALESCE(next_service_due, service_date) AS due_at
+               FROM vehicles
+              WHERE household_id = ?1 AND deleted_at IS NULL
+         )
+         WHERE due_at IS NOT NULL AND due_at >= ?2 AND due_at <= ?3
+         ORDER BY due_at ASC, name ASC, id ASC, kind ASC",
+    )
+    .bind(household_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "vehicles_due_soon")
+            .with_context("household_id", household_id.to_string())
+    })?;
+
+    rows.into_iter()
+        .map(|row| {
+            let kind: String = row.try_get("kind")?;
+            Ok(VehicleDueItem {
+                vehicle_id: row.try_get("id")?,
+                name: row.try_get("name")?,
+                reg: row.try_get("reg")?,
+                kind: if kind == "mot" {
+                    VehicleDueKind::Mot
+                } else {
+                    VehicleDueKind::Service
+                },
+                due_at: row.try_get("due_at")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(|err| AppError::from(err).with_context("operation", "vehicles_due_soon"))
+}
+
+/// Pet medical records falling due within `[now_ms, now_ms + horizon_ms]`.
+///
+/// The reminder timestamp takes precedence over the record date so booster
+/// reminders on historical vaccinations surface when they come around.
+pub async fn pet_medical_due(
+    pool: &SqlitePool,
+    household_id: &str,
+    now_ms: i64,
+    horizon_ms: i64,
+) -> AppResult<Vec<PetMedicalDueItem>> {
+    let (from, to) = horizon_window("pet_medical_due", now_ms, horizon_ms)?;
+    let rows = sqlx::query(
+        "SELECT pm.id AS medical_id, pm.pet_id, p.name AS pet_name, pm.description,
+                COALESCE(pm.reminder, pm.date) AS due_at
+           FROM pet_medical pm
+           JOIN pets p ON p.id = pm.pet_id
+          WHERE pm.household_id = ?1
+            AND pm.deleted_at IS NULL
+            AND p.deleted_at IS NULL
+            AND COALESCE(pm.reminder, pm.date) IS NOT NULL
+            AND COALESCE(pm.reminder, pm.date) >= ?2
+            AND COALESCE(pm.reminder, pm.date) <= ?3
+          ORDER BY due_at ASC, p.name ASC, pm.id ASC",
+    )
+    .bind(household_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "pet_medical_due")
+            .with_context("household_id", household_id.to_string())
+    })?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(PetMedicalDueItem {
+                medical_id: row.try_get("medical_id")?,
+                pet_id: row.try_get("pet_id")?,
+                pet_name: row.try_get("pet_name")?,
+                description: row.try_get("description")?,
+                due_at: row.try_get("due_at")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(|err| AppError::from(err).with_context("operation", "pet_medical_due"))
+}
+
+/// Policies whose renewal date falls within `[now_ms, now_ms + horizon_ms]`.
+///
+/// Policies recorded without a renewal date are skipped rather than falling
+/// back to the premium `due_date`, which says nothing about lapsing cover.
+pub async fn policies_renewal_due(
+    pool: &SqlitePool,
+    household_id: &str,
+    now_ms: i64,
+    horizon_ms: i64,
+) -> AppResult<Vec<PolicyRenewalDueItem>> {
+    let (from, to) = horizon_window("policies_renewal_due", now_ms, horizon_ms)?;
+    let rows = sqlx::query(
+        "SELECT id, name, provider, renewal_date
+           FROM policies
+          WHERE household_id = ?1
+            AND deleted_at IS NULL
+            AND renewal_date IS NOT NULL
+            AND renewal_date >= ?2
+            AND renewal_date <= ?3
+          ORDER BY renewal_date ASC, name ASC, id ASC",
+    )
+    .bind(household_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "policies_renewal_due")
+            .with_context("household_id", household_id.to_string())
+    })?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(PolicyRenewalDueItem {
+                policy_id: row.try_get("id")?,
+                name: row.try_get("name")?,
+                provider: row.try_get("provider")?,
+                due_at: row.try_get("renewal_date")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(|err| AppError::from(err).with_context("operation", "policies_renewal_due"))
+}
+
+/// Everything needing attention within the horizon, merged across domains and
+/// ordered by timestamp. Recurring events contribute one entry per occurrence.
+pub async fn upcoming_summary(
+    pool: &SqlitePool,
+    household_id: &str,
+    now_ms: i64,
+    horizon_ms: i64,
+) -> AppResult<UpcomingSummary> {
+    let (from, to) = horizon_window("upcoming_summary", now_ms, horizon_ms)?;
+    let mut items = Vec::new();
+
+    let bills = commands::bills_due_between(pool, household_id, from, to, None, None).await?;
+    for bill in bills.items {
+        items.push(UpcomingItem {
+            kind: UpcomingKind::Bill,
+            id: bill["id"].as_str().unwrap_or_default().to_string(),
+            title: commands::bill_label(&bill),
+            at: bill["due_date"].as_i64().unwrap_or(from),
+        });
+    }
+
+    let (events, truncated) =
+        schedule::expanded_occurrences(pool, household_id, from, to, None).await?;
+    for event in events {
+        if event.start_at_utc < from || event.start_at_utc > to {
+            continue;
+        }
+        items.push(UpcomingItem {
+            kind: UpcomingKind::Event,
+            id: event.id,
+            title: event.title,
+            at: event.start_at_utc,
+        });
+    }
+
+    for vehicle in vehicles_due_soon(pool, household_id, now_ms, horizon_ms).await? {
+        let (kind, label) = match vehicle.kind {
+            VehicleDueKind::Mot => (UpcomingKind::VehicleMot, "MOT"),
+            VehicleDueKind::Service => (UpcomingKind::VehicleService, "Service"),
+        };
+        items.push(UpcomingItem {
+            kind,
+            id: vehicle.vehicle_id,
+            title: format!("{label}: {}", vehicle.name),
+            at: vehicle.due_at,
+        });
+    }
+
+    for medical in pet_medical_due(pool, household_id, now_ms, horizon_ms).await? {
+        items.push(UpcomingItem {
+            kind: UpcomingKind::PetMedical,
+            id: medical.medical_id,
+            title: format!("{}: {}", medical.pet_name, medical.description),
+            at: medical.due_at,
+        });
+    }
+
+    items.sort_by(|a, b| a.at.cmp(&b.at).then_with(|| a.id.cmp(&b.id)));
+    Ok(UpcomingSummary { items, truncated })
+}
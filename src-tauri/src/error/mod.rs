@@ -12,6 +12,7 @@ use crate::{db::health::DbHealthReport, security::error_map::UiError};
 use anyhow::Error as AnyhowError;
 use once_cell::sync::OnceCell;
 use rusqlite::Error as RusqliteError;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Error as SerdeJsonError;
 use sqlx::Error as SqlxError;
@@ -71,7 +72,7 @@ fn panic_message(info: &PanicHookInfo) -> String {
 }
 
 /// A structured application error that can be serialized and surfaced to the UI.
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, TS, JsonSchema)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct AppError {
     /// Machine readable error code.
@@ -90,6 +91,7 @@ pub struct AppError {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[ts(optional)]
     #[ts(type = "string | undefined")]
+    #[schemars(with = "Option<String>")]
     pub crash_id: Option<CrashId>,
     /// Optional database health report associated with the error.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -125,6 +127,11 @@ impl AppError {
     pub const UNKNOWN_CODE: &'static str = "APP/UNKNOWN";
     /// Code used for errors created from free-form messages.
     pub const GENERIC_CODE: &'static str = "APP/GENERIC";
+    /// Code used when the database stayed locked or the pool had no free
+    /// connection for longer than the configured timeouts.
+    pub const DB_BUSY_CODE: &'static str = "DB_BUSY";
+    const DB_BUSY_MESSAGE: &'static str =
+        "The database is busy. Please wait a moment and try again.";
 
     /// Construct a new application error with the provided code and message.
     pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
@@ -305,17 +312,25 @@ impl AppError {
             SqlxError::ColumnNotFound(name) => {
                 AppError::new("SQLX/COLUMN_NOT_FOUND", format!("Column not found: {name}"))
             }
-            SqlxError::PoolTimedOut => AppError::new(
-                "SQLX/POOL_TIMEOUT",
-                "Timed out acquiring a database connection",
-            ),
+            SqlxError::PoolTimedOut => AppError::new(Self::DB_BUSY_CODE, Self::DB_BUSY_MESSAGE)
+                .with_context("cause", "pool_timeout"),
             SqlxError::PoolClosed => AppError::new("SQLX/POOL_CLOSED", "Database pool is closed"),
             SqlxError::Io(err) => {
                 return AppError::from_io_ref(err).with_context("source", "sqlx");
             }
             SqlxError::Database(db) => {
-                let code = db
-                    .code()
+                let sqlite_code = db.code();
+                // Primary result codes SQLITE_BUSY (5) and SQLITE_LOCKED (6),
+                // including their extended variants.
+                if let Some(raw) = sqlite_code.as_deref() {
+                    if matches!(raw.parse::<i32>().map(|code| code & 0xff), Ok(5 | 6)) {
+                        return AppError::new(Self::DB_BUSY_CODE, Self::DB_BUSY_MESSAGE)
+                            .with_context("cause", "sqlite_busy")
+                            .with_context("sqlite_code", raw.to_string())
+                            .with_error_source(error.source());
+                    }
+                }
+                let code = sqlite_code
                     .map(|code| format!("Sqlite/{code}"))
                     .unwrap_or_else(|| "SQLX/DATABASE".to_string());
                 match db.constraint() {
@@ -352,8 +367,12 @@ impl AppError {
             return AppError::from_serde_json_ref(json);
         }
 
-        let mut root = AppError::new(AppError::UNKNOWN_CODE, err.to_string());
-        if let Some(source) = err.source() {
+        let source = err.source();
+        let mut root = AppError::new(
+            AppError::UNKNOWN_CODE,
+            message_without_source(err.to_string(), source),
+        );
+        if let Some(source) = source {
             root.cause = Some(Box::new(AppError::from_std_error(source)));
         }
         root
@@ -379,6 +398,21 @@ impl AppError {
     }
 }
 
+/// Drop a trailing `": {source}"` from `message`. Wrappers such as
+/// `#[error("failed to copy attachment {path}: {source}")]` repeat their source
+/// in the text, which would otherwise show twice once the source becomes the
+/// nested cause.
+fn message_without_source(message: String, source: Option<&(dyn StdError + 'static)>) -> String {
+    let Some(source) = source else {
+        return message;
+    };
+    let suffix = format!(": {source}");
+    match message.strip_suffix(&suffix) {
+        Some(head) if !head.is_empty() => head.to_string(),
+        _ => message,
+    }
+}
+
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.context.is_empty() {
@@ -0,0 +1,245 @@
+//! Family members attending an event.
+//!
+//! Attendance is a plain many-to-many relation between `events` and
+//! `family_members` within one household. The attendee set is always replaced
+//! as a whole inside a transaction so readers never observe a partial update.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use tauri::State;
+use ts_rs::TS;
+
+use crate::{
+    ipc::guard, repo, state::AppState, time::now_ms, util::dispatch_async_app_result, AppError,
+    AppResult,
+};
+
+pub const EVENT_NOT_FOUND_CODE: &str = "EVENT_ATTENDEES/EVENT_NOT_FOUND";
+pub const MEMBER_NOT_FOUND_CODE: &str = "EVENT_ATTENDEES/MEMBER_NOT_FOUND";
+pub const HOUSEHOLD_MISMATCH_CODE: &str = "EVENT_ATTENDEES/HOUSEHOLD_MISMATCH";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, sqlx::FromRow)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct EventAttendee {
+    pub event_id: String,
+    pub member_id: String,
+    pub name: String,
+    #[ts(type = "number")]
+    pub created_at: i64,
+}
+
+async fn ensure_event_in_household(
+    tx: &mut Transaction<'_, Sqlite>,
+    household_id: &str,
+    event_id: &str,
+) -> AppResult<()> {
+    let owner: Option<String> =
+        sqlx::query_scalar("SELECT household_id FROM events WHERE id = ?1 AND deleted_at IS NULL")
+            .bind(event_id)
+            .fetch_optional(tx.as_mut())
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "event_attendees_event_lookup")
+                    .with_context("event_id", event_id.to_string())
+            })?;
+    match owner {
+        Some(owner) if owner == household_id => Ok(()),
+        Some(_) => Err(AppError::new(
+            HOUSEHOLD_MISMATCH_CODE,
+            "Event belongs to another household",
+        )
+        .with_context("event_id", event_id.to_string())
+        .with_context("household_id", household_id.to_string())),
+        None => Err(AppError::new(EVENT_NOT_FOUND_CODE, "Event not found")
+            .with_context("event_id", event_id.to_string())),
+    }
+}
+
+async fn ensure_member_in_household(
+    tx: &mut Transaction<'_, Sqlite>,
+    household_id: &str,
+    member_id: &str,
+) -> AppResult<()> {
+    let owner: Option<String> = sqlx::query_scalar(
+        "SELECT household_id FROM family_members WHERE id = ?1 AND deleted_at IS NULL",
+    )
+    .bind(member_id)
+    .fetch_optional(tx.as_mut())
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "event_attendees_member_lookup")
+            .with_context("member_id", member_id.to_string())
+    })?;
+    match owner {
+        Some(owner) if owner == household_id => Ok(()),
+        Some(_) => Err(AppError::new(
+            HOUSEHOLD_MISMATCH_CODE,
+            "Family member belongs to another household",
+        )
+        .with_context("member_id", member_id.to_string())
+        .with_context("household_id", household_id.to_string())),
+        None => Err(
+            AppError::new(MEMBER_NOT_FOUND_CODE, "Family member not found")
+                .with_context("member_id", member_id.to_string()),
+        ),
+    }
+}
+
+/// Attendees of an event, ordered by member name.
+pub async fn list_attendees(
+    pool: &SqlitePool,
+    household_id: &str,
+    event_id: &str,
+) -> AppResult<Vec<EventAttendee>> {
+    sqlx::query_as::<_, EventAttendee>(
+        "SELECT ea.event_id, ea.member_id, fm.name, ea.created_at
+           FROM event_attendees ea
+           JOIN family_members fm ON fm.id = ea.member_id
+          WHERE ea.household_id = ?1
+            AND ea.event_id = ?2
+            AND fm.deleted_at IS NULL
+          ORDER BY fm.name COLLATE NOCASE, ea.member_id",
+    )
+    .bind(household_id)
+    .bind(event_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "event_attendees_list")
+            .with_context("household_id", household_id.to_string())
+            .with_context("event_id", event_id.to_string())
+    })
+}
+
+/// Replace the attendee set of an event with `member_ids`.
+///
+/// Every member must be live and belong to the event's household; otherwise
+/// nothing is changed. Members that were already attending keep their
+/// original `created_at`.
+pub async fn set_attendees(
+    pool: &SqlitePool,
+    household_id: &str,
+    event_id: &str,
+    member_ids: &[String],
+) -> AppResult<Vec<EventAttendee>> {
+    let mut wanted: Vec<&str> = Vec::new();
+    let mut seen = HashSet::new();
+    for member_id in member_ids {
+        if seen.insert(member_id.as_str()) {
+            wanted.push(member_id.as_str());
+        }
+    }
+
+    let mut tx = pool.begin().await.map_err(|err| {
+        AppError::from(err).with_context("operation", "event_attendees_set_begin")
+    })?;
+    ensure_event_in_household(&mut tx, household_id, event_id).await?;
+    for member_id in &wanted {
+        ensure_member_in_household(&mut tx, household_id, member_id).await?;
+    }
+
+    let existing: Vec<String> =
+        sqlx::query_scalar("SELECT member_id FROM event_attendees WHERE event_id = ?1")
+            .bind(event_id)
+            .fetch_all(tx.as_mut())
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "event_attendees_set")
+                    .with_context("event_id", event_id.to_string())
+            })?;
+
+    for member_id in existing.iter().filter(|id| !seen.contains(id.as_str())) {
+        sqlx::query("DELETE FROM event_attendees WHERE event_id = ?1 AND member_id = ?2")
+            .bind(event_id)
+            .bind(member_id)
+            .execute(tx.as_mut())
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "event_attendees_set")
+                    .with_context("event_id", event_id.to_string())
+                    .with_context("member_id", member_id.clone())
+            })?;
+    }
+
+    let now = now_ms();
+    for member_id in &wanted {
+        sqlx::query(
+            "INSERT OR IGNORE INTO event_attendees (household_id, event_id, member_id, created_at)
+             VALUES (?1, ?2, ?3, ?4)",
+        )
+        .bind(household_id)
+        .bind(event_id)
+        .bind(member_id)
+        .bind(now)
+        .execute(tx.as_mut())
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "event_attendees_set")
+                .with_context("event_id", event_id.to_string())
+                .with_context("member_id", member_id.to_string())
+        })?;
+    }
+
+    tx.commit().await.map_err(|err| {
+        AppError::from(err).with_context("operation", "event_attendees_set_commit")
+    })?;
+
+    list_attendees(pool, household_id, event_id).await
+}
+
+#[tauri::command]
+pub async fn event_attendees_set(
+    state: State<'_, AppState>,
+    household_id: String,
+    event_id: String,
+    member_ids: Vec<String>,
+) -> AppResult<Vec<EventAttendee>> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let event_id = event_id.clone();
+        let member_ids = member_ids.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "event_attendees_set")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            set_attendees(&pool, &household_id, &event_id, &member_ids).await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn event_attendees_list(
+    state: State<'_, AppState>,
+    household_id: String,
+    event_id: String,
+) -> AppResult<Vec<EventAttendee>> {
+    let pool = state.pool_clone();
+
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let event_id = event_id.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "event_attendees_list")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            list_attendees(&pool, &household_id, &event_id).await
+        }
+    })
+    .await
+}
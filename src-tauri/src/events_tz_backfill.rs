@@ -410,7 +410,7 @@ async fn begin_tx_with_retry<'a>(
 /// instant. Ambiguous times during a DST fall-back choose the earlier
 /// occurrence; gaps choose the earliest valid instant after the gap.
 #[allow(clippy::result_large_err)]
-fn to_utc_ms(local_ms: i64, tz: Tz) -> AppResult<i64> {
+pub(crate) fn to_utc_ms(local_ms: i64, tz: Tz) -> AppResult<i64> {
     #[allow(deprecated)]
     let naive = NaiveDateTime::from_timestamp_millis(local_ms).ok_or_else(|| {
         AppError::new("TIME/INVALID_TIMESTAMP", "Invalid local timestamp")
@@ -0,0 +1,133 @@
+//! Manifest-level comparison of two export bundles.
+//!
+//! Only `manifest.json` and `attachments_manifest.txt` are read, so two
+//! bundles can be compared without importing either. Tables are compared by
+//! row count and the digest of their uncompressed JSONL; attachments by their
+//! manifest key (`household/category/relative_path`) and file digest.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::import::ImportBundle;
+use crate::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum TableDiffStatus {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct ExportTableDiff {
+    pub table: String,
+    pub status: TableDiffStatus,
+    #[ts(type = "number | null")]
+    pub count_a: Option<u64>,
+    #[ts(type = "number | null")]
+    pub count_b: Option<u64>,
+    pub sha256_a: Option<String>,
+    pub sha256_b: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct ExportBundleDiff {
+    /// Tables whose presence, row count or content digest differ.
+    pub tables: Vec<ExportTableDiff>,
+    /// Attachment keys present only in bundle B.
+    pub attachments_added: Vec<String>,
+    /// Attachment keys present only in bundle A.
+    pub attachments_removed: Vec<String>,
+    /// Attachment keys present in both bundles with different content.
+    pub attachments_changed: Vec<String>,
+}
+
+impl ExportBundleDiff {
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+            && self.attachments_added.is_empty()
+            && self.attachments_removed.is_empty()
+            && self.attachments_changed.is_empty()
+    }
+}
+
+/// Compare bundle `a` with bundle `b`; additions are relative to `a`.
+#[allow(clippy::result_large_err)]
+pub fn diff_bundles(a: &Path, b: &Path) -> AppResult<ExportBundleDiff> {
+    let a = load(a)?;
+    let b = load(b)?;
+
+    let tables_a = &a.manifest().tables;
+    let tables_b = &b.manifest().tables;
+    let names: BTreeSet<&String> = tables_a.keys().chain(tables_b.keys()).collect();
+    let mut tables = Vec::new();
+    for name in names {
+        let left = tables_a.get(name);
+        let right = tables_b.get(name);
+        let status = match (left, right) {
+            (None, Some(_)) => TableDiffStatus::Added,
+            (Some(_), None) => TableDiffStatus::Removed,
+            (Some(l), Some(r)) if l.count != r.count || l.sha256 != r.sha256 => {
+                TableDiffStatus::Changed
+            }
+            _ => continue,
+        };
+        tables.push(ExportTableDiff {
+            table: name.clone(),
+            status,
+            count_a: left.map(|info| info.count),
+            count_b: right.map(|info| info.count),
+            sha256_a: left.map(|info| info.sha256.clone()),
+            sha256_b: right.map(|info| info.sha256.clone()),
+        });
+    }
+
+    let attachments_a: BTreeMap<&str, &str> = a
+        .attachments()
+        .iter()
+        .map(|entry| (entry.relative_path.as_str(), entry.sha256.as_str()))
+        .collect();
+    let attachments_b: BTreeMap<&str, &str> = b
+        .attachments()
+        .iter()
+        .map(|entry| (entry.relative_path.as_str(), entry.sha256.as_str()))
+        .collect();
+
+    let mut diff = ExportBundleDiff {
+        tables,
+        ..Default::default()
+    };
+    for (key, sha) in &attachments_b {
+        match attachments_a.get(key) {
+            None => diff.attachments_added.push(key.to_string()),
+            Some(other) if !other.eq_ignore_ascii_case(sha) => {
+                diff.attachments_changed.push(key.to_string())
+            }
+            Some(_) => {}
+        }
+    }
+    diff.attachments_removed = attachments_a
+        .keys()
+        .filter(|key| !attachments_b.contains_key(*key))
+        .map(|key| key.to_string())
+        .collect();
+    Ok(diff)
+}
+
+#[allow(clippy::result_large_err)]
+fn load(root: &Path) -> AppResult<ImportBundle> {
+    ImportBundle::load(root).map_err(|err| {
+        AppError::from(anyhow::Error::new(err))
+            .with_context("operation", "export_diff_load_bundle")
+            .with_context("path", root.display().to_string())
+    })
+}
@@ -12,7 +12,34 @@ use ts_rs::TS;
 pub struct TableInfo {
     #[ts(type = "number")]
     pub count: u64,
+    /// SHA-256 of the uncompressed JSONL content.
     pub sha256: String,
+    /// Compression applied to the data file on disk, e.g. `gzip`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub compression: Option<String>,
+    /// SHA-256 of the compressed file when `compression` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub compressed_sha256: Option<String>,
+}
+
+/// Compression marker recorded for gzip-compressed data files.
+pub const GZIP_COMPRESSION: &str = "gzip";
+
+impl TableInfo {
+    pub fn is_gzip(&self) -> bool {
+        self.compression.as_deref() == Some(GZIP_COMPRESSION)
+    }
+
+    /// File name of the data file for `logical` inside the bundle `data/` directory.
+    pub fn data_file_name(&self, logical: &str) -> String {
+        if self.is_gzip() {
+            format!("{logical}.jsonl.gz")
+        } else {
+            format!("{logical}.jsonl")
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS, Default)]
@@ -26,6 +53,38 @@ pub struct AttachmentsInfo {
     pub sha256_manifest: String,
 }
 
+/// Domain tables chosen for an export or import, by logical name. An empty
+/// include list selects every table; exclusions always win.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct TableSelection {
+    #[serde(default)]
+    pub include_tables: Vec<String>,
+    #[serde(default)]
+    pub exclude_tables: Vec<String>,
+}
+
+impl TableSelection {
+    pub fn is_all(&self) -> bool {
+        self.include_tables.is_empty() && self.exclude_tables.is_empty()
+    }
+
+    pub fn includes(&self, logical: &str) -> bool {
+        (self.include_tables.is_empty() || self.include_tables.iter().any(|t| t == logical))
+            && !self.exclude_tables.iter().any(|t| t == logical)
+    }
+
+    /// First listed name that is not in `known`, if any.
+    pub fn unknown_table<'a>(&'a self, known: &[&str]) -> Option<&'a str> {
+        self.include_tables
+            .iter()
+            .chain(self.exclude_tables.iter())
+            .map(String::as_str)
+            .find(|name| !known.contains(name))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/bindings/")]
@@ -35,6 +94,15 @@ pub struct ExportManifest {
     pub created_at: String,
     pub tables: BTreeMap<String, TableInfo>,
     pub attachments: AttachmentsInfo,
+    /// Selection the bundle was exported with; absent when every table was.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub table_selection: Option<TableSelection>,
+    /// Household whose files an attachments-only bundle carries. Such bundles
+    /// have no `data/` directory and an empty `tables` map.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub attachments_only_household: Option<String>,
 }
 
 impl ExportManifest {
@@ -45,6 +113,8 @@ impl ExportManifest {
             created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
             tables: BTreeMap::new(),
             attachments: AttachmentsInfo::default(),
+            table_selection: None,
+            attachments_only_household: None,
         }
     }
 }
@@ -74,15 +144,39 @@ mod tests {
         let expected = format!("{:x}", sha2::Sha256::digest(b"abc123"));
         assert_eq!(hash, expected);
     }
+
+    #[test]
+    fn gzip_content_sha256_hashes_decompressed_bytes() {
+        let tmp = NamedTempFile::new().unwrap();
+        let mut encoder =
+            flate2::write::GzEncoder::new(tmp.reopen().unwrap(), flate2::Compression::default());
+        std::io::Write::write_all(&mut encoder, b"abc123").unwrap();
+        encoder.finish().unwrap();
+        let hash = gzip_content_sha256(tmp.path()).unwrap();
+        let expected = format!("{:x}", sha2::Sha256::digest(b"abc123"));
+        assert_eq!(hash, expected);
+    }
 }
 
 pub fn file_sha256(path: &Path) -> Result<String> {
-    let mut file =
+    let file =
         File::open(path).with_context(|| format!("open file for hashing: {}", path.display()))?;
+    reader_sha256(file)
+}
+
+/// Hash the decompressed content of a gzip file.
+pub fn gzip_content_sha256(path: &Path) -> Result<String> {
+    let file =
+        File::open(path).with_context(|| format!("open file for hashing: {}", path.display()))?;
+    reader_sha256(flate2::read::GzDecoder::new(file))
+        .with_context(|| format!("decompress {}", path.display()))
+}
+
+fn reader_sha256(mut reader: impl Read) -> Result<String> {
     let mut hasher = Sha256::new();
     let mut buf = [0_u8; 8192];
     loop {
-        let read = file.read(&mut buf)?;
+        let read = reader.read(&mut buf)?;
         if read == 0 {
             break;
         }
@@ -13,23 +13,71 @@ use sqlx::{Row, SqlitePool};
 use tokio::task;
 
 use crate::{
-    attachment_category::AttachmentCategory, db, db::manifest as db_manifest, repo,
-    security::hash_path, vault::Vault, AppError, AppResult,
+    attachment_category::AttachmentCategory,
+    db,
+    db::manifest as db_manifest,
+    ops::progress::{OperationProgress, ProgressObserver, ProgressStage},
+    ops::retry::retry_transient_io_async,
+    repo,
+    security::hash_path,
+    vault::Vault,
+    AppError, AppResult,
 };
 
-use self::manifest::{file_sha256, ExportManifest, TableInfo};
+use self::manifest::{file_sha256, ExportManifest, TableInfo, TableSelection, GZIP_COMPRESSION};
 use serde::Serialize;
 use tracing::warn;
 use ts_rs::TS;
 
+pub mod diff;
 pub mod family;
 pub mod manifest;
+pub mod roundtrip;
 
 const PARTIAL_SUFFIX: &str = ".partial";
 
+/// Tables dumped into `data/<logical>.jsonl`, as `(logical, table)` pairs.
+/// The attachment-bearing tables ride along so that the files copied into
+/// `attachments/` still have rows pointing at them after an import.
+pub(crate) const EXPORT_TABLES: [(&str, &str); 8] = [
+    ("households", "household"),
+    ("events", "events"),
+    ("notes", "notes"),
+    ("files", "files_index"),
+    ("bills", "bills"),
+    ("policies", "policies"),
+    ("property_documents", "property_documents"),
+    ("inventory_items", "inventory_items"),
+];
+
 #[derive(Debug, Clone)]
 pub struct ExportOptions {
     pub out_parent: PathBuf,
+    /// Gzip each data file into `*.jsonl.gz`.
+    pub compress: bool,
+    /// Also dump the append-only `deletions_audit` log into
+    /// `audit/deletions_audit.jsonl`. It is not part of the importable data set.
+    pub include_deletions_audit: bool,
+    /// Tables to dump. Attachment files of unselected tables are skipped too.
+    pub tables: TableSelection,
+}
+
+/// Logical names of the tables an export bundle can carry.
+pub fn export_table_names() -> Vec<&'static str> {
+    EXPORT_TABLES.iter().map(|(logical, _)| *logical).collect()
+}
+
+/// Reject a selection naming tables outside [`EXPORT_TABLES`].
+#[allow(clippy::result_large_err)]
+pub fn validate_table_selection(selection: &TableSelection) -> AppResult<()> {
+    match selection.unknown_table(&export_table_names()) {
+        Some(name) => Err(AppError::new(
+            "EXPORT/UNKNOWN_TABLE",
+            format!("Unknown table '{name}' in table selection."),
+        )
+        .with_context("table", name.to_string())),
+        None => Ok(()),
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -66,54 +114,29 @@ pub async fn create_export(
     pool: &SqlitePool,
     vault: Arc<Vault>,
     opts: ExportOptions,
+) -> AppResult<ExportEntry> {
+    create_export_with_progress(pool, vault, opts, None).await
+}
+
+/// [`create_export`], notifying `progress` after each table dump and each
+/// attachment copied into the bundle.
+pub async fn create_export_with_progress(
+    pool: &SqlitePool,
+    vault: Arc<Vault>,
+    opts: ExportOptions,
+    progress: Option<ProgressObserver>,
 ) -> AppResult<ExportEntry> {
     let out_parent = opts.out_parent;
+    let compress = opts.compress;
+    let include_deletions_audit = opts.include_deletions_audit;
+    let selection = opts.tables;
+    validate_table_selection(&selection)?;
     let schema_version = current_schema_version(pool)
         .await
         .map_err(|err| AppError::from(err).with_context("operation", "schema_version"))?;
 
     let app_version = env!("CARGO_PKG_VERSION").to_string();
-
-    // Preflight: ensure parent exists and enough space is available.
-    fs::create_dir_all(&out_parent).map_err(|err| {
-        AppError::from(err)
-            .with_context("operation", "create_out_parent")
-            .with_context("path", out_parent.display().to_string())
-    })?;
-
-    let preflight = task::spawn_blocking({
-        let vault = vault.clone();
-        move || estimate_export_size(&vault)
-    })
-    .await
-    .map_err(|err| {
-        AppError::new("EXPORT/TASK", "Size estimate task panicked")
-            .with_context("error", err.to_string())
-    })??;
-
-    let avail = free_disk_space(&out_parent)
-        .map_err(|err| AppError::from(err).with_context("operation", "available_space"))?;
-    if avail < preflight.required_bytes {
-        return Err(AppError::new(
-            "EXPORT/LOW_DISK",
-            format!(
-                "Not enough disk space (need ~{}).",
-                format_bytes(preflight.required_bytes)
-            ),
-        )
-        .with_context("available_bytes", avail.to_string())
-        .with_context("required_bytes", preflight.required_bytes.to_string()));
-    }
-
-    // Allocate unique directory
-    let timestamp = Utc::now();
-    let export_dir = unique_export_dir(&out_parent, &timestamp)
-        .map_err(|err| err.with_context("operation", "alloc_export_dir"))?;
-    fs::create_dir_all(&export_dir).map_err(|err| {
-        AppError::from(err)
-            .with_context("operation", "create_export_dir")
-            .with_context("path", export_dir.display().to_string())
-    })?;
+    let export_dir = prepare_export_dir(&out_parent, &vault).await?;
 
     // Layout
     let data_dir = export_dir.join("data");
@@ -123,45 +146,67 @@ pub async fn create_export(
 
     // Dump tables deterministically
     let mut manifest = ExportManifest::new(app_version, schema_version);
+    if !selection.is_all() {
+        manifest.table_selection = Some(selection.clone());
+    }
+
+    for (logical, table) in EXPORT_TABLES {
+        if !selection.includes(logical) {
+            continue;
+        }
+        let path = data_dir.join(format!("{logical}.jsonl"));
+        let info = dump_table_jsonl(pool, table, &path, compress)
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "dump_table")
+                    .with_context("table", table)
+            })?;
+        if let Some(observer) = &progress {
+            observer(OperationProgress {
+                stage: ProgressStage::Table(logical.to_string()),
+                done: info.count,
+                total: info.count,
+                bytes: 0,
+            });
+        }
+        manifest.tables.insert(logical.to_string(), info);
+    }
 
-    let mut table_sha: BTreeMap<&'static str, (u64, String)> = BTreeMap::new();
-    for (table, filename) in [
-        ("household", "households.jsonl"),
-        ("events", "events.jsonl"),
-        ("notes", "notes.jsonl"),
-        ("files_index", "files.jsonl"),
-    ] {
-        let path = data_dir.join(filename);
-        let (count, sha) = dump_table_jsonl(pool, table, &path).await.map_err(|err| {
+    if include_deletions_audit {
+        let audit_dir = export_dir.join("audit");
+        fs::create_dir_all(&audit_dir).map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "create_audit_dir")
+                .with_context("path", audit_dir.display().to_string())
+        })?;
+        dump_table_jsonl(
+            pool,
+            "deletions_audit",
+            &audit_dir.join("deletions_audit.jsonl"),
+            compress,
+        )
+        .await
+        .map_err(|err| {
             AppError::from(err)
                 .with_context("operation", "dump_table")
-                .with_context("table", table)
+                .with_context("table", "deletions_audit")
         })?;
-        table_sha.insert(table, (count, sha));
-    }
-    // Fill manifest.tables with the exported subset
-    for (logical, table) in [
-        ("households", "household"),
-        ("events", "events"),
-        ("notes", "notes"),
-        ("files", "files_index"),
-    ] {
-        if let Some((count, sha)) = table_sha.get(table) {
-            manifest.tables.insert(
-                logical.to_string(),
-                TableInfo {
-                    count: *count,
-                    sha256: sha.clone(),
-                },
-            );
-        }
     }
 
     // Copy attachments with deterministic order and build attachment manifests
     let (attachments_total_count, attachments_total_bytes, attachments_manifest_sha) =
-        copy_attachments_and_build_manifests(pool, vault.as_ref(), &attachments_dir, &export_dir)
-            .await
-            .map_err(|err| err.with_context("operation", "copy_attachments"))?;
+        copy_attachments_and_build_manifests(
+            pool,
+            vault.as_ref(),
+            &attachments_dir,
+            &export_dir,
+            &selection,
+            None,
+            progress.as_ref(),
+        )
+        .await
+        .map_err(|err| err.with_context("operation", "copy_attachments"))?;
 
     manifest.attachments.total_count = attachments_total_count as u64;
     manifest.attachments.total_bytes = attachments_total_bytes as u64;
@@ -192,6 +237,117 @@ pub async fn create_export(
     })
 }
 
+/// Create a bundle holding only one household's vault files, the attachment
+/// manifests and the verify scripts. No `data/` directory is written, so the
+/// bundle cannot restore rows on its own.
+pub async fn create_attachments_export(
+    pool: &SqlitePool,
+    vault: Arc<Vault>,
+    out_parent: &Path,
+    household_id: &str,
+) -> AppResult<ExportEntry> {
+    let schema_version = current_schema_version(pool)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "schema_version"))?;
+    let app_version = env!("CARGO_PKG_VERSION").to_string();
+    let export_dir = prepare_export_dir(out_parent, &vault).await?;
+
+    let attachments_dir = export_dir.join("attachments");
+    fs::create_dir_all(&attachments_dir).ok();
+
+    let mut manifest = ExportManifest::new(app_version, schema_version);
+    manifest.attachments_only_household = Some(household_id.to_string());
+
+    let (attachments_total_count, attachments_total_bytes, attachments_manifest_sha) =
+        copy_attachments_and_build_manifests(
+            pool,
+            vault.as_ref(),
+            &attachments_dir,
+            &export_dir,
+            &TableSelection::default(),
+            Some(household_id),
+            None,
+        )
+        .await
+        .map_err(|err| {
+            err.with_context("operation", "copy_attachments")
+                .with_context("household_id", household_id.to_string())
+        })?;
+    manifest.attachments.total_count = attachments_total_count as u64;
+    manifest.attachments.total_bytes = attachments_total_bytes;
+    manifest.attachments.sha256_manifest = attachments_manifest_sha;
+
+    let manifest_path = export_dir.join("manifest.json");
+    let payload = serde_json::to_vec_pretty(&manifest)
+        .map_err(|err| AppError::from(err).with_context("operation", "serialize_manifest"))?;
+    db::write_atomic(&manifest_path, &payload)
+        .map_err(|err| AppError::from(err).with_context("operation", "write_manifest"))?;
+
+    let verify_sh_path = export_dir.join("verify.sh");
+    let verify_ps1_path = export_dir.join("verify.ps1");
+    write_verify_scripts(
+        &verify_sh_path,
+        &verify_ps1_path,
+        &manifest.tables,
+        &manifest.attachments.sha256_manifest,
+    )?;
+
+    Ok(ExportEntry {
+        directory: export_dir,
+        manifest_path,
+        verify_sh_path,
+        verify_ps1_path,
+    })
+}
+
+/// Check free space against the vault size and allocate a fresh
+/// `export-YYYYMMDD-HHMMSS[-NN]` directory under `out_parent`.
+#[allow(clippy::result_large_err)]
+async fn prepare_export_dir(out_parent: &Path, vault: &Arc<Vault>) -> AppResult<PathBuf> {
+    // Preflight: ensure parent exists and enough space is available.
+    fs::create_dir_all(out_parent).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "create_out_parent")
+            .with_context("path", out_parent.display().to_string())
+    })?;
+
+    let preflight = task::spawn_blocking({
+        let vault = vault.clone();
+        move || estimate_export_size(&vault)
+    })
+    .await
+    .map_err(|err| {
+        AppError::new("EXPORT/TASK", "Size estimate task panicked")
+            .with_context("error", err.to_string())
+    })??;
+
+    let avail = free_disk_space(out_parent)
+        .map_err(|err| AppError::from(err).with_context("operation", "available_space"))?;
+    if avail < preflight.required_bytes {
+        return Err(AppError::new(
+            "EXPORT/LOW_DISK",
+            format!(
+                "Not enough disk space (need ~{}).",
+                format_bytes(preflight.required_bytes)
+            ),
+        )
+        .with_context("available_bytes", avail.to_string())
+        .with_context("required_bytes", preflight.required_bytes.to_string()));
+    }
+
+    // Allocate unique directory
+    let timestamp = Utc::now();
+    let export_dir = unique_export_dir(out_parent, &timestamp)
+        .map_err(|err| err.with_context("operation", "alloc_export_dir"))?;
+    fs::create_dir_all(&export_dir).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "create_export_dir")
+            .with_context("path", export_dir.display().to_string())
+    })?;
+
+    Ok(export_dir)
+}
+
 struct SizeEstimate {
     required_bytes: u64,
 }
@@ -226,7 +382,8 @@ async fn dump_table_jsonl(
     pool: &SqlitePool,
     table: &str,
     path: &Path,
-) -> anyhow::Result<(u64, String)> {
+    compress: bool,
+) -> anyhow::Result<TableInfo> {
     // Dump SELECT * in stable order; only some tables have deleted_at
     let order = "id";
     let has_deleted = matches!(
@@ -265,7 +422,38 @@ async fn dump_table_jsonl(
     drop(file);
     fs::rename(&tmp, path)?;
     let sha = file_sha256(path)?;
-    Ok((count, sha))
+    if !compress {
+        return Ok(TableInfo {
+            count,
+            sha256: sha,
+            compression: None,
+            compressed_sha256: None,
+        });
+    }
+
+    let mut gz_name = OsString::from(path.as_os_str());
+    gz_name.push(".gz");
+    let gz_path = PathBuf::from(gz_name);
+    gzip_file(path, &gz_path)?;
+    fs::remove_file(path)?;
+    let compressed_sha = file_sha256(&gz_path)?;
+    Ok(TableInfo {
+        count,
+        sha256: sha,
+        compression: Some(GZIP_COMPRESSION.to_string()),
+        compressed_sha256: Some(compressed_sha),
+    })
+}
+
+fn gzip_file(src: &Path, dest: &Path) -> anyhow::Result<()> {
+    let tmp = tmp_path(dest);
+    let mut input = fs::File::open(src)?;
+    let mut encoder =
+        flate2::write::GzEncoder::new(fs::File::create(&tmp)?, flate2::Compression::default());
+    std::io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?.flush()?;
+    fs::rename(&tmp, dest)?;
+    Ok(())
 }
 
 async fn copy_attachments_and_build_manifests(
@@ -273,10 +461,16 @@ async fn copy_attachments_and_build_manifests(
     vault: &Vault,
     dest_root: &Path,
     export_root: &Path,
+    selection: &TableSelection,
+    household_id: Option<&str>,
+    progress: Option<&ProgressObserver>,
 ) -> AppResult<(usize, u64, String)> {
     let mut sources = load_attachment_sources(pool)
         .await
         .map_err(|err| err.with_context("operation", "load_attachment_sources"))?;
+    sources.retain(|source| {
+        selection.includes(source.table) && household_id.is_none_or(|hh| source.household_id == hh)
+    });
     sources.sort_by(|a, b| {
         a.household_id
             .cmp(&b.household_id)
@@ -307,7 +501,8 @@ async fn copy_attachments_and_build_manifests(
     let mut total_bytes: u64 = 0;
     let mut total_count: usize = 0;
 
-    for source in &sources {
+    let total_sources = sources.len() as u64;
+    for (index, source) in sources.iter().enumerate() {
         let resolved = vault
             .resolve(&source.household_id, source.category, &source.relative_path)
             .map_err(|err| {
@@ -332,7 +527,7 @@ async fn copy_attachments_and_build_manifests(
                         .with_context("path", parent.display().to_string())
                 })?;
             }
-            let hash = copy_and_hash(&resolved, &dest_path).map_err(|err| {
+            let hash = copy_and_hash(&resolved, &dest_path).await.map_err(|err| {
                 err.with_context("operation", "copy_export_attachment")
                     .with_context("table", source.table.to_string())
                     .with_context("household_id", source.household_id.clone())
@@ -355,6 +550,14 @@ async fn copy_attachments_and_build_manifests(
             );
             writeln!(db_manifest, "{}\tMISSING", manifest_key)?;
         }
+        if let Some(observer) = progress {
+            observer(OperationProgress {
+                stage: ProgressStage::Attachments,
+                done: index as u64 + 1,
+                total: total_sources,
+                bytes: total_bytes,
+            });
+        }
     }
     attach_manifest.flush().ok();
     db_manifest.flush().ok();
@@ -475,17 +678,24 @@ async fn load_attachment_sources(pool: &SqlitePool) -> AppResult<Vec<ExportAttac
     Ok(entries)
 }
 
-fn copy_and_hash(src: &Path, dest: &Path) -> AppResult<String> {
-    let mut in_f = fs::File::open(src).map_err(|err| {
-        AppError::from(err)
-            .with_context("operation", "open_export_attachment")
-            .with_context("path", src.display().to_string())
-    })?;
-    let mut out_f = fs::File::create(dest).map_err(|err| {
+async fn copy_and_hash(src: &Path, dest: &Path) -> AppResult<String> {
+    retry_transient_io_async("copy_export_attachment", dest, || {
+        copy_and_hash_once(src, dest)
+    })
+    .await
+    .map_err(|err| {
         AppError::from(err)
-            .with_context("operation", "create_export_attachment")
-            .with_context("path", dest.display().to_string())
-    })?;
+            .with_context("operation", "copy_export_attachment")
+            .with_context("from", src.display().to_string())
+            .with_context("to", dest.display().to_string())
+    })
+}
+
+/// One pass of [`copy_and_hash`]; the destination is truncated on open so a
+/// retried attempt starts clean.
+fn copy_and_hash_once(src: &Path, dest: &Path) -> std::io::Result<String> {
+    let mut in_f = fs::File::open(src)?;
+    let mut out_f = fs::File::create(dest)?;
     let mut hasher = Sha256::new();
     let mut buf = [0_u8; 131072];
     loop {
@@ -567,17 +777,21 @@ fn write_verify_scripts(
     tables: &BTreeMap<String, TableInfo>,
     attachments_manifest_sha: &str,
 ) -> anyhow::Result<()> {
-    // Extract expected table hashes if available
-    let expect = |key: &str| {
-        tables
-            .get(key)
-            .map(|t| t.sha256.clone())
-            .unwrap_or_default()
-    };
-    let households_sha = expect("households");
-    let events_sha = expect("events");
-    let notes_sha = expect("notes");
-    let files_sha = expect("files");
+    // Expected hashes always cover the uncompressed content; gzip files are
+    // decompressed before hashing.
+    let mut sh_checks = String::new();
+    let mut ps1_checks = String::new();
+    for (logical, _) in EXPORT_TABLES {
+        let info = tables.get(logical).cloned().unwrap_or_default();
+        let file = format!("data/{}", info.data_file_name(logical));
+        let (sh_fn, ps1_fn) = if info.is_gzip() {
+            ("ckz", "Check-GzFile")
+        } else {
+            ("ck", "Check-File")
+        };
+        sh_checks.push_str(&format!("{sh_fn} {file} {}\n", info.sha256));
+        ps1_checks.push_str(&format!("{ps1_fn} '{file}' '{}'\n", info.sha256));
+    }
 
     let sh = format!(
         r#"#!/usr/bin/env bash
@@ -601,11 +815,17 @@ ck() {{ # file expected
   fi
 }}
 
-ck data/households.jsonl {households_sha}
-ck data/events.jsonl {events_sha}
-ck data/notes.jsonl {notes_sha}
-ck data/files.jsonl {files_sha}
+ckz() {{ # gzip file, expected hash of decompressed content
+  local f="$1"; local expected="$2";
+  if [[ -n "$expected" && -f "$f" ]]; then
+    local got; got=$(gzip -dc "$f" | "${{SHACMD[@]}}" | awk '{{{{print $1}}}}');
+    if [[ "$got" != "$expected" ]]; then
+      echo "Mismatch: $f"; echo " expected: $expected"; echo "      got: $got"; exit 1;
+    fi
+  fi
+}}
 
+{sh_checks}
 AM=attachments_manifest.rebuilt.txt
 rm -f "$AM"
 if command -v find >/dev/null 2>&1; then
@@ -629,10 +849,7 @@ fi
 
 echo 'OK'
 "#,
-        households_sha = households_sha,
-        events_sha = events_sha,
-        notes_sha = notes_sha,
-        files_sha = files_sha,
+        sh_checks = sh_checks,
         attachments_sha = attachments_manifest_sha,
     );
 
@@ -653,11 +870,26 @@ function Check-File([string]$Path, [string]$Expected) {{
   }}
 }}
 
-Check-File 'data/households.jsonl' '{households_sha}'
-Check-File 'data/events.jsonl' '{events_sha}'
-Check-File 'data/notes.jsonl' '{notes_sha}'
-Check-File 'data/files.jsonl' '{files_sha}'
+function Get-GzSha256([string]$Path) {{
+  $in = [System.IO.File]::OpenRead($Path)
+  try {{
+    $gz = New-Object System.IO.Compression.GZipStream($in, [System.IO.Compression.CompressionMode]::Decompress)
+    $sha = [System.Security.Cryptography.SHA256]::Create()
+    (($sha.ComputeHash($gz) | ForEach-Object {{ $_.ToString('x2') }}) -join '')
+  }} finally {{ $in.Dispose() }}
+}}
+
+function Check-GzFile([string]$Path, [string]$Expected) {{
+  if ($Expected -and (Test-Path $Path)) {{
+    $got = Get-GzSha256 $Path
+    if ($got -ne $Expected) {{
+      Write-Host "Mismatch: $Path`n expected: $Expected`n      got: $got"
+      exit 1
+    }}
+  }}
+}}
 
+{ps1_checks}
 $am = 'attachments_manifest.rebuilt.txt'
 if (Test-Path $am) {{ Remove-Item $am -Force }}
 Get-ChildItem -Path 'attachments' -Recurse -File | Sort-Object FullName | ForEach-Object {{
@@ -673,10 +905,7 @@ if ($amSha -ne $expect) {{
 }}
 Write-Host 'OK'
 "#,
-        households_sha = households_sha,
-        events_sha = events_sha,
-        notes_sha = notes_sha,
-        files_sha = files_sha,
+        ps1_checks = ps1_checks,
         attachments_sha = attachments_manifest_sha,
     );
 
@@ -838,6 +1067,9 @@ mod tests {
             vault,
             ExportOptions {
                 out_parent: export_dir.path().to_path_buf(),
+                compress: false,
+                include_deletions_audit: false,
+                tables: TableSelection::default(),
             },
         )
         .await
@@ -889,6 +1121,9 @@ mod tests {
             vault,
             ExportOptions {
                 out_parent: export_dir.path().to_path_buf(),
+                compress: false,
+                include_deletions_audit: false,
+                tables: TableSelection::default(),
             },
         )
         .await
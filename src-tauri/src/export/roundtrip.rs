@@ -0,0 +1,155 @@
+//! Export → import → export stability check.
+//!
+//! Exports the live database, imports the bundle into a scratch database and
+//! vault, exports that copy and compares the two manifests. Any difference in
+//! table hashes or the attachments manifest points at non-deterministic
+//! serialization somewhere in the export or import path.
+
+use std::sync::Arc;
+
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+use super::manifest::ExportManifest;
+use super::{create_export, ExportOptions};
+use crate::import::{build_plan, execute_plan, ExecutionContext, ImportBundle, ImportMode};
+use crate::{db, migrate, vault::Vault, AppError, AppResult};
+
+/// A manifest entry that differed between the first and second export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundtripMismatch {
+    /// Logical table name, or `attachments` for the attachments manifest.
+    pub section: String,
+    pub first: String,
+    pub second: String,
+}
+
+/// Run the export → import → export cycle and return every manifest entry
+/// that changed. An empty list means the round-trip is stable.
+pub async fn export_roundtrip_mismatches(
+    pool: &SqlitePool,
+    vault: Arc<Vault>,
+) -> AppResult<Vec<RoundtripMismatch>> {
+    let scratch = TempDir::new()
+        .map_err(|err| AppError::from(err).with_context("operation", "roundtrip_scratch_dir"))?;
+
+    let (bundle_dir, first) = export_manifest(pool, vault, scratch.path().join("first")).await?;
+
+    let replica_pool = db::connect_sqlite_pool(&scratch.path().join("replica.sqlite3"))
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "roundtrip_open_replica"))?;
+    migrate::apply_migrations(&replica_pool)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "roundtrip_migrate"))?;
+    let replica_vault = Arc::new(Vault::new(scratch.path().join("attachments")));
+
+    let bundle = ImportBundle::load(&bundle_dir).map_err(|err| {
+        AppError::from(anyhow::Error::new(err)).with_context("operation", "roundtrip_load_bundle")
+    })?;
+    let plan = build_plan(
+        &bundle,
+        &crate::import::PlanContext {
+            pool: &replica_pool,
+            vault: replica_vault.clone(),
+        },
+        ImportMode::Replace,
+    )
+    .await
+    .map_err(|err| {
+        AppError::from(anyhow::Error::new(err)).with_context("operation", "roundtrip_build_plan")
+    })?;
+    execute_plan(
+        &bundle,
+        &plan,
+        &ExecutionContext::new(&replica_pool, replica_vault.clone()),
+    )
+    .await
+    .map_err(|err| {
+        AppError::from(anyhow::Error::new(err)).with_context("operation", "roundtrip_execute_plan")
+    })?;
+
+    let second = export_manifest(&replica_pool, replica_vault, scratch.path().join("second")).await;
+    replica_pool.close().await;
+    let (_, second) = second?;
+
+    Ok(compare_manifests(&first, &second))
+}
+
+/// Panic with a readable diff when the export round-trip is not stable.
+/// Intended for tests; the CLI uses [`export_roundtrip_mismatches`].
+pub async fn assert_export_roundtrip_stable(pool: &SqlitePool, vault: Arc<Vault>) {
+    let mismatches = export_roundtrip_mismatches(pool, vault)
+        .await
+        .unwrap_or_else(|err| panic!("export round-trip failed: {err}"));
+    if !mismatches.is_empty() {
+        let lines: Vec<String> = mismatches
+            .iter()
+            .map(|m| format!("  {}: {} != {}", m.section, m.first, m.second))
+            .collect();
+        panic!("export round-trip is not stable:\n{}", lines.join("\n"));
+    }
+}
+
+async fn export_manifest(
+    pool: &SqlitePool,
+    vault: Arc<Vault>,
+    out_parent: std::path::PathBuf,
+) -> AppResult<(std::path::PathBuf, ExportManifest)> {
+    let entry = create_export(
+        pool,
+        vault,
+        ExportOptions {
+            out_parent,
+            compress: false,
+            include_deletions_audit: false,
+            tables: Default::default(),
+        },
+    )
+    .await?;
+    let bytes = std::fs::read(&entry.manifest_path)
+        .map_err(|err| AppError::from(err).with_context("operation", "roundtrip_read_manifest"))?;
+    let manifest: ExportManifest = serde_json::from_slice(&bytes)
+        .map_err(|err| AppError::from(err).with_context("operation", "roundtrip_parse_manifest"))?;
+    Ok((entry.directory, manifest))
+}
+
+fn compare_manifests(first: &ExportManifest, second: &ExportManifest) -> Vec<RoundtripMismatch> {
+    let mut mismatches = Vec::new();
+    let sections: std::collections::BTreeSet<&String> =
+        first.tables.keys().chain(second.tables.keys()).collect();
+    for section in sections {
+        let describe = |manifest: &ExportManifest| {
+            manifest
+                .tables
+                .get(section)
+                .map(|info| format!("{} rows sha256={}", info.count, info.sha256))
+                .unwrap_or_else(|| "missing".to_string())
+        };
+        let (a, b) = (describe(first), describe(second));
+        if a != b {
+            mismatches.push(RoundtripMismatch {
+                section: section.clone(),
+                first: a,
+                second: b,
+            });
+        }
+    }
+
+    let describe_attachments = |manifest: &ExportManifest| {
+        format!(
+            "{} files {} bytes sha256={}",
+            manifest.attachments.total_count,
+            manifest.attachments.total_bytes,
+            manifest.attachments.sha256_manifest
+        )
+    };
+    let (a, b) = (describe_attachments(first), describe_attachments(second));
+    if a != b {
+        mismatches.push(RoundtripMismatch {
+            section: "attachments".to_string(),
+            first: a,
+            second: b,
+        });
+    }
+    mismatches
+}
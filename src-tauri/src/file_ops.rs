@@ -9,10 +9,11 @@ use chrono::Utc;
 use futures::TryStreamExt;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use sqlx::{Row, Sqlite, SqlitePool, Transaction};
+use sha2::{Digest, Sha256};
+use sqlx::{Row, Sqlite, SqliteConnection, SqlitePool, Transaction};
 use tauri::{Emitter, Manager};
 use tokio::fs::{self, File};
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore, TryAcquireError};
 use tokio::task::yield_now;
 use tokio::time::{sleep, Duration};
@@ -183,6 +184,87 @@ pub struct FileMoveResponse {
     pub renamed: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentMoveResponse {
+    pub relative_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RowReassignResponse {
+    pub household_id: String,
+    /// Vault-relative path of the row's attachment, if it has one.
+    pub relative_path: Option<String>,
+    /// False when the row had no attachment or its file was already missing.
+    pub file_moved: bool,
+}
+
+/// A file linked to an entity, either on the entity's own row or on a child
+/// row such as a vehicle's maintenance record.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EntityAttachment {
+    /// Table and id of the row carrying the attachment.
+    pub table: String,
+    pub id: String,
+    pub household_id: String,
+    pub category: AttachmentCategory,
+    pub relative_path: String,
+    /// False when the file is missing or its path does not resolve inside the vault.
+    pub exists: bool,
+    pub size_bytes: Option<u64>,
+}
+
+/// A row whose household disagrees with its attachment or its parent row.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HouseholdIntegrityIssue {
+    pub table: String,
+    pub id: String,
+    pub household_id: String,
+    /// `HOUSEHOLD_MISSING`, `CATEGORY_MISMATCH`, `PATH_OUTSIDE_HOUSEHOLD` or
+    /// `PARENT_HOUSEHOLD_MISMATCH`.
+    pub code: String,
+    /// Offending category, foreign household prefix, or `parent_table:household_id`.
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentCopyResponse {
+    pub relative_path: String,
+    pub deduplicated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentRestoreResponse {
+    pub relative_path: String,
+    pub sha256: String,
+    /// False when the vault already held an identical file.
+    pub restored: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AttachmentImportStatus {
+    Imported,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentImportFileResult {
+    /// Path of the source file relative to the import directory.
+    pub source: String,
+    pub status: AttachmentImportStatus,
+    pub id: Option<String>,
+    pub relative_path: Option<String>,
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentsImportDirResponse {
+    pub imported: u64,
+    pub rejected: u64,
+    pub files: Vec<AttachmentImportFileResult>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RepairActionKind {
@@ -304,160 +386,1988 @@ pub async fn move_file<R: tauri::Runtime>(
     let prepared_move = match stage_move(&source_path, &staging_path).await {
         Ok(prepared) => prepared,
         Err(err) => {
-            tracing::error!(
-                target = "arklowdun",
-                event = "file_move_stage_failed",
-                household_id = %request.household_id,
-                error = %err,
-            );
+            tracing::error!(
+                target = "arklowdun",
+                event = "file_move_stage_failed",
+                household_id = %request.household_id,
+                error = %err,
+            );
+            return Err(err);
+        }
+    };
+
+    let new_relative = vault
+        .relative_from_resolved(&target_path, &request.household_id, request.to_category)
+        .ok_or_else(|| {
+            AppError::new(
+                "RELATIVE_RESOLVE_FAILED",
+                "Unable to compute vault relative path for moved file.",
+            )
+        })?;
+
+    let normalized_new = normalize_relative(&new_relative).map_err(|err| {
+        AppError::from(err).with_context("operation", "normalize_target_relative")
+    })?;
+    let new_relative = normalized_new.to_string_lossy().replace('\\', "/");
+
+    let db_outcome = {
+        let pool = pool.clone();
+        async {
+            let mut tx = pool
+                .begin()
+                .await
+                .map_err(|err| AppError::from(err).with_context("operation", "file_move_begin_tx"))?;
+
+            let mut updated_rows = 0_u32;
+            for table in ATTACHMENT_TABLES {
+                let clause = os_eq_clause("relative_path", "?5");
+                let sql = format!(
+                    "UPDATE {table} SET category = ?1, relative_path = ?2 WHERE household_id = ?3 AND category = ?4 AND {clause}"
+                );
+                let result = sqlx::query(&sql)
+                    .bind(request.to_category.as_str())
+                    .bind(&new_relative)
+                    .bind(&request.household_id)
+                    .bind(request.from_category.as_str())
+                    .bind(&from_relative)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|err| {
+                        AppError::from(err)
+                            .with_context("operation", format!("file_move_update_{table}"))
+                    })?;
+                updated_rows += result.rows_affected() as u32;
+            }
+
+            if request.to_category == AttachmentCategory::PetImage {
+                let clause = os_eq_clause("image_path", "?3");
+                let sql = format!(
+                    "UPDATE pets SET image_path = ?1 WHERE household_id = ?2 AND {clause}",
+                );
+                sqlx::query(&sql)
+                    .bind(&new_relative)
+                    .bind(&request.household_id)
+                    .bind(&from_relative)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|err| {
+                        AppError::from(err)
+                            .with_context("operation", "file_move_update_pets")
+                    })?;
+            } else if request.from_category == AttachmentCategory::PetImage {
+                let clause = os_eq_clause("image_path", "?2");
+                let sql = format!(
+                    "UPDATE pets SET image_path = NULL WHERE household_id = ?1 AND {clause}",
+                );
+                sqlx::query(&sql)
+                    .bind(&request.household_id)
+                    .bind(&from_relative)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|err| {
+                        AppError::from(err)
+                            .with_context("operation", "file_move_clear_pets")
+                    })?;
+            }
+            let new_index_name = index_basename(&new_relative);
+            let from_index_name = index_basename(&from_relative);
+            let files_index_clause = os_eq_clause("filename", "?5");
+            let files_index_sql = format!(
+                "UPDATE files_index SET category = ?1, filename = ?2 WHERE household_id = ?3 AND category = ?4 AND {files_index_clause}"
+            );
+            let files_index_result = sqlx::query(&files_index_sql)
+                .bind(request.to_category.as_str())
+                .bind(&new_index_name)
+                .bind(&request.household_id)
+                .bind(request.from_category.as_str())
+                .bind(&from_index_name)
+                .execute(&mut *tx)
+                .await
+                .map_err(|err| {
+                    AppError::from(err).with_context("operation", "file_move_update_files_index")
+                })?;
+
+            updated_rows += files_index_result.rows_affected() as u32;
+
+            tx.commit()
+                .await
+                .map_err(|err| AppError::from(err).with_context("operation", "file_move_commit"))?;
+
+            Ok::<u32, AppError>(updated_rows)
+        }
+        .await
+    };
+
+    let updated_rows = match db_outcome {
+        Ok(rows) => rows,
+        Err(err) => {
+            if let Err(rollback_err) = prepared_move.rollback(&source_path).await {
+                tracing::error!(
+                    target = "arklowdun",
+                    event = "file_move_rollback_failed",
+                    household_id = %request.household_id,
+                    error = %rollback_err,
+                );
+            }
+            return Err(err);
+        }
+    };
+
+    prepared_move
+        .finalize(&source_path, &target_path)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "file_move_finalize"))?;
+
+    emit_move_progress(&app, "completed", &new_relative, 1, 1);
+
+    tracing::info!(
+        target = "arklowdun",
+        event = "file_move_completed",
+        household_id = %request.household_id,
+        from_category = %request.from_category.as_str(),
+        from_relative_hash = %from_hash,
+        to_category = %request.to_category.as_str(),
+        to_relative_hash = %hash_path(Path::new(&new_relative)),
+        rows_updated = updated_rows,
+        renamed = renamed_due_to_conflict,
+    );
+
+    schedule_index_rebuild(&app, &request.household_id);
+
+    Ok(FileMoveResponse {
+        moved: updated_rows,
+        renamed: renamed_due_to_conflict,
+    })
+}
+
+/// Column holding the vault-relative path for an attachment-bearing table.
+fn attachment_path_column(table: &str) -> &'static str {
+    if table == "pets" {
+        "image_path"
+    } else {
+        "relative_path"
+    }
+}
+
+/// Rename a single row's attachment within its household and category. The
+/// file is staged next to the target, the row is updated in a transaction,
+/// and the staged file is only put in place once the update commits.
+pub async fn move_attachment(
+    pool: &SqlitePool,
+    vault: &Vault,
+    table: &str,
+    id: &str,
+    new_relative_path: &str,
+) -> AppResult<AttachmentMoveResponse> {
+    let descriptor = crate::attachments::load_attachment_descriptor(pool, table, id)
+        .await
+        .map_err(|err| err.with_context("operation", "attachment_move"))?;
+    let household_id = descriptor.household_id.as_str();
+    let category = descriptor.category;
+
+    let source_path = vault.resolve(household_id, category, &descriptor.relative_path)?;
+    let target_path = vault
+        .resolve(household_id, category, new_relative_path)
+        .map_err(|err| err.with_context("operation", "attachment_move"))?;
+
+    let new_relative = normalize_relative(new_relative_path)
+        .map_err(|err| err.with_context("operation", "attachment_move"))?
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    if target_path == source_path {
+        return Ok(AttachmentMoveResponse {
+            relative_path: new_relative,
+        });
+    }
+
+    match fs::metadata(&source_path).await {
+        Ok(meta) if meta.is_file() => {}
+        Ok(_) => {
+            return Err(AppError::new(
+                "DIRECTORY_MOVE_UNSUPPORTED",
+                "Moving directories is not supported.",
+            ));
+        }
+        Err(_) => {
+            return Err(AppError::new(
+                "FILE_MISSING",
+                "Source file could not be found in the vault.",
+            )
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string()));
+        }
+    }
+
+    let from_relative = normalize_relative(&descriptor.relative_path)?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let _move_lock = MoveLockGuard::acquire(move_lock_key(household_id, category, &from_relative))?;
+
+    // Another row pointing at the same file would be left dangling by the
+    // rename, and a target path another row already names would be shadowed.
+    let mut conn = pool.acquire().await.map_err(|err| {
+        AppError::from(err).with_context("operation", "attachment_move_check_claims")
+    })?;
+    let source_shared = attachment_path_claimed(
+        &mut conn,
+        household_id,
+        category,
+        &from_relative,
+        Some((table, id)),
+    )
+    .await?;
+    let target_claimed = attachment_path_claimed(
+        &mut conn,
+        household_id,
+        category,
+        &new_relative,
+        Some((table, id)),
+    )
+    .await?;
+    drop(conn);
+    if source_shared {
+        return Err(AppError::new(
+            "ATTACHMENT/PATH_SHARED",
+            "The file is shared with another record; copy it instead.",
+        )
+        .with_context("operation", "attachment_move")
+        .with_context("relative_path_hash", hash_path(Path::new(&from_relative))));
+    }
+
+    if target_claimed || target_path.exists() {
+        return Err(
+            AppError::new("FILE_EXISTS", "Destination file already exists.")
+                .with_context("operation", "attachment_move")
+                .with_context("relative_path_hash", hash_path(Path::new(&new_relative))),
+        );
+    }
+
+    if let Some(parent) = target_path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "create_target_parent"))?;
+    }
+
+    let staging_path = staging_path_for(&target_path);
+    let prepared_move = stage_move(&source_path, &staging_path).await?;
+
+    let column = attachment_path_column(table);
+    let db_outcome = async {
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "attachment_move_begin_tx"))?;
+        // member_attachments has no updated_at column.
+        let touch_updated = table != "member_attachments";
+        let sql = if touch_updated {
+            format!("UPDATE {table} SET {column} = ?1, updated_at = ?3 WHERE id = ?2")
+        } else {
+            format!("UPDATE {table} SET {column} = ?1 WHERE id = ?2")
+        };
+        let mut update = sqlx::query(&sql).bind(&new_relative).bind(id);
+        if touch_updated {
+            update = update.bind(crate::time::now_ms());
+        }
+        update
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", format!("attachment_move_update_{table}"))
+            })?;
+
+        let files_index_clause = os_eq_clause("filename", "?4");
+        sqlx::query(&format!(
+            "UPDATE files_index SET filename = ?1 WHERE household_id = ?2 AND category = ?3 AND {files_index_clause}"
+        ))
+        .bind(&new_relative)
+        .bind(household_id)
+        .bind(category.as_str())
+        .bind(&from_relative)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            AppError::from(err).with_context("operation", "attachment_move_update_files_index")
+        })?;
+
+        tx.commit()
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "attachment_move_commit"))
+    }
+    .await;
+
+    if let Err(err) = db_outcome {
+        if let Err(rollback_err) = prepared_move.rollback(&source_path).await {
+            tracing::error!(
+                target = "arklowdun",
+                event = "attachment_move_rollback_failed",
+                household_id = %household_id,
+                error = %rollback_err,
+            );
+        }
+        return Err(err);
+    }
+
+    prepared_move
+        .finalize(&source_path, &target_path)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "attachment_move_finalize"))?;
+
+    tracing::info!(
+        target = "arklowdun",
+        event = "attachment_move_completed",
+        household_id = %household_id,
+        table = %table,
+        category = %category.as_str(),
+        from_relative_hash = %hash_path(Path::new(&from_relative)),
+        to_relative_hash = %hash_path(Path::new(&new_relative)),
+    );
+
+    Ok(AttachmentMoveResponse {
+        relative_path: new_relative,
+    })
+}
+
+/// Move a single row from `from_household` to `to_household`. For attachment
+/// tables the file keeps its category and relative path and is relocated into
+/// the new household's subtree, staged and finalized around the row update
+/// the same way as [`move_attachment`].
+///
+/// The household row itself cannot be moved, and neither can rows linked to
+/// other rows (a pet with medical records, a note filed under a category),
+/// since the links would cross households.
+pub async fn reassign_row(
+    pool: &SqlitePool,
+    vault: &Vault,
+    table: &str,
+    id: &str,
+    from_household: &str,
+    to_household: &str,
+) -> AppResult<RowReassignResponse> {
+    let context = |err: AppError| {
+        err.with_context("operation", "row_reassign")
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string())
+    };
+    if table == "household" {
+        return Err(context(AppError::new(
+            "REASSIGN/HOUSEHOLD_ROW",
+            "A household cannot be moved to another household.",
+        )));
+    }
+    crate::repo::ensure_table(table)
+        .map_err(|err| context(AppError::from(err).with_context("operation", "row_reassign")))?;
+    let columns = table_column_names(pool, table).await.map_err(context)?;
+    if !columns.iter().any(|column| column == "household_id") {
+        return Err(context(AppError::new(
+            "REASSIGN/UNSUPPORTED_TABLE",
+            "Rows in this table do not belong to a household.",
+        )));
+    }
+    if from_household == to_household {
+        return Err(context(AppError::new(
+            "REASSIGN/SAME_HOUSEHOLD",
+            "The row already belongs to that household.",
+        )));
+    }
+    for household_id in [from_household, to_household] {
+        ensure_household_live(pool, household_id)
+            .await
+            .map_err(context)?;
+    }
+
+    let live = if columns.iter().any(|column| column == "deleted_at") {
+        " AND deleted_at IS NULL"
+    } else {
+        ""
+    };
+    let current: Option<String> = sqlx::query_scalar(&format!(
+        "SELECT household_id FROM {table} WHERE id = ?1{live}"
+    ))
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| context(AppError::from(err)))?;
+    match current {
+        None => {
+            return Err(context(AppError::new("DB/NOT_FOUND", "Record not found")));
+        }
+        Some(current) if current != from_household => {
+            return Err(context(
+                AppError::new(
+                    "REASSIGN/HOUSEHOLD_MISMATCH",
+                    "The row does not belong to the source household.",
+                )
+                .with_context("household_id", from_household.to_string()),
+            ));
+        }
+        Some(_) => {}
+    }
+    let link = match row_link(pool, table, id).await.map_err(context)? {
+        Some(link) => Some(link),
+        None => note_link(pool, table, id, from_household)
+            .await
+            .map_err(context)?,
+    };
+    if let Some(link) = link {
+        return Err(context(
+            AppError::new(
+                "REASSIGN/LINKED_ROW",
+                "The row is linked to other rows in its household.",
+            )
+            .with_context("linked_table", link),
+        ));
+    }
+
+    let attachment = if ATTACHMENT_TABLES.contains(&table) || table == "pets" {
+        match crate::attachments::load_attachment_descriptor(pool, table, id).await {
+            Ok(descriptor) => Some(descriptor),
+            Err(err) if err.code() == "IO/ENOENT" => None,
+            Err(err) => return Err(context(err)),
+        }
+    } else {
+        None
+    };
+
+    let mut relocation = None;
+    let mut relative_path = None;
+    let _move_lock;
+    if let Some(descriptor) = &attachment {
+        let category = descriptor.category;
+        let relative = normalize_relative(&descriptor.relative_path)
+            .map_err(context)?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let source_path = vault
+            .resolve(from_household, category, &relative)
+            .map_err(context)?;
+        let target_path = vault
+            .resolve(to_household, category, &relative)
+            .map_err(context)?;
+        let mut conn = pool
+            .acquire()
+            .await
+            .map_err(|err| context(AppError::from(err)))?;
+        let claimed = attachment_path_claimed(
+            &mut conn,
+            to_household,
+            category,
+            &relative,
+            Some((table, id)),
+        )
+        .await?;
+        drop(conn);
+        if claimed || target_path.exists() {
+            return Err(context(
+                AppError::new("FILE_EXISTS", "Destination file already exists.")
+                    .with_context("relative_path_hash", hash_path(Path::new(&relative))),
+            ));
+        }
+        _move_lock = MoveLockGuard::acquire(move_lock_key(from_household, category, &relative))?;
+        if fs::metadata(&source_path)
+            .await
+            .map(|meta| meta.is_file())
+            .unwrap_or(false)
+        {
+            if let Some(parent) = target_path.parent() {
+                fs::create_dir_all(parent).await.map_err(|err| {
+                    context(AppError::from(err).with_context("operation", "create_target_parent"))
+                })?;
+            }
+            let staging_path = staging_path_for(&target_path);
+            let prepared = stage_move(&source_path, &staging_path).await?;
+            relocation = Some((prepared, source_path, target_path));
+        }
+        relative_path = Some(relative);
+    }
+
+    let db_outcome = async {
+        let mut tx = pool.begin().await.map_err(AppError::from)?;
+        let mut assignments = vec!["household_id = ?1".to_string()];
+        // Positions are unique per household, so the row goes to the end of
+        // the target household's list rather than keeping its old slot.
+        let position = if columns.iter().any(|column| column == "position") {
+            let next: i64 = sqlx::query_scalar(&format!(
+                "SELECT COALESCE(MAX(position), -1) + 1 FROM {table} WHERE household_id = ?1{live}"
+            ))
+            .bind(to_household)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(AppError::from)?;
+            assignments.push(format!("position = ?{}", assignments.len() + 2));
+            Some(next)
+        } else {
+            None
+        };
+        let touch_updated = columns.iter().any(|column| column == "updated_at");
+        if touch_updated {
+            assignments.push(format!("updated_at = ?{}", assignments.len() + 2));
+        }
+        let sql = format!(
+            "UPDATE {table} SET {} WHERE id = ?2",
+            assignments.join(", ")
+        );
+        let mut update = sqlx::query(&sql).bind(to_household).bind(id);
+        if let Some(position) = position {
+            update = update.bind(position);
+        }
+        if touch_updated {
+            update = update.bind(crate::time::now_ms());
+        }
+        update.execute(&mut *tx).await.map_err(AppError::from)?;
+
+        if let (Some(descriptor), Some(relative)) = (&attachment, &relative_path) {
+            let files_index_clause = os_eq_clause("filename", "?5");
+            sqlx::query(&format!(
+                "UPDATE files_index SET household_id = ?1, filename = ?2 WHERE household_id = ?3 AND category = ?4 AND {files_index_clause}"
+            ))
+            .bind(to_household)
+            .bind(relative)
+            .bind(from_household)
+            .bind(descriptor.category.as_str())
+            .bind(relative)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| {
+                AppError::from(err).with_context("operation", "row_reassign_update_files_index")
+            })?;
+        }
+
+        tx.commit().await.map_err(AppError::from)
+    }
+    .await
+    .map_err(context);
+
+    if let Err(err) = db_outcome {
+        if let Some((prepared, source_path, _)) = &relocation {
+            if let Err(rollback_err) = prepared.rollback(source_path).await {
+                tracing::error!(
+                    target = "arklowdun",
+                    event = "row_reassign_rollback_failed",
+                    household_id = %from_household,
+                    error = %rollback_err,
+                );
+            }
+        }
+        return Err(err);
+    }
+
+    let file_moved = relocation.is_some();
+    if let Some((prepared, source_path, target_path)) = relocation {
+        prepared
+            .finalize(&source_path, &target_path)
+            .await
+            .map_err(|err| {
+                context(AppError::from(err).with_context("operation", "row_reassign_finalize"))
+            })?;
+    }
+
+    tracing::info!(
+        target = "arklowdun",
+        event = "row_reassign_completed",
+        table = %table,
+        from_household = %from_household,
+        to_household = %to_household,
+        file_moved,
+    );
+
+    Ok(RowReassignResponse {
+        household_id: to_household.to_string(),
+        relative_path,
+        file_moved,
+    })
+}
+
+/// Cross-check every attachment-bearing row against the households it
+/// touches: the household it claims must be live, any parent row it links to
+/// (a pet, vehicle or family member) must belong to the same household, its
+/// category must match the table, and its relative path must resolve inside
+/// its own household's vault subtree without naming another household.
+///
+/// Read-only; nothing is repaired.
+pub async fn household_integrity_audit(
+    pool: &SqlitePool,
+    vault: &Vault,
+) -> AppResult<Vec<HouseholdIntegrityIssue>> {
+    let context = |err: sqlx::Error| {
+        AppError::from(err).with_context("operation", "household_integrity_audit")
+    };
+    let households: Vec<(String, bool)> =
+        sqlx::query_as("SELECT id, deleted_at IS NULL FROM household")
+            .fetch_all(pool)
+            .await
+            .map_err(context)?;
+    let live: HashMap<&str, bool> = households
+        .iter()
+        .map(|(id, live)| (id.as_str(), *live))
+        .collect();
+
+    let mut issues = Vec::new();
+    for table in ATTACHMENT_TABLES.iter().copied().chain(["pets"]) {
+        let columns = table_column_names(pool, table).await?;
+        let has = |name: &str| columns.iter().any(|column| column == name);
+        let path_column = if table == "pets" {
+            "image_path"
+        } else {
+            "relative_path"
+        };
+        let category_column = if has("category") { "category" } else { "NULL" };
+        let live_filter = if has("deleted_at") {
+            " AND deleted_at IS NULL"
+        } else {
+            ""
+        };
+        let rows: Vec<(String, String, Option<String>, String)> = sqlx::query_as(&format!(
+            "SELECT id, household_id, {category_column}, {path_column} FROM {table}
+             WHERE {path_column} IS NOT NULL AND {path_column} <> ''{live_filter}
+             ORDER BY id"
+        ))
+        .fetch_all(pool)
+        .await
+        .map_err(context)?;
+
+        let expected = AttachmentCategory::for_table(table);
+        for (id, household_id, category, relative_path) in rows {
+            let issue = |code: &str, detail: Option<String>| HouseholdIntegrityIssue {
+                table: table.to_string(),
+                id: id.clone(),
+                household_id: household_id.clone(),
+                code: code.to_string(),
+                detail,
+            };
+            if live.get(household_id.as_str()) != Some(&true) {
+                issues.push(issue("HOUSEHOLD_MISSING", None));
+                continue;
+            }
+
+            let category = match category {
+                Some(value) => match AttachmentCategory::from_str(&value) {
+                    Ok(category) if Some(category) == expected => category,
+                    _ => {
+                        issues.push(issue("CATEGORY_MISMATCH", Some(value)));
+                        continue;
+                    }
+                },
+                None => match expected {
+                    Some(category) => category,
+                    None => continue,
+                },
+            };
+
+            let foreign_prefix = relative_path
+                .split(['/', '\\'])
+                .next()
+                .filter(|segment| *segment != household_id && live.contains_key(segment));
+            if let Some(prefix) = foreign_prefix {
+                issues.push(issue("PATH_OUTSIDE_HOUSEHOLD", Some(prefix.to_string())));
+            } else if vault
+                .resolve(&household_id, category, &relative_path)
+                .is_err()
+            {
+                issues.push(issue("PATH_OUTSIDE_HOUSEHOLD", None));
+            }
+        }
+
+        let parents: Vec<(String, String)> = sqlx::query_as(
+            "SELECT \"table\", \"from\" FROM pragma_foreign_key_list(?1) WHERE \"table\" <> 'household'",
+        )
+        .bind(table)
+        .fetch_all(pool)
+        .await
+        .map_err(context)?;
+        for (parent, column) in parents {
+            if !table_column_names(pool, &parent)
+                .await?
+                .iter()
+                .any(|name| name == "household_id")
+            {
+                continue;
+            }
+            let mismatched: Vec<(String, String, String)> = sqlx::query_as(&format!(
+                "SELECT c.id, c.household_id, p.household_id FROM {table} c
+                 JOIN {parent} p ON p.id = c.{column}
+                 WHERE c.household_id <> p.household_id AND c.{path_column} IS NOT NULL
+                 ORDER BY c.id"
+            ))
+            .fetch_all(pool)
+            .await
+            .map_err(context)?;
+            issues.extend(
+                mismatched
+                    .into_iter()
+                    .map(
+                        |(id, household_id, parent_household)| HouseholdIntegrityIssue {
+                            table: table.to_string(),
+                            id,
+                            household_id,
+                            code: "PARENT_HOUSEHOLD_MISMATCH".to_string(),
+                            detail: Some(format!("{parent}:{parent_household}")),
+                        },
+                    ),
+            );
+        }
+    }
+
+    tracing::info!(
+        target = "arklowdun",
+        event = "household_integrity_audit_completed",
+        issues = issues.len(),
+    );
+    Ok(issues)
+}
+
+/// Household owning live row `id` of `table`, so callers can check it
+/// against the active household before touching the row's attachments.
+pub async fn entity_household(pool: &SqlitePool, table: &str, id: &str) -> AppResult<String> {
+    let context = |err: AppError| {
+        err.with_context("operation", "entity_household")
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string())
+    };
+    crate::repo::ensure_table(table).map_err(|err| context(AppError::from(err)))?;
+    let columns = table_column_names(pool, table).await.map_err(context)?;
+    let live_filter = if columns.iter().any(|column| column == "deleted_at") {
+        " AND deleted_at IS NULL"
+    } else {
+        ""
+    };
+    // The household row is its own household.
+    let household_column = if table == "household" {
+        "id"
+    } else {
+        "household_id"
+    };
+    let household: Option<String> = sqlx::query_scalar(&format!(
+        "SELECT {household_column} FROM {table} WHERE id = ?1{live_filter}"
+    ))
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| context(AppError::from(err)))?;
+    household.ok_or_else(|| context(AppError::new("DB/NOT_FOUND", "Record not found")))
+}
+
+/// List the attachments linked to row `id` of `table`: its own file, if the
+/// table carries one, followed by those of live child rows that reference it
+/// through a foreign key. Each entry reports whether the file is on disk.
+pub async fn list_entity_attachments(
+    pool: &SqlitePool,
+    vault: &Vault,
+    table: &str,
+    id: &str,
+) -> AppResult<Vec<EntityAttachment>> {
+    let context = |err: AppError| {
+        err.with_context("operation", "attachments_list_for_entity")
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string())
+    };
+    entity_household(pool, table, id).await.map_err(context)?;
+
+    let mut sources: Vec<(&str, String)> = Vec::new();
+    for source in ATTACHMENT_TABLES.iter().copied().chain(["pets"]) {
+        if source == table {
+            sources.push((source, "id".to_string()));
+        }
+        let links: Vec<String> = sqlx::query_scalar(
+            "SELECT \"from\" FROM pragma_foreign_key_list(?1) WHERE \"table\" = ?2",
+        )
+        .bind(source)
+        .bind(table)
+        .fetch_all(pool)
+        .await
+        .map_err(|err| context(AppError::from(err)))?;
+        sources.extend(links.into_iter().map(|column| (source, column)));
+    }
+
+    let mut attachments = Vec::new();
+    for (source, column) in sources {
+        let source_columns = table_column_names(pool, source).await.map_err(context)?;
+        let has = |name: &str| source_columns.iter().any(|column| column == name);
+        let path_column = if source == "pets" {
+            "image_path"
+        } else {
+            "relative_path"
+        };
+        let category_column = if has("category") { "category" } else { "NULL" };
+        let live_filter = if has("deleted_at") {
+            " AND deleted_at IS NULL"
+        } else {
+            ""
+        };
+        let rows: Vec<(String, String, Option<String>, String)> = sqlx::query_as(&format!(
+            "SELECT id, household_id, {category_column}, {path_column} FROM {source}
+             WHERE {column} = ?1 AND {path_column} IS NOT NULL AND {path_column} <> ''{live_filter}
+             ORDER BY id"
+        ))
+        .bind(id)
+        .fetch_all(pool)
+        .await
+        .map_err(|err| context(AppError::from(err)))?;
+
+        for (row_id, household_id, category, relative_path) in rows {
+            let category = category
+                .and_then(|value| AttachmentCategory::from_str(&value).ok())
+                .or_else(|| AttachmentCategory::for_table(source))
+                .unwrap_or(AttachmentCategory::Misc);
+            let size_bytes = match vault.resolve(&household_id, category, &relative_path) {
+                Ok(path) => match fs::metadata(&path).await {
+                    Ok(meta) if meta.is_file() => Some(meta.len()),
+                    _ => None,
+                },
+                Err(_) => None,
+            };
+            attachments.push(EntityAttachment {
+                table: source.to_string(),
+                id: row_id,
+                household_id,
+                category,
+                relative_path,
+                exists: size_bytes.is_some(),
+                size_bytes,
+            });
+        }
+    }
+    Ok(attachments)
+}
+
+async fn table_column_names(pool: &SqlitePool, table: &str) -> AppResult<Vec<String>> {
+    sqlx::query_scalar("SELECT name FROM pragma_table_info(?1)")
+        .bind(table)
+        .fetch_all(pool)
+        .await
+        .map_err(AppError::from)
+}
+
+async fn ensure_household_live(pool: &SqlitePool, household_id: &str) -> AppResult<()> {
+    let exists: Option<i64> =
+        sqlx::query_scalar("SELECT 1 FROM household WHERE id = ?1 AND deleted_at IS NULL")
+            .bind(household_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(AppError::from)?;
+    if exists.is_none() {
+        return Err(AppError::new("HOUSEHOLD_NOT_FOUND", "Household not found.")
+            .with_context("household_id", household_id.to_string()));
+    }
+    Ok(())
+}
+
+/// First foreign-key link between the row and another non-household row:
+/// a reference the row holds, or a row that references it. Returns the
+/// linked table's name.
+async fn row_link(pool: &SqlitePool, table: &str, id: &str) -> AppResult<Option<String>> {
+    let outgoing: Vec<(String, String)> = sqlx::query_as(
+        "SELECT \"table\", \"from\" FROM pragma_foreign_key_list(?1) WHERE \"table\" <> 'household'",
+    )
+    .bind(table)
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)?;
+    for (parent, column) in outgoing {
+        let linked: Option<i64> = sqlx::query_scalar(&format!(
+            "SELECT 1 FROM {table} WHERE id = ?1 AND {column} IS NOT NULL"
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(AppError::from)?;
+        if linked.is_some() {
+            return Ok(Some(parent));
+        }
+    }
+
+    let incoming: Vec<(String, String)> = sqlx::query_as(
+        "SELECT m.name, f.\"from\" FROM sqlite_master m JOIN pragma_foreign_key_list(m.name) f
+         WHERE m.type = 'table' AND f.\"table\" = ?1",
+    )
+    .bind(table)
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)?;
+    for (child, column) in incoming {
+        let linked: Option<i64> = sqlx::query_scalar(&format!(
+            "SELECT 1 FROM {child} WHERE {column} = ?1 LIMIT 1"
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(AppError::from)?;
+        if linked.is_some() {
+            return Ok(Some(child));
+        }
+    }
+    Ok(None)
+}
+
+/// `note_links` reference events and indexed files by id alone, so the
+/// foreign keys `row_link` walks do not see them.
+async fn note_link(
+    pool: &SqlitePool,
+    table: &str,
+    id: &str,
+    household_id: &str,
+) -> AppResult<Option<String>> {
+    let linked: Option<i64> = if table == "events" {
+        sqlx::query_scalar(
+            "SELECT 1 FROM note_links
+              WHERE household_id = ?1 AND entity_type = 'event' AND entity_id = ?2
+                AND deleted_at IS NULL
+              LIMIT 1",
+        )
+        .bind(household_id)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(AppError::from)?
+    } else if ATTACHMENT_TABLES.contains(&table) || table == "pets" {
+        let mut conn = pool.acquire().await.map_err(AppError::from)?;
+        let Some((category, relative)) = current_attachment(&mut conn, table, id).await? else {
+            return Ok(None);
+        };
+        sqlx::query_scalar(
+            "SELECT 1 FROM note_links l
+               JOIN files_index f ON f.household_id = l.household_id AND f.file_id = l.entity_id
+              WHERE l.household_id = ?1 AND l.entity_type = 'file' AND l.deleted_at IS NULL
+                AND f.category = ?2 AND f.filename = ?3
+              LIMIT 1",
+        )
+        .bind(household_id)
+        .bind(category.as_str())
+        .bind(relative)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(AppError::from)?
+    } else {
+        None
+    };
+    Ok(linked.map(|_| "note_links".to_string()))
+}
+
+/// Load the household and category a destination row would store its
+/// attachment under. Unlike `load_attachment_descriptor`, the row does not
+/// need to carry an attachment yet.
+async fn load_attachment_target(
+    pool: &SqlitePool,
+    table: &str,
+    id: &str,
+) -> AppResult<(String, AttachmentCategory)> {
+    if !ATTACHMENT_TABLES.contains(&table) && table != "pets" {
+        return Err(AppError::new(
+            "ATTACHMENT/UNSUPPORTED_TABLE",
+            "Table does not hold attachments.",
+        )
+        .with_context("table", table.to_string()));
+    }
+
+    let sql = match table {
+        "member_attachments" => {
+            "SELECT household_id, NULL AS category FROM member_attachments WHERE id = ?1"
+                .to_string()
+        }
+        "pets" => {
+            "SELECT household_id, NULL AS category FROM pets WHERE id = ?1 AND deleted_at IS NULL"
+                .to_string()
+        }
+        _ => format!(
+            "SELECT household_id, category FROM {table} WHERE id = ?1 AND deleted_at IS NULL"
+        ),
+    };
+    let row = sqlx::query(&sql)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "load_attachment_target")
+                .with_context("table", table.to_string())
+                .with_context("id", id.to_string())
+        })?;
+    let Some(row) = row else {
+        return Err(AppError::new("DB/NOT_FOUND", "Record not found")
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string()));
+    };
+
+    let household_id: String = row.try_get("household_id").unwrap_or_default();
+    let stored_category: Option<String> = row.try_get("category").ok().flatten();
+    let category = match stored_category {
+        Some(value) => AttachmentCategory::from_str(&value).map_err(|_| {
+            AppError::new(
+                crate::vault::ERR_INVALID_CATEGORY,
+                "Attachment category is not supported.",
+            )
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string())
+        })?,
+        None => AttachmentCategory::for_table(table).ok_or_else(|| {
+            AppError::new(
+                crate::vault::ERR_INVALID_CATEGORY,
+                "Attachment category is required.",
+            )
+            .with_context("table", table.to_string())
+        })?,
+    };
+    Ok((household_id, category))
+}
+
+/// Whether any attachment row other than `exclude` (a table and id) points at
+/// the vault file `category/relative` in `household_id`. Every attachment
+/// table is consulted, plus pets for pet images, and soft-deleted rows still
+/// count since restoring them brings their path back. Rows may share content
+/// but not a path, so a claimed file is never reused or removed.
+async fn attachment_path_claimed(
+    conn: &mut SqliteConnection,
+    household_id: &str,
+    category: AttachmentCategory,
+    relative: &str,
+    exclude: Option<(&str, &str)>,
+) -> AppResult<bool> {
+    for table in ATTACHMENT_TABLES.iter().copied().chain(["pets"]) {
+        let sql = match table {
+            "member_attachments" if category == AttachmentCategory::Misc => {
+                "SELECT 1 FROM member_attachments WHERE household_id = ?1 AND relative_path = ?3 AND id <> ?4 LIMIT 1"
+                    .to_string()
+            }
+            "pets" if category == AttachmentCategory::PetImage => {
+                "SELECT 1 FROM pets WHERE household_id = ?1 AND image_path = ?3 AND id <> ?4 LIMIT 1"
+                    .to_string()
+            }
+            "member_attachments" | "pets" => continue,
+            _ => format!(
+                "SELECT 1 FROM {table} WHERE household_id = ?1 AND category = ?2 AND relative_path = ?3 AND id <> ?4 LIMIT 1"
+            ),
+        };
+        let exclude_id = match exclude {
+            Some((exclude_table, id)) if exclude_table == table => id,
+            _ => "",
+        };
+        let claimed = sqlx::query_scalar::<_, i64>(&sql)
+            .bind(household_id)
+            .bind(category.as_str())
+            .bind(relative)
+            .bind(exclude_id)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "attachment_path_claimed")
+                    .with_context("table", table.to_string())
+            })?;
+        if claimed.is_some() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// The category and relative path a row's attachment currently sits at, if
+/// it has one.
+async fn current_attachment(
+    conn: &mut SqliteConnection,
+    table: &str,
+    id: &str,
+) -> AppResult<Option<(AttachmentCategory, String)>> {
+    let sql = match table {
+        "member_attachments" => {
+            "SELECT NULL AS category, relative_path FROM member_attachments WHERE id = ?1"
+                .to_string()
+        }
+        "pets" => "SELECT NULL AS category, image_path AS relative_path FROM pets WHERE id = ?1"
+            .to_string(),
+        _ => format!("SELECT category, relative_path FROM {table} WHERE id = ?1"),
+    };
+    let row = sqlx::query(&sql)
+        .bind(id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "load_current_attachment")
+                .with_context("table", table.to_string())
+        })?;
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    let relative: Option<String> = row.try_get("relative_path").ok().flatten();
+    let Some(relative) = relative.filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    let stored: Option<String> = row.try_get("category").ok().flatten();
+    let category = match stored {
+        Some(value) => AttachmentCategory::from_str(&value).ok(),
+        None => AttachmentCategory::for_table(table),
+    };
+    Ok(category.map(|category| (category, relative)))
+}
+
+/// Remove a vault file nothing points at any more and drop it from
+/// `files_index`. Failures are logged; the row update that released the file
+/// has already committed.
+async fn release_unclaimed_file(
+    pool: &SqlitePool,
+    vault: &Vault,
+    household_id: &str,
+    category: AttachmentCategory,
+    relative: &str,
+) {
+    let outcome = async {
+        let mut conn = pool.acquire().await.map_err(AppError::from)?;
+        if attachment_path_claimed(&mut conn, household_id, category, relative, None).await? {
+            return Ok(false);
+        }
+        drop(conn);
+        let path = vault.resolve(household_id, category, relative)?;
+        match fs::remove_file(&path).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(AppError::from(err).with_context("operation", "release_unclaimed_file"))
+            }
+        }
+        crate::files_indexer::forget_vault_file(pool, household_id, category, relative).await?;
+        Ok(true)
+    }
+    .await;
+    match outcome {
+        Ok(removed) => {
+            if removed {
+                tracing::info!(
+                    target = "arklowdun",
+                    event = "attachment_released_unclaimed_file",
+                    household_id = %household_id,
+                    category = %category.as_str(),
+                    relative_hash = %hash_path(Path::new(relative)),
+                );
+            }
+        }
+        Err(err) => {
+            tracing::warn!(
+                target = "arklowdun",
+                event = "attachment_release_failed",
+                household_id = %household_id,
+                category = %category.as_str(),
+                error = %err,
+            );
+        }
+    }
+}
+
+/// Bring the `files_index` row for a freshly written vault file up to date.
+/// The index is a derived cache, so a failure is logged rather than surfaced.
+async fn sync_files_index(
+    pool: &SqlitePool,
+    vault: &Vault,
+    household_id: &str,
+    category: AttachmentCategory,
+    relative: &str,
+) {
+    if let Err(err) =
+        crate::files_indexer::index_vault_file(pool, vault, household_id, category, relative).await
+    {
+        tracing::warn!(
+            target = "arklowdun",
+            event = "files_index_update_failed",
+            household_id = %household_id,
+            category = %category.as_str(),
+            error = %err,
+        );
+    }
+}
+
+pub(crate) async fn file_sha256(path: &Path) -> AppResult<String> {
+    let mut file = File::open(path)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "hash_attachment"))?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let read = file
+            .read(&mut buf)
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "hash_attachment"))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Share one row's attachment with another row in the same household. The
+/// file is copied into the destination's category under the source's
+/// relative path; when a file with identical content already sits at that
+/// path (or at one of its ` (n)` variants) and no other row in any
+/// attachment table points at it, it is reused instead of copied. A file the
+/// destination pointed at before is removed once nothing else claims it.
+pub async fn copy_attachment(
+    pool: &SqlitePool,
+    vault: &Vault,
+    src_table: &str,
+    src_id: &str,
+    dst_table: &str,
+    dst_id: &str,
+) -> AppResult<AttachmentCopyResponse> {
+    let source = crate::attachments::load_attachment_descriptor(pool, src_table, src_id)
+        .await
+        .map_err(|err| err.with_context("operation", "attachment_copy"))?;
+    let (dst_household, dst_category) = load_attachment_target(pool, dst_table, dst_id)
+        .await
+        .map_err(|err| err.with_context("operation", "attachment_copy"))?;
+
+    if dst_household != source.household_id {
+        return Err(AppError::new(
+            crate::vault::ERR_INVALID_HOUSEHOLD,
+            "Attachments can only be copied within a household.",
+        )
+        .with_context("operation", "attachment_copy")
+        .with_context("src_table", src_table.to_string())
+        .with_context("dst_table", dst_table.to_string()));
+    }
+    let household_id = source.household_id.as_str();
+
+    let source_path = vault.resolve(household_id, source.category, &source.relative_path)?;
+    if !fs::metadata(&source_path)
+        .await
+        .map(|meta| meta.is_file())
+        .unwrap_or(false)
+    {
+        return Err(AppError::new(
+            "FILE_MISSING",
+            "Source file could not be found in the vault.",
+        )
+        .with_context("table", src_table.to_string())
+        .with_context("id", src_id.to_string()));
+    }
+    let source_hash = file_sha256(&source_path).await?;
+
+    let base_target = vault.resolve(household_id, dst_category, &source.relative_path)?;
+    let mut target_path = base_target.clone();
+    let mut deduplicated = false;
+    let mut suffix = 0;
+    let mut conn = pool.acquire().await.map_err(AppError::from)?;
+    loop {
+        match fs::metadata(&target_path).await {
+            Err(_) => break,
+            Ok(meta) if meta.is_file() && file_sha256(&target_path).await? == source_hash => {
+                let claimed =
+                    match vault.relative_from_resolved(&target_path, household_id, dst_category) {
+                        Some(relative) => {
+                            attachment_path_claimed(
+                                &mut conn,
+                                household_id,
+                                dst_category,
+                                &relative,
+                                Some((dst_table, dst_id)),
+                            )
+                            .await?
+                        }
+                        None => true,
+                    };
+                if !claimed {
+                    deduplicated = true;
+                    break;
+                }
+            }
+            Ok(_) => {}
+        }
+        suffix += 1;
+        if suffix > 9999 {
+            return Err(AppError::new(
+                "CONFLICT_RESOLUTION_FAILED",
+                "Unable to resolve a unique filename for the destination.",
+            ));
+        }
+        target_path = conflict_candidate(&base_target, suffix);
+    }
+    drop(conn);
+
+    let new_relative = vault
+        .relative_from_resolved(&target_path, household_id, dst_category)
+        .ok_or_else(|| {
+            AppError::new(
+                crate::vault::ERR_PATH_OUT_OF_VAULT,
+                "Destination resolved outside the vault.",
+            )
+            .with_context("operation", "attachment_copy")
+        })?;
+
+    let staging_path = if deduplicated {
+        None
+    } else {
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent).await.map_err(|err| {
+                AppError::from(err).with_context("operation", "create_target_parent")
+            })?;
+        }
+        let staging = staging_path_for(&target_path);
+        fs::copy(&source_path, &staging).await.map_err(|err| {
+            AppError::from(err).with_context("operation", "attachment_copy_stage")
+        })?;
+        Some(staging)
+    };
+
+    // The row update and the file landing in place succeed or fail together:
+    // the staged copy is renamed only inside the open transaction.
+    let db_outcome = async {
+        let mut tx = pool.begin().await.map_err(AppError::from)?;
+        let previous = current_attachment(tx.as_mut(), dst_table, dst_id).await?;
+
+        // pets carry no category column and member_attachments has no updated_at.
+        let update_sql = format!(
+            "UPDATE {dst_table} SET relative_path = ?1, category = ?3, updated_at = ?4, \
+             content_sha256 = ?5, content_hashed_at = ?4 WHERE id = ?2"
+        );
+        let query = match dst_table {
+            "member_attachments" => sqlx::query(
+                "UPDATE member_attachments SET relative_path = ?1, content_sha256 = ?3, \
+                 content_hashed_at = ?4 WHERE id = ?2",
+            )
+            .bind(&new_relative)
+            .bind(dst_id)
+            .bind(&source_hash)
+            .bind(crate::time::now_ms()),
+            "pets" => sqlx::query("UPDATE pets SET image_path = ?1, updated_at = ?3 WHERE id = ?2")
+                .bind(&new_relative)
+                .bind(dst_id)
+                .bind(crate::time::now_ms()),
+            _ => sqlx::query(&update_sql)
+                .bind(&new_relative)
+                .bind(dst_id)
+                .bind(dst_category.as_str())
+                .bind(crate::time::now_ms())
+                .bind(&source_hash),
+        };
+        query.execute(&mut *tx).await.map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", format!("attachment_copy_update_{dst_table}"))
+        })?;
+
+        if let Some(staging) = &staging_path {
+            fs::rename(staging, &target_path).await.map_err(|err| {
+                AppError::from(err).with_context("operation", "attachment_copy_finalize")
+            })?;
+        }
+        if let Err(err) = tx.commit().await {
+            if staging_path.is_some() {
+                let _ = fs::remove_file(&target_path).await;
+            }
+            return Err(AppError::from(err));
+        }
+        Ok(previous)
+    }
+    .await;
+
+    let previous = match db_outcome {
+        Ok(previous) => previous,
+        Err(err) => {
+            if let Some(staging) = &staging_path {
+                let _ = fs::remove_file(staging).await;
+            }
+            return Err(err);
+        }
+    };
+    sync_files_index(pool, vault, household_id, dst_category, &new_relative).await;
+    if let Some((previous_category, previous_relative)) = previous {
+        if (previous_category, previous_relative.as_str()) != (dst_category, new_relative.as_str())
+        {
+            release_unclaimed_file(
+                pool,
+                vault,
+                household_id,
+                previous_category,
+                &previous_relative,
+            )
+            .await;
+        }
+    }
+
+    tracing::info!(
+        target = "arklowdun",
+        event = "attachment_copy_completed",
+        household_id = %household_id,
+        src_table = %src_table,
+        dst_table = %dst_table,
+        category = %dst_category.as_str(),
+        relative_hash = %hash_path(Path::new(&new_relative)),
+        deduplicated,
+    );
+
+    Ok(AttachmentCopyResponse {
+        relative_path: new_relative,
+        deduplicated,
+    })
+}
+
+/// Copy one row's attachment back into the vault from an export bundle.
+/// The file is looked up under its manifest key
+/// (`household/category/relative_path`) and must match the digest the bundle
+/// recorded for it. A different file already at the live path is left alone.
+pub async fn restore_attachment_from_backup(
+    pool: &SqlitePool,
+    vault: &Vault,
+    table: &str,
+    id: &str,
+    backup_path: &Path,
+) -> AppResult<AttachmentRestoreResponse> {
+    let descriptor = crate::attachments::load_attachment_descriptor(pool, table, id)
+        .await
+        .map_err(|err| err.with_context("operation", "attachment_restore_from_backup"))?;
+    let household_id = descriptor.household_id.as_str();
+    let category = descriptor.category;
+    let relative = descriptor.relative_path.as_str();
+
+    let bundle = crate::import::ImportBundle::load(backup_path).map_err(|err| {
+        AppError::from(anyhow::Error::new(err))
+            .with_context("operation", "attachment_restore_load_backup")
+            .with_context("path", backup_path.display().to_string())
+    })?;
+    let manifest_key = format!("{household_id}/{}/{relative}", category.as_str());
+    let entry = bundle
+        .attachments()
+        .iter()
+        .find(|entry| entry.relative_path == manifest_key)
+        .ok_or_else(|| {
+            AppError::new(
+                "ATTACHMENT/NOT_IN_BACKUP",
+                "The backup does not contain this attachment.",
+            )
+            .with_context("operation", "attachment_restore_from_backup")
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string())
+        })?;
+
+    let source = bundle.attachments_dir().join(&manifest_key);
+    let source_hash = file_sha256(&source).await.map_err(|err| {
+        err.with_context("operation", "attachment_restore_from_backup")
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string())
+    })?;
+    if !source_hash.eq_ignore_ascii_case(&entry.sha256) {
+        return Err(AppError::new(
+            "ATTACHMENT/BACKUP_HASH_MISMATCH",
+            "The backup copy of this attachment is corrupted.",
+        )
+        .with_context("operation", "attachment_restore_from_backup")
+        .with_context("table", table.to_string())
+        .with_context("id", id.to_string()));
+    }
+
+    let tracked = tracked_content_hash(pool, table, id).await?;
+    if let Some((tracked, _)) = tracked.as_ref() {
+        if !tracked.eq_ignore_ascii_case(&source_hash) {
+            return Err(AppError::new(
+                "ATTACHMENT/BACKUP_HASH_MISMATCH",
+                "The backup holds a different version of this attachment.",
+            )
+            .with_context("operation", "attachment_restore_from_backup")
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string()));
+        }
+    }
+
+    let target = vault.resolve(household_id, category, relative)?;
+    if fs::metadata(&target)
+        .await
+        .map(|meta| meta.is_file())
+        .unwrap_or(false)
+    {
+        if file_sha256(&target).await? == source_hash {
+            return Ok(AttachmentRestoreResponse {
+                relative_path: relative.to_string(),
+                sha256: source_hash,
+                restored: false,
+            });
+        }
+        return Err(AppError::new(
+            "ATTACHMENT/TARGET_EXISTS",
+            "A different file already exists for this attachment.",
+        )
+        .with_context("operation", "attachment_restore_from_backup")
+        .with_context("table", table.to_string())
+        .with_context("id", id.to_string()));
+    }
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "create_target_parent"))?;
+    }
+    let staging = staging_path_for(&target);
+    fs::copy(&source, &staging)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "attachment_restore_stage"))?;
+    match file_sha256(&staging).await {
+        Ok(staged_hash) if staged_hash == source_hash => {}
+        Ok(_) => {
+            let _ = fs::remove_file(&staging).await;
+            return Err(AppError::new(
+                "COPY_VERIFICATION_FAILED",
+                "Restored attachment does not match the backup copy.",
+            )
+            .with_context("operation", "attachment_restore_from_backup"));
+        }
+        Err(err) => {
+            let _ = fs::remove_file(&staging).await;
             return Err(err);
         }
-    };
+    }
+    fs::rename(&staging, &target).await.map_err(|err| {
+        AppError::from(err).with_context("operation", "attachment_restore_finalize")
+    })?;
+    if tracked.is_none() {
+        store_content_hash(pool, table, id, &source_hash).await?;
+    }
+    sync_files_index(pool, vault, household_id, category, relative).await;
 
-    let new_relative = vault
-        .relative_from_resolved(&target_path, &request.household_id, request.to_category)
-        .ok_or_else(|| {
-            AppError::new(
-                "RELATIVE_RESOLVE_FAILED",
-                "Unable to compute vault relative path for moved file.",
-            )
+    tracing::info!(
+        target = "arklowdun",
+        event = "attachment_restore_from_backup",
+        household_id = %household_id,
+        table = %table,
+        category = %category.as_str(),
+        relative_hash = %hash_path(Path::new(relative)),
+    );
+
+    Ok(AttachmentRestoreResponse {
+        relative_path: relative.to_string(),
+        sha256: source_hash,
+        restored: true,
+    })
+}
+
+/// Stored `content_sha256` and `content_hashed_at` for a row, if hashed.
+async fn tracked_content_hash(
+    pool: &SqlitePool,
+    table: &str,
+    id: &str,
+) -> AppResult<Option<(String, i64)>> {
+    if !ATTACHMENT_TABLES.contains(&table) {
+        return Ok(None);
+    }
+    let sql = format!("SELECT content_sha256, content_hashed_at FROM {table} WHERE id = ?1");
+    let row: Option<(Option<String>, Option<i64>)> = sqlx::query_as(&sql)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "attachment_tracked_hash")
+                .with_context("table", table.to_string())
+                .with_context("id", id.to_string())
         })?;
+    Ok(match row {
+        Some((Some(hash), hashed_at)) => Some((hash, hashed_at.unwrap_or(0))),
+        _ => None,
+    })
+}
 
-    let normalized_new = normalize_relative(&new_relative).map_err(|err| {
-        AppError::from(err).with_context("operation", "normalize_target_relative")
-    })?;
-    let new_relative = normalized_new.to_string_lossy().replace('\\', "/");
+async fn store_content_hash(pool: &SqlitePool, table: &str, id: &str, hash: &str) -> AppResult<()> {
+    let sql =
+        format!("UPDATE {table} SET content_sha256 = ?1, content_hashed_at = ?2 WHERE id = ?3");
+    sqlx::query(&sql)
+        .bind(hash)
+        .bind(crate::time::now_ms())
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "attachment_store_hash")
+                .with_context("table", table.to_string())
+                .with_context("id", id.to_string())
+        })?;
+    Ok(())
+}
 
-    let db_outcome = {
-        let pool = pool.clone();
-        async {
-            let mut tx = pool
-                .begin()
-                .await
-                .map_err(|err| AppError::from(err).with_context("operation", "file_move_begin_tx"))?;
+/// Content hash of a row's attachment, reusing the stored value while the
+/// file has not been modified since it was computed. Missing or stale hashes
+/// are recomputed and saved. Returns `None` when the file is not in the vault.
+pub async fn attachment_content_sha256(
+    pool: &SqlitePool,
+    vault: &Vault,
+    table: &str,
+    id: &str,
+) -> AppResult<Option<String>> {
+    let descriptor = crate::attachments::load_attachment_descriptor(pool, table, id)
+        .await
+        .map_err(|err| err.with_context("operation", "attachment_content_hash"))?;
+    let path = vault.resolve(
+        &descriptor.household_id,
+        descriptor.category,
+        &descriptor.relative_path,
+    )?;
+    let Ok(meta) = fs::metadata(&path).await else {
+        return Ok(None);
+    };
+    if !meta.is_file() {
+        return Ok(None);
+    }
+    let modified_ms = meta
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|elapsed| elapsed.as_millis() as i64);
+
+    if let Some((hash, hashed_at)) = tracked_content_hash(pool, table, id).await? {
+        if modified_ms.is_some_and(|modified| modified <= hashed_at) {
+            return Ok(Some(hash));
+        }
+    }
+    let hash = file_sha256(&path).await?;
+    if ATTACHMENT_TABLES.contains(&table) {
+        store_content_hash(pool, table, id, &hash).await?;
+    }
+    Ok(Some(hash))
+}
 
-            let mut updated_rows = 0_u32;
-            for table in ATTACHMENT_TABLES {
-                let clause = os_eq_clause("relative_path", "?5");
-                let sql = format!(
-                    "UPDATE {table} SET category = ?1, relative_path = ?2 WHERE household_id = ?3 AND category = ?4 AND {clause}"
-                );
-                let result = sqlx::query(&sql)
-                    .bind(request.to_category.as_str())
-                    .bind(&new_relative)
-                    .bind(&request.household_id)
-                    .bind(request.from_category.as_str())
-                    .bind(&from_relative)
-                    .execute(&mut *tx)
-                    .await
-                    .map_err(|err| {
-                        AppError::from(err)
-                            .with_context("operation", format!("file_move_update_{table}"))
-                    })?;
-                updated_rows += result.rows_affected() as u32;
-            }
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AttachmentHashBackfillResponse {
+    pub hashed: u64,
+    /// Rows whose file could not be read from the vault; they stay unhashed.
+    pub missing: u64,
+}
 
-            if request.to_category == AttachmentCategory::PetImage {
-                let clause = os_eq_clause("image_path", "?3");
-                let sql = format!(
-                    "UPDATE pets SET image_path = ?1 WHERE household_id = ?2 AND {clause}",
-                );
-                sqlx::query(&sql)
-                    .bind(&new_relative)
-                    .bind(&request.household_id)
-                    .bind(&from_relative)
-                    .execute(&mut *tx)
-                    .await
-                    .map_err(|err| {
-                        AppError::from(err)
-                            .with_context("operation", "file_move_update_pets")
-                    })?;
-            } else if request.from_category == AttachmentCategory::PetImage {
-                let clause = os_eq_clause("image_path", "?2");
-                let sql = format!(
-                    "UPDATE pets SET image_path = NULL WHERE household_id = ?1 AND {clause}",
-                );
-                sqlx::query(&sql)
-                    .bind(&request.household_id)
-                    .bind(&from_relative)
-                    .execute(&mut *tx)
-                    .await
-                    .map_err(|err| {
-                        AppError::from(err)
-                            .with_context("operation", "file_move_clear_pets")
-                    })?;
+/// Hash every active attachment row that has no stored `content_sha256`.
+pub async fn backfill_attachment_hashes(
+    pool: &SqlitePool,
+    vault: &Vault,
+) -> AppResult<AttachmentHashBackfillResponse> {
+    let mut response = AttachmentHashBackfillResponse::default();
+    for table in ATTACHMENT_TABLES {
+        let filter = if *table == "member_attachments" {
+            "content_sha256 IS NULL AND relative_path <> ''"
+        } else {
+            "content_sha256 IS NULL AND relative_path <> '' AND deleted_at IS NULL"
+        };
+        let sql = format!("SELECT id FROM {table} WHERE {filter} ORDER BY id");
+        let ids: Vec<String> = sqlx::query_scalar(&sql)
+            .fetch_all(pool)
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "attachments_backfill_hashes")
+                    .with_context("table", table.to_string())
+            })?;
+        for id in ids {
+            match attachment_content_sha256(pool, vault, table, &id).await {
+                Ok(Some(_)) => response.hashed += 1,
+                Ok(None) => response.missing += 1,
+                Err(err) => {
+                    tracing::warn!(
+                        target: "arklowdun",
+                        event = "attachment_hash_backfill_skipped",
+                        table = %table,
+                        code = %err.code(),
+                    );
+                    response.missing += 1;
+                }
             }
-            let new_index_name = index_basename(&new_relative);
-            let from_index_name = index_basename(&from_relative);
-            let files_index_clause = os_eq_clause("filename", "?5");
-            let files_index_sql = format!(
-                "UPDATE files_index SET category = ?1, filename = ?2 WHERE household_id = ?3 AND category = ?4 AND {files_index_clause}"
-            );
-            let files_index_result = sqlx::query(&files_index_sql)
-                .bind(request.to_category.as_str())
-                .bind(&new_index_name)
-                .bind(&request.household_id)
-                .bind(request.from_category.as_str())
-                .bind(&from_index_name)
-                .execute(&mut *tx)
-                .await
-                .map_err(|err| {
-                    AppError::from(err).with_context("operation", "file_move_update_files_index")
-                })?;
+            yield_now().await;
+        }
+    }
+    Ok(response)
+}
 
-            updated_rows += files_index_result.rows_affected() as u32;
+/// Tables a bulk import can create rows in. Each needs no more than a file
+/// to produce a valid row; the rest are filled in by the user afterwards.
+/// Bills, policies and property documents are left out: an amount, due date
+/// or renewal date cannot be guessed from a file, and a placeholder would
+/// show up as a real deadline.
+const IMPORT_DIR_TABLES: &[&str] = &["inventory_items"];
+
+/// File types accepted by bulk imports, by lowercase extension.
+const IMPORT_ALLOWED_EXTENSIONS: &[&str] = &[
+    "pdf", "png", "jpg", "jpeg", "gif", "webp", "heic", "tif", "tiff", "txt", "md", "csv", "rtf",
+    "doc", "docx", "xls", "xlsx", "odt", "ods",
+];
+
+/// Largest single file a bulk import will copy into the vault.
+const IMPORT_MAX_FILE_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Total bytes a household's vault may hold once an import has finished.
+const HOUSEHOLD_ATTACHMENT_QUOTA_BYTES: u64 = 10 * 1024 * 1024 * 1024;
+
+fn household_vault_usage(vault: &Vault, household_id: &str) -> u64 {
+    walkdir::WalkDir::new(vault.base().join(household_id))
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|meta| meta.len())
+        .sum()
+}
 
-            tx.commit()
-                .await
-                .map_err(|err| AppError::from(err).with_context("operation", "file_move_commit"))?;
+/// Row insert for a bulk-imported file into one of [`IMPORT_DIR_TABLES`].
+/// Binds: id, label, household, now, position, relative path, category.
+const IMPORT_INSERT_SQL: &str = "INSERT INTO inventory_items (id, name, household_id, created_at, updated_at, position, relative_path, category) VALUES (?1, ?2, ?3, ?4, ?4, ?5, ?6, ?7)";
+
+fn import_rejection(source: String, err: AppError) -> AttachmentImportFileResult {
+    AttachmentImportFileResult {
+        source,
+        status: AttachmentImportStatus::Rejected,
+        id: None,
+        relative_path: None,
+        code: Some(err.code().to_string()),
+        message: Some(err.message().to_string()),
+    }
+}
 
-            Ok::<u32, AppError>(updated_rows)
+/// Copy every file under `source_dir` into the household's vault and create
+/// one `table` row per file, keeping each file's path relative to
+/// `source_dir`. Files that fail the type allowlist, the size and quota
+/// limits or the vault guard are reported and skipped; the rest of the batch
+/// carries on.
+pub async fn import_attachments_dir(
+    pool: &SqlitePool,
+    vault: &Vault,
+    table: &str,
+    household_id: &str,
+    category: AttachmentCategory,
+    source_dir: &Path,
+) -> AppResult<AttachmentsImportDirResponse> {
+    if !IMPORT_DIR_TABLES.contains(&table) {
+        return Err(AppError::new(
+            "ATTACHMENT/UNSUPPORTED_TABLE",
+            "Bulk import does not support this table.",
+        )
+        .with_context("table", table.to_string()));
+    }
+    match fs::metadata(source_dir).await {
+        Ok(meta) if meta.is_dir() => {}
+        _ => {
+            return Err(AppError::new(
+                "ATTACHMENT/SOURCE_NOT_DIRECTORY",
+                "Import source must be an existing directory.",
+            )
+            .with_context("operation", "attachments_import_dir"));
         }
-        .await
-    };
+    }
 
-    let updated_rows = match db_outcome {
-        Ok(rows) => rows,
-        Err(err) => {
-            if let Err(rollback_err) = prepared_move.rollback(&source_path).await {
-                tracing::error!(
-                    target = "arklowdun",
-                    event = "file_move_rollback_failed",
-                    household_id = %request.household_id,
-                    error = %rollback_err,
-                );
+    let mut usage = household_vault_usage(vault, household_id);
+    let mut position: i64 = sqlx::query_scalar(&format!(
+        "SELECT COALESCE(MAX(position), -1) + 1 FROM {table} WHERE household_id = ?1 AND deleted_at IS NULL"
+    ))
+    .bind(household_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|err| AppError::from(err).with_context("operation", "attachments_import_position"))?;
+
+    let mut files = Vec::new();
+    let mut imported = 0_u64;
+    let mut rejected = 0_u64;
+
+    let entries = walkdir::WalkDir::new(source_dir)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter();
+    for entry in entries {
+        // An unreadable directory or entry is reported like any other
+        // rejection instead of silently shrinking the batch.
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                let source = err
+                    .path()
+                    .map(|path| {
+                        path.strip_prefix(source_dir)
+                            .unwrap_or(path)
+                            .to_string_lossy()
+                            .replace('\\', "/")
+                    })
+                    .unwrap_or_default();
+                let err = match err.into_io_error() {
+                    Some(io) => AppError::from(io),
+                    None => AppError::new(
+                        "ATTACHMENT/SOURCE_UNREADABLE",
+                        "Part of the import source could not be read.",
+                    ),
+                };
+                files.push(import_rejection(
+                    source,
+                    err.with_context("operation", "attachments_import_walk"),
+                ));
+                rejected += 1;
+                continue;
             }
-            return Err(err);
+        };
+        if entry.file_type().is_dir() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(source_dir)
+            .unwrap_or(entry.path())
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        if entry.path_is_symlink() {
+            files.push(import_rejection(
+                relative,
+                AppError::new(
+                    crate::vault::ERR_SYMLINK_DENIED,
+                    "Attachments cannot traverse through symlinks.",
+                ),
+            ));
+            rejected += 1;
+            continue;
         }
-    };
 
-    prepared_move
-        .finalize(&source_path, &target_path)
-        .await
-        .map_err(|err| AppError::from(err).with_context("operation", "file_move_finalize"))?;
+        let extension = entry
+            .path()
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default();
+        if !IMPORT_ALLOWED_EXTENSIONS.contains(&extension.as_str()) {
+            files.push(import_rejection(
+                relative,
+                AppError::new(
+                    "ATTACHMENT/TYPE_NOT_ALLOWED",
+                    "This file type cannot be attached.",
+                ),
+            ));
+            rejected += 1;
+            continue;
+        }
 
-    emit_move_progress(&app, "completed", &new_relative, 1, 1);
+        let size = entry.metadata().map(|meta| meta.len()).unwrap_or(0);
+        if size > IMPORT_MAX_FILE_BYTES {
+            files.push(import_rejection(
+                relative,
+                AppError::new("ATTACHMENT/FILE_TOO_LARGE", "File exceeds the size limit."),
+            ));
+            rejected += 1;
+            continue;
+        }
+        if usage + size > HOUSEHOLD_ATTACHMENT_QUOTA_BYTES {
+            files.push(import_rejection(
+                relative,
+                AppError::new(
+                    "ATTACHMENT/QUOTA_EXCEEDED",
+                    "Household attachment storage is full.",
+                ),
+            ));
+            rejected += 1;
+            continue;
+        }
+
+        match import_one_file(
+            pool,
+            vault,
+            table,
+            household_id,
+            category,
+            entry.path(),
+            &relative,
+            position,
+        )
+        .await
+        {
+            Ok((id, relative_path)) => {
+                files.push(AttachmentImportFileResult {
+                    source: relative,
+                    status: AttachmentImportStatus::Imported,
+                    id: Some(id),
+                    relative_path: Some(relative_path),
+                    code: None,
+                    message: None,
+                });
+                imported += 1;
+                usage += size;
+                position += 1;
+            }
+            Err(err) => {
+                files.push(import_rejection(relative, err));
+                rejected += 1;
+            }
+        }
+    }
 
     tracing::info!(
         target = "arklowdun",
-        event = "file_move_completed",
-        household_id = %request.household_id,
-        from_category = %request.from_category.as_str(),
-        from_relative_hash = %from_hash,
-        to_category = %request.to_category.as_str(),
-        to_relative_hash = %hash_path(Path::new(&new_relative)),
-        rows_updated = updated_rows,
-        renamed = renamed_due_to_conflict,
+        event = "attachments_import_dir_completed",
+        household_id = %household_id,
+        table = %table,
+        category = %category.as_str(),
+        imported,
+        rejected,
     );
 
-    schedule_index_rebuild(&app, &request.household_id);
-
-    Ok(FileMoveResponse {
-        moved: updated_rows,
-        renamed: renamed_due_to_conflict,
+    Ok(AttachmentsImportDirResponse {
+        imported,
+        rejected,
+        files,
     })
 }
 
+#[allow(clippy::too_many_arguments)]
+async fn import_one_file(
+    pool: &SqlitePool,
+    vault: &Vault,
+    table: &str,
+    household_id: &str,
+    category: AttachmentCategory,
+    source: &Path,
+    relative: &str,
+    position: i64,
+) -> AppResult<(String, String)> {
+    let target = vault.resolve(household_id, category, relative)?;
+    let relative_path = vault
+        .relative_from_resolved(&target, household_id, category)
+        .unwrap_or_else(|| relative.to_string());
+    if target.exists() {
+        return Err(AppError::new(
+            "FILE_EXISTS",
+            "Destination file already exists.",
+        ));
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "create_target_parent"))?;
+    }
+
+    let staging = staging_path_for(&target);
+    fs::copy(source, &staging)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "attachments_import_copy"))?;
+
+    let id = Uuid::now_v7().to_string();
+    let label = source
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or("Imported file");
+    let inserted = sqlx::query(IMPORT_INSERT_SQL)
+        .bind(&id)
+        .bind(label)
+        .bind(household_id)
+        .bind(crate::time::now_ms())
+        .bind(position)
+        .bind(&relative_path)
+        .bind(category.as_str())
+        .execute(pool)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "attachments_import_insert"));
+    if let Err(err) = inserted {
+        let _ = fs::remove_file(&staging).await;
+        return Err(err);
+    }
+
+    if let Err(err) = fs::rename(&staging, &target).await {
+        let _ = fs::remove_file(&staging).await;
+        let _ = sqlx::query(&format!("DELETE FROM {table} WHERE id = ?1"))
+            .bind(&id)
+            .execute(pool)
+            .await;
+        return Err(AppError::from(err).with_context("operation", "attachments_import_finalize"));
+    }
+    sync_files_index(pool, vault, household_id, category, &relative_path).await;
+
+    Ok((id, relative_path))
+}
+
 pub async fn attachments_repair<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     pool: SqlitePool,
@@ -1232,22 +3142,24 @@ fn emit_repair_progress<R: tauri::Runtime>(
     }
 }
 
-fn resolve_conflict_name(target: &Path) -> AppResult<PathBuf> {
+/// `target` with ` (suffix)` appended to its file stem.
+fn conflict_candidate(target: &Path, suffix: u32) -> PathBuf {
     let parent = target.parent().map(Path::to_path_buf).unwrap_or_default();
     let stem = target
         .file_stem()
         .and_then(OsStr::to_str)
         .filter(|s| !s.is_empty())
-        .map(ToString::to_string)
-        .unwrap_or_else(|| "file".to_string());
-    let extension = target.extension().and_then(OsStr::to_str);
+        .unwrap_or("file");
+    match target.extension().and_then(OsStr::to_str) {
+        Some(ext) => parent.join(format!("{stem} ({suffix}).{ext}")),
+        None => parent.join(format!("{stem} ({suffix})")),
+    }
+}
 
+#[allow(clippy::result_large_err)]
+fn resolve_conflict_name(target: &Path) -> AppResult<PathBuf> {
     for suffix in 1..=9999 {
-        let candidate = if let Some(ext) = extension {
-            parent.join(format!("{stem} ({suffix}).{ext}"))
-        } else {
-            parent.join(format!("{stem} ({suffix})"))
-        };
+        let candidate = conflict_candidate(target, suffix);
         match std::fs::metadata(&candidate) {
             Ok(_) => continue,
             Err(err) => {
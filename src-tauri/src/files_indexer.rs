@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use chrono::{SecondsFormat, Utc};
 use infer::Infer;
@@ -14,6 +14,7 @@ use walkdir::WalkDir;
 
 use crate::attachment_category::AttachmentCategory;
 use crate::vault::Vault;
+use crate::vault_migration::ATTACHMENT_TABLES;
 use crate::{AppError, AppResult};
 
 const PROGRESS_BATCH: u64 = 25;
@@ -131,6 +132,10 @@ impl FilesIndexer {
         let vault = self.vault.clone();
         let cancel = self.cancel_token.clone();
 
+        // Taken before the walk, so rows changed while it runs leave the
+        // index reported as stale.
+        let (source_count, source_max_updated) = source_stats(&pool, &household).await?;
+
         let mut conn = pool.acquire().await?;
         let mut existing: HashMap<(String, String), ExistingRow> = HashMap::new();
         let rows = sqlx::query(
@@ -162,7 +167,6 @@ impl FilesIndexer {
         let mut batch_progress = IndexProgress::default();
         let mut seen: HashSet<(String, String)> = HashSet::new();
         let mut ordinal: i64 = 0;
-        let mut max_modified: Option<i64> = None;
 
         let base = vault.base().join(&household);
         if !base.exists() {
@@ -309,10 +313,6 @@ impl FilesIndexer {
                 updated += 1;
                 batch_progress.updated += 1;
                 seen.insert(key.clone());
-                if let Some(modified) = modified_at {
-                    max_modified =
-                        Some(max_modified.map_or(modified, |existing| existing.max(modified)));
-                }
                 maybe_emit(&mut tx, &mut batch_progress).await?;
             }
         }
@@ -334,21 +334,22 @@ impl FilesIndexer {
 
         let total = seen.len() as u64;
         let duration_ms = start.elapsed().as_millis() as u64;
-        let max_updated_iso = max_modified
-            .and_then(|value| Some(iso_from_epoch(value)))
-            .unwrap_or_else(|| "1970-01-01T00:00:00Z".to_string());
 
-        let last_built = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
-        sqlx::query(
-            "INSERT INTO files_index_meta (household_id, last_built_at_utc, source_row_count, source_max_updated_utc, version)\n             VALUES (?1, ?2, ?3, ?4, ?5)\n             ON CONFLICT(household_id) DO UPDATE SET\n               last_built_at_utc=excluded.last_built_at_utc,\n               source_row_count=excluded.source_row_count,\n               source_max_updated_utc=excluded.source_max_updated_utc,\n               version=excluded.version",
-        )
-        .bind(&household)
-        .bind(&last_built)
-        .bind(total as i64)
-        .bind(&max_updated_iso)
-        .bind(crate::FILES_INDEX_VERSION)
-        .execute(conn.as_mut())
-        .await?;
+        // A cancelled walk leaves the index partial, so its meta is not
+        // advanced and readiness keeps asking for a rebuild.
+        if !cancel.load(Ordering::SeqCst) {
+            let last_built = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+            sqlx::query(
+                "INSERT INTO files_index_meta (household_id, last_built_at_utc, source_row_count, source_max_updated_utc, version)\n             VALUES (?1, ?2, ?3, ?4, ?5)\n             ON CONFLICT(household_id) DO UPDATE SET\n               last_built_at_utc=excluded.last_built_at_utc,\n               source_row_count=excluded.source_row_count,\n               source_max_updated_utc=excluded.source_max_updated_utc,\n               version=excluded.version",
+            )
+            .bind(&household)
+            .bind(&last_built)
+            .bind(source_count)
+            .bind(&source_max_updated)
+            .bind(crate::FILES_INDEX_VERSION)
+            .execute(conn.as_mut())
+            .await?;
+        }
         drop(conn);
 
         if cancel.load(Ordering::SeqCst) {
@@ -398,6 +399,148 @@ impl FilesIndexer {
     }
 }
 
+/// Upsert the `files_index` row for one vault file after an attachment write
+/// so the index stays current without a rebuild. The file keeps its id and
+/// ordinal when it is already indexed.
+pub(crate) async fn index_vault_file(
+    pool: &SqlitePool,
+    vault: &Vault,
+    household_id: &str,
+    category: AttachmentCategory,
+    relative: &str,
+) -> AppResult<()> {
+    let path = vault.resolve(household_id, category, relative)?;
+    let metadata = tokio::fs::metadata(&path)
+        .await
+        .map_err(|err| AppError::from(err).with_context("operation", "files_index_update"))?;
+    let size = metadata.len() as i64;
+    let modified_at = metadata.modified().ok().and_then(to_epoch_seconds);
+    let key = (category.as_str().to_string(), relative.to_string());
+
+    let existing = sqlx::query(
+        "SELECT file_id, ordinal FROM files_index WHERE household_id=?1 AND category=?2 AND filename=?3",
+    )
+    .bind(household_id)
+    .bind(&key.0)
+    .bind(&key.1)
+    .fetch_optional(pool)
+    .await?;
+    let (file_id, ordinal) = match existing {
+        Some(row) => (row.try_get("file_id")?, row.try_get("ordinal")?),
+        None => {
+            let next: i64 = sqlx::query_scalar(
+                "SELECT COALESCE(MAX(ordinal), -1) + 1 FROM files_index WHERE household_id=?1",
+            )
+            .bind(household_id)
+            .fetch_one(pool)
+            .await?;
+            (derive_file_id(&key), next)
+        }
+    };
+    let mime = detect_mime(&Infer::new(), &path);
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    sqlx::query(
+        "INSERT INTO files_index\n         (household_id, file_id, category, filename, updated_at_utc, ordinal, score_hint, size_bytes, mime, modified_at_utc, sha256)\n         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, ?7, ?8, ?9, NULL)\n         ON CONFLICT(household_id, category, filename)\n         DO UPDATE SET\n           updated_at_utc=excluded.updated_at_utc,\n           size_bytes=excluded.size_bytes,\n           mime=excluded.mime,\n           modified_at_utc=excluded.modified_at_utc",
+    )
+    .bind(household_id)
+    .bind(&file_id)
+    .bind(&key.0)
+    .bind(&key.1)
+    .bind(&now)
+    .bind::<i64>(ordinal)
+    .bind(size)
+    .bind(&mime)
+    .bind(modified_at)
+    .execute(pool)
+    .await?;
+
+    refresh_meta(pool, household_id).await
+}
+
+/// Drop the `files_index` row for a vault file that no longer exists.
+pub(crate) async fn forget_vault_file(
+    pool: &SqlitePool,
+    household_id: &str,
+    category: AttachmentCategory,
+    relative: &str,
+) -> AppResult<()> {
+    sqlx::query("DELETE FROM files_index WHERE household_id=?1 AND category=?2 AND filename=?3")
+        .bind(household_id)
+        .bind(category.as_str())
+        .bind(relative)
+        .execute(pool)
+        .await?;
+    refresh_meta(pool, household_id).await
+}
+
+/// Record the current attachment rows as indexed after a single-file update.
+/// Meta rows written by an older index version are left alone so they still
+/// require a rebuild.
+async fn refresh_meta(pool: &SqlitePool, household_id: &str) -> AppResult<()> {
+    let (count, max_updated) = source_stats(pool, household_id).await?;
+    sqlx::query(
+        "UPDATE files_index_meta SET source_row_count=?2, source_max_updated_utc=?3 WHERE household_id=?1 AND version=?4",
+    )
+    .bind(household_id)
+    .bind(count)
+    .bind(&max_updated)
+    .bind(crate::FILES_INDEX_VERSION)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Row count and latest change of the rows `files_index` is built from: the
+/// live attachment rows of every table in `ATTACHMENT_TABLES`. The meta
+/// stores this at build time and the index is ready while it still matches.
+pub(crate) async fn source_stats(
+    pool: &SqlitePool,
+    household_id: &str,
+) -> AppResult<(i64, String)> {
+    let tables: Vec<String> =
+        sqlx::query_scalar("SELECT name FROM sqlite_master WHERE type='table'")
+            .fetch_all(pool)
+            .await?;
+    // Older schemas tracked vault files in a `files` table stamped in seconds.
+    if tables.iter().any(|name| name == "files") {
+        let stats = sqlx::query_as::<_, (i64, String)>(
+            "SELECT COUNT(*), COALESCE(strftime('%Y-%m-%dT%H:%M:%SZ', MAX(updated_at), 'unixepoch'), '1970-01-01T00:00:00Z') FROM files WHERE household_id=?1",
+        )
+        .bind(household_id)
+        .fetch_one(pool)
+        .await?;
+        return Ok(stats);
+    }
+
+    let selects: Vec<String> = ATTACHMENT_TABLES
+        .iter()
+        .filter(|table| tables.iter().any(|name| name == *table))
+        .map(|table| match *table {
+            // Member attachments are hard-deleted and never edited in place.
+            "member_attachments" => {
+                "SELECT added_at AS changed_at FROM member_attachments WHERE household_id=?1"
+                    .to_string()
+            }
+            table => format!(
+                "SELECT updated_at AS changed_at FROM {table} WHERE household_id=?1 AND deleted_at IS NULL AND relative_path IS NOT NULL"
+            ),
+        })
+        .collect();
+    if selects.is_empty() {
+        return Ok((0, "1970-01-01T00:00:00Z".to_string()));
+    }
+    let sql = format!(
+        "SELECT COUNT(*), COALESCE(strftime('%Y-%m-%dT%H:%M:%fZ', MAX(changed_at) / 1000.0, 'unixepoch'), '1970-01-01T00:00:00Z') FROM ({})",
+        selects.join(" UNION ALL ")
+    );
+    let stats = sqlx::query_as::<_, (i64, String)>(&sql)
+        .bind(household_id)
+        .fetch_one(pool)
+        .await?;
+    Ok(stats)
+}
+
 fn relative_filename(base: &Path, path: &Path) -> Option<String> {
     let relative = path.strip_prefix(base).ok()?;
     let mut parts = Vec::new();
@@ -428,12 +571,6 @@ fn to_epoch_seconds(time: SystemTime) -> Option<i64> {
         .map(|dur| dur.as_secs() as i64)
 }
 
-fn iso_from_epoch(epoch: i64) -> String {
-    let dt = UNIX_EPOCH + Duration::from_secs(epoch as u64);
-    let datetime: chrono::DateTime<Utc> = dt.into();
-    datetime.to_rfc3339_opts(SecondsFormat::Secs, true)
-}
-
 fn detect_mime(infer_engine: &Infer, path: &PathBuf) -> String {
     if let Ok(Some(kind)) = infer_engine.get_from_path(path) {
         return kind.mime_type().to_string();
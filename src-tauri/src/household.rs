@@ -14,6 +14,7 @@ use std::time::{Duration, Instant};
 use tokio::time::sleep;
 use walkdir::WalkDir;
 
+use crate::deletions_audit::{record_deletion, OPERATION_SOFT_DELETE};
 use crate::id::new_uuid_v7;
 use crate::repo::admin;
 use crate::security::hash_path;
@@ -215,18 +216,6 @@ pub enum HouseholdCrudError {
     Unexpected(#[from] anyhow::Error),
 }
 
-fn is_valid_hex_color(value: &str) -> bool {
-    if value.len() != 7 {
-        return false;
-    }
-    let mut chars = value.chars();
-    match chars.next() {
-        Some('#') => {}
-        _ => return false,
-    }
-    chars.all(|c| matches!(c, '0'..='9' | 'a'..='f' | 'A'..='F'))
-}
-
 fn normalize_color_value(value: Option<&str>) -> Result<Option<String>, HouseholdCrudError> {
     match value {
         None => Ok(None),
@@ -235,10 +224,9 @@ fn normalize_color_value(value: Option<&str>) -> Result<Option<String>, Househol
             if trimmed.is_empty() {
                 return Ok(None);
             }
-            if !is_valid_hex_color(trimmed) {
-                return Err(HouseholdCrudError::InvalidColor);
-            }
-            Ok(Some(trimmed.to_uppercase()))
+            crate::util::normalize_hex_color(trimmed)
+                .map(Some)
+                .ok_or(HouseholdCrudError::InvalidColor)
         }
     }
 }
@@ -272,6 +260,23 @@ pub struct CascadeProgress {
     pub phase_total: usize,
 }
 
+/// Rows a single cascade phase is expected to delete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CascadePhaseCount {
+    pub phase: String,
+    pub rows: u64,
+}
+
+/// Pre-computed size of a household delete, so progress can be rendered
+/// before the cascade starts. `total_expected` includes the household row and
+/// matches the total the cascade itself reports for its database phases.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CascadeRowCounts {
+    pub household_id: String,
+    pub phases: Vec<CascadePhaseCount>,
+    pub total_expected: u64,
+}
+
 pub type CascadeProgressObserver = Arc<dyn Fn(CascadeProgress) + Send + Sync + 'static>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -352,6 +357,10 @@ const CASCADE_PHASES: &[CascadeTablePhase] = &[
         name: "notes",
         table: "notes",
     },
+    CascadeTablePhase {
+        name: "event_attendees",
+        table: "event_attendees",
+    },
     CascadeTablePhase {
         name: "events",
         table: "events",
@@ -364,6 +373,18 @@ const CASCADE_PHASES: &[CascadeTablePhase] = &[
         name: "files_index_meta",
         table: "files_index_meta",
     },
+    CascadeTablePhase {
+        name: "household_stats",
+        table: "household_stats",
+    },
+    CascadeTablePhase {
+        name: "expense_template_runs",
+        table: "expense_template_runs",
+    },
+    CascadeTablePhase {
+        name: "expense_templates",
+        table: "expense_templates",
+    },
     CascadeTablePhase {
         name: "expenses",
         table: "expenses",
@@ -634,24 +655,39 @@ async fn enqueue_vacuum(pool: &SqlitePool, household_id: &str) -> Result<(), Hou
     Ok(())
 }
 
-async fn compute_total_rows(
+pub async fn count_cascade_rows(
     pool: &SqlitePool,
     household_id: &str,
-) -> Result<i64, HouseholdCrudError> {
-    let mut total = 1i64; // account for household row
-    for phase in CASCADE_PHASES {
-        let sql = format!(
-            "SELECT COUNT(*) FROM {} WHERE household_id = ?1",
-            phase.table
-        );
+) -> Result<CascadeRowCounts, HouseholdCrudError> {
+    let mut phases = Vec::with_capacity(CASCADE_PHASES.len());
+    let mut total_expected = 1u64; // account for household row
+    for table in cascade_phase_tables() {
+        let sql = format!("SELECT COUNT(*) FROM {table} WHERE household_id = ?1");
         let count: i64 = sqlx::query_scalar(&sql)
             .bind(household_id)
             .fetch_one(pool)
             .await
             .map_err(|err| HouseholdCrudError::Unexpected(err.into()))?;
-        total += count;
+        let rows = count.max(0) as u64;
+        total_expected += rows;
+        phases.push(CascadePhaseCount {
+            phase: table.to_string(),
+            rows,
+        });
     }
-    Ok(total)
+    Ok(CascadeRowCounts {
+        household_id: household_id.to_string(),
+        phases,
+        total_expected,
+    })
+}
+
+async fn compute_total_rows(
+    pool: &SqlitePool,
+    household_id: &str,
+) -> Result<i64, HouseholdCrudError> {
+    let counts = count_cascade_rows(pool, household_id).await?;
+    Ok(counts.total_expected as i64)
 }
 
 async fn ensure_tables_empty(
@@ -1070,11 +1106,155 @@ pub async fn create_household(
     fetch_details(pool, &id).await
 }
 
+/// Categories offered to a new household, mirroring the baseline seed for
+/// the default household: `(name, slug, color)`.
+const DEFAULT_CATEGORIES: [(&str, &str, &str); 12] = [
+    ("Primary", "primary", "#4F46E5"),
+    ("Secondary", "secondary", "#1D4ED8"),
+    ("Tasks", "tasks", "#0EA5E9"),
+    ("Bills", "bills", "#F59E0B"),
+    ("Insurance", "insurance", "#EA580C"),
+    ("Property", "property", "#F97316"),
+    ("Vehicles", "vehicles", "#22C55E"),
+    ("Pets", "pets", "#16A34A"),
+    ("Family", "family", "#EF4444"),
+    ("Inventory", "inventory", "#C026D3"),
+    ("Budget", "budget", "#A855F7"),
+    ("Shopping", "shopping", "#6366F1"),
+];
+
+const DEFAULT_BUDGET_CATEGORIES: [&str; 8] = [
+    "Groceries",
+    "Housing",
+    "Utilities",
+    "Transport",
+    "Insurance",
+    "Healthcare",
+    "Entertainment",
+    "Savings",
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeedDefaultsReport {
+    pub categories_created: u32,
+    pub budget_categories_created: u32,
+}
+
+/// Insert the default `categories` and `budget_categories` for a household.
+/// Names (case-insensitive) and slugs that already exist on live rows are
+/// skipped, so running this more than once is harmless. New rows are appended
+/// after the highest live position.
+pub async fn seed_household_defaults(
+    pool: &SqlitePool,
+    household_id: &str,
+) -> Result<SeedDefaultsReport, HouseholdCrudError> {
+    let status = fetch_status(pool, household_id).await?;
+    if status.deleted_at.is_some() {
+        return Err(HouseholdCrudError::Deleted);
+    }
+
+    let unexpected = |err: SqlxError| HouseholdCrudError::Unexpected(err.into());
+    let now = now_ms();
+    let mut report = SeedDefaultsReport::default();
+    let mut tx = pool.begin().await.map_err(unexpected)?;
+
+    let existing: Vec<(String, String)> = sqlx::query_as(
+        "SELECT lower(name), slug FROM categories WHERE household_id = ?1 AND deleted_at IS NULL",
+    )
+    .bind(household_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(unexpected)?;
+    let mut taken_names: HashSet<String> = existing.iter().map(|(name, _)| name.clone()).collect();
+    let mut taken_slugs: HashSet<String> = existing.into_iter().map(|(_, slug)| slug).collect();
+    let mut position: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(MAX(position) + 1, 0) FROM categories WHERE household_id = ?1 AND deleted_at IS NULL",
+    )
+    .bind(household_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(unexpected)?;
+
+    for (name, slug, color) in DEFAULT_CATEGORIES {
+        if taken_names.contains(&name.to_lowercase()) || taken_slugs.contains(slug) {
+            continue;
+        }
+        sqlx::query(
+            "INSERT INTO categories (id, household_id, name, slug, color, position, z, is_visible, created_at, updated_at, deleted_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, 1, ?7, ?7, NULL)",
+        )
+        .bind(new_uuid_v7())
+        .bind(household_id)
+        .bind(name)
+        .bind(slug)
+        .bind(color)
+        .bind(position)
+        .bind(now)
+        .execute(&mut *tx)
+        .await
+        .map_err(unexpected)?;
+        taken_names.insert(name.to_lowercase());
+        taken_slugs.insert(slug.to_string());
+        position += 1;
+        report.categories_created += 1;
+    }
+
+    let mut taken_budget: HashSet<String> = sqlx::query_scalar::<_, String>(
+        "SELECT lower(name) FROM budget_categories WHERE household_id = ?1 AND deleted_at IS NULL",
+    )
+    .bind(household_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(unexpected)?
+    .into_iter()
+    .collect();
+    let mut budget_position: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(MAX(position) + 1, 0) FROM budget_categories WHERE household_id = ?1 AND deleted_at IS NULL",
+    )
+    .bind(household_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(unexpected)?;
+
+    for name in DEFAULT_BUDGET_CATEGORIES {
+        if !taken_budget.insert(name.to_lowercase()) {
+            continue;
+        }
+        sqlx::query(
+            "INSERT INTO budget_categories (id, name, monthly_budget, household_id, created_at, updated_at, deleted_at, position)
+             VALUES (?1, ?2, NULL, ?3, ?4, ?4, NULL, ?5)",
+        )
+        .bind(new_uuid_v7())
+        .bind(name)
+        .bind(household_id)
+        .bind(now)
+        .bind(budget_position)
+        .execute(&mut *tx)
+        .await
+        .map_err(unexpected)?;
+        budget_position += 1;
+        report.budget_categories_created += 1;
+    }
+
+    tx.commit().await.map_err(unexpected)?;
+    info!(
+        target: "arklowdun",
+        event = "household_seed_defaults",
+        household_id = %household_id,
+        categories_created = report.categories_created,
+        budget_categories_created = report.budget_categories_created
+    );
+    Ok(report)
+}
+
 pub struct HouseholdUpdateInput<'a> {
     pub name: Option<&'a str>,
     pub color: Option<Option<&'a str>>,
 }
 
+/// Rename or recolour a household. Only the `household` row changes: vault
+/// paths and attachment rows are keyed by household id, so a rename never
+/// moves files or leaves references behind.
 pub async fn update_household(
     pool: &SqlitePool,
     id: &str,
@@ -1163,10 +1343,20 @@ pub async fn delete_household(
 
     if checkpoint.is_none() {
         let now = now_ms();
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|err| HouseholdCrudError::Unexpected(err.into()))?;
         sqlx::query("UPDATE household SET deleted_at = ?1, updated_at = ?1 WHERE id = ?2")
             .bind(now)
             .bind(id)
-            .execute(pool)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| HouseholdCrudError::Unexpected(err.into()))?;
+        record_deletion(&mut *tx, "household", id, id, OPERATION_SOFT_DELETE, now)
+            .await
+            .map_err(|err| HouseholdCrudError::Unexpected(err.into()))?;
+        tx.commit()
             .await
             .map_err(|err| HouseholdCrudError::Unexpected(err.into()))?;
 
@@ -1515,6 +1705,51 @@ pub async fn pending_cascades(
     Ok(checkpoints)
 }
 
+/// Clear checkpoints whose cascade has in fact finished.
+///
+/// A checkpoint is only treated as stale when the household row is gone, no
+/// cascade table holds rows for it any more and, with filesystem cleanup
+/// enabled, its vault directory no longer exists. Anything short of that is a
+/// genuinely unfinished cascade and is left for `resume_household_delete`.
+/// Returns the household ids whose checkpoints were cleared.
+pub async fn reconcile_cascade_checkpoints(
+    pool: &SqlitePool,
+    vault: &Vault,
+) -> Result<Vec<String>, HouseholdCrudError> {
+    let mut cleared = Vec::new();
+    for checkpoint in pending_cascades(pool).await? {
+        let household_id = checkpoint.household_id.as_str();
+        let household_rows: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM household WHERE id = ?1")
+                .bind(household_id)
+                .fetch_one(pool)
+                .await
+                .map_err(|err| HouseholdCrudError::Unexpected(err.into()))?;
+        if household_rows > 0 {
+            continue;
+        }
+        // compute_total_rows counts the household row itself.
+        if compute_total_rows(pool, household_id).await? > 1 {
+            continue;
+        }
+        if fs_cascade_enabled() && vault.base().join(household_id).exists() {
+            continue;
+        }
+
+        enqueue_vacuum(pool, household_id).await?;
+        clear_checkpoint(pool, household_id).await?;
+        info!(
+            target: "arklowdun",
+            event = "cascade_checkpoint_reconciled",
+            household_id = %household_id,
+            phase = %checkpoint.phase,
+            deleted = checkpoint.deleted_count,
+        );
+        cleared.push(checkpoint.household_id);
+    }
+    Ok(cleared)
+}
+
 pub async fn vacuum_queue(pool: &SqlitePool) -> Result<Vec<VacuumQueueEntry>, HouseholdCrudError> {
     ensure_cascade_tables(pool).await?;
     let entries = sqlx::query_as::<_, VacuumQueueEntry>(
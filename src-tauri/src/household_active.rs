@@ -93,6 +93,17 @@ impl StoreHandle {
     pub fn snapshot(&self) -> Option<String> {
         self.read_active()
     }
+
+    /// Raw string value stored under `key`, if any.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.inner.get(key)
+    }
+
+    /// Store `value` under `key` and flush the store to disk.
+    pub fn set_and_save(&self, key: &str, value: &str) -> anyhow::Result<()> {
+        self.inner.set(key, value);
+        self.persist()
+    }
 }
 
 #[derive(Error, Debug)]
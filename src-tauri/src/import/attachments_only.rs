@@ -0,0 +1,269 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tracing::info;
+use ts_rs::TS;
+
+use crate::attachment_category::AttachmentCategory;
+use crate::export::manifest::file_sha256;
+use crate::ops::retry::retry_transient_io_async;
+use crate::security::hash_path;
+use crate::vault::Vault;
+use crate::{AppError, AppResult};
+
+use super::{ImportBundle, ImportBundleError, ATTACHMENT_TABLES};
+
+pub const IMPORT_NOT_ATTACHMENTS_ONLY_CODE: &str = "IMPORT/NOT_ATTACHMENTS_ONLY";
+pub const IMPORT_ATTACHMENT_KEY_INVALID_CODE: &str = "IMPORT/ATTACHMENT_KEY_INVALID";
+pub const IMPORT_ATTACHMENT_HASH_MISMATCH_CODE: &str = "IMPORT/ATTACHMENT_HASH_MISMATCH";
+
+/// Outcome of importing an attachments-only bundle into a household vault.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct AttachmentsOnlyImportReport {
+    pub dry_run: bool,
+    pub household_id: String,
+    /// Household the bundle was exported from.
+    pub source_household_id: String,
+    /// Bundle files whose hash matched the attachments manifest.
+    pub verified: usize,
+    /// Files written into the vault (or that would be, for a dry run).
+    pub copied: usize,
+    /// Files already present in the vault with identical content.
+    pub skipped: usize,
+    /// `category/relative_path` keys left untouched because the vault holds different content.
+    pub conflicts: Vec<String>,
+    /// Imported files referenced by a live row in the target household.
+    pub linked: usize,
+    /// `category/relative_path` keys with no referencing row; they surface via the files index.
+    pub unlinked: Vec<String>,
+}
+
+struct PlannedAttachment {
+    key: String,
+    category: AttachmentCategory,
+    relative_path: String,
+    source: PathBuf,
+    dest: PathBuf,
+    sha256: String,
+}
+
+/// Copy the files of an attachments-only bundle into `household_id`'s vault.
+///
+/// Every bundle file is hashed against the attachments manifest before
+/// anything is written. Files are linked to existing rows by category and
+/// relative path; no placeholder domain rows are created.
+pub async fn import_attachments_only(
+    pool: &SqlitePool,
+    vault: &Vault,
+    bundle_path: &Path,
+    household_id: &str,
+    dry_run: bool,
+) -> AppResult<AttachmentsOnlyImportReport> {
+    let bundle = ImportBundle::load_attachments_only(bundle_path)
+        .map_err(|err| bundle_error(err, "load_import_bundle"))?;
+    let Some(source_household_id) = bundle.manifest().attachments_only_household.clone() else {
+        return Err(AppError::new(
+            IMPORT_NOT_ATTACHMENTS_ONLY_CODE,
+            "Bundle was not exported as attachments only.",
+        )
+        .with_context("bundle_path", bundle_path.display().to_string()));
+    };
+    ensure_household_live(pool, household_id).await?;
+
+    bundle
+        .verify_attachments_manifest()
+        .map_err(|err| bundle_error(err, "verify_attachments_manifest"))?;
+
+    let mut report = AttachmentsOnlyImportReport {
+        dry_run,
+        household_id: household_id.to_string(),
+        source_household_id,
+        ..Default::default()
+    };
+
+    let mut planned = Vec::with_capacity(bundle.attachments().len());
+    for entry in bundle.attachments() {
+        bundle
+            .verify_attachment_hash(entry)
+            .map_err(|err| match err {
+                ImportBundleError::Hash { .. } => AppError::new(
+                    IMPORT_ATTACHMENT_HASH_MISMATCH_CODE,
+                    "Bundle attachment does not match its manifest hash.",
+                )
+                .with_context("relative_path", entry.relative_path.clone()),
+                other => bundle_error(other, "verify_attachment_hash"),
+            })?;
+        report.verified += 1;
+
+        let (category, relative_path) = split_manifest_key(&entry.relative_path)?;
+        let dest = vault
+            .resolve(household_id, category, &relative_path)
+            .map_err(|err| {
+                err.with_context("operation", "import_resolve_attachment")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+        planned.push(PlannedAttachment {
+            key: format!("{}/{}", category.as_str(), relative_path),
+            category,
+            relative_path,
+            source: bundle.attachments_dir().join(&entry.relative_path),
+            dest,
+            sha256: entry.sha256.clone(),
+        });
+    }
+
+    for item in &planned {
+        if item.dest.exists() {
+            let existing = file_sha256(&item.dest).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "hash_existing_attachment")
+                    .with_context("path", item.dest.display().to_string())
+            })?;
+            if existing == item.sha256 {
+                report.skipped += 1;
+            } else {
+                report.conflicts.push(item.key.clone());
+                continue;
+            }
+        } else {
+            if !dry_run {
+                copy_into_vault(item, household_id).await?;
+            }
+            report.copied += 1;
+        }
+
+        if has_referencing_row(pool, household_id, item.category, &item.relative_path).await? {
+            report.linked += 1;
+        } else {
+            report.unlinked.push(item.key.clone());
+        }
+    }
+
+    Ok(report)
+}
+
+#[allow(clippy::result_large_err)]
+fn split_manifest_key(key: &str) -> AppResult<(AttachmentCategory, String)> {
+    let invalid = || {
+        AppError::new(
+            IMPORT_ATTACHMENT_KEY_INVALID_CODE,
+            "Attachment manifest key is not <household>/<category>/<path>.",
+        )
+        .with_context("relative_path", key.to_string())
+    };
+    let mut parts = key.splitn(3, '/');
+    let (Some(_), Some(category), Some(rel)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(invalid());
+    };
+    let category = AttachmentCategory::from_str(category).map_err(|_| invalid())?;
+    if rel.is_empty() {
+        return Err(invalid());
+    }
+    Ok((category, rel.to_string()))
+}
+
+#[allow(clippy::result_large_err)]
+async fn copy_into_vault(item: &PlannedAttachment, household_id: &str) -> AppResult<()> {
+    if let Some(parent) = item.dest.parent() {
+        fs::create_dir_all(parent).map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "ensure_vault_directory")
+                .with_context("path", parent.display().to_string())
+        })?;
+    }
+    retry_transient_io_async("copy_import_attachment", &item.dest, || {
+        fs::copy(&item.source, &item.dest)
+    })
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "copy_import_attachment")
+            .with_context("path", item.dest.display().to_string())
+    })?;
+    let copied = file_sha256(&item.dest).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "hash_import_attachment")
+            .with_context("path", item.dest.display().to_string())
+    })?;
+    if copied != item.sha256 {
+        let _ = fs::remove_file(&item.dest);
+        return Err(AppError::new(
+            IMPORT_ATTACHMENT_HASH_MISMATCH_CODE,
+            "Copied attachment does not match its manifest hash.",
+        )
+        .with_context("relative_path", item.key.clone()));
+    }
+    info!(
+        target: "arklowdun",
+        event = "import_attachments_only_copy",
+        household_id,
+        category = item.category.as_str(),
+        relative_hash = %hash_path(Path::new(&item.relative_path)),
+        path_hash = %hash_path(&item.dest),
+    );
+    Ok(())
+}
+
+async fn ensure_household_live(pool: &SqlitePool, household_id: &str) -> AppResult<()> {
+    let exists: Option<i64> =
+        sqlx::query_scalar("SELECT 1 FROM household WHERE id = ?1 AND deleted_at IS NULL")
+            .bind(household_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "import_attachments_household")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+    if exists.is_none() {
+        return Err(AppError::new("HOUSEHOLD_NOT_FOUND", "Household not found.")
+            .with_context("household_id", household_id.to_string()));
+    }
+    Ok(())
+}
+
+async fn has_referencing_row(
+    pool: &SqlitePool,
+    household_id: &str,
+    category: AttachmentCategory,
+    relative_path: &str,
+) -> AppResult<bool> {
+    for table in ATTACHMENT_TABLES
+        .iter()
+        .copied()
+        .filter(|table| AttachmentCategory::for_table(table) == Some(category))
+    {
+        // member_attachments rows are hard-deleted, so they carry no deleted_at.
+        let live = if table == "member_attachments" {
+            ""
+        } else {
+            " AND deleted_at IS NULL"
+        };
+        let sql = format!(
+            "SELECT 1 FROM {table} WHERE household_id = ?1 AND relative_path = ?2{live} LIMIT 1"
+        );
+        let found: Option<i64> = sqlx::query_scalar(&sql)
+            .bind(household_id)
+            .bind(relative_path)
+            .fetch_optional(pool)
+            .await
+            .map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "import_attachments_link")
+                    .with_context("table", table.to_string())
+            })?;
+        if found.is_some() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn bundle_error(err: ImportBundleError, operation: &'static str) -> AppError {
+    AppError::from(anyhow::Error::new(err)).with_context("operation", operation)
+}
@@ -1,12 +1,13 @@
 use std::collections::BTreeMap;
 use std::fs::{self, File};
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader};
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Error as AnyError};
 use thiserror::Error;
 
-use crate::export::manifest::{file_sha256, ExportManifest};
+use crate::attachment_category::AttachmentCategory;
+use crate::export::manifest::{file_sha256, gzip_content_sha256, ExportManifest, TableSelection};
 
 use super::table_order::table_order_key;
 
@@ -30,6 +31,10 @@ pub enum ImportBundleError {
     DataFileMissing(String),
     #[error("bundle attachment missing: {0}")]
     AttachmentMissing(String),
+    #[error("unknown table in selection: {0}")]
+    UnknownTable(String),
+    #[error("table {0} was not exported into this bundle")]
+    TableNotInBundle(String),
     #[error("failed to hash file {path}: {source}")]
     Hash {
         path: String,
@@ -55,6 +60,19 @@ pub struct DataFileEntry {
     pub path: PathBuf,
     pub sha256: String,
     pub count: u64,
+    pub gzip: bool,
+}
+
+impl DataFileEntry {
+    /// Open the data file for line reading, decompressing gzip files transparently.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead + Send>> {
+        let file = File::open(&self.path)?;
+        if self.gzip {
+            Ok(Box::new(BufReader::new(flate2::read::GzDecoder::new(file))))
+        } else {
+            Ok(Box::new(BufReader::new(file)))
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -68,11 +86,23 @@ pub struct ImportBundle {
     attachments: Vec<AttachmentEntry>,
     data_files: Vec<DataFileEntry>,
     total_size_bytes: u64,
+    partial: bool,
 }
 
 impl ImportBundle {
+    /// Load a full export bundle. Attachments-only bundles carry no `data/`
+    /// directory and are refused, so they can never reach a table import.
     pub fn load(root: impl AsRef<Path>) -> Result<Self, ImportBundleError> {
-        let root = root.as_ref();
+        Self::load_with(root.as_ref(), false)
+    }
+
+    /// Load a bundle written by `create_attachments_export`, which has no
+    /// `data/` directory.
+    pub fn load_attachments_only(root: impl AsRef<Path>) -> Result<Self, ImportBundleError> {
+        Self::load_with(root.as_ref(), true)
+    }
+
+    fn load_with(root: &Path, attachments_only: bool) -> Result<Self, ImportBundleError> {
         let manifest_path = root.join("manifest.json");
         if !manifest_path.is_file() {
             return Err(ImportBundleError::ManifestMissing);
@@ -84,7 +114,7 @@ impl ImportBundle {
             .map_err(|err| ImportBundleError::ManifestParse(err.to_string()))?;
 
         let data_dir = root.join("data");
-        if !data_dir.is_dir() {
+        if !data_dir.is_dir() && !attachments_only {
             return Err(ImportBundleError::DataDirMissing);
         }
         let attachments_dir = root.join("attachments");
@@ -96,10 +126,18 @@ impl ImportBundle {
             return Err(ImportBundleError::AttachmentsManifestMissing);
         }
 
-        let data_files = Self::resolve_data_files(&manifest.tables, &data_dir)?;
+        let data_files = if data_dir.is_dir() {
+            Self::resolve_data_files(&manifest.tables, &data_dir)?
+        } else {
+            Vec::new()
+        };
         let attachments = Self::load_attachments(&attachments_manifest_path)?;
         let total_size_bytes = Self::calculate_total_size(root)?;
 
+        let partial = manifest
+            .table_selection
+            .as_ref()
+            .is_some_and(|selection| !selection.is_all());
         Ok(Self {
             root: root.to_path_buf(),
             manifest_path,
@@ -110,6 +148,7 @@ impl ImportBundle {
             attachments,
             data_files,
             total_size_bytes,
+            partial,
         })
     }
 
@@ -145,6 +184,46 @@ impl ImportBundle {
         &self.data_files
     }
 
+    /// Restrict the bundle to the selected tables before validation and
+    /// planning. Attachment files belonging to dropped tables are dropped too.
+    /// Including a table the bundle does not carry is an error.
+    pub fn select_tables(&mut self, selection: &TableSelection) -> Result<(), ImportBundleError> {
+        if selection.is_all() {
+            return Ok(());
+        }
+        if let Some(name) = selection.unknown_table(&crate::export::export_table_names()) {
+            return Err(ImportBundleError::UnknownTable(name.to_string()));
+        }
+        if let Some(missing) = selection
+            .include_tables
+            .iter()
+            .find(|name| !self.manifest.tables.contains_key(name.as_str()))
+        {
+            return Err(ImportBundleError::TableNotInBundle(missing.clone()));
+        }
+
+        self.data_files
+            .retain(|entry| selection.includes(&entry.logical_name));
+        let dropped_categories: Vec<&str> = super::ATTACHMENT_TABLES
+            .iter()
+            .filter(|table| !selection.includes(table))
+            .filter_map(|table| AttachmentCategory::for_table(table))
+            .map(|category| category.as_str())
+            .collect();
+        self.attachments.retain(|entry| {
+            let category = entry.relative_path.split('/').nth(1).unwrap_or_default();
+            !dropped_categories.contains(&category)
+        });
+        self.partial = true;
+        Ok(())
+    }
+
+    /// Whether the bundle covers only some tables, either because it was
+    /// exported with a selection or because one was applied on import.
+    pub fn is_partial(&self) -> bool {
+        self.partial
+    }
+
     pub fn total_size_bytes(&self) -> u64 {
         self.total_size_bytes
     }
@@ -155,7 +234,7 @@ impl ImportBundle {
     ) -> Result<Vec<DataFileEntry>, ImportBundleError> {
         let mut entries = Vec::new();
         for (logical_name, table) in tables.iter() {
-            let file_name = format!("{logical_name}.jsonl");
+            let file_name = table.data_file_name(logical_name);
             let path = data_dir.join(&file_name);
             if !path.is_file() {
                 return Err(ImportBundleError::DataFileMissing(file_name));
@@ -165,6 +244,7 @@ impl ImportBundle {
                 path,
                 sha256: table.sha256.clone(),
                 count: table.count,
+                gzip: table.is_gzip(),
             });
         }
         entries.sort_by(|a, b| {
@@ -234,7 +314,12 @@ impl ImportBundle {
     }
 
     pub fn verify_data_file_hash(&self, entry: &DataFileEntry) -> Result<(), ImportBundleError> {
-        let actual = file_sha256(&entry.path).map_err(|err| ImportBundleError::Hash {
+        let hashed = if entry.gzip {
+            gzip_content_sha256(&entry.path)
+        } else {
+            file_sha256(&entry.path)
+        };
+        let actual = hashed.map_err(|err| ImportBundleError::Hash {
             path: entry.path.display().to_string(),
             source: err,
         })?;
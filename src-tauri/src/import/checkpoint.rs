@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::plan::ImportMode;
+
+pub const IMPORT_CHECKPOINT_FILE: &str = "import-checkpoint.json";
+
+/// Marker written next to the import reports while a plan is executing.
+///
+/// A run that stops part-way leaves it behind so the same bundle can be
+/// resumed in the same mode. A resumed merge leaves out the tables listed in
+/// `completed_tables` and skips the rows and files that already landed; a
+/// replace starts the table swap again from scratch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportCheckpoint {
+    pub bundle_path: String,
+    pub mode: ImportMode,
+    /// Digest of the plan the interrupted run executed. A resume is held to
+    /// it rather than to a fresh plan, which counts landed rows as skips.
+    pub plan_digest: String,
+    pub started_at: String,
+    #[serde(default)]
+    pub completed_tables: Vec<String>,
+}
+
+impl ImportCheckpoint {
+    pub fn new(bundle_path: &Path, mode: ImportMode, plan_digest: String) -> Self {
+        Self {
+            bundle_path: bundle_path.display().to_string(),
+            mode,
+            plan_digest,
+            started_at: Utc::now().to_rfc3339(),
+            completed_tables: Vec::new(),
+        }
+    }
+
+    pub fn path(dir: &Path) -> PathBuf {
+        dir.join(IMPORT_CHECKPOINT_FILE)
+    }
+
+    /// Checkpoint left in `dir` by an interrupted run, if any.
+    pub fn load(dir: &Path) -> Result<Option<Self>> {
+        let path = Self::path(dir);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("read import checkpoint {}", path.display()))?;
+        let checkpoint = serde_json::from_str(&text)
+            .with_context(|| format!("parse import checkpoint {}", path.display()))?;
+        Ok(Some(checkpoint))
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("create checkpoint directory {}", dir.display()))?;
+        let path = Self::path(dir);
+        let json = serde_json::to_vec_pretty(self).context("serialize import checkpoint")?;
+        fs::write(&path, json)
+            .with_context(|| format!("write import checkpoint {}", path.display()))
+    }
+
+    pub fn clear(dir: &Path) -> Result<()> {
+        let path = Self::path(dir);
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => {
+                Err(err).with_context(|| format!("remove import checkpoint {}", path.display()))
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -16,13 +16,15 @@ use ts_rs::TS;
 
 use super::bundle::{AttachmentEntry, DataFileEntry, ImportBundle};
 use super::plan::{AttachmentConflict, ImportMode, ImportPlan, TableConflict, TablePlan};
-use super::rows::canonicalize_row;
+use super::rows::{apply_legacy_aliases, canonicalize_row};
 use super::{
     collect_bundle_attachment_metadata, collect_bundle_attachment_updates,
     BundleAttachmentMetadata, MetadataIssue, ATTACHMENT_TABLES,
 };
 use crate::export::manifest::file_sha256;
 use crate::migrate;
+use crate::ops::progress::{OperationProgress, ProgressObserver, ProgressStage};
+use crate::ops::retry::retry_transient_io_async;
 use crate::security::hash_path;
 use crate::vault::{Vault, ERR_FILENAME_INVALID, ERR_NAME_TOO_LONG, ERR_PATH_OUT_OF_VAULT};
 use crate::AppError;
@@ -30,11 +32,18 @@ use tracing::{info, warn};
 
 const ROW_CHUNK_SIZE: usize = 500;
 
-#[derive(Debug, Clone)]
+/// Called with a table's logical name once all of its rows are committed.
+pub type TableCompleteObserver = Arc<dyn Fn(&str) + Send + Sync + 'static>;
+
+#[derive(Clone)]
 pub struct ExecutionContext<'a> {
     pub pool: &'a SqlitePool,
     pub vault: Arc<Vault>,
     pub clear_attachments_on_replace: bool,
+    /// Notified after each committed row chunk and each attachment.
+    pub progress: Option<ProgressObserver>,
+    /// Notified after each table finishes, so an interrupted run can be resumed.
+    pub on_table_complete: Option<TableCompleteObserver>,
 }
 
 impl<'a> ExecutionContext<'a> {
@@ -43,10 +52,47 @@ impl<'a> ExecutionContext<'a> {
             pool,
             vault,
             clear_attachments_on_replace: true,
+            progress: None,
+            on_table_complete: None,
+        }
+    }
+
+    pub fn with_progress(mut self, observer: ProgressObserver) -> Self {
+        self.progress = Some(observer);
+        self
+    }
+
+    pub fn with_table_complete(mut self, observer: TableCompleteObserver) -> Self {
+        self.on_table_complete = Some(observer);
+        self
+    }
+
+    fn report_progress(&self, stage: ProgressStage, done: u64, total: u64, bytes: u64) {
+        if let Some(observer) = &self.progress {
+            observer(OperationProgress {
+                stage,
+                done: done.min(total),
+                total,
+                bytes,
+            });
         }
     }
 }
 
+impl std::fmt::Debug for ExecutionContext<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecutionContext")
+            .field("vault", &self.vault)
+            .field(
+                "clear_attachments_on_replace",
+                &self.clear_attachments_on_replace,
+            )
+            .field("progress", &self.progress.is_some())
+            .field("on_table_complete", &self.on_table_complete.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/bindings/")]
@@ -58,6 +104,10 @@ pub struct TableExecutionSummary {
     #[ts(type = "number")]
     pub skips: u64,
     pub conflicts: Vec<TableConflict>,
+    /// Rows whose current columns were derived from legacy aliases.
+    #[serde(default)]
+    #[ts(type = "number")]
+    pub legacy_aliased: u64,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS, PartialEq, Eq)]
@@ -170,6 +220,9 @@ pub async fn execute_plan(
                 ImportMode::Merge => execute_table_merge(entry, expected, ctx).await?,
             };
             tables.insert(entry.logical_name.clone(), summary);
+            if let Some(observer) = &ctx.on_table_complete {
+                observer(&entry.logical_name);
+            }
         }
     }
 
@@ -178,7 +231,7 @@ pub async fn execute_plan(
 
     let attachments = match plan.mode {
         ImportMode::Replace => {
-            execute_attachments_replace(bundle, &plan.attachments, ctx, &metadata_index)?
+            execute_attachments_replace(bundle, &plan.attachments, ctx, &metadata_index).await?
         }
         ImportMode::Merge => {
             execute_attachments_merge(bundle, &plan.attachments, ctx, &metadata_index).await?
@@ -275,14 +328,8 @@ async fn execute_table_replace(
     ctx: &ExecutionContext<'_>,
 ) -> Result<TableExecutionSummary, ExecutionError> {
     let table = resolve_physical_table(&entry.logical_name)?;
-    let summary = import_table_rows(
-        entry,
-        ctx.pool,
-        &entry.logical_name,
-        table,
-        ImportMode::Replace,
-    )
-    .await?;
+    let summary =
+        import_table_rows(entry, ctx, &entry.logical_name, table, ImportMode::Replace).await?;
 
     verify_table_summary(&entry.logical_name, expected, &summary)?;
     Ok(summary)
@@ -309,14 +356,8 @@ async fn execute_table_merge(
 ) -> Result<TableExecutionSummary, ExecutionError> {
     let table = resolve_physical_table(&entry.logical_name)?;
 
-    let summary = import_table_rows(
-        entry,
-        ctx.pool,
-        &entry.logical_name,
-        table,
-        ImportMode::Merge,
-    )
-    .await?;
+    let summary =
+        import_table_rows(entry, ctx, &entry.logical_name, table, ImportMode::Merge).await?;
 
     verify_table_summary(&entry.logical_name, expected, &summary)?;
     Ok(summary)
@@ -324,21 +365,22 @@ async fn execute_table_merge(
 
 async fn import_table_rows(
     entry: &DataFileEntry,
-    pool: &SqlitePool,
+    ctx: &ExecutionContext<'_>,
     logical_table: &str,
     physical_table: &str,
     mode: ImportMode,
 ) -> Result<TableExecutionSummary, ExecutionError> {
-    let file = fs::File::open(&entry.path).map_err(|err| ExecutionError::DataFileIo {
+    let reader = entry.open().map_err(|err| ExecutionError::DataFileIo {
         path: entry.path.display().to_string(),
         source: err,
     })?;
-    let reader = BufReader::new(file);
 
     let mut summary = TableExecutionSummary::default();
     let mut inserter: Option<TableInserter> = None;
     let mut tx: Option<Transaction<'_, Sqlite>> = None;
     let mut chunk_len: usize = 0;
+    let mut rows_done: u64 = 0;
+    let progress_stage = || ProgressStage::Table(logical_table.to_string());
 
     for line in reader.lines() {
         let line = line.map_err(|err| ExecutionError::DataFileIo {
@@ -354,6 +396,14 @@ async fn import_table_rows(
                 path: entry.path.display().to_string(),
                 source: err,
             })?;
+        if apply_legacy_aliases(logical_table, &mut value).map_err(|err| {
+            ExecutionError::RowNormalization {
+                table: logical_table.to_string(),
+                source: err,
+            }
+        })? {
+            summary.legacy_aliased += 1;
+        }
         value = canonicalize_row(logical_table, value).map_err(|err| {
             ExecutionError::RowNormalization {
                 table: logical_table.to_string(),
@@ -371,7 +421,7 @@ async fn import_table_rows(
         }
 
         if tx.is_none() {
-            tx = Some(pool.begin().await.map_err(ExecutionError::Database)?);
+            tx = Some(ctx.pool.begin().await.map_err(ExecutionError::Database)?);
             chunk_len = 0;
         }
 
@@ -407,10 +457,12 @@ async fn import_table_rows(
         }
 
         chunk_len += 1;
+        rows_done += 1;
 
         if chunk_len >= ROW_CHUNK_SIZE {
             if let Some(active) = tx.take() {
                 active.commit().await.map_err(ExecutionError::Database)?;
+                ctx.report_progress(progress_stage(), rows_done, entry.count, 0);
             }
         }
     }
@@ -418,6 +470,7 @@ async fn import_table_rows(
     if let Some(active) = tx.take() {
         active.commit().await.map_err(ExecutionError::Database)?;
     }
+    ctx.report_progress(progress_stage(), entry.count, entry.count, 0);
 
     Ok(summary)
 }
@@ -534,6 +587,14 @@ fn verify_table_summary(
             actual: actual.skips,
         });
     }
+    if expected.legacy_aliased != actual.legacy_aliased {
+        return Err(ExecutionError::PlanDrift {
+            table: logical.to_string(),
+            field: "legacy_aliased",
+            expected: expected.legacy_aliased,
+            actual: actual.legacy_aliased,
+        });
+    }
     if expected.conflicts != actual.conflicts {
         return Err(ExecutionError::PlanConflictMismatch {
             table: logical.to_string(),
@@ -542,7 +603,7 @@ fn verify_table_summary(
     Ok(())
 }
 
-fn execute_attachments_replace(
+async fn execute_attachments_replace(
     bundle: &ImportBundle,
     expected: &super::plan::AttachmentsPlan,
     ctx: &ExecutionContext<'_>,
@@ -566,15 +627,18 @@ fn execute_attachments_replace(
     })?;
 
     let mut summary = AttachmentExecutionSummary::default();
+    let mut ticker = AttachmentTicker::new(bundle, ctx);
     for attachment in bundle.attachments() {
+        ticker.tick(attachment);
         let metadata = metadata_index
             .get(&attachment.relative_path)
             .ok_or_else(|| ExecutionError::AttachmentMetadataMissing {
                 path: attachment.relative_path.clone(),
             })?;
-        copy_attachment(bundle, attachment, ctx, metadata)?;
+        copy_attachment(bundle, attachment, ctx, metadata).await?;
         summary.adds += 1;
     }
+    ticker.finish();
 
     verify_attachment_summary(expected, &summary)?;
     Ok(summary)
@@ -589,7 +653,9 @@ async fn execute_attachments_merge(
     let mut summary = AttachmentExecutionSummary::default();
     let bundle_updated_index =
         collect_bundle_attachment_updates(bundle).map_err(metadata_error_to_execution)?;
+    let mut ticker = AttachmentTicker::new(bundle, ctx);
     for attachment in bundle.attachments() {
+        ticker.tick(attachment);
         let metadata = metadata_index
             .get(&attachment.relative_path)
             .ok_or_else(|| ExecutionError::AttachmentMetadataMissing {
@@ -600,7 +666,7 @@ async fn execute_attachments_merge(
         let live_updated_at =
             load_live_attachment_updated_at(ctx.pool, &attachment.relative_path, metadata).await?;
         if !dest.exists() {
-            copy_attachment(bundle, attachment, ctx, metadata)?;
+            copy_attachment(bundle, attachment, ctx, metadata).await?;
             summary.adds += 1;
             continue;
         }
@@ -615,7 +681,7 @@ async fn execute_attachments_merge(
 
         match decide_attachment_action(bundle_updated_at, live_updated_at) {
             AttachmentAction::BundleWins { reason } => {
-                copy_attachment(bundle, attachment, ctx, metadata)?;
+                copy_attachment(bundle, attachment, ctx, metadata).await?;
                 summary.updates += 1;
                 summary.conflicts.push(AttachmentConflict {
                     relative_path: attachment.relative_path.clone(),
@@ -635,11 +701,72 @@ async fn execute_attachments_merge(
             }
         }
     }
+    ticker.finish();
 
     verify_attachment_summary(expected, &summary)?;
     Ok(summary)
 }
 
+/// Reports attachment progress as files are handled. Each tick counts the
+/// files finished before the current one, so skips and conflicts are covered
+/// without reporting from every branch.
+struct AttachmentTicker<'c, 'a> {
+    bundle: &'c ImportBundle,
+    ctx: &'c ExecutionContext<'a>,
+    total: u64,
+    done: u64,
+    bytes: u64,
+    current: Option<u64>,
+}
+
+impl<'c, 'a> AttachmentTicker<'c, 'a> {
+    fn new(bundle: &'c ImportBundle, ctx: &'c ExecutionContext<'a>) -> Self {
+        Self {
+            bundle,
+            ctx,
+            total: bundle.attachments().len() as u64,
+            done: 0,
+            bytes: 0,
+            current: None,
+        }
+    }
+
+    fn tick(&mut self, next: &AttachmentEntry) {
+        if self.ctx.progress.is_none() {
+            return;
+        }
+        self.complete_current();
+        self.ctx.report_progress(
+            ProgressStage::Attachments,
+            self.done,
+            self.total,
+            self.bytes,
+        );
+        let source = self.bundle.attachments_dir().join(&next.relative_path);
+        self.current = Some(fs::metadata(source).map(|meta| meta.len()).unwrap_or(0));
+    }
+
+    fn finish(mut self) {
+        if self.ctx.progress.is_none() || self.total == 0 {
+            return;
+        }
+        self.complete_current();
+        self.ctx.report_progress(
+            ProgressStage::Attachments,
+            self.done,
+            self.total,
+            self.bytes,
+        );
+    }
+
+    fn complete_current(&mut self) {
+        if let Some(size) = self.current.take() {
+            self.done += 1;
+            self.bytes += size;
+        }
+    }
+}
+
 fn verify_attachment_summary(
     expected: &super::plan::AttachmentsPlan,
     actual: &AttachmentExecutionSummary,
@@ -671,7 +798,7 @@ fn verify_attachment_summary(
     Ok(())
 }
 
-fn copy_attachment(
+async fn copy_attachment(
     bundle: &ImportBundle,
     attachment: &AttachmentEntry,
     ctx: &ExecutionContext<'_>,
@@ -685,10 +812,12 @@ fn copy_attachment(
             source: err.into(),
         })?;
     }
-    fs::copy(&source, &dest).map_err(|err| ExecutionError::AttachmentIo {
-        path: dest.display().to_string(),
-        source: err.into(),
-    })?;
+    retry_transient_io_async("import_copy_attachment", &dest, || fs::copy(&source, &dest))
+        .await
+        .map_err(|err| ExecutionError::AttachmentIo {
+            path: dest.display().to_string(),
+            source: err.into(),
+        })?;
     info!(
         target: "arklowdun",
         event = "import_copy_attachment",
@@ -934,7 +1063,7 @@ fn json_extract_for_column(column: &str) -> String {
 mod tests {
     use super::*;
     use crate::attachment_category::AttachmentCategory;
-    use crate::import::plan::{build_plan, ImportMode, PlanContext};
+    use crate::import::plan::{build_plan, ImportMode, PlanContext, PlanError};
     use crate::vault::Vault;
     use serde_json::json;
     use sqlx::sqlite::{
@@ -1231,6 +1360,88 @@ mod tests {
         assert_eq!(report.tables.get("household").unwrap().adds, 1);
     }
 
+    #[tokio::test]
+    async fn legacy_event_rows_import_with_utc_values() {
+        let (_db_dir, pool) = setup_pool().await;
+        let tmp = TempDir::new().unwrap();
+        let bundle = write_bundle_with_tables(
+            tmp.path(),
+            &[
+                ("household", vec![household_row("hh1", "Primary", 2)]),
+                (
+                    "events",
+                    vec![json!({
+                        "id": "evt_legacy",
+                        "title": "Legacy",
+                        "household_id": "hh1",
+                        "tz": "America/New_York",
+                        "start_at": 1_704_110_400_000_i64,
+                        "end_at": 1_704_114_000_000_i64,
+                        "created_at": 5,
+                        "updated_at": 6,
+                        "deleted_at": null
+                    })],
+                ),
+            ],
+            &[],
+        );
+
+        let attachments_root = TempDir::new().unwrap();
+        let vault = Arc::new(Vault::new(attachments_root.path()));
+        let plan_ctx = PlanContext {
+            pool: &pool,
+            vault: vault.clone(),
+        };
+        let plan = build_plan(&bundle, &plan_ctx, ImportMode::Replace)
+            .await
+            .unwrap();
+        assert_eq!(plan.tables.get("events").unwrap().legacy_aliased, 1);
+
+        let exec_ctx = ExecutionContext::new(&pool, vault.clone());
+        let report = execute_plan(&bundle, &plan, &exec_ctx).await.unwrap();
+        assert_eq!(report.tables.get("events").unwrap().legacy_aliased, 1);
+
+        // 2024-01-01 12:00 EST is 17:00 UTC.
+        let (start, end): (i64, Option<i64>) =
+            sqlx::query_as("SELECT start_at_utc, end_at_utc FROM events WHERE id = 'evt_legacy'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(start, 1_704_128_400_000);
+        assert_eq!(end, Some(1_704_132_000_000));
+    }
+
+    #[tokio::test]
+    async fn legacy_event_rows_without_tz_fail_planning() {
+        let (_db_dir, pool) = setup_pool().await;
+        let tmp = TempDir::new().unwrap();
+        let bundle = write_bundle_with_tables(
+            tmp.path(),
+            &[(
+                "events",
+                vec![json!({
+                    "id": "evt_legacy",
+                    "title": "Legacy",
+                    "household_id": "hh1",
+                    "start_at": 1_704_110_400_000_i64,
+                    "created_at": 5,
+                    "updated_at": 6
+                })],
+            )],
+            &[],
+        );
+
+        let attachments_root = TempDir::new().unwrap();
+        let plan_ctx = PlanContext {
+            pool: &pool,
+            vault: Arc::new(Vault::new(attachments_root.path())),
+        };
+        let err = build_plan(&bundle, &plan_ctx, ImportMode::Replace)
+            .await
+            .expect_err("missing tz should fail");
+        assert!(matches!(err, PlanError::LegacyAlias { .. }));
+    }
+
     #[tokio::test]
     async fn attachments_replace_overwrites_destination() {
         let (_db_dir, pool) = setup_pool().await;
@@ -1549,4 +1760,43 @@ mod tests {
             .then_some(())
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn attachment_copy_failure_keeps_io_cause_through_app_error() {
+        let (_db_dir, pool) = setup_pool().await;
+        let tmp = TempDir::new().unwrap();
+        let attachments = vec![("docs/file.txt".to_string(), b"bundle".to_vec())];
+        let bundle = write_bundle(tmp.path(), "household", &[], &attachments);
+        let attachment = bundle.attachments()[0].clone();
+        std::fs::remove_file(bundle.attachments_dir().join(&attachment.relative_path)).unwrap();
+
+        let attachments_root = TempDir::new().unwrap();
+        let vault = Arc::new(Vault::new(attachments_root.path()));
+        let exec_ctx = ExecutionContext::new(&pool, vault);
+        let metadata = BundleAttachmentMetadata {
+            household_id: "hh_attach".into(),
+            category: AttachmentCategory::Bills,
+            updated_at: None,
+        };
+        let err = copy_attachment(&bundle, &attachment, &exec_ctx, &metadata)
+            .await
+            .unwrap_err();
+
+        // Mirrors how the import commands wrap execution failures.
+        let app_error = AppError::from(AnyError::new(err).context("execute import plan"));
+        assert_eq!(app_error.message(), "execute import plan");
+        let copy = app_error.cause().expect("copy failure cause");
+        assert!(
+            copy.message().starts_with("failed to copy attachment "),
+            "{}",
+            copy.message()
+        );
+        assert!(copy.message().ends_with("file.txt"), "{}", copy.message());
+        let io = copy.cause().expect("io cause");
+        assert_eq!(io.code(), "IO/NotFound");
+        assert!(io.cause().is_none());
+
+        let dto = serde_json::to_value(app_error.to_dto()).unwrap();
+        assert_eq!(dto["cause"]["cause"]["code"], "IO/NotFound");
+    }
 }
@@ -1,7 +1,6 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::str::FromStr;
 
 use serde_json::Value;
@@ -54,11 +53,10 @@ pub(crate) fn collect_bundle_attachment_metadata(
             continue;
         }
 
-        let file = File::open(&entry.path).map_err(|source| MetadataIssue::DataFileIo {
+        let reader = entry.open().map_err(|source| MetadataIssue::DataFileIo {
             path: entry.path.display().to_string(),
             source,
         })?;
-        let reader = BufReader::new(file);
         for line in reader.lines() {
             let line = line.map_err(|source| MetadataIssue::DataFileIo {
                 path: entry.path.display().to_string(),
@@ -160,11 +158,10 @@ pub(crate) fn collect_bundle_attachment_updates(
             continue;
         }
 
-        let file = File::open(&entry.path).map_err(|source| MetadataIssue::DataFileIo {
+        let reader = entry.open().map_err(|source| MetadataIssue::DataFileIo {
             path: entry.path.display().to_string(),
             source,
         })?;
-        let reader = BufReader::new(file);
         for line in reader.lines() {
             let line = line.map_err(|source| MetadataIssue::DataFileIo {
                 path: entry.path.display().to_string(),
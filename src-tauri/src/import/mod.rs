@@ -1,4 +1,6 @@
+pub mod attachments_only;
 pub mod bundle;
+pub mod checkpoint;
 pub mod execute;
 mod metadata;
 pub mod plan;
@@ -7,16 +9,18 @@ mod rows;
 mod table_order;
 pub mod validator;
 
+pub use attachments_only::{import_attachments_only, AttachmentsOnlyImportReport};
 pub use bundle::{AttachmentEntry, DataFileEntry, ImportBundle, ImportBundleError};
+pub use checkpoint::ImportCheckpoint;
 pub use execute::{
     execute_plan, AttachmentExecutionSummary, ExecutionContext, ExecutionError, ExecutionReport,
-    TableExecutionSummary,
+    TableCompleteObserver, TableExecutionSummary,
 };
 pub use plan::{
-    build_plan, AttachmentConflict, AttachmentsPlan, ImportMode, ImportPlan, PlanContext,
-    PlanError, TableConflict, TablePlan,
+    build_plan, plan_digest, AttachmentConflict, AttachmentsPlan, ImportMode, ImportPlan,
+    PlanContext, PlanError, TableConflict, TablePlan,
 };
-pub use report::write_import_report;
+pub use report::{write_import_report, write_import_report_to};
 pub use validator::{validate_bundle, ValidationContext, ValidationError, ValidationReport};
 
 pub(crate) use metadata::{
@@ -1,12 +1,13 @@
 use std::collections::{BTreeMap, HashMap};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::path::Path;
 use std::sync::Arc;
 
-use anyhow::Error as AnyError;
+use anyhow::{Context, Error as AnyError};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 use sqlx::pool::PoolConnection;
 use sqlx::Row;
 use sqlx::Sqlite;
@@ -15,6 +16,7 @@ use thiserror::Error;
 use ts_rs::TS;
 
 use super::bundle::{DataFileEntry, ImportBundle};
+use super::rows::apply_legacy_aliases;
 use super::{
     collect_bundle_attachment_metadata, collect_bundle_attachment_updates, ATTACHMENT_TABLES,
 };
@@ -24,7 +26,11 @@ use crate::vault::{Vault, ERR_FILENAME_INVALID, ERR_NAME_TOO_LONG, ERR_PATH_OUT_
 use crate::AppError;
 use tracing::info;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+/// Error code for a replace import restricted to some tables. Replace clears
+/// every table and the whole vault, so it only accepts complete bundles.
+pub const IMPORT_PARTIAL_REPLACE_CODE: &str = "IMPORT/PARTIAL_REPLACE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 #[ts(export, export_to = "../../src/bindings/")]
 pub enum ImportMode {
@@ -32,7 +38,7 @@ pub enum ImportMode {
     Replace,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct TablePlan {
@@ -43,9 +49,13 @@ pub struct TablePlan {
     #[ts(type = "number")]
     pub skips: u64,
     pub conflicts: Vec<TableConflict>,
+    /// Rows whose current columns are derived from legacy aliases.
+    #[serde(default)]
+    #[ts(type = "number")]
+    pub legacy_aliased: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct TableConflict {
@@ -57,7 +67,7 @@ pub struct TableConflict {
     pub live_updated_at: Option<i64>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct AttachmentsPlan {
@@ -70,7 +80,7 @@ pub struct AttachmentsPlan {
     pub conflicts: Vec<AttachmentConflict>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct AttachmentConflict {
@@ -82,7 +92,7 @@ pub struct AttachmentConflict {
     pub reason: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct ImportPlan {
@@ -121,6 +131,12 @@ pub enum PlanError {
     },
     #[error("record in {table} missing required field {field}")]
     MissingField { table: String, field: String },
+    #[error("legacy column mapping failed for table {table}: {source}")]
+    LegacyAlias {
+        table: String,
+        #[source]
+        source: AnyError,
+    },
     #[error("database error: {0}")]
     Database(#[from] sqlx::Error),
     #[error("attachment path escapes base: {0}")]
@@ -137,6 +153,20 @@ pub enum PlanError {
     AttachmentMetadataConflict { path: String },
     #[error("attachment {path} metadata has invalid category {category}")]
     AttachmentMetadataInvalidCategory { path: String, category: String },
+    #[error("replace import needs a bundle covering every table")]
+    PartialReplace,
+}
+
+impl From<PlanError> for AppError {
+    fn from(err: PlanError) -> Self {
+        match err {
+            PlanError::PartialReplace => AppError::new(
+                IMPORT_PARTIAL_REPLACE_CODE,
+                "Replace would wipe tables this import leaves out. Use merge, or import every table.",
+            ),
+            other => AppError::from(anyhow::Error::new(other)),
+        }
+    }
 }
 
 pub async fn build_plan(
@@ -144,15 +174,20 @@ pub async fn build_plan(
     ctx: &PlanContext<'_>,
     mode: ImportMode,
 ) -> Result<ImportPlan, PlanError> {
+    if mode == ImportMode::Replace && bundle.is_partial() {
+        return Err(PlanError::PartialReplace);
+    }
+
     let mut tables = BTreeMap::new();
     let metadata_index =
         collect_bundle_attachment_metadata(bundle).map_err(metadata_error_to_plan)?;
 
     for data_entry in bundle.data_files() {
-        let plan = match mode {
+        let mut plan = match mode {
             ImportMode::Replace => plan_table_replace(data_entry),
             ImportMode::Merge => plan_table_merge(ctx.pool, data_entry).await?,
         };
+        plan.legacy_aliased = count_legacy_aliases(data_entry)?;
         tables.insert(data_entry.logical_name.clone(), plan);
     }
 
@@ -178,7 +213,45 @@ fn plan_table_replace(entry: &DataFileEntry) -> TablePlan {
         updates: 0,
         skips: 0,
         conflicts: Vec::new(),
+        legacy_aliased: 0,
+    }
+}
+
+/// Scan a data file for rows needing legacy alias translation so that rows
+/// that cannot be mapped fail the plan before anything is written.
+fn count_legacy_aliases(entry: &DataFileEntry) -> Result<u64, PlanError> {
+    if entry.logical_name != "events" {
+        return Ok(0);
+    }
+    let reader = entry.open().map_err(|err| PlanError::DataFileIo {
+        path: entry.path.display().to_string(),
+        source: err,
+    })?;
+    let mut count = 0_u64;
+    for line in reader.lines() {
+        let line = line.map_err(|err| PlanError::DataFileIo {
+            path: entry.path.display().to_string(),
+            source: err,
+        })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut value: Value =
+            serde_json::from_str(&line).map_err(|err| PlanError::DataFileParse {
+                path: entry.path.display().to_string(),
+                source: err,
+            })?;
+        let migrated = apply_legacy_aliases(&entry.logical_name, &mut value).map_err(|err| {
+            PlanError::LegacyAlias {
+                table: entry.logical_name.clone(),
+                source: err,
+            }
+        })?;
+        if migrated {
+            count += 1;
+        }
     }
+    Ok(count)
 }
 
 async fn plan_table_merge(
@@ -188,11 +261,10 @@ async fn plan_table_merge(
     let table = resolve_physical_table(&entry.logical_name)?;
     let sql = format!("SELECT updated_at, deleted_at FROM {} WHERE id = ?1", table);
 
-    let file = File::open(&entry.path).map_err(|err| PlanError::DataFileIo {
+    let reader = entry.open().map_err(|err| PlanError::DataFileIo {
         path: entry.path.display().to_string(),
         source: err,
     })?;
-    let reader = BufReader::new(file);
 
     let mut adds = 0_u64;
     let mut updates = 0_u64;
@@ -299,6 +371,7 @@ async fn plan_table_merge(
         updates,
         skips,
         conflicts,
+        legacy_aliased: 0,
     })
 }
 
@@ -559,6 +632,15 @@ fn extract_id(table: &str, value: &Value) -> Result<IdValue, PlanError> {
     })
 }
 
+/// Stable digest of a plan, used to check that the plan confirmed after a
+/// dry run is the one being executed.
+pub fn plan_digest(plan: &ImportPlan) -> anyhow::Result<String> {
+    let json = serde_json::to_vec(plan).context("serialize import plan for digest")?;
+    let mut hasher = Sha256::new();
+    hasher.update(&json);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,11 +27,25 @@ pub fn write_import_report(
     plan: &ImportPlan,
     execution: &ExecutionReport,
 ) -> Result<PathBuf> {
-    fs::create_dir_all(reports_dir)
-        .with_context(|| format!("create reports directory {}", reports_dir.display()))?;
-
     let timestamp = Utc::now().format("import-%Y%m%d-%H%M%S.json");
     let path = reports_dir.join(timestamp.to_string());
+    write_import_report_to(&path, bundle_path, validation, plan, execution)?;
+    Ok(path)
+}
+
+/// Write the import report to exactly `path`, creating its parent directory.
+pub fn write_import_report_to(
+    path: &Path,
+    bundle_path: &Path,
+    validation: &ValidationReport,
+    plan: &ImportPlan,
+    execution: &ExecutionReport,
+) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("create reports directory {}", parent.display()))?;
+    }
+
     let payload = ImportReportFile {
         generated_at: Utc::now().to_rfc3339(),
         bundle_path: bundle_path.display().to_string(),
@@ -41,6 +55,5 @@ pub fn write_import_report(
         execution: execution.clone(),
     };
     let json = serde_json::to_string_pretty(&payload).context("serialize import report")?;
-    fs::write(&path, json).with_context(|| format!("write import report {}", path.display()))?;
-    Ok(path)
+    fs::write(path, json).with_context(|| format!("write import report {}", path.display()))
 }
@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
+use chrono_tz::Tz;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
 
@@ -243,6 +244,79 @@ pub struct PetMedicalRow {
     pub extras: BTreeMap<String, Value>,
 }
 
+/// Legacy event columns accepted on import. Bundles from app versions that
+/// predate the UTC migration carry local wall-clock `start_at`/`end_at`
+/// values; these are converted to `start_at_utc`/`end_at_utc` using the row's
+/// `tz`. Legacy columns are dropped when the current column is also present.
+pub const LEGACY_EVENT_ALIASES: &[(&str, &str)] =
+    &[("start_at", "start_at_utc"), ("end_at", "end_at_utc")];
+
+/// Translate legacy column aliases in place. Returns `true` when at least one
+/// current column had to be derived from a legacy one.
+pub fn apply_legacy_aliases(logical_table: &str, value: &mut Value) -> Result<bool> {
+    if logical_table != "events" {
+        return Ok(false);
+    }
+    let Some(object) = value.as_object_mut() else {
+        return Ok(false);
+    };
+
+    let mut migrated = false;
+    for (legacy, current) in LEGACY_EVENT_ALIASES {
+        let Some(legacy_value) = object.remove(*legacy) else {
+            continue;
+        };
+        let camel_current = if *current == "start_at_utc" {
+            "startAtUtc"
+        } else {
+            "endAtUtc"
+        };
+        if object.contains_key(*current) || object.contains_key(camel_current) {
+            continue;
+        }
+        let Some(local_ms) = legacy_value.as_i64() else {
+            if legacy_value.is_null() {
+                continue;
+            }
+            bail!(
+                "import: events[{}] legacy {legacy} is not an integer timestamp",
+                row_id(object)
+            );
+        };
+        let tz_name = object
+            .get("tz")
+            .or_else(|| object.get("timeZone"))
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|tz| !tz.is_empty())
+            .ok_or_else(|| {
+                anyhow!(
+                    "import: events[{}] legacy {legacy} requires tz to derive {current}",
+                    row_id(object)
+                )
+            })?;
+        let tz: Tz = tz_name.parse().map_err(|_| {
+            anyhow!(
+                "import: events[{}] legacy {legacy} has unknown tz {tz_name}",
+                row_id(object)
+            )
+        })?;
+        let utc = crate::events_tz_backfill::to_utc_ms(local_ms, tz)
+            .map_err(|err| anyhow!("import: events[{}] {}", row_id(object), err.message()))?;
+        object.insert((*current).to_string(), Value::from(utc));
+        migrated = true;
+    }
+    Ok(migrated)
+}
+
+fn row_id(object: &serde_json::Map<String, Value>) -> String {
+    match object.get("id") {
+        Some(Value::String(id)) => id.clone(),
+        Some(other) => other.to_string(),
+        None => "?".to_string(),
+    }
+}
+
 fn canonicalize_table_row<T, F>(value: Value, validate: F) -> Result<Value>
 where
     T: DeserializeOwned + Serialize,
@@ -452,4 +526,34 @@ mod tests {
 
         assert_eq!(snake_norm, camel_norm);
     }
+
+    #[test]
+    fn legacy_event_local_times_convert_to_utc() {
+        let mut legacy = load_value(
+            r#"{"id":"evt1","title":"Test","household_id":"hh1","created_at":1,"updated_at":2,"tz":"Europe/London","start_at":1719835200000,"end_at":1719838800000}"#,
+        );
+        assert!(apply_legacy_aliases("events", &mut legacy).unwrap());
+        let row = canonicalize_row("events", legacy).unwrap();
+        // 2024-07-01 12:00 BST is 11:00 UTC.
+        assert_eq!(row["start_at_utc"], 1_719_831_600_000_i64);
+        assert_eq!(row["end_at_utc"], 1_719_835_200_000_i64);
+        assert!(row.get("start_at").is_none());
+    }
+
+    #[test]
+    fn legacy_event_without_tz_fails() {
+        let mut legacy = load_value(
+            r#"{"id":"evt1","title":"Test","household_id":"hh1","created_at":1,"updated_at":2,"start_at":1719835200000}"#,
+        );
+        let err = apply_legacy_aliases("events", &mut legacy).unwrap_err();
+        assert!(err.to_string().contains("requires tz"));
+    }
+
+    #[test]
+    fn legacy_columns_dropped_when_current_present() {
+        let mut row = load_value(r#"{"id":"evt1","start_at":5,"start_at_utc":3,"tz":"UTC"}"#);
+        assert!(!apply_legacy_aliases("events", &mut row).unwrap());
+        assert_eq!(row["start_at_utc"], 3);
+        assert!(row.get("start_at").is_none());
+    }
 }
@@ -8,12 +8,26 @@ use ts_rs::TS;
 
 use super::bundle::{ImportBundle, ImportBundleError};
 use crate::db::manifest as db_manifest;
+use crate::AppError;
+
+/// Error code for bundles exported by a newer app than the one running.
+pub const IMPORT_BUNDLE_TOO_NEW_CODE: &str = "IMPORT/BUNDLE_TOO_NEW";
+/// Error code for attachments-only bundles offered to a table import.
+pub const IMPORT_ATTACHMENTS_ONLY_BUNDLE_CODE: &str = "IMPORT/ATTACHMENTS_ONLY_BUNDLE";
+
+/// Version of the running app, used as the upper bound for bundle versions.
+pub fn current_app_version() -> Version {
+    Version::parse(env!("CARGO_PKG_VERSION")).expect("crate version is valid semver")
+}
 
 #[derive(Debug, Clone)]
 pub struct ValidationContext<'a> {
     pub pool: &'a SqlitePool,
     pub target_root: &'a Path,
     pub minimum_app_version: &'a Version,
+    pub current_app_version: Version,
+    /// Accept bundles exported by a newer app version than `current_app_version`.
+    pub allow_newer: bool,
     pub available_space_override: Option<u64>,
 }
 
@@ -27,6 +41,8 @@ impl<'a> ValidationContext<'a> {
             pool,
             target_root,
             minimum_app_version,
+            current_app_version: current_app_version(),
+            allow_newer: false,
             available_space_override: None,
         }
     }
@@ -38,6 +54,10 @@ pub enum ValidationError {
     SchemaVersionMismatch { live: String, bundle: String },
     #[error("bundle app version {found} is older than minimum supported {minimum}")]
     AppVersionTooOld { minimum: String, found: String },
+    #[error("bundle app version {found} is newer than this app ({current})")]
+    AppVersionTooNew { current: String, found: String },
+    #[error("bundle holds attachments only for household {household_id}")]
+    AttachmentsOnly { household_id: String },
     #[error("bundle size {bundle_bytes} exceeds available disk space {available_bytes}")]
     InsufficientDisk {
         bundle_bytes: u64,
@@ -57,6 +77,25 @@ pub enum ValidationError {
     InvalidAppVersion(String),
 }
 
+impl From<ValidationError> for AppError {
+    fn from(err: ValidationError) -> Self {
+        match err {
+            ValidationError::AppVersionTooNew { current, found } => AppError::new(
+                IMPORT_BUNDLE_TOO_NEW_CODE,
+                "This bundle was exported by a newer version of the app. Update before importing.",
+            )
+            .with_context("current_version", current)
+            .with_context("bundle_version", found),
+            ValidationError::AttachmentsOnly { household_id } => AppError::new(
+                IMPORT_ATTACHMENTS_ONLY_BUNDLE_CODE,
+                "This bundle holds attachments only. Use the attachments import instead.",
+            )
+            .with_context("source_household_id", household_id),
+            other => AppError::from(anyhow::Error::new(other)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/bindings/")]
@@ -70,6 +109,13 @@ pub async fn validate_bundle(
     bundle: &ImportBundle,
     ctx: &ValidationContext<'_>,
 ) -> Result<ValidationReport, ValidationError> {
+    // A table import of a bundle without rows would wipe the database in
+    // replace mode, so attachments-only bundles are refused outright.
+    if let Some(household_id) = &bundle.manifest().attachments_only_household {
+        return Err(ValidationError::AttachmentsOnly {
+            household_id: household_id.clone(),
+        });
+    }
     validate_schema_version(bundle, ctx).await?;
     validate_app_version(bundle, ctx)?;
     let bundle_size = bundle.total_size_bytes();
@@ -127,6 +173,12 @@ fn validate_app_version(
             found: manifest_version.to_string(),
         });
     }
+    if !ctx.allow_newer && manifest_version > ctx.current_app_version {
+        return Err(ValidationError::AppVersionTooNew {
+            current: ctx.current_app_version.to_string(),
+            found: manifest_version.to_string(),
+        });
+    }
     Ok(())
 }
 
@@ -275,6 +327,7 @@ mod tests {
         min_version: &'a Version,
     ) -> ValidationContext<'a> {
         let mut ctx = ValidationContext::with_minimum_version(pool, root, min_version);
+        ctx.current_app_version = Version::parse("1.0.0").unwrap();
         ctx.available_space_override = Some(10_000_000);
         ctx
     }
@@ -342,6 +395,52 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn app_version_too_new() {
+        let pool = setup_pool("20240101000000").await;
+        let dir = TempDir::new().unwrap();
+        write_bundle(dir.path(), "20240101000000", "2.0.0");
+        let bundle = ImportBundle::load(dir.path()).unwrap();
+        let min_version = Version::parse("0.1.0").unwrap();
+        let target_dir = TempDir::new().unwrap();
+        let mut ctx = ctx(&pool, target_dir.path(), &min_version);
+
+        let err = validate_bundle(&bundle, &ctx).await.unwrap_err();
+        assert!(matches!(err, ValidationError::AppVersionTooNew { .. }));
+        let app_error = AppError::from(err);
+        assert_eq!(app_error.code(), IMPORT_BUNDLE_TOO_NEW_CODE);
+        assert_eq!(
+            app_error
+                .context()
+                .get("bundle_version")
+                .map(String::as_str),
+            Some("2.0.0")
+        );
+        assert_eq!(
+            app_error
+                .context()
+                .get("current_version")
+                .map(String::as_str),
+            Some("1.0.0")
+        );
+
+        ctx.allow_newer = true;
+        validate_bundle(&bundle, &ctx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn app_version_in_range_accepted() {
+        let pool = setup_pool("20240101000000").await;
+        let dir = TempDir::new().unwrap();
+        write_bundle(dir.path(), "20240101000000", "0.9.0");
+        let bundle = ImportBundle::load(dir.path()).unwrap();
+        let min_version = Version::parse("0.1.0").unwrap();
+        let target_dir = TempDir::new().unwrap();
+        let ctx = ctx(&pool, target_dir.path(), &min_version);
+
+        validate_bundle(&bundle, &ctx).await.unwrap();
+    }
+
     #[tokio::test]
     async fn insufficient_disk_space() {
         let pool = setup_pool("20240101000000").await;
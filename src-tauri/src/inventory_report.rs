@@ -0,0 +1,108 @@
+//! Insured-value and warranty overview for `inventory_items`.
+//!
+//! Values are summed in SQL with a missing `estimated_value` counting as zero.
+//! Warranties are flagged when they expire within the requested horizon;
+//! warranties that have already lapsed are not reported. Soft-deleted items
+//! are excluded throughout.
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::State;
+use ts_rs::TS;
+
+use crate::{due, repo, state::AppState, util::dispatch_async_app_result, AppError, AppResult};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, sqlx::FromRow)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct WarrantyExpiringItem {
+    pub item_id: String,
+    pub name: String,
+    #[ts(type = "number")]
+    pub warranty_expiry: i64,
+    #[ts(type = "number")]
+    pub estimated_value: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct InventoryReport {
+    /// Sum of `estimated_value` in minor currency units.
+    #[ts(type = "number")]
+    pub total_value: i64,
+    #[ts(type = "number")]
+    pub count: i64,
+    /// Warranties expiring within the horizon, soonest first.
+    pub expiring_warranties: Vec<WarrantyExpiringItem>,
+}
+
+/// Totals for the household plus warranties expiring within
+/// `[now_ms, now_ms + horizon_ms]`.
+pub async fn build_report(
+    pool: &SqlitePool,
+    household_id: &str,
+    now_ms: i64,
+    horizon_ms: i64,
+) -> AppResult<InventoryReport> {
+    let (from, to) = due::horizon_window("inventory_report", now_ms, horizon_ms)?;
+    let map_err = |err: sqlx::Error| {
+        AppError::from(err)
+            .with_context("operation", "inventory_report")
+            .with_context("household_id", household_id.to_string())
+    };
+
+    let (total_value, count): (i64, i64) = sqlx::query_as(
+        "SELECT COALESCE(SUM(COALESCE(estimated_value, 0)), 0), COUNT(*)
+           FROM inventory_items
+          WHERE household_id = ?1 AND deleted_at IS NULL",
+    )
+    .bind(household_id)
+    .fetch_one(pool)
+    .await
+    .map_err(map_err)?;
+
+    let expiring_warranties = sqlx::query_as::<_, WarrantyExpiringItem>(
+        "SELECT id AS item_id, name, warranty_expiry,
+                COALESCE(estimated_value, 0) AS estimated_value
+           FROM inventory_items
+          WHERE household_id = ?1
+            AND deleted_at IS NULL
+            AND warranty_expiry IS NOT NULL
+            AND warranty_expiry >= ?2
+            AND warranty_expiry <= ?3
+          ORDER BY warranty_expiry ASC, name ASC, id ASC",
+    )
+    .bind(household_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+    .map_err(map_err)?;
+
+    Ok(InventoryReport {
+        total_value,
+        count,
+        expiring_warranties,
+    })
+}
+
+#[tauri::command]
+pub async fn inventory_report(
+    state: State<'_, AppState>,
+    household_id: String,
+    horizon_ms: i64,
+) -> AppResult<InventoryReport> {
+    let pool = state.pool_clone();
+
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "inventory_report")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            build_report(&pool, &household_id, crate::time::now_ms(), horizon_ms).await
+        }
+    })
+    .await
+}
@@ -8,12 +8,21 @@
 //! `db_recheck` flow introduced in PR-01. Any new maintenance tasks that can heal
 //! corruption (VACUUM, crash recovery, etc.) must trigger a recheck so this guard sees
 //! fresh state before permitting writes.
+//!
+//! Recovery reads (backups, exports and search) go through [`allow_recovery_read`]
+//! instead and stay available while writes are blocked.
 
 use std::ops::Deref;
 
+use serde::{Deserialize, Serialize};
 use tracing::warn;
+use ts_rs::TS;
 
-use crate::{db::health::DbHealthStatus, state::AppState, AppError, AppResult};
+use crate::{
+    db::health::{DbHealthReport, DbHealthStatus},
+    state::AppState,
+    AppError, AppResult, CASCADE_HEALTH_CHECK,
+};
 
 /// Stable error code returned when database health prevents write operations.
 pub const DB_UNHEALTHY_CODE: &str = "DB_UNHEALTHY_WRITE_BLOCKED";
@@ -30,6 +39,53 @@ pub const DB_MAINTENANCE_CODE: &str = "DB_MAINTENANCE_IN_PROGRESS";
 pub const DB_MAINTENANCE_MESSAGE: &str =
     "Database maintenance is running. Please try again after repair completes.";
 
+/// Commands that stay available while writes are blocked for health reasons.
+///
+/// They only read from the live database, and getting data out through a backup,
+/// an export or a search is exactly what users need before attempting a repair.
+pub const RECOVERY_COMMANDS: &[&str] = &[
+    "db_backup_create",
+    "db_export_attachments_only",
+    "db_export_run",
+    "search_entities",
+    "search_entities_stream",
+];
+
+/// Context key carrying the [`WriteBlockReason`] on a rejected mutation.
+pub const WRITE_BLOCK_REASON_KEY: &str = "write_block_reason";
+
+/// Why the guard refused a mutation, so the UI can tailor its messaging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum WriteBlockReason {
+    Unhealthy,
+    PendingCascade,
+    Maintenance,
+}
+
+impl WriteBlockReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WriteBlockReason::Unhealthy => "unhealthy",
+            WriteBlockReason::PendingCascade => "pending_cascade",
+            WriteBlockReason::Maintenance => "maintenance",
+        }
+    }
+
+    fn from_report(report: &DbHealthReport) -> Self {
+        let cascade_pending = report
+            .checks
+            .iter()
+            .any(|check| check.name == CASCADE_HEALTH_CHECK && !check.passed);
+        if cascade_pending {
+            WriteBlockReason::PendingCascade
+        } else {
+            WriteBlockReason::Unhealthy
+        }
+    }
+}
+
 #[must_use = "Database health must be checked before executing a mutation"]
 #[derive(Debug)]
 pub struct DbWriteGuard {
@@ -71,7 +127,14 @@ pub fn ensure_db_writable(state: &(impl AppStateRef + ?Sized)) -> AppResult<DbWr
     let state = state.as_app_state();
     if state.maintenance_active() {
         warn!(target: "arklowdun", event = "db_write_blocked", reason = "maintenance");
-        return Err(AppError::new(DB_MAINTENANCE_CODE, DB_MAINTENANCE_MESSAGE));
+        let mut error = AppError::new(DB_MAINTENANCE_CODE, DB_MAINTENANCE_MESSAGE).with_context(
+            WRITE_BLOCK_REASON_KEY,
+            WriteBlockReason::Maintenance.as_str(),
+        );
+        if let Some(reason) = state.maintenance_reason() {
+            error = error.with_context("maintenance_reason", reason);
+        }
+        return Err(error);
     }
     let report = state
         .db_health
@@ -85,13 +148,16 @@ pub fn ensure_db_writable(state: &(impl AppStateRef + ?Sized)) -> AppResult<DbWr
         .clone();
 
     if !matches!(report.status, DbHealthStatus::Ok) {
+        let reason = WriteBlockReason::from_report(&report);
         warn!(
             target: "arklowdun",
             event = "db_write_blocked",
-            status = ?report.status
+            status = ?report.status,
+            reason = reason.as_str()
         );
         let error = AppError::new(DB_UNHEALTHY_CODE, DB_UNHEALTHY_MESSAGE)
             .with_context("status", format!("{:?}", report.status))
+            .with_context(WRITE_BLOCK_REASON_KEY, reason.as_str())
             .with_health_report(report);
         return Err(error);
     }
@@ -99,14 +165,51 @@ pub fn ensure_db_writable(state: &(impl AppStateRef + ?Sized)) -> AppResult<DbWr
     Ok(DbWriteGuard::new())
 }
 
+/// Explicit allowance for one of the [`RECOVERY_COMMANDS`].
+///
+/// Unlike [`ensure_db_writable`] this never rejects an unhealthy database. It logs
+/// that a recovery read ran while writes were blocked so diagnostics show what the
+/// user attempted before repairing.
+#[allow(clippy::result_large_err)]
+pub fn allow_recovery_read(
+    state: &(impl AppStateRef + ?Sized),
+    command: &'static str,
+) -> AppResult<()> {
+    debug_assert!(
+        RECOVERY_COMMANDS.contains(&command),
+        "{command} is not a recovery command"
+    );
+    let state = state.as_app_state();
+    let status = state
+        .db_health
+        .lock()
+        .map_err(|_| {
+            AppError::new(
+                "STATE/LOCK_POISONED",
+                "Failed to access database health cache",
+            )
+        })?
+        .status;
+    if !matches!(status, DbHealthStatus::Ok) {
+        warn!(
+            target: "arklowdun",
+            event = "db_recovery_read",
+            command,
+            status = ?status
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::health::DbHealthReport;
+    use crate::db::health::DbHealthCheck;
     use crate::events_tz_backfill::BackfillCoordinator;
     use crate::files_indexer::FilesIndexer;
     use crate::household_active::StoreHandle;
     use crate::pets::metrics::PetAttachmentMetrics;
+    use crate::state::{MAINTENANCE_ALREADY_ACTIVE_CODE, MAINTENANCE_NOT_ACTIVE_CODE};
     use crate::vault::Vault;
     use crate::vault_migration::VaultMigrationManager;
     use sqlx::sqlite::SqlitePoolOptions;
@@ -143,6 +246,7 @@ mod tests {
                 VaultMigrationManager::new(&attachments).expect("create vault migration manager"),
             ),
             maintenance: Arc::new(AtomicBool::new(false)),
+            maintenance_reason: Arc::new(Mutex::new(None)),
             files_indexer,
             pet_metrics: Arc::new(PetAttachmentMetrics::new()),
         }
@@ -175,6 +279,32 @@ mod tests {
         assert_eq!(report.status, DbHealthStatus::Error);
     }
 
+    #[tokio::test]
+    async fn unhealthy_database_still_allows_backups() {
+        let state = app_state_with_report(sample_report(DbHealthStatus::Error));
+        let dir = Builder::new()
+            .prefix("guard-test-db")
+            .tempdir()
+            .expect("temp db dir");
+        let db_path = dir.path().join("arklowdun.sqlite3");
+        let pool = crate::db::connect_sqlite_pool(&db_path)
+            .await
+            .expect("open sqlite pool");
+        crate::migrate::apply_migrations(&pool)
+            .await
+            .expect("apply migrations");
+        state.replace_pool(pool);
+
+        allow_recovery_read(&state, "db_backup_create").expect("backup allowed");
+        let entry = crate::db::backup::create_backup(&state.pool_clone(), &db_path)
+            .await
+            .expect("backup succeeds while unhealthy");
+        assert!(PathBuf::from(&entry.sqlite_path).exists());
+
+        let err = ensure_db_writable(&state).expect_err("create mutation still blocked");
+        assert_eq!(err.code(), DB_UNHEALTHY_CODE);
+    }
+
     #[tokio::test]
     async fn blocks_mutations_during_maintenance() {
         let state = app_state_with_report(sample_report(DbHealthStatus::Ok));
@@ -185,4 +315,88 @@ mod tests {
         drop(guard);
         assert!(ensure_db_writable(&state).is_ok());
     }
+
+    #[tokio::test]
+    async fn manual_maintenance_blocks_writes_until_ended() {
+        let state = app_state_with_report(sample_report(DbHealthStatus::Ok));
+        state
+            .begin_manual_maintenance("copying vault to external drive".into())
+            .expect("enter manual maintenance");
+
+        let err = ensure_db_writable(&state).expect_err("expected maintenance to block writes");
+        assert_eq!(err.code(), DB_MAINTENANCE_CODE);
+        assert_eq!(
+            err.context().get("maintenance_reason").map(String::as_str),
+            Some("copying vault to external drive")
+        );
+
+        let nested = state
+            .begin_manual_maintenance("second".into())
+            .expect_err("nested begin should be refused");
+        assert_eq!(nested.code(), MAINTENANCE_ALREADY_ACTIVE_CODE);
+
+        state
+            .end_manual_maintenance()
+            .expect("end manual maintenance");
+        assert!(ensure_db_writable(&state).is_ok());
+        let err = state
+            .end_manual_maintenance()
+            .expect_err("ending twice should fail");
+        assert_eq!(err.code(), MAINTENANCE_NOT_ACTIVE_CODE);
+    }
+
+    #[tokio::test]
+    async fn manual_maintenance_cannot_end_repair_maintenance() {
+        let state = app_state_with_report(sample_report(DbHealthStatus::Ok));
+        let guard = state.begin_maintenance().expect("enter maintenance");
+        let err = state
+            .end_manual_maintenance()
+            .expect_err("repair maintenance is owned by its guard");
+        assert_eq!(err.code(), MAINTENANCE_NOT_ACTIVE_CODE);
+        assert!(ensure_db_writable(&state).is_err());
+        drop(guard);
+        assert!(ensure_db_writable(&state).is_ok());
+    }
+
+    #[tokio::test]
+    async fn denial_reasons_distinguish_cascade_and_maintenance() {
+        let mut report = sample_report(DbHealthStatus::Error);
+        report.checks.push(DbHealthCheck {
+            name: CASCADE_HEALTH_CHECK.to_string(),
+            passed: false,
+            duration_ms: 0,
+            details: Some("pending cascade".into()),
+        });
+        let state = app_state_with_report(report);
+        let cascade = ensure_db_writable(&state).expect_err("pending cascade blocks writes");
+        assert_eq!(cascade.code(), DB_UNHEALTHY_CODE);
+        assert_eq!(
+            cascade
+                .context()
+                .get(WRITE_BLOCK_REASON_KEY)
+                .map(String::as_str),
+            Some(WriteBlockReason::PendingCascade.as_str())
+        );
+
+        let unhealthy = app_state_with_report(sample_report(DbHealthStatus::Error));
+        let err = ensure_db_writable(&unhealthy).expect_err("unhealthy blocks writes");
+        assert_eq!(
+            err.context()
+                .get(WRITE_BLOCK_REASON_KEY)
+                .map(String::as_str),
+            Some(WriteBlockReason::Unhealthy.as_str())
+        );
+
+        let healthy = app_state_with_report(sample_report(DbHealthStatus::Ok));
+        let _guard = healthy.begin_maintenance().expect("enter maintenance");
+        let maintenance = ensure_db_writable(&healthy).expect_err("maintenance blocks writes");
+        assert_eq!(maintenance.code(), DB_MAINTENANCE_CODE);
+        assert_eq!(
+            maintenance
+                .context()
+                .get(WRITE_BLOCK_REASON_KEY)
+                .map(String::as_str),
+            Some(WriteBlockReason::Maintenance.as_str())
+        );
+    }
 }
@@ -0,0 +1,97 @@
+//! JSON Schema documents for IPC payload types.
+//!
+//! The schemas are derived from the same structs that back the generated
+//! TypeScript bindings, so payloads can be validated outside TypeScript.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use schemars::{schema::RootSchema, schema_for};
+
+use crate::{
+    db::health::DbHealthReport, import::plan::ImportPlan, util::dispatch_async_app_result,
+    AppError, AppResult, Event, Vehicle,
+};
+
+/// Every type with an emitted schema, keyed by its binding name.
+pub fn schema_documents() -> Vec<(&'static str, RootSchema)> {
+    vec![
+        ("AppError", schema_for!(AppError)),
+        ("DbHealthReport", schema_for!(DbHealthReport)),
+        ("Event", schema_for!(Event)),
+        ("ImportPlan", schema_for!(ImportPlan)),
+        ("Vehicle", schema_for!(Vehicle)),
+    ]
+}
+
+/// Write `<Name>.schema.json` for every schema document into `out_dir`.
+#[allow(clippy::result_large_err)]
+pub fn emit_json_schemas(out_dir: &Path) -> AppResult<Vec<PathBuf>> {
+    fs::create_dir_all(out_dir).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "emit_json_schema")
+            .with_context("path", out_dir.display().to_string())
+    })?;
+
+    let mut written = Vec::new();
+    for (name, schema) in schema_documents() {
+        let path = out_dir.join(format!("{name}.schema.json"));
+        let payload = serde_json::to_vec_pretty(&schema).map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "emit_json_schema")
+                .with_context("type", name)
+        })?;
+        crate::db::write_atomic(&path, &payload).map_err(|err| {
+            AppError::from(err)
+                .with_context("operation", "emit_json_schema")
+                .with_context("path", path.display().to_string())
+        })?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[tauri::command]
+pub async fn bindings_emit_json_schema(out_dir: String) -> AppResult<Vec<String>> {
+    dispatch_async_app_result(move || async move {
+        let paths = emit_json_schemas(Path::new(&out_dir))?;
+        Ok(paths
+            .into_iter()
+            .map(|path| path.display().to_string())
+            .collect())
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use tempfile::TempDir;
+
+    #[test]
+    fn event_schema_describes_fields() {
+        let schema = serde_json::to_value(schema_for!(Event)).unwrap();
+        let properties = &schema["properties"];
+        assert_eq!(properties["start_at_utc"]["type"], "integer");
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .expect("required list")
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        assert!(required.contains(&"title"));
+        assert!(!required.contains(&"end_at_utc"));
+    }
+
+    #[test]
+    fn emit_writes_one_document_per_type() {
+        let dir = TempDir::new().unwrap();
+        let written = emit_json_schemas(dir.path()).unwrap();
+        assert_eq!(written.len(), schema_documents().len());
+        let event: Value =
+            serde_json::from_slice(&fs::read(dir.path().join("Event.schema.json")).unwrap())
+                .unwrap();
+        assert_eq!(event["title"], "Event");
+    }
+}
@@ -4,18 +4,18 @@
 // - absolute-path access to macro_export items expanded in this crate (tauri __cmd__ helpers)
 // - deep json! expansions in tests that exceed the default recursion limit
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
-use anyhow::{Context, Result as AnyResult};
+use anyhow::Context;
 use base64::Engine;
 use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use image::codecs::jpeg::JpegEncoder;
 use image::GenericImageView;
 use once_cell::sync::OnceCell;
 use paste::paste;
+use schemars::JsonSchema;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 use sha1::{Digest as Sha1Digest, Sha1};
-use sha2::Sha256;
 use sqlx::{Row, SqlitePool};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::{
@@ -36,8 +36,9 @@ use tracing_appender::non_blocking::{ErrorCounter, NonBlocking, WorkerGuard};
 use tracing_subscriber::{
     fmt::{self, time::UtcTime, MakeWriter},
     layer::SubscriberExt,
+    reload,
     util::SubscriberInitExt,
-    EnvFilter,
+    EnvFilter, Registry,
 };
 use ts_rs::TS; // for .encode on base64 engines
 
@@ -53,8 +54,10 @@ use crate::{
     file_ops::{
         attachments_repair as run_attachments_repair,
         attachments_repair_manifest_export as run_attachments_repair_manifest_export,
-        move_file as run_file_move, AttachmentsRepairRequest, AttachmentsRepairResponse,
-        FileMoveRequest, FileMoveResponse,
+        move_file as run_file_move, AttachmentCopyResponse, AttachmentHashBackfillResponse,
+        AttachmentMoveResponse, AttachmentRestoreResponse, AttachmentsImportDirResponse,
+        AttachmentsRepairRequest, AttachmentsRepairResponse, FileMoveRequest, FileMoveResponse,
+        HouseholdIntegrityIssue, RowReassignResponse,
     },
     files_indexer::{IndexProgress, IndexerState, RebuildMode},
     household_active::ActiveSetError,
@@ -74,6 +77,8 @@ static FILE_LOG_WRITER: OnceCell<NonBlocking> = OnceCell::new();
 static FILE_LOG_GUARD: OnceCell<WorkerGuard> = OnceCell::new();
 static LOG_DROPPED_COUNTER: OnceCell<ErrorCounter> = OnceCell::new();
 static LOG_IO_ERROR: AtomicBool = AtomicBool::new(false);
+static LOG_FILTER_HANDLE: OnceCell<reload::Handle<EnvFilter, Registry>> = OnceCell::new();
+static LOG_FILTER_DIRECTIVE: Mutex<String> = Mutex::new(String::new());
 
 #[derive(Clone, Default)]
 struct RotatingFileWriter;
@@ -136,12 +141,16 @@ impl<'a> MakeWriter<'a> for RotatingFileWriter {
 
 pub mod attachment_category;
 mod attachments;
+pub mod calendar_range;
 mod categories;
 pub mod commands;
 pub mod commands_family;
 pub mod db;
+pub mod deletions_audit;
 pub mod diagnostics;
+pub mod due;
 pub mod error;
+pub mod event_attendees;
 pub mod events_tz_backfill;
 pub mod exdate;
 pub mod export;
@@ -153,16 +162,20 @@ mod household; // declare module; avoid `use` to prevent name collision
 pub mod household_active;
 pub mod pets;
 pub use household::{
-    acknowledge_vacuum, assert_household_active, cascade_phase_tables, create_household,
-    default_household_id, delete_household, ensure_household_invariants, get_household,
-    list_households, pending_cascades, restore_household, resume_household_delete,
-    update_household, vacuum_queue, CascadeDeleteOptions, CascadeProgress, CascadeProgressObserver,
-    DeleteOutcome, HouseholdCrudError, HouseholdGuardError, HouseholdRecord, HouseholdUpdateInput,
+    acknowledge_vacuum, assert_household_active, cascade_phase_tables, count_cascade_rows,
+    create_household, default_household_id, delete_household, ensure_household_invariants,
+    get_household, list_households, pending_cascades, reconcile_cascade_checkpoints,
+    restore_household, resume_household_delete, seed_household_defaults, update_household,
+    vacuum_queue, CascadeDeleteOptions, CascadePhaseCount, CascadeProgress,
+    CascadeProgressObserver, CascadeRowCounts, DeleteOutcome, HouseholdCrudError,
+    HouseholdGuardError, HouseholdRecord, HouseholdUpdateInput, SeedDefaultsReport,
 };
 mod id;
 pub mod import;
 mod importer;
+pub mod inventory_report;
 pub mod ipc;
+pub mod json_schema;
 pub mod logging;
 pub mod migrate;
 pub mod migration_guard;
@@ -170,10 +183,18 @@ pub mod model_family;
 pub mod note_links;
 mod notes;
 pub mod ops;
+pub mod recurrence;
+pub mod recurring_expenses;
 mod repo;
 pub mod repo_family;
+pub mod schedule;
+pub mod search_dates;
+pub mod search_fold;
 pub mod security;
+pub mod settings;
+pub mod shopping;
 mod state;
+pub use repo::GET_MANY_MAX_IDS;
 pub use state::AppState;
 mod time;
 pub mod time_errors;
@@ -183,23 +204,32 @@ pub mod util;
 pub mod vault;
 pub use self::vault::Vault;
 pub mod vault_migration;
+pub mod vehicle_costs;
 
+use crate::time::timezones_list;
+use calendar_range::{day_bounds_utc, period_bounds_utc};
 use categories::{
     categories_create, categories_delete, categories_get, categories_list, categories_restore,
     categories_update,
 };
+use deletions_audit::deletions_audit_list;
 pub use error::{AppError, AppResult, ErrorDto};
+use event_attendees::{event_attendees_list, event_attendees_set};
 use events_tz_backfill::{
     events_backfill_timezone, events_backfill_timezone_cancel, events_backfill_timezone_status,
 };
+use inventory_report::inventory_report;
 use note_links::{
     note_links_create, note_links_delete, note_links_get_for_note, note_links_list_by_entity,
-    note_links_unlink_entity, notes_list_for_entity, notes_quick_create_for_entity,
+    note_links_unlink_entity, notes_create_with_links, notes_list_for_entity,
+    notes_quick_create_for_entity,
 };
 use notes::{
     notes_create, notes_delete, notes_get, notes_list_by_deadline_range, notes_list_cursor,
-    notes_restore, notes_update,
+    notes_restore, notes_set_pinned, notes_update,
 };
+use recurrence::rrule_validate;
+use recurring_expenses::expenses_generate_from_templates;
 
 #[cfg(test)]
 mod cascade_health_tests {
@@ -217,17 +247,7 @@ mod cascade_health_tests {
         sync::{atomic::AtomicBool, Arc, Mutex, RwLock},
     };
 
-    #[tokio::test]
-    async fn pending_cascade_blocks_writes_via_health_cache() -> Result<()> {
-        let pool = SqlitePoolOptions::new()
-            .max_connections(1)
-            .connect("sqlite::memory:")
-            .await?;
-        sqlx::query("PRAGMA foreign_keys=ON;")
-            .execute(&pool)
-            .await?;
-        crate::migrate::apply_migrations(&pool).await?;
-
+    fn test_state(pool: &SqlitePool, db_path: PathBuf, attachments: PathBuf) -> AppState {
         let report = DbHealthReport {
             status: DbHealthStatus::Ok,
             checks: Vec::new(),
@@ -236,24 +256,41 @@ mod cascade_health_tests {
             app_version: "test".into(),
             generated_at: "2024-01-01T00:00:00Z".into(),
         };
-
-        let attachments = PathBuf::from("test.attachments");
         let vault = Arc::new(crate::vault::Vault::new(attachments.clone()));
-        let state = AppState {
+        AppState {
             pool: Arc::new(RwLock::new(pool.clone())),
             active_household_id: Arc::new(Mutex::new(String::new())),
             store: StoreHandle::in_memory(),
             backfill: Arc::new(Mutex::new(BackfillCoordinator::new())),
             db_health: Arc::new(Mutex::new(report)),
-            db_path: Arc::new(PathBuf::from("test.sqlite")),
+            db_path: Arc::new(db_path),
             vault: vault.clone(),
             vault_migration: Arc::new(
                 crate::vault_migration::VaultMigrationManager::new(&attachments).unwrap(),
             ),
             maintenance: Arc::new(AtomicBool::new(false)),
+            maintenance_reason: Arc::new(Mutex::new(None)),
             files_indexer: Arc::new(crate::files_indexer::FilesIndexer::new(pool.clone(), vault)),
             pet_metrics: Arc::new(PetAttachmentMetrics::new()),
-        };
+        }
+    }
+
+    #[tokio::test]
+    async fn pending_cascade_blocks_writes_via_health_cache() -> Result<()> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+        sqlx::query("PRAGMA foreign_keys=ON;")
+            .execute(&pool)
+            .await?;
+        crate::migrate::apply_migrations(&pool).await?;
+
+        let state = test_state(
+            &pool,
+            PathBuf::from("test.sqlite"),
+            PathBuf::from("test.attachments"),
+        );
 
         let household = crate::household::create_household(&pool, "Health", None).await?;
         let _ = crate::household::pending_cascades(&pool).await?;
@@ -269,10 +306,38 @@ mod cascade_health_tests {
         assert_eq!(err.code(), guard::DB_UNHEALTHY_CODE);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn stale_checkpoint_is_reconciled_and_health_recovers() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let db_path = dir.path().join("arklowdun.sqlite3");
+        let pool = crate::db::connect_sqlite_pool(&db_path).await?;
+        crate::migrate::apply_migrations(&pool).await?;
+        let state = test_state(&pool, db_path, dir.path().join("attachments"));
+
+        let _ = crate::household::pending_cascades(&pool).await?;
+        sqlx::query(
+            "INSERT INTO cascade_checkpoints (household_id, phase_index, deleted_count, total, phase, updated_at_utc, vacuum_pending, remaining_paths)\n             VALUES ('hh_gone', 3, 12, 12, 'events', 1, 0, 0)",
+        )
+        .execute(&pool)
+        .await?;
+        update_cascade_health_cache(&state, &["hh_gone".to_string()])?;
+        assert!(guard::ensure_db_writable(&state).is_err());
+
+        sync_cascade_health(&state, &pool).await?;
+        assert!(crate::household::pending_cascades(&pool).await?.is_empty());
+        assert_eq!(state.db_health.lock().unwrap().status, DbHealthStatus::Ok);
+        assert!(guard::ensure_db_writable(&state).is_ok());
+        let queue = crate::household::vacuum_queue(&pool).await?;
+        assert!(queue.iter().any(|entry| entry.household_id == "hh_gone"));
+        Ok(())
+    }
 }
 use security::{error_map::UiError, fs_policy, fs_policy::RootKey, hash_path};
+use shopping::{shopping_items_complete, shopping_items_list};
 use util::dispatch_async_app_result;
 use vault_migration::{MigrationMode, MigrationProgress, VaultMigrationManager};
+use vehicle_costs::vehicle_maintenance_summary;
 
 // Simple count-based rotating writer that rotates before writing
 // when the next write would exceed the size limit, ensuring whole-line writes
@@ -404,23 +469,56 @@ pub(crate) fn log_io_error_detected() -> bool {
     LOG_IO_ERROR.load(Ordering::Relaxed)
 }
 
-pub fn init_logging() {
-    let filter = std::env::var("TAURI_ARKLOWDUN_LOG")
-        .unwrap_or_else(|_| "arklowdun=info,sqlx=warn".to_string());
+/// Output format for the stdout log layer, chosen with
+/// `TAURI_ARKLOWDUN_LOG_FORMAT`. The file layer is always JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StdoutLogFormat {
+    #[default]
+    Json,
+    Pretty,
+}
 
-    // Forward `log` crate macros to the `tracing` subscriber so that
-    // `log::info!`/`log::error!` statements are captured alongside
-    // existing `tracing` instrumentation and end up in the persistent
-    // log directory.
-    let _ = tracing_log::LogTracer::init();
+impl StdoutLogFormat {
+    /// Parse the env value; anything other than `pretty` keeps JSON.
+    pub fn from_env_value(value: Option<&str>) -> Self {
+        match value.map(str::trim) {
+            Some(v) if v.eq_ignore_ascii_case("pretty") => Self::Pretty,
+            _ => Self::Json,
+        }
+    }
+}
 
-    let stdout_layer = fmt::layer()
-        .with_writer(io::stdout)
-        .json()
-        .with_target(true)
-        .with_timer(UtcTime::rfc_3339())
-        .with_current_span(false)
-        .with_span_list(false);
+fn build_subscriber(
+    filter: &str,
+    format: StdoutLogFormat,
+) -> (
+    impl tracing::Subscriber + Send + Sync + 'static,
+    reload::Handle<EnvFilter, Registry>,
+) {
+    let (json_stdout, pretty_stdout) = match format {
+        StdoutLogFormat::Json => (
+            Some(
+                fmt::layer()
+                    .with_writer(io::stdout)
+                    .json()
+                    .with_target(true)
+                    .with_timer(UtcTime::rfc_3339())
+                    .with_current_span(false)
+                    .with_span_list(false),
+            ),
+            None,
+        ),
+        StdoutLogFormat::Pretty => (
+            None,
+            Some(
+                fmt::layer()
+                    .with_writer(io::stdout)
+                    .pretty()
+                    .with_target(true)
+                    .with_timer(UtcTime::rfc_3339()),
+            ),
+        ),
+    };
 
     let file_layer = fmt::layer()
         .with_writer(RotatingFileWriter)
@@ -430,15 +528,108 @@ pub fn init_logging() {
         .with_current_span(false)
         .with_span_list(false);
 
+    let (filter_layer, filter_handle) = reload::Layer::new(EnvFilter::new(filter));
     let subscriber = tracing_subscriber::registry()
-        .with(EnvFilter::new(filter))
-        .with(stdout_layer)
+        .with(filter_layer)
+        .with(json_stdout)
+        .with(pretty_stdout)
         .with(file_layer);
+    (subscriber, filter_handle)
+}
+
+pub fn init_logging() {
+    let filter = std::env::var("TAURI_ARKLOWDUN_LOG")
+        .unwrap_or_else(|_| "arklowdun=info,sqlx=warn".to_string());
+    let format = StdoutLogFormat::from_env_value(
+        std::env::var("TAURI_ARKLOWDUN_LOG_FORMAT").ok().as_deref(),
+    );
+
+    // Forward `log` crate macros to the `tracing` subscriber so that
+    // `log::info!`/`log::error!` statements are captured alongside
+    // existing `tracing` instrumentation and end up in the persistent
+    // log directory.
+    let _ = tracing_log::LogTracer::init();
 
-    let _ = subscriber.try_init();
+    let (subscriber, filter_handle) = build_subscriber(&filter, format);
+    if subscriber.try_init().is_ok() {
+        let _ = LOG_FILTER_HANDLE.set(filter_handle);
+        if let Ok(mut current) = LOG_FILTER_DIRECTIVE.lock() {
+            *current = filter;
+        }
+    }
     crate::error::install_panic_hook();
 }
 
+/// Replace the `EnvFilter` on the running subscriber with `directive` and
+/// return the directive it replaces. The directive is parsed before anything
+/// is swapped, so an invalid string leaves the active filter untouched.
+#[allow(clippy::result_large_err)]
+pub fn set_log_filter(directive: &str) -> AppResult<String> {
+    let filter = EnvFilter::try_new(directive).map_err(|err| {
+        AppError::new(
+            "LOGGING/INVALID_FILTER",
+            "Log filter directive is not valid.",
+        )
+        .with_context("directive", directive.to_string())
+        .with_context("error", err.to_string())
+    })?;
+    let handle = LOG_FILTER_HANDLE.get().ok_or_else(|| {
+        AppError::new(
+            "LOGGING/NOT_INITIALIZED",
+            "Logging has not been initialised in this process.",
+        )
+    })?;
+    let mut current = LOG_FILTER_DIRECTIVE
+        .lock()
+        .map_err(|_| AppError::new("LOGGING/STATE_POISONED", "Log filter state is poisoned."))?;
+    handle.reload(filter).map_err(|err| {
+        AppError::new("LOGGING/RELOAD_FAILED", "Failed to apply the log filter.")
+            .with_context("error", err.to_string())
+    })?;
+    let previous = std::mem::replace(&mut *current, directive.to_string());
+    drop(current);
+    tracing::info!(
+        target: "arklowdun",
+        event = "log_filter_changed",
+        previous = %previous,
+        directive = %directive,
+    );
+    Ok(previous)
+}
+
+/// `directive` with its bare `arklowdun=` entry set to `level`, appended when
+/// absent. Every other directive is kept as written.
+fn with_arklowdun_level(directive: &str, level: &str) -> String {
+    let mut replaced = false;
+    let mut parts: Vec<String> = directive
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.strip_prefix("arklowdun=") {
+            Some(_) => {
+                replaced = true;
+                format!("arklowdun={level}")
+            }
+            None => part.to_string(),
+        })
+        .collect();
+    if !replaced {
+        parts.push(format!("arklowdun={level}"));
+    }
+    parts.join(",")
+}
+
+/// Set the level of the `arklowdun` target on the running filter, leaving
+/// the rest of the directive alone. Returns the directive it replaces.
+#[allow(clippy::result_large_err)]
+pub fn set_log_level(level: &str) -> AppResult<String> {
+    let current = LOG_FILTER_DIRECTIVE
+        .lock()
+        .map_err(|_| AppError::new("LOGGING/STATE_POISONED", "Log filter state is poisoned."))?
+        .clone();
+    set_log_filter(&with_arklowdun_level(&current, level))
+}
+
 pub(crate) fn git_commit_hash() -> &'static str {
     option_env!("ARK_GIT_HASH").unwrap_or("unknown")
 }
@@ -612,6 +803,77 @@ fn file_logging_limits() -> (u64, usize) {
     (max_bytes, max_files)
 }
 
+/// Size and retention of the log directory.
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct LogsStatus {
+    pub directory: String,
+    /// Size of the active `arklowdun.log`.
+    #[ts(type = "number")]
+    pub current_bytes: u64,
+    /// Number of rolled `arklowdun.log.N` files on disk.
+    pub rolled_files: u32,
+    /// Combined size of the active and rolled files.
+    #[ts(type = "number")]
+    pub total_bytes: u64,
+    /// Modification time of `arklowdun.log.1`, i.e. roughly when the log last
+    /// rotated, in milliseconds since the epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional, type = "number")]
+    pub last_rotated_at: Option<i64>,
+    #[ts(type = "number")]
+    pub max_bytes: u64,
+    pub max_files: u32,
+}
+
+/// Inspect the log files in `dir` against the configured rotation limits.
+pub fn logs_status_for_dir(dir: &Path) -> LogsStatus {
+    let (max_bytes, max_files) = file_logging_limits();
+    let rolled_prefix = format!("{LOG_FILE_NAME}.");
+    let mut current_bytes = 0;
+    let mut rolled_files = 0;
+    let mut total_bytes = 0;
+    let mut last_rotated_at = None;
+
+    for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if !meta.is_file() {
+            continue;
+        }
+        if name == LOG_FILE_NAME {
+            current_bytes = meta.len();
+        } else if name
+            .strip_prefix(&rolled_prefix)
+            .is_some_and(|suffix| suffix.parse::<usize>().is_ok())
+        {
+            rolled_files += 1;
+            if name == format!("{rolled_prefix}1") {
+                last_rotated_at = meta
+                    .modified()
+                    .ok()
+                    .map(|time| DateTime::<Utc>::from(time).timestamp_millis());
+            }
+        } else {
+            continue;
+        }
+        total_bytes += meta.len();
+    }
+
+    LogsStatus {
+        directory: dir.display().to_string(),
+        current_bytes,
+        rolled_files,
+        total_bytes,
+        last_rotated_at,
+        max_bytes,
+        max_files: u32::try_from(max_files).unwrap_or(u32::MAX),
+    }
+}
+
 pub fn log_fs_ok(root: RootKey, real: &std::path::Path) {
     tracing::info!(
         target: "arklowdun",
@@ -660,21 +922,27 @@ macro_rules! gen_domain_cmds_ns {
     ( $( $table:ident ),+ $(,)? ) => {
         paste! {
             $(
-                mod [<__gen_ $table>] {
+                mod [<__gen_list_ $table>] {
                     use super::*;
 
                     #[tauri::command]
+                    #[allow(clippy::too_many_arguments)]
                     pub async fn [<$table _list>](
                         state: State<'_, AppState>,
                         household_id: String,
                         order_by: Option<String>,
                         limit: Option<i64>,
                         offset: Option<i64>,
+                        date_field: Option<String>,
+                        from_ms: Option<i64>,
+                        to_ms: Option<i64>,
                     ) -> AppResult<Vec<serde_json::Value>> {
                         let pool = state.pool_clone();
                         dispatch_async_app_result(move || {
                             let order_by = order_by;
                             let household_id = household_id;
+                            let date_range =
+                                commands::ListDateRange::from_parts(date_field, from_ms, to_ms);
                             async move {
                                 commands::list_command(
                                     &pool,
@@ -683,12 +951,27 @@ macro_rules! gen_domain_cmds_ns {
                                     order_by.as_deref(),
                                     limit,
                                     offset,
+                                    date_range.as_ref(),
                                 )
                                 .await
                             }
                         })
                         .await
                     }
+                }
+
+                pub use [<__gen_list_ $table>]::[<$table _list>];
+            )+
+        }
+        gen_domain_cmds_ns!(@without_list $( $table ),+);
+    };
+    // Everything but `<table>_list`, for tables whose list command takes
+    // extra filters and is written by hand.
+    (@without_list $( $table:ident ),+ $(,)? ) => {
+        paste! {
+            $(
+                mod [<__gen_ $table>] {
+                    use super::*;
 
                     #[tauri::command]
                     pub async fn [<$table _get>](
@@ -714,6 +997,29 @@ macro_rules! gen_domain_cmds_ns {
                         .await
                     }
 
+                    #[tauri::command]
+                    pub async fn [<$table _get_many>](
+                        state: State<'_, AppState>,
+                        household_id: String,
+                        ids: Vec<String>,
+                    ) -> AppResult<Vec<serde_json::Value>> {
+                        let pool = state.pool_clone();
+                        dispatch_async_app_result(move || {
+                            let household_id = household_id;
+                            let ids = ids;
+                            async move {
+                                commands::get_many_command(
+                                    &pool,
+                                    stringify!($table),
+                                    &household_id,
+                                    &ids,
+                                )
+                                .await
+                            }
+                        })
+                        .await
+                    }
+
                     #[tauri::command]
                     pub async fn [<$table _create>](
                         state: State<'_, AppState>,
@@ -780,6 +1086,7 @@ macro_rules! gen_domain_cmds_ns {
                         id: String,
                         data: serde_json::Map<String, serde_json::Value>,
                         household_id: Option<String>,
+                        expected_updated_at: Option<i64>,
                     ) -> AppResult<()> {
                         let family_scope_info = if stringify!($table) == "family_members" {
                             let household = household_id
@@ -837,6 +1144,7 @@ macro_rules! gen_domain_cmds_ns {
                                     data,
                                     hh,
                                     guard,
+                                    expected_updated_at,
                                 )
                                 .await
                             }
@@ -946,8 +1254,8 @@ macro_rules! gen_domain_cmds_ns {
                 }
 
                 pub use [<__gen_ $table>]::{
-                    [<$table _list>],
                     [<$table _get>],
+                    [<$table _get_many>],
                     [<$table _create>],
                     [<$table _update>],
                     [<$table _delete>],
@@ -970,8 +1278,9 @@ gen_domain_cmds_ns!(
     family_members,
     budget_categories,
     expenses,
-    shopping_items,
+    expense_templates,
 );
+gen_domain_cmds_ns!(@without_list shopping_items);
 
 pub mod vehicles_api {
     use super::*;
@@ -996,6 +1305,7 @@ pub mod vehicles_api {
                     order_by.as_deref(),
                     limit,
                     offset,
+                    None,
                 )
                 .await
             }
@@ -1253,7 +1563,7 @@ async fn pets_delete_hard(
     .await
 }
 
-#[derive(Serialize, Deserialize, Clone, TS, sqlx::FromRow)]
+#[derive(Serialize, Deserialize, Clone, TS, JsonSchema, sqlx::FromRow)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct Vehicle {
     #[serde(default)]
@@ -1520,7 +1830,7 @@ pub struct HouseholdSummary {
     pub color: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[derive(Serialize, Deserialize, Clone, TS, JsonSchema, Debug)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct Event {
     #[serde(default)]
@@ -1585,6 +1895,41 @@ async fn events_list_range(
     .await
 }
 
+#[tauri::command]
+async fn events_find_conflicts(
+    state: State<'_, AppState>,
+    household_id: String,
+    start: i64,
+    end: i64,
+    member_id: Option<String>,
+) -> AppResult<schedule::EventConflictsResponse> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        let member_id = member_id;
+        async move {
+            schedule::find_event_conflicts(&pool, &household_id, start, end, member_id.as_deref())
+                .await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn family_member_busy(
+    state: State<'_, AppState>,
+    member_id: String,
+    start: i64,
+    end: i64,
+) -> AppResult<schedule::MemberBusyResponse> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let member_id = member_id;
+        async move { schedule::family_member_busy(&pool, &member_id, start, end).await }
+    })
+    .await
+}
+
 #[tauri::command]
 async fn event_create(
     state: State<'_, AppState>,
@@ -1605,6 +1950,7 @@ async fn event_update(
     id: String,
     data: serde_json::Map<String, serde_json::Value>,
     household_id: String,
+    expected_updated_at: Option<i64>,
 ) -> AppResult<()> {
     let _permit = guard::ensure_db_writable(&state)?;
     let pool = state.pool_clone();
@@ -1613,7 +1959,16 @@ async fn event_update(
         let data = data;
         let household_id = household_id;
         async move {
-            commands::update_command(&pool, "events", &id, data, Some(&household_id), None).await
+            commands::update_command(
+                &pool,
+                "events",
+                &id,
+                data,
+                Some(&household_id),
+                None,
+                expected_updated_at,
+            )
+            .await
         }
     })
     .await
@@ -1652,84 +2007,332 @@ async fn event_restore(
 }
 
 #[tauri::command]
-async fn bills_list_due_between(
+async fn event_duplicate(
     state: State<'_, AppState>,
     household_id: String,
-    from_ms: i64,
-    to_ms: i64,
-    limit: Option<i64>,
-    offset: Option<i64>,
-) -> AppResult<Vec<serde_json::Value>> {
-    use sqlx::query;
+    id: String,
+    new_start_utc: i64,
+) -> AppResult<serde_json::Value> {
+    let _permit = guard::ensure_db_writable(&state)?;
     let pool = state.pool_clone();
     dispatch_async_app_result(move || {
         let household_id = household_id;
-        async move {
-            let base_sql = r#"
-        SELECT * FROM bills
-        WHERE household_id = ?1
-          AND deleted_at IS NULL
-          AND due_date >= ?2
-          AND due_date <= ?3
-        ORDER BY due_date ASC, created_at ASC, id ASC
-    "#;
-
-            let mut sql = base_sql.to_string();
-            let limit_value = limit.filter(|value| *value > 0);
-            let offset_value = offset.filter(|value| *value > 0);
-            if limit_value.is_some() {
-                sql.push_str(" LIMIT ?4");
-            }
-            if offset_value.is_some() {
-                sql.push_str(" OFFSET ?5");
-            }
-
-            let mut q = query(&sql).bind(&household_id).bind(from_ms).bind(to_ms);
-
-            if let Some(value) = limit_value {
-                q = q.bind(value);
-            }
-            if let Some(value) = offset_value {
-                q = q.bind(value);
-            }
-
-            let rows = q.fetch_all(&pool).await.map_err(|err| {
-                AppError::from(err)
-                    .with_context("operation", "bills_list_due_between")
-                    .with_context("household_id", household_id.clone())
-            })?;
+        let id = id;
+        async move { commands::duplicate_event(&pool, &household_id, &id, new_start_utc).await }
+    })
+    .await
+}
 
-            Ok(rows.into_iter().map(crate::repo::row_to_json).collect())
-        }
+#[tauri::command]
+async fn event_export_one(
+    state: State<'_, AppState>,
+    household_id: String,
+    id: String,
+) -> AppResult<String> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        let id = id;
+        async move { commands::export_event(&pool, &household_id, &id).await }
     })
     .await
 }
 
 #[tauri::command]
-async fn household_get_active(state: tauri::State<'_, state::AppState>) -> Result<String, String> {
+async fn event_import_one(
+    state: State<'_, AppState>,
+    household_id: String,
+    json: String,
+    dry_run: Option<bool>,
+) -> AppResult<serde_json::Value> {
+    let dry_run = dry_run.unwrap_or(false);
+    let _permit = if dry_run {
+        None
+    } else {
+        Some(guard::ensure_db_writable(&state)?)
+    };
     let pool = state.pool_clone();
-    let store = state.store.clone();
-    let id = crate::household_active::get_active_household_id(&pool, &store)
-        .await
-        .map_err(|err| err.to_string())?;
-    let mut guard = state
-        .active_household_id
-        .lock()
-        .map_err(|_| "STATE_LOCK_POISONED".to_string())?;
-    *guard = id.clone();
-    Ok(id)
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        let json = json;
+        async move { commands::import_event(&pool, &household_id, &json, dry_run).await }
+    })
+    .await
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct HouseholdCreateArgs {
-    name: String,
-    #[serde(default)]
-    color: Option<String>,
+#[tauri::command]
+async fn events_shift(
+    state: State<'_, AppState>,
+    household_id: String,
+    ids: Vec<String>,
+    delta_ms: i64,
+) -> AppResult<u64> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        let ids = ids;
+        async move { commands::shift_events(&pool, &household_id, &ids, delta_ms).await }
+    })
+    .await
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[tauri::command]
+async fn tables_last_modified(
+    state: State<'_, AppState>,
+    household_id: String,
+) -> AppResult<std::collections::BTreeMap<String, Option<i64>>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        async move { commands::tables_last_modified(&pool, &household_id).await }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn trash_list(
+    state: State<'_, AppState>,
+    household_id: String,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> AppResult<Vec<commands::TrashEntry>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        async move { commands::trash_list(&pool, &household_id, limit, offset).await }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn trash_restore(
+    state: State<'_, AppState>,
+    table: String,
+    id: String,
+) -> AppResult<serde_json::Value> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let table = table;
+        let id = id;
+        async move { commands::trash_restore(&pool, &table, &id).await }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn changes_since(
+    state: State<'_, AppState>,
+    household_id: String,
+    since_ms: i64,
+    tables: Option<Vec<String>>,
+    cursors: Option<std::collections::BTreeMap<String, commands::ChangesCursor>>,
+) -> AppResult<commands::ChangesSince> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        let tables = tables.unwrap_or_default();
+        let cursors = cursors.unwrap_or_default();
+        async move {
+            commands::changes_since(&pool, &household_id, since_ms, &tables, &cursors).await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn bills_list_due_between(
+    state: State<'_, AppState>,
+    household_id: String,
+    from_ms: i64,
+    to_ms: i64,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> AppResult<Vec<serde_json::Value>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        async move {
+            commands::bills_due_between(&pool, &household_id, from_ms, to_ms, limit, offset)
+                .await
+                .map(|page| page.items)
+        }
+    })
+    .await
+}
+
+/// Same window as [`bills_list_due_between`] plus `total` and `has_more`.
+#[tauri::command]
+async fn bills_list_due_between_paged(
+    state: State<'_, AppState>,
+    household_id: String,
+    from_ms: i64,
+    to_ms: i64,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> AppResult<commands::BillsDuePage> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        async move {
+            commands::bills_due_between(&pool, &household_id, from_ms, to_ms, limit, offset).await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn events_next_occurrences(
+    state: State<'_, AppState>,
+    household_id: String,
+    id: String,
+    count: Option<u16>,
+    after_utc: i64,
+) -> AppResult<Vec<i64>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let id = id.clone();
+        async move {
+            recurrence::next_occurrences(
+                &pool,
+                &household_id,
+                &id,
+                count.unwrap_or(recurrence::DEFAULT_PREVIEW_COUNT),
+                after_utc,
+            )
+            .await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn events_validate_recurrence(
+    state: State<'_, AppState>,
+    household_id: String,
+    fix: Option<bool>,
+) -> AppResult<recurrence::RecurrenceReport> {
+    let fix = fix.unwrap_or(false);
+    let _permit = if fix {
+        Some(guard::ensure_db_writable(&state)?)
+    } else {
+        None
+    };
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        async move {
+            let issues = recurrence::scan_event_recurrence(&pool, &household_id).await?;
+            let fixes = if fix {
+                recurrence::fix_event_recurrence(&pool, &household_id).await?
+            } else {
+                Vec::new()
+            };
+            Ok(recurrence::RecurrenceReport { issues, fixes })
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn vehicles_due_soon(
+    state: State<'_, AppState>,
+    household_id: String,
+    horizon_ms: i64,
+) -> AppResult<Vec<due::VehicleDueItem>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        async move {
+            due::vehicles_due_soon(&pool, &household_id, crate::time::now_ms(), horizon_ms).await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn pet_medical_due(
+    state: State<'_, AppState>,
+    household_id: String,
+    horizon_ms: i64,
+) -> AppResult<Vec<due::PetMedicalDueItem>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        async move {
+            due::pet_medical_due(&pool, &household_id, crate::time::now_ms(), horizon_ms).await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn policies_renewal_due(
+    state: State<'_, AppState>,
+    household_id: String,
+    horizon_ms: i64,
+) -> AppResult<Vec<due::PolicyRenewalDueItem>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "policies_renewal_due")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            due::policies_renewal_due(&pool, &household_id, crate::time::now_ms(), horizon_ms).await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn upcoming_summary(
+    state: State<'_, AppState>,
+    household_id: String,
+    horizon_ms: i64,
+) -> AppResult<due::UpcomingSummary> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id;
+        async move {
+            due::upcoming_summary(&pool, &household_id, crate::time::now_ms(), horizon_ms).await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn household_get_active(state: tauri::State<'_, state::AppState>) -> Result<String, String> {
+    let pool = state.pool_clone();
+    let store = state.store.clone();
+    let id = crate::household_active::get_active_household_id(&pool, &store)
+        .await
+        .map_err(|err| err.to_string())?;
+    let mut guard = state
+        .active_household_id
+        .lock()
+        .map_err(|_| "STATE_LOCK_POISONED".to_string())?;
+    *guard = id.clone();
+    Ok(id)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HouseholdCreateArgs {
+    name: String,
+    #[serde(default)]
+    color: Option<String>,
+    /// Seed the default categories and budget categories after creating.
+    #[serde(default)]
+    seed_defaults: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct HouseholdUpdateArgs {
     id: String,
     name: Option<String>,
@@ -1765,7 +2368,7 @@ fn map_household_crud_error(err: crate::household::HouseholdCrudError) -> AppErr
             AppError::new("HOUSEHOLD_DELETED", "Household is deleted.")
         }
         crate::household::HouseholdCrudError::InvalidColor => {
-            AppError::new("INVALID_COLOR", "Please use a hex colour like #2563EB.")
+            AppError::new(util::INVALID_COLOR_CODE, util::INVALID_COLOR_MESSAGE)
         }
         crate::household::HouseholdCrudError::CascadeDbNotEmpty => AppError::new(
             "CASCADE_DB_NOT_EMPTY",
@@ -1853,6 +2456,9 @@ fn update_cascade_health_cache(state: &state::AppState, offenders: &[String]) ->
 }
 
 async fn sync_cascade_health(state: &state::AppState, pool: &SqlitePool) -> AppResult<()> {
+    crate::household::reconcile_cascade_checkpoints(pool, state.vault().as_ref())
+        .await
+        .map_err(map_household_crud_error)?;
     let pending = crate::household::pending_cascades(pool)
         .await
         .map_err(map_household_crud_error)?;
@@ -1939,6 +2545,24 @@ async fn household_get(
     .await
 }
 
+#[tauri::command]
+async fn household_cascade_counts(
+    state: State<'_, AppState>,
+    id: String,
+) -> AppResult<crate::household::CascadeRowCounts> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let pool = pool.clone();
+        let id = id.clone();
+        async move {
+            crate::household::count_cascade_rows(&pool, &id)
+                .await
+                .map_err(map_household_crud_error)
+        }
+    })
+    .await
+}
+
 #[tauri::command]
 async fn household_create(
     state: State<'_, AppState>,
@@ -1948,12 +2572,19 @@ async fn household_create(
     let pool = state.pool_clone();
     let name = args.name;
     let color = args.color;
+    let seed_defaults = args.seed_defaults;
     let result = dispatch_async_app_result(move || {
         let pool = pool.clone();
         async move {
-            crate::household::create_household(&pool, &name, color.as_deref())
+            let record = crate::household::create_household(&pool, &name, color.as_deref())
                 .await
-                .map_err(map_household_crud_error)
+                .map_err(map_household_crud_error)?;
+            if seed_defaults {
+                crate::household::seed_household_defaults(&pool, &record.id)
+                    .await
+                    .map_err(map_household_crud_error)?;
+            }
+            Ok(record)
         }
     })
     .await;
@@ -1983,6 +2614,26 @@ async fn household_create(
     }
 }
 
+#[tauri::command]
+async fn household_seed_defaults(
+    state: State<'_, AppState>,
+    household_id: String,
+) -> AppResult<SeedDefaultsReport> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let pool = pool.clone();
+        async move {
+            crate::household::seed_household_defaults(&pool, &household_id)
+                .await
+                .map_err(|err| {
+                    map_household_crud_error(err).with_context("household_id", household_id.clone())
+                })
+        }
+    })
+    .await
+}
+
 #[tauri::command]
 async fn household_update(
     state: State<'_, AppState>,
@@ -2093,6 +2744,7 @@ async fn household_delete<R: tauri::Runtime>(
     };
 
     sync_cascade_health(&state, &pool).await?;
+    refresh_household_stats_after(&pool, &vault, "household_delete").await;
 
     if let Some(ref fallback) = outcome.fallback_id {
         match crate::household_active::set_active_household_id(&pool, &state.store, fallback).await
@@ -2198,6 +2850,7 @@ async fn household_resume_delete<R: tauri::Runtime>(
     };
 
     sync_cascade_health(&state, &pool).await?;
+    refresh_household_stats_after(&pool, &vault, "household_resume_delete").await;
 
     if let Some(ref fallback) = outcome.fallback_id {
         match crate::household_active::set_active_household_id(&pool, &state.store, fallback).await
@@ -2321,6 +2974,7 @@ async fn household_repair<R: tauri::Runtime>(
     };
 
     sync_cascade_health(&state, &pool).await?;
+    refresh_household_stats_after(&pool, &vault, "household_repair").await;
 
     if let Some(ref fallback) = outcome.fallback_id {
         match crate::household_active::set_active_household_id(&pool, &state.store, fallback).await
@@ -2379,6 +3033,18 @@ async fn household_repair<R: tauri::Runtime>(
     })
 }
 
+/// Clear stale cascade checkpoints and refresh the cached health report.
+#[tauri::command]
+async fn household_cascade_reconcile(state: State<'_, AppState>) -> AppResult<Vec<String>> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    let cleared = crate::household::reconcile_cascade_checkpoints(&pool, state.vault().as_ref())
+        .await
+        .map_err(map_household_crud_error)?;
+    sync_cascade_health(&state, &pool).await?;
+    Ok(cleared)
+}
+
 #[tauri::command]
 async fn household_vacuum_execute(state: State<'_, AppState>, id: String) -> AppResult<()> {
     let _permit = guard::ensure_db_writable(&state)?;
@@ -2567,6 +3233,10 @@ struct ImportArgs {
 struct ImportPreviewArgs {
     bundle_path: String,
     mode: import::plan::ImportMode,
+    #[serde(default)]
+    allow_newer: bool,
+    #[serde(flatten)]
+    tables: export::manifest::TableSelection,
 }
 
 #[derive(Deserialize)]
@@ -2575,6 +3245,10 @@ struct ImportExecuteArgs {
     bundle_path: String,
     mode: import::plan::ImportMode,
     expected_plan_digest: String,
+    #[serde(default)]
+    allow_newer: bool,
+    #[serde(flatten)]
+    tables: export::manifest::TableSelection,
 }
 
 #[tauri::command]
@@ -2629,7 +3303,12 @@ async fn db_import_preview(
     state: State<'_, AppState>,
     args: ImportPreviewArgs,
 ) -> AppResult<ImportPreviewDto> {
-    let ImportPreviewArgs { bundle_path, mode } = args;
+    let ImportPreviewArgs {
+        bundle_path,
+        mode,
+        allow_newer,
+        tables,
+    } = args;
     let pool = state.pool_clone();
     let db_path = (*state.db_path).clone();
     let (target_root, _) = resolve_import_paths(&db_path);
@@ -2640,48 +3319,55 @@ async fn db_import_preview(
         let vault = vault.clone();
         let bundle_path_buf = PathBuf::from(bundle_path.clone());
         async move {
-            let result: AnyResult<ImportPreviewDto> = async {
-                let bundle = import::bundle::ImportBundle::load(&bundle_path_buf)
-                    .map_err(anyhow::Error::new)
-                    .context("load import bundle")?;
-                let minimum_version = Version::parse(import::MIN_SUPPORTED_APP_VERSION)
-                    .context("parse minimum supported app version")?;
-                let validation_ctx = import::validator::ValidationContext {
-                    pool: &pool,
-                    target_root: target_root.as_path(),
-                    minimum_app_version: &minimum_version,
-                    available_space_override: None,
-                };
-                let validation = import::validate_bundle(&bundle, &validation_ctx)
-                    .await
-                    .map_err(anyhow::Error::new)
-                    .context("validate import bundle")?;
-                let plan_ctx = import::plan::PlanContext {
-                    pool: &pool,
-                    vault: vault.clone(),
-                };
-                let plan = import::build_plan(&bundle, &plan_ctx, mode)
-                    .await
-                    .map_err(anyhow::Error::new)
-                    .context("build import plan")?;
-                let plan_digest = compute_plan_digest(&plan)?;
-                Ok(ImportPreviewDto {
-                    bundle_path: bundle_path_buf.display().to_string(),
-                    mode,
-                    validation,
-                    plan,
-                    plan_digest,
-                })
-            }
-            .await;
-            result.map_err(AppError::from)
-        }
-    })
-    .await
-}
-
-#[tauri::command]
-async fn db_import_execute(
+            let mut bundle = import::bundle::ImportBundle::load(&bundle_path_buf)
+                .map_err(anyhow::Error::new)
+                .context("load import bundle")
+                .map_err(AppError::from)?;
+            bundle
+                .select_tables(&tables)
+                .map_err(anyhow::Error::new)
+                .context("select import tables")
+                .map_err(AppError::from)?;
+            let minimum_version = Version::parse(import::MIN_SUPPORTED_APP_VERSION)
+                .context("parse minimum supported app version")
+                .map_err(AppError::from)?;
+            let validation_ctx = import::validator::ValidationContext {
+                pool: &pool,
+                target_root: target_root.as_path(),
+                minimum_app_version: &minimum_version,
+                current_app_version: import::validator::current_app_version(),
+                allow_newer,
+                available_space_override: None,
+            };
+            let validation = import::validate_bundle(&bundle, &validation_ctx)
+                .await
+                .map_err(|err| {
+                    AppError::from(err).with_context("operation", "validate_import_bundle")
+                })?;
+            let plan_ctx = import::plan::PlanContext {
+                pool: &pool,
+                vault: vault.clone(),
+            };
+            let plan = import::build_plan(&bundle, &plan_ctx, mode)
+                .await
+                .map_err(|err| {
+                    AppError::from(err).with_context("operation", "build_import_plan")
+                })?;
+            let plan_digest = import::plan_digest(&plan).map_err(AppError::from)?;
+            Ok(ImportPreviewDto {
+                bundle_path: bundle_path_buf.display().to_string(),
+                mode,
+                validation,
+                plan,
+                plan_digest,
+            })
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn db_import_execute(
     state: State<'_, AppState>,
     args: ImportExecuteArgs,
 ) -> AppResult<ImportExecuteDto> {
@@ -2690,6 +3376,8 @@ async fn db_import_execute(
         bundle_path,
         mode,
         expected_plan_digest,
+        allow_newer,
+        tables,
     } = args;
     let pool = state.pool_clone();
     let db_path = (*state.db_path).clone();
@@ -2703,64 +3391,74 @@ async fn db_import_execute(
         let expected_digest = expected_plan_digest.clone();
         let bundle_path_buf = PathBuf::from(bundle_path.clone());
         async move {
-            let result: AnyResult<ImportExecuteDto> = async {
-                let bundle = import::bundle::ImportBundle::load(&bundle_path_buf)
-                    .map_err(anyhow::Error::new)
-                    .context("load import bundle")?;
-                let minimum_version = Version::parse(import::MIN_SUPPORTED_APP_VERSION)
-                    .context("parse minimum supported app version")?;
-                let validation_ctx = import::validator::ValidationContext {
-                    pool: &pool,
-                    target_root: target_root.as_path(),
-                    minimum_app_version: &minimum_version,
-                    available_space_override: None,
-                };
-                let validation = import::validate_bundle(&bundle, &validation_ctx)
-                    .await
-                    .map_err(anyhow::Error::new)
-                    .context("validate import bundle")?;
-                let plan_ctx = import::plan::PlanContext {
-                    pool: &pool,
-                    vault: vault.clone(),
-                };
-                let plan = import::build_plan(&bundle, &plan_ctx, mode)
-                    .await
-                    .map_err(anyhow::Error::new)
-                    .context("build import plan")?;
-                let plan_digest = compute_plan_digest(&plan)?;
-                if plan_digest != expected_digest {
-                    anyhow::bail!(
-                        "Import plan changed after preview. Run a new dry-run before importing."
-                    );
-                }
-                std::fs::create_dir_all(vault.base()).with_context(|| {
-                    format!("create attachments directory {}", vault.base().display())
+            let mut bundle = import::bundle::ImportBundle::load(&bundle_path_buf)
+                .map_err(anyhow::Error::new)
+                .context("load import bundle")
+                .map_err(AppError::from)?;
+            bundle
+                .select_tables(&tables)
+                .map_err(anyhow::Error::new)
+                .context("select import tables")
+                .map_err(AppError::from)?;
+            let minimum_version = Version::parse(import::MIN_SUPPORTED_APP_VERSION)
+                .context("parse minimum supported app version")
+                .map_err(AppError::from)?;
+            let validation_ctx = import::validator::ValidationContext {
+                pool: &pool,
+                target_root: target_root.as_path(),
+                minimum_app_version: &minimum_version,
+                current_app_version: import::validator::current_app_version(),
+                allow_newer,
+                available_space_override: None,
+            };
+            let validation = import::validate_bundle(&bundle, &validation_ctx)
+                .await
+                .map_err(|err| {
+                    AppError::from(err).with_context("operation", "validate_import_bundle")
                 })?;
-                let exec_ctx = import::execute::ExecutionContext::new(&pool, vault.clone());
-                let execution = import::execute::execute_plan(&bundle, &plan, &exec_ctx)
-                    .await
-                    .map_err(anyhow::Error::new)
-                    .context("execute import plan")?;
-                let report_path = import::write_import_report(
-                    &reports_dir,
-                    &bundle_path_buf,
-                    &validation,
-                    &plan,
-                    &execution,
-                )
-                .context("write import report")?;
-                Ok(ImportExecuteDto {
-                    bundle_path: bundle_path_buf.display().to_string(),
-                    mode,
-                    validation,
-                    plan,
-                    plan_digest,
-                    execution,
-                    report_path: report_path.display().to_string(),
-                })
+            let plan_ctx = import::plan::PlanContext {
+                pool: &pool,
+                vault: vault.clone(),
+            };
+            let plan = import::build_plan(&bundle, &plan_ctx, mode)
+                .await
+                .map_err(|err| {
+                    AppError::from(err).with_context("operation", "build_import_plan")
+                })?;
+            let plan_digest = import::plan_digest(&plan).map_err(AppError::from)?;
+            if plan_digest != expected_digest {
+                return Err(AppError::from(anyhow::anyhow!(
+                    "Import plan changed after preview. Run a new dry-run before importing."
+                )));
             }
-            .await;
-            result.map_err(AppError::from)
+            std::fs::create_dir_all(vault.base())
+                .with_context(|| format!("create attachments directory {}", vault.base().display()))
+                .map_err(AppError::from)?;
+            let exec_ctx = import::execute::ExecutionContext::new(&pool, vault.clone());
+            let execution = import::execute::execute_plan(&bundle, &plan, &exec_ctx)
+                .await
+                .map_err(anyhow::Error::new)
+                .context("execute import plan")
+                .map_err(AppError::from)?;
+            let report_path = import::write_import_report(
+                &reports_dir,
+                &bundle_path_buf,
+                &validation,
+                &plan,
+                &execution,
+            )
+            .context("write import report")
+            .map_err(AppError::from)?;
+            refresh_household_stats_after(&pool, &vault, "db_import_execute").await;
+            Ok(ImportExecuteDto {
+                bundle_path: bundle_path_buf.display().to_string(),
+                mode,
+                validation,
+                plan,
+                plan_digest,
+                execution,
+                report_path: report_path.display().to_string(),
+            })
         }
     })
     .await
@@ -2775,11 +3473,14 @@ fn resolve_import_paths(db_path: &Path) -> (PathBuf, PathBuf) {
     (target_root, reports_dir)
 }
 
-fn compute_plan_digest(plan: &import::plan::ImportPlan) -> AnyResult<String> {
-    let json = serde_json::to_vec(plan).context("serialize import plan for digest")?;
-    let mut hasher = Sha256::new();
-    hasher.update(&json);
-    Ok(format!("{:x}", hasher.finalize()))
+/// A matched range inside one field of a [`SearchResult`]. Offsets are UTF-16
+/// code units so the UI can slice the JavaScript string directly.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct SearchHighlight {
+    pub field: String,
+    pub start: u32,
+    pub end: u32,
 }
 
 #[derive(Serialize, Deserialize, Clone, TS)]
@@ -2791,6 +3492,8 @@ pub enum SearchResult {
         filename: String,
         #[ts(type = "number")]
         updated_at: i64,
+        #[serde(default)]
+        highlights: Vec<SearchHighlight>,
     },
     Event {
         id: String,
@@ -2798,6 +3501,8 @@ pub enum SearchResult {
         #[ts(type = "number")]
         start_at_utc: i64,
         tz: String,
+        #[serde(default)]
+        highlights: Vec<SearchHighlight>,
     },
     Note {
         id: String,
@@ -2805,6 +3510,8 @@ pub enum SearchResult {
         #[ts(type = "number")]
         updated_at: i64,
         color: String,
+        #[serde(default)]
+        highlights: Vec<SearchHighlight>,
     },
     Vehicle {
         id: String,
@@ -2814,6 +3521,8 @@ pub enum SearchResult {
         #[ts(type = "number")]
         updated_at: i64,
         nickname: String,
+        #[serde(default)]
+        highlights: Vec<SearchHighlight>,
     },
     Pet {
         id: String,
@@ -2821,9 +3530,21 @@ pub enum SearchResult {
         species: String,
         #[ts(type = "number")]
         updated_at: i64,
+        #[serde(default)]
+        highlights: Vec<SearchHighlight>,
     },
 }
 
+#[derive(Serialize, Deserialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct SearchResponse {
+    pub results: Vec<SearchResult>,
+    /// Set when a branch ran past the caller's timeout and was abandoned.
+    pub partial: bool,
+    #[serde(default)]
+    pub timed_out_branches: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct SearchErrorPayload {
     pub code: String,
@@ -2855,10 +3576,7 @@ async fn table_exists(pool: &sqlx::SqlitePool, name: &str) -> bool {
 }
 
 async fn files_index_ready(pool: &sqlx::SqlitePool, household_id: &str) -> bool {
-    if !table_exists(pool, "files_index").await
-        || !table_exists(pool, "files_index_meta").await
-        || !table_exists(pool, "files").await
-    {
+    if !table_exists(pool, "files_index").await || !table_exists(pool, "files_index_meta").await {
         return false;
     }
 
@@ -2887,23 +3605,12 @@ async fn files_index_ready(pool: &sqlx::SqlitePool, household_id: &str) -> bool
         _ => return false,
     };
 
-    let count: i64 =
-        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM files WHERE household_id=?1")
-            .bind(household_id)
-            .fetch_one(pool)
-            .await
-            .unwrap_or(0);
-
-    let max_updated: String = sqlx::query_scalar::<_, String>(
-        "SELECT COALESCE(strftime('%Y-%m-%dT%H:%M:%SZ', MAX(updated_at), 'unixepoch'), '1970-01-01T00:00:00Z') FROM files WHERE household_id=?1",
-    )
-    .bind(household_id)
-    .fetch_one(pool)
-    .await
-    .unwrap_or_else(|_| "1970-01-01T00:00:00Z".into());
-
-    // Rebuild tooling must persist `source_max_updated_utc` using the same strftime format
-    meta.0 == count && meta.1 == max_updated
+    // The rebuild and `files_indexer::refresh_meta` persist the same stats
+    // of the attachment rows, so any change since then reads as stale.
+    match files_indexer::source_stats(pool, household_id).await {
+        Ok((count, max_updated)) => meta.0 == count && meta.1 == max_updated,
+        Err(_) => false,
+    }
 }
 
 fn emit_index_state_event<R: tauri::Runtime>(
@@ -3157,6 +3864,51 @@ fn spawn_idle_index_scheduler<R: tauri::Runtime + 'static>(app: tauri::AppHandle
     });
 }
 
+fn spawn_db_upkeep_scheduler<R: tauri::Runtime + 'static>(
+    app: tauri::AppHandle<R>,
+    config: crate::db::upkeep::UpkeepConfig,
+) {
+    if !config.enabled() {
+        tracing::info!(target: "arklowdun", event = "db_upkeep_disabled");
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        loop {
+            sleep(config.interval).await;
+
+            let state = app.state::<crate::state::AppState>().inner().clone();
+            match crate::db::upkeep::run_upkeep_tick(&state).await {
+                Ok(crate::db::upkeep::UpkeepOutcome::Refreshed(report)) => {
+                    log_db_health(&report);
+                    if let Err(err) = app.emit(crate::db::upkeep::DB_HEALTH_UPDATED_EVENT, &report)
+                    {
+                        tracing::warn!(
+                            target: "arklowdun",
+                            event = "db_health_emit_failed",
+                            error = %err,
+                        );
+                    }
+                }
+                Ok(outcome) => {
+                    tracing::debug!(
+                        target: "arklowdun",
+                        event = "db_upkeep_skipped",
+                        outcome = ?outcome,
+                    );
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        target: "arklowdun",
+                        event = "db_upkeep_failed",
+                        error_code = %err.code(),
+                        error = %err,
+                    );
+                }
+            }
+        }
+    });
+}
+
 #[tauri::command]
 async fn files_index_cancel<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
@@ -3260,6 +4012,71 @@ async fn db_recheck(state: State<'_, AppState>) -> AppResult<DbHealthReport> {
     .await
 }
 
+/// Report the schema hash and the applied migration versions for support.
+#[tauri::command]
+async fn db_schema_info(state: State<'_, AppState>) -> AppResult<crate::db::manifest::SchemaInfo> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || async move {
+        crate::db::manifest::schema_info(&pool)
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "db_schema_info"))
+    })
+    .await
+}
+
+/// Return the schema DDL (tables, indexes, triggers) as a read-only SQL dump.
+#[tauri::command]
+async fn db_schema_dump(state: State<'_, AppState>) -> AppResult<String> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || async move {
+        crate::db::manifest::schema_dump(&pool)
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "db_schema_dump"))
+    })
+    .await
+}
+
+/// List indexes with their sizes and flag ones already covered by another.
+#[tauri::command]
+async fn db_index_stats(
+    state: State<'_, AppState>,
+) -> AppResult<Vec<crate::db::manifest::IndexStat>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || async move {
+        crate::db::manifest::index_stats(&pool)
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "db_index_stats"))
+    })
+    .await
+}
+
+/// Report where the database file lives and how large it and its sidecars are.
+#[tauri::command]
+async fn db_path_info(state: State<'_, AppState>) -> AppResult<crate::db::manifest::DbPathInfo> {
+    let db_path = (*state.db_path).clone();
+    dispatch_async_app_result(move || {
+        let db_path = db_path.clone();
+        async move {
+            let home = dirs::home_dir();
+            Ok(crate::db::manifest::path_info(&db_path, home.as_deref()))
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn db_migrations_pending(
+    state: State<'_, AppState>,
+) -> AppResult<Vec<migrate::PendingMigration>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || async move {
+        migrate::pending_migrations(&pool)
+            .await
+            .map_err(|err| AppError::from(err).with_context("operation", "db_migrations_pending"))
+    })
+    .await
+}
+
 fn log_db_health(report: &DbHealthReport) {
     if matches!(report.status, DbHealthStatus::Ok) {
         if storage_sanity_was_healed(report) {
@@ -3335,6 +4152,35 @@ fn like_escape(s: &str) -> String {
         .replace('_', "\\_")
 }
 
+/// Locate every occurrence of the raw query in `text`. The query is matched
+/// literally, mirroring the `like_escape`d LIKE pattern, after both sides are
+/// accent- and case-folded the way the search columns are.
+fn highlight_spans(field: &str, text: &str, q: &str) -> Vec<SearchHighlight> {
+    let needle = search_fold::fold_search_text(q);
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    // For each byte of the folded text, the UTF-16 range of the source char.
+    let mut folded = String::with_capacity(text.len());
+    let mut source: Vec<(u32, u32)> = Vec::with_capacity(text.len());
+    let mut pos = 0u32;
+    for ch in text.chars() {
+        let before = folded.len();
+        search_fold::fold_char_into(ch, &mut folded);
+        let next = pos + ch.len_utf16() as u32;
+        source.extend(std::iter::repeat_n((pos, next), folded.len() - before));
+        pos = next;
+    }
+    folded
+        .match_indices(&needle)
+        .map(|(byte_start, matched)| SearchHighlight {
+            field: field.to_string(),
+            start: source[byte_start].0,
+            end: source[byte_start + matched.len() - 1].1,
+        })
+        .collect()
+}
+
 struct SearchHit {
     score: i64,
     ts: i64,
@@ -3344,6 +4190,19 @@ struct SearchHit {
     result: SearchResult,
 }
 
+/// Queries shorter than this (in characters) are "short" unless the caller
+/// passes `min_len`.
+const SEARCH_MIN_LEN_DEFAULT: usize = 2;
+
+/// Event carrying one branch's ranked results for a streaming search.
+const SEARCH_RESULTS_EVENT: &str = "search_results";
+/// Event marking the end of a streaming search.
+const SEARCH_DONE_EVENT: &str = "search_done";
+
+/// Cancel flags for in-flight streaming searches, keyed by `request_id`.
+static SEARCH_STREAMS: Mutex<std::collections::BTreeMap<String, Arc<AtomicBool>>> =
+    Mutex::new(std::collections::BTreeMap::new());
+
 #[tauri::command]
 async fn search_entities(
     state: State<'_, AppState>,
@@ -3351,360 +4210,805 @@ async fn search_entities(
     query: String,
     limit: i64,
     offset: i64,
-) -> AppResult<Vec<SearchResult>> {
-    use sqlx::Row;
+    min_len: Option<usize>,
+    branch_timeout_ms: Option<u64>,
+) -> AppResult<SearchResponse> {
+    guard::allow_recovery_read(&state, "search_entities")?;
     let pool = state.pool_clone();
+    let branch_timeout = branch_timeout_ms.map(std::time::Duration::from_millis);
     dispatch_async_app_result(move || {
         let household_id = household_id;
         let query = query;
         let pool = pool.clone();
         async move {
-            let pool = &pool;
-
-            if household_id.trim().is_empty() {
-                return Err(AppError::new("BAD_REQUEST", "household_id is required"));
-            }
-            if !(1..=10_000).contains(&limit) || offset < 0 {
-                return Err(AppError::new("BAD_REQUEST", "invalid limit/offset")
-                    .with_context("limit", limit.to_string())
-                    .with_context("offset", offset.to_string()));
-            }
-
-            let q = query.trim().to_string();
-            tracing::debug!(target: "arklowdun", household_id = %household_id, q = %q, limit, offset, "search_invoke");
-            if q.is_empty() {
-                return Ok(vec![]);
-            }
-            let esc = like_escape(&q);
-            let prefix = format!("{esc}%");
-            let sub = format!("%{esc}%");
-            let branch_limit = limit.saturating_add(offset).min(10_000);
-
-            let index_ready = files_index_ready(pool, &household_id).await;
+            run_search(
+                &pool,
+                household_id,
+                query,
+                limit,
+                offset,
+                min_len,
+                branch_timeout,
+            )
+            .await
+        }
+    })
+    .await
+}
 
-            let has_events = table_exists(pool, "events").await;
-            if !has_events {
-                tracing::debug!(target: "arklowdun", name = "events", "missing_table");
-            }
-            let has_notes = table_exists(pool, "notes").await;
-            if !has_notes {
-                tracing::debug!(target: "arklowdun", name = "notes", "missing_table");
-            }
-            let has_vehicles = table_exists(pool, "vehicles").await;
-            if !has_vehicles {
-                tracing::debug!(target: "arklowdun", name = "vehicles", "missing_table");
-            }
-            let has_pets = table_exists(pool, "pets").await;
-            if !has_pets {
-                tracing::debug!(target: "arklowdun", name = "pets", "missing_table");
-            }
+#[derive(Serialize, Clone)]
+struct SearchResultsPayload {
+    request_id: String,
+    branch: &'static str,
+    results: Vec<SearchResult>,
+}
 
-            let short = q.len() < 2;
-            if short && !index_ready {
-                tracing::debug!(target: "arklowdun", q = %q, len = q.len(), "short_query_bypass");
-                return Ok(vec![]);
-            }
+#[derive(Serialize, Clone)]
+struct SearchDonePayload {
+    request_id: String,
+    total: usize,
+    partial: bool,
+    cancelled: bool,
+    timed_out_branches: Vec<String>,
+}
 
-            let mapq = |branch: &str, e: sqlx::Error| {
-                AppError::from(e)
-                    .with_context("operation", "search_query")
-                    .with_context("branch", branch.to_string())
+/// Streaming variant of [`search_entities`]. Each branch's results are
+/// emitted as a `search_results` event as soon as that branch finishes,
+/// followed by a single `search_done`; both carry the caller's `request_id`
+/// so the UI can drop events from a search it has abandoned, and
+/// [`search_entities_stream_cancel`] stops it before the next branch. At most
+/// `limit` results are emitted in total. Returns the number emitted.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+async fn search_entities_stream<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, AppState>,
+    request_id: String,
+    household_id: String,
+    query: String,
+    limit: i64,
+    min_len: Option<usize>,
+    branch_timeout_ms: Option<u64>,
+) -> AppResult<usize> {
+    guard::allow_recovery_read(&state, "search_entities_stream")?;
+    let pool = state.pool_clone();
+    let branch_timeout = branch_timeout_ms.map(std::time::Duration::from_millis);
+    let cancel = Arc::new(AtomicBool::new(false));
+    SEARCH_STREAMS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(request_id.clone(), cancel.clone());
+    let stream_id = request_id.clone();
+    let result = dispatch_async_app_result(move || {
+        let pool = pool.clone();
+        async move {
+            let outcome = run_search_streaming(
+                &pool,
+                household_id,
+                query,
+                limit,
+                min_len,
+                branch_timeout,
+                &cancel,
+                |branch, results| {
+                    let payload = SearchResultsPayload {
+                        request_id: request_id.clone(),
+                        branch,
+                        results,
+                    };
+                    if let Err(err) = app.emit(SEARCH_RESULTS_EVENT, payload) {
+                        tracing::warn!(
+                            target: "arklowdun",
+                            event = "search_stream_emit_failed",
+                            branch,
+                            error = %err
+                        );
+                    }
+                },
+            )
+            .await?;
+            let total = outcome.total;
+            let done = SearchDonePayload {
+                request_id: request_id.clone(),
+                total,
+                partial: !outcome.timed_out_branches.is_empty(),
+                cancelled: outcome.cancelled,
+                timed_out_branches: outcome.timed_out_branches,
             };
-
-            let mut hits: Vec<SearchHit> = Vec::new();
-            let mut ord: usize = 0;
-
-            if index_ready {
-                let sql = "SELECT file_id AS id, filename, strftime('%s', updated_at_utc) AS ts, ordinal AS ord, score_hint\n             FROM files_index\n             WHERE household_id=?1 AND filename LIKE ?2 ESCAPE '\\' COLLATE NOCASE\n             ORDER BY score_hint DESC, filename COLLATE NOCASE ASC, file_id ASC\n             LIMIT ?3 OFFSET ?4";
-                let start = std::time::Instant::now();
-                let rows = sqlx::query(sql)
-                    .bind(&household_id)
-                    .bind(&prefix)
-                    .bind(branch_limit)
-                    .bind(0)
-                    .fetch_all(pool)
-                    .await
-                    .map_err(|e| mapq("files_index", e))?;
-                let elapsed = start.elapsed().as_millis() as i64;
-                tracing::debug!(
-                    target: "arklowdun",
-                    name = "files_index",
-                    rows = rows.len(),
-                    elapsed_ms = elapsed,
-                    "branch"
-                );
-                for r in rows {
-                    let filename: String = r.try_get("filename").unwrap_or_default();
-                    let ts: i64 = r.try_get("ts").unwrap_or_default();
-                    let ord_val: i64 = r.try_get("ord").unwrap_or_default();
-                    let score_hint: i64 = r.try_get("score_hint").unwrap_or(0);
-                    let id: String = r.try_get("id").unwrap_or_default();
-                    hits.push(SearchHit {
-                        score: score_hint,
-                        ts,
-                        ordinal: ord_val.max(0) as usize,
-                        filename_key: Some(filename.to_ascii_lowercase()),
-                        id_key: Some(id.clone()),
-                        result: SearchResult::File {
-                            id,
-                            filename,
-                            updated_at: ts,
-                        },
-                    });
-                }
-            } else {
-                tracing::debug!(
+            if let Err(err) = app.emit(SEARCH_DONE_EVENT, done) {
+                tracing::warn!(
                     target: "arklowdun",
-                    name = "files_index",
-                    "index_not_ready"
+                    event = "search_stream_emit_failed",
+                    branch = "done",
+                    error = %err
                 );
             }
+            Ok(total)
+        }
+    })
+    .await;
+    SEARCH_STREAMS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(&stream_id);
+    result
+}
 
-            if !short {
-                if has_events {
-                    let start = std::time::Instant::now();
-                    let events = sqlx::query(
-                        "SELECT id, title, start_at_utc AS ts, COALESCE(tz,'Europe/London') AS tz\n         FROM events\n         WHERE household_id=?1 AND title LIKE ?2 ESCAPE '\\' COLLATE NOCASE\n         ORDER BY title ASC LIMIT ?3 OFFSET ?4",
-                    )
-                    .bind(&household_id)
-                    .bind(&sub)
-                    .bind(branch_limit)
-                    .bind(0)
-                    .fetch_all(pool)
-                    .await
-                    .map_err(|e| mapq("events", e))?;
-                    let elapsed = start.elapsed().as_millis() as i64;
-                    tracing::debug!(target: "arklowdun", name = "events", rows = events.len(), elapsed_ms = elapsed, "branch");
-                    for r in events {
-                        let title: String = r.try_get("title").unwrap_or_default();
-                        let ts: i64 = r.try_get("ts").unwrap_or_default();
-                        let tz: String = r.try_get("tz").unwrap_or_else(|_| "Europe/London".to_string());
-                        let score = if title.eq_ignore_ascii_case(&q) { 2 } else { 1 };
-                        let id: String = r.try_get("id").unwrap_or_default();
-                        hits.push(SearchHit {
-                            score: score as i64,
-                            ts,
-                            ordinal: ord,
-                            filename_key: None,
-                            id_key: None,
-                            result: SearchResult::Event {
-                                id,
-                                title,
-                                start_at_utc: ts,
-                                tz,
-                            },
-                        });
-                        ord += 1;
-                    }
-                }
-
-                if has_notes {
-                    let start = std::time::Instant::now();
-                    let notes = sqlx::query(
-                        "SELECT id, text, updated_at AS ts, COALESCE(color,'') AS color\n         FROM notes\n         WHERE household_id=?1 AND text LIKE ?2 ESCAPE '\\' COLLATE NOCASE\n         ORDER BY ts DESC LIMIT ?3 OFFSET ?4",
-                    )
-                    .bind(&household_id)
-                    .bind(&sub)
-                    .bind(branch_limit)
-                    .bind(0)
-                    .fetch_all(pool)
-                    .await
-                    .map_err(|e| mapq("notes", e))?;
-                    let elapsed = start.elapsed().as_millis() as i64;
-                    tracing::debug!(target: "arklowdun", name = "notes", rows = notes.len(), elapsed_ms = elapsed, "branch");
-                    for r in notes {
-                        let text: String = r.try_get("text").unwrap_or_default();
-                        let ts: i64 = r.try_get("ts").unwrap_or_default();
-                        let color: String = r.try_get("color").unwrap_or_default();
-                        let score = if text.eq_ignore_ascii_case(&q) { 2 } else { 1 };
-                        let snippet: String = text.chars().take(80).collect();
-                        let id: String = r.try_get("id").unwrap_or_default();
-                        hits.push(SearchHit {
-                            score: score as i64,
-                            ts,
-                            ordinal: ord,
-                            filename_key: None,
-                            id_key: None,
-                            result: SearchResult::Note {
-                                id,
-                                snippet,
-                                updated_at: ts,
-                                color,
-                            },
-                        });
-                        ord += 1;
-                    }
-                }
+/// Stop the streaming search started with `request_id` before its next
+/// branch; its `search_done` is flagged `cancelled`. Returns `false` when no
+/// such search is running.
+#[tauri::command]
+fn search_entities_stream_cancel(request_id: String) -> bool {
+    let streams = SEARCH_STREAMS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match streams.get(&request_id) {
+        Some(cancel) => {
+            cancel.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
 
-                if has_vehicles {
-                    let start = std::time::Instant::now();
-                    let vcols = table_columns(pool, "vehicles").await;
-                    let reg_expr = coalesce_expr(&vcols, &["reg", "registration", "plate"], "''");
-                    let nick_expr = coalesce_expr(&vcols, &["nickname", "name"], "''");
-                    let ts_expr = coalesce_expr(&vcols, &["updated_at", "created_at"], "0");
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchBranch {
+    FilesIndex,
+    Events,
+    Notes,
+    Vehicles,
+    Pets,
+}
 
-                    let make_expr = if vcols.contains("make") {
-                        "COALESCE(make,'')"
-                    } else {
-                        "''"
-                    };
-                    let model_expr = if vcols.contains("model") {
-                        "COALESCE(model,'')"
-                    } else {
-                        "''"
-                    };
+impl SearchBranch {
+    fn name(self) -> &'static str {
+        match self {
+            SearchBranch::FilesIndex => "files_index",
+            SearchBranch::Events => "events",
+            SearchBranch::Notes => "notes",
+            SearchBranch::Vehicles => "vehicles",
+            SearchBranch::Pets => "pets",
+        }
+    }
+}
 
-                    let sql = format!(
-                        "SELECT id, {make_expr} AS make, {model_expr} AS model, {reg_expr} AS reg, {nick_expr} AS nickname, {ts_expr} AS ts \
-                 FROM vehicles \
-                 WHERE household_id=?1 AND ( \
-                     {make_expr} LIKE ?2 ESCAPE '\\' COLLATE NOCASE OR \
-                     {model_expr} LIKE ?2 ESCAPE '\\' COLLATE NOCASE OR \
-                     {reg_expr}   LIKE ?2 ESCAPE '\\' COLLATE NOCASE OR \
-                     {nick_expr}  LIKE ?2 ESCAPE '\\' COLLATE NOCASE \
-                 ) \
-                 ORDER BY ts DESC LIMIT ?3 OFFSET ?4",
-                        make_expr = make_expr,
-                        model_expr = model_expr,
-                        reg_expr = reg_expr,
-                        nick_expr = nick_expr,
-                        ts_expr = ts_expr,
-                    );
+/// Patterns and limits shared by every branch of one search.
+struct SearchTerms {
+    household_id: String,
+    q: String,
+    folded_q: String,
+    prefix: String,
+    sub: String,
+    sub_norm: String,
+    /// Pattern for the fields short queries still search (prefix when short).
+    field_pattern: String,
+    norm_pattern: String,
+    /// UTC window named by a date phrase in the query; events starting in it
+    /// match in addition to title matches.
+    date_range: Option<calendar_range::UtcRange>,
+    branch_limit: i64,
+    /// Test hook standing in for a slow scan.
+    #[cfg(test)]
+    slow_branch: Option<(SearchBranch, std::time::Duration)>,
+}
+
+/// Validate the request and work out which branches apply. Returns `None`
+/// for an empty query.
+///
+/// Short queries (below `min_len`, default [`SEARCH_MIN_LEN_DEFAULT`]) only
+/// hit the files index and prefix matches on event titles and pet names or
+/// species: substring scans over note bodies and vehicle fields cannot use an
+/// index, and a one-character `%x%` pattern matches nearly every row.
+/// Lowering `min_len` opts into those full scans.
+async fn plan_search(
+    pool: &SqlitePool,
+    household_id: String,
+    query: String,
+    limit: i64,
+    offset: i64,
+    min_len: Option<usize>,
+) -> AppResult<Option<(SearchTerms, Vec<SearchBranch>)>> {
+    if household_id.trim().is_empty() {
+        return Err(AppError::new("BAD_REQUEST", "household_id is required"));
+    }
+    if !(1..=10_000).contains(&limit) || offset < 0 {
+        return Err(AppError::new("BAD_REQUEST", "invalid limit/offset")
+            .with_context("limit", limit.to_string())
+            .with_context("offset", offset.to_string()));
+    }
 
-                    let rows = sqlx::query(&sql)
-                        .bind(&household_id)
-                        .bind(&sub)
-                        .bind(branch_limit)
-                        .bind(0)
-                        .fetch_all(pool)
-                        .await
-                        .map_err(|e| mapq("vehicles", e))?;
-                    let elapsed = start.elapsed().as_millis() as i64;
-                    tracing::debug!(target: "arklowdun", name = "vehicles", rows = rows.len(), elapsed_ms = elapsed, "branch");
-                    for r in rows {
-                        let make: String = r.try_get("make").unwrap_or_default();
-                        let model: String = r.try_get("model").unwrap_or_default();
-                        let reg: String = r.try_get("reg").unwrap_or_default();
-                        let nickname: String = r.try_get("nickname").unwrap_or_default();
-                        let ts: i64 = r.try_get("ts").unwrap_or_default();
-                        let exact = |s: &str| !s.is_empty() && s.eq_ignore_ascii_case(&q);
-                        let score = if exact(&make) || exact(&model) || exact(&reg) || exact(&nickname) {
-                            2
-                        } else {
-                            1
-                        };
-                        let id: String = r.try_get("id").unwrap_or_default();
-                        hits.push(SearchHit {
-                            score: score as i64,
-                            ts,
-                            ordinal: ord,
-                            filename_key: None,
-                            id_key: None,
-                            result: SearchResult::Vehicle {
-                                id,
-                                make,
-                                model,
-                                reg,
-                                updated_at: ts,
-                                nickname,
-                            },
-                        });
-                        ord += 1;
-                    }
-                }
+    let q = query.trim().to_string();
+    tracing::debug!(target: "arklowdun", household_id = %household_id, q = %q, limit, offset, "search_invoke");
+    if q.is_empty() {
+        return Ok(None);
+    }
+    let esc = like_escape(&q);
+    let prefix = format!("{esc}%");
+    let sub = format!("%{esc}%");
+    let folded_q = search_fold::fold_search_text(&q);
+    let sub_norm = format!("%{}%", like_escape(&folded_q));
+    let prefix_norm = format!("{}%", like_escape(&folded_q));
+    let branch_limit = limit.saturating_add(offset).min(10_000);
+
+    let min_len = min_len.unwrap_or(SEARCH_MIN_LEN_DEFAULT).max(1);
+    let short = q.chars().count() < min_len;
+    if short {
+        tracing::debug!(target: "arklowdun", q = %q, min_len, "short_query_prefix_only");
+    }
 
-                if has_pets {
-                    let start = std::time::Instant::now();
-                    let pcols = table_columns(pool, "pets").await;
-                    let name_expr = if pcols.contains("name") {
-                        "COALESCE(name,'')"
-                    } else {
-                        "''"
-                    };
-                    let species_expr = coalesce_expr(&pcols, &["species", "type"], "''");
-                    let ts_expr = coalesce_expr(&pcols, &["updated_at", "created_at"], "0");
-
-                    let sql = format!(
-                        "SELECT id, {name_expr} AS name, {species_expr} AS species, {ts_expr} AS ts \
-                 FROM pets \
-                 WHERE household_id=?1 AND ( \
-                     {name_expr}   LIKE ?2 ESCAPE '\\' COLLATE NOCASE OR \
-                     {species_expr} LIKE ?2 ESCAPE '\\' COLLATE NOCASE \
-                 ) \
-                 ORDER BY ts DESC LIMIT ?3 OFFSET ?4",
-                        name_expr = name_expr,
-                        species_expr = species_expr,
-                        ts_expr = ts_expr,
-                    );
+    let mut branches = Vec::new();
+    if files_index_ready(pool, &household_id).await {
+        branches.push(SearchBranch::FilesIndex);
+    } else {
+        tracing::debug!(
+            target: "arklowdun",
+            name = "files_index",
+            "index_not_ready"
+        );
+    }
+    for (branch, table, scans) in [
+        (SearchBranch::Events, "events", true),
+        (SearchBranch::Notes, "notes", !short),
+        (SearchBranch::Vehicles, "vehicles", !short),
+        (SearchBranch::Pets, "pets", true),
+    ] {
+        if !table_exists(pool, table).await {
+            tracing::debug!(target: "arklowdun", name = table, "missing_table");
+        } else if scans {
+            branches.push(branch);
+        }
+    }
 
-                    let rows = sqlx::query(&sql)
-                        .bind(&household_id)
-                        .bind(&sub)
-                        .bind(branch_limit)
-                        .bind(0)
-                        .fetch_all(pool)
-                        .await
-                        .map_err(|e| mapq("pets", e))?;
-                    let elapsed = start.elapsed().as_millis() as i64;
-                    tracing::debug!(target: "arklowdun", name = "pets", rows = rows.len(), elapsed_ms = elapsed, "branch");
-                    for r in rows {
-                        let name: String = r.try_get("name").unwrap_or_default();
-                        let species: String = r.try_get("species").unwrap_or_default();
-                        let ts: i64 = r.try_get("ts").unwrap_or_default();
-                        let score = if name.eq_ignore_ascii_case(&q) || species.eq_ignore_ascii_case(&q) {
-                            2
-                        } else {
-                            1
-                        };
-                        let id: String = r.try_get("id").unwrap_or_default();
-                        hits.push(SearchHit {
-                            score: score as i64,
-                            ts,
-                            ordinal: ord,
-                            filename_key: None,
-                            id_key: None,
-                            result: SearchResult::Pet {
-                                id,
-                                name,
-                                species,
-                                updated_at: ts,
-                            },
-                        });
-                        ord += 1;
-                    }
-                }
-            }
+    let date_range = if branches.contains(&SearchBranch::Events) {
+        search_date_range(pool, &household_id, &q).await
+    } else {
+        None
+    };
 
-            hits.sort_by(|a, b| match (a.filename_key.as_ref(), b.filename_key.as_ref()) {
-                (Some(a_name), Some(b_name)) => {
-                    let aid = a.id_key.as_deref().unwrap_or("");
-                    let bid = b.id_key.as_deref().unwrap_or("");
-                    b.score
-                        .cmp(&a.score)
-                        .then_with(|| a_name.cmp(b_name))
-                        .then_with(|| aid.cmp(bid))
-                }
-                _ => b
-                    .score
-                    .cmp(&a.score)
-                    .then(b.ts.cmp(&a.ts))
-                    .then(a.ordinal.cmp(&b.ordinal)),
-            });
-            let total_before = hits.len();
-            let hits = hits
-                .into_iter()
-                .skip(offset as usize)
-                .take(limit as usize)
-                .collect::<Vec<_>>();
-            tracing::debug!(target: "arklowdun", total_before, returned = hits.len(), "result_summary");
+    // Short queries fall back to prefix matching on the restricted fields.
+    let (field_pattern, norm_pattern) = if short {
+        (prefix.clone(), prefix_norm)
+    } else {
+        (sub.clone(), sub_norm.clone())
+    };
 
-            Ok(hits.into_iter().map(|hit| hit.result).collect())
-        }
-    })
-    .await
+    Ok(Some((
+        SearchTerms {
+            household_id,
+            q,
+            folded_q,
+            prefix,
+            sub,
+            sub_norm,
+            field_pattern,
+            norm_pattern,
+            date_range,
+            branch_limit,
+            #[cfg(test)]
+            slow_branch: None,
+        },
+        branches,
+    )))
 }
 
-#[derive(Debug, Clone)]
+/// Resolve a date phrase in `q` to a UTC window in the household's timezone.
+/// Queries that are not date phrases return `None` and search titles only.
+async fn search_date_range(
+    pool: &SqlitePool,
+    household_id: &str,
+    q: &str,
+) -> Option<calendar_range::UtcRange> {
+    let tz = calendar_range::household_timezone(pool, household_id)
+        .await
+        .unwrap_or(chrono_tz::UTC);
+    let today = chrono::Utc::now().with_timezone(&tz).date_naive();
+    let (from, until) = search_dates::parse_date_query(q, today)?;
+    tracing::debug!(target: "arklowdun", event = "search_date_range", %from, %until);
+    Some(calendar_range::local_dates_bounds(&tz, from, until))
+}
+
+/// Ranking shared by the batch and streaming searches.
+fn compare_search_hits(a: &SearchHit, b: &SearchHit) -> std::cmp::Ordering {
+    match (a.filename_key.as_ref(), b.filename_key.as_ref()) {
+        (Some(a_name), Some(b_name)) => {
+            let aid = a.id_key.as_deref().unwrap_or("");
+            let bid = b.id_key.as_deref().unwrap_or("");
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a_name.cmp(b_name))
+                .then_with(|| aid.cmp(bid))
+        }
+        _ => b
+            .score
+            .cmp(&a.score)
+            .then(b.ts.cmp(&a.ts))
+            .then(a.ordinal.cmp(&b.ordinal)),
+    }
+}
+
+/// Run a search across the files index and the domain tables.
+///
+/// With a `branch_timeout`, a branch that has not finished in time is
+/// abandoned and the response is flagged `partial`; the other branches still
+/// contribute their results.
+async fn run_search(
+    pool: &SqlitePool,
+    household_id: String,
+    query: String,
+    limit: i64,
+    offset: i64,
+    min_len: Option<usize>,
+    branch_timeout: Option<std::time::Duration>,
+) -> AppResult<SearchResponse> {
+    let Some((terms, branches)) =
+        plan_search(pool, household_id, query, limit, offset, min_len).await?
+    else {
+        return Ok(SearchResponse {
+            results: vec![],
+            partial: false,
+            timed_out_branches: vec![],
+        });
+    };
+    collect_search(pool, &terms, branches, limit, offset, branch_timeout).await
+}
+
+async fn collect_search(
+    pool: &SqlitePool,
+    terms: &SearchTerms,
+    branches: Vec<SearchBranch>,
+    limit: i64,
+    offset: i64,
+    branch_timeout: Option<std::time::Duration>,
+) -> AppResult<SearchResponse> {
+    let mut hits: Vec<SearchHit> = Vec::new();
+    let mut timed_out_branches = Vec::new();
+    let mut ord: usize = 0;
+    for branch in branches {
+        let Some(mut branch_hits) =
+            search_branch_within(pool, terms, branch, branch_timeout).await?
+        else {
+            timed_out_branches.push(branch.name().to_string());
+            continue;
+        };
+        if branch != SearchBranch::FilesIndex {
+            for hit in &mut branch_hits {
+                hit.ordinal += ord;
+            }
+            ord += branch_hits.len();
+        }
+        hits.extend(branch_hits);
+    }
+
+    hits.sort_by(compare_search_hits);
+    let total_before = hits.len();
+    let hits = hits
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .collect::<Vec<_>>();
+    tracing::debug!(target: "arklowdun", total_before, returned = hits.len(), "result_summary");
+
+    Ok(SearchResponse {
+        results: hits.into_iter().map(|hit| hit.result).collect(),
+        partial: !timed_out_branches.is_empty(),
+        timed_out_branches,
+    })
+}
+
+struct SearchStreamOutcome {
+    total: usize,
+    cancelled: bool,
+    timed_out_branches: Vec<String>,
+}
+
+/// Run the same branches as [`run_search`] but hand each branch's ranked
+/// results to `emit` as soon as the branch completes, instead of holding
+/// every match for a global sort. Branches share one budget of `limit`
+/// results, so later branches are skipped once it is spent; setting `cancel`
+/// stops the search before the next branch.
+#[allow(clippy::too_many_arguments)]
+async fn run_search_streaming<F>(
+    pool: &SqlitePool,
+    household_id: String,
+    query: String,
+    limit: i64,
+    min_len: Option<usize>,
+    branch_timeout: Option<std::time::Duration>,
+    cancel: &AtomicBool,
+    mut emit: F,
+) -> AppResult<SearchStreamOutcome>
+where
+    F: FnMut(&'static str, Vec<SearchResult>),
+{
+    let mut outcome = SearchStreamOutcome {
+        total: 0,
+        cancelled: false,
+        timed_out_branches: Vec::new(),
+    };
+    let Some((terms, branches)) = plan_search(pool, household_id, query, limit, 0, min_len).await?
+    else {
+        return Ok(outcome);
+    };
+
+    let mut remaining = limit.max(0) as usize;
+    for branch in branches {
+        if remaining == 0 {
+            break;
+        }
+        if cancel.load(Ordering::SeqCst) {
+            outcome.cancelled = true;
+            break;
+        }
+        let Some(mut hits) = search_branch_within(pool, &terms, branch, branch_timeout).await?
+        else {
+            outcome.timed_out_branches.push(branch.name().to_string());
+            continue;
+        };
+        hits.sort_by(compare_search_hits);
+        hits.truncate(remaining);
+        remaining -= hits.len();
+        outcome.total += hits.len();
+        emit(
+            branch.name(),
+            hits.into_iter().map(|hit| hit.result).collect(),
+        );
+    }
+    tracing::debug!(target: "arklowdun", total = outcome.total, "stream_summary");
+    Ok(outcome)
+}
+
+/// [`search_branch`] bounded by an optional timeout. `None` means the branch
+/// was abandoned.
+async fn search_branch_within(
+    pool: &SqlitePool,
+    terms: &SearchTerms,
+    branch: SearchBranch,
+    branch_timeout: Option<std::time::Duration>,
+) -> AppResult<Option<Vec<SearchHit>>> {
+    let Some(limit) = branch_timeout else {
+        return search_branch(pool, terms, branch).await.map(Some);
+    };
+    match tokio::time::timeout(limit, search_branch(pool, terms, branch)).await {
+        Ok(hits) => hits.map(Some),
+        Err(_) => {
+            tracing::warn!(
+                target: "arklowdun",
+                event = "search_branch_timeout",
+                name = branch.name(),
+                timeout_ms = limit.as_millis() as u64,
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Query a single branch. Hits carry branch-local ordinals; callers merging
+/// several branches offset them to keep the original tie-break order.
+async fn search_branch(
+    pool: &SqlitePool,
+    terms: &SearchTerms,
+    branch: SearchBranch,
+) -> AppResult<Vec<SearchHit>> {
+    use sqlx::Row;
+
+    #[cfg(test)]
+    if let Some((slow, delay)) = terms.slow_branch {
+        if slow == branch {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    let SearchTerms {
+        household_id,
+        q,
+        folded_q,
+        prefix,
+        sub,
+        sub_norm,
+        field_pattern,
+        norm_pattern,
+        date_range,
+        branch_limit,
+        ..
+    } = terms;
+    let q = q.as_str();
+    let mapq = |e: sqlx::Error| {
+        AppError::from(e)
+            .with_context("operation", "search_query")
+            .with_context("branch", branch.name().to_string())
+    };
+
+    let start = std::time::Instant::now();
+    let mut hits: Vec<SearchHit> = Vec::new();
+    match branch {
+        SearchBranch::FilesIndex => {
+            let sql = "SELECT file_id AS id, filename, strftime('%s', updated_at_utc) AS ts, ordinal AS ord, score_hint\n             FROM files_index\n             WHERE household_id=?1 AND filename LIKE ?2 ESCAPE '\\' COLLATE NOCASE\n             ORDER BY score_hint DESC, filename COLLATE NOCASE ASC, file_id ASC\n             LIMIT ?3 OFFSET ?4";
+            let rows = sqlx::query(sql)
+                .bind(household_id)
+                .bind(prefix)
+                .bind(branch_limit)
+                .bind(0)
+                .fetch_all(pool)
+                .await
+                .map_err(mapq)?;
+            for r in rows {
+                let filename: String = r.try_get("filename").unwrap_or_default();
+                let ts: i64 = r.try_get("ts").unwrap_or_default();
+                let ord_val: i64 = r.try_get("ord").unwrap_or_default();
+                let score_hint: i64 = r.try_get("score_hint").unwrap_or(0);
+                let id: String = r.try_get("id").unwrap_or_default();
+                hits.push(SearchHit {
+                    score: score_hint,
+                    ts,
+                    ordinal: ord_val.max(0) as usize,
+                    filename_key: Some(filename.to_ascii_lowercase()),
+                    id_key: Some(id.clone()),
+                    result: SearchResult::File {
+                        highlights: highlight_spans("filename", &filename, q),
+                        id,
+                        filename,
+                        updated_at: ts,
+                    },
+                });
+            }
+        }
+        SearchBranch::Events => {
+            let has_norm = table_columns(pool, "events").await.contains("title_norm");
+            let norm_match = if has_norm {
+                " OR title_norm LIKE ?5 ESCAPE '\\'"
+            } else {
+                ""
+            };
+            let date_match = match date_range {
+                Some(_) => {
+                    let first = if has_norm { 6 } else { 5 };
+                    format!(
+                        " OR (start_at_utc >= ?{first} AND start_at_utc < ?{})",
+                        first + 1
+                    )
+                }
+                None => String::new(),
+            };
+            let sql = format!(
+                "SELECT id, title, start_at_utc AS ts, tz\n         FROM events\n         WHERE household_id=?1 AND (title LIKE ?2 ESCAPE '\\' COLLATE NOCASE{norm_match}{date_match})\n         ORDER BY title ASC LIMIT ?3 OFFSET ?4",
+            );
+            let mut events_query = sqlx::query(&sql)
+                .bind(household_id)
+                .bind(field_pattern)
+                .bind(branch_limit)
+                .bind(0);
+            if has_norm {
+                events_query = events_query.bind(norm_pattern);
+            }
+            if let Some(range) = date_range {
+                events_query = events_query.bind(range.start_at_utc).bind(range.end_at_utc);
+            }
+            let events = events_query.fetch_all(pool).await.map_err(mapq)?;
+            for r in events {
+                let title: String = r.try_get("title").unwrap_or_default();
+                let ts: i64 = r.try_get("ts").unwrap_or_default();
+                let tz = r
+                    .try_get::<Option<String>, _>("tz")
+                    .ok()
+                    .flatten()
+                    .filter(|tz| !tz.trim().is_empty())
+                    .unwrap_or_else(settings::fallback_timezone);
+                let score = if search_fold::fold_search_text(&title) == *folded_q {
+                    2
+                } else {
+                    1
+                };
+                let id: String = r.try_get("id").unwrap_or_default();
+                hits.push(SearchHit {
+                    score: score as i64,
+                    ts,
+                    ordinal: hits.len(),
+                    filename_key: None,
+                    id_key: None,
+                    result: SearchResult::Event {
+                        highlights: highlight_spans("title", &title, q),
+                        id,
+                        title,
+                        start_at_utc: ts,
+                        tz,
+                    },
+                });
+            }
+        }
+        SearchBranch::Notes => {
+            let has_norm = table_columns(pool, "notes").await.contains("text_norm");
+            let norm_match = if has_norm {
+                " OR text_norm LIKE ?5 ESCAPE '\\'"
+            } else {
+                ""
+            };
+            let sql = format!(
+                "SELECT id, text, updated_at AS ts, COALESCE(color,'') AS color\n         FROM notes\n         WHERE household_id=?1 AND (text LIKE ?2 ESCAPE '\\' COLLATE NOCASE{norm_match})\n         ORDER BY ts DESC LIMIT ?3 OFFSET ?4",
+            );
+            let mut notes_query = sqlx::query(&sql)
+                .bind(household_id)
+                .bind(sub)
+                .bind(branch_limit)
+                .bind(0);
+            if has_norm {
+                notes_query = notes_query.bind(sub_norm);
+            }
+            let notes = notes_query.fetch_all(pool).await.map_err(mapq)?;
+            for r in notes {
+                let text: String = r.try_get("text").unwrap_or_default();
+                let ts: i64 = r.try_get("ts").unwrap_or_default();
+                let color: String = r.try_get("color").unwrap_or_default();
+                let score = if search_fold::fold_search_text(&text) == *folded_q {
+                    2
+                } else {
+                    1
+                };
+                let snippet: String = text.chars().take(80).collect();
+                let id: String = r.try_get("id").unwrap_or_default();
+                hits.push(SearchHit {
+                    score: score as i64,
+                    ts,
+                    ordinal: hits.len(),
+                    filename_key: None,
+                    id_key: None,
+                    result: SearchResult::Note {
+                        highlights: highlight_spans("snippet", &snippet, q),
+                        id,
+                        snippet,
+                        updated_at: ts,
+                        color,
+                    },
+                });
+            }
+        }
+        SearchBranch::Vehicles => {
+            let vcols = table_columns(pool, "vehicles").await;
+            let reg_expr = coalesce_expr(&vcols, &["reg", "registration", "plate"], "''");
+            let nick_expr = coalesce_expr(&vcols, &["nickname", "name"], "''");
+            let ts_expr = coalesce_expr(&vcols, &["updated_at", "created_at"], "0");
+
+            let make_expr = if vcols.contains("make") {
+                "COALESCE(make,'')"
+            } else {
+                "''"
+            };
+            let model_expr = if vcols.contains("model") {
+                "COALESCE(model,'')"
+            } else {
+                "''"
+            };
+
+            let sql = format!(
+                "SELECT id, {make_expr} AS make, {model_expr} AS model, {reg_expr} AS reg, {nick_expr} AS nickname, {ts_expr} AS ts \
+         FROM vehicles \
+         WHERE household_id=?1 AND ( \
+             {make_expr} LIKE ?2 ESCAPE '\\' COLLATE NOCASE OR \
+             {model_expr} LIKE ?2 ESCAPE '\\' COLLATE NOCASE OR \
+             {reg_expr}   LIKE ?2 ESCAPE '\\' COLLATE NOCASE OR \
+             {nick_expr}  LIKE ?2 ESCAPE '\\' COLLATE NOCASE \
+         ) \
+         ORDER BY ts DESC LIMIT ?3 OFFSET ?4",
+                make_expr = make_expr,
+                model_expr = model_expr,
+                reg_expr = reg_expr,
+                nick_expr = nick_expr,
+                ts_expr = ts_expr,
+            );
+
+            let rows = sqlx::query(&sql)
+                .bind(household_id)
+                .bind(sub)
+                .bind(branch_limit)
+                .bind(0)
+                .fetch_all(pool)
+                .await
+                .map_err(mapq)?;
+            for r in rows {
+                let make: String = r.try_get("make").unwrap_or_default();
+                let model: String = r.try_get("model").unwrap_or_default();
+                let reg: String = r.try_get("reg").unwrap_or_default();
+                let nickname: String = r.try_get("nickname").unwrap_or_default();
+                let ts: i64 = r.try_get("ts").unwrap_or_default();
+                let exact = |s: &str| !s.is_empty() && s.eq_ignore_ascii_case(q);
+                let score = if exact(&make) || exact(&model) || exact(&reg) || exact(&nickname) {
+                    2
+                } else {
+                    1
+                };
+                let id: String = r.try_get("id").unwrap_or_default();
+                hits.push(SearchHit {
+                    score: score as i64,
+                    ts,
+                    ordinal: hits.len(),
+                    filename_key: None,
+                    id_key: None,
+                    result: SearchResult::Vehicle {
+                        highlights: [
+                            ("make", &make),
+                            ("model", &model),
+                            ("reg", &reg),
+                            ("nickname", &nickname),
+                        ]
+                        .into_iter()
+                        .flat_map(|(field, text)| highlight_spans(field, text, q))
+                        .collect(),
+                        id,
+                        make,
+                        model,
+                        reg,
+                        updated_at: ts,
+                        nickname,
+                    },
+                });
+            }
+        }
+        SearchBranch::Pets => {
+            let pcols = table_columns(pool, "pets").await;
+            let name_expr = if pcols.contains("name") {
+                "COALESCE(name,'')"
+            } else {
+                "''"
+            };
+            let species_expr = coalesce_expr(&pcols, &["species", "type"], "''");
+            let ts_expr = coalesce_expr(&pcols, &["updated_at", "created_at"], "0");
+
+            let sql = format!(
+                "SELECT id, {name_expr} AS name, {species_expr} AS species, {ts_expr} AS ts \
+     FROM pets \
+     WHERE household_id=?1 AND ( \
+         {name_expr}   LIKE ?2 ESCAPE '\\' COLLATE NOCASE OR \
+         {species_expr} LIKE ?2 ESCAPE '\\' COLLATE NOCASE \
+     ) \
+     ORDER BY ts DESC LIMIT ?3 OFFSET ?4",
+                name_expr = name_expr,
+                species_expr = species_expr,
+                ts_expr = ts_expr,
+            );
+
+            let rows = sqlx::query(&sql)
+                .bind(household_id)
+                .bind(field_pattern)
+                .bind(branch_limit)
+                .bind(0)
+                .fetch_all(pool)
+                .await
+                .map_err(mapq)?;
+            for r in rows {
+                let name: String = r.try_get("name").unwrap_or_default();
+                let species: String = r.try_get("species").unwrap_or_default();
+                let ts: i64 = r.try_get("ts").unwrap_or_default();
+                let score = if name.eq_ignore_ascii_case(q) || species.eq_ignore_ascii_case(q) {
+                    2
+                } else {
+                    1
+                };
+                let id: String = r.try_get("id").unwrap_or_default();
+                hits.push(SearchHit {
+                    score: score as i64,
+                    ts,
+                    ordinal: hits.len(),
+                    filename_key: None,
+                    id_key: None,
+                    result: SearchResult::Pet {
+                        highlights: [("name", &name), ("species", &species)]
+                            .into_iter()
+                            .flat_map(|(field, text)| highlight_spans(field, text, q))
+                            .collect(),
+                        id,
+                        name,
+                        species,
+                        updated_at: ts,
+                    },
+                });
+            }
+        }
+    }
+    let elapsed = start.elapsed().as_millis() as i64;
+    tracing::debug!(target: "arklowdun", name = branch.name(), rows = hits.len(), elapsed_ms = elapsed, "branch");
+    Ok(hits)
+}
+
+#[derive(Debug, Clone)]
 struct PetImageDescriptor {
     household_id: String,
     image_path: Option<String>,
@@ -4108,7 +5412,22 @@ async fn resolve_attachment_for_ipc_read(
     id: &str,
     operation: &'static str,
 ) -> AppResult<PathBuf> {
-    let descriptor = crate::attachments::load_attachment_descriptor(pool, table, id).await?;
+    let descriptor = match crate::attachments::load_attachment_descriptor(pool, table, id).await {
+        Ok(descriptor) => descriptor,
+        Err(err)
+            if err.code() == "DB/NOT_FOUND"
+                && crate::attachments::is_soft_deleted(pool, table, id).await? =>
+        {
+            return Err(AppError::new(
+                crate::attachments::ERR_ATTACHMENT_DELETED,
+                "This attachment was deleted. Restore it to open the file.",
+            )
+            .with_context("operation", operation)
+            .with_context("table", table.to_string())
+            .with_context("id", id.to_string()));
+        }
+        Err(err) => return Err(err),
+    };
     let table_value = table.to_string();
     let id_value = id.to_string();
 
@@ -4608,6 +5927,52 @@ async fn resolve_attachment_for_ipc_delete(
     )))
 }
 
+#[tauri::command]
+async fn attachments_list_for_entity(
+    state: State<'_, AppState>,
+    table: String,
+    id: String,
+) -> AppResult<Vec<crate::file_ops::EntityAttachment>> {
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    let active_household = state.active_household_id.clone();
+    dispatch_async_app_result(move || {
+        let table = table.clone();
+        let id = id.clone();
+        let vault = vault.clone();
+        let active_household = active_household.clone();
+        async move {
+            // Checked up front so an entity without attachments in another
+            // household is refused rather than listed as empty.
+            let household = crate::file_ops::entity_household(&pool, &table, &id).await?;
+            ensure_active_household_for_ipc(
+                &active_household,
+                &household,
+                AttachmentCategory::for_table(&table).unwrap_or(AttachmentCategory::Misc),
+                "",
+                "attachments_list_for_entity",
+                &table,
+                Some(&id),
+            )?;
+            let attachments =
+                crate::file_ops::list_entity_attachments(&pool, &vault, &table, &id).await?;
+            for attachment in &attachments {
+                ensure_active_household_for_ipc(
+                    &active_household,
+                    &attachment.household_id,
+                    attachment.category,
+                    &attachment.relative_path,
+                    "attachments_list_for_entity",
+                    &attachment.table,
+                    Some(&attachment.id),
+                )?;
+            }
+            Ok(attachments)
+        }
+    })
+    .await
+}
+
 #[tauri::command]
 async fn attachment_open<R: tauri::Runtime>(
     _app: tauri::AppHandle<R>,
@@ -5181,6 +6546,15 @@ async fn attachments_resume_migration<R: tauri::Runtime>(
     result
 }
 
+/// Stop the running vault migration before its next file. The run returns a
+/// partial, `cancelled` progress and keeps its checkpoint for resuming.
+#[tauri::command]
+async fn attachments_migrate_cancel(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> AppResult<()> {
+    state.vault_migration().request_cancel()
+}
+
 #[tauri::command]
 async fn open_path<R: tauri::Runtime>(app: tauri::AppHandle<R>, path: String) -> AppResult<()> {
     let app = app.clone();
@@ -5214,6 +6588,57 @@ async fn open_path<R: tauri::Runtime>(app: tauri::AppHandle<R>, path: String) ->
     .await
 }
 
+#[tauri::command]
+async fn logs_status<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> AppResult<LogsStatus> {
+    let dir = resolve_logs_dir(&app).map_err(|err| {
+        AppError::new("LOGGING/DIR_UNAVAILABLE", "Log directory is unavailable.")
+            .with_context("error", err.to_string())
+    })?;
+    flush_file_logs();
+    Ok(logs_status_for_dir(&dir))
+}
+
+#[tauri::command]
+async fn logging_set_filter(directive: String) -> AppResult<String> {
+    set_log_filter(&directive)
+}
+
+#[tauri::command]
+async fn settings_get(state: State<'_, AppState>) -> AppResult<settings::Settings> {
+    Ok(settings::load_settings(&state.store))
+}
+
+#[tauri::command]
+async fn settings_update<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, AppState>,
+    patch: settings::SettingsPatch,
+) -> AppResult<settings::Settings> {
+    let previous = settings::load_settings(&state.store);
+    let previous_level = previous.log_level;
+    let updated = settings::update_settings(&state.store, &patch)?;
+    if updated.fallback_timezone != previous.fallback_timezone {
+        settings::set_fallback_timezone(&updated.fallback_timezone)?;
+    }
+    if updated.log_level != previous_level {
+        if let Err(err) = set_log_level(&updated.log_level) {
+            tracing::warn!(
+                target: "arklowdun",
+                event = "settings_log_level_apply_failed",
+                error = %err
+            );
+        }
+    }
+    if let Err(err) = app.emit(settings::EVENT_SETTINGS_CHANGED, &updated) {
+        tracing::warn!(
+            target: "arklowdun",
+            event = "settings_changed_emit_failed",
+            error = %err
+        );
+    }
+    Ok(updated)
+}
+
 #[tauri::command]
 async fn diagnostics_summary<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
@@ -5229,6 +6654,50 @@ async fn diagnostics_summary<R: tauri::Runtime>(
     .await
 }
 
+#[tauri::command]
+async fn household_stats_refresh(
+    state: State<'_, AppState>,
+) -> AppResult<Vec<diagnostics::CachedHouseholdStats>> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    dispatch_async_app_result(move || {
+        let pool = pool.clone();
+        let vault = vault.clone();
+        async move { diagnostics::refresh_household_stats(&pool, &vault).await }
+    })
+    .await
+}
+
+/// Recompute the `household_stats` cache after a mutation that moves rows or
+/// attachment files between households. A failed refresh only leaves the
+/// cache stale, so it is logged rather than failing the mutation.
+async fn refresh_household_stats_after(pool: &SqlitePool, vault: &Vault, operation: &'static str) {
+    if let Err(err) = diagnostics::refresh_household_stats(pool, vault).await {
+        tracing::warn!(
+            target: "arklowdun",
+            event = "household_stats_refresh_failed",
+            operation,
+            error_code = %err.code()
+        );
+    }
+}
+
+/// Cached statistics as last written by [`household_stats_refresh`], an
+/// import, a household delete, [`row_reassign`] or [`attachments_import_dir`].
+/// Ordinary row edits do not refresh the cache.
+#[tauri::command]
+async fn household_stats_get(
+    state: State<'_, AppState>,
+) -> AppResult<Vec<diagnostics::CachedHouseholdStats>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let pool = pool.clone();
+        async move { diagnostics::cached_household_stats(&pool).await }
+    })
+    .await
+}
+
 #[tauri::command]
 async fn diagnostics_household_stats(
     state: State<'_, AppState>,
@@ -5297,53 +6766,157 @@ async fn open_diagnostics_doc<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Ap
 }
 
 #[tauri::command]
-async fn db_backup_overview(state: State<'_, AppState>) -> AppResult<backup::BackupOverview> {
-    let pool = state.pool_clone();
-    let db_path = (*state.db_path).clone();
+async fn open_logs_dir<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> AppResult<()> {
+    let app = app.clone();
     dispatch_async_app_result(move || {
-        let pool = pool.clone();
-        async move { backup::overview(&pool, &db_path).await }
+        let app = app;
+        async move {
+            let dir = diagnostics::logs_dir(&app)?;
+            std::fs::create_dir_all(&dir).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "open_logs_dir")
+                    .with_context("path", dir.display().to_string())
+            })?;
+            crate::attachments::reveal_with_os(&dir)
+        }
     })
     .await
 }
 
 #[tauri::command]
-async fn db_backup_create(state: State<'_, AppState>) -> AppResult<backup::BackupEntry> {
-    let pool = state.pool_clone();
-    let db_path = (*state.db_path).clone();
+async fn diagnostics_export_bundle<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, AppState>,
+    out_dir: String,
+) -> AppResult<String> {
+    let health = state
+        .db_health
+        .lock()
+        .map_err(|_| {
+            AppError::new(
+                "STATE/LOCK_POISONED",
+                "Failed to access database health cache",
+            )
+        })?
+        .clone();
+    let app = app.clone();
     dispatch_async_app_result(move || {
-        let pool = pool.clone();
-        async move { backup::create_backup(&pool, &db_path).await }
+        let app = app;
+        let out_dir = PathBuf::from(&out_dir);
+        let health = health.clone();
+        async move {
+            diagnostics::export_bundle(&app, &out_dir, &health)
+                .map(|path| path.display().to_string())
+        }
     })
     .await
 }
 
 #[tauri::command]
-async fn db_backup_reveal_root(state: State<'_, AppState>) -> AppResult<()> {
+async fn storage_footprint<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, AppState>,
+) -> AppResult<db::footprint::StorageFootprint> {
+    let logs_dir = resolve_logs_dir(&app).map_err(|err| {
+        AppError::new("LOGGING/DIR_UNAVAILABLE", "Log directory is unavailable.")
+            .with_context("error", err.to_string())
+    })?;
+    flush_file_logs();
     let db_path = (*state.db_path).clone();
+    let vault = state.vault();
     dispatch_async_app_result(move || {
-        let db_path = db_path.clone();
-        async move { backup::reveal_backup_root(&db_path) }
+        let vault = vault.clone();
+        async move { db::footprint::storage_footprint(&db_path, vault.base(), &logs_dir) }
     })
     .await
 }
 
 #[tauri::command]
-async fn db_backup_reveal(state: State<'_, AppState>, path: String) -> AppResult<()> {
+async fn db_backup_overview(state: State<'_, AppState>) -> AppResult<backup::BackupOverview> {
+    let pool = state.pool_clone();
     let db_path = (*state.db_path).clone();
     dispatch_async_app_result(move || {
-        let target = PathBuf::from(path);
-        async move { backup::reveal_backup(&db_path, &target) }
+        let pool = pool.clone();
+        async move { backup::overview(&pool, &db_path).await }
     })
     .await
 }
 
 #[tauri::command]
-async fn db_export_run<R: tauri::Runtime>(
-    _app: tauri::AppHandle<R>,
+async fn db_backup_create(
+    state: State<'_, AppState>,
+    differential: Option<bool>,
+) -> AppResult<backup::BackupEntry> {
+    guard::allow_recovery_read(&state, "db_backup_create")?;
+    let pool = state.pool_clone();
+    let db_path = (*state.db_path).clone();
+    dispatch_async_app_result(move || {
+        let pool = pool.clone();
+        async move {
+            if differential.unwrap_or(false) {
+                backup::create_differential_backup(&pool, &db_path).await
+            } else {
+                backup::create_backup(&pool, &db_path).await
+            }
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn db_backup_reveal_root(state: State<'_, AppState>) -> AppResult<()> {
+    let db_path = (*state.db_path).clone();
+    dispatch_async_app_result(move || {
+        let db_path = db_path.clone();
+        async move { backup::reveal_backup_root(&db_path) }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn db_backup_reveal(state: State<'_, AppState>, path: String) -> AppResult<()> {
+    let db_path = (*state.db_path).clone();
+    dispatch_async_app_result(move || {
+        let target = PathBuf::from(path);
+        async move { backup::reveal_backup(&db_path, &target) }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn db_backup_verify(backup_path: String) -> AppResult<backup::BackupVerification> {
+    dispatch_async_app_result(move || {
+        let target = PathBuf::from(&backup_path);
+        async move { backup::verify_backup(&target).await }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn db_backup_restore(backup_path: String, dest_path: String) -> AppResult<String> {
+    dispatch_async_app_result(move || {
+        let backup_dir = PathBuf::from(&backup_path);
+        let dest = PathBuf::from(&dest_path);
+        async move {
+            backup::restore_backup_to(&backup_dir, &dest)
+                .await
+                .map(|path| path.to_string_lossy().into_owned())
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn db_export_run<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
     state: State<'_, AppState>,
     out_parent: String,
+    compress: Option<bool>,
+    include_deletions_audit: Option<bool>,
+    include_tables: Option<Vec<String>>,
+    exclude_tables: Option<Vec<String>>,
 ) -> AppResult<export::ExportEntryDto> {
+    guard::allow_recovery_read(&state, "db_export_run")?;
     let pool = state.pool_clone();
     let out = std::path::PathBuf::from(out_parent);
     let vault = state.vault();
@@ -5351,10 +6924,18 @@ async fn db_export_run<R: tauri::Runtime>(
         let pool = pool.clone();
         let vault = vault.clone();
         async move {
-            let entry =
-                export::create_export(&pool, vault, export::ExportOptions { out_parent: out })
-                    .await
-                    .map_err(|err| err.with_context("operation", "export_run"))?;
+            let opts = export::ExportOptions {
+                out_parent: out,
+                compress: compress.unwrap_or(false),
+                include_deletions_audit: include_deletions_audit.unwrap_or(false),
+                tables: export::manifest::TableSelection {
+                    include_tables: include_tables.unwrap_or_default(),
+                    exclude_tables: exclude_tables.unwrap_or_default(),
+                },
+            };
+            let entry = export::create_export(&pool, vault, opts)
+                .await
+                .map_err(|err| err.with_context("operation", "export_run"))?;
             Ok::<_, crate::AppError>(export::ExportEntryDto::from(entry))
         }
     })
@@ -5362,6 +6943,73 @@ async fn db_export_run<R: tauri::Runtime>(
     Ok(result)
 }
 
+/// Export one household's vault files without the data dump.
+#[tauri::command]
+async fn db_export_attachments_only(
+    state: State<'_, AppState>,
+    out_parent: String,
+    household_id: String,
+) -> AppResult<export::ExportEntryDto> {
+    guard::allow_recovery_read(&state, "db_export_attachments_only")?;
+    let pool = state.pool_clone();
+    let out = std::path::PathBuf::from(out_parent);
+    let vault = state.vault();
+    dispatch_async_app_result(move || {
+        let pool = pool.clone();
+        let vault = vault.clone();
+        async move {
+            let entry = export::create_attachments_export(&pool, vault, &out, &household_id)
+                .await
+                .map_err(|err| err.with_context("operation", "export_attachments_only"))?;
+            Ok::<_, crate::AppError>(export::ExportEntryDto::from(entry))
+        }
+    })
+    .await
+}
+
+/// Copy an attachments-only bundle into a household's vault, linking files to existing rows.
+#[tauri::command]
+async fn db_import_attachments_only(
+    state: State<'_, AppState>,
+    bundle_path: String,
+    household_id: String,
+    dry_run: Option<bool>,
+) -> AppResult<import::AttachmentsOnlyImportReport> {
+    let dry_run = dry_run.unwrap_or(false);
+    let _permit = if dry_run {
+        None
+    } else {
+        Some(guard::ensure_db_writable(&state)?)
+    };
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    dispatch_async_app_result(move || {
+        let pool = pool.clone();
+        let vault = vault.clone();
+        let bundle_path = PathBuf::from(&bundle_path);
+        let household_id = household_id.clone();
+        async move {
+            import::import_attachments_only(&pool, &vault, &bundle_path, &household_id, dry_run)
+                .await
+                .map_err(|err| err.with_context("operation", "import_attachments_only"))
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+async fn db_export_diff(
+    bundle_a: String,
+    bundle_b: String,
+) -> AppResult<export::diff::ExportBundleDiff> {
+    dispatch_async_app_result(move || {
+        let bundle_a = PathBuf::from(&bundle_a);
+        let bundle_b = PathBuf::from(&bundle_b);
+        async move { export::diff::diff_bundles(&bundle_a, &bundle_b) }
+    })
+    .await
+}
+
 #[tauri::command]
 async fn db_repair_run<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
@@ -5369,6 +7017,7 @@ async fn db_repair_run<R: tauri::Runtime>(
 ) -> AppResult<DbRepairSummary> {
     let maintenance_guard = state.begin_maintenance()?;
     let pool = state.pool_clone();
+    let pool_settings = crate::db::PoolSettings::matching(&pool);
     let pool_handle = state.pool.clone();
     let db_path = (*state.db_path).clone();
     let db_path_for_reopen = db_path.clone();
@@ -5423,8 +7072,9 @@ async fn db_repair_run<R: tauri::Runtime>(
                     let cache = cache.clone();
                     let flag = flag.clone();
                     Box::pin(async move {
-                        let new_pool = crate::db::connect_sqlite_pool(&db_path)
-                            .await
+                        let new_pool =
+                            crate::db::connect_sqlite_pool_with(&db_path, pool_settings)
+                                .await
                             .map_err(|err| {
                                 AppError::from(err)
                                     .with_context("operation", "reopen_pool_after_swap")
@@ -5467,7 +7117,7 @@ async fn db_repair_run<R: tauri::Runtime>(
     drop(maintenance_guard);
 
     if pool_closed_after.load(Ordering::SeqCst) {
-        let reopened = crate::db::connect_sqlite_pool(&db_path_for_reopen)
+        let reopened = crate::db::connect_sqlite_pool_with(&db_path_for_reopen, pool_settings)
             .await
             .map_err(|err| {
                 AppError::from(err).with_context("operation", "reopen_pool_after_failure")
@@ -5483,6 +7133,7 @@ async fn db_repair_run<R: tauri::Runtime>(
 async fn db_hard_repair_run(state: State<'_, AppState>) -> AppResult<HardRepairOutcome> {
     let maintenance_guard = state.begin_maintenance()?;
     let pool = state.pool_clone();
+    let pool_settings = crate::db::PoolSettings::matching(&pool);
     let pool_handle = state.pool.clone();
     let db_path_for_task = (*state.db_path).clone();
     let db_path_for_reopen = (*state.db_path).clone();
@@ -5501,7 +7152,7 @@ async fn db_hard_repair_run(state: State<'_, AppState>) -> AppResult<HardRepairO
             pool.close().await;
             pool_closed.store(true, Ordering::SeqCst);
             let outcome = hard_repair::run_hard_repair(&db_path).await?;
-            let new_pool = crate::db::connect_sqlite_pool(&db_path)
+            let new_pool = crate::db::connect_sqlite_pool_with(&db_path, pool_settings)
                 .await
                 .map_err(|err| {
                     AppError::from(err).with_context("operation", "reopen_pool_after_hard_repair")
@@ -5533,7 +7184,7 @@ async fn db_hard_repair_run(state: State<'_, AppState>) -> AppResult<HardRepairO
     drop(maintenance_guard);
 
     if pool_closed_after.load(Ordering::SeqCst) {
-        let reopened = crate::db::connect_sqlite_pool(&db_path_for_reopen)
+        let reopened = crate::db::connect_sqlite_pool_with(&db_path_for_reopen, pool_settings)
             .await
             .map_err(|err| {
                 AppError::from(err)
@@ -5546,6 +7197,31 @@ async fn db_hard_repair_run(state: State<'_, AppState>) -> AppResult<HardRepairO
     result
 }
 
+#[tauri::command]
+async fn maintenance_begin(state: State<'_, AppState>, reason: String) -> AppResult<()> {
+    let reason = reason.trim().to_string();
+    if reason.is_empty() {
+        return Err(AppError::new(
+            "MAINTENANCE/REASON_REQUIRED",
+            "A reason is required to enter maintenance mode.",
+        ));
+    }
+    state.begin_manual_maintenance(reason.clone())?;
+    tracing::info!(
+        target: "arklowdun",
+        event = "maintenance_begin",
+        reason = %reason
+    );
+    Ok(())
+}
+
+#[tauri::command]
+async fn maintenance_end(state: State<'_, AppState>) -> AppResult<()> {
+    state.end_manual_maintenance()?;
+    tracing::info!(target: "arklowdun", event = "maintenance_end");
+    Ok(())
+}
+
 #[tauri::command]
 async fn file_move<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
@@ -5557,6 +7233,205 @@ async fn file_move<R: tauri::Runtime>(
     run_file_move(app, pool, vault, request).await
 }
 
+#[tauri::command]
+async fn attachment_move(
+    state: State<'_, AppState>,
+    table: String,
+    id: String,
+    new_relative_path: String,
+) -> AppResult<AttachmentMoveResponse> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    let active_household = state.active_household_id.clone();
+    dispatch_async_app_result(move || async move {
+        let descriptor = crate::attachments::load_attachment_descriptor(&pool, &table, &id)
+            .await
+            .map_err(|err| {
+                err.with_context("operation", "attachment_move")
+                    .with_context("table", table.clone())
+                    .with_context("id", id.clone())
+            })?;
+        ensure_active_household_for_ipc(
+            &active_household,
+            &descriptor.household_id,
+            descriptor.category,
+            &descriptor.relative_path,
+            "attachment_move",
+            &table,
+            Some(&id),
+        )?;
+        crate::file_ops::move_attachment(&pool, &vault, &table, &id, &new_relative_path).await
+    })
+    .await
+}
+
+#[tauri::command]
+async fn row_reassign(
+    state: State<'_, AppState>,
+    table: String,
+    id: String,
+    from_household: String,
+    to_household: String,
+) -> AppResult<RowReassignResponse> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    dispatch_async_app_result(move || async move {
+        let response = crate::file_ops::reassign_row(
+            &pool,
+            &vault,
+            &table,
+            &id,
+            &from_household,
+            &to_household,
+        )
+        .await?;
+        refresh_household_stats_after(&pool, &vault, "row_reassign").await;
+        Ok(response)
+    })
+    .await
+}
+
+#[tauri::command]
+async fn household_integrity_audit(
+    state: State<'_, AppState>,
+) -> AppResult<Vec<HouseholdIntegrityIssue>> {
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    dispatch_async_app_result(move || async move {
+        crate::file_ops::household_integrity_audit(&pool, &vault).await
+    })
+    .await
+}
+
+#[tauri::command]
+async fn attachment_copy(
+    state: State<'_, AppState>,
+    src_table: String,
+    src_id: String,
+    dst_table: String,
+    dst_id: String,
+) -> AppResult<AttachmentCopyResponse> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    let active_household = state.active_household_id.clone();
+    dispatch_async_app_result(move || async move {
+        let descriptor = crate::attachments::load_attachment_descriptor(&pool, &src_table, &src_id)
+            .await
+            .map_err(|err| {
+                err.with_context("operation", "attachment_copy")
+                    .with_context("table", src_table.clone())
+                    .with_context("id", src_id.clone())
+            })?;
+        ensure_active_household_for_ipc(
+            &active_household,
+            &descriptor.household_id,
+            descriptor.category,
+            &descriptor.relative_path,
+            "attachment_copy",
+            &src_table,
+            Some(&src_id),
+        )?;
+        // copy_attachment rejects a destination outside the source household,
+        // so both rows end up checked against the active household.
+        crate::file_ops::copy_attachment(&pool, &vault, &src_table, &src_id, &dst_table, &dst_id)
+            .await
+    })
+    .await
+}
+
+#[tauri::command]
+async fn attachment_restore_from_backup(
+    state: State<'_, AppState>,
+    table: String,
+    id: String,
+    backup_path: String,
+) -> AppResult<AttachmentRestoreResponse> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    let active_household = state.active_household_id.clone();
+    dispatch_async_app_result(move || async move {
+        let descriptor = crate::attachments::load_attachment_descriptor(&pool, &table, &id)
+            .await
+            .map_err(|err| {
+                err.with_context("operation", "attachment_restore_from_backup")
+                    .with_context("table", table.clone())
+                    .with_context("id", id.clone())
+            })?;
+        ensure_active_household_for_ipc(
+            &active_household,
+            &descriptor.household_id,
+            descriptor.category,
+            &descriptor.relative_path,
+            "attachment_restore_from_backup",
+            &table,
+            Some(&id),
+        )?;
+        crate::file_ops::restore_attachment_from_backup(
+            &pool,
+            &vault,
+            &table,
+            &id,
+            Path::new(&backup_path),
+        )
+        .await
+    })
+    .await
+}
+
+#[tauri::command]
+async fn attachments_backfill_hashes(
+    state: State<'_, AppState>,
+) -> AppResult<AttachmentHashBackfillResponse> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    dispatch_async_app_result(move || async move {
+        crate::file_ops::backfill_attachment_hashes(&pool, &vault).await
+    })
+    .await
+}
+
+#[tauri::command]
+async fn attachments_import_dir(
+    state: State<'_, AppState>,
+    table: String,
+    household_id: String,
+    category: AttachmentCategory,
+    source_dir: String,
+) -> AppResult<AttachmentsImportDirResponse> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    let vault = state.vault();
+    let active_household = state.active_household_id.clone();
+    dispatch_async_app_result(move || async move {
+        ensure_active_household_for_ipc(
+            &active_household,
+            &household_id,
+            category,
+            &source_dir,
+            "attachments_import_dir",
+            &table,
+            None,
+        )?;
+        let response = crate::file_ops::import_attachments_dir(
+            &pool,
+            &vault,
+            &table,
+            &household_id,
+            category,
+            Path::new(&source_dir),
+        )
+        .await?;
+        refresh_household_stats_after(&pool, &vault, "attachments_import_dir").await;
+        Ok(response)
+    })
+    .await
+}
+
 #[tauri::command]
 async fn attachments_repair<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
@@ -5622,64 +7497,94 @@ macro_rules! app_commands {
             events_backfill_timezone_cancel,
             events_backfill_timezone_status,
             events_list_range,
+            events_find_conflicts,
+            family_member_busy,
             event_create,
             event_update,
             event_delete,
             event_restore,
+            event_duplicate,
+            event_export_one,
+            event_import_one,
+            events_shift,
+            tables_last_modified,
+            changes_since,
+            trash_list,
+            trash_restore,
             household_get_active,
             household_list_all,
             household_list,
             household_get,
             family_ui_log,
             household_create,
+            household_seed_defaults,
             household_update,
             household_delete,
             household_resume_delete,
             household_repair,
             household_vacuum_execute,
+            household_cascade_reconcile,
+            household_cascade_counts,
             household_restore,
             file_move,
+            attachment_move,
+            row_reassign,
+            household_integrity_audit,
+            attachment_copy,
+            attachment_restore_from_backup,
+            attachments_backfill_hashes,
+            attachments_import_dir,
             attachments_repair,
             attachments_repair_manifest_export,
             bills_list,
             bills_get,
+            bills_get_many,
             bills_create,
             bills_update,
             bills_delete,
             bills_restore,
             bills_list_due_between,
+            bills_list_due_between_paged,
             policies_list,
             policies_get,
+            policies_get_many,
             policies_create,
             policies_update,
             policies_delete,
             policies_restore,
             property_documents_list,
             property_documents_get,
+            property_documents_get_many,
             property_documents_create,
             property_documents_update,
             property_documents_delete,
             property_documents_restore,
             inventory_items_list,
             inventory_items_get,
+            inventory_items_get_many,
             inventory_items_create,
             inventory_items_update,
             inventory_items_delete,
             inventory_items_restore,
+            inventory_report,
             vehicles_api::vehicles_list,
             vehicles_api::vehicles_get,
             vehicles_api::vehicles_create,
             vehicles_api::vehicles_update,
             vehicles_api::vehicles_delete,
             vehicles_api::vehicles_restore,
+            vehicles_due_soon,
             vehicle_maintenance_list,
             vehicle_maintenance_get,
+            vehicle_maintenance_get_many,
             vehicle_maintenance_create,
             vehicle_maintenance_update,
             vehicle_maintenance_delete,
             vehicle_maintenance_restore,
+            vehicle_maintenance_summary,
             pets_list,
             pets_get,
+            pets_get_many,
             pets_create,
             pets_update,
             pets_delete_soft,
@@ -5688,12 +7593,17 @@ macro_rules! app_commands {
             pets_restore,
             pet_medical_list,
             pet_medical_get,
+            pet_medical_get_many,
             pet_medical_create,
             pet_medical_update,
             pet_medical_delete,
             pet_medical_restore,
+            pet_medical_due,
+            policies_renewal_due,
+            upcoming_summary,
             family_members_list,
             family_members_get,
+            family_members_get_many,
             family_members_create,
             family_members_update,
             family_members_delete,
@@ -5713,23 +7623,42 @@ macro_rules! app_commands {
             categories_restore,
             budget_categories_list,
             budget_categories_get,
+            budget_categories_get_many,
             budget_categories_create,
             budget_categories_update,
             budget_categories_delete,
             budget_categories_restore,
             expenses_list,
             expenses_get,
+            expenses_get_many,
             expenses_create,
             expenses_update,
             expenses_delete,
             expenses_restore,
+            expense_templates_list,
+            expense_templates_get,
+            expense_templates_get_many,
+            expense_templates_create,
+            expense_templates_update,
+            expense_templates_delete,
+            expense_templates_restore,
+            expenses_generate_from_templates,
+            rrule_validate,
+            events_next_occurrences,
+            events_validate_recurrence,
+            day_bounds_utc,
+            period_bounds_utc,
             notes_list_cursor,
             notes_list_by_deadline_range,
             notes_get,
             notes_create,
             notes_update,
+            notes_set_pinned,
             notes_delete,
             notes_restore,
+            event_attendees_set,
+            event_attendees_list,
+            deletions_audit_list,
             note_links_create,
             note_links_delete,
             note_links_get_for_note,
@@ -5737,33 +7666,56 @@ macro_rules! app_commands {
             note_links_unlink_entity,
             notes_list_for_entity,
             notes_quick_create_for_entity,
+            notes_create_with_links,
             shopping_items_list,
+            shopping_items_complete,
             shopping_items_get,
+            shopping_items_get_many,
             shopping_items_create,
             shopping_items_update,
             shopping_items_delete,
             shopping_items_restore,
             attachment_open,
             attachment_reveal,
+            attachments_list_for_entity,
             files_exists,
             thumbnails_get_or_create,
             attachments_migration_status,
             attachments_migrate,
             attachments_resume_migration,
+            attachments_migrate_cancel,
             diagnostics_summary,
+            logging_set_filter,
+            logs_status,
+            settings_get,
+            settings_update,
             diagnostics_household_stats,
+            household_stats_refresh,
+            household_stats_get,
             diagnostics_doc_path,
             open_diagnostics_doc,
+            open_logs_dir,
+            diagnostics_export_bundle,
             db_backup_overview,
+            storage_footprint,
             db_backup_create,
             db_backup_reveal_root,
             db_backup_reveal,
+            db_backup_verify,
+            db_backup_restore,
             db_export_run,
+            db_export_attachments_only,
+            db_import_attachments_only,
+            db_export_diff,
             db_import_preview,
             db_import_execute,
             db_repair_run,
             db_hard_repair_run,
+            maintenance_begin,
+            maintenance_end,
             time_invariants_check,
+            timezones_list,
+            json_schema::bindings_emit_json_schema,
             about_metadata,
             $($extra),*
         ]
@@ -5784,6 +7736,26 @@ pub fn run() {
             let store_handle = crate::household_active::StoreHandle::tauri(
                 tauri_plugin_store::StoreBuilder::new(app, "arklowdun.json").build()?,
             );
+            let stored_settings = settings::load_settings(&store_handle);
+            let fallback_timezone = stored_settings.fallback_timezone;
+            if let Err(err) = settings::set_fallback_timezone(&fallback_timezone) {
+                tracing::warn!(
+                    target: "arklowdun",
+                    event = "settings_fallback_timezone_invalid",
+                    timezone = %fallback_timezone,
+                    error = %err
+                );
+            }
+            // An explicit TAURI_ARKLOWDUN_LOG filter wins over the stored level.
+            if std::env::var_os("TAURI_ARKLOWDUN_LOG").is_none() {
+                if let Err(err) = set_log_level(&stored_settings.log_level) {
+                    tracing::warn!(
+                        target: "arklowdun",
+                        event = "settings_log_level_apply_failed",
+                        error = %err
+                    );
+                }
+            }
             let handle = app.handle();
             if let Err(err) = crate::init_file_logging(handle.clone()) {
                 tracing::warn!(
@@ -5792,6 +7764,8 @@ pub fn run() {
                     error = %err
                 );
             }
+            // Resolve the timestamp bounds now so a rejected override is logged at launch.
+            commands::TimestampRange::configured();
             #[allow(clippy::needless_borrow)]
             let (mut pool, db_path) =
                 tauri::async_runtime::block_on(crate::db::open_sqlite_pool(&handle))?;
@@ -5936,15 +7910,22 @@ pub fn run() {
                 vault,
                 vault_migration,
                 maintenance: Arc::new(AtomicBool::new(false)),
+                maintenance_reason: Arc::new(Mutex::new(None)),
                 files_indexer: files_indexer.clone(),
                 pet_metrics: Arc::new(PetAttachmentMetrics::new()),
             });
 
             spawn_idle_index_scheduler(app.handle().clone());
+            spawn_db_upkeep_scheduler(
+                app.handle().clone(),
+                crate::db::upkeep::UpkeepConfig::from_env(),
+            );
             Ok(())
         })
         .invoke_handler(app_commands![
             search_entities,
+            search_entities_stream,
+            search_entities_stream_cancel,
             import_run_legacy,
             open_path,
             household_get_active,
@@ -5960,6 +7941,11 @@ pub fn run() {
             // Database health IPC commands consumed by the frontend shell.
             db_get_health_report,
             db_recheck,
+            db_schema_info,
+            db_schema_dump,
+            db_index_stats,
+            db_path_info,
+            db_migrations_pending,
             pets_diagnostics_counters
         ])
         .run(tauri::generate_context!("tauri.conf.json5"))
@@ -5993,6 +7979,74 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod log_status_tests {
+    use super::*;
+
+    #[test]
+    fn stdout_format_defaults_to_json() {
+        assert_eq!(StdoutLogFormat::from_env_value(None), StdoutLogFormat::Json);
+        assert_eq!(
+            StdoutLogFormat::from_env_value(Some("json")),
+            StdoutLogFormat::Json
+        );
+        assert_eq!(
+            StdoutLogFormat::from_env_value(Some(" Pretty ")),
+            StdoutLogFormat::Pretty
+        );
+        assert_eq!(
+            StdoutLogFormat::from_env_value(Some("compact")),
+            StdoutLogFormat::Json
+        );
+    }
+
+    #[test]
+    fn subscriber_builds_in_both_stdout_formats() {
+        for format in [StdoutLogFormat::Json, StdoutLogFormat::Pretty] {
+            let (subscriber, handle) = build_subscriber("arklowdun=info", format);
+            tracing::subscriber::with_default(subscriber, || {
+                tracing::info!(target: "arklowdun", event = "format_smoke", ?format);
+                handle
+                    .reload(EnvFilter::new("arklowdun=debug"))
+                    .expect("reload filter while subscriber is alive");
+                tracing::debug!(target: "arklowdun", event = "format_smoke", ?format);
+            });
+        }
+    }
+
+    #[test]
+    fn log_level_only_touches_the_arklowdun_directive() {
+        assert_eq!(
+            with_arklowdun_level("arklowdun=info,sqlx=warn,hyper=error", "debug"),
+            "arklowdun=debug,sqlx=warn,hyper=error"
+        );
+        assert_eq!(
+            with_arklowdun_level("sqlx=warn,arklowdun::db=trace", "warn"),
+            "sqlx=warn,arklowdun::db=trace,arklowdun=warn"
+        );
+        assert_eq!(with_arklowdun_level("", "info"), "arklowdun=info");
+    }
+
+    #[test]
+    fn logs_status_counts_rolled_files_after_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+        let mut rotator = CountRotator::new(path, 64, 2).unwrap();
+        let line = [b'x'; 40];
+        for _ in 0..5 {
+            rotator.write_all(&line).unwrap();
+        }
+        rotator.flush().unwrap();
+        std::fs::write(dir.path().join("unrelated.txt"), b"ignored").unwrap();
+
+        let status = logs_status_for_dir(dir.path());
+        assert_eq!(status.rolled_files, 2, "retention caps rolled files");
+        assert_eq!(status.current_bytes, 40);
+        assert_eq!(status.total_bytes, 120);
+        assert!(status.last_rotated_at.is_some());
+    }
+}
+
 #[cfg(test)]
 mod vault_root_key_tests {
     use super::*;
@@ -6182,39 +8236,528 @@ mod search_tests {
             .execute(&pool)
             .await
             .unwrap();
-        sqlx::query(
-            "INSERT INTO files (id, household_id, filename, updated_at) VALUES ('f1','hh','a',0)",
+        sqlx::query(
+            "INSERT INTO files (id, household_id, filename, updated_at) VALUES ('f1','hh','a',0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "CREATE TABLE files_index_meta (household_id TEXT PRIMARY KEY, last_built_at_utc TEXT NOT NULL, source_row_count INTEGER NOT NULL, source_max_updated_utc TEXT NOT NULL, version INTEGER NOT NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO files_index_meta (household_id, last_built_at_utc, source_row_count, source_max_updated_utc, version) VALUES ('hh','2024-01-01T00:00:00Z',1,'1970-01-01T00:00:00Z',0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(!files_index_ready(&pool, "hh").await);
+        sqlx::query("UPDATE files_index_meta SET version=?1")
+            .bind(FILES_INDEX_VERSION)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(files_index_ready(&pool, "hh").await);
+        sqlx::query("UPDATE files SET updated_at=1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(!files_index_ready(&pool, "hh").await);
+    }
+
+    #[tokio::test]
+    async fn files_index_updates_keep_index_ready() {
+        let pool = search_pool().await;
+        let dir = tempfile::tempdir().unwrap();
+        let vault = Vault::new(dir.path());
+        sqlx::query("INSERT INTO files_index_meta (household_id, last_built_at_utc, source_row_count, source_max_updated_utc, version) VALUES ('hh','2024-01-01T00:00:00Z',0,'1970-01-01T00:00:00Z',?1)")
+            .bind(FILES_INDEX_VERSION)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(files_index_ready(&pool, "hh").await);
+
+        let category = AttachmentCategory::Bills;
+        let path = vault.resolve("hh", category, "a.pdf").unwrap();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, b"pdf-bytes").unwrap();
+        sqlx::query("INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, root_key, relative_path, category) VALUES ('b1', 100, 0, 'hh', 0, 0, 'attachments', 'a.pdf', 'bills')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(!files_index_ready(&pool, "hh").await);
+        crate::files_indexer::index_vault_file(&pool, &vault, "hh", category, "a.pdf")
+            .await
+            .unwrap();
+        assert!(files_index_ready(&pool, "hh").await);
+        let count: i64 = sqlx::query_scalar(
+            "SELECT source_row_count FROM files_index_meta WHERE household_id='hh'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(count, 1);
+
+        std::fs::remove_file(&path).unwrap();
+        sqlx::query("UPDATE bills SET deleted_at=1 WHERE id='b1'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        crate::files_indexer::forget_vault_file(&pool, "hh", category, "a.pdf")
+            .await
+            .unwrap();
+        assert!(files_index_ready(&pool, "hh").await);
+        let count: i64 = sqlx::query_scalar(
+            "SELECT source_row_count FROM files_index_meta WHERE household_id='hh'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(count, 0);
+
+        // A meta row from an older index version is left for a full rebuild.
+        sqlx::query("UPDATE files_index_meta SET version=?1")
+            .bind(FILES_INDEX_VERSION - 1)
+            .execute(&pool)
+            .await
+            .unwrap();
+        std::fs::write(&path, b"pdf-bytes").unwrap();
+        sqlx::query("UPDATE bills SET deleted_at=NULL, updated_at=2 WHERE id='b1'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        crate::files_indexer::index_vault_file(&pool, &vault, "hh", category, "a.pdf")
+            .await
+            .unwrap();
+        assert!(!files_index_ready(&pool, "hh").await);
+    }
+
+    #[test]
+    fn like_escape_escapes_wildcards() {
+        assert_eq!(like_escape("50%_\\test"), "50\\%\\_\\\\test");
+    }
+
+    async fn search_pool() -> SqlitePool {
+        let pool: SqlitePool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::migrate::apply_migrations(&pool).await.unwrap();
+        sqlx::query("INSERT INTO household (id, name, created_at, updated_at, tz) VALUES ('hh','Test',0,0,'UTC')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    async fn insert_note(pool: &SqlitePool, id: &str, text: &str) {
+        sqlx::query("INSERT INTO notes (id, household_id, position, created_at, updated_at, text) VALUES (?1, 'hh', (SELECT COALESCE(MAX(position), -1) + 1 FROM notes WHERE household_id = 'hh'), 0, 0, ?2)")
+            .bind(id)
+            .bind(text)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn highlight_spans_use_utf16_offsets_and_literal_wildcards() {
+        let spans = highlight_spans("title", "Café 50% OFF, 50% again", "50%");
+        assert_eq!(
+            spans,
+            vec![
+                SearchHighlight {
+                    field: "title".into(),
+                    start: 5,
+                    end: 8,
+                },
+                SearchHighlight {
+                    field: "title".into(),
+                    start: 14,
+                    end: 17,
+                },
+            ]
+        );
+        assert!(highlight_spans("title", "a_b", "%").is_empty());
+    }
+
+    #[tokio::test]
+    async fn stored_norm_columns_match_search_fold() {
+        let pool = search_pool().await;
+        let mut text: String = search_fold::FOLDED_RANGE.collect();
+        text.push_str(" Cafe\u{301} NAI\u{308}VE ");
+        text.extend(search_fold::STRIPPED_MARKS);
+        insert_note(&pool, "n_fold", &text).await;
+        sqlx::query("INSERT INTO events (id, title, household_id, created_at, updated_at, start_at_utc) VALUES ('e_fold', ?1, 'hh', 0, 0, 0)")
+            .bind(&text)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let expected = search_fold::fold_search_text(&text);
+        let text_norm: String =
+            sqlx::query_scalar("SELECT text_norm FROM notes WHERE id = 'n_fold'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(text_norm, expected);
+        let title_norm: String =
+            sqlx::query_scalar("SELECT title_norm FROM events WHERE id = 'e_fold'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(title_norm, expected);
+    }
+
+    #[tokio::test]
+    async fn accent_insensitive_matches_across_notes_and_events() {
+        let pool = search_pool().await;
+        let spellings = ["café", "cafe", "CAFÉ"];
+        for (i, text) in spellings.iter().enumerate() {
+            insert_note(&pool, &format!("n{i}"), &format!("Meet at the {text}")).await;
+            sqlx::query("INSERT INTO events (id, title, household_id, created_at, updated_at, start_at_utc) VALUES (?1, ?2, 'hh', 0, 0, 0)")
+                .bind(format!("e{i}"))
+                .bind(format!("{text} brunch"))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        for query in spellings {
+            let results = run_search(&pool, "hh".into(), query.into(), 50, 0, None, None)
+                .await
+                .unwrap()
+                .results;
+            let notes = results
+                .iter()
+                .filter(|r| matches!(r, SearchResult::Note { .. }))
+                .count();
+            let events = results
+                .iter()
+                .filter(|r| matches!(r, SearchResult::Event { .. }))
+                .count();
+            assert_eq!(notes, 3, "notes matching {query}");
+            assert_eq!(events, 3, "events matching {query}");
+            for result in &results {
+                let (SearchResult::Note { highlights, .. }
+                | SearchResult::Event { highlights, .. }) = result
+                else {
+                    continue;
+                };
+                assert_eq!(highlights.len(), 1, "highlight for {query}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn single_character_query_respects_min_len() {
+        let pool: SqlitePool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::migrate::apply_migrations(&pool).await.unwrap();
+        sqlx::query("INSERT INTO household (id, name, created_at, updated_at, tz) VALUES ('hh','Test',0,0,'UTC')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO files_index (household_id, file_id, filename, updated_at_utc, ordinal) VALUES ('hh','f1','x-ray.pdf','1970-01-01T00:00:00Z',0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO files_index_meta (household_id, last_built_at_utc, source_row_count, source_max_updated_utc, version) VALUES ('hh','2024-01-01T00:00:00Z',0,'1970-01-01T00:00:00Z',?1)")
+            .bind(FILES_INDEX_VERSION)
+            .execute(&pool)
+            .await
+            .unwrap();
+        insert_note(&pool, "n1", "Book the x-ray follow-up").await;
+
+        let default = run_search(&pool, "hh".into(), "x".into(), 10, 0, None, None)
+            .await
+            .unwrap()
+            .results;
+        assert_eq!(default.len(), 1);
+        assert!(matches!(&default[0], SearchResult::File { id, .. } if id == "f1"));
+
+        let lowered = run_search(&pool, "hh".into(), "x".into(), 10, 0, Some(1), None)
+            .await
+            .unwrap()
+            .results;
+        assert!(lowered
+            .iter()
+            .any(|r| matches!(r, SearchResult::File { .. })));
+        assert!(lowered
+            .iter()
+            .any(|r| matches!(r, SearchResult::Note { id, .. } if id == "n1")));
+    }
+
+    #[tokio::test]
+    async fn streamed_batches_match_batch_results() {
+        let pool = search_pool().await;
+        insert_note(&pool, "n1", "Swim kit in the hallway").await;
+        insert_note(&pool, "n2", "Book swim lessons").await;
+        sqlx::query("INSERT INTO events (id, title, household_id, created_at, updated_at, start_at_utc) VALUES ('e1', 'Swim gala', 'hh', 0, 0, 10)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO pets (id, name, type, household_id, created_at, updated_at) VALUES ('p1', 'Swimmer', 'Fish', 'hh', 0, 0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let batch = run_search(&pool, "hh".into(), "swim".into(), 50, 0, None, None)
+            .await
+            .unwrap()
+            .results;
+        let mut branches = Vec::new();
+        let mut streamed = Vec::new();
+        let outcome = run_search_streaming(
+            &pool,
+            "hh".into(),
+            "swim".into(),
+            50,
+            None,
+            None,
+            &AtomicBool::new(false),
+            |branch, results| {
+                branches.push(branch);
+                streamed.extend(results);
+            },
+        )
+        .await
+        .unwrap();
+        let total = outcome.total;
+
+        assert_eq!(branches, vec!["events", "notes", "vehicles", "pets"]);
+        assert_eq!(total, streamed.len());
+        let keyed = |results: &[SearchResult]| {
+            let mut keys: Vec<String> = results
+                .iter()
+                .map(|result| serde_json::to_string(result).unwrap())
+                .collect();
+            keys.sort();
+            keys
+        };
+        assert_eq!(batch.len(), 4);
+        assert_eq!(keyed(&streamed), keyed(&batch));
+    }
+
+    #[tokio::test]
+    async fn streamed_results_respect_the_overall_limit() {
+        let pool = search_pool().await;
+        for (id, text) in [("n1", "Swim kit"), ("n2", "Swim cap"), ("n3", "Swim towel")] {
+            insert_note(&pool, id, text).await;
+        }
+        sqlx::query("INSERT INTO events (id, title, household_id, created_at, updated_at, start_at_utc) VALUES ('e1', 'Swim gala', 'hh', 0, 0, 10)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut branches = Vec::new();
+        let mut streamed = Vec::new();
+        let outcome = run_search_streaming(
+            &pool,
+            "hh".into(),
+            "swim".into(),
+            2,
+            None,
+            None,
+            &AtomicBool::new(false),
+            |branch, results| {
+                branches.push(branch);
+                streamed.extend(results);
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome.total, 2);
+        assert_eq!(streamed.len(), 2);
+        assert_eq!(branches, vec!["events", "notes"]);
+        assert!(!outcome.cancelled);
+    }
+
+    #[tokio::test]
+    async fn cancelled_stream_stops_before_the_next_branch() {
+        let pool = search_pool().await;
+        insert_note(&pool, "n1", "Swim kit").await;
+
+        let cancel = AtomicBool::new(false);
+        let mut branches = Vec::new();
+        let outcome = run_search_streaming(
+            &pool,
+            "hh".into(),
+            "swim".into(),
+            50,
+            None,
+            None,
+            &cancel,
+            |branch, _| {
+                branches.push(branch);
+                cancel.store(true, Ordering::SeqCst);
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(outcome.cancelled);
+        assert_eq!(branches, vec!["events"]);
+    }
+
+    fn event_ids(response: &SearchResponse) -> Vec<String> {
+        let mut ids: Vec<String> = response
+            .results
+            .iter()
+            .filter_map(|result| match result {
+                SearchResult::Event { id, .. } => Some(id.clone()),
+                _ => None,
+            })
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test]
+    async fn iso_month_query_matches_events_starting_that_month() {
+        let pool = search_pool().await;
+        // 2024-05-31T23:00Z, 2024-06-15T12:00Z, 2024-07-01T00:00Z
+        for (id, title, start) in [
+            ("e_may", "Dentist", 1_717_196_400_000_i64),
+            ("e_june", "Swim gala", 1_718_452_800_000),
+            ("e_july", "Picnic", 1_719_792_000_000),
+            ("e_named", "Budget 2024-06 review", 0),
+        ] {
+            sqlx::query("INSERT INTO events (id, title, household_id, created_at, updated_at, start_at_utc) VALUES (?1, ?2, 'hh', 0, 0, ?3)")
+                .bind(id)
+                .bind(title)
+                .bind(start)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let june = run_search(&pool, "hh".into(), "2024-06".into(), 50, 0, None, None)
+            .await
+            .unwrap();
+        assert_eq!(event_ids(&june), vec!["e_june", "e_named"]);
+
+        // Not a valid month, so only the title match remains.
+        sqlx::query("UPDATE events SET title = 'Invoice 2024-13' WHERE id = 'e_july'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let invalid = run_search(&pool, "hh".into(), "2024-13".into(), 50, 0, None, None)
+            .await
+            .unwrap();
+        assert_eq!(event_ids(&invalid), vec!["e_july"]);
+    }
+
+    #[tokio::test]
+    async fn tz_less_event_reports_configured_fallback_timezone() {
+        let pool = search_pool().await;
+        sqlx::query("INSERT INTO events (id, title, household_id, created_at, updated_at, start_at_utc) VALUES ('e_tzless', 'Recital', 'hh', 0, 0, 0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let event_tz = |response: &SearchResponse| {
+            response.results.iter().find_map(|result| match result {
+                SearchResult::Event { tz, .. } => Some(tz.clone()),
+                _ => None,
+            })
+        };
+
+        let before = run_search(&pool, "hh".into(), "recital".into(), 10, 0, None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            event_tz(&before).as_deref(),
+            Some(settings::DEFAULT_FALLBACK_TIMEZONE)
+        );
+
+        settings::set_fallback_timezone("America/Chicago").unwrap();
+        let after = run_search(&pool, "hh".into(), "recital".into(), 10, 0, None, None).await;
+        settings::set_fallback_timezone(settings::DEFAULT_FALLBACK_TIMEZONE).unwrap();
+        assert_eq!(
+            event_tz(&after.unwrap()).as_deref(),
+            Some("America/Chicago")
+        );
+
+        let err = settings::set_fallback_timezone("Mars/Olympus").unwrap_err();
+        assert_eq!(err.code(), settings::SETTINGS_INVALID_TIMEZONE_CODE);
+    }
+
+    #[tokio::test]
+    async fn slow_branch_is_abandoned_and_flagged_partial() {
+        let pool = search_pool().await;
+        insert_note(&pool, "n1", "Dentist reminder").await;
+        sqlx::query("INSERT INTO events (id, title, household_id, created_at, updated_at, start_at_utc) VALUES ('e1', 'Dentist', 'hh', 0, 0, 0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (mut terms, branches) = plan_search(&pool, "hh".into(), "dentist".into(), 10, 0, None)
+            .await
+            .unwrap()
+            .expect("non-empty query");
+        terms.slow_branch = Some((SearchBranch::Notes, std::time::Duration::from_secs(5)));
+        let response = collect_search(
+            &pool,
+            &terms,
+            branches.clone(),
+            10,
+            0,
+            Some(std::time::Duration::from_millis(50)),
         )
-        .execute(&pool)
         .await
         .unwrap();
-        sqlx::query(
-            "CREATE TABLE files_index_meta (household_id TEXT PRIMARY KEY, last_built_at_utc TEXT NOT NULL, source_row_count INTEGER NOT NULL, source_max_updated_utc TEXT NOT NULL, version INTEGER NOT NULL)",
+        assert!(response.partial);
+        assert_eq!(response.timed_out_branches, vec!["notes".to_string()]);
+        assert_eq!(response.results.len(), 1);
+        assert!(matches!(&response.results[0], SearchResult::Event { id, .. } if id == "e1"));
+
+        terms.slow_branch = None;
+        let response = collect_search(
+            &pool,
+            &terms,
+            branches,
+            10,
+            0,
+            Some(std::time::Duration::from_secs(5)),
         )
-        .execute(&pool)
         .await
         .unwrap();
-        sqlx::query("INSERT INTO files_index_meta (household_id, last_built_at_utc, source_row_count, source_max_updated_utc, version) VALUES ('hh','2024-01-01T00:00:00Z',1,'1970-01-01T00:00:00Z',0)")
-            .execute(&pool)
-            .await
-            .unwrap();
-        assert!(!files_index_ready(&pool, "hh").await);
-        sqlx::query("UPDATE files_index_meta SET version=?1")
-            .bind(FILES_INDEX_VERSION)
-            .execute(&pool)
-            .await
-            .unwrap();
-        assert!(files_index_ready(&pool, "hh").await);
-        sqlx::query("UPDATE files SET updated_at=1")
-            .execute(&pool)
-            .await
-            .unwrap();
-        assert!(!files_index_ready(&pool, "hh").await);
+        assert!(!response.partial);
+        assert_eq!(response.results.len(), 2);
     }
 
-    #[test]
-    fn like_escape_escapes_wildcards() {
-        assert_eq!(like_escape("50%_\\test"), "50\\%\\_\\\\test");
+    #[tokio::test]
+    async fn note_snippet_highlight_covers_match() {
+        let pool = search_pool().await;
+        insert_note(&pool, "n1", "Pick up the Dentist letter").await;
+
+        let results = run_search(&pool, "hh".into(), "dentist".into(), 10, 0, None, None)
+            .await
+            .unwrap()
+            .results;
+        let Some(SearchResult::Note {
+            snippet,
+            highlights,
+            ..
+        }) = results.into_iter().next()
+        else {
+            panic!("expected a note result");
+        };
+        assert_eq!(highlights.len(), 1);
+        let span = &highlights[0];
+        assert_eq!(span.field, "snippet");
+        let covered: String = snippet
+            .encode_utf16()
+            .skip(span.start as usize)
+            .take((span.end - span.start) as usize)
+            .map(|unit| char::from_u32(unit as u32).unwrap())
+            .collect();
+        assert_eq!(covered, "Dentist");
     }
 }
 
@@ -6299,6 +8842,7 @@ mod db_health_command_tests {
                 crate::vault_migration::VaultMigrationManager::new(&attachments_root).unwrap(),
             ),
             maintenance: Arc::new(AtomicBool::new(false)),
+            maintenance_reason: Arc::new(Mutex::new(None)),
             files_indexer: Arc::new(crate::files_indexer::FilesIndexer::new(pool.clone(), vault)),
             pet_metrics: Arc::new(PetAttachmentMetrics::new()),
         };
@@ -6338,6 +8882,114 @@ mod db_health_command_tests {
     }
 }
 
+#[cfg(test)]
+mod import_command_tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use tauri::test::{mock_builder, mock_context, noop_assets};
+    use tauri::Manager;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn import_commands_report_bundle_too_new_code() {
+        let dir = tempdir().expect("temp dir");
+        let db_path = dir.path().join("arklowdun.sqlite3");
+        let pool = crate::db::connect_sqlite_pool(&db_path)
+            .await
+            .expect("connect sqlite");
+        crate::migrate::apply_migrations(&pool)
+            .await
+            .expect("apply migrations");
+        let report = crate::db::health::run_health_checks(&pool, &db_path)
+            .await
+            .expect("health report");
+
+        let attachments_root = dir.path().join("attachments");
+        std::fs::create_dir_all(&attachments_root).expect("create attachments dir");
+        let vault = Arc::new(Vault::new(attachments_root.clone()));
+        let export = crate::export::create_export(
+            &pool,
+            vault.clone(),
+            crate::export::ExportOptions {
+                out_parent: dir.path().join("exports"),
+                compress: false,
+                include_deletions_audit: false,
+                tables: Default::default(),
+            },
+        )
+        .await
+        .expect("create export");
+        let manifest_path = export.directory.join("manifest.json");
+        let mut manifest: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&manifest_path).expect("read manifest"))
+                .expect("parse manifest");
+        manifest["appVersion"] = serde_json::Value::from("999.0.0");
+        std::fs::write(
+            &manifest_path,
+            serde_json::to_vec_pretty(&manifest).expect("serialize manifest"),
+        )
+        .expect("write manifest");
+
+        let app_state = crate::state::AppState {
+            pool: Arc::new(RwLock::new(pool.clone())),
+            active_household_id: Arc::new(Mutex::new(String::new())),
+            store: crate::household_active::StoreHandle::in_memory(),
+            backfill: Arc::new(Mutex::new(
+                crate::events_tz_backfill::BackfillCoordinator::new(),
+            )),
+            db_health: Arc::new(Mutex::new(report)),
+            db_path: Arc::new(db_path.clone()),
+            vault: vault.clone(),
+            vault_migration: Arc::new(
+                crate::vault_migration::VaultMigrationManager::new(&attachments_root).unwrap(),
+            ),
+            maintenance: Arc::new(AtomicBool::new(false)),
+            maintenance_reason: Arc::new(Mutex::new(None)),
+            files_indexer: Arc::new(crate::files_indexer::FilesIndexer::new(pool.clone(), vault)),
+            pet_metrics: Arc::new(PetAttachmentMetrics::new()),
+        };
+        let app = mock_builder()
+            .manage(app_state)
+            .build(mock_context(noop_assets()))
+            .expect("build tauri app");
+        let bundle_path = export.directory.display().to_string();
+
+        let err = super::db_import_preview(
+            app.state(),
+            ImportPreviewArgs {
+                bundle_path: bundle_path.clone(),
+                mode: import::plan::ImportMode::Merge,
+                allow_newer: false,
+                tables: Default::default(),
+            },
+        )
+        .await
+        .err()
+        .expect("newer bundle rejected by preview");
+        assert_eq!(err.code(), import::validator::IMPORT_BUNDLE_TOO_NEW_CODE);
+        assert_eq!(
+            err.context().get("bundle_version").map(String::as_str),
+            Some("999.0.0")
+        );
+
+        let err = super::db_import_execute(
+            app.state(),
+            ImportExecuteArgs {
+                bundle_path,
+                mode: import::plan::ImportMode::Merge,
+                expected_plan_digest: String::new(),
+                allow_newer: false,
+                tables: Default::default(),
+            },
+        )
+        .await
+        .err()
+        .expect("newer bundle rejected by execute");
+        assert_eq!(err.code(), import::validator::IMPORT_BUNDLE_TOO_NEW_CODE);
+    }
+}
+
 #[cfg(test)]
 mod attachment_ipc_read_tests {
     use super::*;
@@ -6421,6 +9073,118 @@ mod attachment_ipc_read_tests {
         .expect_err("expected absolute guard to reject");
         assert_eq!(err.code(), crate::vault::ERR_PATH_OUT_OF_VAULT);
     }
+
+    #[tokio::test]
+    async fn soft_deleted_rows_report_deleted() {
+        let pool = setup_pool().await;
+        insert_descriptor(&pool, "a3", "docs/report.pdf").await;
+        sqlx::query("UPDATE files SET deleted_at = 1 WHERE id = 'a3'")
+            .execute(&pool)
+            .await
+            .expect("soft delete row");
+        let active = Arc::new(Mutex::new(String::new()));
+        let vault_dir = tempdir().expect("tempdir");
+        let vault = Arc::new(Vault::new(vault_dir.path()));
+
+        let err = resolve_attachment_for_ipc_read(
+            &pool,
+            &active,
+            &vault,
+            "files",
+            "a3",
+            "attachment_open",
+        )
+        .await
+        .expect_err("expected deleted row to be rejected");
+        assert_eq!(err.code(), crate::attachments::ERR_ATTACHMENT_DELETED);
+        assert_eq!(
+            err.context().get("operation").map(String::as_str),
+            Some("attachment_open")
+        );
+
+        let err = resolve_attachment_for_ipc_read(
+            &pool,
+            &active,
+            &vault,
+            "files",
+            "missing",
+            "attachment_open",
+        )
+        .await
+        .expect_err("expected missing row to be rejected");
+        assert_eq!(err.code(), "DB/NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn renamed_household_attachments_still_resolve() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("connect sqlite memory");
+        crate::migrate::apply_migrations(&pool)
+            .await
+            .expect("apply migrations");
+        let household = crate::household::create_household(&pool, "Smiths", None)
+            .await
+            .expect("create household");
+        sqlx::query(
+            "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category)
+             VALUES ('bill1', 100, 0, ?1, 1, 1, 0, 'attachments', 'docs/gas.pdf', 'bills')",
+        )
+        .bind(&household.id)
+        .execute(&pool)
+        .await
+        .expect("insert bill");
+        let active = Arc::new(Mutex::new(household.id.clone()));
+        let vault_dir = tempdir().expect("tempdir");
+        let vault = Arc::new(Vault::new(vault_dir.path()));
+        let path = vault
+            .resolve(&household.id, AttachmentCategory::Bills, "docs/gas.pdf")
+            .expect("resolve vault path");
+        std::fs::create_dir_all(path.parent().unwrap()).expect("create vault dirs");
+        std::fs::write(&path, b"gas").expect("write attachment");
+
+        let before = resolve_attachment_for_ipc_read(
+            &pool,
+            &active,
+            &vault,
+            "bills",
+            "bill1",
+            "attachment_open",
+        )
+        .await
+        .expect("resolve before rename");
+
+        let renamed = crate::household::update_household(
+            &pool,
+            &household.id,
+            crate::household::HouseholdUpdateInput {
+                name: Some("The Smith Family"),
+                color: None,
+            },
+        )
+        .await
+        .expect("rename household");
+        assert_eq!(renamed.name, "The Smith Family");
+
+        let after = resolve_attachment_for_ipc_read(
+            &pool,
+            &active,
+            &vault,
+            "bills",
+            "bill1",
+            "attachment_open",
+        )
+        .await
+        .expect("resolve after rename");
+        assert_eq!(after, before);
+        assert_eq!(std::fs::read(&after).expect("read attachment"), b"gas");
+        let issues = crate::file_ops::household_integrity_audit(&pool, &vault)
+            .await
+            .expect("integrity audit");
+        assert!(issues.is_empty(), "{issues:?}");
+    }
 }
 
 #[cfg(test)]
@@ -6759,6 +9523,7 @@ mod attachment_ipc_command_tests {
             vault,
             vault_migration: migration,
             maintenance: Arc::new(AtomicBool::new(false)),
+            maintenance_reason: Arc::new(Mutex::new(None)),
             files_indexer,
             pet_metrics: Arc::new(PetAttachmentMetrics::new()),
         }
@@ -6889,6 +9654,146 @@ mod attachment_ipc_command_tests {
             Some(crate::vault::ERR_INVALID_HOUSEHOLD)
         );
     }
+
+    #[test]
+    fn attachments_list_for_entity_rejects_bare_row_in_other_household() {
+        let dir = tempdir().expect("tempdir");
+        let attachments_root = crate::vault::paths::attachments_root_for_appdata(dir.path());
+        std::fs::create_dir_all(&attachments_root).expect("create attachments root");
+
+        let runtime = Runtime::new().expect("create runtime");
+        let pool = runtime.block_on(async {
+            let pool = SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect("sqlite::memory:")
+                .await
+                .expect("connect sqlite");
+            sqlx::query(
+                "CREATE TABLE bills (id TEXT PRIMARY KEY, household_id TEXT NOT NULL, category TEXT NOT NULL, relative_path TEXT, deleted_at INTEGER)",
+            )
+            .execute(&pool)
+            .await
+            .expect("create bills table");
+            sqlx::query(
+                "INSERT INTO bills (id, household_id, category, relative_path, deleted_at) VALUES ('bill1', 'hh1', 'bills', NULL, NULL)",
+            )
+            .execute(&pool)
+            .await
+            .expect("insert bill");
+            pool
+        });
+        drop(runtime);
+
+        let state = build_app_state(
+            pool.clone(),
+            &attachments_root,
+            String::from("hh2"),
+            attachments_root.join("ipc-list.sqlite3"),
+        );
+
+        let app = mock_builder()
+            .manage(state)
+            .invoke_handler(tauri::generate_handler![super::attachments_list_for_entity])
+            .build(mock_context(noop_assets()))
+            .expect("build tauri app");
+
+        let window = WebviewWindowBuilder::new(&app, "main", Default::default())
+            .build()
+            .expect("create window");
+
+        let payload = serde_json::json!({
+            "table": "bills",
+            "id": "bill1"
+        });
+
+        let response = get_ipc_response(
+            &window,
+            invoke_request_with_payload("attachments_list_for_entity", payload),
+        );
+        let err = response.expect_err("expected household guard error");
+        let obj = err.as_object().expect("error payload is object");
+        assert_eq!(
+            obj.get("code").and_then(|v| v.as_str()),
+            Some(crate::vault::ERR_INVALID_HOUSEHOLD)
+        );
+    }
+
+    #[test]
+    fn row_reassign_refreshes_household_stats() {
+        let dir = tempdir().expect("tempdir");
+        let attachments_root = crate::vault::paths::attachments_root_for_appdata(dir.path());
+        std::fs::create_dir_all(&attachments_root).expect("create attachments root");
+
+        let runtime = Runtime::new().expect("create runtime");
+        let pool = runtime.block_on(async {
+            let pool = SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect("sqlite::memory:")
+                .await
+                .expect("connect sqlite");
+            crate::migrate::apply_migrations(&pool)
+                .await
+                .expect("apply migrations");
+            for id in ["hh1", "hh2"] {
+                sqlx::query(
+                    "INSERT INTO household (id, name, created_at, updated_at) VALUES (?1, ?1, 0, 0)",
+                )
+                .bind(id)
+                .execute(&pool)
+                .await
+                .expect("insert household");
+            }
+            sqlx::query(
+                "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category) VALUES ('bill1', 100, 0, 'hh1', 0, 0, 0, 'attachments', 'water.pdf', 'bills')",
+            )
+            .execute(&pool)
+            .await
+            .expect("insert bill");
+            pool
+        });
+
+        let state = build_app_state(
+            pool.clone(),
+            &attachments_root,
+            String::from("hh1"),
+            attachments_root.join("ipc-reassign.sqlite3"),
+        );
+
+        let app = mock_builder()
+            .manage(state)
+            .invoke_handler(tauri::generate_handler![super::row_reassign])
+            .build(mock_context(noop_assets()))
+            .expect("build tauri app");
+
+        let window = WebviewWindowBuilder::new(&app, "main", Default::default())
+            .build()
+            .expect("create window");
+
+        let payload = serde_json::json!({
+            "table": "bills",
+            "id": "bill1",
+            "fromHousehold": "hh1",
+            "toHousehold": "hh2"
+        });
+
+        get_ipc_response(
+            &window,
+            invoke_request_with_payload("row_reassign", payload),
+        )
+        .expect("reassign bill");
+
+        let cached = runtime
+            .block_on(crate::diagnostics::cached_household_stats(&pool))
+            .expect("read household stats");
+        let bills = |household: &str| {
+            cached
+                .iter()
+                .find(|entry| entry.household_id == household)
+                .and_then(|entry| entry.counts.get("bills").copied())
+        };
+        assert_eq!(bills("hh1"), Some(0));
+        assert_eq!(bills("hh2"), Some(1));
+    }
 }
 
 #[cfg(test)]
@@ -6984,6 +9889,7 @@ mod write_guard_tests {
                 crate::vault_migration::VaultMigrationManager::new(&attachments_root).unwrap(),
             ),
             maintenance: Arc::new(AtomicBool::new(false)),
+            maintenance_reason: Arc::new(Mutex::new(None)),
             files_indexer,
             pet_metrics: Arc::new(PetAttachmentMetrics::new()),
         };
@@ -2,9 +2,10 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use std::fs;
+use std::io::{IsTerminal, Write};
 use std::path::{Path, PathBuf};
 use std::process;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
@@ -17,17 +18,22 @@ use sqlx::SqlitePool;
 use arklowdun_lib::db::{
     backup, hard_repair,
     health::{DbHealthReport, DbHealthStatus},
+    manifest::SchemaInfo,
     repair::{
         self, DbRepairEvent, DbRepairOptions, DbRepairStep, DbRepairStepState, DbRepairSummary,
+        REPAIR_STEPS,
     },
+    upkeep,
 };
 use arklowdun_lib::diagnostics::{self, HOUSEHOLD_STATS_ALIASES};
 use arklowdun_lib::import::{
-    build_plan, execute_plan, validate_bundle, write_import_report, ExecutionContext,
-    ExecutionReport, ImportBundle, ImportMode, ImportPlan, PlanContext, ValidationContext,
-    ValidationReport, MIN_SUPPORTED_APP_VERSION,
+    build_plan, execute_plan, plan_digest, validate_bundle, write_import_report,
+    write_import_report_to, ExecutionContext, ExecutionReport, ImportBundle, ImportCheckpoint,
+    ImportMode, ImportPlan, PlanContext, ValidationContext, ValidationReport,
+    MIN_SUPPORTED_APP_VERSION,
 };
 use arklowdun_lib::ipc::guard::{DB_UNHEALTHY_CLI_HINT, DB_UNHEALTHY_CODE, DB_UNHEALTHY_EXIT_CODE};
+use arklowdun_lib::ops::progress::{OperationProgress, ProgressObserver, ProgressStage};
 use arklowdun_lib::vault::{paths, Vault};
 use arklowdun_lib::vault_migration::{
     run_vault_migration_headless, HeadlessLegacyRoots, MigrationMode, MigrationProgress,
@@ -61,23 +67,53 @@ enum DbCommand {
         /// Emit the raw JSON health report instead of the table view.
         #[arg(long)]
         json: bool,
+        /// Print every offender and each check's full multi-line details.
+        #[arg(long, conflicts_with = "json")]
+        verbose: bool,
     },
     /// Run VACUUM to compact the database when it is healthy.
     Vacuum,
+    /// Run ANALYZE to refresh query planner statistics when the database is healthy.
+    Analyze,
     /// Create a consistent snapshot of the database with manifest metadata.
     Backup {
         /// Emit a machine-readable JSON object with the backup entry details.
         #[arg(long)]
         json: bool,
+        /// Store only pages changed since the latest full backup.
+        #[arg(long)]
+        differential: bool,
+    },
+    /// Rebuild the database held by a backup directory at a new path.
+    RestoreBackup {
+        /// Backup directory to restore, full or differential.
+        #[arg(long = "from", value_name = "PATH")]
+        from: PathBuf,
+        /// Where to write the restored database; must not exist yet.
+        #[arg(long, value_name = "PATH")]
+        out: PathBuf,
     },
     /// Export data, attachments, and a manifest for verification.
     Export {
         /// Parent directory to create export-YYYYMMDD-HHMMSS under.
         #[arg(long, value_name = "PATH")]
         out: std::path::PathBuf,
+        /// Gzip data files into *.jsonl.gz.
+        #[arg(long)]
+        compress: bool,
+        /// Include the deletions audit log under audit/.
+        #[arg(long)]
+        include_deletions_audit: bool,
+        /// Show table and attachment progress while exporting.
+        #[arg(long)]
+        progress: bool,
     },
     /// Attempt to repair a corrupted database by rebuilding and swapping files.
-    Repair,
+    Repair {
+        /// Show step progress instead of the per-step status table.
+        #[arg(long)]
+        progress: bool,
+    },
     /// Attempt a last-resort hard repair that rebuilds the schema and imports tables.
     HardRepair,
     /// Import data from an export bundle with validation and dry-run planning.
@@ -91,7 +127,25 @@ enum DbCommand {
         /// Run validation and planning without executing the plan.
         #[arg(long)]
         dry_run: bool,
+        /// Accept bundles exported by a newer app version.
+        #[arg(long)]
+        allow_newer: bool,
+        /// Continue an import that stopped part-way, reusing its mode.
+        #[arg(long, conflicts_with = "dry_run")]
+        resume: bool,
+        /// Write the import report to this file instead of the reports directory.
+        #[arg(long, value_name = "PATH")]
+        report: Option<PathBuf>,
+        /// Only execute when the plan matches the digest printed by a dry run.
+        #[arg(long, value_name = "HASH")]
+        expected_digest: Option<String>,
+        /// Show row and attachment progress while the plan executes.
+        #[arg(long)]
+        progress: bool,
     },
+    /// Check that export, re-import into a scratch db and re-export match.
+    #[command(hide = true)]
+    RoundtripCheck,
 }
 
 #[derive(Debug, Subcommand)]
@@ -238,7 +292,7 @@ fn handle_resume_migration() -> Result<i32> {
 
 fn handle_db_command(command: DbCommand) -> Result<i32> {
     match command {
-        DbCommand::Status { json } => {
+        DbCommand::Status { json, verbose } => {
             let db_path = default_db_path().context("determine database path")?;
             if let Some(parent) = db_path.parent() {
                 std::fs::create_dir_all(parent).with_context(|| {
@@ -246,19 +300,22 @@ fn handle_db_command(command: DbCommand) -> Result<i32> {
                 })?;
             }
 
-            let report = tauri::async_runtime::block_on(async {
+            let (report, schema) = tauri::async_runtime::block_on(async {
                 let pool = open_health_pool(&db_path).await?;
                 let report = arklowdun_lib::db::health::run_health_checks(&pool, &db_path)
                     .await
                     .context("run database health checks")?;
+                let schema = arklowdun_lib::db::manifest::schema_info(&pool)
+                    .await
+                    .context("read applied migrations")?;
                 pool.close().await;
-                Result::<DbHealthReport>::Ok(report)
+                Result::<(DbHealthReport, SchemaInfo)>::Ok((report, schema))
             })?;
 
             if json {
                 print_report_json(&report)?;
             } else {
-                print_report_table(&report);
+                print_report_table(&report, &schema, verbose);
             }
 
             Ok(match report.status {
@@ -267,15 +324,39 @@ fn handle_db_command(command: DbCommand) -> Result<i32> {
             })
         }
         DbCommand::Vacuum => handle_db_vacuum(),
-        DbCommand::Backup { json } => handle_db_backup(json),
-        DbCommand::Export { out } => handle_db_export(out),
-        DbCommand::Repair => handle_db_repair(),
+        DbCommand::Analyze => handle_db_analyze(),
+        DbCommand::Backup { json, differential } => handle_db_backup(json, differential),
+        DbCommand::RestoreBackup { from, out } => handle_db_restore_backup(&from, &out),
+        DbCommand::Export {
+            out,
+            compress,
+            include_deletions_audit,
+            progress,
+        } => handle_db_export(out, compress, include_deletions_audit, progress),
+        DbCommand::Repair { progress } => handle_db_repair(progress),
         DbCommand::HardRepair => handle_db_hard_repair(),
         DbCommand::Import {
             input,
             mode,
             dry_run,
-        } => handle_db_import(input, mode, dry_run),
+            allow_newer,
+            resume,
+            report,
+            expected_digest,
+            progress,
+        } => handle_db_import(
+            input,
+            CliImportOptions {
+                mode: mode.into(),
+                dry_run,
+                allow_newer,
+                resume,
+                report,
+                expected_digest,
+                progress,
+            },
+        ),
+        DbCommand::RoundtripCheck => handle_db_roundtrip_check(),
     }
 }
 
@@ -401,7 +482,7 @@ fn handle_db_vacuum() -> Result<i32> {
     }
 }
 
-fn handle_db_backup(emit_json: bool) -> Result<i32> {
+fn handle_db_analyze() -> Result<i32> {
     let db_path = default_db_path().context("determine database path")?;
     if let Some(parent) = db_path.parent() {
         std::fs::create_dir_all(parent)
@@ -410,10 +491,40 @@ fn handle_db_backup(emit_json: bool) -> Result<i32> {
 
     match guard_cli_db_mutation(&db_path)? {
         Ok(pool) => {
-            let entry = tauri::async_runtime::block_on(async {
-                let result = backup::create_backup(&pool, &db_path)
+            let elapsed = tauri::async_runtime::block_on(async move {
+                let result = upkeep::analyze_database(&pool)
                     .await
-                    .context("create database backup");
+                    .map_err(|err| anyhow::anyhow!("{}: {}", err.code(), err.message()))
+                    .context("analyze database");
+                pool.close().await;
+                result
+            })?;
+            println!(
+                "Database analyze completed in {:.2} seconds.",
+                elapsed.as_secs_f64()
+            );
+            Ok(0)
+        }
+        Err(code) => Ok(code),
+    }
+}
+
+fn handle_db_backup(emit_json: bool, differential: bool) -> Result<i32> {
+    let db_path = default_db_path().context("determine database path")?;
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("create database parent directory {}", parent.display()))?;
+    }
+
+    match guard_cli_db_mutation(&db_path)? {
+        Ok(pool) => {
+            let entry = tauri::async_runtime::block_on(async {
+                let result = if differential {
+                    backup::create_differential_backup(&pool, &db_path).await
+                } else {
+                    backup::create_backup(&pool, &db_path).await
+                }
+                .context("create database backup");
                 pool.close().await;
                 result
             })?;
@@ -438,8 +549,20 @@ fn handle_db_backup(emit_json: bool) -> Result<i32> {
     }
 }
 
-fn handle_db_export(out_parent: std::path::PathBuf) -> Result<i32> {
-    use arklowdun_lib::export::{create_export, ExportOptions};
+fn handle_db_restore_backup(from: &Path, out: &Path) -> Result<i32> {
+    let restored = tauri::async_runtime::block_on(backup::restore_backup_to(from, out))
+        .context("restore database backup")?;
+    println!("Backup restored to {}", restored.display());
+    Ok(0)
+}
+
+fn handle_db_export(
+    out_parent: std::path::PathBuf,
+    compress: bool,
+    include_deletions_audit: bool,
+    progress: bool,
+) -> Result<i32> {
+    use arklowdun_lib::export::{create_export_with_progress, ExportOptions};
 
     let db_path = default_db_path().context("determine database path")?;
     if let Some(parent) = db_path.parent() {
@@ -461,7 +584,14 @@ fn handle_db_export(out_parent: std::path::PathBuf) -> Result<i32> {
             let entry = tauri::async_runtime::block_on({
                 let vault = vault.clone();
                 async move {
-                    let res = create_export(&pool, vault, ExportOptions { out_parent })
+                    let opts = ExportOptions {
+                        out_parent,
+                        compress,
+                        include_deletions_audit,
+                        tables: Default::default(),
+                    };
+                    let observer = progress.then(|| CliProgress::observer("Exporting"));
+                    let res = create_export_with_progress(&pool, vault, opts, observer)
                         .await
                         .context("create export package");
                     pool.close().await;
@@ -478,7 +608,38 @@ fn handle_db_export(out_parent: std::path::PathBuf) -> Result<i32> {
     }
 }
 
-fn handle_db_repair() -> Result<i32> {
+fn handle_db_roundtrip_check() -> Result<i32> {
+    use arklowdun_lib::export::roundtrip::export_roundtrip_mismatches;
+
+    let db_path = default_db_path().context("determine database path")?;
+    let attachments_root = default_attachments_path().context("resolve attachments directory")?;
+
+    match guard_cli_db_mutation(&db_path)? {
+        Ok(pool) => {
+            let vault = Arc::new(Vault::new(&attachments_root));
+            let mismatches = tauri::async_runtime::block_on(async move {
+                let res = export_roundtrip_mismatches(&pool, vault).await;
+                pool.close().await;
+                res.map_err(|err| anyhow::anyhow!("{}: {}", err.code(), err.message()))
+            })?;
+            if mismatches.is_empty() {
+                println!("Export round-trip is stable.");
+                return Ok(0);
+            }
+            eprintln!("Export round-trip is not stable:");
+            for mismatch in &mismatches {
+                eprintln!(
+                    "  {}: {} != {}",
+                    mismatch.section, mismatch.first, mismatch.second
+                );
+            }
+            Ok(1)
+        }
+        Err(code) => Ok(code),
+    }
+}
+
+fn handle_db_repair(progress: bool) -> Result<i32> {
     let db_path = default_db_path().context("determine database path")?;
     if let Some(parent) = db_path.parent() {
         std::fs::create_dir_all(parent)
@@ -487,7 +648,9 @@ fn handle_db_repair() -> Result<i32> {
 
     let pool = tauri::async_runtime::block_on(open_health_pool(&db_path))?;
 
-    let printer: Arc<dyn Fn(DbRepairEvent) + Send + Sync> = Arc::new(|event| match event {
+    let renderer = CliProgress::new();
+    let finished = std::sync::atomic::AtomicU64::new(0);
+    let printer: Arc<dyn Fn(DbRepairEvent) + Send + Sync> = Arc::new(move |event| match event {
         DbRepairEvent::Step {
             step,
             status,
@@ -495,7 +658,23 @@ fn handle_db_repair() -> Result<i32> {
         } => {
             let label = cli_step_label(&step);
             let status_label = cli_status_label(&status);
-            if let Some(msg) = message {
+            if progress {
+                let done = if matches!(
+                    status,
+                    DbRepairStepState::Pending | DbRepairStepState::Running
+                ) {
+                    finished.load(std::sync::atomic::Ordering::SeqCst)
+                } else {
+                    finished.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
+                };
+                renderer.render(
+                    &format!("Repair {} {status_label}", label.to_lowercase()),
+                    done,
+                    REPAIR_STEPS.len() as u64,
+                    "steps",
+                    None,
+                );
+            } else if let Some(msg) = message {
                 println!("{label:<12} {status_label:<9} {msg}");
             } else {
                 println!("{label:<12} {status_label:<9}");
@@ -635,7 +814,7 @@ fn handle_db_hard_repair() -> Result<i32> {
     }
 }
 
-fn handle_db_import(input: PathBuf, mode: ImportModeArg, dry_run: bool) -> Result<i32> {
+fn handle_db_import(input: PathBuf, options: CliImportOptions) -> Result<i32> {
     let db_path = default_db_path().context("determine database path")?;
     if let Some(parent) = db_path.parent() {
         fs::create_dir_all(parent)
@@ -668,8 +847,7 @@ fn handle_db_import(input: PathBuf, mode: ImportModeArg, dry_run: bool) -> Resul
                 vault,
                 target_root,
                 reports_dir,
-                mode.into(),
-                dry_run,
+                options,
             )) {
                 Ok(outcome) => {
                     print_import_outcome(&outcome)?;
@@ -689,6 +867,16 @@ fn handle_db_import(input: PathBuf, mode: ImportModeArg, dry_run: bool) -> Resul
     }
 }
 
+struct CliImportOptions {
+    mode: ImportMode,
+    dry_run: bool,
+    allow_newer: bool,
+    resume: bool,
+    report: Option<PathBuf>,
+    expected_digest: Option<String>,
+    progress: bool,
+}
+
 enum ImportCliError {
     Validation(anyhow::Error),
     Execution(anyhow::Error),
@@ -697,6 +885,7 @@ enum ImportCliError {
 struct ImportOutcome {
     validation: ValidationReport,
     plan: ImportPlan,
+    plan_digest: String,
     result: ImportResult,
 }
 
@@ -714,10 +903,41 @@ async fn run_cli_import(
     vault: Arc<Vault>,
     target_root: PathBuf,
     reports_dir: PathBuf,
-    mode: ImportMode,
-    dry_run: bool,
+    options: CliImportOptions,
 ) -> Result<ImportOutcome, ImportCliError> {
     let result = async {
+        let mut mode = options.mode;
+        let mut resumed = None;
+        if options.resume {
+            match ImportCheckpoint::load(&reports_dir).map_err(ImportCliError::Validation)? {
+                Some(checkpoint) => {
+                    if checkpoint.bundle_path != bundle_path.display().to_string() {
+                        return Err(ImportCliError::Validation(anyhow::anyhow!(
+                            "The interrupted import was for {}; pass that bundle to resume it.",
+                            checkpoint.bundle_path
+                        )));
+                    }
+                    // Rows that already landed change a fresh plan, so the
+                    // previewed digest is checked against the interrupted one.
+                    if let Some(expected) = options.expected_digest.as_deref() {
+                        if expected != checkpoint.plan_digest {
+                            return Err(ImportCliError::Validation(anyhow::anyhow!(
+                                "The interrupted import ran a different plan. Run a new dry-run and import without --resume."
+                            )));
+                        }
+                    }
+                    mode = checkpoint.mode;
+                    println!(
+                        "Resuming import started at {} in {} mode.",
+                        checkpoint.started_at,
+                        import_mode_label(mode)
+                    );
+                    resumed = Some(checkpoint);
+                }
+                None => println!("No import checkpoint found; starting a fresh import."),
+            }
+        }
+
         let bundle = ImportBundle::load(&bundle_path)
             .map_err(anyhow::Error::new)
             .context("load import bundle")
@@ -731,6 +951,8 @@ async fn run_cli_import(
             pool: &pool,
             target_root: target_root.as_path(),
             minimum_app_version: &minimum_version,
+            current_app_version: arklowdun_lib::import::validator::current_app_version(),
+            allow_newer: options.allow_newer,
             available_space_override: None,
         };
         let validation = validate_bundle(&bundle, &validation_ctx)
@@ -743,34 +965,89 @@ async fn run_cli_import(
             pool: &pool,
             vault: vault.clone(),
         };
-        let plan = build_plan(&bundle, &plan_ctx, mode)
+        let mut plan = build_plan(&bundle, &plan_ctx, mode)
             .await
             .map_err(anyhow::Error::new)
             .context("build import plan")
             .map_err(ImportCliError::Validation)?;
+        let plan_digest = plan_digest(&plan).map_err(ImportCliError::Validation)?;
 
-        if dry_run {
+        if options.dry_run {
             return Ok(ImportOutcome {
                 validation,
                 plan,
+                plan_digest,
                 result: ImportResult::DryRun,
             });
         }
+        if let (None, Some(expected)) = (&resumed, options.expected_digest.as_deref()) {
+            if expected != plan_digest {
+                return Err(ImportCliError::Validation(anyhow::anyhow!(
+                    "Import plan changed after preview. Run a new dry-run before importing."
+                )));
+            }
+        }
 
-        let exec_ctx = ExecutionContext::new(&pool, vault.clone());
+        let checkpoint = match resumed {
+            Some(checkpoint) if mode == ImportMode::Merge => {
+                plan.tables
+                    .retain(|table, _| !checkpoint.completed_tables.contains(table));
+                if !checkpoint.completed_tables.is_empty() {
+                    println!(
+                        "Skipping {} table(s) completed before the interruption.",
+                        checkpoint.completed_tables.len()
+                    );
+                }
+                checkpoint
+            }
+            // A replace clears every table first, so it always starts over.
+            Some(checkpoint) => ImportCheckpoint {
+                completed_tables: Vec::new(),
+                ..checkpoint
+            },
+            None => ImportCheckpoint::new(&bundle_path, mode, plan_digest.clone()),
+        };
+        checkpoint
+            .save(&reports_dir)
+            .map_err(ImportCliError::Execution)?;
+        let checkpoint = Arc::new(Mutex::new(checkpoint));
+        let mut exec_ctx = ExecutionContext::new(&pool, vault.clone()).with_table_complete({
+            let checkpoint = checkpoint.clone();
+            let reports_dir = reports_dir.clone();
+            Arc::new(move |table: &str| {
+                let Ok(mut checkpoint) = checkpoint.lock() else {
+                    return;
+                };
+                checkpoint.completed_tables.push(table.to_string());
+                if let Err(err) = checkpoint.save(&reports_dir) {
+                    eprintln!("Warning: could not update the import checkpoint: {err:#}");
+                }
+            })
+        });
+        if options.progress {
+            exec_ctx = exec_ctx.with_progress(CliProgress::observer("Importing"));
+        }
         let execution = execute_plan(&bundle, &plan, &exec_ctx)
             .await
             .map_err(anyhow::Error::new)
             .context("execute import plan")
             .map_err(ImportCliError::Execution)?;
 
-        let report_path =
-            write_import_report(&reports_dir, &bundle_path, &validation, &plan, &execution)
-                .map_err(ImportCliError::Execution)?;
+        let report_path = match options.report {
+            Some(path) => {
+                write_import_report_to(&path, &bundle_path, &validation, &plan, &execution)
+                    .map_err(ImportCliError::Execution)?;
+                path
+            }
+            None => write_import_report(&reports_dir, &bundle_path, &validation, &plan, &execution)
+                .map_err(ImportCliError::Execution)?,
+        };
+        ImportCheckpoint::clear(&reports_dir).map_err(ImportCliError::Execution)?;
 
         Ok(ImportOutcome {
             validation,
             plan,
+            plan_digest,
             result: ImportResult::Executed {
                 execution,
                 report_path,
@@ -792,6 +1069,7 @@ fn print_import_outcome(outcome: &ImportOutcome) -> Result<()> {
                 outcome.validation.data_files_verified,
                 outcome.validation.attachments_verified
             );
+            println!("Plan digest: {}", outcome.plan_digest);
             let plan_json =
                 serde_json::to_string_pretty(&outcome.plan).context("serialize dry-run plan")?;
             println!("{plan_json}");
@@ -802,10 +1080,7 @@ fn print_import_outcome(outcome: &ImportOutcome) -> Result<()> {
         } => {
             println!(
                 "Import complete in {} mode.",
-                match execution.mode {
-                    ImportMode::Merge => "merge",
-                    ImportMode::Replace => "replace",
-                }
+                import_mode_label(execution.mode)
             );
 
             let (mut adds, mut updates, mut skips) = (0_u64, 0_u64, 0_u64);
@@ -830,6 +1105,74 @@ fn print_import_outcome(outcome: &ImportOutcome) -> Result<()> {
     Ok(())
 }
 
+/// Renders `--progress` output: one redrawn bar line on a terminal, plain
+/// lines otherwise so piped output and logs stay readable.
+#[derive(Debug, Clone, Copy)]
+struct CliProgress {
+    tty: bool,
+}
+
+impl CliProgress {
+    const BAR_WIDTH: u64 = 24;
+
+    fn new() -> Self {
+        Self {
+            tty: std::io::stdout().is_terminal(),
+        }
+    }
+
+    /// Observer for import/export progress, labelled `"<verb> <table>"`.
+    fn observer(verb: &'static str) -> ProgressObserver {
+        let renderer = Self::new();
+        Arc::new(move |progress: OperationProgress| {
+            let (label, unit, bytes) = match &progress.stage {
+                ProgressStage::Table(table) => (format!("{verb} {table}"), "rows", None),
+                ProgressStage::Attachments => {
+                    (format!("{verb} attachments"), "files", Some(progress.bytes))
+                }
+            };
+            renderer.render(&label, progress.done, progress.total, unit, bytes);
+        })
+    }
+
+    fn render(&self, label: &str, done: u64, total: u64, unit: &str, bytes: Option<u64>) {
+        let percent = if total == 0 {
+            100
+        } else {
+            done.min(total) * 100 / total
+        };
+        let bytes = bytes
+            .map(|bytes| format!(", {bytes} bytes"))
+            .unwrap_or_default();
+        if self.tty {
+            let filled = (percent * Self::BAR_WIDTH / 100) as usize;
+            let bar = format!(
+                "{}{}",
+                "#".repeat(filled),
+                "-".repeat(Self::BAR_WIDTH as usize - filled)
+            );
+            let mut stdout = std::io::stdout().lock();
+            let _ = write!(
+                stdout,
+                "\r\x1b[2K{label} [{bar}] {percent:>3}% {done}/{total} {unit}{bytes}"
+            );
+            if done >= total {
+                let _ = writeln!(stdout);
+            }
+            let _ = stdout.flush();
+        } else {
+            println!("{label}: {done}/{total} {unit} ({percent}%){bytes}");
+        }
+    }
+}
+
+fn import_mode_label(mode: ImportMode) -> &'static str {
+    match mode {
+        ImportMode::Merge => "merge",
+        ImportMode::Replace => "replace",
+    }
+}
+
 fn cli_step_label(step: &DbRepairStep) -> &'static str {
     match step {
         DbRepairStep::Backup => "Backup",
@@ -857,10 +1200,20 @@ fn print_report_json(report: &DbHealthReport) -> Result<()> {
     Ok(())
 }
 
-fn print_report_table(report: &DbHealthReport) {
+/// Offenders shown by the default table view before eliding the remainder.
+const TABLE_OFFENDER_LIMIT: usize = 10;
+/// Column at which the `Details` field starts in the checks table.
+const TABLE_DETAILS_INDENT: usize = 44;
+
+fn print_report_table(report: &DbHealthReport, schema: &SchemaInfo, verbose: bool) {
     println!("Database health report");
     println!("Status       : {}", status_label(&report.status));
     println!("Schema hash  : {}", report.schema_hash);
+    println!(
+        "Migrations   : {} applied (latest {})",
+        schema.applied_migrations.len(),
+        schema.latest_version.as_deref().unwrap_or("none")
+    );
     println!("App version  : {}", report.app_version);
     println!("Generated at : {}", report.generated_at);
 
@@ -871,11 +1224,15 @@ fn print_report_table(report: &DbHealthReport) {
     );
     for check in &report.checks {
         let passed = if check.passed { "yes" } else { "no" };
-        let details = check
-            .details
-            .as_deref()
-            .map(|value| value.replace('\n', " "))
-            .unwrap_or_else(|| "-".to_string());
+        let details = match check.details.as_deref() {
+            Some(value) if verbose => value.lines().collect::<Vec<_>>().join(&format!(
+                "\n{:width$}",
+                "",
+                width = TABLE_DETAILS_INDENT
+            )),
+            Some(value) => value.replace('\n', " "),
+            None => "-".to_string(),
+        };
         println!(
             "{:<20} {:<7} {:>13}  {}",
             check.name, passed, check.duration_ms, details
@@ -887,13 +1244,22 @@ fn print_report_table(report: &DbHealthReport) {
     } else {
         println!("\nOffenders:");
         println!("{:<20} {:>10}  Message", "Table", "RowID");
-        for offender in &report.offenders {
-            println!(
-                "{:<20} {:>10}  {}",
-                offender.table,
-                offender.rowid,
+        let shown = if verbose {
+            report.offenders.len()
+        } else {
+            report.offenders.len().min(TABLE_OFFENDER_LIMIT)
+        };
+        for offender in &report.offenders[..shown] {
+            let message = if verbose {
+                offender.message.clone()
+            } else {
                 offender.message.replace('\n', " ")
-            );
+            };
+            println!("{:<20} {:>10}  {}", offender.table, offender.rowid, message);
+        }
+        let hidden = report.offenders.len() - shown;
+        if hidden > 0 {
+            println!("... {hidden} more offender(s) not shown; rerun with --verbose to list all.");
         }
     }
 }
@@ -2,10 +2,12 @@ use anyhow::{anyhow, bail};
 use include_dir::{include_dir, Dir};
 use once_cell::sync::OnceCell;
 use regex::Regex;
+use serde::Serialize;
 use sqlx::sqlite::SqliteConnection;
 use sqlx::{Executor, Row, SqlitePool};
 use std::collections::HashSet;
 use std::time::Instant;
+use ts_rs::TS;
 
 use crate::time::now_ms;
 use tracing::{debug, error, info, warn};
@@ -595,6 +597,97 @@ pub async fn apply_migrations(pool: &SqlitePool) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// A migration from the embedded set that has not been recorded in
+/// `schema_migrations` yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+#[serde(rename_all = "camelCase")]
+pub struct PendingMigration {
+    pub version: String,
+    /// True when the migration drops or rewrites existing data.
+    pub destructive: bool,
+}
+
+const DESTRUCTIVE_HEADER: &str = "-- destructive:";
+
+fn destructive_stmt_regex() -> anyhow::Result<&'static Regex> {
+    static DESTRUCTIVE_RE: OnceCell<Regex> = OnceCell::new();
+    DESTRUCTIVE_RE
+        .get_or_try_init(|| {
+            Regex::new(
+                r"(?i)^\s*(?:DROP\s+TABLE|DELETE\s+FROM|ALTER\s+TABLE\s+[^\s]+\s+DROP\s+COLUMN)\b",
+            )
+        })
+        .map_err(|err| anyhow!("invalid destructive statement regex: {err}"))
+}
+
+/// Classify a migration as destructive. An explicit `-- destructive: yes|no`
+/// header line wins; otherwise any `DROP TABLE`, `DELETE FROM` or
+/// `DROP COLUMN` statement marks the migration as destructive.
+fn is_destructive(sql: &str) -> anyhow::Result<bool> {
+    for line in sql.lines() {
+        let trimmed = line.trim();
+        if trimmed.len() >= DESTRUCTIVE_HEADER.len()
+            && trimmed[..DESTRUCTIVE_HEADER.len()].eq_ignore_ascii_case(DESTRUCTIVE_HEADER)
+        {
+            let value = trimmed[DESTRUCTIVE_HEADER.len()..].trim();
+            return match value.to_ascii_lowercase().as_str() {
+                "yes" | "true" => Ok(true),
+                "no" | "false" => Ok(false),
+                other => bail!("invalid destructive header value: {other}"),
+            };
+        }
+    }
+
+    let cleaned = sql
+        .lines()
+        .filter(|line| {
+            let t = line.trim_start();
+            !(t.is_empty() || t.starts_with("--"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let re = destructive_stmt_regex()?;
+    Ok(split_statements(&cleaned)
+        .iter()
+        .any(|stmt| re.is_match(stmt)))
+}
+
+/// List the embedded migrations that `apply_migrations` would run against
+/// this database, in application order. Nothing is written.
+pub async fn pending_migrations(pool: &SqlitePool) -> anyhow::Result<Vec<PendingMigration>> {
+    let has_table = sqlx::query_scalar::<_, i64>(
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='schema_migrations'",
+    )
+    .fetch_optional(pool)
+    .await?
+    .is_some();
+
+    let applied: HashSet<String> = if has_table {
+        sqlx::query_scalar::<_, String>("SELECT version FROM schema_migrations")
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .collect()
+    } else {
+        HashSet::new()
+    };
+
+    let mut pending = Vec::new();
+    for entry in load_migrations()? {
+        if applied.contains(&entry.name) {
+            continue;
+        }
+        let destructive =
+            is_destructive(&entry.sql).map_err(|err| anyhow!("{}: {err}", entry.name))?;
+        pending.push(PendingMigration {
+            version: entry.name,
+            destructive,
+        });
+    }
+    Ok(pending)
+}
+
 #[allow(dead_code)]
 // TXN: domain=OUT OF SCOPE tables=schema_migrations
 pub async fn revert_last_migration(pool: &SqlitePool) -> anyhow::Result<()> {
@@ -663,3 +756,31 @@ pub async fn revert_last_migration(pool: &SqlitePool) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drop_and_delete_statements_are_destructive() {
+        assert!(is_destructive("DROP TABLE legacy;").unwrap());
+        assert!(is_destructive("delete from notes WHERE deleted_at IS NOT NULL;").unwrap());
+        assert!(is_destructive("ALTER TABLE pets DROP COLUMN image_path;").unwrap());
+    }
+
+    #[test]
+    fn additive_statements_are_not_destructive() {
+        let sql = "-- DROP TABLE in a comment is ignored\n\
+                   CREATE TABLE t (id TEXT, parent TEXT REFERENCES p(id) ON DELETE CASCADE);\n\
+                   ALTER TABLE t ADD COLUMN x TEXT;\n\
+                   DROP INDEX IF EXISTS t_idx;";
+        assert!(!is_destructive(sql).unwrap());
+    }
+
+    #[test]
+    fn header_overrides_heuristic() {
+        assert!(is_destructive("-- destructive: yes\nUPDATE t SET x = NULL;").unwrap());
+        assert!(!is_destructive("-- Destructive: no\nDROP TABLE scratch;").unwrap());
+        assert!(is_destructive("-- destructive: maybe\n").is_err());
+    }
+}
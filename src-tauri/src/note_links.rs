@@ -6,14 +6,21 @@ use sqlx::{
     encode::IsNull,
     error::BoxDynError,
     sqlite::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef},
-    Executor, Row, Sqlite, SqlitePool, Transaction,
+    Executor, Row, Sqlite, SqliteConnection, SqlitePool, Transaction,
 };
 use tauri::State;
 use ts_rs::TS;
 
 use crate::{
-    id::new_uuid_v7, ipc::guard, notes::Note, repo, state::AppState, time::now_ms,
-    util::dispatch_async_app_result, AppError, AppResult,
+    deletions_audit::{record_deletion, OPERATION_SOFT_DELETE},
+    id::new_uuid_v7,
+    ipc::guard,
+    notes::Note,
+    repo,
+    state::AppState,
+    time::now_ms,
+    util::dispatch_async_app_result,
+    AppError, AppResult,
 };
 
 const DEFAULT_PAGE_SIZE: i64 = 20;
@@ -107,6 +114,25 @@ pub struct NoteLinkList {
     pub items: Vec<NoteLinkListItem>,
 }
 
+/// One entity to link a newly created note to. Accepts `type`/`id` as
+/// shorthand for `entity_type`/`entity_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct NoteLinkTarget {
+    #[serde(alias = "type")]
+    pub entity_type: NoteLinkEntityType,
+    #[serde(alias = "id")]
+    pub entity_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct NoteWithLinksCreated {
+    pub note_id: String,
+    /// Link ids in the same order as the requested targets.
+    pub link_ids: Vec<String>,
+}
+
 fn decode_cursor(cursor: Option<String>) -> AppResult<Option<(i64, String)>> {
     if let Some(cursor) = cursor {
         if cursor.trim().is_empty() {
@@ -449,7 +475,7 @@ pub async fn delete_link(pool: &SqlitePool, household_id: &str, link_id: &str) -
                 entity_type,
                 entity_id
            FROM note_links
-          WHERE id = ?1 AND household_id = ?2",
+          WHERE id = ?1 AND household_id = ?2 AND deleted_at IS NULL",
     )
     .bind(link_id)
     .bind(household_id)
@@ -501,6 +527,102 @@ pub async fn delete_link(pool: &SqlitePool, household_id: &str, link_id: &str) -
     Ok(())
 }
 
+/// Soft-delete every active link pointing at an entity that has itself been
+/// soft-deleted, so its notes no longer reference it. Each hidden link is
+/// recorded in `deletions_audit`. Runs on the caller's connection so it can
+/// share the entity's own delete transaction. Returns how many links were
+/// hidden.
+pub async fn soft_delete_links_for_entity(
+    conn: &mut SqliteConnection,
+    household_id: &str,
+    entity_type: NoteLinkEntityType,
+    entity_id: &str,
+) -> AppResult<u64> {
+    let context = |err: sqlx::Error| {
+        AppError::from(err)
+            .with_context("operation", "note_links_soft_delete_for_entity")
+            .with_context("household_id", household_id.to_string())
+            .with_context("entity_type", entity_type.to_string())
+            .with_context("entity_id", entity_id.to_string())
+    };
+    let now = now_ms();
+    let ids: Vec<String> = sqlx::query_scalar(
+        "SELECT id FROM note_links
+          WHERE household_id = ?1
+            AND entity_type = ?2
+            AND entity_id = ?3
+            AND deleted_at IS NULL",
+    )
+    .bind(household_id)
+    .bind(entity_type.as_str())
+    .bind(entity_id)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(context)?;
+    let result = sqlx::query(
+        "UPDATE note_links
+            SET deleted_at = ?1, updated_at = ?1
+          WHERE household_id = ?2
+            AND entity_type = ?3
+            AND entity_id = ?4
+            AND deleted_at IS NULL",
+    )
+    .bind(now)
+    .bind(household_id)
+    .bind(entity_type.as_str())
+    .bind(entity_id)
+    .execute(&mut *conn)
+    .await
+    .map_err(context)?;
+    for id in &ids {
+        record_deletion(
+            &mut *conn,
+            "note_links",
+            household_id,
+            id,
+            OPERATION_SOFT_DELETE,
+            now,
+        )
+        .await
+        .map_err(context)?;
+    }
+    Ok(result.rows_affected())
+}
+
+/// Bring back links hidden by [`soft_delete_links_for_entity`] once the
+/// entity is restored, on the caller's connection. Returns how many links
+/// were restored.
+pub async fn restore_links_for_entity(
+    conn: &mut SqliteConnection,
+    household_id: &str,
+    entity_type: NoteLinkEntityType,
+    entity_id: &str,
+) -> AppResult<u64> {
+    let now = now_ms();
+    let result = sqlx::query(
+        "UPDATE note_links
+            SET deleted_at = NULL, updated_at = ?1
+          WHERE household_id = ?2
+            AND entity_type = ?3
+            AND entity_id = ?4
+            AND deleted_at IS NOT NULL",
+    )
+    .bind(now)
+    .bind(household_id)
+    .bind(entity_type.as_str())
+    .bind(entity_id)
+    .execute(&mut *conn)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "note_links_restore_for_entity")
+            .with_context("household_id", household_id.to_string())
+            .with_context("entity_type", entity_type.to_string())
+            .with_context("entity_id", entity_id.to_string())
+    })?;
+    Ok(result.rows_affected())
+}
+
 pub async fn unlink_note_from_entity(
     pool: &SqlitePool,
     household_id: &str,
@@ -537,7 +659,8 @@ pub async fn unlink_note_from_entity(
           WHERE household_id = ?1
             AND note_id = ?2
             AND entity_type = ?3
-            AND entity_id = ?4",
+            AND entity_id = ?4
+            AND deleted_at IS NULL",
     )
     .bind(household_id)
     .bind(note_id)
@@ -597,7 +720,8 @@ pub async fn get_link_for_note(
           WHERE household_id = ?1
             AND note_id = ?2
             AND entity_type = ?3
-            AND entity_id = ?4",
+            AND entity_id = ?4
+            AND deleted_at IS NULL",
     )
     .bind(household_id)
     .bind(note_id)
@@ -667,6 +791,62 @@ pub async fn quick_create_note_for_entity(
     Ok(note)
 }
 
+/// Create a note and link it to every target in one transaction. If any
+/// target is missing, belongs to another household or is listed twice, the
+/// note and all links are rolled back.
+pub async fn create_note_with_links(
+    pool: &SqlitePool,
+    household_id: &str,
+    category_id: &str,
+    text: &str,
+    color: Option<&str>,
+    targets: &[NoteLinkTarget],
+) -> AppResult<NoteWithLinksCreated> {
+    let mut tx = pool.begin().await.map_err(|err| {
+        AppError::from(err).with_context("operation", "notes_create_with_links_tx")
+    })?;
+
+    for target in targets {
+        ensure_entity_exists_tx(&mut tx, household_id, target.entity_type, &target.entity_id)
+            .await?;
+    }
+    let note = create_note_for_entity(&mut tx, household_id, category_id, text, color).await?;
+    let mut links = Vec::with_capacity(targets.len());
+    for target in targets {
+        let link = create_link_with_tx(
+            &mut tx,
+            household_id,
+            &note.id,
+            target.entity_type,
+            &target.entity_id,
+            None,
+        )
+        .await?;
+        links.push(link);
+    }
+    tx.commit().await.map_err(|err| {
+        AppError::from(err).with_context("operation", "notes_create_with_links_commit")
+    })?;
+
+    for link in &links {
+        tracing::debug!(
+            target = "contextual-notes",
+            action = "create_link",
+            link_id = %link.id,
+            note_id = %note.id,
+            entity_type = %link.entity_type,
+            entity_id = %link.entity_id,
+            household_id = %household_id,
+            relation = %link.relation
+        );
+    }
+
+    Ok(NoteWithLinksCreated {
+        note_id: note.id,
+        link_ids: links.into_iter().map(|link| link.id).collect(),
+    })
+}
+
 pub async fn list_notes_for_entity(
     pool: &SqlitePool,
     household_id: &str,
@@ -705,7 +885,8 @@ pub async fn list_notes_for_entity(
                 n.y,
                 n.z,
                 n.deadline,
-                n.deadline_tz
+                n.deadline_tz,
+                n.pinned
            FROM note_links nl
            JOIN notes n ON n.id = nl.note_id
            JOIN events e ON e.id = nl.entity_id
@@ -713,6 +894,7 @@ pub async fn list_notes_for_entity(
             AND nl.entity_id = ?
             AND e.household_id = ?
             AND nl.household_id = ?
+            AND nl.deleted_at IS NULL
             AND n.household_id = ?
             AND n.deleted_at IS NULL",
     );
@@ -804,7 +986,8 @@ pub async fn list_notes_for_entity(
                FROM note_links
               WHERE household_id = ?1
                 AND entity_type = ?2
-                AND entity_id = ?3",
+                AND entity_id = ?3
+                AND deleted_at IS NULL",
         )
         .bind(household_id)
         .bind(entity_type.as_str())
@@ -873,6 +1056,7 @@ pub async fn list_notes_for_entity_page(
              n.z AS note_z,
              n.deadline AS note_deadline,
              n.deadline_tz AS note_deadline_tz,
+             n.pinned AS note_pinned,
              nl.id AS link_id,
              nl.household_id AS link_household_id,
              nl.note_id AS link_note_id,
@@ -888,6 +1072,7 @@ pub async fn list_notes_for_entity_page(
           AND nl.entity_id = ?
           AND e.household_id = ?
           AND nl.household_id = ?
+          AND nl.deleted_at IS NULL
           AND n.household_id = ?
           AND n.deleted_at IS NULL",
     );
@@ -951,6 +1136,7 @@ pub async fn list_notes_for_entity_page(
             z: row.try_get("note_z")?,
             deadline: row.try_get("note_deadline")?,
             deadline_tz: row.try_get("note_deadline_tz")?,
+            pinned: row.try_get("note_pinned")?,
         };
         if note.z.is_none() {
             note.z = Some(0);
@@ -1318,3 +1504,41 @@ pub async fn notes_quick_create_for_entity(
     })
     .await
 }
+
+#[tauri::command]
+pub async fn notes_create_with_links(
+    state: State<'_, AppState>,
+    household_id: String,
+    category_id: String,
+    text: String,
+    color: Option<String>,
+    entities: Vec<NoteLinkTarget>,
+) -> AppResult<NoteWithLinksCreated> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let category_id = category_id.clone();
+        let text = text.clone();
+        let color = color.clone();
+        let entities = entities.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "notes_create_with_links")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            create_note_with_links(
+                &pool,
+                &household_id,
+                &category_id,
+                &text,
+                color.as_deref(),
+                &entities,
+            )
+            .await
+        }
+    })
+    .await
+}
@@ -17,11 +17,30 @@ use crate::{
 const DEFAULT_PAGE_SIZE: i64 = 20;
 const MAX_PAGE_SIZE: i64 = 100;
 const NOTE_SELECT_FIELDS: &str =
-    "id, household_id, category_id, position, created_at, updated_at, deleted_at, text, color, x, y, z, deadline, deadline_tz";
+    "id, household_id, category_id, position, created_at, updated_at, deleted_at, text, color, x, y, z, deadline, deadline_tz, pinned";
 const DAY_MS: i64 = 86_400_000;
 const DEADLINE_DEFAULT_LIMIT: i64 = 200;
 const DEADLINE_MAX_LIMIT: i64 = 500;
 const DEADLINE_PADDING_MS: i64 = DAY_MS * 2;
+/// Prefix marking list cursors that point into the pinned section. Cursors
+/// without it address the unpinned section, which keeps cursors issued before
+/// pinning existed valid.
+const PINNED_CURSOR_PREFIX: &str = "pinned:";
+
+/// Accept SQLite's 0/1 integers as well as JSON booleans.
+fn bool_from_int<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Bool(flag) => Ok(flag),
+        Value::Number(num) => Ok(num.as_i64().unwrap_or(0) != 0),
+        Value::Null => Ok(false),
+        other => Err(serde::de::Error::custom(format!(
+            "expected boolean or integer, got {other}"
+        ))),
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS, sqlx::FromRow)]
 #[ts(export, export_to = "../../src/bindings/")]
@@ -53,6 +72,10 @@ pub struct Note {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[ts(optional)]
     pub deadline_tz: Option<String>,
+    /// Pinned notes are listed ahead of unpinned ones.
+    #[serde(default, deserialize_with = "bool_from_int")]
+    #[sqlx(default)]
+    pub pinned: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -74,34 +97,46 @@ pub struct NotesDeadlineRangePage {
     pub cursor: Option<String>,
 }
 
-fn decode_cursor(cursor: Option<String>) -> AppResult<Option<(i64, String)>> {
-    if let Some(cursor) = cursor {
-        if cursor.trim().is_empty() {
-            return Ok(None);
-        }
-        let decoded = STANDARD_NO_PAD.decode(cursor.as_bytes()).map_err(|err| {
-            AppError::new("NOTES/CURSOR_DECODE", "Failed to decode cursor")
-                .with_context("cause", err.to_string())
-        })?;
-        let decoded_str = String::from_utf8(decoded).map_err(|err| {
-            AppError::new("NOTES/CURSOR_DECODE", "Failed to decode cursor")
-                .with_context("cause", err.to_string())
-        })?;
-        let mut parts = decoded_str.splitn(2, ':');
-        let created_at = parts
-            .next()
-            .ok_or_else(|| AppError::new("NOTES/CURSOR_INVALID", "Cursor missing created_at"))?;
-        let id = parts
-            .next()
-            .ok_or_else(|| AppError::new("NOTES/CURSOR_INVALID", "Cursor missing id"))?;
-        let created_at = created_at.parse::<i64>().map_err(|err| {
-            AppError::new("NOTES/CURSOR_INVALID", "Cursor contains invalid created_at")
-                .with_context("cause", err.to_string())
-        })?;
-        Ok(Some((created_at, id.to_string())))
-    } else {
-        Ok(None)
+#[allow(clippy::result_large_err)]
+fn decode_cursor_text(cursor: Option<String>) -> AppResult<Option<String>> {
+    let Some(cursor) = cursor else {
+        return Ok(None);
+    };
+    if cursor.trim().is_empty() {
+        return Ok(None);
     }
+    let decoded = STANDARD_NO_PAD.decode(cursor.as_bytes()).map_err(|err| {
+        AppError::new("NOTES/CURSOR_DECODE", "Failed to decode cursor")
+            .with_context("cause", err.to_string())
+    })?;
+    let decoded_str = String::from_utf8(decoded).map_err(|err| {
+        AppError::new("NOTES/CURSOR_DECODE", "Failed to decode cursor")
+            .with_context("cause", err.to_string())
+    })?;
+    Ok(Some(decoded_str))
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_cursor_key(decoded_str: &str) -> AppResult<(i64, String)> {
+    let mut parts = decoded_str.splitn(2, ':');
+    let created_at = parts
+        .next()
+        .ok_or_else(|| AppError::new("NOTES/CURSOR_INVALID", "Cursor missing created_at"))?;
+    let id = parts
+        .next()
+        .ok_or_else(|| AppError::new("NOTES/CURSOR_INVALID", "Cursor missing id"))?;
+    let created_at = created_at.parse::<i64>().map_err(|err| {
+        AppError::new("NOTES/CURSOR_INVALID", "Cursor contains invalid created_at")
+            .with_context("cause", err.to_string())
+    })?;
+    Ok((created_at, id.to_string()))
+}
+
+#[allow(clippy::result_large_err)]
+fn decode_cursor(cursor: Option<String>) -> AppResult<Option<(i64, String)>> {
+    decode_cursor_text(cursor)?
+        .map(|text| parse_cursor_key(&text))
+        .transpose()
 }
 
 fn encode_cursor(created_at: i64, id: &str) -> String {
@@ -109,6 +144,40 @@ fn encode_cursor(created_at: i64, id: &str) -> String {
     STANDARD_NO_PAD.encode(value.as_bytes())
 }
 
+/// Position in the notes list: `(pinned, created_at, id)`.
+type ListCursor = (bool, i64, String);
+
+#[allow(clippy::result_large_err)]
+fn decode_list_cursor(cursor: Option<String>) -> AppResult<Option<ListCursor>> {
+    let Some(text) = decode_cursor_text(cursor)? else {
+        return Ok(None);
+    };
+    let (pinned, key) = match text.strip_prefix(PINNED_CURSOR_PREFIX) {
+        Some(rest) => (true, rest),
+        None => (false, text.as_str()),
+    };
+    let (created_at, id) = parse_cursor_key(key)?;
+    Ok(Some((pinned, created_at, id)))
+}
+
+fn encode_list_cursor(note: &Note) -> String {
+    let prefix = if note.pinned {
+        PINNED_CURSOR_PREFIX
+    } else {
+        ""
+    };
+    let value = format!("{prefix}{}:{}", note.created_at, note.id);
+    STANDARD_NO_PAD.encode(value.as_bytes())
+}
+
+/// List order: pinned notes first, then oldest first with id as tiebreak.
+fn list_order(a: &Note, b: &Note) -> Ordering {
+    b.pinned
+        .cmp(&a.pinned)
+        .then(a.created_at.cmp(&b.created_at))
+        .then_with(|| a.id.cmp(&b.id))
+}
+
 async fn fetch_note(
     pool: &SqlitePool,
     household_id: Option<&str>,
@@ -183,7 +252,7 @@ fn window_end_bound(end: i64) -> i64 {
 async fn list_page(
     pool: &SqlitePool,
     household_id: &str,
-    after: Option<(i64, String)>,
+    after: Option<ListCursor>,
     limit: i64,
     category_ids: Option<Vec<String>>,
     include_deleted: bool,
@@ -216,8 +285,14 @@ async fn list_page(
         sql.push_str(" AND deleted_at IS NULL");
     }
 
-    if after.is_some() {
-        sql.push_str(" AND (created_at > ? OR (created_at = ? AND id > ?))");
+    match &after {
+        Some((true, _, _)) => sql.push_str(
+            " AND ((pinned = 1 AND (created_at > ? OR (created_at = ? AND id > ?))) OR pinned = 0)",
+        ),
+        Some((false, _, _)) => {
+            sql.push_str(" AND pinned = 0 AND (created_at > ? OR (created_at = ? AND id > ?))")
+        }
+        None => {}
     }
 
     if !filter_categories.is_empty() {
@@ -227,10 +302,10 @@ async fn list_page(
         sql.push(')');
     }
 
-    sql.push_str(" ORDER BY created_at, id LIMIT ?");
+    sql.push_str(" ORDER BY pinned DESC, created_at, id LIMIT ?");
 
     let mut query = sqlx::query_as::<_, Note>(&sql).bind(household_id);
-    if let Some((created_at, id)) = &after {
+    if let Some((_, created_at, id)) = &after {
         query = query.bind(created_at).bind(created_at).bind(id);
     }
     for category in &filter_categories {
@@ -423,12 +498,9 @@ async fn list_deadline_range_page(
 fn paginate(mut notes: Vec<Note>, limit: i64) -> NotesPage {
     let mut next_cursor = None;
     if notes.len() as i64 > limit {
-        notes.sort_by(|a, b| match a.created_at.cmp(&b.created_at) {
-            Ordering::Equal => a.id.cmp(&b.id),
-            other => other,
-        });
+        notes.sort_by(list_order);
         if let Some(note) = notes.get(limit as usize - 1) {
-            next_cursor = Some(encode_cursor(note.created_at, &note.id));
+            next_cursor = Some(encode_list_cursor(note));
         }
         notes.truncate(limit as usize);
     }
@@ -450,7 +522,7 @@ pub async fn notes_list_cursor(
         let household_id = household_id.clone();
         let category_ids = category_ids.clone();
         async move {
-            let after = decode_cursor(after_cursor)?;
+            let after = decode_list_cursor(after_cursor)?;
             let limit = normalise_limit(limit);
             let mut notes = list_page(
                 &pool,
@@ -461,10 +533,7 @@ pub async fn notes_list_cursor(
                 include_deleted,
             )
             .await?;
-            notes.sort_by(|a, b| match a.created_at.cmp(&b.created_at) {
-                Ordering::Equal => a.id.cmp(&b.id),
-                other => other,
-            });
+            notes.sort_by(list_order);
             Ok(paginate(notes, limit))
         }
     })
@@ -584,6 +653,7 @@ pub async fn notes_update(
     id: String,
     data: Map<String, Value>,
     household_id: Option<String>,
+    expected_updated_at: Option<i64>,
 ) -> AppResult<Note> {
     let _permit = guard::ensure_db_writable(&state)?;
     let pool = state.pool_clone();
@@ -592,8 +662,16 @@ pub async fn notes_update(
         let data = data.clone();
         let household_id = household_id.clone();
         async move {
-            commands::update_command(&pool, "notes", &id, data, household_id.as_deref(), None)
-                .await?;
+            commands::update_command(
+                &pool,
+                "notes",
+                &id,
+                data,
+                household_id.as_deref(),
+                None,
+                expected_updated_at,
+            )
+            .await?;
             fetch_note(&pool, household_id.as_deref(), &id)
                 .await?
                 .ok_or_else(|| AppError::new("NOTES/NOT_FOUND", "Note not found after update"))
@@ -639,6 +717,58 @@ pub async fn notes_restore(
     .await
 }
 
+async fn set_pinned(
+    pool: &SqlitePool,
+    household_id: &str,
+    id: &str,
+    pinned: bool,
+) -> AppResult<Note> {
+    repo::require_household(household_id).map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "notes_set_pinned")
+            .with_context("table", "notes".to_string())
+    })?;
+    let result = sqlx::query(
+        "UPDATE notes SET pinned = ?1, updated_at = ?2 WHERE id = ?3 AND household_id = ?4 AND deleted_at IS NULL",
+    )
+    .bind(pinned)
+    .bind(crate::time::now_ms())
+    .bind(id)
+    .bind(household_id)
+    .execute(pool)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "notes_set_pinned")
+            .with_context("id", id.to_string())
+    })?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::new("NOTES/NOT_FOUND", "Note not found")
+            .with_context("id", id.to_string())
+            .with_context("household_id", household_id.to_string()));
+    }
+    fetch_note(pool, Some(household_id), id)
+        .await?
+        .ok_or_else(|| AppError::new("NOTES/NOT_FOUND", "Note not found after update"))
+}
+
+#[tauri::command]
+pub async fn notes_set_pinned(
+    state: State<'_, AppState>,
+    household_id: String,
+    id: String,
+    pinned: bool,
+) -> AppResult<Note> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let id = id.clone();
+        async move { set_pinned(&pool, &household_id, &id, pinned).await }
+    })
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -723,7 +853,7 @@ mod tests {
         assert_eq!(page_one.notes.len(), 10, "first page has 10 results");
         let cursor = page_one.next_cursor.clone().expect("next cursor present");
 
-        let decoded = decode_cursor(Some(cursor.clone()))
+        let decoded = decode_list_cursor(Some(cursor.clone()))
             .expect("decode cursor")
             .expect("cursor values");
         let raw_page_two = list_page(&pool, "default", Some(decoded), 10, None, false)
@@ -742,7 +872,7 @@ mod tests {
         let raw_page_three = list_page(
             &pool,
             "default",
-            decode_cursor(page_two.next_cursor.clone()).unwrap(),
+            decode_list_cursor(page_two.next_cursor.clone()).unwrap(),
             10,
             None,
             false,
@@ -757,6 +887,70 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn pinned_notes_sort_first_and_toggle() {
+        let pool = setup_pool().await;
+        let mut ids = Vec::new();
+        for idx in 0..4 {
+            let mut payload = note_payload(&format!("note-{idx}"), idx);
+            payload.insert("created_at".into(), Value::from(1_000 + idx));
+            let created = commands::create_command(&pool, "notes", payload, None)
+                .await
+                .expect("create note");
+            ids.push(created["id"].as_str().expect("id").to_string());
+        }
+        let newest = ids[3].clone();
+        let oldest = ids[0].clone();
+
+        let pinned = set_pinned(&pool, "default", &oldest, true)
+            .await
+            .expect("pin note");
+        assert!(pinned.pinned);
+        let pinned_newest = set_pinned(&pool, "default", &newest, true)
+            .await
+            .expect("pin newest");
+        assert!(pinned_newest.pinned);
+
+        let page_one = paginate(
+            list_page(&pool, "default", None, 2, None, false)
+                .await
+                .expect("first page"),
+            2,
+        );
+        let first: Vec<_> = page_one.notes.iter().map(|n| n.id.clone()).collect();
+        assert_eq!(first, vec![oldest.clone(), newest.clone()]);
+
+        let after = decode_list_cursor(page_one.next_cursor.clone()).expect("cursor");
+        assert!(after.as_ref().is_some_and(|(pinned, _, _)| *pinned));
+        let page_two = paginate(
+            list_page(&pool, "default", after, 2, None, false)
+                .await
+                .expect("second page"),
+            2,
+        );
+        let second: Vec<_> = page_two.notes.iter().map(|n| n.id.clone()).collect();
+        assert_eq!(second, vec![ids[1].clone(), ids[2].clone()]);
+        assert!(page_two.notes.iter().all(|n| !n.pinned));
+
+        let unpinned = set_pinned(&pool, "default", &newest, false)
+            .await
+            .expect("unpin note");
+        assert!(!unpinned.pinned);
+        let all = list_page(&pool, "default", None, 10, None, false)
+            .await
+            .expect("list all");
+        let order: Vec<_> = all.iter().map(|n| n.id.clone()).collect();
+        assert_eq!(
+            order,
+            vec![oldest, ids[1].clone(), ids[2].clone(), newest.clone()]
+        );
+
+        let err = set_pinned(&pool, "other", &newest, true)
+            .await
+            .expect_err("wrong household");
+        assert_eq!(err.code(), "NOTES/NOT_FOUND");
+    }
+
     #[tokio::test]
     async fn notes_deadline_fields_roundtrip() {
         let pool = setup_pool().await;
@@ -1 +1,3 @@
+pub mod progress;
 pub mod reporting;
+pub mod retry;
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+/// Which part of a long-running import or export a progress tick belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressStage {
+    /// Rows of one logical table.
+    Table(String),
+    /// Files copied into or out of the vault.
+    Attachments,
+}
+
+/// Counters reported while an import or export works through a stage.
+///
+/// `done` never exceeds `total`. `bytes` is only tracked for attachments and
+/// accumulates the size of the files handled so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationProgress {
+    pub stage: ProgressStage,
+    pub done: u64,
+    pub total: u64,
+    pub bytes: u64,
+}
+
+pub type ProgressObserver = Arc<dyn Fn(OperationProgress) + Send + Sync + 'static>;
@@ -0,0 +1,154 @@
+use std::io::{Error as IoError, ErrorKind};
+use std::path::Path;
+use std::thread::sleep;
+use std::time::Duration;
+
+use tracing::warn;
+
+use crate::security::hash_path;
+
+/// Attempts made for one attachment copy before the error is returned.
+pub const COPY_RETRY_MAX_ATTEMPTS: u32 = 4;
+/// Wait before the first retry; doubled for every further attempt.
+pub const COPY_RETRY_BASE_DELAY_MS: u64 = 50;
+
+/// Windows `ERROR_SHARING_VIOLATION` and `ERROR_LOCK_VIOLATION`, raised while
+/// another process (often a virus scanner or sync client) briefly holds the
+/// file open.
+#[cfg(windows)]
+const TRANSIENT_OS_CODES: &[i32] = &[32, 33];
+#[cfg(not(windows))]
+const TRANSIENT_OS_CODES: &[i32] = &[];
+
+/// Whether `err` is likely to clear up if the same operation is repeated.
+pub fn is_transient_io_error(err: &IoError) -> bool {
+    matches!(
+        err.kind(),
+        ErrorKind::Interrupted
+            | ErrorKind::WouldBlock
+            | ErrorKind::TimedOut
+            | ErrorKind::ResourceBusy
+            | ErrorKind::ExecutableFileBusy
+    ) || err
+        .raw_os_error()
+        .is_some_and(|code| TRANSIENT_OS_CODES.contains(&code))
+}
+
+/// Run `op` until it succeeds, fails with a non-transient error, or
+/// [`COPY_RETRY_MAX_ATTEMPTS`] attempts have been made. `op` must be safe to
+/// repeat from scratch, e.g. by truncating its destination. Blocks the thread
+/// between attempts; async callers use [`retry_transient_io_async`].
+pub fn retry_transient_io<T>(
+    operation: &'static str,
+    path: &Path,
+    mut op: impl FnMut() -> Result<T, IoError>,
+) -> Result<T, IoError> {
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < COPY_RETRY_MAX_ATTEMPTS && is_transient_io_error(&err) => {
+                sleep(retry_wait(operation, path, attempt, &err));
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// [`retry_transient_io`] for async callers: waits with `tokio::time::sleep`
+/// so a retry does not hold up the runtime worker.
+pub async fn retry_transient_io_async<T>(
+    operation: &'static str,
+    path: &Path,
+    mut op: impl FnMut() -> Result<T, IoError>,
+) -> Result<T, IoError> {
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < COPY_RETRY_MAX_ATTEMPTS && is_transient_io_error(&err) => {
+                tokio::time::sleep(retry_wait(operation, path, attempt, &err)).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Logs a transient failure and returns how long to wait before retrying.
+fn retry_wait(operation: &'static str, path: &Path, attempt: u32, err: &IoError) -> Duration {
+    let wait = Duration::from_millis(COPY_RETRY_BASE_DELAY_MS << (attempt - 1));
+    warn!(
+        target: "arklowdun",
+        event = "attachment_copy_retry",
+        operation,
+        path_hash = %hash_path(path),
+        attempt,
+        wait_ms = wait.as_millis() as u64,
+        error_kind = ?err.kind(),
+        error = %err
+    );
+    wait
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn transient_failure_succeeds_on_retry() {
+        let calls = Cell::new(0);
+        let result = retry_transient_io("test_copy", Path::new("a/b.txt"), || {
+            calls.set(calls.get() + 1);
+            if calls.get() < 3 {
+                Err(IoError::from(ErrorKind::Interrupted))
+            } else {
+                Ok("hash")
+            }
+        });
+        assert_eq!(result.unwrap(), "hash");
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn transient_failure_gives_up_after_max_attempts() {
+        let calls = Cell::new(0);
+        let err = retry_transient_io("test_copy", Path::new("a/b.txt"), || {
+            calls.set(calls.get() + 1);
+            Err::<(), _>(IoError::from(ErrorKind::WouldBlock))
+        })
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WouldBlock);
+        assert_eq!(calls.get(), COPY_RETRY_MAX_ATTEMPTS);
+    }
+
+    #[tokio::test]
+    async fn async_retry_succeeds_on_retry() {
+        let calls = Cell::new(0);
+        let result = retry_transient_io_async("test_copy", Path::new("a/b.txt"), || {
+            calls.set(calls.get() + 1);
+            if calls.get() < 2 {
+                Err(IoError::from(ErrorKind::Interrupted))
+            } else {
+                Ok("hash")
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), "hash");
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn permanent_failure_is_not_retried() {
+        let calls = Cell::new(0);
+        let err = retry_transient_io("test_copy", Path::new("a/b.txt"), || {
+            calls.set(calls.get() + 1);
+            Err::<(), _>(IoError::from(ErrorKind::PermissionDenied))
+        })
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(calls.get(), 1);
+    }
+}
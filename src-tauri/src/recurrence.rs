@@ -0,0 +1,593 @@
+//! Recurrence rule checks for the event editor.
+//!
+//! Rules are parsed and validated with the same `rrule` crate that expands
+//! series in `events_list_range`, so a rule accepted here expands the same way
+//! once saved. Parse failures are narrowed down to the offending `KEY=VALUE`
+//! part so the editor can highlight it.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz as ChronoTz;
+use rrule::{RRule, RRuleSet, Tz, Unvalidated};
+use serde::{Deserialize, Serialize};
+use sqlx::{SqliteExecutor, SqlitePool};
+use tracing::{info, warn};
+use ts_rs::TS;
+
+use crate::exdate::{inspect_exdates, parse_rrule_until, split_csv_exdates, ExdateContext};
+use crate::{time_errors::TimeErrorCode, util::dispatch_async_app_result, AppError, AppResult};
+
+/// Occurrences returned with a valid rule when the caller does not ask for a count.
+pub const DEFAULT_PREVIEW_COUNT: u16 = 5;
+const MAX_PREVIEW_COUNT: u16 = 50;
+/// Upper bound on occurrences walked from the series start while looking for
+/// ones after the requested instant, so a rule the engine keeps expanding
+/// without reaching it cannot stall the caller.
+const NEXT_OCCURRENCES_ITERATION_CAP: usize = 100_000;
+const RRULE_PREFIX: &str = "RRULE:";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct RruleValidation {
+    /// Rule as the expansion engine prints it, without an `RRULE:` prefix.
+    pub rrule: String,
+    pub tz: String,
+    /// Next occurrences from `dtstart_utc`, as UTC milliseconds.
+    #[ts(type = "Array<number>")]
+    pub occurrences_utc: Vec<i64>,
+}
+
+/// A stored event whose recurrence data the editor would reject.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct RecurrenceIssue {
+    pub event_id: String,
+    /// Time error taxonomy code, e.g. `E_EXDATE_INVALID_FORMAT`.
+    pub code: String,
+    /// Offending rule or EXDATE token, when one can be pinned down.
+    pub value: Option<String>,
+}
+
+/// A stored recurrence value rewritten by [`fix_event_recurrence`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct RecurrenceFix {
+    pub event_id: String,
+    /// Column that changed: `rrule` or `exdates`.
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Result of `events_validate_recurrence`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct RecurrenceReport {
+    /// Problems found before any repair ran.
+    pub issues: Vec<RecurrenceIssue>,
+    /// Changes written when a fix was requested.
+    pub fixes: Vec<RecurrenceFix>,
+}
+
+/// A `KEY=VALUE` part of a rule and its byte offset in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RuleToken<'a> {
+    position: usize,
+    text: &'a str,
+}
+
+/// Validate `rrule` anchored at `dtstart_utc` in `tz` (UTC when omitted) and
+/// return the normalised rule with up to `count` occurrences.
+#[allow(clippy::result_large_err)]
+pub fn validate_rrule(
+    rrule: &str,
+    dtstart_utc: i64,
+    tz: Option<&str>,
+    count: u16,
+) -> AppResult<RruleValidation> {
+    let tz_name = tz
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("UTC");
+    let tz_chrono: ChronoTz = tz_name.parse().map_err(|_| {
+        TimeErrorCode::TimezoneUnknown
+            .into_error()
+            .with_context("operation", "rrule_validate")
+            .with_context("timezone", tz_name.to_string())
+    })?;
+    let tz: Tz = tz_chrono.into();
+    let start = DateTime::<Utc>::from_timestamp_millis(dtstart_utc)
+        .ok_or_else(|| {
+            AppError::new(
+                "TIME/INVALID_TIMESTAMP",
+                "Invalid recurrence anchor timestamp",
+            )
+            .with_context("operation", "rrule_validate")
+            .with_context("dtstart_utc", dtstart_utc.to_string())
+        })?
+        .with_timezone(&tz);
+
+    let (offset, body) = rule_body(rrule);
+    let parsed = body.parse::<RRule<Unvalidated>>().map_err(|err| {
+        let token = find_bad_token(body, offset, |probe| {
+            probe.parse::<RRule<Unvalidated>>().is_ok()
+        });
+        with_token(
+            TimeErrorCode::RruleParse
+                .into_error()
+                .with_context("operation", "rrule_validate")
+                .with_context("rrule", rrule.to_string())
+                .with_context("detail", err.to_string()),
+            token,
+        )
+    })?;
+    let normalized = parsed.to_string();
+
+    let validated = parsed.validate(start).map_err(|err| {
+        let token = find_bad_token(body, offset, |probe| {
+            probe
+                .parse::<RRule<Unvalidated>>()
+                .map(|rule| rule.validate(start).is_ok())
+                .unwrap_or(false)
+        });
+        with_token(
+            TimeErrorCode::RruleUnsupportedField
+                .into_error()
+                .with_context("operation", "rrule_validate")
+                .with_context("rrule", rrule.to_string())
+                .with_context("detail", err.to_string()),
+            token,
+        )
+    })?;
+
+    let count = count.clamp(1, MAX_PREVIEW_COUNT);
+    let occurrences_utc = RRuleSet::new(start)
+        .rrule(validated)
+        .all(count)
+        .dates
+        .into_iter()
+        .map(|occ| occ.with_timezone(&Utc).timestamp_millis())
+        .collect();
+
+    Ok(RruleValidation {
+        rrule: normalized,
+        tz: tz_chrono.name().to_string(),
+        occurrences_utc,
+    })
+}
+
+/// Strip surrounding whitespace and an optional `RRULE:` prefix, returning
+/// the byte offset of the remaining body in `input`.
+fn rule_body(input: &str) -> (usize, &str) {
+    let leading = input.len() - input.trim_start().len();
+    let mut body = input.trim();
+    let mut offset = leading;
+    if body
+        .get(..RRULE_PREFIX.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(RRULE_PREFIX))
+    {
+        body = &body[RRULE_PREFIX.len()..];
+        offset += RRULE_PREFIX.len();
+    }
+    (offset, body)
+}
+
+fn tokens(body: &str, offset: usize) -> Vec<RuleToken<'_>> {
+    let mut out = Vec::new();
+    let mut position = 0;
+    for part in body.split(';') {
+        if !part.trim().is_empty() {
+            out.push(RuleToken {
+                position: offset + position,
+                text: part,
+            });
+        }
+        position += part.len() + 1;
+    }
+    out
+}
+
+/// First token that fails `accepts` on its own. Each non-`FREQ` token is
+/// probed together with the rule's own `FREQ` (or `FREQ=DAILY` when that is
+/// the broken part) since most properties are meaningless without one.
+fn find_bad_token<'a>(
+    body: &'a str,
+    offset: usize,
+    accepts: impl Fn(&str) -> bool,
+) -> Option<RuleToken<'a>> {
+    let tokens = tokens(body, offset);
+    let is_freq = |token: &RuleToken<'_>| {
+        token
+            .text
+            .trim()
+            .get(..5)
+            .is_some_and(|key| key.eq_ignore_ascii_case("FREQ="))
+    };
+    let freq = tokens
+        .iter()
+        .find(|token| is_freq(token))
+        .filter(|token| accepts(token.text))
+        .map(|token| token.text.trim())
+        .unwrap_or("FREQ=DAILY");
+    tokens.into_iter().find(|token| {
+        if !token.text.contains('=') {
+            return true;
+        }
+        if is_freq(token) {
+            !accepts(token.text)
+        } else {
+            !accepts(&format!("{freq};{}", token.text))
+        }
+    })
+}
+
+fn with_token(err: AppError, token: Option<RuleToken<'_>>) -> AppError {
+    match token {
+        Some(token) => err
+            .with_context("token", token.text.to_string())
+            .with_context("position", token.position.to_string()),
+        None => err,
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct StoredRecurrence {
+    id: String,
+    start_at_utc: Option<i64>,
+    tz: Option<String>,
+    rrule: Option<String>,
+    exdates: Option<String>,
+}
+
+async fn load_stored_recurrence<'e, E>(
+    executor: E,
+    household_id: &str,
+) -> AppResult<Vec<StoredRecurrence>>
+where
+    E: SqliteExecutor<'e>,
+{
+    sqlx::query_as(
+        "SELECT id, start_at_utc, tz, rrule, exdates
+           FROM events
+          WHERE household_id = ?1
+            AND deleted_at IS NULL
+            AND (rrule IS NOT NULL OR exdates IS NOT NULL)
+          ORDER BY id",
+    )
+    .bind(household_id)
+    .fetch_all(executor)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "events_validate_recurrence")
+            .with_context("household_id", household_id.to_string())
+    })
+}
+
+/// Check the RRULE and EXDATE list of every live event in `household_id`
+/// against the rules enforced on save. Nothing is written.
+pub async fn scan_event_recurrence(
+    pool: &SqlitePool,
+    household_id: &str,
+) -> AppResult<Vec<RecurrenceIssue>> {
+    let rows = load_stored_recurrence(pool, household_id).await?;
+
+    let mut issues = Vec::new();
+    for StoredRecurrence {
+        id: event_id,
+        start_at_utc: start_ms,
+        tz,
+        rrule,
+        exdates,
+    } in rows
+    {
+        let rrule = rrule
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let issue = |code: &str, value: Option<String>| RecurrenceIssue {
+            event_id: event_id.clone(),
+            code: code.to_string(),
+            value,
+        };
+
+        if let Some(rule) = rrule.as_deref() {
+            if let Err(err) = validate_rrule(rule, start_ms.unwrap_or_default(), tz.as_deref(), 1) {
+                issues.push(issue(err.code(), Some(rule.to_string())));
+            }
+        }
+
+        let tokens = exdates
+            .as_deref()
+            .map(split_csv_exdates)
+            .unwrap_or_default();
+        if tokens.is_empty() {
+            continue;
+        }
+        let Some(rule) = rrule.as_deref() else {
+            issues.push(issue(TimeErrorCode::ExdateOutOfRange.as_str(), None));
+            continue;
+        };
+        let context = ExdateContext {
+            start: start_ms.and_then(DateTime::<Utc>::from_timestamp_millis),
+            until: parse_rrule_until(rule),
+        };
+        let inspection = inspect_exdates(tokens, &context);
+        for value in inspection
+            .invalid_format
+            .into_iter()
+            .chain(inspection.non_utc)
+        {
+            issues.push(issue(
+                TimeErrorCode::ExdateInvalidFormat.as_str(),
+                Some(value),
+            ));
+        }
+        for value in inspection.out_of_range {
+            issues.push(issue(TimeErrorCode::ExdateOutOfRange.as_str(), Some(value)));
+        }
+    }
+    Ok(issues)
+}
+
+/// Repair the recurrence data reported by [`scan_event_recurrence`] in one
+/// transaction. Rules the engine cannot parse or expand are dropped, which
+/// turns the event into a single occurrence together with its EXDATEs;
+/// remaining EXDATE lists are deduplicated, sorted and stripped of invalid
+/// or out-of-range values. A blank rule is stored as NULL. Rules rejected for
+/// other reasons, such as an unknown timezone, are left for the user. Every
+/// rewritten event gets a fresh `updated_at` so sync picks it up.
+pub async fn fix_event_recurrence(
+    pool: &SqlitePool,
+    household_id: &str,
+) -> AppResult<Vec<RecurrenceFix>> {
+    let mut tx = pool.begin().await.map_err(|err| {
+        AppError::from(err).with_context("operation", "events_fix_recurrence_begin")
+    })?;
+    let rows = load_stored_recurrence(&mut *tx, household_id).await?;
+
+    let now = crate::time::now_ms();
+    let mut fixes = Vec::new();
+    for row in rows {
+        let rule = row
+            .rrule
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+        let unparseable = rule.is_some_and(|rule| {
+            validate_rrule(
+                rule,
+                row.start_at_utc.unwrap_or_default(),
+                row.tz.as_deref(),
+                1,
+            )
+            .is_err_and(|err| {
+                err.code() == TimeErrorCode::RruleParse.as_str()
+                    || err.code() == TimeErrorCode::RruleUnsupportedField.as_str()
+            })
+        });
+        let rrule_after = if unparseable || rule.is_none() {
+            None
+        } else {
+            row.rrule.clone()
+        };
+        let exdates_after = match (rule, unparseable) {
+            (Some(rule), false) => {
+                let tokens = row
+                    .exdates
+                    .as_deref()
+                    .map(split_csv_exdates)
+                    .unwrap_or_default();
+                let context = ExdateContext {
+                    start: row
+                        .start_at_utc
+                        .and_then(DateTime::<Utc>::from_timestamp_millis),
+                    until: parse_rrule_until(rule),
+                };
+                inspect_exdates(tokens, &context).canonical
+            }
+            _ => None,
+        };
+
+        for (field, before, after) in [
+            ("rrule", &row.rrule, rrule_after),
+            ("exdates", &row.exdates, exdates_after),
+        ] {
+            if *before == after {
+                continue;
+            }
+            let sql = format!("UPDATE events SET {field} = ?1, updated_at = ?3 WHERE id = ?2");
+            sqlx::query(&sql)
+                .bind(&after)
+                .bind(&row.id)
+                .bind(now)
+                .execute(&mut *tx)
+                .await
+                .map_err(|err| {
+                    AppError::from(err)
+                        .with_context("operation", "events_fix_recurrence")
+                        .with_context("event_id", row.id.clone())
+                        .with_context("field", field)
+                })?;
+            fixes.push(RecurrenceFix {
+                event_id: row.id.clone(),
+                field: field.to_string(),
+                before: before.clone(),
+                after,
+            });
+        }
+    }
+
+    tx.commit().await.map_err(|err| {
+        AppError::from(err).with_context("operation", "events_fix_recurrence_commit")
+    })?;
+    if !fixes.is_empty() {
+        info!(
+            target: "arklowdun",
+            event = "events_recurrence_fixed",
+            household_id,
+            fixes = fixes.len()
+        );
+    }
+    Ok(fixes)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct StoredSeries {
+    start_at_utc: i64,
+    tz: Option<String>,
+    rrule: Option<String>,
+    exdates: Option<String>,
+}
+
+/// Expand the stored rule of event `id` in `household_id` in its own timezone
+/// and return up to `count` occurrence starts strictly after `after_utc`, with
+/// its EXDATEs removed. A rule ended by UNTIL or COUNT may yield fewer; an
+/// event without a rule yields its single start when that falls after
+/// `after_utc`.
+pub async fn next_occurrences(
+    pool: &SqlitePool,
+    household_id: &str,
+    id: &str,
+    count: u16,
+    after_utc: i64,
+) -> AppResult<Vec<i64>> {
+    let context = |err: AppError| {
+        err.with_context("operation", "events_next_occurrences")
+            .with_context("household_id", household_id.to_string())
+            .with_context("event_id", id.to_string())
+    };
+    let row: Option<StoredSeries> = sqlx::query_as(
+        "SELECT start_at_utc, tz, rrule, exdates
+           FROM events
+          WHERE id = ?1 AND household_id = ?2 AND deleted_at IS NULL",
+    )
+    .bind(id)
+    .bind(household_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| context(AppError::from(err)))?;
+    let row = row.ok_or_else(|| {
+        context(AppError::new("DB/NOT_FOUND", "Record not found").with_context("table", "events"))
+    })?;
+
+    let count = usize::from(count.clamp(1, MAX_PREVIEW_COUNT));
+    let Some(rule) = row
+        .rrule
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    else {
+        return Ok(if row.start_at_utc > after_utc {
+            vec![row.start_at_utc]
+        } else {
+            Vec::new()
+        });
+    };
+
+    let tz_name = row
+        .tz
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(crate::settings::fallback_timezone);
+    let normalized = validate_rrule(rule, row.start_at_utc, Some(&tz_name), 1).map_err(context)?;
+    let tz: Tz = normalized
+        .tz
+        .parse::<ChronoTz>()
+        .map_err(|_| {
+            context(
+                TimeErrorCode::TimezoneUnknown
+                    .into_error()
+                    .with_context("timezone", normalized.tz.clone()),
+            )
+        })?
+        .into();
+    let start = DateTime::<Utc>::from_timestamp_millis(row.start_at_utc)
+        .ok_or_else(|| {
+            context(AppError::new(
+                "TIME/INVALID_TIMESTAMP",
+                "Invalid recurrence anchor timestamp",
+            ))
+        })?
+        .with_timezone(&tz);
+    let validated = normalized
+        .rrule
+        .parse::<RRule<Unvalidated>>()
+        .and_then(|parsed| parsed.validate(start))
+        .map_err(|err| {
+            context(
+                TimeErrorCode::RruleUnsupportedField
+                    .into_error()
+                    .with_context("rrule", rule.to_string())
+                    .with_context("detail", err.to_string()),
+            )
+        })?;
+
+    let mut set = RRuleSet::new(start).rrule(validated);
+    for token in row
+        .exdates
+        .as_deref()
+        .map(split_csv_exdates)
+        .unwrap_or_default()
+    {
+        if let Ok(exdate) = DateTime::parse_from_rfc3339(&token) {
+            set = set.exdate(exdate.with_timezone(&tz));
+        }
+    }
+
+    let mut occurrences = Vec::with_capacity(count);
+    for (walked, occurrence) in (&set).into_iter().enumerate() {
+        if walked >= NEXT_OCCURRENCES_ITERATION_CAP {
+            warn!(
+                target: "arklowdun",
+                event = "events_next_occurrences_capped",
+                event_id = id,
+                cap = NEXT_OCCURRENCES_ITERATION_CAP
+            );
+            break;
+        }
+        let at = occurrence.with_timezone(&Utc).timestamp_millis();
+        if at <= after_utc {
+            continue;
+        }
+        occurrences.push(at);
+        if occurrences.len() == count {
+            break;
+        }
+    }
+    Ok(occurrences)
+}
+
+#[tauri::command]
+pub async fn rrule_validate(
+    rrule: String,
+    dtstart_utc: i64,
+    tz: Option<String>,
+    count: Option<u16>,
+) -> AppResult<RruleValidation> {
+    dispatch_async_app_result(move || {
+        let rrule = rrule.clone();
+        let tz = tz.clone();
+        async move {
+            validate_rrule(
+                &rrule,
+                dtstart_utc,
+                tz.as_deref(),
+                count.unwrap_or(DEFAULT_PREVIEW_COUNT),
+            )
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_report_offsets_after_prefix() {
+        let (offset, body) = rule_body("  rrule:FREQ=WEEKLY;BYDAY=MO");
+        assert_eq!(offset, 8);
+        let found = tokens(body, offset);
+        assert_eq!(found[1].text, "BYDAY=MO");
+        assert_eq!(found[1].position, 20);
+    }
+}
@@ -0,0 +1,197 @@
+//! Monthly expenses generated from `expense_templates`.
+//!
+//! Generating a month inserts one expense per active template. Each insert is
+//! paired with a row in `expense_template_runs` keyed by template and period,
+//! so running the same month again is a no-op, including for entries the user
+//! has since deleted.
+
+use chrono::{Datelike, NaiveDate};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use tauri::State;
+
+use crate::{
+    id::new_uuid_v7, ipc::guard, repo, state::AppState, time::now_ms,
+    util::dispatch_async_app_result, AppError, AppResult,
+};
+
+pub const INVALID_MONTH_CODE: &str = "EXPENSE_TEMPLATES/INVALID_MONTH";
+
+struct TemplateRow {
+    id: String,
+    category_id: String,
+    amount: i64,
+    description: Option<String>,
+    day_of_month: i64,
+}
+
+/// Parse a `YYYY-MM` period key into the first day of that month.
+#[allow(clippy::result_large_err)]
+fn parse_month(month: &str) -> AppResult<NaiveDate> {
+    let invalid = || {
+        AppError::new(INVALID_MONTH_CODE, "Month must be formatted as YYYY-MM")
+            .with_context("month", month.to_string())
+    };
+    let (year, mon) = month.trim().split_once('-').ok_or_else(invalid)?;
+    if year.len() != 4 || mon.len() != 2 {
+        return Err(invalid());
+    }
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let mon: u32 = mon.parse().map_err(|_| invalid())?;
+    NaiveDate::from_ymd_opt(year, mon, 1).ok_or_else(invalid)
+}
+
+/// Midnight UTC on `day` of the month starting at `first`, clamped to the
+/// month's last day so a template on the 31st still lands in February.
+fn expense_date_ms(first: NaiveDate, day: i64) -> i64 {
+    let next = first
+        .checked_add_months(chrono::Months::new(1))
+        .unwrap_or(first);
+    let last_day = next.pred_opt().map(|d| d.day()).unwrap_or(28);
+    let day = (day.clamp(1, 31) as u32).min(last_day);
+    first
+        .with_day(day)
+        .unwrap_or(first)
+        .and_hms_opt(0, 0, 0)
+        .map(|dt| dt.and_utc().timestamp_millis())
+        .unwrap_or_default()
+}
+
+async fn active_templates(
+    tx: &mut Transaction<'_, Sqlite>,
+    household_id: &str,
+) -> sqlx::Result<Vec<TemplateRow>> {
+    let rows: Vec<(String, String, i64, Option<String>, i64)> = sqlx::query_as(
+        "SELECT id, category_id, amount, description, day_of_month
+           FROM expense_templates
+          WHERE household_id = ?1 AND active = 1 AND deleted_at IS NULL
+          ORDER BY created_at, id",
+    )
+    .bind(household_id)
+    .fetch_all(tx.as_mut())
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(
+            |(id, category_id, amount, description, day_of_month)| TemplateRow {
+                id,
+                category_id,
+                amount,
+                description,
+                day_of_month,
+            },
+        )
+        .collect())
+}
+
+/// Materialise one expense per active template for `month` (`YYYY-MM`).
+/// Returns how many expenses were created; templates already generated for
+/// the month are skipped.
+pub async fn generate_from_templates(
+    pool: &SqlitePool,
+    household_id: &str,
+    month: &str,
+) -> AppResult<u64> {
+    let first = parse_month(month)?;
+    let period_key = first.format("%Y-%m").to_string();
+    let map_err = |err: sqlx::Error| {
+        AppError::from(err)
+            .with_context("operation", "expenses_generate_from_templates")
+            .with_context("household_id", household_id.to_string())
+            .with_context("month", period_key.clone())
+    };
+
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let templates = active_templates(&mut tx, household_id)
+        .await
+        .map_err(map_err)?;
+    let now = now_ms();
+    let mut created = 0u64;
+    for template in templates {
+        let expense_id = new_uuid_v7();
+        let claimed = sqlx::query(
+            "INSERT OR IGNORE INTO expense_template_runs (household_id, template_id, period_key, expense_id, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )
+        .bind(household_id)
+        .bind(&template.id)
+        .bind(&period_key)
+        .bind(&expense_id)
+        .bind(now)
+        .execute(tx.as_mut())
+        .await
+        .map_err(map_err)?
+        .rows_affected();
+        if claimed == 0 {
+            continue;
+        }
+        sqlx::query(
+            "INSERT INTO expenses (id, category_id, amount, date, description, household_id, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)",
+        )
+        .bind(&expense_id)
+        .bind(&template.category_id)
+        .bind(template.amount)
+        .bind(expense_date_ms(first, template.day_of_month))
+        .bind(&template.description)
+        .bind(household_id)
+        .bind(now)
+        .execute(tx.as_mut())
+        .await
+        .map_err(map_err)?;
+        created += 1;
+    }
+    tx.commit().await.map_err(map_err)?;
+    Ok(created)
+}
+
+#[tauri::command]
+pub async fn expenses_generate_from_templates(
+    state: State<'_, AppState>,
+    household_id: String,
+    month: String,
+) -> AppResult<u64> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let month = month.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "expenses_generate_from_templates")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            generate_from_templates(&pool, &household_id, &month).await
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_month_accepts_only_year_and_month() {
+        assert_eq!(
+            parse_month("2024-02").unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+        );
+        for bad in ["2024-2", "2024-13", "2024", "24-02", "2024-02-01"] {
+            assert_eq!(parse_month(bad).unwrap_err().code(), INVALID_MONTH_CODE);
+        }
+    }
+
+    #[test]
+    fn expense_date_clamps_to_month_end() {
+        let feb = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2024, 2, 29)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis();
+        assert_eq!(expense_date_ms(feb, 31), expected);
+    }
+}
@@ -1,8 +1,9 @@
 use serde_json::{Map, Value};
 use sqlx::sqlite::SqliteRow;
-use sqlx::{Column, Executor, Row, Sqlite, SqlitePool, TypeInfo, ValueRef};
+use sqlx::{Column, Executor, Row, Sqlite, SqliteConnection, SqlitePool, TypeInfo, ValueRef};
 
 use crate::db::with_tx;
+use crate::deletions_audit::{record_deletion, OPERATION_SOFT_DELETE};
 use crate::time::now_ms;
 
 pub(crate) const DOMAIN_TABLES: &[&str] = &[
@@ -21,6 +22,7 @@ pub(crate) const DOMAIN_TABLES: &[&str] = &[
     "categories",
     "budget_categories",
     "expenses",
+    "expense_templates",
     "notes",
     "shopping_items",
 ];
@@ -39,9 +41,16 @@ const ORDERED_TABLES: &[&str] = &[
     "shopping_items",
 ];
 
+/// Serialize a row as a JSON object with keys in sorted column-name order.
+/// The order does not depend on the driver's column order or on whether
+/// serde_json's `preserve_order` feature is enabled somewhere in the build,
+/// so JSONL dumps and plan digests are reproducible. Integers and reals keep
+/// their numeric type; everything else is emitted as text.
 pub(crate) fn row_to_json(row: SqliteRow) -> Value {
+    let mut columns: Vec<_> = row.columns().iter().collect();
+    columns.sort_by(|a, b| a.name().cmp(b.name()));
     let mut map = Map::new();
-    for col in row.columns() {
+    for col in columns {
         let idx = col.ordinal();
         let v = row.try_get_raw(idx).ok();
         let val = match v {
@@ -87,10 +96,7 @@ pub mod notes {
         ensure_table("notes")?;
         let household_id = require_household(household_id)?.to_string();
 
-        let default_order = "z DESC, position, created_at, id";
-        let order = order_by
-            .filter(|ob| ALLOWED_ORDERS.contains(ob))
-            .unwrap_or(default_order);
+        let order = resolve_order_by(pool, "notes", order_by).await?;
 
         let (category_ids, filter_active) = match category_ids {
             Some(ids) if ids.is_empty() => return Ok(Vec::new()),
@@ -109,7 +115,7 @@ pub mod notes {
         }
 
         sql.push_str(" ORDER BY ");
-        sql.push_str(order);
+        sql.push_str(&order);
 
         if limit.is_some() {
             sql.push_str(" LIMIT ?");
@@ -154,11 +160,76 @@ pub(crate) fn require_household(id: &str) -> anyhow::Result<&str> {
     }
 }
 
-const ALLOWED_ORDERS: &[&str] = &[
-    "z DESC, position, created_at, id",
-    "position, created_at, id",
-    "created_at, id",
-];
+/// Ordering used when a caller does not supply `order_by`.
+fn default_order(table: &str) -> &'static str {
+    if table == "notes" {
+        "z DESC, position, created_at, id"
+    } else if ORDERED_TABLES.contains(&table) {
+        "position, created_at, id"
+    } else {
+        "created_at, id"
+    }
+}
+
+fn bad_order(order_by: &str, reason: &str) -> anyhow::Error {
+    anyhow::Error::new(
+        crate::AppError::new("BAD_REQUEST", format!("Invalid order_by: {reason}"))
+            .with_context("order_by", order_by.to_string()),
+    )
+}
+
+/// Validate a client supplied `order_by` against the table's columns.
+///
+/// The clause is a comma separated list of `column [ASC|DESC]` terms. Each
+/// column must exist in `PRAGMA table_info` for `table`; anything else
+/// (unknown columns, expressions, extra tokens) is rejected with
+/// `BAD_REQUEST`. A missing or blank clause yields [`default_order`].
+pub(crate) async fn resolve_order_by<'e, E>(
+    executor: E,
+    table: &str,
+    order_by: Option<&str>,
+) -> anyhow::Result<String>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    ensure_table(table)?;
+    let raw = match order_by.map(str::trim) {
+        Some(raw) if !raw.is_empty() => raw,
+        _ => return Ok(default_order(table).to_string()),
+    };
+
+    let columns: Vec<String> =
+        sqlx::query_scalar(&format!("SELECT name FROM pragma_table_info('{table}')"))
+            .fetch_all(executor)
+            .await?;
+
+    let mut terms = Vec::new();
+    for term in raw.split(',') {
+        let mut tokens = term.split_whitespace();
+        let column = tokens
+            .next()
+            .ok_or_else(|| bad_order(raw, "empty sort term"))?;
+        if !columns.iter().any(|known| known == column) {
+            return Err(bad_order(raw, &format!("unknown column `{column}`")));
+        }
+        let direction = match tokens.next() {
+            None => None,
+            Some(dir) if dir.eq_ignore_ascii_case("asc") => Some("ASC"),
+            Some(dir) if dir.eq_ignore_ascii_case("desc") => Some("DESC"),
+            Some(other) => {
+                return Err(bad_order(raw, &format!("unexpected token `{other}`")));
+            }
+        };
+        if let Some(extra) = tokens.next() {
+            return Err(bad_order(raw, &format!("unexpected token `{extra}`")));
+        }
+        terms.push(match direction {
+            Some(dir) => format!("{column} {dir}"),
+            None => column.to_string(),
+        });
+    }
+    Ok(terms.join(", "))
+}
 
 // Intentionally kept for test coverage of household scoping.
 // Suppress dead_code in non-test builds.
@@ -170,25 +241,63 @@ pub(crate) async fn list_active(
     order_by: Option<&str>,
     limit: Option<i64>,
     offset: Option<i64>,
+) -> anyhow::Result<Vec<sqlx::sqlite::SqliteRow>> {
+    list_active_in_range(pool, table, household_id, order_by, limit, offset, None).await
+}
+
+/// Inclusive window on one timestamp column, applied by
+/// [`list_active_in_range`]. Either bound may be left open.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct ListDateRange {
+    pub field: String,
+    pub from_ms: Option<i64>,
+    pub to_ms: Option<i64>,
+}
+
+impl ListDateRange {
+    /// Build a range from the loose IPC arguments. No field means no filter.
+    pub fn from_parts(
+        field: Option<String>,
+        from_ms: Option<i64>,
+        to_ms: Option<i64>,
+    ) -> Option<Self> {
+        field.map(|field| Self {
+            field,
+            from_ms,
+            to_ms,
+        })
+    }
+}
+
+/// [`list_active`] narrowed to rows whose `date_range.field` falls inside the
+/// inclusive window. The caller must have validated the field name; it is
+/// interpolated into the SQL.
+pub(crate) async fn list_active_in_range(
+    pool: &SqlitePool,
+    table: &str,
+    household_id: &str,
+    order_by: Option<&str>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    date_range: Option<&ListDateRange>,
 ) -> anyhow::Result<Vec<sqlx::sqlite::SqliteRow>> {
     ensure_table(table)?;
     let household_id = require_household(household_id)?;
-    let default_order = if table == "notes" {
-        "z DESC, position, created_at, id"
-    } else if ORDERED_TABLES.contains(&table) {
-        "position, created_at, id"
-    } else {
-        "created_at, id"
-    };
-    let order = order_by
-        .filter(|ob| ALLOWED_ORDERS.contains(ob))
-        .unwrap_or(default_order);
+    let order = resolve_order_by(pool, table, order_by).await?;
 
-    let where_clause = if table == "household" {
-        "WHERE deleted_at IS NULL AND id = ?"
+    let mut where_clause = if table == "household" {
+        "WHERE deleted_at IS NULL AND id = ?".to_string()
     } else {
-        "WHERE deleted_at IS NULL AND household_id = ?"
+        "WHERE deleted_at IS NULL AND household_id = ?".to_string()
     };
+    if let Some(range) = date_range {
+        if range.from_ms.is_some() {
+            where_clause.push_str(&format!(" AND {} >= ?", range.field));
+        }
+        if range.to_ms.is_some() {
+            where_clause.push_str(&format!(" AND {} <= ?", range.field));
+        }
+    }
     let mut sql = format!("SELECT * FROM {table} {where_clause} ORDER BY {order}");
     if limit.is_some() {
         sql.push_str(" LIMIT ?");
@@ -198,6 +307,14 @@ pub(crate) async fn list_active(
     }
 
     let mut query = sqlx::query(&sql).bind(household_id);
+    if let Some(range) = date_range {
+        if let Some(from_ms) = range.from_ms {
+            query = query.bind(from_ms);
+        }
+        if let Some(to_ms) = range.to_ms {
+            query = query.bind(to_ms);
+        }
+    }
     if let Some(l) = limit {
         query = query.bind(l);
     }
@@ -246,74 +363,132 @@ pub(crate) async fn get_active(
     Ok(row)
 }
 
+/// Upper bound on ids accepted by [`get_many_active`]; keeps the `IN (...)`
+/// list well below SQLite's bound-parameter limit.
+pub const GET_MANY_MAX_IDS: usize = 200;
+
+/// Fetch the active rows for `ids` in a single query.
+///
+/// Rows come back in the order the ids were requested. Ids that do not exist,
+/// are soft-deleted or belong to another household are left out; duplicate
+/// ids are returned once. The `household` table itself is not supported.
+pub(crate) async fn get_many_active(
+    pool: &SqlitePool,
+    table: &str,
+    household_id: &str,
+    ids: &[String],
+) -> anyhow::Result<Vec<sqlx::sqlite::SqliteRow>> {
+    ensure_table(table)?;
+    if table == "household" {
+        anyhow::bail!("invalid table");
+    }
+    let household_id = require_household(household_id)?;
+    if ids.len() > GET_MANY_MAX_IDS {
+        return Err(anyhow::Error::new(
+            crate::AppError::new(
+                "BAD_REQUEST",
+                format!("Too many ids requested (max {GET_MANY_MAX_IDS})"),
+            )
+            .with_context("requested", ids.len().to_string()),
+        ));
+    }
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let placeholders = vec!["?"; ids.len()].join(", ");
+    let sql = format!(
+        "SELECT * FROM {table} WHERE household_id = ? AND id IN ({placeholders}) AND deleted_at IS NULL",
+    );
+    let mut query = sqlx::query(&sql).bind(household_id);
+    for id in ids {
+        query = query.bind(id);
+    }
+    let rows = query.fetch_all(pool).await?;
+
+    let mut by_id: std::collections::HashMap<String, sqlx::sqlite::SqliteRow> = rows
+        .into_iter()
+        .filter_map(|row| {
+            let id: String = row.try_get("id").ok()?;
+            Some((id, row))
+        })
+        .collect();
+    Ok(ids.iter().filter_map(|id| by_id.remove(id)).collect())
+}
+
 // TXN: domain=OUT OF SCOPE tables=*
 pub async fn set_deleted_at(
     pool: &SqlitePool,
     table: &str,
     household_id: &str,
     id: &str,
+) -> anyhow::Result<()> {
+    let mut tx = pool.begin().await?;
+    set_deleted_at_in(&mut tx, table, household_id, id).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// [`set_deleted_at`] on the caller's connection, for callers that hide
+/// dependent rows in the same transaction.
+// TXN: domain=OUT OF SCOPE tables=*
+pub async fn set_deleted_at_in(
+    conn: &mut SqliteConnection,
+    table: &str,
+    household_id: &str,
+    id: &str,
 ) -> anyhow::Result<()> {
     ensure_table(table)?;
     let household_id = require_household(household_id)?;
     let now = now_ms();
-    if table != "household" && ORDERED_TABLES.contains(&table) {
-        let household_id = household_id.to_string();
-        let id = id.to_string();
-        let table = table.to_string();
-        with_tx(pool, |tx| {
-            Box::pin(async move {
-                let tx: &mut sqlx::Transaction<'_, sqlx::Sqlite> = tx;
-                let sql = format!(
-                    "UPDATE {table} SET deleted_at = ?, updated_at = ? WHERE household_id = ? AND id = ?",
-                );
-                let res = tx
-                    .execute(
-                        sqlx::query(&sql)
-                            .bind(now)
-                            .bind(now)
-                            .bind(&household_id)
-                            .bind(&id),
-                    )
-                    .await?;
-                if res.rows_affected() == 0 {
-                    anyhow::bail!("id not found");
-                }
-                renumber_positions(&mut **tx, &table, &household_id).await?;
-                Ok(())
-            })
-        })
-        .await
+    let res = if table == "household" {
+        let sql = format!("UPDATE {table} SET deleted_at = ?, updated_at = ? WHERE id = ?");
+        sqlx::query(&sql)
+            .bind(now)
+            .bind(now)
+            .bind(id)
+            .execute(&mut *conn)
+            .await?
     } else {
-        let res = if table == "household" {
-            let sql = format!("UPDATE {table} SET deleted_at = ?, updated_at = ? WHERE id = ?");
-            sqlx::query(&sql)
-                .bind(now)
-                .bind(now)
-                .bind(id)
-                .execute(pool)
-                .await?
-        } else {
-            let sql = format!(
-                "UPDATE {table} SET deleted_at = ?, updated_at = ? WHERE household_id = ? AND id = ?",
-            );
-            sqlx::query(&sql)
-                .bind(now)
-                .bind(now)
-                .bind(household_id)
-                .bind(id)
-                .execute(pool)
-                .await?
-        };
-        if res.rows_affected() == 0 {
-            anyhow::bail!("id not found");
-        }
-        Ok(())
+        let sql = format!(
+            "UPDATE {table} SET deleted_at = ?, updated_at = ? WHERE household_id = ? AND id = ?",
+        );
+        sqlx::query(&sql)
+            .bind(now)
+            .bind(now)
+            .bind(household_id)
+            .bind(id)
+            .execute(&mut *conn)
+            .await?
+    };
+    if res.rows_affected() == 0 {
+        anyhow::bail!("id not found");
     }
+    if table != "household" && ORDERED_TABLES.contains(&table) {
+        renumber_positions(&mut *conn, table, household_id).await?;
+    }
+    let audit_household = if table == "household" {
+        id
+    } else {
+        household_id
+    };
+    record_deletion(
+        &mut *conn,
+        table,
+        audit_household,
+        id,
+        OPERATION_SOFT_DELETE,
+        now,
+    )
+    .await?;
+    Ok(())
 }
 
+/// Undo [`set_deleted_at`] on the caller's connection. A restored ordered
+/// row goes to the end of its household's list.
 // TXN: domain=OUT OF SCOPE tables=*
-pub async fn clear_deleted_at(
-    pool: &SqlitePool,
+pub async fn clear_deleted_at_in(
+    conn: &mut SqliteConnection,
     table: &str,
     household_id: &str,
     id: &str,
@@ -321,54 +496,48 @@ pub async fn clear_deleted_at(
     ensure_table(table)?;
     let household_id = require_household(household_id)?;
     let now = now_ms();
-    if table != "household" && ORDERED_TABLES.contains(&table) {
-        let household_id = household_id.to_string();
-        let id = id.to_string();
-        let table = table.to_string();
-        with_tx(pool, |tx| {
-            Box::pin(async move {
-                let tx: &mut sqlx::Transaction<'_, sqlx::Sqlite> = tx;
-                let sql = format!(
-                    "UPDATE {table} SET deleted_at = NULL, position = position + 1000000, updated_at = ? WHERE household_id = ? AND id = ?",
-                );
-                let res = tx
-                    .execute(sqlx::query(&sql).bind(now).bind(&household_id).bind(&id))
-                    .await?;
-                if res.rows_affected() == 0 {
-                    anyhow::bail!("id not found");
-                }
-                renumber_positions(&mut **tx, &table, &household_id).await?;
-                Ok(())
-            })
-        })
-        .await
+    let res = if table == "household" {
+        let sql = format!("UPDATE {table} SET deleted_at = NULL, updated_at = ? WHERE id = ?");
+        sqlx::query(&sql)
+            .bind(now)
+            .bind(id)
+            .execute(&mut *conn)
+            .await?
+    } else if ORDERED_TABLES.contains(&table) {
+        // Park the restored row past the live ones so the renumber appends it.
+        let sql = format!(
+            "UPDATE {table} SET deleted_at = NULL, position = position + 1000000, updated_at = ? WHERE household_id = ? AND id = ?",
+        );
+        sqlx::query(&sql)
+            .bind(now)
+            .bind(household_id)
+            .bind(id)
+            .execute(&mut *conn)
+            .await?
     } else {
-        let res = if table == "household" {
-            let sql = format!("UPDATE {table} SET deleted_at = NULL, updated_at = ? WHERE id = ?");
-            sqlx::query(&sql).bind(now).bind(id).execute(pool).await?
-        } else {
-            let sql = format!(
-                "UPDATE {table} SET deleted_at = NULL, position = position + 1000000, updated_at = ? WHERE household_id = ? AND id = ?",
-            );
-            sqlx::query(&sql)
-                .bind(now)
-                .bind(household_id)
-                .bind(id)
-                .execute(pool)
-                .await?
-        };
-        if res.rows_affected() == 0 {
-            anyhow::bail!("id not found");
-        }
-        if table != "household" && ORDERED_TABLES.contains(&table) {
-            renumber_positions(pool, table, household_id).await?;
-        }
-        Ok(())
+        // Unordered tables (events, expenses, ...) have no position column.
+        let sql = format!(
+            "UPDATE {table} SET deleted_at = NULL, updated_at = ? WHERE household_id = ? AND id = ?",
+        );
+        sqlx::query(&sql)
+            .bind(now)
+            .bind(household_id)
+            .bind(id)
+            .execute(&mut *conn)
+            .await?
+    };
+    if res.rows_affected() == 0 {
+        anyhow::bail!("id not found");
+    }
+    if table != "household" && ORDERED_TABLES.contains(&table) {
+        renumber_positions(&mut *conn, table, household_id).await?;
     }
+    Ok(())
 }
 
 pub mod items {
     use super::{ensure_table, require_household};
+    use crate::deletions_audit::{record_deletion, OPERATION_SOFT_DELETE};
     use crate::{db::with_tx, time::now_ms};
     use sqlx::{Executor, SqlitePool};
 
@@ -422,6 +591,15 @@ pub mod items {
                 );
                 tx.execute(sqlx::query::<sqlx::Sqlite>(&renumber_sql).bind(&household_id))
                     .await?;
+                record_deletion(
+                    &mut **tx,
+                    &table,
+                    &household_id,
+                    &id,
+                    OPERATION_SOFT_DELETE,
+                    now,
+                )
+                .await?;
                 Ok(())
             })
         })
@@ -625,16 +803,7 @@ pub mod admin {
         offset: Option<i64>,
     ) -> anyhow::Result<Vec<SqliteRow>> {
         ensure_table(table)?;
-        let default_order = if table == "notes" {
-            "z DESC, position, created_at, id"
-        } else if ORDERED_TABLES.contains(&table) {
-            "position, created_at, id"
-        } else {
-            "created_at, id"
-        };
-        let order = order_by
-            .filter(|ob| ALLOWED_ORDERS.contains(ob))
-            .unwrap_or(default_order);
+        let order = resolve_order_by(pool, table, order_by).await?;
         let mut sql = format!("SELECT * FROM {table} WHERE deleted_at IS NULL ORDER BY {order}");
         if limit.is_some() {
             sql.push_str(" LIMIT ?");
@@ -669,11 +838,21 @@ mod tests {
     use sqlx::sqlite::SqlitePoolOptions;
     use sqlx::{Row, SqlitePool};
 
+    async fn create_deletions_audit(pool: &SqlitePool) {
+        sqlx::query(
+            "CREATE TABLE deletions_audit (id INTEGER PRIMARY KEY, household_id TEXT NOT NULL, table_name TEXT NOT NULL, row_id TEXT NOT NULL, operation TEXT NOT NULL, deleted_at INTEGER NOT NULL)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
     async fn setup_db() -> SqlitePool {
         let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
         sqlx::query(
             "CREATE TABLE events (id TEXT PRIMARY KEY, household_id TEXT NOT NULL, deleted_at INTEGER, created_at INTEGER, updated_at INTEGER)"
         ).execute(&pool).await.unwrap();
+        create_deletions_audit(&pool).await;
         pool
     }
 
@@ -736,11 +915,27 @@ mod tests {
         assert_eq!(rows.len(), 1);
     }
 
+    #[tokio::test]
+    async fn row_to_json_sorts_keys_and_keeps_types() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let sql = "SELECT 'x' AS zeta, 42 AS alpha, 1.5 AS mid, NULL AS beta, '7' AS gamma";
+        let first = row_to_json(sqlx::query(sql).fetch_one(&pool).await.unwrap());
+        let second = row_to_json(sqlx::query(sql).fetch_one(&pool).await.unwrap());
+
+        let first_bytes = serde_json::to_vec(&first).unwrap();
+        assert_eq!(first_bytes, serde_json::to_vec(&second).unwrap());
+        assert_eq!(
+            String::from_utf8(first_bytes).unwrap(),
+            r#"{"alpha":42,"beta":null,"gamma":"7","mid":1.5,"zeta":"x"}"#
+        );
+    }
+
     async fn setup_ordered_db() -> SqlitePool {
         let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
         sqlx::query(
             "CREATE TABLE bills (id TEXT PRIMARY KEY, household_id TEXT NOT NULL, position INTEGER NOT NULL, deleted_at INTEGER, created_at INTEGER, updated_at INTEGER)"
         ).execute(&pool).await.unwrap();
+        create_deletions_audit(&pool).await;
         pool
     }
 
@@ -818,6 +1013,7 @@ mod tests {
         .execute(&pool)
         .await
         .unwrap();
+        create_deletions_audit(&pool).await;
         pool
     }
 
@@ -839,7 +1035,9 @@ mod tests {
         assert_eq!(id, "b");
         assert_eq!(pos, 0); // renumbered
 
-        clear_deleted_at(&pool, "notes", "H", "a").await.unwrap();
+        clear_deleted_at_in(&mut pool.acquire().await.unwrap(), "notes", "H", "a")
+            .await
+            .unwrap();
         let rows = list_active(
             &pool,
             "notes",
@@ -913,6 +1111,67 @@ mod tests {
         assert_eq!(ids, vec![("a".into(), 0), ("b".into(), 1)]);
     }
 
+    async fn seed_sortable_bills(pool: &SqlitePool) {
+        sqlx::query(
+            "INSERT INTO bills (id, household_id, position, created_at, updated_at) VALUES ('a','A',1,10,0), ('b','A',0,30,0), ('c','A',2,20,0)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    fn ids(rows: &[SqliteRow]) -> Vec<String> {
+        rows.iter().map(|r| r.try_get("id").unwrap()).collect()
+    }
+
+    #[tokio::test]
+    async fn list_active_honours_valid_sort() {
+        let pool = setup_ordered_db().await;
+        seed_sortable_bills(&pool).await;
+        let rows = list_active(&pool, "bills", "A", Some("created_at desc, id"), None, None)
+            .await
+            .unwrap();
+        assert_eq!(ids(&rows), vec!["b", "c", "a"]);
+    }
+
+    #[tokio::test]
+    async fn list_active_uses_table_default_sort() {
+        let pool = setup_ordered_db().await;
+        seed_sortable_bills(&pool).await;
+        let rows = list_active(&pool, "bills", "A", None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(ids(&rows), vec!["b", "a", "c"]);
+        assert_eq!(
+            resolve_order_by(&pool, "bills", Some("  ")).await.unwrap(),
+            "position, created_at, id"
+        );
+    }
+
+    #[tokio::test]
+    async fn list_active_rejects_unknown_sort_columns() {
+        let pool = setup_ordered_db().await;
+        seed_sortable_bills(&pool).await;
+        for order in [
+            "id; DROP TABLE bills",
+            "(SELECT 1)",
+            "id DESC NULLS FIRST",
+            "amount",
+            "id,",
+        ] {
+            let Err(err) = list_active(&pool, "bills", "A", Some(order), None, None).await else {
+                panic!("order {order:?} should be rejected");
+            };
+            let app = crate::AppError::from(err);
+            assert_eq!(app.code(), "BAD_REQUEST", "order {order:?}");
+        }
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM bills")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 3);
+    }
+
     #[tokio::test]
     async fn restore_renumbers_bills() {
         let pool = setup_ordered_db().await;
@@ -922,7 +1181,9 @@ mod tests {
             .unwrap();
 
         set_deleted_at(&pool, "bills", "A", "b").await.unwrap();
-        clear_deleted_at(&pool, "bills", "A", "b").await.unwrap();
+        clear_deleted_at_in(&mut pool.acquire().await.unwrap(), "bills", "A", "b")
+            .await
+            .unwrap();
 
         let rows = list_active(
             &pool,
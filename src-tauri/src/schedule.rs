@@ -0,0 +1,225 @@
+//! Scheduling queries built on top of expanded event occurrences.
+//!
+//! Recurring events are expanded through [`commands::events_list_range_command`]
+//! so conflicts are detected per occurrence rather than per series row.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use ts_rs::TS;
+
+use crate::{commands, model_family::VALIDATION_MEMBER_MISSING, AppError, AppResult, Event};
+
+#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct EventConflict {
+    pub first: Event,
+    pub second: Event,
+    #[ts(type = "number")]
+    pub overlap_start_utc: i64,
+    #[ts(type = "number")]
+    pub overlap_end_utc: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct EventConflictsResponse {
+    #[serde(default)]
+    pub conflicts: Vec<EventConflict>,
+    /// True when the underlying range expansion hit its occurrence limit.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct BusyInterval {
+    #[ts(type = "number")]
+    pub start_utc: i64,
+    #[ts(type = "number")]
+    pub end_utc: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, TS, Debug)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct MemberBusyResponse {
+    pub member_id: String,
+    #[serde(default)]
+    pub busy: Vec<BusyInterval>,
+    /// True when the underlying range expansion hit its occurrence limit.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// Start/end bounds of an occurrence; events without an end are instants.
+fn occurrence_bounds(event: &Event) -> (i64, i64) {
+    let start = event.start_at_utc;
+    (start, event.end_at_utc.unwrap_or(start).max(start))
+}
+
+/// Identifier of the stored event row an occurrence was expanded from.
+fn series_id(event: &Event) -> &str {
+    event.series_parent_id.as_deref().unwrap_or(&event.id)
+}
+
+/// Event ids a family member attends, either explicitly via `event_attendees`
+/// or through notes owned by that member.
+pub(crate) async fn member_event_ids(
+    pool: &SqlitePool,
+    household_id: &str,
+    member_id: &str,
+) -> AppResult<HashSet<String>> {
+    let ids: Vec<String> = sqlx::query_scalar(
+        "SELECT nl.entity_id
+           FROM note_links nl
+           JOIN notes n ON n.id = nl.note_id
+          WHERE nl.household_id = ?1
+            AND nl.entity_type = 'event'
+            AND nl.deleted_at IS NULL
+            AND n.member_id = ?2
+            AND n.deleted_at IS NULL
+         UNION
+         SELECT event_id
+           FROM event_attendees
+          WHERE household_id = ?1
+            AND member_id = ?2",
+    )
+    .bind(household_id)
+    .bind(member_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "member_event_ids")
+            .with_context("household_id", household_id.to_string())
+            .with_context("member_id", member_id.to_string())
+    })?;
+    Ok(ids.into_iter().collect())
+}
+
+/// Expand events in `[start, end]`, optionally keeping only those linked to a member.
+pub(crate) async fn expanded_occurrences(
+    pool: &SqlitePool,
+    household_id: &str,
+    start: i64,
+    end: i64,
+    member_id: Option<&str>,
+) -> AppResult<(Vec<Event>, bool)> {
+    let response = commands::events_list_range_command(pool, household_id, start, end).await?;
+    let mut items = response.items;
+    if let Some(member_id) = member_id {
+        let linked = member_event_ids(pool, household_id, member_id).await?;
+        items.retain(|event| linked.contains(series_id(event)));
+    }
+    Ok((items, response.truncated))
+}
+
+/// Pair up occurrences whose time ranges strictly overlap.
+///
+/// Ranges are half-open, so an event ending exactly when another starts is not
+/// a conflict, and events without a duration never conflict.
+pub fn find_overlaps(events: &[Event]) -> Vec<EventConflict> {
+    let mut ordered: Vec<&Event> = events.iter().collect();
+    ordered.sort_by(|a, b| {
+        occurrence_bounds(a)
+            .cmp(&occurrence_bounds(b))
+            .then(a.id.cmp(&b.id))
+    });
+
+    let mut conflicts = Vec::new();
+    for (index, first) in ordered.iter().enumerate() {
+        let (first_start, first_end) = occurrence_bounds(first);
+        for second in &ordered[index + 1..] {
+            let (second_start, second_end) = occurrence_bounds(second);
+            if second_start >= first_end {
+                break;
+            }
+            let overlap_start = first_start.max(second_start);
+            let overlap_end = first_end.min(second_end);
+            if first.id == second.id || overlap_start >= overlap_end {
+                continue;
+            }
+            conflicts.push(EventConflict {
+                first: (*first).clone(),
+                second: (*second).clone(),
+                overlap_start_utc: overlap_start,
+                overlap_end_utc: overlap_end,
+            });
+        }
+    }
+    conflicts
+}
+
+pub async fn find_event_conflicts(
+    pool: &SqlitePool,
+    household_id: &str,
+    start: i64,
+    end: i64,
+    member_id: Option<&str>,
+) -> AppResult<EventConflictsResponse> {
+    let (occurrences, truncated) =
+        expanded_occurrences(pool, household_id, start, end, member_id).await?;
+    Ok(EventConflictsResponse {
+        conflicts: find_overlaps(&occurrences),
+        truncated,
+    })
+}
+
+/// Coalesce occurrences into contiguous busy blocks clipped to `[start, end]`.
+///
+/// Overlapping or touching occurrences merge into a single interval; events
+/// without a duration do not occupy any time.
+pub fn merge_busy(events: &[Event], start: i64, end: i64) -> Vec<BusyInterval> {
+    let mut bounds: Vec<(i64, i64)> = events
+        .iter()
+        .map(occurrence_bounds)
+        .map(|(s, e)| (s.max(start), e.min(end)))
+        .filter(|(s, e)| s < e)
+        .collect();
+    bounds.sort_unstable();
+
+    let mut merged: Vec<BusyInterval> = Vec::new();
+    for (s, e) in bounds {
+        match merged.last_mut() {
+            Some(last) if s <= last.end_utc => last.end_utc = last.end_utc.max(e),
+            _ => merged.push(BusyInterval {
+                start_utc: s,
+                end_utc: e,
+            }),
+        }
+    }
+    merged
+}
+
+pub async fn family_member_busy(
+    pool: &SqlitePool,
+    member_id: &str,
+    start: i64,
+    end: i64,
+) -> AppResult<MemberBusyResponse> {
+    let household_id: Option<String> = sqlx::query_scalar(
+        "SELECT household_id FROM family_members WHERE id = ?1 AND deleted_at IS NULL",
+    )
+    .bind(member_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| {
+        AppError::from(err)
+            .with_context("operation", "family_member_busy")
+            .with_context("member_id", member_id.to_string())
+    })?;
+    let household_id = household_id.ok_or_else(|| {
+        AppError::new(VALIDATION_MEMBER_MISSING, "Member record not found.")
+            .with_context("operation", "family_member_busy")
+            .with_context("member_id", member_id.to_string())
+    })?;
+
+    let (occurrences, truncated) =
+        expanded_occurrences(pool, &household_id, start, end, Some(member_id)).await?;
+    Ok(MemberBusyResponse {
+        member_id: member_id.to_string(),
+        busy: merge_busy(&occurrences, start, end),
+        truncated,
+    })
+}
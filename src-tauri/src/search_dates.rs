@@ -0,0 +1,172 @@
+//! Date phrases in search queries. Recognises ISO date fragments (`2024`,
+//! `2024-06`, `2024-06-14`) and a small set of relative phrases, and turns
+//! them into a range of local calendar dates for the events branch. Anything
+//! else is left to title matching.
+
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+/// Local calendar dates `[from, until)` named by `query`, relative to `today`.
+///
+/// A bare weekday or `this <weekday>` is the coming one, today included;
+/// `next <weekday>` is the first one after today and `last <weekday>` the
+/// most recent one before it. Weeks start on Monday, matching the calendar
+/// views' default.
+pub(crate) fn parse_date_query(query: &str, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    let phrase = query.trim().to_ascii_lowercase();
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+    if let Some(range) = parse_iso_fragment(&phrase) {
+        return Some(range);
+    }
+
+    let day = |date: NaiveDate| Some((date, date.succ_opt()?));
+    match phrase.as_str() {
+        "today" => return day(today),
+        "tomorrow" => return day(today.succ_opt()?),
+        "yesterday" => return day(today.pred_opt()?),
+        _ => {}
+    }
+
+    let (modifier, unit) = match phrase.split_once(' ') {
+        Some((modifier, unit)) => (Some(modifier), unit),
+        None => (None, phrase.as_str()),
+    };
+    let offset = match modifier {
+        None | Some("this") => 0,
+        Some("next") => 1,
+        Some("last") => -1,
+        Some(_) => return None,
+    };
+
+    match unit {
+        "week" if modifier.is_some() => {
+            let monday = today.week(Weekday::Mon).first_day();
+            let from = monday.checked_add_signed(Duration::weeks(offset))?;
+            Some((from, from.checked_add_signed(Duration::weeks(1))?))
+        }
+        "month" if modifier.is_some() => {
+            let first = today.with_day(1)?;
+            let from = match offset {
+                1 => first.checked_add_months(Months::new(1))?,
+                -1 => first.checked_sub_months(Months::new(1))?,
+                _ => first,
+            };
+            Some((from, from.checked_add_months(Months::new(1))?))
+        }
+        _ => {
+            let weekday = parse_weekday(unit)?;
+            let ahead = i64::from(
+                (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7,
+            );
+            let days = match offset {
+                1 if ahead == 0 => 7,
+                -1 => ahead - 7,
+                _ => ahead,
+            };
+            day(today.checked_add_signed(Duration::days(days))?)
+        }
+    }
+}
+
+fn parse_iso_fragment(phrase: &str) -> Option<(NaiveDate, NaiveDate)> {
+    let parts: Vec<&str> = phrase.split('-').collect();
+    let widths_ok = parts
+        .iter()
+        .zip([4, 2, 2])
+        .all(|(part, width)| part.len() == width && part.bytes().all(|b| b.is_ascii_digit()));
+    if parts.len() > 3 || !widths_ok {
+        return None;
+    }
+    let year: i32 = parts[0].parse().ok()?;
+    match parts.as_slice() {
+        [_] => Some((
+            NaiveDate::from_ymd_opt(year, 1, 1)?,
+            NaiveDate::from_ymd_opt(year + 1, 1, 1)?,
+        )),
+        [_, month] => {
+            let from = NaiveDate::from_ymd_opt(year, month.parse().ok()?, 1)?;
+            Some((from, from.checked_add_months(Months::new(1))?))
+        }
+        [_, month, day] => {
+            let from = NaiveDate::from_ymd_opt(year, month.parse().ok()?, day.parse().ok()?)?;
+            Some((from, from.succ_opt()?))
+        }
+        _ => None,
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    Some(match word {
+        "monday" | "mon" => Weekday::Mon,
+        "tuesday" | "tue" | "tues" => Weekday::Tue,
+        "wednesday" | "wed" => Weekday::Wed,
+        "thursday" | "thu" | "thurs" => Weekday::Thu,
+        "friday" | "fri" => Weekday::Fri,
+        "saturday" | "sat" => Weekday::Sat,
+        "sunday" | "sun" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // 2024-06-12 is a Wednesday.
+    fn today() -> NaiveDate {
+        date(2024, 6, 12)
+    }
+
+    #[test]
+    fn parses_iso_fragments() {
+        assert_eq!(
+            parse_date_query("2024", today()),
+            Some((date(2024, 1, 1), date(2025, 1, 1)))
+        );
+        assert_eq!(
+            parse_date_query("2024-06", today()),
+            Some((date(2024, 6, 1), date(2024, 7, 1)))
+        );
+        assert_eq!(
+            parse_date_query(" 2024-02-29 ", today()),
+            Some((date(2024, 2, 29), date(2024, 3, 1)))
+        );
+        for invalid in ["2024-13", "2023-02-29", "24-06", "2024-6", "2024-06-01-01"] {
+            assert_eq!(parse_date_query(invalid, today()), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn parses_relative_phrases() {
+        assert_eq!(
+            parse_date_query("Tomorrow", today()),
+            Some((date(2024, 6, 13), date(2024, 6, 14)))
+        );
+        assert_eq!(
+            parse_date_query("next  week", today()),
+            Some((date(2024, 6, 17), date(2024, 6, 24)))
+        );
+        assert_eq!(
+            parse_date_query("last month", today()),
+            Some((date(2024, 5, 1), date(2024, 6, 1)))
+        );
+        assert_eq!(
+            parse_date_query("friday", today()),
+            Some((date(2024, 6, 14), date(2024, 6, 15)))
+        );
+        assert_eq!(
+            parse_date_query("next wednesday", today()),
+            Some((date(2024, 6, 19), date(2024, 6, 20)))
+        );
+        assert_eq!(
+            parse_date_query("last fri", today()),
+            Some((date(2024, 6, 7), date(2024, 6, 8)))
+        );
+        for other in ["week", "someday", "next dentist", "friday club"] {
+            assert_eq!(parse_date_query(other, today()), None, "{other}");
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Accent folding for search. Mirrors the `text_norm`/`title_norm` triggers
+//! installed by `0030_search_norm`, so a folded query can be matched against
+//! the shadow columns with a plain `LIKE`.
+
+use std::ops::RangeInclusive;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Precomposed letters folded by the triggers: Latin-1 Supplement and Latin
+/// Extended-A.
+pub(crate) const FOLDED_RANGE: RangeInclusive<char> = '\u{C0}'..='\u{17F}';
+
+/// Combining marks the triggers strip from already-decomposed text.
+pub(crate) const STRIPPED_MARKS: [char; 13] = [
+    '\u{300}', '\u{301}', '\u{302}', '\u{303}', '\u{304}', '\u{306}', '\u{307}', '\u{308}',
+    '\u{30A}', '\u{30B}', '\u{30C}', '\u{327}', '\u{328}',
+];
+
+/// Append the folded form of `ch` to `out`: NFD with combining marks removed
+/// for letters in the folded range, then ASCII lowercase.
+pub(crate) fn fold_char_into(ch: char, out: &mut String) {
+    if STRIPPED_MARKS.contains(&ch) {
+        return;
+    }
+    if FOLDED_RANGE.contains(&ch) {
+        for part in std::iter::once(ch).nfd() {
+            if !is_combining_mark(part) {
+                out.push(part.to_ascii_lowercase());
+            }
+        }
+    } else {
+        out.push(ch.to_ascii_lowercase());
+    }
+}
+
+/// Fold `text` the same way the shadow-column triggers do.
+pub fn fold_search_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        fold_char_into(ch, &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_accents_and_case() {
+        assert_eq!(fold_search_text("café"), "cafe");
+        assert_eq!(fold_search_text("CAFÉ"), "cafe");
+        assert_eq!(fold_search_text("cafe\u{301}"), "cafe");
+        assert_eq!(fold_search_text("Ñandú Żywiec"), "nandu zywiec");
+    }
+
+    #[test]
+    fn leaves_unmapped_letters_alone() {
+        assert_eq!(fold_search_text("Æsir straße"), "Æsir straße");
+        assert_eq!(fold_search_text("Ωmega"), "Ωmega");
+    }
+}
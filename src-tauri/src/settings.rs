@@ -0,0 +1,321 @@
+//! Typed application settings persisted through the app store.
+//!
+//! Settings are stored as a single JSON document under [`SETTINGS_KEY`].
+//! Missing or unreadable documents fall back to [`Settings::default`], and
+//! updates are applied as partial patches that are validated before they are
+//! written back.
+
+use std::sync::RwLock;
+
+use chrono_tz::Tz;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use ts_rs::TS;
+
+use crate::household_active::StoreHandle;
+use crate::{AppError, AppResult};
+
+/// Store key holding the serialized settings document.
+pub const SETTINGS_KEY: &str = "settings";
+/// Event emitted with the full [`Settings`] after a successful update.
+pub const EVENT_SETTINGS_CHANGED: &str = "settings:changed";
+
+pub const SETTINGS_INVALID_BACKUP_INTERVAL_CODE: &str = "SETTINGS/INVALID_BACKUP_INTERVAL";
+pub const SETTINGS_INVALID_HORIZON_CODE: &str = "SETTINGS/INVALID_HORIZON";
+pub const SETTINGS_INVALID_VAULT_ROOT_CODE: &str = "SETTINGS/INVALID_VAULT_ROOT";
+pub const SETTINGS_INVALID_LOG_LEVEL_CODE: &str = "SETTINGS/INVALID_LOG_LEVEL";
+pub const SETTINGS_INVALID_TIMEZONE_CODE: &str = "SETTINGS/INVALID_TIMEZONE";
+pub const SETTINGS_PERSIST_FAILED_CODE: &str = "SETTINGS/PERSIST_FAILED";
+/// Zone assumed for events stored without one until settings say otherwise.
+pub const DEFAULT_FALLBACK_TIMEZONE: &str = "Europe/London";
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+/// Longest horizon the dashboard accepts (one year).
+const MAX_HORIZON_MS: i64 = 366 * DAY_MS;
+/// Backups may run at most hourly and at least every 30 days.
+const BACKUP_INTERVAL_HOURS_RANGE: std::ops::RangeInclusive<u32> = 1..=24 * 30;
+const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+static FALLBACK_TIMEZONE: Lazy<RwLock<String>> =
+    Lazy::new(|| RwLock::new(DEFAULT_FALLBACK_TIMEZONE.to_string()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum Theme {
+    System,
+    Light,
+    Dark,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase", default)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct Settings {
+    pub theme: Theme,
+    /// Window used by the upcoming/due views, in milliseconds.
+    pub default_horizon_ms: i64,
+    /// Hours between scheduled backups.
+    pub backup_interval_hours: u32,
+    /// Override for the attachments vault location. `None` keeps the default
+    /// under the app data directory.
+    pub vault_root: Option<String>,
+    /// Level for the `arklowdun` target, applied at startup and on update.
+    /// Other targets keep whatever the active filter gives them.
+    pub log_level: String,
+    /// IANA zone assumed for events stored without a timezone.
+    pub fallback_timezone: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: Theme::System,
+            default_horizon_ms: 30 * DAY_MS,
+            backup_interval_hours: 24,
+            vault_root: None,
+            log_level: "info".to_string(),
+            fallback_timezone: DEFAULT_FALLBACK_TIMEZONE.to_string(),
+        }
+    }
+}
+
+/// Partial update for [`Settings`]. Omitted fields keep their current value;
+/// `vaultRoot: null` clears the override.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, TS)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct SettingsPatch {
+    #[serde(default)]
+    #[ts(optional)]
+    pub theme: Option<Theme>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub default_horizon_ms: Option<i64>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub backup_interval_hours: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[ts(optional, type = "string | null")]
+    pub vault_root: Option<Option<String>>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub log_level: Option<String>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub fallback_timezone: Option<String>,
+}
+
+/// Distinguish an explicit `null` (`Some(None)`) from an omitted field (`None`).
+fn deserialize_nullable<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer).map(Some)
+}
+
+impl Settings {
+    /// Apply `patch` on top of `self` without validating the result.
+    pub fn merged(&self, patch: &SettingsPatch) -> Settings {
+        let mut next = self.clone();
+        if let Some(theme) = patch.theme {
+            next.theme = theme;
+        }
+        if let Some(horizon) = patch.default_horizon_ms {
+            next.default_horizon_ms = horizon;
+        }
+        if let Some(hours) = patch.backup_interval_hours {
+            next.backup_interval_hours = hours;
+        }
+        if let Some(root) = &patch.vault_root {
+            next.vault_root = root.clone();
+        }
+        if let Some(level) = &patch.log_level {
+            next.log_level = level.trim().to_ascii_lowercase();
+        }
+        if let Some(tz) = &patch.fallback_timezone {
+            next.fallback_timezone = tz.trim().to_string();
+        }
+        next
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> AppResult<()> {
+        if !(0..=MAX_HORIZON_MS).contains(&self.default_horizon_ms) {
+            return Err(AppError::new(
+                SETTINGS_INVALID_HORIZON_CODE,
+                "Default horizon must be between 0 and 366 days.",
+            )
+            .with_context("default_horizon_ms", self.default_horizon_ms.to_string()));
+        }
+        if !BACKUP_INTERVAL_HOURS_RANGE.contains(&self.backup_interval_hours) {
+            return Err(AppError::new(
+                SETTINGS_INVALID_BACKUP_INTERVAL_CODE,
+                "Backup interval must be between 1 hour and 30 days.",
+            )
+            .with_context(
+                "backup_interval_hours",
+                self.backup_interval_hours.to_string(),
+            ));
+        }
+        if let Some(root) = &self.vault_root {
+            if root.trim().is_empty() || !std::path::Path::new(root).is_absolute() {
+                return Err(AppError::new(
+                    SETTINGS_INVALID_VAULT_ROOT_CODE,
+                    "Vault root must be an absolute path.",
+                )
+                .with_context("vault_root", root.clone()));
+            }
+        }
+        if !LOG_LEVELS.contains(&self.log_level.as_str()) {
+            return Err(AppError::new(
+                SETTINGS_INVALID_LOG_LEVEL_CODE,
+                "Log level must be one of error, warn, info, debug or trace.",
+            )
+            .with_context("log_level", self.log_level.clone()));
+        }
+        parse_timezone(&self.fallback_timezone)?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_timezone(name: &str) -> AppResult<Tz> {
+    name.parse::<Tz>().map_err(|_| {
+        AppError::new(
+            SETTINGS_INVALID_TIMEZONE_CODE,
+            "Fallback timezone must be an IANA zone such as Europe/London.",
+        )
+        .with_context("fallback_timezone", name.to_string())
+    })
+}
+
+/// Zone assumed for events stored without one. Reads the process-wide value
+/// set by [`set_fallback_timezone`], so queries never touch the store.
+pub fn fallback_timezone() -> String {
+    FALLBACK_TIMEZONE
+        .read()
+        .map(|tz| tz.clone())
+        .unwrap_or_else(|_| DEFAULT_FALLBACK_TIMEZONE.to_string())
+}
+
+/// Replace the process-wide fallback zone after checking it names a real
+/// zone. Called once at startup with the stored setting and again when
+/// `settings_update` changes it.
+#[allow(clippy::result_large_err)]
+pub fn set_fallback_timezone(name: &str) -> AppResult<()> {
+    let tz = parse_timezone(name.trim())?;
+    let mut current = FALLBACK_TIMEZONE.write().unwrap_or_else(|e| e.into_inner());
+    *current = tz.name().to_string();
+    Ok(())
+}
+
+/// Read the persisted settings, falling back to defaults when nothing has
+/// been stored yet or the stored document no longer parses.
+pub fn load_settings(store: &StoreHandle) -> Settings {
+    let Some(raw) = store.get(SETTINGS_KEY) else {
+        return Settings::default();
+    };
+    match serde_json::from_str::<Settings>(&raw) {
+        Ok(settings) => settings,
+        Err(err) => {
+            warn!(
+                target: "arklowdun",
+                event = "settings_parse_failed",
+                error = %err
+            );
+            Settings::default()
+        }
+    }
+}
+
+/// Merge `patch` into the stored settings, validate and persist the result.
+#[allow(clippy::result_large_err)]
+pub fn update_settings(store: &StoreHandle, patch: &SettingsPatch) -> AppResult<Settings> {
+    let next = load_settings(store).merged(patch);
+    next.validate()?;
+    let raw = serde_json::to_string(&next)
+        .map_err(|err| AppError::from(err).with_context("operation", "settings_serialize"))?;
+    store.set_and_save(SETTINGS_KEY, &raw).map_err(|err| {
+        AppError::new(SETTINGS_PERSIST_FAILED_CODE, "Failed to save settings.")
+            .with_context("error", err.to_string())
+    })?;
+    Ok(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_update_keeps_other_fields() {
+        let store = StoreHandle::in_memory();
+        update_settings(
+            &store,
+            &SettingsPatch {
+                vault_root: Some(Some("/srv/vault".into())),
+                backup_interval_hours: Some(12),
+                ..SettingsPatch::default()
+            },
+        )
+        .expect("seed settings");
+
+        let updated = update_settings(
+            &store,
+            &SettingsPatch {
+                theme: Some(Theme::Dark),
+                ..SettingsPatch::default()
+            },
+        )
+        .expect("update theme");
+
+        assert_eq!(updated.theme, Theme::Dark);
+        assert_eq!(updated.backup_interval_hours, 12);
+        assert_eq!(updated.vault_root.as_deref(), Some("/srv/vault"));
+        assert_eq!(updated.log_level, "info");
+        assert_eq!(load_settings(&store), updated);
+    }
+
+    #[test]
+    fn rejects_bad_backup_interval_without_persisting() {
+        let store = StoreHandle::in_memory();
+        let err = update_settings(
+            &store,
+            &SettingsPatch {
+                backup_interval_hours: Some(0),
+                ..SettingsPatch::default()
+            },
+        )
+        .expect_err("zero interval rejected");
+        assert_eq!(err.code(), SETTINGS_INVALID_BACKUP_INTERVAL_CODE);
+        assert_eq!(load_settings(&store), Settings::default());
+    }
+
+    #[test]
+    fn rejects_bad_log_level_without_persisting() {
+        let store = StoreHandle::in_memory();
+        let err = update_settings(
+            &store,
+            &SettingsPatch {
+                log_level: Some("verbose".into()),
+                ..SettingsPatch::default()
+            },
+        )
+        .expect_err("unknown level rejected");
+        assert_eq!(err.code(), SETTINGS_INVALID_LOG_LEVEL_CODE);
+        assert_eq!(load_settings(&store), Settings::default());
+    }
+
+    #[test]
+    fn patch_null_vault_root_clears_override() {
+        let patch: SettingsPatch =
+            serde_json::from_str(r#"{"vaultRoot":null}"#).expect("parse patch");
+        assert_eq!(patch.vault_root, Some(None));
+        let current = Settings {
+            vault_root: Some("/srv/vault".into()),
+            ..Settings::default()
+        };
+        assert_eq!(current.merged(&patch).vault_root, None);
+    }
+}
@@ -0,0 +1,179 @@
+//! Shopping list commands that go beyond the generated CRUD set.
+//!
+//! Completing a shop checks off many items at once. Completed items are
+//! archived via the `completed` flag rather than soft-deleted, so they stay
+//! restorable and can still be listed with `include_completed`.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+use sqlx::SqlitePool;
+use tauri::State;
+
+use crate::{
+    ipc::guard, repo, state::AppState, time::now_ms, util::dispatch_async_app_result, AppError,
+    AppResult,
+};
+
+pub const ITEM_NOT_FOUND_CODE: &str = "SHOPPING/ITEM_NOT_FOUND";
+
+/// Active shopping items, optionally leaving out completed ones.
+pub async fn list_items(
+    pool: &SqlitePool,
+    household_id: &str,
+    include_completed: bool,
+    order_by: Option<&str>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> AppResult<Vec<Value>> {
+    let map_err = |err: anyhow::Error| {
+        AppError::from(err)
+            .with_context("operation", "list")
+            .with_context("table", "shopping_items")
+            .with_context("household_id", household_id.to_string())
+    };
+    let household_id = repo::require_household(household_id).map_err(map_err)?;
+    let order = repo::resolve_order_by(pool, "shopping_items", order_by)
+        .await
+        .map_err(map_err)?;
+
+    let mut sql =
+        String::from("SELECT * FROM shopping_items WHERE deleted_at IS NULL AND household_id = ?");
+    if !include_completed {
+        sql.push_str(" AND completed = 0");
+    }
+    sql.push_str(" ORDER BY ");
+    sql.push_str(&order);
+    if limit.is_some() {
+        sql.push_str(" LIMIT ?");
+    }
+    if offset.is_some() {
+        sql.push_str(" OFFSET ?");
+    }
+
+    let mut query = sqlx::query(&sql).bind(household_id);
+    if let Some(l) = limit {
+        query = query.bind(l);
+    }
+    if let Some(o) = offset {
+        query = query.bind(o);
+    }
+    let rows = query
+        .fetch_all(pool)
+        .await
+        .map_err(|err| map_err(err.into()))?;
+    Ok(rows.into_iter().map(repo::row_to_json).collect())
+}
+
+/// Mark the given items completed in one transaction. Every id must be an
+/// active item of `household_id`, otherwise nothing is changed. Returns how
+/// many items were newly completed; items already checked off are left as is.
+pub async fn complete_items(
+    pool: &SqlitePool,
+    household_id: &str,
+    ids: &[String],
+) -> AppResult<u64> {
+    let map_err = |err: sqlx::Error| {
+        AppError::from(err)
+            .with_context("operation", "shopping_items_complete")
+            .with_context("household_id", household_id.to_string())
+    };
+    let mut seen = HashSet::new();
+    let ids: Vec<&str> = ids
+        .iter()
+        .map(String::as_str)
+        .filter(|id| seen.insert(*id))
+        .collect();
+
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let now = now_ms();
+    let mut completed = 0u64;
+    for id in ids {
+        let found: Option<i64> = sqlx::query_scalar(
+            "SELECT completed FROM shopping_items
+              WHERE id = ?1 AND household_id = ?2 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(household_id)
+        .fetch_optional(tx.as_mut())
+        .await
+        .map_err(map_err)?;
+        match found {
+            None => {
+                return Err(
+                    AppError::new(ITEM_NOT_FOUND_CODE, "Shopping item not found")
+                        .with_context("id", id.to_string())
+                        .with_context("household_id", household_id.to_string()),
+                );
+            }
+            Some(0) => {
+                sqlx::query(
+                    "UPDATE shopping_items
+                        SET completed = 1, completed_at = ?1, updated_at = ?1
+                      WHERE id = ?2",
+                )
+                .bind(now)
+                .bind(id)
+                .execute(tx.as_mut())
+                .await
+                .map_err(map_err)?;
+                completed += 1;
+            }
+            Some(_) => {}
+        }
+    }
+    tx.commit().await.map_err(map_err)?;
+    Ok(completed)
+}
+
+#[tauri::command]
+pub async fn shopping_items_list(
+    state: State<'_, AppState>,
+    household_id: String,
+    order_by: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    include_completed: Option<bool>,
+) -> AppResult<Vec<Value>> {
+    let pool = state.pool_clone();
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let order_by = order_by.clone();
+        async move {
+            list_items(
+                &pool,
+                &household_id,
+                include_completed.unwrap_or(true),
+                order_by.as_deref(),
+                limit,
+                offset,
+            )
+            .await
+        }
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn shopping_items_complete(
+    state: State<'_, AppState>,
+    household_id: String,
+    ids: Vec<String>,
+) -> AppResult<u64> {
+    let _permit = guard::ensure_db_writable(&state)?;
+    let pool = state.pool_clone();
+
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let ids = ids.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "shopping_items_complete")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            complete_items(&pool, &household_id, &ids).await
+        }
+    })
+    .await
+}
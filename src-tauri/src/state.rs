@@ -1,7 +1,7 @@
 use sqlx::SqlitePool;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex, RwLock};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
 
 use crate::{
     db::health::DbHealthReport, events_tz_backfill::BackfillCoordinator,
@@ -10,6 +10,11 @@ use crate::{
     AppError, AppResult,
 };
 
+/// Error code returned when maintenance mode is requested while already active.
+pub const MAINTENANCE_ALREADY_ACTIVE_CODE: &str = "MAINTENANCE_ALREADY_ACTIVE";
+/// Error code returned when ending maintenance mode that was never started.
+pub const MAINTENANCE_NOT_ACTIVE_CODE: &str = "MAINTENANCE_NOT_ACTIVE";
+
 #[derive(Clone)]
 pub struct AppState {
     pub pool: Arc<RwLock<SqlitePool>>,
@@ -21,6 +26,7 @@ pub struct AppState {
     pub vault: Arc<Vault>,
     pub vault_migration: Arc<VaultMigrationManager>,
     pub maintenance: Arc<AtomicBool>,
+    pub maintenance_reason: Arc<Mutex<Option<String>>>,
     pub files_indexer: Arc<FilesIndexer>,
     pub pet_metrics: Arc<PetAttachmentMetrics>,
 }
@@ -43,6 +49,59 @@ impl AppState {
         self.maintenance.load(Ordering::SeqCst)
     }
 
+    /// Enter operator-requested maintenance, blocking writes until
+    /// [`AppState::end_manual_maintenance`] is called.
+    #[allow(clippy::result_large_err)]
+    pub fn begin_manual_maintenance(&self, reason: String) -> AppResult<()> {
+        let mut slot = self.maintenance_reason_slot()?;
+        if self
+            .maintenance
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            let mut error = AppError::new(
+                MAINTENANCE_ALREADY_ACTIVE_CODE,
+                "Maintenance mode is already active.",
+            );
+            if let Some(existing) = slot.as_deref() {
+                error = error.with_context("reason", existing);
+            }
+            return Err(error);
+        }
+        *slot = Some(reason);
+        Ok(())
+    }
+
+    /// Leave operator-requested maintenance. Maintenance entered internally by
+    /// repair flows is released by its [`MaintenanceGuard`] and cannot be ended here.
+    #[allow(clippy::result_large_err)]
+    pub fn end_manual_maintenance(&self) -> AppResult<()> {
+        let mut slot = self.maintenance_reason_slot()?;
+        if slot.take().is_none() {
+            return Err(AppError::new(
+                MAINTENANCE_NOT_ACTIVE_CODE,
+                "Maintenance mode is not active.",
+            ));
+        }
+        self.maintenance.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Reason supplied when maintenance was entered manually, if any.
+    pub fn maintenance_reason(&self) -> Option<String> {
+        self.maintenance_reason
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn maintenance_reason_slot(&self) -> AppResult<MutexGuard<'_, Option<String>>> {
+        self.maintenance_reason
+            .lock()
+            .map_err(|_| AppError::new("STATE/LOCK_POISONED", "Failed to access maintenance state"))
+    }
+
     pub fn vault(&self) -> Arc<Vault> {
         self.vault.clone()
     }
@@ -119,6 +178,7 @@ mod tests {
             vault: vault.clone(),
             vault_migration: Arc::new(VaultMigrationManager::new(tmp.path()).expect("manager")),
             maintenance: Arc::new(AtomicBool::new(false)),
+            maintenance_reason: Arc::new(Mutex::new(None)),
             files_indexer: Arc::new(FilesIndexer::new(pool.clone(), vault.clone())),
             pet_metrics: Arc::new(PetAttachmentMetrics::new()),
         };
@@ -1,6 +1,16 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::{util::dispatch_app_result, AppError, AppResult};
+
+/// Region bucket for zone identifiers without an `Area/` prefix (e.g. `UTC`, `EST`).
+const TIMEZONE_REGION_OTHER: &str = "Other";
 
-use crate::{AppError, AppResult};
+static TIMEZONE_IDS: OnceCell<Vec<&'static str>> = OnceCell::new();
 
 pub fn now_ms() -> i64 {
     Utc::now().timestamp_millis()
@@ -16,6 +26,51 @@ pub(crate) fn to_date(ms: i64) -> AppResult<DateTime<Utc>> {
     })
 }
 
+/// All IANA zone identifiers known to the bundled tz database, sorted.
+pub(crate) fn timezone_ids() -> &'static [&'static str] {
+    TIMEZONE_IDS.get_or_init(|| {
+        let mut ids: Vec<&'static str> =
+            chrono_tz::TZ_VARIANTS.iter().map(|tz| tz.name()).collect();
+        ids.sort_unstable();
+        ids
+    })
+}
+
+/// Group zone identifiers by their leading region (`Europe`, `America`, ...).
+pub(crate) fn timezone_regions() -> BTreeMap<String, Vec<String>> {
+    let mut regions: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for id in timezone_ids() {
+        let region = match id.split_once('/') {
+            Some((region, _)) => region,
+            None => TIMEZONE_REGION_OTHER,
+        };
+        regions
+            .entry(region.to_string())
+            .or_default()
+            .push((*id).to_string());
+    }
+    regions
+}
+
+#[derive(Serialize, Debug, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct TimezoneList {
+    pub zones: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub regions: Option<BTreeMap<String, Vec<String>>>,
+}
+
+#[tauri::command]
+#[allow(clippy::result_large_err)]
+pub async fn timezones_list(grouped: Option<bool>) -> AppResult<TimezoneList> {
+    dispatch_app_result(move || {
+        let zones = timezone_ids().iter().map(|id| (*id).to_string()).collect();
+        let regions = grouped.unwrap_or(false).then(timezone_regions);
+        Ok(TimezoneList { zones, regions })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,4 +87,25 @@ mod tests {
         let d = to_date(0).expect("epoch timestamp is valid");
         assert_eq!(d.timestamp_millis(), 0);
     }
+
+    #[test]
+    fn timezone_ids_cover_common_zones() {
+        let ids = timezone_ids();
+        assert!(ids.contains(&"Europe/London"));
+        assert!(ids.contains(&"America/New_York"));
+        assert!(
+            ids.len() > 300,
+            "expected full tz database, got {}",
+            ids.len()
+        );
+    }
+
+    #[test]
+    fn timezone_regions_group_by_prefix() {
+        let regions = timezone_regions();
+        let europe = regions.get("Europe").expect("Europe region");
+        assert!(europe.iter().any(|id| id == "Europe/London"));
+        let other = regions.get(TIMEZONE_REGION_OTHER).expect("other region");
+        assert!(other.iter().any(|id| id == "UTC"));
+    }
 }
@@ -1,14 +1,42 @@
 use std::any::Any;
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use futures::Future;
 use futures::FutureExt;
+use tracing::Instrument;
 
 use crate::{
     error::{panic_payload, take_panic_crash_id},
     AppError, AppResult,
 };
 
+/// Context key carrying the correlation id of the IPC dispatch that produced an error.
+pub const REQUEST_ID_CONTEXT_KEY: &str = "request_id";
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Allocate a short, process-unique correlation id for a dispatched command.
+pub fn next_request_id() -> String {
+    format!("req-{:x}", NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+#[allow(clippy::result_large_err)]
+fn tag_request_id<T>(result: AppResult<T>, request_id: &str) -> AppResult<T> {
+    result.map_err(|err| {
+        if err.context().contains_key(REQUEST_ID_CONTEXT_KEY) {
+            return err;
+        }
+        tracing::info!(
+            target: "arklowdun",
+            event = "ipc_dispatch_error",
+            request_id = %request_id,
+            code = %err.code()
+        );
+        err.with_context(REQUEST_ID_CONTEXT_KEY, request_id)
+    })
+}
+
 fn app_error_from_panic(payload: Box<dyn Any + Send>) -> AppError {
     let message = panic_payload(payload.as_ref());
     let crash_id = take_panic_crash_id().unwrap_or_default();
@@ -48,7 +76,10 @@ pub fn dispatch_app_result<T, F>(f: F) -> AppResult<T>
 where
     F: FnOnce() -> AppResult<T>,
 {
-    dispatch_with_fence(f)?
+    let request_id = next_request_id();
+    let span = tracing::info_span!(target: "arklowdun", "ipc_dispatch", request_id = %request_id);
+    let result = span.in_scope(|| dispatch_with_fence(f).and_then(|inner| inner));
+    tag_request_id(result, &request_id)
 }
 
 #[allow(clippy::result_large_err)]
@@ -57,7 +88,33 @@ where
     F: FnOnce() -> Fut,
     Fut: Future<Output = AppResult<T>>,
 {
-    dispatch_async_with_fence(f).await?
+    let request_id = next_request_id();
+    let span = tracing::info_span!(target: "arklowdun", "ipc_dispatch", request_id = %request_id);
+    let result = dispatch_async_with_fence(f)
+        .instrument(span)
+        .await
+        .and_then(|inner| inner);
+    tag_request_id(result, &request_id)
+}
+
+/// Error code shared by every command that stores a user-picked colour.
+pub const INVALID_COLOR_CODE: &str = "INVALID_COLOR";
+pub const INVALID_COLOR_MESSAGE: &str = "Please use a hex colour like #2563EB.";
+
+/// `#RRGGBB` with hex digits in either case.
+pub fn is_valid_hex_color(value: &str) -> bool {
+    value.len() == 7 && value.starts_with('#') && value[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Trimmed, upper-cased colour when `raw` is a valid `#RRGGBB` value.
+pub fn normalize_hex_color(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    is_valid_hex_color(trimmed).then(|| trimmed.to_uppercase())
+}
+
+pub fn invalid_color_error(value: &str) -> AppError {
+    AppError::new(INVALID_COLOR_CODE, INVALID_COLOR_MESSAGE)
+        .with_context("color", value.to_string())
 }
 
 #[cfg(test)]
@@ -100,4 +157,52 @@ mod tests {
         assert!(err.crash_id().is_some());
         assert!(err.context().is_empty());
     }
+
+    #[test]
+    fn dispatch_app_result_tags_errors_with_request_id() {
+        let err = dispatch_app_result::<(), _>(|| Err(AppError::new("TEST/FAIL", "nope")))
+            .expect_err("error should propagate");
+        let request_id = err
+            .context()
+            .get(REQUEST_ID_CONTEXT_KEY)
+            .expect("request id attached");
+        assert!(request_id.starts_with("req-"));
+    }
+
+    #[tokio::test]
+    async fn dispatch_async_app_result_tags_errors_with_request_id() {
+        let err = dispatch_async_app_result::<_, _, ()>(|| async {
+            Err(AppError::new("TEST/FAIL", "nope"))
+        })
+        .await
+        .expect_err("error should propagate");
+        assert!(err.context().contains_key(REQUEST_ID_CONTEXT_KEY));
+
+        let err = dispatch_async_app_result(|| async move {
+            panic!("boom");
+            #[allow(unreachable_code)]
+            Ok(())
+        })
+        .await
+        .expect_err("panic should convert");
+        assert_eq!(err.code(), "RUNTIME/PANIC");
+        assert!(err.crash_id().is_some());
+        assert!(err.context().contains_key(REQUEST_ID_CONTEXT_KEY));
+    }
+
+    #[test]
+    fn hex_colors_are_normalized() {
+        assert_eq!(normalize_hex_color(" #2563eb "), Some("#2563EB".into()));
+        for bad in ["2563EB", "#2563E", "#2563EBFF", "#GGGGGG", "blue", ""] {
+            assert!(
+                normalize_hex_color(bad).is_none(),
+                "{bad} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn request_ids_are_unique() {
+        assert_ne!(next_request_id(), next_request_id());
+    }
 }
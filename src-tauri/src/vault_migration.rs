@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Component, Path, PathBuf};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
@@ -110,8 +111,10 @@ impl MigrationCounts {
             table: table.into(),
             counts: self.clone(),
             completed,
+            cancelled: false,
             manifest_path,
             checkpoint_path,
+            plan: Vec::new(),
         }
     }
 }
@@ -144,8 +147,16 @@ pub struct MigrationProgress {
     pub table: String,
     pub counts: MigrationCounts,
     pub completed: bool,
+    /// Set when the run stopped early on request. The checkpoint is kept so
+    /// `attachments_resume_migration` continues after the last finished row.
+    #[serde(default)]
+    pub cancelled: bool,
     pub manifest_path: Option<String>,
     pub checkpoint_path: Option<String>,
+    /// Planned actions of a dry run, in the same shape as the manifest.
+    /// Always empty for applied runs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plan: Vec<ManifestEntry>,
 }
 
 impl Default for MigrationProgress {
@@ -155,8 +166,10 @@ impl Default for MigrationProgress {
             table: String::new(),
             counts: MigrationCounts::default(),
             completed: false,
+            cancelled: false,
             manifest_path: None,
             checkpoint_path: None,
+            plan: Vec::new(),
         }
     }
 }
@@ -254,6 +267,7 @@ impl<R: tauri::Runtime> LegacyRootProvider for tauri::AppHandle<R> {
 pub struct VaultMigrationManager {
     state_dir: PathBuf,
     state: Mutex<ManagerState>,
+    cancel_requested: AtomicBool,
 }
 
 impl VaultMigrationManager {
@@ -268,6 +282,7 @@ impl VaultMigrationManager {
         Ok(Self {
             state_dir,
             state: Mutex::new(ManagerState::default()),
+            cancel_requested: AtomicBool::new(false),
         })
     }
 
@@ -354,9 +369,28 @@ impl VaultMigrationManager {
         }
         guard.running = true;
         guard.last_summary = None;
+        self.cancel_requested.store(false, Ordering::SeqCst);
         Ok(())
     }
 
+    /// Ask the running migration to stop before its next file.
+    #[allow(clippy::result_large_err)]
+    pub fn request_cancel(&self) -> AppResult<()> {
+        let guard = self.state.lock().expect("manager state poisoned");
+        if !guard.running {
+            return Err(AppError::new(
+                "VAULT_MIGRATION_NOT_RUNNING",
+                "No vault migration is running.",
+            ));
+        }
+        self.cancel_requested.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn cancel_requested(&self) -> bool {
+        self.cancel_requested.load(Ordering::SeqCst)
+    }
+
     pub fn finish(&self, summary: MigrationProgress) {
         let mut guard = self.state.lock().expect("manager state poisoned");
         guard.running = false;
@@ -470,6 +504,7 @@ where
     Sink: ProgressSink,
 {
     let mut counts = MigrationCounts::default();
+    let mut plan: Vec<ManifestEntry> = Vec::new();
     let mut checkpoint = load_checkpoint(checkpoint_path).unwrap_or_else(|| Checkpoint {
         table_index: 0,
         last_id: None,
@@ -501,6 +536,29 @@ where
         })? {
             let id: String = row.try_get("id").unwrap_or_default();
 
+            if manager.cancel_requested() {
+                // The checkpoint already records the last finished row; keep the
+                // manifest journal so a resumed run appends to it.
+                manifest_writer.flush().await?;
+                emitter.emit(&counts, mode, table, false, Some(checkpoint_path), None);
+                tracing::info!(
+                    target: "arklowdun",
+                    event = "vault_migration_cancelled",
+                    table = %table,
+                    processed = counts.processed,
+                );
+                let mut partial = counts.snapshot(
+                    mode,
+                    table.to_string(),
+                    false,
+                    Some(checkpoint_path.to_string_lossy().to_string()),
+                    None,
+                );
+                partial.cancelled = true;
+                partial.plan = plan;
+                return Ok(partial);
+            }
+
             if checkpoint.table_index == idx {
                 if let Some(last_id) = &checkpoint.last_id {
                     if last_id >= &id {
@@ -522,7 +580,7 @@ where
                 Err(err) => {
                     let code = err.code().to_string();
                     counts.record_unsupported(&code);
-                    manifest_writer.push(ManifestEntry {
+                    let entry = ManifestEntry {
                         table: table.to_string(),
                         id: id.clone(),
                         action: ManifestAction::Skip,
@@ -532,7 +590,11 @@ where
                         conflict_suffix: None,
                         relative_path_hash: Some(relative_hash.clone()),
                         skipped_delete: false,
-                    });
+                    };
+                    if !mode.is_apply() {
+                        plan.push(entry.clone());
+                    }
+                    manifest_writer.push(entry);
                     checkpoint.last_id = Some(id.clone());
                     checkpoint.table_index = idx;
                     save_checkpoint(checkpoint_path, &checkpoint).await?;
@@ -695,15 +757,36 @@ where
                     }
                 }
             } else {
-                if let LegacyResolution::Unsupported {
-                    reason: legacy_reason,
-                } = &legacy_resolution
-                {
-                    let legacy_reason = *legacy_reason;
-                    counts.record_unsupported(legacy_reason);
-                    reason = Some(legacy_reason.to_string());
-                } else {
-                    counts.record_copy(false);
+                // Dry run: plan the same decisions as apply using only reads.
+                match &legacy_resolution {
+                    LegacyResolution::Unsupported {
+                        reason: legacy_reason,
+                    } => {
+                        let legacy_reason = *legacy_reason;
+                        counts.record_unsupported(legacy_reason);
+                        reason = Some(legacy_reason.to_string());
+                    }
+                    LegacyResolution::Supported(source) if !source.path.exists() => {
+                        counts.record_skip(SKIP_REASON_SOURCE_MISSING);
+                        reason = Some(SKIP_REASON_SOURCE_MISSING.to_string());
+                        manifest_action = ManifestAction::Skip;
+                    }
+                    LegacyResolution::Supported(_) => {
+                        let (planned_path, conflict) = resolve_conflict(&target_path).await?;
+                        if conflict {
+                            manifest_action = ManifestAction::ConflictRename;
+                            let original = target_path.file_stem().and_then(|s| s.to_str());
+                            let planned = planned_path.file_stem().and_then(|s| s.to_str());
+                            if let (Some(orig), Some(new_stem)) = (original, planned) {
+                                conflict_suffix = new_stem
+                                    .strip_prefix(orig)
+                                    .filter(|suffix| !suffix.is_empty())
+                                    .map(str::to_string);
+                            }
+                            target_hash = Some(hash_path(&planned_path));
+                        }
+                        counts.record_copy(conflict);
+                    }
                 }
             }
 
@@ -711,7 +794,7 @@ where
                 manifest_action = ManifestAction::Skip;
             }
 
-            manifest_writer.push(ManifestEntry {
+            let entry = ManifestEntry {
                 table: table.to_string(),
                 id: id.clone(),
                 action: manifest_action,
@@ -721,7 +804,11 @@ where
                 conflict_suffix,
                 relative_path_hash: Some(relative_hash.clone()),
                 skipped_delete,
-            });
+            };
+            if !mode.is_apply() {
+                plan.push(entry.clone());
+            }
+            manifest_writer.push(entry);
 
             emitter.emit(&counts, mode, table, false, Some(checkpoint_path), None);
 
@@ -750,13 +837,14 @@ where
         .then(|| manifest_path.to_string_lossy().to_string());
 
     let completion_table = "(complete)";
-    let summary = counts.snapshot(
+    let mut summary = counts.snapshot(
         mode,
         completion_table.to_string(),
         true,
         None,
         manifest_path_str.clone(),
     );
+    summary.plan = plan;
 
     emitter.emit(
         &counts,
@@ -1266,6 +1354,117 @@ mod tests {
 
         assert_eq!(manager.resume_mode(), Some(MigrationMode::Apply));
     }
+
+    struct CancelAfter {
+        manager: std::sync::Arc<VaultMigrationManager>,
+        processed: u64,
+    }
+
+    impl ProgressSink for CancelAfter {
+        fn emit(
+            &mut self,
+            counts: &MigrationCounts,
+            _mode: MigrationMode,
+            _table: &str,
+            _completed: bool,
+            _checkpoint_path: Option<&Path>,
+            _manifest_path: Option<String>,
+        ) {
+            if counts.processed == self.processed {
+                let _ = self.manager.request_cancel();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn cancelled_apply_resumes_without_redoing_files() {
+        let dir = tempdir().expect("tempdir");
+        let legacy_root = dir.path().join("legacy");
+        let vault_root = dir.path().join("vault");
+        fs::create_dir_all(&legacy_root).expect("legacy dir");
+        fs::create_dir_all(&vault_root).expect("vault dir");
+
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("pool");
+        crate::migrate::apply_migrations(&pool)
+            .await
+            .expect("migrations");
+        for idx in 0..3 {
+            let name = format!("bill-{idx}.pdf");
+            fs::write(legacy_root.join(&name), format!("bytes-{idx}")).expect("legacy file");
+            sqlx::query(
+                "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category) \
+                 VALUES (?1, 100, 0, 'default', 0, 0, ?2, 'attachments', ?3, 'bills')",
+            )
+            .bind(format!("bill-{idx}"))
+            .bind(idx)
+            .bind(&name)
+            .execute(&pool)
+            .await
+            .expect("insert bill");
+        }
+
+        let vault = std::sync::Arc::new(Vault::new(&vault_root));
+        let manager =
+            std::sync::Arc::new(VaultMigrationManager::new(&vault_root).expect("manager"));
+        let roots = HeadlessLegacyRoots::new(dir.path().to_path_buf(), legacy_root.clone());
+        let mut sink = CancelAfter {
+            manager: manager.clone(),
+            processed: 1,
+        };
+
+        let partial = run_vault_migration_with(
+            pool.clone(),
+            vault.clone(),
+            manager.clone(),
+            MigrationMode::Apply,
+            &mut sink,
+            &roots,
+        )
+        .await
+        .expect("cancelled run");
+        assert!(partial.cancelled);
+        assert!(!partial.completed);
+        assert_eq!(partial.counts.copied, 1);
+        assert!(partial.checkpoint_path.is_some());
+        assert_eq!(manager.resume_mode(), Some(MigrationMode::Apply));
+        assert!(!legacy_root.join("bill-0.pdf").exists());
+        assert!(legacy_root.join("bill-1.pdf").exists());
+        assert!(manager.request_cancel().is_err());
+
+        let resumed = run_vault_migration_headless(
+            pool.clone(),
+            vault.clone(),
+            manager.clone(),
+            MigrationMode::Apply,
+            roots,
+        )
+        .await
+        .expect("resumed run");
+        assert!(resumed.completed);
+        assert!(!resumed.cancelled);
+        assert_eq!(resumed.counts.processed, 2);
+        assert_eq!(resumed.counts.copied, 2);
+        assert_eq!(resumed.counts.conflicts, 0);
+        for idx in 0..3 {
+            let target = vault_root
+                .join("default")
+                .join("bills")
+                .join(format!("bill-{idx}.pdf"));
+            assert_eq!(
+                fs::read(&target).expect("migrated file"),
+                format!("bytes-{idx}").into_bytes()
+            );
+        }
+
+        let manifest: Vec<ManifestEntry> =
+            serde_json::from_slice(&fs::read(manager.manifest_path()).expect("manifest"))
+                .expect("manifest json");
+        assert_eq!(manifest.len(), 3);
+    }
 }
 
 pub async fn ensure_housekeeping(pool: &SqlitePool, vault: &Vault) -> AppResult<()> {
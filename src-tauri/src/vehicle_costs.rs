@@ -0,0 +1,132 @@
+//! Spend rollups over `vehicle_maintenance`.
+//!
+//! Totals are computed in SQL so the UI no longer sums rows client-side.
+//! Soft-deleted maintenance rows are excluded and a missing cost counts as
+//! zero. The breakdown groups by the maintenance `type` (service, MOT, tyres…).
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::State;
+use ts_rs::TS;
+
+use crate::{repo, state::AppState, util::dispatch_async_app_result, AppError, AppResult};
+
+pub const VEHICLE_NOT_FOUND_CODE: &str = "VEHICLE_COSTS/VEHICLE_NOT_FOUND";
+pub const INVALID_RANGE_CODE: &str = "VEHICLE_COSTS/INVALID_RANGE";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, sqlx::FromRow)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct MaintenanceKindTotal {
+    pub kind: String,
+    #[ts(type = "number")]
+    pub total_cost: i64,
+    #[ts(type = "number")]
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct VehicleMaintenanceSummary {
+    pub vehicle_id: String,
+    #[ts(type = "number")]
+    pub from_ms: i64,
+    #[ts(type = "number")]
+    pub to_ms: i64,
+    #[ts(type = "number")]
+    pub total_cost: i64,
+    #[ts(type = "number")]
+    pub count: i64,
+    /// Per-kind totals, largest spend first.
+    pub by_kind: Vec<MaintenanceKindTotal>,
+}
+
+/// Sum maintenance costs for a vehicle with `date` in `[from_ms, to_ms]`.
+pub async fn summarize_maintenance(
+    pool: &SqlitePool,
+    household_id: &str,
+    vehicle_id: &str,
+    from_ms: i64,
+    to_ms: i64,
+) -> AppResult<VehicleMaintenanceSummary> {
+    if from_ms > to_ms {
+        return Err(
+            AppError::new(INVALID_RANGE_CODE, "from_ms must not be after to_ms")
+                .with_context("from_ms", from_ms.to_string())
+                .with_context("to_ms", to_ms.to_string()),
+        );
+    }
+    let map_err = |err: sqlx::Error| {
+        AppError::from(err)
+            .with_context("operation", "vehicle_maintenance_summary")
+            .with_context("vehicle_id", vehicle_id.to_string())
+    };
+
+    let exists: Option<i64> = sqlx::query_scalar(
+        "SELECT 1 FROM vehicles WHERE id = ?1 AND household_id = ?2 AND deleted_at IS NULL",
+    )
+    .bind(vehicle_id)
+    .bind(household_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(map_err)?;
+    if exists.is_none() {
+        return Err(AppError::new(VEHICLE_NOT_FOUND_CODE, "Vehicle not found")
+            .with_context("vehicle_id", vehicle_id.to_string())
+            .with_context("household_id", household_id.to_string()));
+    }
+
+    let by_kind = sqlx::query_as::<_, MaintenanceKindTotal>(
+        "SELECT type AS kind,
+                COALESCE(SUM(COALESCE(cost, 0)), 0) AS total_cost,
+                COUNT(*) AS count
+           FROM vehicle_maintenance
+          WHERE vehicle_id = ?1
+            AND household_id = ?2
+            AND deleted_at IS NULL
+            AND date >= ?3
+            AND date <= ?4
+          GROUP BY type
+          ORDER BY total_cost DESC, kind ASC",
+    )
+    .bind(vehicle_id)
+    .bind(household_id)
+    .bind(from_ms)
+    .bind(to_ms)
+    .fetch_all(pool)
+    .await
+    .map_err(map_err)?;
+
+    Ok(VehicleMaintenanceSummary {
+        vehicle_id: vehicle_id.to_string(),
+        from_ms,
+        to_ms,
+        total_cost: by_kind.iter().map(|kind| kind.total_cost).sum(),
+        count: by_kind.iter().map(|kind| kind.count).sum(),
+        by_kind,
+    })
+}
+
+#[tauri::command]
+pub async fn vehicle_maintenance_summary(
+    state: State<'_, AppState>,
+    household_id: String,
+    vehicle_id: String,
+    from_ms: i64,
+    to_ms: i64,
+) -> AppResult<VehicleMaintenanceSummary> {
+    let pool = state.pool_clone();
+
+    dispatch_async_app_result(move || {
+        let household_id = household_id.clone();
+        let vehicle_id = vehicle_id.clone();
+        async move {
+            repo::require_household(&household_id).map_err(|err| {
+                AppError::from(err)
+                    .with_context("operation", "vehicle_maintenance_summary")
+                    .with_context("household_id", household_id.to_string())
+            })?;
+            summarize_maintenance(&pool, &household_id, &vehicle_id, from_ms, to_ms).await
+        }
+    })
+    .await
+}
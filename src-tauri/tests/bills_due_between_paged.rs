@@ -0,0 +1,68 @@
+use arklowdun_lib::{commands, migrate};
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    for (idx, due) in [1_000_i64, 2_000, 3_000, 4_000, 5_000, 9_000]
+        .iter()
+        .enumerate()
+    {
+        sqlx::query(
+            "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position)
+             VALUES (?1, 100, ?2, 'default', 1, 1, ?3)",
+        )
+        .bind(format!("bill_{idx}"))
+        .bind(due)
+        .bind(idx as i64)
+        .execute(&pool)
+        .await
+        .expect("insert bill");
+    }
+    sqlx::query("UPDATE bills SET deleted_at = 2 WHERE id = 'bill_4'")
+        .execute(&pool)
+        .await
+        .expect("soft delete bill");
+    pool
+}
+
+#[tokio::test]
+async fn total_is_stable_and_has_more_flips_on_last_page() {
+    let pool = setup_pool().await;
+
+    let first = commands::bills_due_between(&pool, "default", 1_000, 8_000, Some(2), None)
+        .await
+        .expect("first page");
+    let second = commands::bills_due_between(&pool, "default", 1_000, 8_000, Some(2), Some(2))
+        .await
+        .expect("second page");
+
+    assert_eq!(first.total, 4);
+    assert_eq!(second.total, 4);
+    assert!(first.has_more);
+    assert!(!second.has_more);
+
+    let ids: Vec<&str> = first
+        .items
+        .iter()
+        .chain(second.items.iter())
+        .map(|bill| bill["id"].as_str().expect("id"))
+        .collect();
+    assert_eq!(ids, vec!["bill_0", "bill_1", "bill_2", "bill_3"]);
+}
+
+#[tokio::test]
+async fn offset_without_limit_returns_the_rest() {
+    let pool = setup_pool().await;
+
+    let page = commands::bills_due_between(&pool, "default", 0, 10_000, None, Some(3))
+        .await
+        .expect("page");
+    assert_eq!(page.total, 5);
+    assert_eq!(page.items.len(), 2);
+    assert!(!page.has_more);
+}
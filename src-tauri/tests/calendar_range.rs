@@ -0,0 +1,120 @@
+use arklowdun_lib::calendar_range::{
+    day_bounds, day_bounds_for_household, parse_local_date, period_bounds, PeriodKind, Weekday,
+    INVALID_LOCAL_DATE_CODE,
+};
+use arklowdun_lib::migrate;
+use chrono::{TimeZone, Utc};
+use chrono_tz::Tz;
+use sqlx::sqlite::SqlitePoolOptions;
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+
+fn utc_ms(y: i32, m: u32, d: u32, h: u32) -> i64 {
+    Utc.with_ymd_and_hms(y, m, d, h, 0, 0)
+        .unwrap()
+        .timestamp_millis()
+}
+
+#[test]
+fn normal_day_spans_24_hours_from_local_midnight() {
+    let tz: Tz = "America/New_York".parse().unwrap();
+    let range = day_bounds(&tz, parse_local_date("2024-06-12").unwrap()).unwrap();
+    assert_eq!(range.start_at_utc, utc_ms(2024, 6, 12, 4));
+    assert_eq!(range.end_at_utc - range.start_at_utc, 24 * HOUR_MS);
+    assert_eq!(range.tz, "America/New_York");
+}
+
+#[test]
+fn dst_transition_days_are_23_and_25_hours() {
+    let tz: Tz = "America/New_York".parse().unwrap();
+    let spring = day_bounds(&tz, parse_local_date("2024-03-10").unwrap()).unwrap();
+    assert_eq!(spring.start_at_utc, utc_ms(2024, 3, 10, 5));
+    assert_eq!(spring.end_at_utc, utc_ms(2024, 3, 11, 4));
+    assert_eq!(spring.end_at_utc - spring.start_at_utc, 23 * HOUR_MS);
+
+    let fall = day_bounds(&tz, parse_local_date("2024-11-03").unwrap()).unwrap();
+    assert_eq!(fall.end_at_utc - fall.start_at_utc, 25 * HOUR_MS);
+}
+
+#[test]
+fn skipped_midnight_starts_at_first_existing_minute() {
+    // Santiago moved clocks from 00:00 to 01:00 on 2024-09-08.
+    let tz: Tz = "America/Santiago".parse().unwrap();
+    let range = day_bounds(&tz, parse_local_date("2024-09-08").unwrap()).unwrap();
+    assert_eq!(range.start_at_utc, utc_ms(2024, 9, 8, 4));
+    assert_eq!(range.end_at_utc - range.start_at_utc, 23 * HOUR_MS);
+}
+
+#[test]
+fn malformed_date_is_rejected() {
+    let err = parse_local_date("10/03/2024").unwrap_err();
+    assert_eq!(err.code(), INVALID_LOCAL_DATE_CODE);
+}
+
+#[tokio::test]
+async fn household_timezone_drives_bounds() {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    migrate::apply_migrations(&pool).await.unwrap();
+
+    let utc = day_bounds_for_household(&pool, "default", "2024-03-31")
+        .await
+        .unwrap();
+    assert_eq!(utc.start_at_utc, utc_ms(2024, 3, 31, 0));
+    assert_eq!(utc.tz, "UTC");
+
+    sqlx::query("UPDATE household SET tz = 'Europe/Dublin' WHERE id = 'default'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let dublin = day_bounds_for_household(&pool, "default", "2024-03-31")
+        .await
+        .unwrap();
+    assert_eq!(dublin.start_at_utc, utc_ms(2024, 3, 31, 0));
+    assert_eq!(dublin.end_at_utc, utc_ms(2024, 3, 31, 23));
+}
+
+#[test]
+fn february_month_bounds_follow_month_length() {
+    let tz: Tz = "Europe/London".parse().unwrap();
+    let anchor = parse_local_date("2024-02-17").unwrap();
+    let leap = period_bounds(&tz, PeriodKind::Month, anchor, Weekday::Monday).unwrap();
+    assert_eq!(leap.start_at_utc, utc_ms(2024, 2, 1, 0));
+    assert_eq!(leap.end_at_utc, utc_ms(2024, 3, 1, 0));
+    assert_eq!(leap.end_at_utc - leap.start_at_utc, 29 * 24 * HOUR_MS);
+
+    let anchor = parse_local_date("2023-02-28").unwrap();
+    let common = period_bounds(&tz, PeriodKind::Month, anchor, Weekday::Monday).unwrap();
+    assert_eq!(common.end_at_utc - common.start_at_utc, 28 * 24 * HOUR_MS);
+
+    // March loses an hour when British Summer Time starts.
+    let anchor = parse_local_date("2024-03-05").unwrap();
+    let march = period_bounds(&tz, PeriodKind::Month, anchor, Weekday::Monday).unwrap();
+    assert_eq!(march.end_at_utc, utc_ms(2024, 3, 31, 23));
+    assert_eq!(
+        march.end_at_utc - march.start_at_utc,
+        (31 * 24 - 1) * HOUR_MS
+    );
+}
+
+#[test]
+fn week_bounds_honour_week_start() {
+    let tz: Tz = "UTC".parse().unwrap();
+    // Wednesday 2024-05-15.
+    let anchor = parse_local_date("2024-05-15").unwrap();
+    let monday = period_bounds(&tz, PeriodKind::Week, anchor, Weekday::Monday).unwrap();
+    assert_eq!(monday.start_at_utc, utc_ms(2024, 5, 13, 0));
+    assert_eq!(monday.end_at_utc, utc_ms(2024, 5, 20, 0));
+
+    let sunday = period_bounds(&tz, PeriodKind::Week, anchor, Weekday::Sunday).unwrap();
+    assert_eq!(sunday.start_at_utc, utc_ms(2024, 5, 12, 0));
+    assert_eq!(sunday.end_at_utc, utc_ms(2024, 5, 19, 0));
+
+    // An anchor on the week start belongs to the week it opens.
+    let anchor = parse_local_date("2024-05-12").unwrap();
+    let same = period_bounds(&tz, PeriodKind::Week, anchor, Weekday::Sunday).unwrap();
+    assert_eq!(same, sunday);
+}
@@ -23,6 +23,7 @@ async fn categories_list_returns_seeded_rows() -> Result<()> {
         Some("position, created_at, id"),
         None,
         None,
+        None,
     )
     .await?;
 
@@ -158,6 +159,7 @@ async fn categories_toggle_updates_is_visible() -> Result<()> {
         hide_patch,
         Some("default"),
         None,
+        None,
     )
     .await?;
 
@@ -175,6 +177,7 @@ async fn categories_toggle_updates_is_visible() -> Result<()> {
         show_patch,
         Some("default"),
         None,
+        None,
     )
     .await?;
 
@@ -226,6 +229,7 @@ async fn categories_toggle_roundtrip() -> Result<()> {
         hide_patch,
         Some("default"),
         None,
+        None,
     )
     .await?;
 
@@ -249,6 +253,7 @@ async fn categories_toggle_roundtrip() -> Result<()> {
         show_patch,
         Some("default"),
         None,
+        None,
     )
     .await?;
 
@@ -320,6 +325,7 @@ async fn notes_hidden_category() -> Result<()> {
         hide_patch,
         Some("default"),
         None,
+        None,
     )
     .await?;
 
@@ -349,6 +355,7 @@ async fn notes_hidden_category() -> Result<()> {
         show_patch,
         Some("default"),
         None,
+        None,
     )
     .await?;
 
@@ -365,3 +372,117 @@ async fn notes_hidden_category() -> Result<()> {
 
     Ok(())
 }
+
+fn category_payload(slug: &str, color: &str) -> Map<String, Value> {
+    let mut payload = Map::new();
+    payload.insert("household_id".into(), Value::from("default"));
+    payload.insert("name".into(), Value::from(slug));
+    payload.insert("slug".into(), Value::from(slug));
+    payload.insert("color".into(), Value::from(color));
+    payload.insert("position".into(), Value::from(50));
+    payload.insert("z".into(), Value::from(0));
+    payload
+}
+
+#[tokio::test]
+async fn categories_reject_invalid_colors_like_households() -> Result<()> {
+    let pool = setup_pool().await?;
+
+    let err = arklowdun_lib::commands::create_command(
+        &pool,
+        "categories",
+        category_payload("bad", "blue"),
+        None,
+    )
+    .await
+    .expect_err("non-hex colour rejected");
+    assert_eq!(err.code(), arklowdun_lib::util::INVALID_COLOR_CODE);
+    assert_eq!(err.message(), arklowdun_lib::util::INVALID_COLOR_MESSAGE);
+
+    let created = arklowdun_lib::commands::create_command(
+        &pool,
+        "categories",
+        category_payload("good", "#2563eb"),
+        None,
+    )
+    .await?;
+    assert_eq!(
+        created.get("color").and_then(Value::as_str),
+        Some("#2563EB")
+    );
+    let id = created
+        .get("id")
+        .and_then(Value::as_str)
+        .expect("created category has id")
+        .to_string();
+
+    let mut update = Map::new();
+    update.insert("color".into(), Value::from("#12345"));
+    let err = arklowdun_lib::commands::update_command(
+        &pool,
+        "categories",
+        &id,
+        update,
+        Some("default"),
+        None,
+        None,
+    )
+    .await
+    .expect_err("short hex rejected on update");
+    assert_eq!(err.code(), arklowdun_lib::util::INVALID_COLOR_CODE);
+
+    let stored: String = sqlx::query_scalar("SELECT color FROM categories WHERE id = ?")
+        .bind(&id)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(stored, "#2563EB");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn categories_recreate_deleted_slug_surfaces_restorable_conflict() -> Result<()> {
+    let pool = setup_pool().await?;
+
+    let created = arklowdun_lib::commands::create_command(
+        &pool,
+        "categories",
+        category_payload("travel", "#336699"),
+        None,
+    )
+    .await?;
+    let id = created
+        .get("id")
+        .and_then(Value::as_str)
+        .expect("created category has id")
+        .to_string();
+    arklowdun_lib::commands::delete_command(&pool, "categories", "default", &id, None).await?;
+
+    let err = arklowdun_lib::commands::create_command(
+        &pool,
+        "categories",
+        category_payload("travel", "#336699"),
+        None,
+    )
+    .await
+    .expect_err("recreating a deleted slug should conflict");
+    assert_eq!(
+        err.code(),
+        arklowdun_lib::commands::SOFT_DELETED_EXISTS_CODE
+    );
+    assert_eq!(err.context().get("id"), Some(&id));
+    assert_eq!(
+        err.context().get("slug").map(String::as_str),
+        Some("travel")
+    );
+
+    arklowdun_lib::commands::restore_command(&pool, "categories", "default", &id).await?;
+    let live: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM categories WHERE slug = 'travel' AND deleted_at IS NULL",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(live, 1);
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+
+use arklowdun_lib::{commands, migrate};
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    for (id, position) in [("bill_a", 0), ("bill_b", 1)] {
+        sqlx::query(
+            "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position)
+             VALUES (?1, 1200, 1700000000000, 'default', 100, 100, ?2)",
+        )
+        .bind(id)
+        .bind(position)
+        .execute(&pool)
+        .await
+        .expect("insert bill");
+    }
+    pool
+}
+
+fn no_cursors() -> BTreeMap<String, commands::ChangesCursor> {
+    BTreeMap::new()
+}
+
+fn ids(changes: &commands::ChangesSince, table: &str) -> Vec<String> {
+    changes.tables[table]
+        .iter()
+        .map(|row| row["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn updated_row_appears_and_untouched_row_does_not() {
+    let pool = setup_pool().await;
+    let mut data = Map::new();
+    data.insert("amount".into(), Value::from(1500));
+    commands::update_command(&pool, "bills", "bill_a", data, Some("default"), None, None)
+        .await
+        .expect("update bill");
+
+    let changes =
+        commands::changes_since(&pool, "default", 100, &["bills".to_string()], &no_cursors())
+            .await
+            .expect("changes since");
+    assert_eq!(ids(&changes, "bills"), vec!["bill_a".to_string()]);
+    assert_eq!(changes.tables["bills"][0]["amount"], Value::from(1500));
+    assert!(!changes.truncated);
+    assert_eq!(changes.tables.len(), 1);
+}
+
+#[tokio::test]
+async fn soft_deleted_rows_come_back_as_tombstones() {
+    let pool = setup_pool().await;
+    commands::delete_command(&pool, "bills", "default", "bill_b", None)
+        .await
+        .expect("delete bill");
+
+    let changes = commands::changes_since(&pool, "default", 100, &[], &no_cursors())
+        .await
+        .expect("changes since");
+    assert_eq!(ids(&changes, "bills"), vec!["bill_b".to_string()]);
+    assert!(changes.tables["bills"][0]["deleted_at"].is_i64());
+    assert!(changes.tables["notes"].is_empty());
+}
+
+#[tokio::test]
+async fn feed_is_capped_and_reports_truncation() {
+    let pool = setup_pool().await;
+    let extra = commands::CHANGES_SINCE_ROW_LIMIT as i64;
+    sqlx::query(
+        "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < ?1)
+         INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position)
+         SELECT 'bulk_' || i, 100, 1700000000000, 'default', 200, 200 + i, i + 1 FROM n",
+    )
+    .bind(extra)
+    .execute(&pool)
+    .await
+    .expect("insert bulk bills");
+
+    let changes =
+        commands::changes_since(&pool, "default", 0, &["bills".to_string()], &no_cursors())
+            .await
+            .expect("changes since");
+    assert!(changes.truncated);
+    assert_eq!(
+        changes.tables["bills"].len(),
+        commands::CHANGES_SINCE_ROW_LIMIT
+    );
+    assert_eq!(changes.tables["bills"][0]["id"], Value::from("bill_a"));
+}
+
+#[tokio::test]
+async fn truncated_feed_resumes_across_tables_without_gaps() {
+    let pool = setup_pool().await;
+    // Every bulk bill shares the cutoff's `updated_at`, so resuming by
+    // timestamp alone would skip the ones past the limit.
+    sqlx::query(
+        "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < ?1)
+         INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position)
+         SELECT printf('bulk_%05d', i), 100, 1700000000000, 'default', 200, 200, i + 1 FROM n",
+    )
+    .bind(commands::CHANGES_SINCE_ROW_LIMIT as i64)
+    .execute(&pool)
+    .await
+    .expect("insert bulk bills");
+    for (id, position) in [("note_a", 0), ("note_b", 1)] {
+        sqlx::query(
+            "INSERT INTO notes (id, household_id, position, created_at, updated_at, text)
+             VALUES (?1, 'default', ?2, 50, 50, 'n')",
+        )
+        .bind(id)
+        .bind(position)
+        .execute(&pool)
+        .await
+        .expect("insert note");
+    }
+
+    let tables = ["bills".to_string(), "notes".to_string()];
+    let first = commands::changes_since(&pool, "default", 0, &tables, &no_cursors())
+        .await
+        .expect("first page");
+    assert!(first.truncated);
+    assert!(!first.tables.contains_key("notes"));
+    assert!(!first.cursors.contains_key("notes"));
+
+    let second = commands::changes_since(&pool, "default", 0, &tables, &first.cursors)
+        .await
+        .expect("second page");
+    assert!(!second.truncated);
+    assert_eq!(ids(&second, "notes"), vec!["note_a", "note_b"]);
+
+    let mut seen: Vec<String> = ids(&first, "bills");
+    seen.extend(ids(&second, "bills"));
+    let total = commands::CHANGES_SINCE_ROW_LIMIT + 2;
+    assert_eq!(seen.len(), total);
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen.len(), total);
+    assert_eq!(second.cursors["notes"].id, "note_b");
+
+    let third = commands::changes_since(&pool, "default", 0, &tables, &second.cursors)
+        .await
+        .expect("caught up");
+    assert!(third.tables.values().all(Vec::is_empty));
+    assert_eq!(third.cursors, second.cursors);
+}
+
+#[tokio::test]
+async fn unknown_table_is_rejected() {
+    let pool = setup_pool().await;
+    let err = commands::changes_since(
+        &pool,
+        "default",
+        0,
+        &["sqlite_master".to_string()],
+        &no_cursors(),
+    )
+    .await
+    .expect_err("unknown table");
+    assert_eq!(err.code(), commands::CHANGES_SINCE_INVALID_TABLE_CODE);
+}
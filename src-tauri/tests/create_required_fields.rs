@@ -0,0 +1,100 @@
+use arklowdun_lib::{commands, migrate};
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+fn event_payload() -> Map<String, Value> {
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from("default"));
+    data.insert("title".into(), Value::from("Dentist"));
+    data.insert("tz".into(), Value::from("UTC"));
+    data.insert("start_at_utc".into(), Value::from(1_717_232_400_000i64));
+    data
+}
+
+#[tokio::test]
+async fn event_without_title_names_the_field() {
+    let pool = setup_pool().await;
+    let mut data = event_payload();
+    data.remove("title");
+
+    let err = commands::create_command(&pool, "events", data, None)
+        .await
+        .expect_err("title is required");
+    assert_eq!(err.code(), commands::VALIDATION_REQUIRED_FIELD_CODE);
+    assert_eq!(
+        err.context().get("field").map(String::as_str),
+        Some("title")
+    );
+}
+
+#[tokio::test]
+async fn null_counts_as_missing() {
+    let pool = setup_pool().await;
+    let mut data = event_payload();
+    data.insert("start_at_utc".into(), Value::Null);
+
+    let err = commands::create_command(&pool, "events", data, None)
+        .await
+        .expect_err("start is required");
+    assert_eq!(err.code(), commands::VALIDATION_REQUIRED_FIELD_CODE);
+    assert_eq!(
+        err.context().get("field").map(String::as_str),
+        Some("start_at_utc")
+    );
+}
+
+#[tokio::test]
+async fn budget_category_without_name_is_rejected_before_insert() {
+    let pool = setup_pool().await;
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from("default"));
+    data.insert("monthly_budget".into(), Value::from(5000));
+
+    let err = commands::create_command(&pool, "budget_categories", data, None)
+        .await
+        .expect_err("name is required");
+    assert_eq!(err.code(), commands::VALIDATION_REQUIRED_FIELD_CODE);
+    assert_eq!(err.context().get("field").map(String::as_str), Some("name"));
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM budget_categories")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn complete_event_is_created() {
+    let pool = setup_pool().await;
+    let created = commands::create_command(&pool, "events", event_payload(), None)
+        .await
+        .expect("create event");
+    assert_eq!(created["title"], Value::from("Dentist"));
+}
+
+#[tokio::test]
+async fn expense_template_without_amount_names_the_field() {
+    let pool = setup_pool().await;
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from("default"));
+    data.insert("category_id".into(), Value::from("cat_rent"));
+
+    let err = commands::create_command(&pool, "expense_templates", data, None)
+        .await
+        .expect_err("amount is required");
+    assert_eq!(err.code(), commands::VALIDATION_REQUIRED_FIELD_CODE);
+    assert_eq!(
+        err.context().get("field").map(String::as_str),
+        Some("amount")
+    );
+}
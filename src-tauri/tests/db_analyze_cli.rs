@@ -0,0 +1,53 @@
+use anyhow::Result;
+use arklowdun_lib::{db, migrate};
+use assert_cmd::Command;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn analyze_populates_planner_statistics() -> Result<()> {
+    let tmp = tempdir()?;
+    let appdata = tmp.path().join("appdata");
+    std::fs::create_dir_all(&appdata)?;
+    let db_path = appdata.join("arklowdun.sqlite3");
+
+    let pool = db::connect_sqlite_pool(&db_path).await?;
+    migrate::apply_migrations(&pool).await?;
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh_stats', 'Stats', 100, 100, NULL, 'UTC')",
+    )
+    .execute(&pool)
+    .await?;
+    for position in 0..20 {
+        sqlx::query(
+            "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y)
+             VALUES (?1, 'hh_stats', ?2, 100, 100, 1, 'note', '#FFFF88', 0, 0)",
+        )
+        .bind(format!("note_{position}"))
+        .bind(position)
+        .execute(&pool)
+        .await?;
+    }
+    pool.close().await;
+
+    let output = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args(["db", "analyze"])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "analyze failed: stdout={stdout} stderr={}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("Database analyze completed in"), "{stdout}");
+
+    let pool = db::connect_sqlite_pool(&db_path).await?;
+    let notes_stats: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM sqlite_stat1 WHERE tbl = 'notes'")
+            .fetch_one(&pool)
+            .await?;
+    assert!(notes_stats > 0);
+    pool.close().await;
+    Ok(())
+}
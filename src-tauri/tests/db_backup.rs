@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use arklowdun_lib::db::backup;
-use arklowdun_lib::db::manifest::{file_sha256, read_manifest, BackupManifest};
+use arklowdun_lib::db::manifest::{file_sha256, read_manifest, BackupKind, BackupManifest};
 use assert_cmd::Command;
 use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
 use sqlx::{ConnectOptions, Connection};
@@ -243,3 +243,253 @@ async fn backup_retention_prunes_old_snapshots() -> Result<()> {
     assert_eq!(dirs.len(), 2, "expected retention to prune older backups");
     Ok(())
 }
+
+#[tokio::test]
+async fn backup_verify_detects_corruption() -> Result<()> {
+    let tmp = tempdir()?;
+    let db_path = tmp.path().join("arklowdun.sqlite3");
+    prepare_database(&db_path).await?;
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}", db_path.display()))
+        .await?;
+    let entry = backup::create_backup(&pool, &db_path).await?;
+    pool.close().await;
+
+    let verified = backup::verify_backup(Path::new(&entry.directory)).await?;
+    assert_eq!(verified.status, backup::BackupVerifyStatus::Ok);
+    assert_eq!(verified.actual_sha256, entry.manifest.sha256);
+    assert_eq!(verified.actual_size_bytes, entry.manifest.db_size_bytes);
+    assert!(verified.integrity_ok);
+
+    let sqlite_path = PathBuf::from(&entry.sqlite_path);
+    let mut bytes = fs::read(&sqlite_path)?;
+    for byte in bytes.iter_mut().take(64) {
+        *byte = !*byte;
+    }
+    fs::write(&sqlite_path, &bytes)?;
+
+    let corrupted = backup::verify_backup(&sqlite_path).await?;
+    assert_eq!(corrupted.status, backup::BackupVerifyStatus::Mismatch);
+    assert_ne!(corrupted.actual_sha256, corrupted.expected_sha256);
+    assert_eq!(corrupted.actual_size_bytes, corrupted.expected_size_bytes);
+    assert!(!corrupted.integrity_ok);
+    assert!(corrupted.integrity_details.is_some());
+    Ok(())
+}
+
+async fn insert_rows(db_path: &Path, count: usize) -> Result<()> {
+    let mut conn = SqliteConnectOptions::new()
+        .filename(db_path)
+        .connect()
+        .await?;
+    let payload = "x".repeat(512);
+    for _ in 0..count {
+        sqlx::query("INSERT INTO sample(value) VALUES (?1);")
+            .bind(&payload)
+            .execute(&mut conn)
+            .await?;
+    }
+    conn.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn differential_backup_is_small_and_restores() -> Result<()> {
+    let tmp = tempdir()?;
+    let db_path = tmp.path().join("arklowdun.sqlite3");
+    prepare_database(&db_path).await?;
+    insert_rows(&db_path, 2_000).await?;
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}", db_path.display()))
+        .await?;
+
+    // Without a full backup to diff against, a full snapshot is taken.
+    let full = backup::create_differential_backup(&pool, &db_path).await?;
+    assert_eq!(full.manifest.kind, BackupKind::Full);
+    let full_size = fs::metadata(&full.sqlite_path)?.len();
+
+    sqlx::query("UPDATE sample SET value = 'changed' WHERE id = 1;")
+        .execute(&pool)
+        .await?;
+    let diff = backup::create_differential_backup(&pool, &db_path).await?;
+    pool.close().await;
+
+    assert_eq!(diff.manifest.kind, BackupKind::Differential);
+    assert_eq!(
+        diff.manifest.base_directory.as_deref(),
+        Path::new(&full.directory)
+            .file_name()
+            .and_then(|name| name.to_str())
+    );
+    let diff_size = fs::metadata(&diff.sqlite_path)?.len();
+    assert!(
+        diff_size * 10 < full_size,
+        "diff {diff_size} bytes vs full {full_size} bytes"
+    );
+
+    let restored = tmp.path().join("restored").join("arklowdun.sqlite3");
+    backup::restore_backup_to(Path::new(&diff.directory), &restored).await?;
+    assert_eq!(file_sha256(&restored)?, diff.manifest.sha256);
+
+    let mut conn = SqliteConnectOptions::new()
+        .filename(&restored)
+        .read_only(true)
+        .connect()
+        .await?;
+    let value: String = sqlx::query_scalar("SELECT value FROM sample WHERE id = 1")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, "changed");
+    let rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sample")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(rows, 2_001);
+    conn.close().await?;
+
+    let verified = backup::verify_backup(Path::new(&diff.directory)).await?;
+    assert_eq!(verified.status, backup::BackupVerifyStatus::Ok);
+    Ok(())
+}
+
+#[tokio::test]
+async fn retention_keeps_backup_chains_whole() -> Result<()> {
+    let tmp = tempdir()?;
+    let db_path = tmp.path().join("arklowdun.sqlite3");
+    prepare_database(&db_path).await?;
+    let root = tmp.path().join("backups");
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}", db_path.display()))
+        .await?;
+
+    // One full backup and more differentials than the default max count of 5.
+    let full = backup::create_backup(&pool, &db_path).await?;
+    for round in 0..7 {
+        sqlx::query("UPDATE sample SET value = ?1 WHERE id = 1;")
+            .bind(format!("round {round}"))
+            .execute(&pool)
+            .await?;
+        let diff = backup::create_differential_backup(&pool, &db_path).await?;
+        assert_eq!(diff.manifest.kind, BackupKind::Differential);
+        assert!(Path::new(&diff.directory).is_dir(), "new backup was pruned");
+        assert!(diff.base_present);
+    }
+    assert_eq!(list_backup_dirs(&root)?.len(), 8);
+    assert!(Path::new(&full.directory).is_dir());
+
+    // Five more chains push the oldest one out as a whole.
+    let mut latest = None;
+    for _ in 0..5 {
+        let entry = backup::create_backup(&pool, &db_path).await?;
+        assert!(
+            Path::new(&entry.directory).is_dir(),
+            "new backup was pruned"
+        );
+        latest = Some(entry);
+    }
+    let diff = backup::create_differential_backup(&pool, &db_path).await?;
+    assert!(Path::new(&diff.directory).is_dir());
+    assert_eq!(
+        diff.manifest.base_directory.as_deref(),
+        latest
+            .as_ref()
+            .and_then(|entry| Path::new(&entry.directory).file_name())
+            .and_then(|name| name.to_str())
+    );
+
+    let overview = backup::overview(&pool, &db_path).await?;
+    pool.close().await;
+    assert!(!Path::new(&full.directory).exists());
+    assert_eq!(list_backup_dirs(&root)?.len(), 6);
+    assert!(overview.backups.iter().all(|entry| entry.base_present));
+    Ok(())
+}
+
+#[tokio::test]
+async fn differential_backup_without_base_verifies_as_mismatch() -> Result<()> {
+    let tmp = tempdir()?;
+    let db_path = tmp.path().join("arklowdun.sqlite3");
+    prepare_database(&db_path).await?;
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}", db_path.display()))
+        .await?;
+    let full = backup::create_backup(&pool, &db_path).await?;
+    sqlx::query("UPDATE sample SET value = 'changed' WHERE id = 1;")
+        .execute(&pool)
+        .await?;
+    let diff = backup::create_differential_backup(&pool, &db_path).await?;
+    assert_eq!(diff.manifest.kind, BackupKind::Differential);
+    assert!(diff.base_present);
+
+    // A path naming the database file inside a differential backup is
+    // resolved to its diff.
+    let by_file =
+        backup::verify_backup(&Path::new(&diff.directory).join("arklowdun.sqlite3")).await?;
+    assert_eq!(by_file.status, backup::BackupVerifyStatus::Ok);
+    assert_eq!(by_file.kind, BackupKind::Differential);
+    assert_eq!(by_file.sqlite_path, diff.sqlite_path);
+
+    fs::remove_dir_all(&full.directory)?;
+    let verified = backup::verify_backup(Path::new(&diff.directory)).await?;
+    assert_eq!(verified.status, backup::BackupVerifyStatus::Mismatch);
+    assert!(verified.base_details.is_some());
+
+    let overview = backup::overview(&pool, &db_path).await?;
+    pool.close().await;
+    let listed = overview
+        .backups
+        .iter()
+        .find(|entry| entry.directory == diff.directory)
+        .context("differential backup listed")?;
+    assert!(!listed.base_present);
+    Ok(())
+}
+
+#[tokio::test]
+async fn restore_backup_cli_rebuilds_database_without_overwriting() -> Result<()> {
+    let tmp = tempdir()?;
+    let appdata = tmp.path().join("appdata");
+    let db_path = appdata.join("arklowdun.sqlite3");
+    prepare_database(&db_path).await?;
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}", db_path.display()))
+        .await?;
+    backup::create_backup(&pool, &db_path).await?;
+    sqlx::query("UPDATE sample SET value = 'changed' WHERE id = 1;")
+        .execute(&pool)
+        .await?;
+    let diff = backup::create_differential_backup(&pool, &db_path).await?;
+    pool.close().await;
+
+    let restored = tmp.path().join("restored.sqlite3");
+    let output = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args(["db", "restore-backup", "--from"])
+        .arg(&diff.directory)
+        .arg("--out")
+        .arg(&restored)
+        .output()?;
+    assert!(
+        output.status.success(),
+        "restore failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(file_sha256(&restored)?, diff.manifest.sha256);
+
+    let live_sha = file_sha256(&db_path)?;
+    let err = backup::restore_backup_to(Path::new(&diff.directory), &db_path)
+        .await
+        .expect_err("existing target must not be replaced");
+    assert_eq!(err.code(), "DB_BACKUP/RESTORE_TARGET_EXISTS");
+    assert_eq!(file_sha256(&db_path)?, live_sha);
+    Ok(())
+}
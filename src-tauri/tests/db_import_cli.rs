@@ -0,0 +1,204 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use arklowdun_lib::{
+    db,
+    export::{create_export, ExportOptions},
+    import::ImportCheckpoint,
+    migrate,
+    vault::Vault,
+};
+use assert_cmd::Command;
+use tempfile::tempdir;
+
+async fn migrated_pool(path: &Path) -> Result<sqlx::SqlitePool> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let pool = db::connect_sqlite_pool(path).await?;
+    migrate::apply_migrations(&pool).await?;
+    Ok(pool)
+}
+
+async fn export_bundle(source_db: &Path, out: &Path, attachments: &Path) -> Result<String> {
+    let pool = migrated_pool(source_db).await?;
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh_cli', 'Imported', 100, 100, NULL, 'UTC')",
+    )
+    .execute(&pool)
+    .await?;
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y)
+         VALUES ('note_cli', 'hh_cli', 0, 100, 100, 1, 'from bundle', '#FFFF88', 0, 0)",
+    )
+    .execute(&pool)
+    .await?;
+    let entry = create_export(
+        &pool,
+        Arc::new(Vault::new(attachments)),
+        ExportOptions {
+            out_parent: out.to_path_buf(),
+            compress: false,
+            include_deletions_audit: false,
+            tables: Default::default(),
+        },
+    )
+    .await?;
+    pool.close().await;
+    Ok(entry.directory.display().to_string())
+}
+
+#[tokio::test]
+async fn resume_skips_tables_finished_before_interruption() -> Result<()> {
+    let tmp = tempdir()?;
+    let appdata = tmp.path().join("appdata");
+    let target_db = appdata.join("arklowdun.sqlite3");
+    migrated_pool(&target_db).await?.close().await;
+
+    let bundle = export_bundle(
+        &tmp.path().join("source/arklowdun.sqlite3"),
+        &tmp.path().join("exports"),
+        &tmp.path().join("source/attachments"),
+    )
+    .await?;
+
+    let dry_run = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args(["db", "import", "--in", &bundle, "--dry-run"])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&dry_run.stdout);
+    assert!(
+        dry_run.status.success(),
+        "dry run failed: stdout={stdout} stderr={}",
+        String::from_utf8_lossy(&dry_run.stderr)
+    );
+    let digest = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("Plan digest: "))
+        .expect("dry run prints the plan digest")
+        .trim()
+        .to_string();
+
+    let rejected = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args([
+            "db",
+            "import",
+            "--in",
+            &bundle,
+            "--expected-digest",
+            "stale",
+        ])
+        .output()?;
+    assert_eq!(rejected.status.code(), Some(1));
+
+    // Stop the first run part-way: household commits, notes are refused.
+    let pool = db::connect_sqlite_pool(&target_db).await?;
+    sqlx::query(
+        "CREATE TRIGGER block_notes BEFORE INSERT ON notes BEGIN SELECT RAISE(ABORT, 'interrupted'); END",
+    )
+    .execute(&pool)
+    .await?;
+    pool.close().await;
+
+    let interrupted = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args(["db", "import", "--in", &bundle, "--expected-digest"])
+        .arg(&digest)
+        .output()?;
+    assert_eq!(
+        interrupted.status.code(),
+        Some(2),
+        "stderr={}",
+        String::from_utf8_lossy(&interrupted.stderr)
+    );
+    let reports_dir = appdata.join("reports");
+    let checkpoint = ImportCheckpoint::load(&reports_dir)?.expect("checkpoint left behind");
+    assert_eq!(checkpoint.plan_digest, digest);
+    assert!(checkpoint
+        .completed_tables
+        .contains(&"household".to_string()));
+    assert!(!checkpoint.completed_tables.contains(&"notes".to_string()));
+
+    let pool = db::connect_sqlite_pool(&target_db).await?;
+    sqlx::query("DROP TRIGGER block_notes")
+        .execute(&pool)
+        .await?;
+    pool.close().await;
+
+    let report_path = tmp.path().join("custom/import-report.json");
+    let resumed = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args([
+            "db",
+            "import",
+            "--in",
+            &bundle,
+            "--resume",
+            "--expected-digest",
+        ])
+        .arg(&digest)
+        .arg("--report")
+        .arg(&report_path)
+        .output()?;
+    let stdout = String::from_utf8_lossy(&resumed.stdout);
+    assert!(
+        resumed.status.success(),
+        "resumed import failed: stdout={stdout} stderr={}",
+        String::from_utf8_lossy(&resumed.stderr)
+    );
+    assert!(stdout.contains("Resuming import"), "{stdout}");
+    assert!(stdout.contains("Skipping"), "{stdout}");
+    assert!(ImportCheckpoint::load(&reports_dir)?.is_none());
+
+    let report: serde_json::Value = serde_json::from_slice(&std::fs::read(&report_path)?)?;
+    let executed = report["execution"]["tables"]
+        .as_object()
+        .expect("executed tables");
+    assert!(!executed.contains_key("household"), "{executed:?}");
+    assert_eq!(executed["notes"]["adds"], 1);
+
+    let pool = db::connect_sqlite_pool(&target_db).await?;
+    let text: String = sqlx::query_scalar("SELECT text FROM notes WHERE id = 'note_cli'")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(text, "from bundle");
+    pool.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn progress_flag_prints_plain_lines_when_piped() -> Result<()> {
+    let tmp = tempdir()?;
+    let appdata = tmp.path().join("appdata");
+    migrated_pool(&appdata.join("arklowdun.sqlite3"))
+        .await?
+        .close()
+        .await;
+
+    let bundle = export_bundle(
+        &tmp.path().join("source/arklowdun.sqlite3"),
+        &tmp.path().join("exports"),
+        &tmp.path().join("source/attachments"),
+    )
+    .await?;
+
+    let output = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args(["db", "import", "--in", &bundle, "--progress"])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "import failed: stdout={stdout} stderr={}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        stdout.contains("Importing notes: 1/1 rows (100%)"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains('\r'), "{stdout:?}");
+    Ok(())
+}
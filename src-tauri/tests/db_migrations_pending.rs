@@ -0,0 +1,50 @@
+use arklowdun_lib::migrate;
+use sqlx::SqlitePool;
+
+#[tokio::test]
+async fn reports_only_unrecorded_migration() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+
+    assert!(migrate::pending_migrations(&pool)
+        .await
+        .expect("pending after apply")
+        .is_empty());
+
+    sqlx::query("DELETE FROM schema_migrations WHERE version = ?")
+        .bind("0028_pets_image_path.up.sql")
+        .execute(&pool)
+        .await
+        .expect("forget latest migration");
+
+    let pending = migrate::pending_migrations(&pool)
+        .await
+        .expect("pending migrations");
+    assert_eq!(
+        pending,
+        vec![migrate::PendingMigration {
+            version: "0028_pets_image_path.up.sql".into(),
+            destructive: false,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn fresh_database_lists_every_migration_in_order() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+
+    let pending = migrate::pending_migrations(&pool)
+        .await
+        .expect("pending migrations");
+    let versions: Vec<&str> = pending.iter().map(|m| m.version.as_str()).collect();
+    assert_eq!(versions.first(), Some(&"0001_baseline.sql"));
+    let mut sorted = versions.clone();
+    sorted.sort();
+    assert_eq!(versions, sorted);
+}
@@ -0,0 +1,122 @@
+use arklowdun_lib::{db::manifest, migrate};
+use sqlx::SqlitePool;
+
+#[tokio::test]
+async fn schema_info_lists_applied_migrations() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+
+    let info = manifest::schema_info(&pool).await.expect("schema info");
+
+    let rows: Vec<String> =
+        sqlx::query_scalar("SELECT version FROM schema_migrations ORDER BY version")
+            .fetch_all(&pool)
+            .await
+            .expect("read schema_migrations");
+    assert!(!rows.is_empty());
+    assert_eq!(info.applied_migrations, rows);
+    assert_eq!(info.latest_version.as_ref(), rows.last());
+    assert_eq!(
+        info.schema_hash,
+        manifest::schema_hash(&pool).await.expect("schema hash")
+    );
+}
+
+#[tokio::test]
+async fn path_info_reports_database_file() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let db_path = dir.path().join("arklowdun.sqlite3");
+    let pool = arklowdun_lib::db::connect_sqlite_pool(&db_path)
+        .await
+        .expect("connect sqlite file");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh', 'Household', 1, 1, NULL, 'UTC')",
+    )
+    .execute(&pool)
+    .await
+    .expect("write row");
+    sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+        .execute(&pool)
+        .await
+        .expect("checkpoint wal");
+
+    let info = manifest::path_info(&db_path, Some(dir.path()));
+    assert_eq!(info.path, db_path.display().to_string());
+    assert_eq!(
+        info.display_path,
+        std::path::Path::new("~")
+            .join("arklowdun.sqlite3")
+            .display()
+            .to_string()
+    );
+    assert!(info.size_bytes > 0);
+    assert!(info.writable);
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn schema_dump_lists_ddl_without_internal_tables() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+
+    let dump = manifest::schema_dump(&pool).await.expect("schema dump");
+
+    assert!(
+        dump.contains("CREATE TABLE events"),
+        "events missing: {dump}"
+    );
+    assert!(dump.contains("CREATE INDEX"));
+    assert!(!dump.contains("sqlite_sequence"));
+    assert!(!dump.contains("sqlite_autoindex"));
+    let first_index = dump.find("CREATE INDEX").expect("index present");
+    let last_table = dump.rfind("CREATE TABLE").expect("table present");
+    assert!(last_table < first_index, "tables should precede indexes");
+    assert_eq!(
+        dump,
+        manifest::schema_dump(&pool).await.expect("second dump")
+    );
+}
+
+#[tokio::test]
+async fn index_stats_reports_events_indexes() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query("CREATE INDEX zz_events_household_only ON events(household_id)")
+        .execute(&pool)
+        .await
+        .expect("create redundant index");
+
+    let stats = manifest::index_stats(&pool).await.expect("index stats");
+    let find = |name: &str| {
+        stats
+            .iter()
+            .find(|stat| stat.name == name)
+            .unwrap_or_else(|| panic!("{name} missing from {stats:?}"))
+    };
+
+    let start = find("events_household_start_at_utc_idx");
+    assert_eq!(start.table, "events");
+    assert_eq!(start.columns, vec!["household_id", "start_at_utc"]);
+    assert!(!start.partial);
+    assert!(start.covered_by.is_none());
+    assert!(find("events_household_end_at_utc_idx").covered_by.is_none());
+    assert!(find("idx_events_household_active").partial);
+    assert!(find("zz_events_household_only").covered_by.is_some());
+    assert!(stats.iter().all(|stat| !stat.name.starts_with("sqlite_")));
+}
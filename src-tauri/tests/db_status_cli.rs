@@ -133,6 +133,87 @@ async fn db_status_cli_reports_error_and_nonzero_exit() -> Result<()> {
     Ok(())
 }
 
+async fn prepare_many_fk_violations(db_path: &Path, count: usize) -> Result<Vec<String>> {
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut conn = SqliteConnectOptions::new()
+        .filename(db_path)
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal)
+        .synchronous(SqliteSynchronous::Full)
+        .foreign_keys(true)
+        .connect()
+        .await?;
+
+    sqlx::query("PRAGMA foreign_keys = OFF;")
+        .execute(&mut conn)
+        .await?;
+    sqlx::query("CREATE TABLE parent(id INTEGER PRIMARY KEY);")
+        .execute(&mut conn)
+        .await?;
+    let mut tables = Vec::with_capacity(count);
+    for index in 0..count {
+        let table = format!("orphan_{index:02}");
+        sqlx::query(&format!(
+            "CREATE TABLE {table}(id INTEGER PRIMARY KEY, parent_id INTEGER REFERENCES parent(id));"
+        ))
+        .execute(&mut conn)
+        .await?;
+        sqlx::query(&format!(
+            "INSERT INTO {table}(id, parent_id) VALUES (1, 999);"
+        ))
+        .execute(&mut conn)
+        .await?;
+        tables.push(table);
+    }
+    sqlx::query("PRAGMA foreign_keys = ON;")
+        .execute(&mut conn)
+        .await?;
+
+    conn.close().await?;
+    Ok(tables)
+}
+
+#[tokio::test]
+async fn db_status_cli_verbose_lists_all_offenders() -> Result<()> {
+    let tmp = tempdir()?;
+    let appdata = tmp.path().join("appdata");
+    let db_path = appdata.join("arklowdun.sqlite3");
+
+    let tables = prepare_many_fk_violations(&db_path, 12).await?;
+
+    let output = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args(["db", "status"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("more offender(s) not shown"));
+    let elided: Vec<&String> = tables
+        .iter()
+        .filter(|table| !stdout.contains(table.as_str()))
+        .collect();
+    assert!(!elided.is_empty(), "default view should elide offenders");
+
+    let verbose = Command::cargo_bin("arklowdun")?
+        .env("ARK_FAKE_APPDATA", &appdata)
+        .args(["db", "status", "--verbose"])
+        .output()?;
+    assert_eq!(verbose.status.code(), Some(1));
+    let verbose_stdout = String::from_utf8_lossy(&verbose.stdout);
+    assert!(!verbose_stdout.contains("more offender(s) not shown"));
+    for table in elided {
+        assert!(
+            verbose_stdout.contains(table.as_str()),
+            "verbose output missing offender {table}: {verbose_stdout}"
+        );
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn db_vacuum_succeeds_on_healthy_db() -> Result<()> {
     let tmp = tempdir()?;
@@ -0,0 +1,116 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use anyhow::Result;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+use tempfile::TempDir;
+
+use arklowdun_lib::{
+    db::health::{DbHealthCheck, DbHealthReport, DbHealthStatus},
+    db::upkeep::{run_upkeep_tick, UpkeepConfig, UpkeepOutcome},
+    events_tz_backfill::BackfillCoordinator,
+    files_indexer::FilesIndexer,
+    household_active::StoreHandle,
+    migrate,
+    pets::metrics::PetAttachmentMetrics,
+    vault::Vault,
+    vault_migration::VaultMigrationManager,
+    AppState,
+};
+
+fn stale_report() -> DbHealthReport {
+    DbHealthReport {
+        status: DbHealthStatus::Error,
+        checks: vec![DbHealthCheck {
+            name: "quick_check".into(),
+            passed: false,
+            duration_ms: 0,
+            details: Some("stale".into()),
+        }],
+        offenders: Vec::new(),
+        schema_hash: String::new(),
+        app_version: String::new(),
+        generated_at: "stale".into(),
+    }
+}
+
+async fn build_state(dir: &TempDir) -> Result<AppState> {
+    let db_path = dir.path().join("upkeep.sqlite3");
+    let options = SqliteConnectOptions::new()
+        .filename(&db_path)
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal)
+        .foreign_keys(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await?;
+    migrate::apply_migrations(&pool).await?;
+
+    let attachments_root = dir.path().join("attachments");
+    std::fs::create_dir_all(&attachments_root)?;
+    let vault = Arc::new(Vault::new(&attachments_root));
+    Ok(AppState {
+        pool: Arc::new(RwLock::new(pool.clone())),
+        active_household_id: Arc::new(Mutex::new(String::new())),
+        store: StoreHandle::in_memory(),
+        backfill: Arc::new(Mutex::new(BackfillCoordinator::new())),
+        db_health: Arc::new(Mutex::new(stale_report())),
+        db_path: Arc::new(db_path),
+        vault: vault.clone(),
+        vault_migration: Arc::new(VaultMigrationManager::new(&attachments_root)?),
+        maintenance: Arc::new(AtomicBool::new(false)),
+        maintenance_reason: Arc::new(Mutex::new(None)),
+        files_indexer: Arc::new(FilesIndexer::new(pool, vault)),
+        pet_metrics: Arc::new(PetAttachmentMetrics::new()),
+    })
+}
+
+#[test]
+fn upkeep_is_disabled_by_default() {
+    let config = UpkeepConfig::default();
+    assert_eq!(config.interval, Duration::ZERO);
+    assert!(!config.enabled());
+}
+
+#[tokio::test]
+async fn tick_refreshes_health_cache() -> Result<()> {
+    let dir = TempDir::new()?;
+    let state = build_state(&dir).await?;
+
+    let outcome = run_upkeep_tick(&state).await?;
+    let UpkeepOutcome::Refreshed(report) = outcome else {
+        panic!("expected a refreshed report, got {outcome:?}");
+    };
+    assert_eq!(report.status, DbHealthStatus::Ok);
+
+    let cached = state.db_health.lock().unwrap().clone();
+    assert_eq!(cached.status, DbHealthStatus::Ok);
+    assert_ne!(cached.generated_at, "stale");
+    assert_eq!(cached.generated_at, report.generated_at);
+    Ok(())
+}
+
+#[tokio::test]
+async fn tick_leaves_cache_alone_during_maintenance_and_cascades() -> Result<()> {
+    let dir = TempDir::new()?;
+    let state = build_state(&dir).await?;
+
+    state.begin_manual_maintenance("vacuum".into())?;
+    let outcome = run_upkeep_tick(&state).await?;
+    assert!(matches!(outcome, UpkeepOutcome::SkippedMaintenance));
+    assert_eq!(state.db_health.lock().unwrap().generated_at, "stale");
+    state.end_manual_maintenance()?;
+
+    state.db_health.lock().unwrap().checks.push(DbHealthCheck {
+        name: "cascade_state".into(),
+        passed: false,
+        duration_ms: 0,
+        details: Some("Unfinished cascade for hh".into()),
+    });
+    let outcome = run_upkeep_tick(&state).await?;
+    assert!(matches!(outcome, UpkeepOutcome::SkippedCascade));
+    assert_eq!(state.db_health.lock().unwrap().generated_at, "stale");
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use arklowdun_lib::{commands, deletions_audit, migrate, note_links};
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    for (id, position) in [("bill_a", 0), ("bill_b", 1)] {
+        sqlx::query(
+            "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position)
+             VALUES (?1, 1200, 1700000000000, 'default', 100, 100, ?2)",
+        )
+        .bind(id)
+        .bind(position)
+        .execute(&pool)
+        .await
+        .expect("insert bill");
+    }
+    pool
+}
+
+#[tokio::test]
+async fn deleting_a_bill_records_an_audit_row() {
+    let pool = setup_pool().await;
+    commands::delete_command(&pool, "bills", "default", "bill_a", None)
+        .await
+        .expect("delete bill");
+
+    let entries = deletions_audit::list_deletions(&pool, "default", None)
+        .await
+        .expect("list deletions");
+    assert_eq!(entries.len(), 1);
+    let entry = &entries[0];
+    assert_eq!(entry.table_name, "bills");
+    assert_eq!(entry.row_id, "bill_a");
+    assert_eq!(entry.household_id, "default");
+    assert_eq!(entry.operation, deletions_audit::OPERATION_SOFT_DELETE);
+
+    let deleted_at: i64 = sqlx::query_scalar("SELECT deleted_at FROM bills WHERE id = 'bill_a'")
+        .fetch_one(&pool)
+        .await
+        .expect("bill deleted_at");
+    assert_eq!(entry.deleted_at, deleted_at);
+
+    let later = deletions_audit::list_deletions(&pool, "default", Some(deleted_at + 1))
+        .await
+        .expect("list deletions since");
+    assert!(later.is_empty());
+}
+
+#[tokio::test]
+async fn failed_delete_leaves_no_audit_row() {
+    let pool = setup_pool().await;
+    commands::delete_command(&pool, "bills", "default", "missing", None)
+        .await
+        .expect_err("missing bill");
+    commands::delete_command(&pool, "bills", "other", "bill_b", None)
+        .await
+        .expect_err("bill in another household");
+
+    let entries = deletions_audit::list_deletions(&pool, "default", None)
+        .await
+        .expect("list deletions");
+    assert!(entries.is_empty());
+}
+
+#[tokio::test]
+async fn soft_deleting_note_links_records_audit_rows() {
+    let pool = setup_pool().await;
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, text, color, x, y)
+         VALUES ('note_a', 'default', 0, 100, 100, 'n', '#FFFF88', 0, 0)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert note");
+    sqlx::query(
+        "INSERT INTO note_links (id, household_id, note_id, entity_type, entity_id, relation, created_at, updated_at)
+         VALUES ('link_a', 'default', 'note_a', 'event', 'event_a', 'attached_to', 100, 100)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert note link");
+
+    let mut conn = pool.acquire().await.expect("acquire connection");
+    let removed = note_links::soft_delete_links_for_entity(
+        &mut conn,
+        "default",
+        note_links::NoteLinkEntityType::Event,
+        "event_a",
+    )
+    .await
+    .expect("soft delete links");
+    assert_eq!(removed, 1);
+    drop(conn);
+
+    let entries = deletions_audit::list_deletions(&pool, "default", None)
+        .await
+        .expect("list deletions");
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].table_name, "note_links");
+    assert_eq!(entries[0].row_id, "link_a");
+}
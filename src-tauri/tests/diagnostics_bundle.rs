@@ -0,0 +1,77 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::io::Read;
+
+use arklowdun_lib::db::health::{DbHealthReport, DbHealthStatus};
+use arklowdun_lib::diagnostics::{self, BUNDLE_HEALTH_ENTRY, BUNDLE_SUMMARY_ENTRY};
+use tempfile::tempdir;
+
+#[test]
+fn bundle_contains_logs_summary_and_health() {
+    let appdata = tempdir().unwrap();
+    let logs_dir = appdata.path().join("logs");
+    std::fs::create_dir_all(&logs_dir).unwrap();
+    std::fs::write(
+        logs_dir.join("arklowdun.log"),
+        "{\"event\":\"login\",\"user\":\"someone@example.com\"}\n",
+    )
+    .unwrap();
+    std::fs::write(logs_dir.join("arklowdun.log.1"), "older line\n").unwrap();
+    std::fs::write(logs_dir.join("unrelated.txt"), "not a log\n").unwrap();
+    std::env::set_var("ARK_FAKE_APPDATA", appdata.path());
+
+    let app = tauri::test::mock_app();
+    let health = DbHealthReport {
+        status: DbHealthStatus::Ok,
+        checks: Vec::new(),
+        offenders: Vec::new(),
+        schema_hash: "test".into(),
+        app_version: "test".into(),
+        generated_at: "2024-01-01T00:00:00Z".into(),
+    };
+    let out = tempdir().unwrap();
+    let zip_path = diagnostics::export_bundle(app.handle(), out.path(), &health).unwrap();
+    assert!(zip_path.starts_with(out.path()));
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(&zip_path).unwrap()).unwrap();
+    let mut names: Vec<String> = archive.file_names().map(str::to_string).collect();
+    names.sort();
+    assert_eq!(
+        names,
+        vec![
+            "about.json",
+            BUNDLE_HEALTH_ENTRY,
+            "logs/arklowdun.log",
+            "logs/arklowdun.log.1",
+            BUNDLE_SUMMARY_ENTRY,
+        ]
+    );
+
+    let mut log = String::new();
+    archive
+        .by_name("logs/arklowdun.log")
+        .unwrap()
+        .read_to_string(&mut log)
+        .unwrap();
+    assert!(log.contains("<redacted:email>"));
+    assert!(!log.contains("someone@example.com"));
+
+    let mut health_json = String::new();
+    archive
+        .by_name(BUNDLE_HEALTH_ENTRY)
+        .unwrap()
+        .read_to_string(&mut health_json)
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&health_json).unwrap();
+    assert_eq!(parsed["status"], "ok");
+}
+
+#[test]
+fn redaction_masks_home_and_secrets() {
+    let home = std::path::Path::new("/home/alex");
+    let text = "opened /home/alex/docs at 192.168.1.20 with token=abc123";
+    assert_eq!(
+        diagnostics::redact_text(text, Some(home)),
+        "opened ~/docs at <redacted:ip> with token=\"<redacted:secret>\""
+    );
+}
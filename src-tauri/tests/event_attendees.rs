@@ -0,0 +1,118 @@
+use arklowdun_lib::{event_attendees, migrate, schedule};
+use sqlx::SqlitePool;
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const BASE_MS: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    for id in ["hh", "other"] {
+        sqlx::query(
+            "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+             VALUES (?1, ?1, ?2, ?2, NULL, 'UTC')",
+        )
+        .bind(id)
+        .bind(BASE_MS)
+        .execute(&pool)
+        .await
+        .expect("insert household");
+    }
+    for (id, name, household, position) in [
+        ("alex", "Alex", "hh", 0),
+        ("sam", "Sam", "hh", 1),
+        ("jo", "Jo", "hh", 2),
+        ("stranger", "Stranger", "other", 0),
+    ] {
+        sqlx::query(
+            "INSERT INTO family_members (id, name, household_id, created_at, updated_at, position)
+             VALUES (?1, ?2, ?3, ?4, ?4, ?5)",
+        )
+        .bind(id)
+        .bind(name)
+        .bind(household)
+        .bind(BASE_MS)
+        .bind(position)
+        .execute(&pool)
+        .await
+        .expect("insert member");
+    }
+    sqlx::query(
+        "INSERT INTO events (id, title, household_id, created_at, updated_at, tz, start_at_utc, end_at_utc)
+         VALUES ('evt', 'Swimming', 'hh', ?1, ?1, 'UTC', ?1, ?2)",
+    )
+    .bind(BASE_MS)
+    .bind(BASE_MS + HOUR_MS)
+    .execute(&pool)
+    .await
+    .expect("insert event");
+    pool
+}
+
+fn member_ids(attendees: &[event_attendees::EventAttendee]) -> Vec<&str> {
+    attendees.iter().map(|a| a.member_id.as_str()).collect()
+}
+
+#[tokio::test]
+async fn set_attendees_stores_and_lists_members() {
+    let pool = setup_pool().await;
+    let attendees = event_attendees::set_attendees(
+        &pool,
+        "hh",
+        "evt",
+        &["sam".into(), "alex".into(), "sam".into()],
+    )
+    .await
+    .expect("set attendees");
+    assert_eq!(member_ids(&attendees), vec!["alex", "sam"]);
+
+    let listed = event_attendees::list_attendees(&pool, "hh", "evt")
+        .await
+        .expect("list attendees");
+    assert_eq!(listed, attendees);
+
+    let busy = schedule::family_member_busy(&pool, "alex", BASE_MS - HOUR_MS, BASE_MS + HOUR_MS)
+        .await
+        .expect("busy query");
+    assert_eq!(busy.busy.len(), 1);
+}
+
+#[tokio::test]
+async fn set_attendees_replaces_previous_set() {
+    let pool = setup_pool().await;
+    event_attendees::set_attendees(&pool, "hh", "evt", &["alex".into(), "sam".into()])
+        .await
+        .expect("initial set");
+    let replaced = event_attendees::set_attendees(&pool, "hh", "evt", &["jo".into(), "sam".into()])
+        .await
+        .expect("replace set");
+    assert_eq!(member_ids(&replaced), vec!["jo", "sam"]);
+
+    let cleared = event_attendees::set_attendees(&pool, "hh", "evt", &[])
+        .await
+        .expect("clear set");
+    assert!(cleared.is_empty());
+}
+
+#[tokio::test]
+async fn set_attendees_rejects_cross_household_member() {
+    let pool = setup_pool().await;
+    event_attendees::set_attendees(&pool, "hh", "evt", &["alex".into()])
+        .await
+        .expect("initial set");
+
+    let err =
+        event_attendees::set_attendees(&pool, "hh", "evt", &["sam".into(), "stranger".into()])
+            .await
+            .expect_err("cross-household member rejected");
+    assert_eq!(err.code(), event_attendees::HOUSEHOLD_MISMATCH_CODE);
+
+    let listed = event_attendees::list_attendees(&pool, "hh", "evt")
+        .await
+        .expect("list attendees");
+    assert_eq!(member_ids(&listed), vec!["alex"]);
+}
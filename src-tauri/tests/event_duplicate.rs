@@ -0,0 +1,117 @@
+use arklowdun_lib::{commands, migrate};
+use chrono::{TimeZone, Utc};
+use serde_json::{Map, Value};
+use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    migrate::apply_migrations(&pool).await.unwrap();
+    pool
+}
+
+fn start_ms() -> i64 {
+    Utc.with_ymd_and_hms(2024, 5, 6, 9, 0, 0)
+        .unwrap()
+        .timestamp_millis()
+}
+
+async fn create_series(pool: &SqlitePool) -> String {
+    let start = start_ms();
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from("default"));
+    data.insert("title".into(), Value::from("Swimming"));
+    data.insert("tz".into(), Value::from("Europe/Dublin"));
+    data.insert("start_at_utc".into(), Value::from(start));
+    data.insert("end_at_utc".into(), Value::from(start + HOUR_MS));
+    data.insert("reminder".into(), Value::from(start - HOUR_MS));
+    data.insert("rrule".into(), Value::from("FREQ=WEEKLY;COUNT=4"));
+    data.insert("exdates".into(), Value::from("2024-05-13T09:00:00Z"));
+    let created = commands::create_command(pool, "events", data, None)
+        .await
+        .unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn duplicate_shifts_times_and_keeps_recurrence() {
+    let pool = setup_pool().await;
+    let id = create_series(&pool).await;
+    let new_start = start_ms() + 2 * DAY_MS;
+
+    let copy = commands::duplicate_event(&pool, "default", &id, new_start)
+        .await
+        .unwrap();
+    let copy_id = copy["id"].as_str().unwrap();
+    assert_ne!(copy_id, id);
+
+    let row = sqlx::query(
+        "SELECT title, tz, start_at_utc, end_at_utc, reminder, rrule, exdates \
+         FROM events WHERE id = ?",
+    )
+    .bind(copy_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(row.get::<String, _>("title"), "Swimming");
+    assert_eq!(row.get::<String, _>("tz"), "Europe/Dublin");
+    assert_eq!(row.get::<i64, _>("start_at_utc"), new_start);
+    assert_eq!(row.get::<i64, _>("end_at_utc"), new_start + HOUR_MS);
+    assert_eq!(row.get::<i64, _>("reminder"), new_start - HOUR_MS);
+    assert_eq!(row.get::<String, _>("rrule"), "FREQ=WEEKLY;COUNT=4");
+    assert_eq!(
+        row.get::<Option<String>, _>("exdates").as_deref(),
+        Some("2024-05-15T09:00:00Z")
+    );
+
+    let original: i64 = sqlx::query("SELECT start_at_utc FROM events WHERE id = ?")
+        .bind(&id)
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+        .get("start_at_utc");
+    assert_eq!(original, start_ms());
+}
+
+#[tokio::test]
+async fn duplicate_rejects_deleted_event() {
+    let pool = setup_pool().await;
+    let id = create_series(&pool).await;
+    commands::delete_command(&pool, "events", "default", &id, None)
+        .await
+        .unwrap();
+
+    let err = commands::duplicate_event(&pool, "default", &id, start_ms())
+        .await
+        .expect_err("deleted events cannot be duplicated");
+    assert_eq!(err.code(), "DB/NOT_FOUND");
+}
+
+#[tokio::test]
+async fn duplicate_shifts_rrule_until() {
+    let pool = setup_pool().await;
+    let id = create_series(&pool).await;
+    sqlx::query("UPDATE events SET rrule = ?1, exdates = NULL WHERE id = ?2")
+        .bind("FREQ=WEEKLY;UNTIL=20240527T090000Z")
+        .bind(&id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let copy = commands::duplicate_event(&pool, "default", &id, start_ms() + 2 * DAY_MS)
+        .await
+        .unwrap();
+    let rrule: String = sqlx::query("SELECT rrule FROM events WHERE id = ?")
+        .bind(copy["id"].as_str().unwrap())
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+        .get("rrule");
+    assert_eq!(rrule, "FREQ=WEEKLY;UNTIL=20240529T090000Z");
+}
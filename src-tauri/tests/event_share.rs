@@ -0,0 +1,139 @@
+use arklowdun_lib::{commands, migrate};
+use chrono::{TimeZone, Utc};
+use serde_json::{Map, Value};
+use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    migrate::apply_migrations(&pool).await.unwrap();
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh_family', 'Family', 100, 100, NULL, 'America/New_York')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    pool
+}
+
+fn start_ms() -> i64 {
+    Utc.with_ymd_and_hms(2024, 5, 6, 9, 0, 0)
+        .unwrap()
+        .timestamp_millis()
+}
+
+async fn create_series(pool: &SqlitePool) -> String {
+    let start = start_ms();
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from("default"));
+    data.insert("title".into(), Value::from("Swimming"));
+    data.insert("tz".into(), Value::from("Europe/Dublin"));
+    data.insert("start_at_utc".into(), Value::from(start));
+    data.insert("end_at_utc".into(), Value::from(start + HOUR_MS));
+    data.insert("reminder".into(), Value::from(start - HOUR_MS));
+    data.insert("rrule".into(), Value::from("FREQ=WEEKLY;COUNT=4"));
+    data.insert("exdates".into(), Value::from("2024-05-13T09:00:00Z"));
+    let created = commands::create_command(pool, "events", data, None)
+        .await
+        .unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+async fn count_in(pool: &SqlitePool, household_id: &str) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM events WHERE household_id = ?")
+        .bind(household_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn recurring_event_round_trips_into_another_household() {
+    let pool = setup_pool().await;
+    let id = create_series(&pool).await;
+
+    let err = commands::export_event(&pool, "hh_family", &id)
+        .await
+        .expect_err("another household cannot export the event");
+    assert_eq!(err.code(), "DB/NOT_FOUND");
+
+    let json = commands::export_event(&pool, "default", &id).await.unwrap();
+    let shared: commands::SharedEvent = serde_json::from_str(&json).unwrap();
+    assert_eq!(shared.format, commands::EVENT_SHARE_FORMAT);
+    assert_eq!(shared.rrule.as_deref(), Some("FREQ=WEEKLY;COUNT=4"));
+    assert!(!json.contains(&id), "export leaks the source id: {json}");
+
+    let preview = commands::import_event(&pool, "hh_family", &json, true)
+        .await
+        .unwrap();
+    assert!(preview.get("id").is_none());
+    assert_eq!(preview["tz"], "Europe/Dublin");
+    assert_eq!(count_in(&pool, "hh_family").await, 0);
+
+    let created = commands::import_event(&pool, "hh_family", &json, false)
+        .await
+        .unwrap();
+    let new_id = created["id"].as_str().unwrap();
+    assert_ne!(new_id, id);
+
+    let row = sqlx::query(
+        "SELECT household_id, title, tz, start_at_utc, end_at_utc, reminder, rrule, exdates \
+         FROM events WHERE id = ?",
+    )
+    .bind(new_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(row.get::<String, _>("household_id"), "hh_family");
+    assert_eq!(row.get::<String, _>("title"), "Swimming");
+    assert_eq!(row.get::<String, _>("tz"), "Europe/Dublin");
+    assert_eq!(row.get::<i64, _>("start_at_utc"), start_ms());
+    assert_eq!(row.get::<i64, _>("end_at_utc"), start_ms() + HOUR_MS);
+    assert_eq!(row.get::<i64, _>("reminder"), start_ms() - HOUR_MS);
+    assert_eq!(row.get::<String, _>("rrule"), "FREQ=WEEKLY;COUNT=4");
+    assert_eq!(
+        row.get::<Option<String>, _>("exdates").as_deref(),
+        Some("2024-05-13T09:00:00Z")
+    );
+}
+
+#[tokio::test]
+async fn import_falls_back_to_household_timezone_and_rejects_bad_input() {
+    let pool = setup_pool().await;
+    let json = format!(
+        r#"{{"format":"{}","title":"Call","start_at_utc":{}}}"#,
+        commands::EVENT_SHARE_FORMAT,
+        start_ms()
+    );
+    let created = commands::import_event(&pool, "hh_family", &json, false)
+        .await
+        .unwrap();
+    assert_eq!(created["tz"], "America/New_York");
+
+    let err = commands::import_event(&pool, "hh_family", "not json", true)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), commands::EVENT_SHARE_INVALID_CODE);
+
+    let other_format = json.replace(commands::EVENT_SHARE_FORMAT, "arklowdun.event/9");
+    let err = commands::import_event(&pool, "hh_family", &other_format, true)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), commands::EVENT_SHARE_UNSUPPORTED_FORMAT_CODE);
+
+    let bad_rule = format!(
+        r#"{{"format":"{}","title":"Call","start_at_utc":{},"rrule":"FREQ=SOMETIMES"}}"#,
+        commands::EVENT_SHARE_FORMAT,
+        start_ms()
+    );
+    assert!(commands::import_event(&pool, "hh_family", &bad_rule, true)
+        .await
+        .is_err());
+    assert_eq!(count_in(&pool, "hh_family").await, 1);
+}
@@ -0,0 +1,151 @@
+use arklowdun_lib::{migrate, schedule};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const BASE_MS: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh', 'Household', ?1, ?1, NULL, 'UTC')",
+    )
+    .bind(BASE_MS)
+    .execute(&pool)
+    .await
+    .expect("insert household");
+    pool
+}
+
+async fn insert_event(pool: &SqlitePool, title: &str, start: i64, end: i64) -> String {
+    let id = Uuid::now_v7().to_string();
+    sqlx::query(
+        "INSERT INTO events (id, title, reminder, household_id, created_at, updated_at, deleted_at, tz, start_at_utc, end_at_utc)
+         VALUES (?1, ?2, NULL, 'hh', ?3, ?3, NULL, 'UTC', ?4, ?5)",
+    )
+    .bind(&id)
+    .bind(title)
+    .bind(BASE_MS)
+    .bind(start)
+    .bind(end)
+    .execute(pool)
+    .await
+    .expect("insert event");
+    id
+}
+
+#[tokio::test]
+async fn overlapping_events_produce_one_conflict() {
+    let pool = setup_pool().await;
+    let dentist = insert_event(&pool, "Dentist", BASE_MS, BASE_MS + 2 * HOUR_MS).await;
+    let football = insert_event(&pool, "Football", BASE_MS + HOUR_MS, BASE_MS + 3 * HOUR_MS).await;
+
+    let response = schedule::find_event_conflicts(
+        &pool,
+        "hh",
+        BASE_MS - HOUR_MS,
+        BASE_MS + 24 * HOUR_MS,
+        None,
+    )
+    .await
+    .expect("find conflicts");
+
+    assert_eq!(response.conflicts.len(), 1);
+    let conflict = &response.conflicts[0];
+    assert_eq!(conflict.first.id, dentist);
+    assert_eq!(conflict.second.id, football);
+    assert_eq!(conflict.overlap_start_utc, BASE_MS + HOUR_MS);
+    assert_eq!(conflict.overlap_end_utc, BASE_MS + 2 * HOUR_MS);
+}
+
+#[tokio::test]
+async fn touching_events_do_not_conflict() {
+    let pool = setup_pool().await;
+    insert_event(&pool, "Breakfast", BASE_MS, BASE_MS + HOUR_MS).await;
+    insert_event(
+        &pool,
+        "School run",
+        BASE_MS + HOUR_MS,
+        BASE_MS + 2 * HOUR_MS,
+    )
+    .await;
+
+    let response = schedule::find_event_conflicts(
+        &pool,
+        "hh",
+        BASE_MS - HOUR_MS,
+        BASE_MS + 24 * HOUR_MS,
+        None,
+    )
+    .await
+    .expect("find conflicts");
+
+    assert!(response.conflicts.is_empty());
+}
+
+#[tokio::test]
+async fn member_filter_limits_conflicts_to_linked_events() {
+    let pool = setup_pool().await;
+    let swim = insert_event(&pool, "Swimming", BASE_MS, BASE_MS + 2 * HOUR_MS).await;
+    let piano = insert_event(&pool, "Piano", BASE_MS + HOUR_MS, BASE_MS + 2 * HOUR_MS).await;
+    insert_event(&pool, "Work call", BASE_MS, BASE_MS + 2 * HOUR_MS).await;
+
+    for (event_id, position) in [(&swim, 0), (&piano, 1)] {
+        let note_id = Uuid::now_v7().to_string();
+        sqlx::query(
+            "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y, member_id)
+             VALUES (?1, 'hh', ?2, ?3, ?3, 0, 'kid', '#FFF4B8', 0.0, 0.0, 'member-1')",
+        )
+        .bind(&note_id)
+        .bind(position)
+        .bind(BASE_MS)
+        .execute(&pool)
+        .await
+        .expect("insert note");
+        sqlx::query(
+            "INSERT INTO note_links (id, household_id, note_id, entity_type, entity_id, relation, created_at, updated_at)
+             VALUES (?1, 'hh', ?2, 'event', ?3, 'attached_to', ?4, ?4)",
+        )
+        .bind(Uuid::now_v7().to_string())
+        .bind(&note_id)
+        .bind(event_id)
+        .bind(BASE_MS)
+        .execute(&pool)
+        .await
+        .expect("insert note link");
+    }
+
+    let all = schedule::find_event_conflicts(
+        &pool,
+        "hh",
+        BASE_MS - HOUR_MS,
+        BASE_MS + 24 * HOUR_MS,
+        None,
+    )
+    .await
+    .expect("find conflicts");
+    assert_eq!(all.conflicts.len(), 3);
+
+    let member = schedule::find_event_conflicts(
+        &pool,
+        "hh",
+        BASE_MS - HOUR_MS,
+        BASE_MS + 24 * HOUR_MS,
+        Some("member-1"),
+    )
+    .await
+    .expect("find member conflicts");
+    assert_eq!(member.conflicts.len(), 1);
+    let ids = [
+        member.conflicts[0].first.id.as_str(),
+        member.conflicts[0].second.id.as_str(),
+    ];
+    assert!(ids.contains(&swim.as_str()));
+    assert!(ids.contains(&piano.as_str()));
+}
@@ -0,0 +1,94 @@
+use arklowdun_lib::{commands, migrate, recurrence};
+use chrono::{TimeZone, Utc};
+use serde_json::{Map, Value};
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    migrate::apply_migrations(&pool).await.unwrap();
+    pool
+}
+
+// Monday 2024-01-01 09:00 UTC.
+fn start_ms() -> i64 {
+    Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0)
+        .unwrap()
+        .timestamp_millis()
+}
+
+async fn create_series(pool: &SqlitePool, rrule: &str, exdates: Option<&str>) -> String {
+    let start = start_ms();
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from("default"));
+    data.insert("title".into(), Value::from("Swimming"));
+    data.insert("tz".into(), Value::from("UTC"));
+    data.insert("start_at_utc".into(), Value::from(start));
+    data.insert("end_at_utc".into(), Value::from(start + HOUR_MS));
+    data.insert("rrule".into(), Value::from(rrule));
+    if let Some(exdates) = exdates {
+        data.insert("exdates".into(), Value::from(exdates));
+    }
+    let created = commands::create_command(pool, "events", data, None)
+        .await
+        .unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn weekly_rule_returns_next_dates_without_exdates() {
+    let pool = setup_pool().await;
+    let id = create_series(&pool, "FREQ=WEEKLY;BYDAY=MO", Some("2024-01-22T09:00:00Z")).await;
+    let start = start_ms();
+
+    // Asking from the start itself skips it; the third week is excluded.
+    let next = recurrence::next_occurrences(&pool, "default", &id, 3, start)
+        .await
+        .unwrap();
+    assert_eq!(
+        next,
+        vec![start + 7 * DAY_MS, start + 14 * DAY_MS, start + 28 * DAY_MS]
+    );
+
+    let later = recurrence::next_occurrences(&pool, "default", &id, 2, start + 100 * DAY_MS)
+        .await
+        .unwrap();
+    assert_eq!(later, vec![start + 105 * DAY_MS, start + 112 * DAY_MS]);
+}
+
+#[tokio::test]
+async fn rule_ended_by_until_returns_fewer_than_requested() {
+    let pool = setup_pool().await;
+    let id = create_series(&pool, "FREQ=WEEKLY;UNTIL=20240122T090000Z", None).await;
+    let start = start_ms();
+
+    let next = recurrence::next_occurrences(&pool, "default", &id, 10, start + DAY_MS)
+        .await
+        .unwrap();
+    assert_eq!(
+        next,
+        vec![start + 7 * DAY_MS, start + 14 * DAY_MS, start + 21 * DAY_MS]
+    );
+
+    let past_end = recurrence::next_occurrences(&pool, "default", &id, 10, start + 21 * DAY_MS)
+        .await
+        .unwrap();
+    assert!(past_end.is_empty(), "{past_end:?}");
+}
+
+#[tokio::test]
+async fn other_household_cannot_read_occurrences() {
+    let pool = setup_pool().await;
+    let id = create_series(&pool, "FREQ=WEEKLY;BYDAY=MO", None).await;
+
+    let err = recurrence::next_occurrences(&pool, "hh_family", &id, 3, start_ms())
+        .await
+        .expect_err("another household cannot read the series");
+    assert_eq!(err.code(), "DB/NOT_FOUND");
+}
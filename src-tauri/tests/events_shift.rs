@@ -0,0 +1,95 @@
+use arklowdun_lib::{commands, migrate};
+use serde_json::{Map, Value};
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const DAY_MS: i64 = 24 * HOUR_MS;
+const BASE_MS: i64 = 1_717_232_400_000; // 2024-06-01T09:00:00Z
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    migrate::apply_migrations(&pool).await.unwrap();
+    pool
+}
+
+async fn create_event(pool: &SqlitePool, title: &str, start: i64, end: Option<i64>) -> String {
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from("default"));
+    data.insert("title".into(), Value::from(title));
+    data.insert("tz".into(), Value::from("UTC"));
+    data.insert("start_at_utc".into(), Value::from(start));
+    if let Some(end) = end {
+        data.insert("end_at_utc".into(), Value::from(end));
+    }
+    let created = commands::create_command(pool, "events", data, None)
+        .await
+        .unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+async fn times(pool: &SqlitePool, id: &str) -> (i64, Option<i64>) {
+    sqlx::query_as("SELECT start_at_utc, end_at_utc FROM events WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn shift_moves_every_event_by_delta() {
+    let pool = setup_pool().await;
+    let flight = create_event(&pool, "Flight", BASE_MS, Some(BASE_MS + 3 * HOUR_MS)).await;
+    let dinner = create_event(&pool, "Dinner", BASE_MS + DAY_MS, None).await;
+
+    let delta = 2 * DAY_MS + HOUR_MS;
+    let moved = commands::shift_events(
+        &pool,
+        "default",
+        &[flight.clone(), dinner.clone(), flight.clone()],
+        delta,
+    )
+    .await
+    .unwrap();
+    assert_eq!(moved, 2);
+
+    assert_eq!(
+        times(&pool, &flight).await,
+        (BASE_MS + delta, Some(BASE_MS + 3 * HOUR_MS + delta))
+    );
+    assert_eq!(
+        times(&pool, &dinner).await,
+        (BASE_MS + DAY_MS + delta, None)
+    );
+}
+
+#[tokio::test]
+async fn shift_rejecting_one_event_leaves_all_unchanged() {
+    let pool = setup_pool().await;
+    // Legacy rows can predate create-time range checks.
+    let early = create_event(&pool, "Early", BASE_MS, Some(BASE_MS + HOUR_MS)).await;
+    sqlx::query("UPDATE events SET start_at_utc = ?1, end_at_utc = ?2 WHERE id = ?3")
+        .bind(HOUR_MS)
+        .bind(2 * HOUR_MS)
+        .bind(&early)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let late = create_event(&pool, "Late", BASE_MS, None).await;
+
+    let err = commands::shift_events(&pool, "default", &[late.clone(), early.clone()], -DAY_MS)
+        .await
+        .expect_err("negative timestamps are rejected");
+    assert_eq!(err.code(), commands::EVENTS_SHIFT_OUT_OF_RANGE_CODE);
+    assert_eq!(times(&pool, &late).await, (BASE_MS, None));
+    assert_eq!(times(&pool, &early).await, (HOUR_MS, Some(2 * HOUR_MS)));
+
+    let err = commands::shift_events(&pool, "default", &[late.clone(), "missing".into()], DAY_MS)
+        .await
+        .expect_err("unknown events are rejected");
+    assert_eq!(err.code(), "DB/NOT_FOUND");
+    assert_eq!(times(&pool, &late).await, (BASE_MS, None));
+}
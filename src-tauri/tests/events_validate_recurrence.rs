@@ -0,0 +1,200 @@
+use arklowdun_lib::{
+    db, migrate,
+    recurrence::{fix_event_recurrence, scan_event_recurrence},
+};
+use chrono::{TimeZone, Utc};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn setup_pool(dir: &TempDir) -> SqlitePool {
+    let pool = db::connect_sqlite_pool(&dir.path().join("arklowdun.sqlite3"))
+        .await
+        .expect("connect sqlite pool");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    for household in ["hh", "hh_other"] {
+        sqlx::query(
+            "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+             VALUES (?1, ?1, 0, 0, NULL, 'UTC')",
+        )
+        .bind(household)
+        .execute(&pool)
+        .await
+        .expect("insert household");
+    }
+    pool
+}
+
+async fn insert_event(
+    pool: &SqlitePool,
+    id: &str,
+    household_id: &str,
+    rrule: Option<&str>,
+    exdates: Option<&str>,
+) {
+    let start = Utc
+        .with_ymd_and_hms(2023, 11, 1, 9, 0, 0)
+        .unwrap()
+        .timestamp_millis();
+    sqlx::query(
+        "INSERT INTO events (id, title, household_id, created_at, updated_at, tz, start_at_utc, rrule, exdates)
+         VALUES (?1, ?1, ?2, 0, 0, 'UTC', ?3, ?4, ?5)",
+    )
+    .bind(id)
+    .bind(household_id)
+    .bind(start)
+    .bind(rrule)
+    .bind(exdates)
+    .execute(pool)
+    .await
+    .expect("insert event");
+}
+
+#[tokio::test]
+async fn malformed_exdate_is_reported_and_valid_series_is_not() {
+    let dir = TempDir::new().unwrap();
+    let pool = setup_pool(&dir).await;
+    insert_event(
+        &pool,
+        "valid",
+        "hh",
+        Some("FREQ=DAILY;COUNT=5"),
+        Some("2023-11-02T09:00:00Z"),
+    )
+    .await;
+    insert_event(
+        &pool,
+        "bad_exdate",
+        "hh",
+        Some("FREQ=DAILY;COUNT=5"),
+        Some("2023-11-02T09:00:00Z,2023-11-03"),
+    )
+    .await;
+    insert_event(&pool, "bad_rule", "hh", Some("FREQ=SOMETIMES"), None).await;
+    insert_event(&pool, "elsewhere", "hh_other", None, Some("nonsense")).await;
+
+    let issues = scan_event_recurrence(&pool, "hh").await.expect("scan");
+
+    let found: Vec<(&str, &str, Option<&str>)> = issues
+        .iter()
+        .map(|issue| {
+            (
+                issue.event_id.as_str(),
+                issue.code.as_str(),
+                issue.value.as_deref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("bad_exdate", "E_EXDATE_INVALID_FORMAT", Some("2023-11-03")),
+            ("bad_rule", "E_RRULE_PARSE", Some("FREQ=SOMETIMES")),
+        ]
+    );
+
+    let exdates: Option<String> =
+        sqlx::query_scalar("SELECT exdates FROM events WHERE id = 'bad_exdate'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(
+        exdates.as_deref(),
+        Some("2023-11-02T09:00:00Z,2023-11-03"),
+        "validation must not rewrite stored values"
+    );
+}
+
+async fn stored(pool: &SqlitePool, id: &str) -> (Option<String>, Option<String>) {
+    sqlx::query_as("SELECT rrule, exdates FROM events WHERE id = ?1")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn fix_cleans_dirty_exdates_and_leaves_valid_events_untouched() {
+    let dir = TempDir::new().unwrap();
+    let pool = setup_pool(&dir).await;
+    insert_event(
+        &pool,
+        "valid",
+        "hh",
+        Some("FREQ=DAILY;COUNT=5"),
+        Some("2023-11-02T09:00:00Z"),
+    )
+    .await;
+    insert_event(
+        &pool,
+        "dirty",
+        "hh",
+        Some("FREQ=DAILY;COUNT=5"),
+        Some("2023-11-03T09:00:00Z, 2023-11-02T09:00:00Z,2023-11-03T09:00:00Z,2023-11-04,2020-01-01T00:00:00Z"),
+    )
+    .await;
+    insert_event(
+        &pool,
+        "bad_rule",
+        "hh",
+        Some("FREQ=SOMETIMES"),
+        Some("2023-11-02T09:00:00Z"),
+    )
+    .await;
+    insert_event(&pool, "blank_rule", "hh", Some("   "), None).await;
+
+    let fixes = fix_event_recurrence(&pool, "hh").await.expect("fix");
+
+    assert_eq!(
+        stored(&pool, "dirty").await,
+        (
+            Some("FREQ=DAILY;COUNT=5".into()),
+            Some("2023-11-02T09:00:00Z,2023-11-03T09:00:00Z".into())
+        )
+    );
+    assert_eq!(stored(&pool, "bad_rule").await, (None, None));
+    assert_eq!(stored(&pool, "blank_rule").await, (None, None));
+    assert_eq!(
+        stored(&pool, "valid").await,
+        (
+            Some("FREQ=DAILY;COUNT=5".into()),
+            Some("2023-11-02T09:00:00Z".into())
+        )
+    );
+
+    let changed: Vec<(&str, &str)> = fixes
+        .iter()
+        .map(|fix| (fix.event_id.as_str(), fix.field.as_str()))
+        .collect();
+    assert_eq!(
+        changed,
+        vec![
+            ("bad_rule", "rrule"),
+            ("bad_rule", "exdates"),
+            ("blank_rule", "rrule"),
+            ("dirty", "exdates")
+        ]
+    );
+    for (id, touched) in [
+        ("bad_rule", true),
+        ("blank_rule", true),
+        ("dirty", true),
+        ("valid", false),
+    ] {
+        let updated_at: i64 = sqlx::query_scalar("SELECT updated_at FROM events WHERE id = ?1")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(updated_at > 0, touched, "updated_at of {id}");
+    }
+    assert!(scan_event_recurrence(&pool, "hh")
+        .await
+        .expect("rescan")
+        .is_empty());
+    assert!(fix_event_recurrence(&pool, "hh")
+        .await
+        .expect("second fix")
+        .is_empty());
+}
@@ -117,7 +117,7 @@ async fn update_normalises_and_sorts_exdates() {
         "exdates".into(),
         Value::String("2023-11-04T09:00:00Z,2023-11-02T09:00:00Z".into()),
     );
-    commands::update_command(&pool, "events", &event_id, update, Some("HH"), None)
+    commands::update_command(&pool, "events", &event_id, update, Some("HH"), None, None)
         .await
         .unwrap();
 
@@ -149,7 +149,7 @@ async fn update_rejects_invalid_payloads() {
 
     let mut update = Map::new();
     update.insert("exdates".into(), Value::String("bad".into()));
-    let err = commands::update_command(&pool, "events", &event_id, update, Some("HH"), None)
+    let err = commands::update_command(&pool, "events", &event_id, update, Some("HH"), None, None)
         .await
         .expect_err("invalid exdates should fail");
     assert_eq!(err.code, "E_EXDATE_INVALID_FORMAT");
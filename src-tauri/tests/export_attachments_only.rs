@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use arklowdun_lib::{
+    db,
+    export::{create_attachments_export, manifest::ExportManifest},
+    migrate,
+    vault::Vault,
+};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn setup_pool(dir: &TempDir) -> SqlitePool {
+    let pool = db::connect_sqlite_pool(&dir.path().join("arklowdun.sqlite3"))
+        .await
+        .expect("connect sqlite pool");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+async fn seed(pool: &SqlitePool, vault_root: &std::path::Path) {
+    for household in ["hh_docs", "hh_other"] {
+        sqlx::query(
+            "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+             VALUES (?1, ?1, 100, 100, NULL, 'UTC')",
+        )
+        .bind(household)
+        .execute(pool)
+        .await
+        .expect("insert household");
+        sqlx::query(
+            "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category)
+             VALUES (?1, 4200, 0, ?2, 100, 100, 0, 'attachments', 'water.pdf', 'bills')",
+        )
+        .bind(format!("bill_{household}"))
+        .bind(household)
+        .execute(pool)
+        .await
+        .expect("insert bill");
+
+        let dir = vault_root.join(household).join("bills");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("water.pdf"), household.as_bytes()).unwrap();
+    }
+}
+
+#[tokio::test]
+async fn bundle_has_attachments_and_manifest_but_no_data() {
+    let db_dir = TempDir::new().unwrap();
+    let pool = setup_pool(&db_dir).await;
+    let attachments = TempDir::new().unwrap();
+    seed(&pool, attachments.path()).await;
+    let vault = Arc::new(Vault::new(attachments.path()));
+
+    let out = TempDir::new().unwrap();
+    let entry = create_attachments_export(&pool, vault, out.path(), "hh_docs")
+        .await
+        .expect("attachments-only export");
+
+    assert!(!entry.directory.join("data").exists());
+    assert!(entry.verify_sh_path.exists());
+    assert!(entry.verify_ps1_path.exists());
+
+    let manifest: ExportManifest =
+        serde_json::from_slice(&std::fs::read(&entry.manifest_path).unwrap()).unwrap();
+    assert!(manifest.tables.is_empty());
+    assert_eq!(
+        manifest.attachments_only_household.as_deref(),
+        Some("hh_docs")
+    );
+    assert_eq!(manifest.attachments.total_count, 1);
+
+    let copied = entry.directory.join("attachments/hh_docs/bills/water.pdf");
+    assert_eq!(std::fs::read(copied).unwrap(), b"hh_docs");
+    assert!(!entry.directory.join("attachments/hh_other").exists());
+    let listed = std::fs::read_to_string(entry.directory.join("attachments_manifest.txt")).unwrap();
+    assert!(listed.contains("hh_docs/bills/water.pdf"));
+    assert!(!listed.contains("hh_other"));
+}
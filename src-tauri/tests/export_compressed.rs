@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use arklowdun_lib::{
+    db,
+    export::{create_export, manifest::ExportManifest, ExportOptions},
+    import::{build_plan, execute_plan, ExecutionContext, ImportBundle, ImportMode, PlanContext},
+    migrate,
+    vault::Vault,
+};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn setup_pool(dir: &TempDir) -> SqlitePool {
+    let pool = db::connect_sqlite_pool(&dir.path().join("arklowdun.sqlite3"))
+        .await
+        .expect("connect sqlite pool");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+async fn seed(pool: &SqlitePool) {
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh_export', 'Exported', 100, 100, NULL, 'UTC')",
+    )
+    .execute(pool)
+    .await
+    .expect("insert household");
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y)
+         VALUES ('note_export', 'hh_export', 0, 100, 100, 1, 'compressed', '#FFFF88', 0, 0)",
+    )
+    .execute(pool)
+    .await
+    .expect("insert note");
+}
+
+#[tokio::test]
+async fn compressed_export_round_trips_through_import() {
+    let source_dir = TempDir::new().unwrap();
+    let source = setup_pool(&source_dir).await;
+    seed(&source).await;
+
+    let attachments = TempDir::new().unwrap();
+    let vault = Arc::new(Vault::new(attachments.path()));
+    let out = TempDir::new().unwrap();
+    let entry = create_export(
+        &source,
+        vault.clone(),
+        ExportOptions {
+            out_parent: out.path().to_path_buf(),
+            compress: true,
+            include_deletions_audit: false,
+            tables: Default::default(),
+        },
+    )
+    .await
+    .expect("compressed export");
+
+    let data_dir = entry.directory.join("data");
+    assert!(data_dir.join("notes.jsonl.gz").is_file());
+    assert!(!data_dir.join("notes.jsonl").exists());
+
+    let manifest: ExportManifest =
+        serde_json::from_slice(&std::fs::read(&entry.manifest_path).unwrap()).unwrap();
+    let notes = manifest.tables.get("notes").expect("notes table info");
+    assert!(notes.is_gzip());
+    assert!(notes.compressed_sha256.is_some());
+    assert_ne!(
+        notes.compressed_sha256.as_deref(),
+        Some(notes.sha256.as_str())
+    );
+    let verify_sh = std::fs::read_to_string(&entry.verify_sh_path).unwrap();
+    assert!(verify_sh.contains("ckz data/notes.jsonl.gz"));
+
+    let bundle = ImportBundle::load(&entry.directory).expect("load compressed bundle");
+    for data_file in bundle.data_files() {
+        bundle
+            .verify_data_file_hash(data_file)
+            .expect("decompressed hash matches manifest");
+    }
+
+    let target_dir = TempDir::new().unwrap();
+    let target = setup_pool(&target_dir).await;
+    let target_attachments = TempDir::new().unwrap();
+    let target_vault = Arc::new(Vault::new(target_attachments.path()));
+    let plan = build_plan(
+        &bundle,
+        &PlanContext {
+            pool: &target,
+            vault: target_vault.clone(),
+        },
+        ImportMode::Merge,
+    )
+    .await
+    .expect("build plan");
+    execute_plan(
+        &bundle,
+        &plan,
+        &ExecutionContext::new(&target, target_vault),
+    )
+    .await
+    .expect("execute plan");
+
+    let text: String = sqlx::query_scalar("SELECT text FROM notes WHERE id = 'note_export'")
+        .fetch_one(&target)
+        .await
+        .expect("imported note");
+    assert_eq!(text, "compressed");
+    let name: String = sqlx::query_scalar("SELECT name FROM household WHERE id = 'hh_export'")
+        .fetch_one(&target)
+        .await
+        .expect("imported household");
+    assert_eq!(name, "Exported");
+}
@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::Path;
+
+use arklowdun_lib::export::diff::{diff_bundles, TableDiffStatus};
+use arklowdun_lib::export::manifest::file_sha256;
+use serde_json::json;
+use tempfile::TempDir;
+
+/// Write a minimal uncompressed bundle with the given table contents and
+/// attachments (`(manifest key, bytes)`).
+fn write_bundle(root: &Path, tables: &[(&str, &str)], attachments: &[(&str, &[u8])]) {
+    fs::create_dir_all(root.join("data")).unwrap();
+    fs::create_dir_all(root.join("attachments")).unwrap();
+
+    let mut table_entries = serde_json::Map::new();
+    for (name, content) in tables {
+        let path = root.join("data").join(format!("{name}.jsonl"));
+        fs::write(&path, content).unwrap();
+        table_entries.insert(
+            name.to_string(),
+            json!({
+                "count": content.lines().count(),
+                "sha256": file_sha256(&path).unwrap(),
+            }),
+        );
+    }
+
+    let mut manifest_lines = String::new();
+    let mut total_bytes = 0;
+    for (key, bytes) in attachments {
+        let path = root.join("attachments").join(key);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, bytes).unwrap();
+        total_bytes += bytes.len();
+        manifest_lines.push_str(&format!("{key}\t{}\n", file_sha256(&path).unwrap()));
+    }
+    let attachments_manifest = root.join("attachments_manifest.txt");
+    fs::write(&attachments_manifest, manifest_lines).unwrap();
+
+    let manifest = json!({
+        "appVersion": "1.0.0",
+        "schemaVersion": "0038_note_links_soft_delete",
+        "createdAt": "2024-01-01T00:00:00Z",
+        "tables": table_entries,
+        "attachments": {
+            "totalCount": attachments.len(),
+            "totalBytes": total_bytes,
+            "sha256Manifest": file_sha256(&attachments_manifest).unwrap(),
+        },
+    });
+    fs::write(
+        root.join("manifest.json"),
+        serde_json::to_vec_pretty(&manifest).unwrap(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn diff_lists_only_changed_table_and_attachment() {
+    let tmp = TempDir::new().unwrap();
+    let a = tmp.path().join("a");
+    let b = tmp.path().join("b");
+    let households = "{\"id\":\"default\"}\n";
+    write_bundle(
+        &a,
+        &[("households", households), ("notes", "{\"id\":\"n1\"}\n")],
+        &[("default/bills/statement.pdf", &b"statement"[..])],
+    );
+    write_bundle(
+        &b,
+        &[
+            ("households", households),
+            ("notes", "{\"id\":\"n1\"}\n{\"id\":\"n2\"}\n"),
+        ],
+        &[
+            ("default/bills/statement.pdf", &b"statement"[..]),
+            ("default/policies/cover.pdf", &b"cover"[..]),
+        ],
+    );
+
+    let diff = diff_bundles(&a, &b).unwrap();
+    assert_eq!(diff.tables.len(), 1);
+    let notes = &diff.tables[0];
+    assert_eq!(notes.table, "notes");
+    assert_eq!(notes.status, TableDiffStatus::Changed);
+    assert_eq!(notes.count_a, Some(1));
+    assert_eq!(notes.count_b, Some(2));
+    assert_eq!(diff.attachments_added, vec!["default/policies/cover.pdf"]);
+    assert!(diff.attachments_removed.is_empty());
+    assert!(diff.attachments_changed.is_empty());
+
+    let reversed = diff_bundles(&b, &a).unwrap();
+    assert_eq!(
+        reversed.attachments_removed,
+        vec!["default/policies/cover.pdf"]
+    );
+    assert!(reversed.attachments_added.is_empty());
+
+    assert!(diff_bundles(&a, &a).unwrap().is_empty());
+}
+
+#[test]
+fn diff_rejects_directory_without_manifest() {
+    let tmp = TempDir::new().unwrap();
+    let a = tmp.path().join("a");
+    write_bundle(&a, &[("households", "{}\n")], &[]);
+    let err = diff_bundles(&a, &tmp.path().join("missing")).unwrap_err();
+    assert_eq!(
+        err.context().get("operation").map(String::as_str),
+        Some("export_diff_load_bundle")
+    );
+}
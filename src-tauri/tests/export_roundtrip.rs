@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use arklowdun_lib::{
+    db,
+    export::roundtrip::{assert_export_roundtrip_stable, export_roundtrip_mismatches},
+    migrate,
+    vault::Vault,
+};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn setup_pool(dir: &TempDir) -> SqlitePool {
+    let pool = db::connect_sqlite_pool(&dir.path().join("arklowdun.sqlite3"))
+        .await
+        .expect("connect sqlite pool");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+async fn seed(pool: &SqlitePool, attachments_root: &std::path::Path) {
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh_rt', 'Round trip', 100, 100, NULL, 'Europe/Dublin')",
+    )
+    .execute(pool)
+    .await
+    .expect("insert household");
+    sqlx::query(
+        "INSERT INTO events (id, title, household_id, created_at, updated_at, tz, start_at_utc, end_at_utc, rrule)
+         VALUES ('evt_rt', 'Bin day — café run', 'hh_rt', 100, 200, 'Europe/Dublin', 1700000000000, 1700003600000, 'FREQ=WEEKLY;COUNT=4')",
+    )
+    .execute(pool)
+    .await
+    .expect("insert event");
+    for (id, position, text) in [
+        ("note_a", 0, "Pay the plumber"),
+        ("note_b", 1, "Ünïcödé and \"quotes\"\nacross lines"),
+    ] {
+        sqlx::query(
+            "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y)
+             VALUES (?1, 'hh_rt', ?2, 100, 100, 1, ?3, '#FFFF88', 1.5, 0)",
+        )
+        .bind(id)
+        .bind(position)
+        .bind(text)
+        .execute(pool)
+        .await
+        .expect("insert note");
+    }
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category)
+         VALUES ('bill_rt', 4250, 1700000000000, 'hh_rt', 100, 100, 0, 'attachments', 'energy/october.pdf', 'bills')",
+    )
+    .execute(pool)
+    .await
+    .expect("insert bill");
+
+    let file = attachments_root.join("hh_rt/bills/energy/october.pdf");
+    std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+    std::fs::write(&file, b"%PDF-1.4 october").unwrap();
+}
+
+#[tokio::test]
+async fn export_import_export_is_stable() {
+    let db_dir = TempDir::new().unwrap();
+    let pool = setup_pool(&db_dir).await;
+    let attachments = TempDir::new().unwrap();
+    seed(&pool, attachments.path()).await;
+    let vault = Arc::new(Vault::new(attachments.path()));
+
+    let mismatches = export_roundtrip_mismatches(&pool, vault.clone())
+        .await
+        .expect("round-trip runs");
+    assert!(mismatches.is_empty(), "unexpected drift: {mismatches:?}");
+
+    assert_export_roundtrip_stable(&pool, vault).await;
+}
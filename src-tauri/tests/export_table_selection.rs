@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use arklowdun_lib::{
+    db,
+    export::{
+        create_export,
+        manifest::{ExportManifest, TableSelection},
+        ExportOptions,
+    },
+    import::{
+        build_plan, plan::IMPORT_PARTIAL_REPLACE_CODE, ImportBundle, ImportBundleError, ImportMode,
+        PlanContext, PlanError,
+    },
+    migrate,
+    vault::Vault,
+    AppError,
+};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn setup_pool(dir: &TempDir) -> SqlitePool {
+    let pool = db::connect_sqlite_pool(&dir.path().join("arklowdun.sqlite3"))
+        .await
+        .expect("connect sqlite pool");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+async fn seed(pool: &SqlitePool, vault_root: &std::path::Path) {
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh_export', 'Exported', 100, 100, NULL, 'UTC')",
+    )
+    .execute(pool)
+    .await
+    .expect("insert household");
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category)
+         VALUES ('bill_export', 4200, 0, 'hh_export', 100, 100, 0, 'attachments', 'water.pdf', 'bills')",
+    )
+    .execute(pool)
+    .await
+    .expect("insert bill");
+    sqlx::query(
+        "INSERT INTO inventory_items (id, name, household_id, created_at, updated_at, position, root_key, relative_path, category)
+         VALUES ('item_export', 'Boiler', 'hh_export', 100, 100, 0, 'attachments', 'boiler.pdf', 'inventory_items')",
+    )
+    .execute(pool)
+    .await
+    .expect("insert inventory item");
+
+    for (category, name) in [("bills", "water.pdf"), ("inventory_items", "boiler.pdf")] {
+        let dir = vault_root.join("hh_export").join(category);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(name), name.as_bytes()).unwrap();
+    }
+}
+
+fn options(out: &TempDir, tables: TableSelection) -> ExportOptions {
+    ExportOptions {
+        out_parent: out.path().to_path_buf(),
+        compress: false,
+        include_deletions_audit: false,
+        tables,
+    }
+}
+
+#[tokio::test]
+async fn excluded_table_and_its_files_are_left_out_of_the_bundle() {
+    let db_dir = TempDir::new().unwrap();
+    let pool = setup_pool(&db_dir).await;
+    let attachments = TempDir::new().unwrap();
+    seed(&pool, attachments.path()).await;
+    let vault = Arc::new(Vault::new(attachments.path()));
+
+    let out = TempDir::new().unwrap();
+    let selection = TableSelection {
+        include_tables: Vec::new(),
+        exclude_tables: vec!["bills".into()],
+    };
+    let entry = create_export(&pool, vault, options(&out, selection.clone()))
+        .await
+        .expect("export with exclusion");
+
+    let manifest: ExportManifest =
+        serde_json::from_slice(&std::fs::read(&entry.manifest_path).unwrap()).unwrap();
+    assert!(!manifest.tables.contains_key("bills"));
+    assert!(manifest.tables.contains_key("inventory_items"));
+    assert!(manifest.tables.contains_key("households"));
+    assert_eq!(manifest.table_selection, Some(selection));
+    assert!(!entry.directory.join("data/bills.jsonl").exists());
+
+    assert_eq!(manifest.attachments.total_count, 1);
+    let listed = std::fs::read_to_string(entry.directory.join("attachments_manifest.txt")).unwrap();
+    assert!(listed.contains("hh_export/inventory_items/boiler.pdf"));
+    assert!(!listed.contains("water.pdf"));
+    assert!(!entry
+        .directory
+        .join("attachments/hh_export/bills/water.pdf")
+        .exists());
+
+    // The import side knows the bundle never carried bills.
+    let mut bundle = ImportBundle::load(&entry.directory).expect("load bundle");
+    let err = bundle
+        .select_tables(&TableSelection {
+            include_tables: vec!["bills".into()],
+            exclude_tables: Vec::new(),
+        })
+        .expect_err("bills are not in the bundle");
+    assert!(matches!(err, ImportBundleError::TableNotInBundle(ref t) if t == "bills"));
+
+    bundle
+        .select_tables(&TableSelection {
+            include_tables: Vec::new(),
+            exclude_tables: vec!["inventory_items".into()],
+        })
+        .expect("select tables");
+    assert!(bundle
+        .data_files()
+        .iter()
+        .all(|entry| entry.logical_name != "inventory_items"));
+    assert!(bundle.attachments().is_empty());
+}
+
+#[tokio::test]
+async fn unknown_tables_are_rejected() {
+    let db_dir = TempDir::new().unwrap();
+    let pool = setup_pool(&db_dir).await;
+    let attachments = TempDir::new().unwrap();
+    let vault = Arc::new(Vault::new(attachments.path()));
+    let out = TempDir::new().unwrap();
+
+    let err = create_export(
+        &pool,
+        vault,
+        options(
+            &out,
+            TableSelection {
+                include_tables: vec!["events".into(), "payroll".into()],
+                exclude_tables: Vec::new(),
+            },
+        ),
+    )
+    .await
+    .expect_err("unknown table");
+    assert_eq!(err.code(), "EXPORT/UNKNOWN_TABLE");
+    assert_eq!(
+        err.context().get("table").map(String::as_str),
+        Some("payroll")
+    );
+}
+
+#[tokio::test]
+async fn replace_import_rejects_a_partial_bundle_or_selection() {
+    let db_dir = TempDir::new().unwrap();
+    let pool = setup_pool(&db_dir).await;
+    let attachments = TempDir::new().unwrap();
+    seed(&pool, attachments.path()).await;
+    let vault = Arc::new(Vault::new(attachments.path()));
+    let ctx = PlanContext {
+        pool: &pool,
+        vault: vault.clone(),
+    };
+
+    let out = TempDir::new().unwrap();
+    let full = create_export(
+        &pool,
+        vault.clone(),
+        options(&out, TableSelection::default()),
+    )
+    .await
+    .expect("full export");
+    let bundle = ImportBundle::load(&full.directory).expect("load full bundle");
+    assert!(!bundle.is_partial());
+    build_plan(&bundle, &ctx, ImportMode::Replace)
+        .await
+        .expect("replace accepts a complete bundle");
+
+    let mut selected = ImportBundle::load(&full.directory).expect("load full bundle");
+    selected
+        .select_tables(&TableSelection {
+            include_tables: Vec::new(),
+            exclude_tables: vec!["bills".into()],
+        })
+        .expect("select tables");
+    let err = build_plan(&selected, &ctx, ImportMode::Replace)
+        .await
+        .expect_err("replace with an exclusion");
+    assert!(matches!(err, PlanError::PartialReplace));
+    assert_eq!(AppError::from(err).code(), IMPORT_PARTIAL_REPLACE_CODE);
+
+    let partial_out = TempDir::new().unwrap();
+    let partial = create_export(
+        &pool,
+        vault,
+        options(
+            &partial_out,
+            TableSelection {
+                include_tables: Vec::new(),
+                exclude_tables: vec!["inventory_items".into()],
+            },
+        ),
+    )
+    .await
+    .expect("partial export");
+    let bundle = ImportBundle::load(&partial.directory).expect("load partial bundle");
+    assert!(bundle.is_partial());
+    let err = build_plan(&bundle, &ctx, ImportMode::Replace)
+        .await
+        .expect_err("replace from a partial bundle");
+    assert!(matches!(err, PlanError::PartialReplace));
+}
@@ -71,6 +71,7 @@ async fn build_app_state(dir: &TempDir) -> Result<(AppState, SqlitePool, PathBuf
         vault,
         vault_migration: Arc::new(VaultMigrationManager::new(&attachments_root)?),
         maintenance: Arc::new(AtomicBool::new(false)),
+        maintenance_reason: Arc::new(Mutex::new(None)),
         files_indexer,
         pet_metrics: Arc::new(PetAttachmentMetrics::new()),
     };
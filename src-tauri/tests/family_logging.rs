@@ -131,6 +131,7 @@ fn unhealthy_state() -> (AppState, TempDir) {
             VaultMigrationManager::new(&attachments_root).expect("create vault migration manager"),
         ),
         maintenance: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        maintenance_reason: Arc::new(std::sync::Mutex::new(None)),
         files_indexer,
         pet_metrics: Arc::new(PetAttachmentMetrics::new()),
     };
@@ -147,9 +148,17 @@ async fn update_command_emits_family_logs() {
     data.insert("notes".into(), Value::String("updated".into()));
     data.insert("updated_at".into(), Value::from(now_ms_local()));
 
-    commands::update_command(&pool, "family_members", "mem-1", data, Some("hh-1"), None)
-        .await
-        .expect("update command succeeds");
+    commands::update_command(
+        &pool,
+        "family_members",
+        "mem-1",
+        data,
+        Some("hh-1"),
+        None,
+        None,
+    )
+    .await
+    .expect("update command succeeds");
 
     let log = logs_to_string(&buffer);
     assert!(
@@ -182,6 +191,7 @@ async fn list_command_emits_family_logs() {
         Some("position, created_at, id"),
         None,
         None,
+        None,
     )
     .await
     .expect("list command succeeds");
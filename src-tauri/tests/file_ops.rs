@@ -11,12 +11,17 @@ use tempfile::tempdir;
 use uuid::Uuid;
 
 use arklowdun_lib::attachment_category::AttachmentCategory;
+use arklowdun_lib::commands::{self, AttachmentMutationGuard};
+use arklowdun_lib::export::{create_export, ExportOptions};
 use arklowdun_lib::file_ops::{
-    attachments_repair as run_attachments_repair,
-    attachments_repair_manifest_export as run_attachments_repair_manifest_export,
-    move_file as run_file_move, AttachmentsRepairMode, AttachmentsRepairRequest, ConflictStrategy,
+    attachment_content_sha256, attachments_repair as run_attachments_repair,
+    attachments_repair_manifest_export as run_attachments_repair_manifest_export, copy_attachment,
+    household_integrity_audit, import_attachments_dir, list_entity_attachments, move_attachment,
+    move_file as run_file_move, reassign_row, restore_attachment_from_backup,
+    AttachmentImportStatus, AttachmentsRepairMode, AttachmentsRepairRequest, ConflictStrategy,
     FileMoveRequest, RepairAction, RepairActionKind,
 };
+use arklowdun_lib::files_indexer::{FilesIndexer, RebuildMode};
 use arklowdun_lib::migrate;
 use arklowdun_lib::vault::Vault;
 
@@ -92,6 +97,24 @@ async fn insert_bill(
     Ok(())
 }
 
+async fn insert_inventory_item(
+    pool: &SqlitePool,
+    id: &str,
+    household_id: &str,
+    position: i64,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO inventory_items (id, name, household_id, created_at, updated_at, position) VALUES (?1, 'Boiler', ?2, ?3, ?3, ?4)",
+    )
+    .bind(id)
+    .bind(household_id)
+    .bind(Utc::now().timestamp())
+    .bind(position)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
 async fn insert_policy(
     pool: &SqlitePool,
     id: &str,
@@ -611,3 +634,1131 @@ async fn move_respects_case_sensitivity_on_unix() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn attachment_move_renames_row_and_file() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_move";
+    seed_household(&pool, household_id).await?;
+
+    let category = AttachmentCategory::Bills;
+    let from_rel = "inbox/scan.pdf";
+    let source_path = attachment_path(&root, household_id, category, from_rel);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"bill-bytes")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(&pool, &bill_id, household_id, category, from_rel).await?;
+    insert_files_index(&pool, household_id, &bill_id, category, from_rel).await?;
+
+    let response = move_attachment(&pool, &vault, "bills", &bill_id, "2024/energy.pdf").await?;
+    assert_eq!(response.relative_path, "2024/energy.pdf");
+
+    let target_path = attachment_path(&root, household_id, category, "2024/energy.pdf");
+    assert!(target_path.exists(), "target file must exist after move");
+    assert!(!source_path.exists(), "source file removed after move");
+    assert_eq!(std::fs::read(&target_path)?, b"bill-bytes");
+
+    let (relative, stored_category, stored_household): (String, String, String) =
+        sqlx::query_as("SELECT relative_path, category, household_id FROM bills WHERE id = ?1")
+            .bind(&bill_id)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(relative, "2024/energy.pdf");
+    assert_eq!(stored_category, category.as_str());
+    assert_eq!(stored_household, household_id);
+
+    let indexed: String = sqlx::query_scalar(
+        "SELECT filename FROM files_index WHERE household_id = ?1 AND file_id = ?2",
+    )
+    .bind(household_id)
+    .bind(&bill_id)
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(indexed, "2024/energy.pdf");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_move_refuses_file_shared_with_another_row() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_move_shared";
+    seed_household(&pool, household_id).await?;
+
+    let category = AttachmentCategory::Bills;
+    let from_rel = "shared/scan.pdf";
+    let source_path = attachment_path(&root, household_id, category, from_rel);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"bill-bytes")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(&pool, &bill_id, household_id, category, from_rel).await?;
+    // A policy filed under the bills category points at the same file.
+    let policy_id = Uuid::now_v7().to_string();
+    sqlx::query(
+        "INSERT INTO policies (id, amount, due_date, household_id, created_at, updated_at, position, relative_path, category) VALUES (?1, 0, 0, ?2, 0, 0, 0, ?3, 'bills')",
+    )
+    .bind(&policy_id)
+    .bind(household_id)
+    .bind(from_rel)
+    .execute(&pool)
+    .await?;
+
+    let err = move_attachment(&pool, &vault, "bills", &bill_id, "moved.pdf")
+        .await
+        .expect_err("shared file must not be moved");
+    assert_eq!(err.code(), "ATTACHMENT/PATH_SHARED");
+    assert!(source_path.exists(), "shared file stays in place");
+    assert!(!attachment_path(&root, household_id, category, "moved.pdf").exists());
+
+    let paths: Vec<String> = sqlx::query_scalar(
+        "SELECT relative_path FROM bills WHERE id = ?1 UNION ALL SELECT relative_path FROM policies WHERE id = ?2",
+    )
+    .bind(&bill_id)
+    .bind(&policy_id)
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(paths, vec![from_rel.to_string(), from_rel.to_string()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_move_rejects_traversal_target() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_escape";
+    seed_household(&pool, household_id).await?;
+
+    let category = AttachmentCategory::Bills;
+    let from_rel = "inbox/scan.pdf";
+    let source_path = attachment_path(&root, household_id, category, from_rel);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"bill-bytes")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(&pool, &bill_id, household_id, category, from_rel).await?;
+
+    let err = move_attachment(&pool, &vault, "bills", &bill_id, "../../escape.pdf")
+        .await
+        .expect_err("traversal target rejected");
+    assert_eq!(err.code(), arklowdun_lib::vault::ERR_PATH_OUT_OF_VAULT);
+
+    assert!(source_path.exists(), "source file left in place");
+    let relative: Option<String> =
+        sqlx::query_scalar("SELECT relative_path FROM bills WHERE id = ?1")
+            .bind(&bill_id)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(relative.as_deref(), Some(from_rel));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn row_reassign_moves_bill_and_its_file_to_new_household() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    seed_household(&pool, "hh_wrong").await?;
+    seed_household(&pool, "hh_right").await?;
+
+    let category = AttachmentCategory::Bills;
+    let rel = "2024/water.pdf";
+    let source_path = attachment_path(&root, "hh_wrong", category, rel);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"water-bill")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(&pool, &bill_id, "hh_wrong", category, rel).await?;
+    insert_files_index(&pool, "hh_wrong", &bill_id, category, rel).await?;
+
+    let response = reassign_row(&pool, &vault, "bills", &bill_id, "hh_wrong", "hh_right").await?;
+    assert_eq!(response.household_id, "hh_right");
+    assert_eq!(response.relative_path.as_deref(), Some(rel));
+    assert!(response.file_moved);
+
+    let stored: String = sqlx::query_scalar("SELECT household_id FROM bills WHERE id = ?1")
+        .bind(&bill_id)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(stored, "hh_right");
+
+    let resolved = vault.resolve("hh_right", category, rel)?;
+    assert_eq!(resolved, attachment_path(&root, "hh_right", category, rel));
+    assert_eq!(std::fs::read(&resolved)?, b"water-bill");
+    assert!(!source_path.exists(), "file left behind in old household");
+
+    let indexed: Vec<(String, String)> =
+        sqlx::query_as("SELECT household_id, filename FROM files_index WHERE file_id = ?1")
+            .bind(&bill_id)
+            .fetch_all(&pool)
+            .await?;
+    assert_eq!(indexed, vec![("hh_right".to_string(), rel.to_string())]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn row_reassign_appends_to_a_populated_household() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    seed_household(&pool, "hh_wrong").await?;
+    seed_household(&pool, "hh_right").await?;
+
+    let category = AttachmentCategory::Bills;
+    let resident_id = Uuid::now_v7().to_string();
+    insert_bill(&pool, &resident_id, "hh_right", category, "resident.pdf").await?;
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(&pool, &bill_id, "hh_wrong", category, "moved.pdf").await?;
+
+    reassign_row(&pool, &vault, "bills", &bill_id, "hh_wrong", "hh_right").await?;
+
+    let positions: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT id, position FROM bills WHERE household_id = 'hh_right' ORDER BY position",
+    )
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(positions, vec![(resident_id, 0), (bill_id, 1)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn row_reassign_rejects_event_with_note_links() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    seed_household(&pool, "hh_a").await?;
+    seed_household(&pool, "hh_b").await?;
+
+    sqlx::query(
+        "INSERT INTO events (id, title, household_id, created_at, updated_at, tz, start_at_utc)
+         VALUES ('event_a', 'Linked', 'hh_a', 0, 0, 'UTC', 0)",
+    )
+    .execute(&pool)
+    .await?;
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, text, color, x, y)
+         VALUES ('note_a', 'hh_a', 0, 0, 0, 'Task', '#fff', 0, 0)",
+    )
+    .execute(&pool)
+    .await?;
+    sqlx::query(
+        "INSERT INTO note_links (id, household_id, note_id, entity_type, entity_id, relation, created_at, updated_at)
+         VALUES ('link_a', 'hh_a', 'note_a', 'event', 'event_a', 'attached_to', 0, 0)",
+    )
+    .execute(&pool)
+    .await?;
+
+    let err = reassign_row(&pool, &vault, "events", "event_a", "hh_a", "hh_b")
+        .await
+        .expect_err("linked event cannot move");
+    assert_eq!(err.code(), "REASSIGN/LINKED_ROW");
+    assert_eq!(
+        err.context().get("linked_table").map(String::as_str),
+        Some("note_links")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn row_reassign_rejects_household_row_and_wrong_source() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    seed_household(&pool, "hh_a").await?;
+    seed_household(&pool, "hh_b").await?;
+
+    let err = reassign_row(&pool, &vault, "household", "hh_a", "hh_a", "hh_b")
+        .await
+        .expect_err("household row cannot move");
+    assert_eq!(err.code(), "REASSIGN/HOUSEHOLD_ROW");
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(&pool, &bill_id, "hh_a", AttachmentCategory::Bills, "a.pdf").await?;
+    let err = reassign_row(&pool, &vault, "bills", &bill_id, "hh_b", "hh_a")
+        .await
+        .expect_err("row is not in the source household");
+    assert_eq!(err.code(), "REASSIGN/HOUSEHOLD_MISMATCH");
+
+    let err = reassign_row(&pool, &vault, "bills", &bill_id, "hh_a", "hh_missing")
+        .await
+        .expect_err("target household must exist");
+    assert_eq!(err.code(), "HOUSEHOLD_NOT_FOUND");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn household_integrity_audit_flags_rows_crossing_households() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    seed_household(&pool, "hh_a").await?;
+    seed_household(&pool, "hh_b").await?;
+
+    insert_bill(
+        &pool,
+        "bill_ok",
+        "hh_a",
+        AttachmentCategory::Bills,
+        "water.pdf",
+    )
+    .await?;
+    insert_bill(
+        &pool,
+        "bill_leak",
+        "hh_b",
+        AttachmentCategory::Bills,
+        "hh_a/gas.pdf",
+    )
+    .await?;
+    let now = Utc::now().timestamp();
+    sqlx::query(
+        "INSERT INTO pets (id, name, type, household_id, created_at, updated_at, position) VALUES ('pet_a', 'Rex', 'dog', 'hh_a', ?1, ?1, 0)",
+    )
+    .bind(now)
+    .execute(&pool)
+    .await?;
+    sqlx::query(
+        "INSERT INTO pet_medical (id, pet_id, date, description, household_id, created_at, updated_at, root_key, relative_path, category) VALUES (?1, 'pet_a', ?2, 'Vaccine', ?3, ?2, ?2, 'attachments', ?4, 'pet_medical')",
+    )
+    .bind("med_ok")
+    .bind(now)
+    .bind("hh_a")
+    .bind("vaccine.pdf")
+    .execute(&pool)
+    .await?;
+    sqlx::query(
+        "INSERT INTO pet_medical (id, pet_id, date, description, household_id, created_at, updated_at, root_key, relative_path, category) VALUES (?1, 'pet_a', ?2, 'Checkup', ?3, ?2, ?2, 'attachments', ?4, 'pet_medical')",
+    )
+    .bind("med_leak")
+    .bind(now)
+    .bind("hh_b")
+    .bind("checkup.pdf")
+    .execute(&pool)
+    .await?;
+
+    let issues = household_integrity_audit(&pool, &vault).await?;
+    let flagged: Vec<(&str, &str, Option<&str>)> = issues
+        .iter()
+        .map(|issue| {
+            (
+                issue.id.as_str(),
+                issue.code.as_str(),
+                issue.detail.as_deref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        flagged,
+        vec![
+            ("bill_leak", "PATH_OUTSIDE_HOUSEHOLD", Some("hh_a")),
+            ("med_leak", "PARENT_HOUSEHOLD_MISMATCH", Some("pets:hh_a")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_copy_shares_file_with_destination_row() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_copy";
+    seed_household(&pool, household_id).await?;
+
+    let relative = "receipts/boiler.pdf";
+    let source_path = attachment_path(&root, household_id, AttachmentCategory::Bills, relative);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"receipt-bytes")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(
+        &pool,
+        &bill_id,
+        household_id,
+        AttachmentCategory::Bills,
+        relative,
+    )
+    .await?;
+    let first_item = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &first_item, household_id, 0).await?;
+    let second_item = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &second_item, household_id, 1).await?;
+
+    let first = copy_attachment(
+        &pool,
+        &vault,
+        "bills",
+        &bill_id,
+        "inventory_items",
+        &first_item,
+    )
+    .await?;
+    assert_eq!(first.relative_path, relative);
+    assert!(!first.deduplicated);
+
+    let copied_path = attachment_path(
+        &root,
+        household_id,
+        AttachmentCategory::InventoryItems,
+        relative,
+    );
+    assert_eq!(std::fs::read(&copied_path)?, b"receipt-bytes");
+    let (stored_relative, stored_category): (String, String) =
+        sqlx::query_as("SELECT relative_path, category FROM inventory_items WHERE id = ?1")
+            .bind(&first_item)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(stored_category, AttachmentCategory::InventoryItems.as_str());
+    assert_eq!(
+        vault.resolve(
+            household_id,
+            AttachmentCategory::InventoryItems,
+            &stored_relative
+        )?,
+        copied_path
+    );
+
+    let second = copy_attachment(
+        &pool,
+        &vault,
+        "bills",
+        &bill_id,
+        "inventory_items",
+        &second_item,
+    )
+    .await?;
+    assert_eq!(second.relative_path, "receipts/boiler (1).pdf");
+    assert!(
+        !second.deduplicated,
+        "a path claimed by another row is not shared"
+    );
+
+    assert_eq!(std::fs::read(&source_path)?, b"receipt-bytes");
+    let bill_relative: Option<String> =
+        sqlx::query_scalar("SELECT relative_path FROM bills WHERE id = ?1")
+            .bind(&bill_id)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(bill_relative.as_deref(), Some(relative));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_copy_renames_when_different_content_exists() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_copy_conflict";
+    seed_household(&pool, household_id).await?;
+
+    let relative = "scan.pdf";
+    let source_path = attachment_path(&root, household_id, AttachmentCategory::Bills, relative);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"new-scan")?;
+    let occupied = attachment_path(
+        &root,
+        household_id,
+        AttachmentCategory::InventoryItems,
+        relative,
+    );
+    std::fs::create_dir_all(occupied.parent().unwrap())?;
+    std::fs::write(&occupied, b"unrelated")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(
+        &pool,
+        &bill_id,
+        household_id,
+        AttachmentCategory::Bills,
+        relative,
+    )
+    .await?;
+    let item_id = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &item_id, household_id, 0).await?;
+
+    let response = copy_attachment(
+        &pool,
+        &vault,
+        "bills",
+        &bill_id,
+        "inventory_items",
+        &item_id,
+    )
+    .await?;
+    assert_eq!(response.relative_path, "scan (1).pdf");
+    assert!(!response.deduplicated);
+    assert_eq!(std::fs::read(&occupied)?, b"unrelated");
+    assert_eq!(
+        std::fs::read(attachment_path(
+            &root,
+            household_id,
+            AttachmentCategory::InventoryItems,
+            "scan (1).pdf"
+        ))?,
+        b"new-scan"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_copy_reuses_identical_unclaimed_file() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_copy_dedup";
+    seed_household(&pool, household_id).await?;
+
+    let relative = "manual.pdf";
+    let source_path = attachment_path(&root, household_id, AttachmentCategory::Bills, relative);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"manual-bytes")?;
+    let existing = attachment_path(
+        &root,
+        household_id,
+        AttachmentCategory::InventoryItems,
+        relative,
+    );
+    std::fs::create_dir_all(existing.parent().unwrap())?;
+    std::fs::write(&existing, b"manual-bytes")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(
+        &pool,
+        &bill_id,
+        household_id,
+        AttachmentCategory::Bills,
+        relative,
+    )
+    .await?;
+    let item_id = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &item_id, household_id, 0).await?;
+
+    let response = copy_attachment(
+        &pool,
+        &vault,
+        "bills",
+        &bill_id,
+        "inventory_items",
+        &item_id,
+    )
+    .await?;
+    assert_eq!(response.relative_path, relative);
+    assert!(response.deduplicated);
+    let entries = std::fs::read_dir(existing.parent().unwrap())?.count();
+    assert_eq!(entries, 1, "no second copy written");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_copy_does_not_reuse_file_claimed_by_another_table() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_copy_claimed";
+    seed_household(&pool, household_id).await?;
+
+    let relative = "manual.pdf";
+    let source_path = attachment_path(&root, household_id, AttachmentCategory::Bills, relative);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"manual-bytes")?;
+    let existing = attachment_path(
+        &root,
+        household_id,
+        AttachmentCategory::InventoryItems,
+        relative,
+    );
+    std::fs::create_dir_all(existing.parent().unwrap())?;
+    std::fs::write(&existing, b"manual-bytes")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(
+        &pool,
+        &bill_id,
+        household_id,
+        AttachmentCategory::Bills,
+        relative,
+    )
+    .await?;
+    // A policy filed under the inventory category owns the identical file.
+    sqlx::query(
+        "INSERT INTO policies (id, amount, due_date, household_id, created_at, updated_at, position, relative_path, category) VALUES (?1, 0, 0, ?2, 0, 0, 0, ?3, 'inventory_items')",
+    )
+    .bind(Uuid::now_v7().to_string())
+    .bind(household_id)
+    .bind(relative)
+    .execute(&pool)
+    .await?;
+    let item_id = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &item_id, household_id, 0).await?;
+
+    let response = copy_attachment(
+        &pool,
+        &vault,
+        "bills",
+        &bill_id,
+        "inventory_items",
+        &item_id,
+    )
+    .await?;
+    assert_eq!(response.relative_path, "manual (1).pdf");
+    assert!(!response.deduplicated);
+    assert_eq!(std::fs::read(&existing)?, b"manual-bytes");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_copy_releases_the_destination_previous_file() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_copy_release";
+    seed_household(&pool, household_id).await?;
+
+    let source_path = attachment_path(&root, household_id, AttachmentCategory::Bills, "new.pdf");
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"new-bytes")?;
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(
+        &pool,
+        &bill_id,
+        household_id,
+        AttachmentCategory::Bills,
+        "new.pdf",
+    )
+    .await?;
+
+    let old_path = attachment_path(
+        &root,
+        household_id,
+        AttachmentCategory::InventoryItems,
+        "old.pdf",
+    );
+    std::fs::create_dir_all(old_path.parent().unwrap())?;
+    std::fs::write(&old_path, b"old-bytes")?;
+    let item_id = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &item_id, household_id, 0).await?;
+    sqlx::query("UPDATE inventory_items SET relative_path = 'old.pdf' WHERE id = ?1")
+        .bind(&item_id)
+        .execute(&pool)
+        .await?;
+    insert_files_index(
+        &pool,
+        household_id,
+        "old-file",
+        AttachmentCategory::InventoryItems,
+        "old.pdf",
+    )
+    .await?;
+
+    // A second item shares the same previous path, so the file stays until
+    // both have moved on.
+    let shared_path = attachment_path(
+        &root,
+        household_id,
+        AttachmentCategory::InventoryItems,
+        "shared.pdf",
+    );
+    std::fs::write(&shared_path, b"shared-bytes")?;
+    let keeper_id = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &keeper_id, household_id, 1).await?;
+    sqlx::query(
+        "UPDATE inventory_items SET relative_path = 'shared.pdf', deleted_at = 1 WHERE id = ?1",
+    )
+    .bind(&keeper_id)
+    .execute(&pool)
+    .await?;
+    let sharer_id = Uuid::now_v7().to_string();
+    sqlx::query(
+        "INSERT INTO policies (id, amount, due_date, household_id, created_at, updated_at, position, relative_path, category) VALUES (?1, 0, 0, ?2, 0, 0, 0, 'shared.pdf', 'inventory_items')",
+    )
+    .bind(&sharer_id)
+    .bind(household_id)
+    .execute(&pool)
+    .await?;
+
+    copy_attachment(
+        &pool,
+        &vault,
+        "bills",
+        &bill_id,
+        "inventory_items",
+        &item_id,
+    )
+    .await?;
+    assert!(!old_path.exists(), "unclaimed previous file is removed");
+    let indexed: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM files_index WHERE household_id = ?1 AND filename = 'old.pdf'",
+    )
+    .bind(household_id)
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(indexed, 0);
+
+    copy_attachment(&pool, &vault, "bills", &bill_id, "policies", &sharer_id).await?;
+    assert!(
+        shared_path.exists(),
+        "a soft-deleted row still claims its file"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_copy_updates_files_index_without_rebuild() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_copy_index";
+    seed_household(&pool, household_id).await?;
+
+    let relative = "warranty.pdf";
+    let source_path = attachment_path(&root, household_id, AttachmentCategory::Bills, relative);
+    std::fs::create_dir_all(source_path.parent().unwrap())?;
+    std::fs::write(&source_path, b"warranty-bytes")?;
+
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(
+        &pool,
+        &bill_id,
+        household_id,
+        AttachmentCategory::Bills,
+        relative,
+    )
+    .await?;
+    let item_id = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &item_id, household_id, 0).await?;
+
+    let indexer = FilesIndexer::new(pool.clone(), vault.clone());
+    let (tx, _rx) = tokio::sync::mpsc::channel(64);
+    indexer
+        .rebuild(household_id, RebuildMode::Full, tx)
+        .await
+        .map_err(|err| anyhow::anyhow!(err.to_string()))?;
+    let built: i64 =
+        sqlx::query_scalar("SELECT source_row_count FROM files_index_meta WHERE household_id = ?1")
+            .bind(household_id)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(built, 1);
+
+    copy_attachment(
+        &pool,
+        &vault,
+        "bills",
+        &bill_id,
+        "inventory_items",
+        &item_id,
+    )
+    .await?;
+
+    let (filename, size): (String, Option<i64>) = sqlx::query_as(
+        "SELECT filename, size_bytes FROM files_index WHERE household_id = ?1 AND category = ?2",
+    )
+    .bind(household_id)
+    .bind(AttachmentCategory::InventoryItems.as_str())
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(filename, relative);
+    assert_eq!(size, Some(b"warranty-bytes".len() as i64));
+    let count: i64 =
+        sqlx::query_scalar("SELECT source_row_count FROM files_index_meta WHERE household_id = ?1")
+            .bind(household_id)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachments_import_dir_creates_rows_and_reports_rejections() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let source = tmp.path().join("scans");
+    std::fs::create_dir_all(source.join("2023"))?;
+    std::fs::write(source.join("boiler.pdf"), b"boiler")?;
+    std::fs::write(source.join("2023/fridge.jpg"), b"fridge")?;
+    std::fs::write(source.join("installer.exe"), b"nope")?;
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_import";
+    seed_household(&pool, household_id).await?;
+
+    let category = AttachmentCategory::InventoryItems;
+    let response = import_attachments_dir(
+        &pool,
+        &vault,
+        "inventory_items",
+        household_id,
+        category,
+        &source,
+    )
+    .await?;
+    assert_eq!(response.imported, 2);
+    assert_eq!(response.rejected, 1);
+
+    let rejected: Vec<_> = response
+        .files
+        .iter()
+        .filter(|file| file.status == AttachmentImportStatus::Rejected)
+        .collect();
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0].source, "installer.exe");
+    assert_eq!(
+        rejected[0].code.as_deref(),
+        Some("ATTACHMENT/TYPE_NOT_ALLOWED")
+    );
+
+    for relative in ["boiler.pdf", "2023/fridge.jpg"] {
+        assert!(
+            attachment_path(&root, household_id, category, relative).exists(),
+            "{relative} copied into the vault"
+        );
+    }
+    assert!(!attachment_path(&root, household_id, category, "installer.exe").exists());
+
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT name, relative_path FROM inventory_items WHERE household_id = ?1 ORDER BY relative_path",
+    )
+    .bind(household_id)
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(
+        rows,
+        vec![
+            ("fridge".to_string(), "2023/fridge.jpg".to_string()),
+            ("boiler".to_string(), "boiler.pdf".to_string()),
+        ]
+    );
+    assert!(source.join("boiler.pdf").exists(), "source left untouched");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachments_import_dir_refuses_tables_with_required_dates() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let source = tmp.path().join("scans");
+    std::fs::create_dir_all(&source)?;
+    std::fs::write(source.join("water.pdf"), b"water")?;
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_import_bills";
+    seed_household(&pool, household_id).await?;
+
+    for (table, category) in [
+        ("bills", AttachmentCategory::Bills),
+        ("policies", AttachmentCategory::Policies),
+        ("property_documents", AttachmentCategory::PropertyDocuments),
+    ] {
+        let err = import_attachments_dir(&pool, &vault, table, household_id, category, &source)
+            .await
+            .expect_err("bulk import cannot invent amounts or dates");
+        assert_eq!(err.code(), "ATTACHMENT/UNSUPPORTED_TABLE");
+    }
+    let bills: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM bills WHERE household_id = ?1")
+        .bind(household_id)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(bills, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_restore_from_backup_recovers_lost_file() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+    migrate::apply_migrations(&pool).await?;
+    let household_id = "hh_attach_restore";
+    seed_household(&pool, household_id).await?;
+
+    let relative = "statements/march.pdf";
+    let live_path = attachment_path(&root, household_id, AttachmentCategory::Bills, relative);
+    std::fs::create_dir_all(live_path.parent().unwrap())?;
+    std::fs::write(&live_path, b"statement-bytes")?;
+    let bill_id = Uuid::now_v7().to_string();
+    insert_bill(
+        &pool,
+        &bill_id,
+        household_id,
+        AttachmentCategory::Bills,
+        relative,
+    )
+    .await?;
+    // A second bill needs its own position; insert_bill always uses 0.
+    let unbacked_id = Uuid::now_v7().to_string();
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category) VALUES (?1, 1000, ?2, ?3, ?2, ?2, 1, 'attachments', 'statements/april.pdf', 'bills')",
+    )
+    .bind(&unbacked_id)
+    .bind(Utc::now().timestamp())
+    .bind(household_id)
+    .execute(&pool)
+    .await?;
+
+    let backup = create_export(
+        &pool,
+        vault.clone(),
+        ExportOptions {
+            out_parent: tmp.path().join("exports"),
+            compress: false,
+            include_deletions_audit: false,
+            tables: Default::default(),
+        },
+    )
+    .await?;
+
+    std::fs::remove_file(&live_path)?;
+    let restored =
+        restore_attachment_from_backup(&pool, &vault, "bills", &bill_id, &backup.directory).await?;
+    assert!(restored.restored);
+    assert_eq!(restored.relative_path, relative);
+    let reopened = vault.resolve(household_id, AttachmentCategory::Bills, relative)?;
+    assert_eq!(std::fs::read(reopened)?, b"statement-bytes");
+
+    let again =
+        restore_attachment_from_backup(&pool, &vault, "bills", &bill_id, &backup.directory).await?;
+    assert!(!again.restored, "identical file is left in place");
+
+    let err =
+        restore_attachment_from_backup(&pool, &vault, "bills", &unbacked_id, &backup.directory)
+            .await
+            .expect_err("file missing from backup");
+    assert_eq!(err.code(), "ATTACHMENT/NOT_IN_BACKUP");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_content_hash_tracks_created_and_copied_files() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    let household_id = "hh_attach_hash";
+    seed_household(&pool, household_id).await?;
+
+    let relative = "utilities/water.pdf";
+    let file_path = attachment_path(&root, household_id, AttachmentCategory::Bills, relative);
+    std::fs::create_dir_all(file_path.parent().unwrap())?;
+    std::fs::write(&file_path, b"water-bill")?;
+
+    let mut data = serde_json::Map::new();
+    data.insert("amount".into(), 1200.into());
+    data.insert("due_date".into(), 1_700_000_000_000_i64.into());
+    data.insert("household_id".into(), household_id.into());
+    data.insert("position".into(), 0.into());
+    data.insert("content_sha256".into(), "forged".into());
+    let guard = AttachmentMutationGuard::new(
+        household_id.to_string(),
+        AttachmentCategory::Bills,
+        Some(relative.to_string()),
+        Some(file_path.clone()),
+    );
+    let created = commands::create_command(&pool, "bills", data, Some(guard)).await?;
+    let bill_id = created["id"].as_str().unwrap().to_string();
+
+    let stored: Option<String> =
+        sqlx::query_scalar("SELECT content_sha256 FROM bills WHERE id = ?1")
+            .bind(&bill_id)
+            .fetch_one(&pool)
+            .await?;
+    let stored = stored.expect("hash recorded on create");
+    assert_ne!(stored, "forged");
+    assert_eq!(stored.len(), 64);
+
+    let item_id = Uuid::now_v7().to_string();
+    insert_inventory_item(&pool, &item_id, household_id, 0).await?;
+    copy_attachment(
+        &pool,
+        &vault,
+        "bills",
+        &bill_id,
+        "inventory_items",
+        &item_id,
+    )
+    .await?;
+    let copied: Option<String> =
+        sqlx::query_scalar("SELECT content_sha256 FROM inventory_items WHERE id = ?1")
+            .bind(&item_id)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(copied.as_deref(), Some(stored.as_str()));
+
+    // A file edited after it was hashed is rehashed on next use.
+    std::fs::write(&file_path, b"water-bill-corrected")?;
+    sqlx::query("UPDATE bills SET content_hashed_at = 0 WHERE id = ?1")
+        .bind(&bill_id)
+        .execute(&pool)
+        .await?;
+    let fresh = attachment_content_sha256(&pool, &vault, "bills", &bill_id)
+        .await?
+        .expect("file present");
+    assert_ne!(fresh, stored);
+    let saved: Option<String> =
+        sqlx::query_scalar("SELECT content_sha256 FROM bills WHERE id = ?1")
+            .bind(&bill_id)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(saved.as_deref(), Some(fresh.as_str()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachments_list_for_entity_returns_bill_file_and_nothing_for_bare_bill() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    seed_household(&pool, "hh_a").await?;
+    insert_bill(
+        &pool,
+        "bill_doc",
+        "hh_a",
+        AttachmentCategory::Bills,
+        "docs/gas.pdf",
+    )
+    .await?;
+    let path = vault.resolve("hh_a", AttachmentCategory::Bills, "docs/gas.pdf")?;
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, b"gas bill")?;
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position) VALUES ('bill_bare', 500, 0, 'hh_a', 0, 0, 1)",
+    )
+    .execute(&pool)
+    .await?;
+
+    let listed = list_entity_attachments(&pool, &vault, "bills", "bill_doc").await?;
+    assert_eq!(listed.len(), 1);
+    let attachment = &listed[0];
+    assert_eq!(attachment.table, "bills");
+    assert_eq!(attachment.id, "bill_doc");
+    assert_eq!(attachment.household_id, "hh_a");
+    assert_eq!(attachment.category, AttachmentCategory::Bills);
+    assert_eq!(attachment.relative_path, "docs/gas.pdf");
+    assert!(attachment.exists);
+    assert_eq!(attachment.size_bytes, Some(8));
+
+    let bare = list_entity_attachments(&pool, &vault, "bills", "bill_bare").await?;
+    assert!(bare.is_empty(), "{bare:?}");
+
+    let err = list_entity_attachments(&pool, &vault, "bills", "missing")
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), "DB/NOT_FOUND");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachments_list_for_entity_includes_child_row_files() -> Result<()> {
+    let tmp = tempdir()?;
+    let root = tmp.path().join("attachments");
+    std::fs::create_dir_all(&root)?;
+    let vault = setup_vault(&root);
+
+    let pool = setup_pool().await?;
+    seed_household(&pool, "hh_a").await?;
+    let now = Utc::now().timestamp();
+    sqlx::query(
+        "INSERT INTO pets (id, name, type, household_id, created_at, updated_at, position) VALUES ('pet_a', 'Rex', 'dog', 'hh_a', ?1, ?1, 0)",
+    )
+    .bind(now)
+    .execute(&pool)
+    .await?;
+    sqlx::query(
+        "INSERT INTO pet_medical (id, pet_id, date, description, household_id, created_at, updated_at, root_key, relative_path, category) VALUES ('med_a', 'pet_a', ?1, 'Vaccine', 'hh_a', ?1, ?1, 'attachments', 'vaccine.pdf', 'pet_medical')",
+    )
+    .bind(now)
+    .execute(&pool)
+    .await?;
+
+    let listed = list_entity_attachments(&pool, &vault, "pets", "pet_a").await?;
+    let found: Vec<(&str, &str, bool)> = listed
+        .iter()
+        .map(|item| (item.table.as_str(), item.id.as_str(), item.exists))
+        .collect();
+    assert_eq!(found, vec![("pet_medical", "med_a", false)]);
+    assert_eq!(listed[0].size_bytes, None);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use arklowdun_lib::{commands, migrate};
+use serde_json::Value;
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    for (id, position, deleted_at) in [
+        ("bill_a", 0, None),
+        ("bill_b", 1, None),
+        ("bill_c", 2, None),
+        ("bill_gone", 3, Some(200_i64)),
+    ] {
+        sqlx::query(
+            "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, deleted_at)
+             VALUES (?1, 1000, 1700000000000, 'default', 100, 100, ?2, ?3)",
+        )
+        .bind(id)
+        .bind(position)
+        .bind(deleted_at)
+        .execute(&pool)
+        .await
+        .expect("insert bill");
+    }
+    pool
+}
+
+fn ids(rows: &[Value]) -> Vec<&str> {
+    rows.iter()
+        .map(|row| row.get("id").and_then(Value::as_str).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn get_many_preserves_requested_order() {
+    let pool = setup_pool().await;
+    let requested = vec!["bill_c".to_string(), "bill_a".into(), "bill_b".into()];
+    let rows = commands::get_many_command(&pool, "bills", "default", &requested)
+        .await
+        .expect("get many");
+    assert_eq!(ids(&rows), vec!["bill_c", "bill_a", "bill_b"]);
+}
+
+#[tokio::test]
+async fn get_many_omits_missing_and_deleted_ids() {
+    let pool = setup_pool().await;
+    let requested = vec![
+        "missing".to_string(),
+        "bill_b".into(),
+        "bill_gone".into(),
+        "bill_a".into(),
+        "bill_b".into(),
+    ];
+    let rows = commands::get_many_command(&pool, "bills", "default", &requested)
+        .await
+        .expect("get many");
+    assert_eq!(ids(&rows), vec!["bill_b", "bill_a"]);
+
+    let other = commands::get_many_command(&pool, "bills", "other", &requested)
+        .await
+        .expect("get many for other household");
+    assert!(other.is_empty());
+}
+
+#[tokio::test]
+async fn get_many_rejects_oversized_requests() {
+    let pool = setup_pool().await;
+    let requested: Vec<String> = (0..=arklowdun_lib::GET_MANY_MAX_IDS)
+        .map(|i| format!("bill_{i}"))
+        .collect();
+    let err = commands::get_many_command(&pool, "bills", "default", &requested)
+        .await
+        .expect_err("too many ids");
+    assert_eq!(err.code(), "BAD_REQUEST");
+}
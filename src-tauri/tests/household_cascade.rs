@@ -6,8 +6,9 @@ use std::{
 
 use anyhow::Result;
 use arklowdun_lib::{
-    create_household, delete_household, pending_cascades, resume_household_delete, vacuum_queue,
-    CascadeDeleteOptions, CascadeProgress, CascadeProgressObserver,
+    count_cascade_rows, create_household, delete_household, deletions_audit, pending_cascades,
+    resume_household_delete, vacuum_queue, CascadeDeleteOptions, CascadeProgress,
+    CascadeProgressObserver,
 };
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 #[path = "util.rs"]
@@ -115,6 +116,40 @@ async fn cascade_deletes_related_rows_and_queues_vacuum() -> Result<()> {
         .fetch_optional(&pool)
         .await?;
     assert!(exists.is_none());
+
+    let audit = deletions_audit::list_deletions(&pool, &household_id, None).await?;
+    assert!(audit
+        .iter()
+        .any(|entry| entry.table_name == "household" && entry.row_id == household_id));
+    Ok(())
+}
+
+#[tokio::test]
+async fn precomputed_counts_match_cascade_total() -> Result<()> {
+    let pool = memory_pool().await?;
+    let household_id = seed_household(&pool).await?;
+    let (_vault_guard, vault) = util::temp_vault();
+
+    let counts = count_cascade_rows(&pool, &household_id).await?;
+    let notes = counts
+        .phases
+        .iter()
+        .find(|phase| phase.phase == "notes")
+        .expect("notes phase counted");
+    assert_eq!(notes.rows, 1);
+    let phase_rows: u64 = counts.phases.iter().map(|phase| phase.rows).sum();
+    assert_eq!(counts.total_expected, phase_rows + 1);
+
+    let (observer, records) = progress_collector();
+    let mut options = CascadeDeleteOptions::default();
+    options.progress = Some(observer);
+    let outcome = delete_household(&pool, &vault, &household_id, None, options).await?;
+    assert!(outcome.completed);
+    assert_eq!(outcome.total_expected, counts.total_expected);
+
+    let progress = records.lock().unwrap();
+    let first = progress.first().expect("progress emitted");
+    assert_eq!(first.total, counts.total_expected);
     Ok(())
 }
 
@@ -219,7 +254,11 @@ async fn cascade_phase_registry_covers_household_tables() -> Result<()> {
     for table in tables {
         if matches!(
             table.as_str(),
-            "household" | "cascade_checkpoints" | "cascade_vacuum_queue" | "shadow_read_audit"
+            "household"
+                | "cascade_checkpoints"
+                | "cascade_vacuum_queue"
+                | "shadow_read_audit"
+                | "deletions_audit"
         ) {
             continue;
         }
@@ -2,8 +2,8 @@ use anyhow::Result;
 use arklowdun_lib::{
     create_household, default_household_id, delete_household, get_household,
     household_active::{self, ActiveSetError, StoreHandle},
-    list_households, migrate, restore_household, update_household, CascadeDeleteOptions,
-    HouseholdCrudError, HouseholdUpdateInput,
+    list_households, migrate, restore_household, seed_household_defaults, update_household,
+    CascadeDeleteOptions, HouseholdCrudError, HouseholdUpdateInput, SeedDefaultsReport,
 };
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 #[path = "util.rs"]
@@ -210,3 +210,68 @@ async fn list_includes_deleted_when_requested() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn seed_defaults_is_idempotent() -> Result<()> {
+    let pool = memory_pool().await?;
+    let created = create_household(&pool, "Seeded", None).await?;
+    sqlx::query(
+        "INSERT INTO categories (id, household_id, name, slug, color, position, z, is_visible, created_at, updated_at, deleted_at)
+         VALUES ('cat_own', ?1, 'bills', 'my-bills', '#000000', 0, 0, 1, 1, 1, NULL)",
+    )
+    .bind(&created.id)
+    .execute(&pool)
+    .await?;
+
+    let first = seed_household_defaults(&pool, &created.id).await?;
+    assert_eq!(
+        first,
+        SeedDefaultsReport {
+            categories_created: 11,
+            budget_categories_created: 8,
+        }
+    );
+
+    let second = seed_household_defaults(&pool, &created.id).await?;
+    assert_eq!(second, SeedDefaultsReport::default());
+
+    let names: Vec<String> = sqlx::query_scalar(
+        "SELECT name FROM categories WHERE household_id = ?1 AND deleted_at IS NULL ORDER BY position",
+    )
+    .bind(&created.id)
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(names.len(), 12);
+    assert_eq!(names[0], "bills");
+    for expected in ["Primary", "Tasks", "Insurance", "Shopping"] {
+        assert!(
+            names.iter().any(|name| name == expected),
+            "missing {expected}"
+        );
+    }
+
+    let budget: Vec<String> = sqlx::query_scalar(
+        "SELECT name FROM budget_categories WHERE household_id = ?1 AND deleted_at IS NULL ORDER BY position",
+    )
+    .bind(&created.id)
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(budget.first().map(String::as_str), Some("Groceries"));
+    assert!(budget.iter().any(|name| name == "Savings"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn seed_defaults_rejects_deleted_household() -> Result<()> {
+    let pool = memory_pool().await?;
+    let created = create_household(&pool, "Gone", None).await?;
+    sqlx::query("UPDATE household SET deleted_at = 1 WHERE id = ?1")
+        .bind(&created.id)
+        .execute(&pool)
+        .await?;
+    let err = seed_household_defaults(&pool, &created.id)
+        .await
+        .expect_err("deleted household rejected");
+    assert!(matches!(err, HouseholdCrudError::Deleted));
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use arklowdun_lib::{default_household_id, diagnostics, migrate, vault::Vault};
+use sqlx::sqlite::SqlitePoolOptions;
+use tempfile::tempdir;
+
+async fn memory_pool() -> Result<sqlx::SqlitePool> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+    sqlx::query("PRAGMA foreign_keys=ON;")
+        .execute(&pool)
+        .await?;
+    migrate::apply_migrations(&pool).await?;
+    Ok(pool)
+}
+
+async fn insert_note(
+    pool: &sqlx::SqlitePool,
+    id: &str,
+    household_id: &str,
+    position: i64,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, text, color, x, y)
+         VALUES (?1, ?2, ?3, 0, 0, 'Note', '#FFFFFF', 0, 0)",
+    )
+    .bind(id)
+    .bind(household_id)
+    .bind(position)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh_populates_cache_with_counts_and_bytes() -> Result<()> {
+    let pool = memory_pool().await?;
+    let default_id = default_household_id(&pool).await?;
+    let dir = tempdir()?;
+    let vault = Vault::new(dir.path());
+
+    insert_note(&pool, "note-a", &default_id, 0).await?;
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, relative_path)
+         VALUES ('bill-a', 100, 0, ?1, 0, 0, 0, 'statements/april.pdf')",
+    )
+    .bind(&default_id)
+    .execute(&pool)
+    .await?;
+    let file = dir
+        .path()
+        .join(&default_id)
+        .join("bills")
+        .join("statements/april.pdf");
+    std::fs::create_dir_all(file.parent().unwrap())?;
+    std::fs::write(&file, vec![0u8; 42])?;
+
+    assert!(diagnostics::cached_household_stats(&pool).await?.is_empty());
+
+    let refreshed = diagnostics::refresh_household_stats(&pool, &vault).await?;
+    let cached = diagnostics::cached_household_stats(&pool).await?;
+    assert_eq!(cached, refreshed);
+
+    let entry = cached
+        .iter()
+        .find(|entry| entry.household_id == default_id)
+        .expect("default household cached");
+    assert_eq!(entry.counts.get("notes"), Some(&1));
+    assert_eq!(entry.counts.get("bills"), Some(&1));
+    assert_eq!(entry.attachment_bytes, 42);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_returns_cache_without_recomputing() -> Result<()> {
+    let pool = memory_pool().await?;
+    let default_id = default_household_id(&pool).await?;
+    let dir = tempdir()?;
+    let vault = Vault::new(dir.path());
+
+    insert_note(&pool, "note-a", &default_id, 0).await?;
+    let refreshed = diagnostics::refresh_household_stats(&pool, &vault).await?;
+
+    insert_note(&pool, "note-b", &default_id, 1).await?;
+    let cached = diagnostics::cached_household_stats(&pool).await?;
+    assert_eq!(cached, refreshed);
+    let entry = cached
+        .iter()
+        .find(|entry| entry.household_id == default_id)
+        .expect("default household cached");
+    assert_eq!(entry.counts.get("notes"), Some(&1));
+
+    let refreshed = diagnostics::refresh_household_stats(&pool, &vault).await?;
+    let entry = refreshed
+        .iter()
+        .find(|entry| entry.household_id == default_id)
+        .expect("default household refreshed");
+    assert_eq!(entry.counts.get("notes"), Some(&2));
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use arklowdun_lib::{
+    attachment_category::AttachmentCategory,
+    db,
+    export::create_attachments_export,
+    import::{
+        import_attachments_only, validate_bundle, validator::IMPORT_ATTACHMENTS_ONLY_BUNDLE_CODE,
+        ImportBundle, ImportBundleError, ValidationContext, ValidationError,
+        MIN_SUPPORTED_APP_VERSION,
+    },
+    migrate,
+    vault::Vault,
+};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn setup_pool(dir: &TempDir) -> SqlitePool {
+    let pool = db::connect_sqlite_pool(&dir.path().join("arklowdun.sqlite3"))
+        .await
+        .expect("connect sqlite pool");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+async fn insert_household(pool: &SqlitePool, id: &str) {
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES (?1, ?1, 100, 100, NULL, 'UTC')",
+    )
+    .bind(id)
+    .execute(pool)
+    .await
+    .expect("insert household");
+}
+
+async fn insert_bill(pool: &SqlitePool, id: &str, household_id: &str, position: i64, rel: &str) {
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category)
+         VALUES (?1, 4200, 0, ?2, 100, 100, ?3, 'attachments', ?4, 'bills')",
+    )
+    .bind(id)
+    .bind(household_id)
+    .bind(position)
+    .bind(rel)
+    .execute(pool)
+    .await
+    .expect("insert bill");
+}
+
+async fn count_bills(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM bills")
+        .fetch_one(pool)
+        .await
+        .expect("count bills")
+}
+
+async fn seed_and_export(
+    pool: &SqlitePool,
+    vault: Arc<Vault>,
+    out: &TempDir,
+) -> std::path::PathBuf {
+    insert_household(pool, "hh_src").await;
+    insert_household(pool, "hh_dst").await;
+    for (position, (id, rel)) in [("bill_water", "water.pdf"), ("bill_gas", "gas.pdf")]
+        .into_iter()
+        .enumerate()
+    {
+        insert_bill(pool, id, "hh_src", position as i64, rel).await;
+        let path = vault
+            .resolve("hh_src", AttachmentCategory::Bills, rel)
+            .unwrap();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, rel.as_bytes()).unwrap();
+    }
+    // The target household already tracks water.pdf but has no file for it.
+    insert_bill(pool, "bill_dst_water", "hh_dst", 0, "water.pdf").await;
+
+    create_attachments_export(pool, vault, out.path(), "hh_src")
+        .await
+        .expect("attachments-only export")
+        .directory
+}
+
+#[tokio::test]
+async fn imports_files_into_target_vault_and_links_rows() {
+    let db_dir = TempDir::new().unwrap();
+    let pool = setup_pool(&db_dir).await;
+    let attachments = TempDir::new().unwrap();
+    let vault = Arc::new(Vault::new(attachments.path()));
+    let out = TempDir::new().unwrap();
+    let bundle = seed_and_export(&pool, vault.clone(), &out).await;
+
+    let preview = import_attachments_only(&pool, &vault, &bundle, "hh_dst", true)
+        .await
+        .expect("dry run");
+    assert!(preview.dry_run);
+    assert_eq!(preview.source_household_id, "hh_src");
+    assert_eq!(preview.verified, 2);
+    assert_eq!(preview.copied, 2);
+    assert!(!attachments.path().join("hh_dst").exists());
+
+    let report = import_attachments_only(&pool, &vault, &bundle, "hh_dst", false)
+        .await
+        .expect("import");
+    assert_eq!(report.copied, 2);
+    assert_eq!(report.linked, 1);
+    assert_eq!(report.unlinked, vec!["bills/gas.pdf".to_string()]);
+    assert!(report.conflicts.is_empty());
+
+    for rel in ["water.pdf", "gas.pdf"] {
+        let resolved = vault
+            .resolve("hh_dst", AttachmentCategory::Bills, rel)
+            .expect("resolve in target vault");
+        assert_eq!(std::fs::read(resolved).unwrap(), rel.as_bytes());
+    }
+
+    let again = import_attachments_only(&pool, &vault, &bundle, "hh_dst", false)
+        .await
+        .expect("re-import");
+    assert_eq!(again.copied, 0);
+    assert_eq!(again.skipped, 2);
+}
+
+#[tokio::test]
+async fn tampered_bundle_is_rejected_before_writing() {
+    let db_dir = TempDir::new().unwrap();
+    let pool = setup_pool(&db_dir).await;
+    let attachments = TempDir::new().unwrap();
+    let vault = Arc::new(Vault::new(attachments.path()));
+    let out = TempDir::new().unwrap();
+    let bundle = seed_and_export(&pool, vault.clone(), &out).await;
+
+    std::fs::write(
+        bundle.join("attachments/hh_src/bills/water.pdf"),
+        b"tampered",
+    )
+    .unwrap();
+
+    let err = import_attachments_only(&pool, &vault, &bundle, "hh_dst", false)
+        .await
+        .expect_err("hash mismatch");
+    assert_eq!(err.code(), "IMPORT/ATTACHMENT_HASH_MISMATCH");
+    assert!(!attachments.path().join("hh_dst").exists());
+}
+
+#[tokio::test]
+async fn replace_import_of_attachments_only_bundle_is_refused() {
+    let db_dir = TempDir::new().unwrap();
+    let pool = setup_pool(&db_dir).await;
+    let attachments = TempDir::new().unwrap();
+    let vault = Arc::new(Vault::new(attachments.path()));
+    let out = TempDir::new().unwrap();
+    let bundle = seed_and_export(&pool, vault.clone(), &out).await;
+    let bills_before = count_bills(&pool).await;
+
+    let err = ImportBundle::load(&bundle).expect_err("no data directory");
+    assert!(matches!(err, ImportBundleError::DataDirMissing));
+
+    // A data directory added by hand still does not make it a table import.
+    std::fs::create_dir_all(bundle.join("data")).unwrap();
+    let loaded = ImportBundle::load(&bundle).expect("load with data dir");
+    let minimum = semver::Version::parse(MIN_SUPPORTED_APP_VERSION).unwrap();
+    let ctx = ValidationContext::with_minimum_version(&pool, db_dir.path(), &minimum);
+    let err = validate_bundle(&loaded, &ctx)
+        .await
+        .expect_err("attachments-only bundle refused");
+    assert!(matches!(err, ValidationError::AttachmentsOnly { .. }));
+    assert_eq!(
+        arklowdun_lib::AppError::from(err).code(),
+        IMPORT_ATTACHMENTS_ONLY_BUNDLE_CODE
+    );
+
+    assert_eq!(count_bills(&pool).await, bills_before);
+}
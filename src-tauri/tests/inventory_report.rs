@@ -0,0 +1,104 @@
+use arklowdun_lib::{due, inventory_report, migrate};
+use sqlx::SqlitePool;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const NOW_MS: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+async fn insert_item(
+    pool: &SqlitePool,
+    id: &str,
+    position: i64,
+    estimated_value: Option<i64>,
+    warranty_expiry: Option<i64>,
+    deleted_at: Option<i64>,
+) {
+    sqlx::query(
+        "INSERT INTO inventory_items (id, name, warranty_expiry, estimated_value, household_id, position, created_at, updated_at, deleted_at)
+         VALUES (?1, ?1, ?2, ?3, 'default', ?4, ?5, ?5, ?6)",
+    )
+    .bind(id)
+    .bind(warranty_expiry)
+    .bind(estimated_value)
+    .bind(position)
+    .bind(NOW_MS)
+    .bind(deleted_at)
+    .execute(pool)
+    .await
+    .expect("insert inventory item");
+}
+
+#[tokio::test]
+async fn sums_values_and_flags_expiring_warranties() {
+    let pool = setup_pool().await;
+    insert_item(
+        &pool,
+        "tv",
+        0,
+        Some(80_000),
+        Some(NOW_MS + 10 * DAY_MS),
+        None,
+    )
+    .await;
+    insert_item(
+        &pool,
+        "sofa",
+        1,
+        Some(120_000),
+        Some(NOW_MS + 400 * DAY_MS),
+        None,
+    )
+    .await;
+    insert_item(&pool, "lamp", 2, None, None, None).await;
+    insert_item(&pool, "expired", 3, None, Some(NOW_MS - DAY_MS), None).await;
+    insert_item(
+        &pool,
+        "gone",
+        4,
+        Some(50_000),
+        Some(NOW_MS + DAY_MS),
+        Some(NOW_MS),
+    )
+    .await;
+
+    let report = inventory_report::build_report(&pool, "default", NOW_MS, 30 * DAY_MS)
+        .await
+        .expect("inventory report");
+
+    assert_eq!(report.total_value, 200_000);
+    assert_eq!(report.count, 4);
+    assert_eq!(report.expiring_warranties.len(), 1);
+    let tv = &report.expiring_warranties[0];
+    assert_eq!(tv.item_id, "tv");
+    assert_eq!(tv.warranty_expiry, NOW_MS + 10 * DAY_MS);
+    assert_eq!(tv.estimated_value, 80_000);
+}
+
+#[tokio::test]
+async fn empty_household_reports_zero() {
+    let pool = setup_pool().await;
+    let report = inventory_report::build_report(&pool, "default", NOW_MS, 30 * DAY_MS)
+        .await
+        .expect("inventory report");
+    assert_eq!(report.total_value, 0);
+    assert_eq!(report.count, 0);
+    assert!(report.expiring_warranties.is_empty());
+}
+
+#[tokio::test]
+async fn negative_horizon_is_rejected() {
+    let pool = setup_pool().await;
+    let err = inventory_report::build_report(&pool, "default", NOW_MS, -1)
+        .await
+        .expect_err("negative horizon");
+    assert_eq!(err.code(), due::DUE_INVALID_HORIZON_CODE);
+}
@@ -87,6 +87,7 @@ async fn events_update_rejects_wrong_household() -> Result<()> {
         data,
         Some("wrong-household"),
         None,
+        None,
     )
     .await
     .expect_err("expected mismatched event update to fail");
@@ -137,7 +138,7 @@ async fn notes_update_rejects_missing_household() -> Result<()> {
     let mut data = Map::new();
     data.insert("text".into(), Value::String("Updated".into()));
 
-    let err = commands::update_command(&pool, "notes", "note-update", data, Some(""), None)
+    let err = commands::update_command(&pool, "notes", "note-update", data, Some(""), None, None)
         .await
         .expect_err("expected update without household id to fail");
     assert_scope_violation(err);
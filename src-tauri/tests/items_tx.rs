@@ -28,6 +28,18 @@ async fn setup_table(pool: &sqlx::SqlitePool, table: &str) -> Result<()> {
 );"
     );
     sqlx::query(&sql).execute(pool).await?;
+    sqlx::query(
+        "CREATE TABLE deletions_audit (
+  id INTEGER PRIMARY KEY,
+  household_id TEXT NOT NULL,
+  table_name TEXT NOT NULL,
+  row_id TEXT NOT NULL,
+  operation TEXT NOT NULL,
+  deleted_at INTEGER NOT NULL
+);",
+    )
+    .execute(pool)
+    .await?;
     sqlx::query(
         "INSERT INTO household (id, name, created_at, updated_at) VALUES ('H', 'hh', 0, 0)",
     )
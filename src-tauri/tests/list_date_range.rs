@@ -0,0 +1,86 @@
+use arklowdun_lib::commands::{self, ListDateRange, VALIDATION_DATE_FIELD_CODE};
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    arklowdun_lib::migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    for (idx, due) in [1_000_i64, 2_000, 3_000, 4_000].iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position)
+             VALUES (?1, 100, ?2, 'default', 1, 1, ?3)",
+        )
+        .bind(format!("bill_{idx}"))
+        .bind(due)
+        .bind(idx as i64)
+        .execute(&pool)
+        .await
+        .expect("insert bill");
+    }
+    pool
+}
+
+fn ids(rows: &[serde_json::Value]) -> Vec<&str> {
+    rows.iter()
+        .map(|row| row["id"].as_str().expect("id"))
+        .collect()
+}
+
+#[tokio::test]
+async fn bills_filter_by_due_date_window() {
+    let pool = setup_pool().await;
+
+    let range = ListDateRange {
+        field: "due_date".into(),
+        from_ms: Some(2_000),
+        to_ms: Some(3_000),
+    };
+    let rows = commands::list_command(
+        &pool,
+        "bills",
+        "default",
+        Some("due_date"),
+        None,
+        None,
+        Some(&range),
+    )
+    .await
+    .expect("list bills in range");
+    assert_eq!(ids(&rows), vec!["bill_1", "bill_2"]);
+
+    let open_ended = ListDateRange::from_parts(Some("due_date".into()), Some(3_000), None);
+    let rows = commands::list_command(
+        &pool,
+        "bills",
+        "default",
+        Some("due_date"),
+        None,
+        None,
+        open_ended.as_ref(),
+    )
+    .await
+    .expect("list bills from bound");
+    assert_eq!(ids(&rows), vec!["bill_2", "bill_3"]);
+}
+
+#[tokio::test]
+async fn rejects_non_timestamp_date_field() {
+    let pool = setup_pool().await;
+
+    let range = ListDateRange {
+        field: "amount".into(),
+        from_ms: Some(0),
+        to_ms: None,
+    };
+    let err = commands::list_command(&pool, "bills", "default", None, None, None, Some(&range))
+        .await
+        .expect_err("amount is not a timestamp");
+    assert_eq!(err.code(), VALIDATION_DATE_FIELD_CODE);
+    assert_eq!(
+        err.context().get("field").map(String::as_str),
+        Some("amount")
+    );
+}
@@ -0,0 +1,24 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use tracing::Level;
+
+#[test]
+fn set_log_filter_validates_and_swaps_the_active_filter() {
+    std::env::set_var("TAURI_ARKLOWDUN_LOG", "arklowdun=info,sqlx=warn");
+    arklowdun_lib::init_logging();
+
+    assert!(!tracing::enabled!(target: "sqlx", Level::DEBUG));
+
+    let err = arklowdun_lib::set_log_filter("sqlx=loud").expect_err("invalid level rejected");
+    assert_eq!(err.code(), "LOGGING/INVALID_FILTER");
+    assert!(!tracing::enabled!(target: "sqlx", Level::DEBUG));
+
+    let previous =
+        arklowdun_lib::set_log_filter("arklowdun=info,sqlx=debug").expect("valid directive");
+    assert_eq!(previous, "arklowdun=info,sqlx=warn");
+    assert!(tracing::enabled!(target: "sqlx", Level::DEBUG));
+
+    let previous = arklowdun_lib::set_log_filter("arklowdun=info,sqlx=warn").unwrap();
+    assert_eq!(previous, "arklowdun=info,sqlx=debug");
+    assert!(!tracing::enabled!(target: "sqlx", Level::DEBUG));
+}
@@ -0,0 +1,128 @@
+use arklowdun_lib::{migrate, schedule};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const BASE_MS: i64 = 1_700_000_000_000;
+const MEMBER_ID: &str = "member-1";
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh', 'Household', ?1, ?1, NULL, 'UTC')",
+    )
+    .bind(BASE_MS)
+    .execute(&pool)
+    .await
+    .expect("insert household");
+    sqlx::query(
+        "INSERT INTO family_members (id, name, household_id, created_at, updated_at, position)
+         VALUES (?1, 'Sam', 'hh', ?2, ?2, 0)",
+    )
+    .bind(MEMBER_ID)
+    .bind(BASE_MS)
+    .execute(&pool)
+    .await
+    .expect("insert member");
+    pool
+}
+
+async fn insert_member_event(pool: &SqlitePool, position: i64, start: i64, end: i64) {
+    let event_id = Uuid::now_v7().to_string();
+    sqlx::query(
+        "INSERT INTO events (id, title, reminder, household_id, created_at, updated_at, deleted_at, tz, start_at_utc, end_at_utc)
+         VALUES (?1, 'Busy', NULL, 'hh', ?2, ?2, NULL, 'UTC', ?3, ?4)",
+    )
+    .bind(&event_id)
+    .bind(BASE_MS)
+    .bind(start)
+    .bind(end)
+    .execute(pool)
+    .await
+    .expect("insert event");
+
+    let note_id = Uuid::now_v7().to_string();
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y, member_id)
+         VALUES (?1, 'hh', ?2, ?3, ?3, 0, 'busy', '#FFF4B8', 0.0, 0.0, ?4)",
+    )
+    .bind(&note_id)
+    .bind(position)
+    .bind(BASE_MS)
+    .bind(MEMBER_ID)
+    .execute(pool)
+    .await
+    .expect("insert note");
+    sqlx::query(
+        "INSERT INTO note_links (id, household_id, note_id, entity_type, entity_id, relation, created_at, updated_at)
+         VALUES (?1, 'hh', ?2, 'event', ?3, 'attached_to', ?4, ?4)",
+    )
+    .bind(Uuid::now_v7().to_string())
+    .bind(&note_id)
+    .bind(&event_id)
+    .bind(BASE_MS)
+    .execute(pool)
+    .await
+    .expect("insert note link");
+}
+
+#[tokio::test]
+async fn overlapping_events_coalesce_into_one_interval() {
+    let pool = setup_pool().await;
+    insert_member_event(&pool, 0, BASE_MS, BASE_MS + 2 * HOUR_MS).await;
+    insert_member_event(&pool, 1, BASE_MS + HOUR_MS, BASE_MS + 3 * HOUR_MS).await;
+
+    let response =
+        schedule::family_member_busy(&pool, MEMBER_ID, BASE_MS - HOUR_MS, BASE_MS + 24 * HOUR_MS)
+            .await
+            .expect("busy query");
+
+    assert_eq!(
+        response.busy,
+        vec![schedule::BusyInterval {
+            start_utc: BASE_MS,
+            end_utc: BASE_MS + 3 * HOUR_MS,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn gap_between_events_produces_two_intervals() {
+    let pool = setup_pool().await;
+    insert_member_event(&pool, 0, BASE_MS, BASE_MS + HOUR_MS).await;
+    insert_member_event(&pool, 1, BASE_MS + 2 * HOUR_MS, BASE_MS + 3 * HOUR_MS).await;
+
+    let response =
+        schedule::family_member_busy(&pool, MEMBER_ID, BASE_MS - HOUR_MS, BASE_MS + 24 * HOUR_MS)
+            .await
+            .expect("busy query");
+
+    assert_eq!(
+        response.busy,
+        vec![
+            schedule::BusyInterval {
+                start_utc: BASE_MS,
+                end_utc: BASE_MS + HOUR_MS,
+            },
+            schedule::BusyInterval {
+                start_utc: BASE_MS + 2 * HOUR_MS,
+                end_utc: BASE_MS + 3 * HOUR_MS,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn unknown_member_is_rejected() {
+    let pool = setup_pool().await;
+    let err = schedule::family_member_busy(&pool, "missing", BASE_MS, BASE_MS + HOUR_MS)
+        .await
+        .expect_err("unknown member");
+    assert_eq!(err.code(), "VALIDATION/MEMBER_NOT_FOUND");
+}
@@ -131,3 +131,39 @@ async fn migrate_from_zero_is_correct_and_idempotent() -> Result<()> {
     assert_fk_and_integrity_ok(&pool).await?;
     Ok(())
 }
+
+#[tokio::test]
+async fn migrate_from_zero_adds_attachment_categories() -> Result<()> {
+    let pool = SqlitePool::connect("sqlite::memory:").await?;
+    arklowdun_lib::migrate::apply_migrations(&pool)
+        .await
+        .context("apply_migrations")?;
+
+    for table in [
+        "bills",
+        "inventory_items",
+        "pet_medical",
+        "policies",
+        "property_documents",
+        "vehicle_maintenance",
+    ] {
+        let has_category: Option<i64> =
+            sqlx::query_scalar("SELECT 1 FROM pragma_table_info(?) WHERE name = 'category';")
+                .bind(table)
+                .fetch_optional(&pool)
+                .await?;
+        assert!(has_category.is_some(), "expected `{table}.category`");
+        assert_index_exists(&pool, &format!("{table}_household_category_path_idx")).await?;
+    }
+
+    let index_sql: String = sqlx::query_scalar(
+        "SELECT sql FROM sqlite_master WHERE type='index' AND name='vehicle_maintenance_household_category_path_idx';",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert!(
+        index_sql.contains("WHERE deleted_at IS NULL"),
+        "vehicle_maintenance path index must ignore deleted rows: {index_sql}"
+    );
+    Ok(())
+}
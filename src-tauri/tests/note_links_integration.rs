@@ -1,8 +1,9 @@
 use arklowdun_lib::{
-    migrate,
+    commands, migrate,
     note_links::{
-        create_link, get_link_for_note, list_notes_for_entity, list_notes_for_entity_page,
-        quick_create_note_for_entity, NoteLinkEntityType,
+        create_link, create_note_with_links, get_link_for_note, list_notes_for_entity,
+        list_notes_for_entity_page, quick_create_note_for_entity, NoteLinkEntityType,
+        NoteLinkTarget,
     },
 };
 use sqlx::SqlitePool;
@@ -388,6 +389,195 @@ async fn quick_create_is_atomic() {
     assert_eq!(note_count, 0, "no notes inserted on failure");
 }
 
+#[tokio::test]
+async fn create_with_links_links_every_target() {
+    let pool = setup_pool().await;
+    let event_id = insert_event(&pool, "default", "Move-in day", 1).await;
+    let file_id = insert_file(&pool, "default", "lease.pdf", 0).await;
+
+    let created = create_note_with_links(
+        &pool,
+        "default",
+        "cat_primary",
+        "Keys from the agent",
+        None,
+        &[
+            NoteLinkTarget {
+                entity_type: NoteLinkEntityType::Event,
+                entity_id: event_id.clone(),
+            },
+            NoteLinkTarget {
+                entity_type: NoteLinkEntityType::File,
+                entity_id: file_id.clone(),
+            },
+        ],
+    )
+    .await
+    .expect("create note with links");
+    assert_eq!(created.link_ids.len(), 2);
+
+    let links: Vec<(String, String, String)> =
+        sqlx::query_as("SELECT id, note_id, entity_id FROM note_links ORDER BY entity_type ASC")
+            .fetch_all(&pool)
+            .await
+            .expect("fetch links");
+    assert_eq!(
+        links,
+        vec![
+            (
+                created.link_ids[0].clone(),
+                created.note_id.clone(),
+                event_id
+            ),
+            (
+                created.link_ids[1].clone(),
+                created.note_id.clone(),
+                file_id
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn create_with_links_rolls_back_on_invalid_target() {
+    let pool = setup_pool().await;
+    let event_id = insert_event(&pool, "default", "Move-in day", 1).await;
+
+    let err = create_note_with_links(
+        &pool,
+        "default",
+        "cat_primary",
+        "Should not persist",
+        None,
+        &[
+            NoteLinkTarget {
+                entity_type: NoteLinkEntityType::Event,
+                entity_id: event_id,
+            },
+            NoteLinkTarget {
+                entity_type: NoteLinkEntityType::File,
+                entity_id: "missing-file".into(),
+            },
+        ],
+    )
+    .await
+    .expect_err("invalid target aborts");
+    assert_eq!(err.code(), "NOTE_LINK/ENTITY_NOT_FOUND");
+
+    let link_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM note_links")
+        .fetch_one(&pool)
+        .await
+        .expect("count links");
+    assert_eq!(link_count, 0, "no links inserted on failure");
+
+    let note_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM notes")
+        .fetch_one(&pool)
+        .await
+        .expect("count notes");
+    assert_eq!(note_count, 0, "no notes inserted on failure");
+}
+
+#[tokio::test]
+async fn deleting_event_hides_links_until_restored() {
+    let pool = setup_pool().await;
+    let event_id = insert_event(&pool, "default", "Dentist", 1).await;
+    let note_id = insert_note(&pool, "default", "cat_primary", 0, 1, "Bring forms").await;
+    let link = create_link(
+        &pool,
+        "default",
+        &note_id,
+        NoteLinkEntityType::Event,
+        &event_id,
+        None,
+    )
+    .await
+    .expect("create link");
+
+    commands::delete_command(&pool, "events", "default", &event_id, None)
+        .await
+        .expect("delete event");
+    let active: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM note_links WHERE deleted_at IS NULL")
+            .fetch_one(&pool)
+            .await
+            .expect("count active links");
+    assert_eq!(active, 0, "links to a deleted event are hidden");
+    let err = list_notes_for_entity_page(
+        &pool,
+        "default",
+        NoteLinkEntityType::Event,
+        &event_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .expect_err("deleted event is not listable");
+    assert_eq!(err.code(), "NOTE_LINK/ENTITY_NOT_FOUND");
+
+    commands::restore_command(&pool, "events", "default", &event_id)
+        .await
+        .expect("restore event");
+    let restored = get_link_for_note(
+        &pool,
+        "default",
+        &note_id,
+        NoteLinkEntityType::Event,
+        &event_id,
+    )
+    .await
+    .expect("link restored with event");
+    assert_eq!(restored.id, link.id);
+    let listed = list_notes_for_entity_page(
+        &pool,
+        "default",
+        NoteLinkEntityType::Event,
+        &event_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .expect("list restored links");
+    assert_eq!(listed.items.len(), 1);
+}
+
+#[tokio::test]
+async fn event_delete_rolls_back_when_links_cannot_be_hidden() {
+    let pool = setup_pool().await;
+    let event_id = insert_event(&pool, "default", "Dentist", 1).await;
+    let note_id = insert_note(&pool, "default", "cat_primary", 0, 1, "Bring forms").await;
+    create_link(
+        &pool,
+        "default",
+        &note_id,
+        NoteLinkEntityType::Event,
+        &event_id,
+        None,
+    )
+    .await
+    .expect("create link");
+    sqlx::query(
+        "CREATE TRIGGER note_links_frozen BEFORE UPDATE ON note_links
+         BEGIN SELECT RAISE(ABORT, 'note_links frozen'); END",
+    )
+    .execute(&pool)
+    .await
+    .expect("create trigger");
+
+    commands::delete_command(&pool, "events", "default", &event_id, None)
+        .await
+        .expect_err("link update fails");
+    let deleted_at: Option<i64> = sqlx::query_scalar("SELECT deleted_at FROM events WHERE id = ?1")
+        .bind(&event_id)
+        .fetch_one(&pool)
+        .await
+        .expect("event row");
+    assert_eq!(deleted_at, None, "event delete rolled back with its links");
+}
+
 #[tokio::test]
 async fn recurring_series_parent_notes_visible_to_instances() {
     let pool = setup_pool().await;
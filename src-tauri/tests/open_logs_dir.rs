@@ -0,0 +1,20 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use arklowdun_lib::diagnostics;
+use tempfile::tempdir;
+
+#[test]
+fn logs_dir_follows_fake_appdata() {
+    let appdata = tempdir().unwrap();
+    std::env::set_var("ARK_FAKE_APPDATA", appdata.path());
+
+    let app = tauri::test::mock_app();
+    let dir = diagnostics::logs_dir(app.handle()).unwrap();
+    assert_eq!(dir, appdata.path().join("logs"));
+
+    let summary = diagnostics::gather_summary(app.handle()).unwrap();
+    assert_eq!(
+        std::path::Path::new(&summary.log_path).parent(),
+        Some(dir.as_path())
+    );
+}
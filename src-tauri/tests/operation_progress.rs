@@ -0,0 +1,153 @@
+use std::sync::{Arc, Mutex};
+
+use arklowdun_lib::{
+    attachment_category::AttachmentCategory,
+    db,
+    export::{create_export_with_progress, ExportOptions},
+    import::{build_plan, execute_plan, ExecutionContext, ImportBundle, ImportMode, PlanContext},
+    migrate,
+    ops::progress::{OperationProgress, ProgressObserver, ProgressStage},
+    vault::Vault,
+};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn setup_pool(dir: &TempDir) -> SqlitePool {
+    let pool = db::connect_sqlite_pool(&dir.path().join("arklowdun.sqlite3"))
+        .await
+        .expect("connect sqlite pool");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+fn recorder() -> (ProgressObserver, Arc<Mutex<Vec<OperationProgress>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    let observer: ProgressObserver = Arc::new(move |progress| {
+        sink.lock().unwrap().push(progress);
+    });
+    (observer, events)
+}
+
+fn last_for(events: &[OperationProgress], stage: &ProgressStage) -> OperationProgress {
+    events
+        .iter()
+        .rev()
+        .find(|event| &event.stage == stage)
+        .cloned()
+        .unwrap_or_else(|| panic!("no progress reported for {stage:?}"))
+}
+
+async fn seed_note(pool: &SqlitePool) {
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh_progress', 'Progress', 100, 100, NULL, 'UTC')",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y)
+         VALUES ('note_progress', 'hh_progress', 0, 100, 100, 1, 'tracked', '#FFFF88', 0, 0)",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn seed_bill_attachment(pool: &SqlitePool, vault: &Vault) {
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category)
+         VALUES ('bill_progress', 4200, 0, 'hh_progress', 100, 100, 0, 'attachments', 'water.pdf', 'bills')",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    let path = vault
+        .resolve("hh_progress", AttachmentCategory::Bills, "water.pdf")
+        .unwrap();
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, b"twelve bytes").unwrap();
+}
+
+fn export_options(out: &TempDir) -> ExportOptions {
+    ExportOptions {
+        out_parent: out.path().to_path_buf(),
+        compress: false,
+        include_deletions_audit: false,
+        tables: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn export_reports_table_and_attachment_progress() {
+    let source_dir = TempDir::new().unwrap();
+    let source = setup_pool(&source_dir).await;
+    let attachments = TempDir::new().unwrap();
+    let vault = Arc::new(Vault::new(attachments.path()));
+    seed_note(&source).await;
+    seed_bill_attachment(&source, &vault).await;
+
+    let out = TempDir::new().unwrap();
+    let (observer, events) = recorder();
+    create_export_with_progress(&source, vault, export_options(&out), Some(observer))
+        .await
+        .expect("export");
+
+    let events = events.lock().unwrap().clone();
+    let notes = last_for(&events, &ProgressStage::Table("notes".into()));
+    assert_eq!((notes.done, notes.total), (1, 1));
+    let files = last_for(&events, &ProgressStage::Attachments);
+    assert_eq!((files.done, files.total, files.bytes), (1, 1, 12));
+}
+
+#[tokio::test]
+async fn import_reports_row_progress_per_table() {
+    let source_dir = TempDir::new().unwrap();
+    let source = setup_pool(&source_dir).await;
+    let source_attachments = TempDir::new().unwrap();
+    seed_note(&source).await;
+    let out = TempDir::new().unwrap();
+    let entry = create_export_with_progress(
+        &source,
+        Arc::new(Vault::new(source_attachments.path())),
+        export_options(&out),
+        None,
+    )
+    .await
+    .expect("export");
+
+    let target_dir = TempDir::new().unwrap();
+    let target = setup_pool(&target_dir).await;
+    let target_attachments = TempDir::new().unwrap();
+    let target_vault = Arc::new(Vault::new(target_attachments.path()));
+    let bundle = ImportBundle::load(&entry.directory).expect("load bundle");
+    let plan = build_plan(
+        &bundle,
+        &PlanContext {
+            pool: &target,
+            vault: target_vault.clone(),
+        },
+        ImportMode::Merge,
+    )
+    .await
+    .expect("build plan");
+
+    let (observer, events) = recorder();
+    execute_plan(
+        &bundle,
+        &plan,
+        &ExecutionContext::new(&target, target_vault).with_progress(observer),
+    )
+    .await
+    .expect("execute plan");
+
+    let events = events.lock().unwrap().clone();
+    let notes = last_for(&events, &ProgressStage::Table("notes".into()));
+    assert_eq!((notes.done, notes.total), (1, 1));
+    let households = last_for(&events, &ProgressStage::Table("households".into()));
+    assert_eq!(households.done, households.total);
+    assert!(events.iter().all(|event| event.done <= event.total));
+}
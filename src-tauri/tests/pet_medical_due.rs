@@ -0,0 +1,102 @@
+use arklowdun_lib::{due, migrate};
+use sqlx::SqlitePool;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const NOW_MS: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh', 'Household', ?1, ?1, NULL, 'UTC')",
+    )
+    .bind(NOW_MS)
+    .execute(&pool)
+    .await
+    .expect("insert household");
+    sqlx::query(
+        "INSERT INTO pets (id, name, type, household_id, created_at, updated_at, position)
+         VALUES ('pet-1', 'Biscuit', 'dog', 'hh', ?1, ?1, 0)",
+    )
+    .bind(NOW_MS)
+    .execute(&pool)
+    .await
+    .expect("insert pet");
+    pool
+}
+
+async fn insert_medical(
+    pool: &SqlitePool,
+    id: &str,
+    date: i64,
+    reminder: Option<i64>,
+    deleted_at: Option<i64>,
+) {
+    sqlx::query(
+        "INSERT INTO pet_medical (id, pet_id, date, description, reminder, household_id, created_at, updated_at, deleted_at)
+         VALUES (?1, 'pet-1', ?2, ?1, ?3, 'hh', ?4, ?4, ?5)",
+    )
+    .bind(id)
+    .bind(date)
+    .bind(reminder)
+    .bind(NOW_MS)
+    .bind(deleted_at)
+    .execute(pool)
+    .await
+    .expect("insert pet medical");
+}
+
+#[tokio::test]
+async fn reports_due_records_with_pet_name() {
+    let pool = setup_pool().await;
+    insert_medical(
+        &pool,
+        "booster",
+        NOW_MS - 300 * DAY_MS,
+        Some(NOW_MS + 3 * DAY_MS),
+        None,
+    )
+    .await;
+    insert_medical(&pool, "checkup", NOW_MS + 10 * DAY_MS, None, None).await;
+    insert_medical(&pool, "dental", NOW_MS + 120 * DAY_MS, None, None).await;
+    insert_medical(&pool, "removed", NOW_MS + 2 * DAY_MS, None, Some(NOW_MS)).await;
+
+    let items = due::pet_medical_due(&pool, "hh", NOW_MS, 30 * DAY_MS)
+        .await
+        .expect("query pet medical due");
+
+    let ids: Vec<&str> = items.iter().map(|item| item.medical_id.as_str()).collect();
+    assert_eq!(ids, vec!["booster", "checkup"]);
+    assert!(items.iter().all(|item| item.pet_name == "Biscuit"));
+    assert_eq!(items[0].due_at, NOW_MS + 3 * DAY_MS);
+}
+
+#[tokio::test]
+async fn skips_records_for_deleted_pets() {
+    let pool = setup_pool().await;
+    insert_medical(&pool, "checkup", NOW_MS + DAY_MS, None, None).await;
+    sqlx::query("UPDATE pets SET deleted_at = ?1 WHERE id = 'pet-1'")
+        .bind(NOW_MS)
+        .execute(&pool)
+        .await
+        .expect("soft delete pet");
+
+    let items = due::pet_medical_due(&pool, "hh", NOW_MS, 30 * DAY_MS)
+        .await
+        .expect("query pet medical due");
+    assert!(items.is_empty());
+}
+
+#[tokio::test]
+async fn rejects_negative_horizon() {
+    let pool = setup_pool().await;
+    let err = due::pet_medical_due(&pool, "hh", NOW_MS, -1)
+        .await
+        .expect_err("negative horizon should fail");
+    assert_eq!(err.code(), due::DUE_INVALID_HORIZON_CODE);
+}
@@ -52,6 +52,7 @@ async fn build_state(dir: &TempDir) -> Result<(AppState, SqlitePool, PathBuf)> {
         vault,
         vault_migration: Arc::new(VaultMigrationManager::new(&attachments_root)?),
         maintenance: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        maintenance_reason: Arc::new(std::sync::Mutex::new(None)),
         files_indexer,
         pet_metrics: Arc::new(PetAttachmentMetrics::new()),
     };
@@ -0,0 +1,72 @@
+use arklowdun_lib::{due, migrate};
+use sqlx::SqlitePool;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const NOW_MS: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh', 'Household', ?1, ?1, NULL, 'UTC')",
+    )
+    .bind(NOW_MS)
+    .execute(&pool)
+    .await
+    .expect("insert household");
+    pool
+}
+
+async fn insert_policy(
+    pool: &SqlitePool,
+    id: &str,
+    position: i64,
+    renewal_date: Option<i64>,
+    deleted_at: Option<i64>,
+) {
+    sqlx::query(
+        "INSERT INTO policies (id, amount, due_date, household_id, name, provider, renewal_date, position, created_at, updated_at, deleted_at)
+         VALUES (?1, 12000, ?2, 'hh', ?1, 'Acme Mutual', ?3, ?4, ?2, ?2, ?5)",
+    )
+    .bind(id)
+    .bind(NOW_MS)
+    .bind(renewal_date)
+    .bind(position)
+    .bind(deleted_at)
+    .execute(pool)
+    .await
+    .expect("insert policy");
+}
+
+#[tokio::test]
+async fn reports_policies_renewing_inside_the_horizon() {
+    let pool = setup_pool().await;
+    insert_policy(&pool, "home", 0, Some(NOW_MS + 10 * DAY_MS), None).await;
+    insert_policy(&pool, "car", 1, Some(NOW_MS + 90 * DAY_MS), None).await;
+    insert_policy(&pool, "no-renewal", 2, None, None).await;
+    insert_policy(&pool, "deleted", 3, Some(NOW_MS + DAY_MS), Some(NOW_MS)).await;
+
+    let items = due::policies_renewal_due(&pool, "hh", NOW_MS, 30 * DAY_MS)
+        .await
+        .expect("renewal query");
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].policy_id, "home");
+    assert_eq!(items[0].name.as_deref(), Some("home"));
+    assert_eq!(items[0].provider.as_deref(), Some("Acme Mutual"));
+    assert_eq!(items[0].due_at, NOW_MS + 10 * DAY_MS);
+}
+
+#[tokio::test]
+async fn negative_horizon_is_rejected() {
+    let pool = setup_pool().await;
+    let err = due::policies_renewal_due(&pool, "hh", NOW_MS, -1)
+        .await
+        .expect_err("negative horizon");
+    assert_eq!(err.code(), due::DUE_INVALID_HORIZON_CODE);
+}
@@ -0,0 +1,86 @@
+use arklowdun_lib::{migrate, recurring_expenses};
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO budget_categories (id, name, household_id, created_at, updated_at, position)
+         VALUES ('housing', 'Housing', 'default', 0, 0, 0)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert budget category");
+    for (id, amount, description, day, active) in [
+        ("rent", 95_000, "Rent", 1, 1),
+        ("stream", 1_099, "Streaming", 31, 1),
+        ("gym", 4_000, "Gym", 15, 0),
+    ] {
+        sqlx::query(
+            "INSERT INTO expense_templates (id, household_id, category_id, amount, description, day_of_month, active, created_at, updated_at)
+             VALUES (?1, 'default', 'housing', ?2, ?3, ?4, ?5, 0, 0)",
+        )
+        .bind(id)
+        .bind(amount)
+        .bind(description)
+        .bind(day)
+        .bind(active)
+        .execute(&pool)
+        .await
+        .expect("insert template");
+    }
+    pool
+}
+
+async fn expense_count(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM expenses WHERE household_id = 'default'")
+        .fetch_one(pool)
+        .await
+        .expect("count expenses")
+}
+
+#[tokio::test]
+async fn generating_the_same_month_twice_is_a_no_op() {
+    let pool = setup_pool().await;
+    let created = recurring_expenses::generate_from_templates(&pool, "default", "2024-02")
+        .await
+        .expect("first run");
+    assert_eq!(created, 2);
+    assert_eq!(expense_count(&pool).await, 2);
+
+    let again = recurring_expenses::generate_from_templates(&pool, "default", "2024-02")
+        .await
+        .expect("second run");
+    assert_eq!(again, 0);
+    assert_eq!(expense_count(&pool).await, 2);
+
+    let streaming_date: i64 =
+        sqlx::query_scalar("SELECT date FROM expenses WHERE description = 'Streaming'")
+            .fetch_one(&pool)
+            .await
+            .expect("streaming expense");
+    // 2024-02-29T00:00:00Z: day 31 clamps to the end of February.
+    assert_eq!(streaming_date, 1_709_164_800_000);
+}
+
+#[tokio::test]
+async fn a_new_month_creates_new_entries() {
+    let pool = setup_pool().await;
+    recurring_expenses::generate_from_templates(&pool, "default", "2024-02")
+        .await
+        .expect("february");
+    let created = recurring_expenses::generate_from_templates(&pool, "default", "2024-03")
+        .await
+        .expect("march");
+    assert_eq!(created, 2);
+    assert_eq!(expense_count(&pool).await, 4);
+
+    let err = recurring_expenses::generate_from_templates(&pool, "default", "March")
+        .await
+        .expect_err("invalid month");
+    assert_eq!(err.code(), recurring_expenses::INVALID_MONTH_CODE);
+}
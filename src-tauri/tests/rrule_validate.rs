@@ -0,0 +1,74 @@
+use arklowdun_lib::recurrence::validate_rrule;
+use arklowdun_lib::time_errors::TimeErrorCode;
+use chrono::{TimeZone, Utc};
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+#[test]
+fn weekly_rule_returns_upcoming_occurrences() {
+    // Monday 2024-01-01 09:00 UTC.
+    let start = Utc
+        .with_ymd_and_hms(2024, 1, 1, 9, 0, 0)
+        .unwrap()
+        .timestamp_millis();
+    let result = validate_rrule("RRULE:FREQ=WEEKLY;BYDAY=MO", start, Some("UTC"), 4)
+        .expect("weekly rule is valid");
+
+    assert!(result.rrule.starts_with("FREQ=WEEKLY"), "{}", result.rrule);
+    assert!(result.rrule.contains("BYDAY=MO"), "{}", result.rrule);
+    assert_eq!(result.tz, "UTC");
+    assert_eq!(
+        result.occurrences_utc,
+        vec![
+            start,
+            start + 7 * DAY_MS,
+            start + 14 * DAY_MS,
+            start + 21 * DAY_MS
+        ]
+    );
+}
+
+#[test]
+fn occurrences_follow_local_wall_clock() {
+    // 09:00 GMT on Monday 2024-03-25; the clocks go forward on 2024-03-31,
+    // so the next 09:00 local is 08:00 UTC.
+    let start = Utc
+        .with_ymd_and_hms(2024, 3, 25, 9, 0, 0)
+        .unwrap()
+        .timestamp_millis();
+    let result = validate_rrule("FREQ=WEEKLY;COUNT=2", start, Some("Europe/London"), 5)
+        .expect("rule is valid");
+    assert_eq!(result.occurrences_utc.len(), 2);
+    assert_eq!(
+        result.occurrences_utc[1],
+        start + 7 * DAY_MS - 60 * 60 * 1000
+    );
+}
+
+#[test]
+fn malformed_rule_points_at_offending_token() {
+    let start = Utc
+        .with_ymd_and_hms(2024, 1, 1, 9, 0, 0)
+        .unwrap()
+        .timestamp_millis();
+    let err = validate_rrule("FREQ=WEEKLY;BYDAY=XX;COUNT=3", start, None, 5)
+        .expect_err("invalid weekday rejected");
+
+    assert_eq!(err.code(), TimeErrorCode::RruleParse.as_str());
+    let context = err.context();
+    assert_eq!(context.get("token").map(String::as_str), Some("BYDAY=XX"));
+    assert_eq!(context.get("position").map(String::as_str), Some("12"));
+    assert!(
+        context
+            .get("detail")
+            .is_some_and(|detail| !detail.is_empty()),
+        "parse detail included"
+    );
+}
+
+#[test]
+fn unknown_timezone_is_rejected() {
+    let err =
+        validate_rrule("FREQ=DAILY", 0, Some("Mars/Olympus"), 5).expect_err("unknown tz rejected");
+    assert_eq!(err.code(), TimeErrorCode::TimezoneUnknown.as_str());
+}
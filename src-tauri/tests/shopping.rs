@@ -0,0 +1,88 @@
+use arklowdun_lib::{migrate, shopping};
+use serde_json::Value;
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('other', 'Other', 0, 0, NULL, 'UTC')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert household");
+    for (id, household, position) in [
+        ("milk", "default", 0),
+        ("eggs", "default", 1),
+        ("bread", "default", 2),
+        ("theirs", "other", 0),
+    ] {
+        sqlx::query(
+            "INSERT INTO shopping_items (id, household_id, position, created_at, updated_at)
+             VALUES (?1, ?2, ?3, 100, 100)",
+        )
+        .bind(id)
+        .bind(household)
+        .bind(position)
+        .execute(&pool)
+        .await
+        .expect("insert shopping item");
+    }
+    pool
+}
+
+fn ids(rows: &[Value]) -> Vec<&str> {
+    rows.iter()
+        .map(|row| row.get("id").and_then(Value::as_str).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn completing_a_subset_hides_it_from_the_default_list() {
+    let pool = setup_pool().await;
+    let completed = shopping::complete_items(
+        &pool,
+        "default",
+        &["milk".to_string(), "bread".into(), "milk".into()],
+    )
+    .await
+    .expect("complete items");
+    assert_eq!(completed, 2);
+
+    let open = shopping::list_items(&pool, "default", false, None, None, None)
+        .await
+        .expect("list open items");
+    assert_eq!(ids(&open), vec!["eggs"]);
+
+    let all = shopping::list_items(&pool, "default", true, None, None, None)
+        .await
+        .expect("list all items");
+    assert_eq!(ids(&all), vec!["milk", "eggs", "bread"]);
+    let milk = &all[0];
+    assert_eq!(milk.get("completed").and_then(Value::as_i64), Some(1));
+    assert!(milk.get("completed_at").and_then(Value::as_i64).is_some());
+
+    let again = shopping::complete_items(&pool, "default", &["milk".to_string()])
+        .await
+        .expect("complete again");
+    assert_eq!(again, 0);
+}
+
+#[tokio::test]
+async fn completing_another_households_item_changes_nothing() {
+    let pool = setup_pool().await;
+    let err = shopping::complete_items(&pool, "default", &["eggs".to_string(), "theirs".into()])
+        .await
+        .expect_err("foreign item rejected");
+    assert_eq!(err.code(), shopping::ITEM_NOT_FOUND_CODE);
+
+    let open = shopping::list_items(&pool, "default", false, None, None, None)
+        .await
+        .expect("list open items");
+    assert_eq!(ids(&open), vec!["milk", "eggs", "bread"]);
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::path::Path;
+
+use arklowdun_lib::db::footprint::storage_footprint;
+use tempfile::TempDir;
+
+fn write(path: &Path, len: usize) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, vec![0_u8; len]).unwrap();
+}
+
+#[test]
+fn components_sum_to_total_on_seeded_layout() {
+    let root = TempDir::new().unwrap();
+    let app_dir = root.path();
+    let db_path = app_dir.join("arklowdun.sqlite3");
+    write(&db_path, 4096);
+    write(&app_dir.join("arklowdun.sqlite3-wal"), 1000);
+    write(&app_dir.join("arklowdun.sqlite3-shm"), 32);
+
+    let vault_root = app_dir.join("attachments");
+    write(&vault_root.join("hh_a/bills/gas.pdf"), 700);
+    write(&vault_root.join("hh_b/misc/notes/todo.txt"), 50);
+
+    write(
+        &app_dir.join("backups/20240101-000000/arklowdun.sqlite3"),
+        4096,
+    );
+    write(&app_dir.join("backups/20240101-000000/manifest.json"), 120);
+
+    let logs_dir = app_dir.join("logs");
+    write(&logs_dir.join("arklowdun.log"), 300);
+    write(&logs_dir.join("arklowdun.log.1"), 200);
+
+    let footprint = storage_footprint(&db_path, &vault_root, &logs_dir).unwrap();
+    assert_eq!(footprint.database_bytes, 4096 + 1000 + 32);
+    assert_eq!(footprint.vault_bytes, 750);
+    assert_eq!(footprint.backups_bytes, 4216);
+    assert_eq!(footprint.logs_bytes, 500);
+    assert_eq!(
+        footprint.total_bytes,
+        footprint.database_bytes
+            + footprint.vault_bytes
+            + footprint.backups_bytes
+            + footprint.logs_bytes
+    );
+}
+
+#[test]
+fn missing_areas_count_as_zero() {
+    let root = TempDir::new().unwrap();
+    let db_path = root.path().join("arklowdun.sqlite3");
+    write(&db_path, 10);
+
+    let footprint = storage_footprint(
+        &db_path,
+        &root.path().join("attachments"),
+        &root.path().join("logs"),
+    )
+    .unwrap();
+    assert_eq!(footprint.database_bytes, 10);
+    assert_eq!(footprint.vault_bytes, 0);
+    assert_eq!(footprint.backups_bytes, 0);
+    assert_eq!(footprint.logs_bytes, 0);
+    assert_eq!(footprint.total_bytes, 10);
+}
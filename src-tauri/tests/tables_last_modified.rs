@@ -0,0 +1,74 @@
+use arklowdun_lib::{commands, migrate};
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position)
+         VALUES ('bill_a', 1200, 1700000000000, 'default', 100, 100, 0)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert bill");
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, text, color, x, y, position, created_at, updated_at)
+         VALUES ('note_a', 'default', 'Milk', '#FFF4B8', 0, 0, 0, 200, 200)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert note");
+    pool
+}
+
+#[tokio::test]
+async fn updating_a_bill_bumps_only_bills() {
+    let pool = setup_pool().await;
+    let before = commands::tables_last_modified(&pool, "default")
+        .await
+        .expect("last modified before");
+    assert_eq!(before["bills"], Some(100));
+    assert_eq!(before["notes"], Some(200));
+    assert_eq!(before["events"], None);
+
+    let mut data = Map::new();
+    data.insert("amount".into(), Value::from(1500));
+    commands::update_command(&pool, "bills", "bill_a", data, Some("default"), None, None)
+        .await
+        .expect("update bill");
+
+    let after = commands::tables_last_modified(&pool, "default")
+        .await
+        .expect("last modified after");
+    assert!(after["bills"].expect("bills timestamp") > 100);
+    for (table, value) in &before {
+        if table != "bills" {
+            assert_eq!(after.get(table), Some(value), "{table} changed");
+        }
+    }
+}
+
+#[tokio::test]
+async fn deleted_rows_and_other_households_are_ignored() {
+    let pool = setup_pool().await;
+    sqlx::query("UPDATE bills SET deleted_at = 300, updated_at = 300 WHERE id = 'bill_a'")
+        .execute(&pool)
+        .await
+        .expect("soft delete bill");
+
+    let modified = commands::tables_last_modified(&pool, "default")
+        .await
+        .expect("last modified");
+    assert_eq!(modified["bills"], None);
+
+    let other = commands::tables_last_modified(&pool, "other")
+        .await
+        .expect("last modified other");
+    assert_eq!(other["notes"], None);
+    assert_eq!(other["household"], None);
+}
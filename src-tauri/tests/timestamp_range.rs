@@ -0,0 +1,77 @@
+use arklowdun_lib::{commands, migrate};
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+
+const START_MS: i64 = 1_717_232_400_000; // 2024-06-01T09:00:00Z
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    pool
+}
+
+fn event_payload(start: i64) -> Map<String, Value> {
+    let mut data = Map::new();
+    data.insert("household_id".into(), Value::from("default"));
+    data.insert("title".into(), Value::from("Dentist"));
+    data.insert("tz".into(), Value::from("UTC"));
+    data.insert("start_at_utc".into(), Value::from(start));
+    data
+}
+
+#[tokio::test]
+async fn seconds_scale_start_is_rejected_with_hint() {
+    let pool = setup_pool().await;
+    let err = commands::create_command(&pool, "events", event_payload(START_MS / 1000), None)
+        .await
+        .expect_err("seconds are rejected");
+    assert_eq!(err.code(), commands::VALIDATION_TIMESTAMP_RANGE_CODE);
+    assert_eq!(
+        err.context().get("field").map(String::as_str),
+        Some("start_at_utc")
+    );
+    assert!(err.context().contains_key("hint"));
+}
+
+#[tokio::test]
+async fn millis_scale_start_is_accepted() {
+    let pool = setup_pool().await;
+    let created = commands::create_command(&pool, "events", event_payload(START_MS), None)
+        .await
+        .expect("millis accepted");
+    assert_eq!(created["start_at_utc"], Value::from(START_MS));
+}
+
+#[tokio::test]
+async fn update_rejects_out_of_range_end() {
+    let pool = setup_pool().await;
+    let created = commands::create_command(&pool, "events", event_payload(START_MS), None)
+        .await
+        .expect("create event");
+    let id = created["id"].as_str().unwrap();
+
+    let mut patch = Map::new();
+    patch.insert("end_at_utc".into(), Value::from(START_MS * 1000));
+    let err = commands::update_command(&pool, "events", id, patch, Some("default"), None, None)
+        .await
+        .expect_err("microseconds are rejected");
+    assert_eq!(err.code(), commands::VALIDATION_TIMESTAMP_RANGE_CODE);
+    assert!(!err.context().contains_key("hint"));
+}
+
+#[test]
+fn range_bounds_are_configurable() {
+    let range = commands::TimestampRange {
+        min_ms: 0,
+        max_ms: START_MS,
+    };
+    assert!(range.check("start_at_utc", 1_000).is_ok());
+    assert!(range.check("start_at_utc", START_MS + 1).is_err());
+    assert!(commands::TimestampRange::default()
+        .check("start_at_utc", 1_000)
+        .is_err());
+}
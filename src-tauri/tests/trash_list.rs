@@ -0,0 +1,149 @@
+use arklowdun_lib::{commands, migrate};
+use sqlx::SqlitePool;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category)
+         VALUES ('bill_gas', 1200, 1700000000000, 'default', 100, 100, 0, 'attachments', 'gas.pdf', 'bills')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert bill");
+    for (id, text, position) in [
+        ("note_keep", "Keep me", 0),
+        ("note_gone", "Call the plumber", 1),
+    ] {
+        sqlx::query(
+            "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y)
+             VALUES (?1, 'default', ?2, 100, 100, 1, ?3, '#FFFF88', 0, 0)",
+        )
+        .bind(id)
+        .bind(position)
+        .bind(text)
+        .execute(&pool)
+        .await
+        .expect("insert note");
+    }
+    pool
+}
+
+#[tokio::test]
+async fn deleted_rows_from_two_tables_share_one_listing() {
+    let pool = setup_pool().await;
+    commands::delete_command(&pool, "bills", "default", "bill_gas", None)
+        .await
+        .expect("delete bill");
+    commands::delete_command(&pool, "notes", "default", "note_gone", None)
+        .await
+        .expect("delete note");
+    sqlx::query("UPDATE bills SET deleted_at = 1000 WHERE id = 'bill_gas'")
+        .execute(&pool)
+        .await
+        .expect("age bill deletion");
+
+    let trash = commands::trash_list(&pool, "default", None, None)
+        .await
+        .expect("trash list");
+    let listed: Vec<(&str, &str, Option<&str>)> = trash
+        .iter()
+        .map(|entry| {
+            (
+                entry.table.as_str(),
+                entry.id.as_str(),
+                entry.label.as_deref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        listed,
+        vec![
+            ("notes", "note_gone", Some("Call the plumber")),
+            ("bills", "bill_gas", Some("gas.pdf")),
+        ]
+    );
+    assert!(trash[0].deleted_at > trash[1].deleted_at);
+
+    let page = commands::trash_list(&pool, "default", Some(1), Some(1))
+        .await
+        .expect("second page");
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].id, "bill_gas");
+
+    let other = commands::trash_list(&pool, "hh_elsewhere", None, None)
+        .await
+        .expect("other household");
+    assert!(other.is_empty());
+}
+
+#[tokio::test]
+async fn restore_brings_back_an_event_and_a_bill() {
+    let pool = setup_pool().await;
+    sqlx::query(
+        "INSERT INTO events (id, title, household_id, created_at, updated_at, tz, start_at_utc)
+         VALUES ('event_swim', 'Swimming', 'default', 100, 100, 'UTC', 1700000000000)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert event");
+    commands::delete_command(&pool, "events", "default", "event_swim", None)
+        .await
+        .expect("delete event");
+    commands::delete_command(&pool, "bills", "default", "bill_gas", None)
+        .await
+        .expect("delete bill");
+
+    let event = commands::trash_restore(&pool, "events", "event_swim")
+        .await
+        .expect("restore event");
+    assert_eq!(event["title"], "Swimming");
+    assert!(event["deleted_at"].is_null());
+    let bill = commands::trash_restore(&pool, "bills", "bill_gas")
+        .await
+        .expect("restore bill");
+    assert_eq!(bill["amount"], 1200);
+    assert!(bill["deleted_at"].is_null());
+
+    let trash = commands::trash_list(&pool, "default", None, None)
+        .await
+        .expect("trash list");
+    assert!(trash.is_empty());
+
+    let err = commands::trash_restore(&pool, "bills", "bill_gas")
+        .await
+        .expect_err("bill is live again");
+    assert_eq!(err.code(), commands::TRASH_NOT_DELETED_CODE);
+    let err = commands::trash_restore(&pool, "household", "default")
+        .await
+        .expect_err("households are not trash rows");
+    assert_eq!(err.code(), commands::TRASH_UNSUPPORTED_TABLE_CODE);
+}
+
+#[tokio::test]
+async fn restore_refuses_rows_of_a_deleted_household() {
+    let pool = setup_pool().await;
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh_gone', 'Gone', 100, 100, 500, 'UTC')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert household");
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, deleted_at, z, text, color, x, y)
+         VALUES ('note_orphan', 'hh_gone', 0, 100, 100, 500, 1, 'Orphan', '#FFFF88', 0, 0)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert note");
+
+    let err = commands::trash_restore(&pool, "notes", "note_orphan")
+        .await
+        .expect_err("household is deleted");
+    assert_eq!(err.code(), "HOUSEHOLD_DELETED");
+}
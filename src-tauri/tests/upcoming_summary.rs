@@ -0,0 +1,118 @@
+use arklowdun_lib::{
+    due::{self, UpcomingKind},
+    migrate,
+};
+use sqlx::SqlitePool;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const NOW_MS: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh', 'Household', ?1, ?1, NULL, 'UTC')",
+    )
+    .bind(NOW_MS)
+    .execute(&pool)
+    .await
+    .expect("insert household");
+    pool
+}
+
+async fn seed_domains(pool: &SqlitePool) {
+    sqlx::query(
+        "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at)
+         VALUES ('bill-1', 4250, ?1, 'hh', ?2, ?2)",
+    )
+    .bind(NOW_MS + 4 * DAY_MS)
+    .bind(NOW_MS)
+    .execute(pool)
+    .await
+    .expect("insert bill");
+    sqlx::query(
+        "INSERT INTO events (id, title, reminder, household_id, created_at, updated_at, deleted_at, tz, start_at_utc, end_at_utc)
+         VALUES ('event-1', 'Dentist', NULL, 'hh', ?1, ?1, NULL, 'UTC', ?2, ?3)",
+    )
+    .bind(NOW_MS)
+    .bind(NOW_MS + DAY_MS)
+    .bind(NOW_MS + DAY_MS + 60 * 60 * 1000)
+    .execute(pool)
+    .await
+    .expect("insert event");
+    sqlx::query(
+        "INSERT INTO vehicles (id, household_id, name, position, next_mot_due, created_at, updated_at)
+         VALUES ('car-1', 'hh', 'Car', 0, ?1, ?2, ?2)",
+    )
+    .bind(NOW_MS + 2 * DAY_MS)
+    .bind(NOW_MS)
+    .execute(pool)
+    .await
+    .expect("insert vehicle");
+    sqlx::query(
+        "INSERT INTO pets (id, name, type, household_id, created_at, updated_at, position)
+         VALUES ('pet-1', 'Biscuit', 'dog', 'hh', ?1, ?1, 0)",
+    )
+    .bind(NOW_MS)
+    .execute(pool)
+    .await
+    .expect("insert pet");
+    sqlx::query(
+        "INSERT INTO pet_medical (id, pet_id, date, description, household_id, created_at, updated_at)
+         VALUES ('med-1', 'pet-1', ?1, 'Vaccination', 'hh', ?2, ?2)",
+    )
+    .bind(NOW_MS + 3 * DAY_MS)
+    .bind(NOW_MS)
+    .execute(pool)
+    .await
+    .expect("insert pet medical");
+}
+
+#[tokio::test]
+async fn interleaves_domains_by_timestamp() {
+    let pool = setup_pool().await;
+    seed_domains(&pool).await;
+
+    let summary = due::upcoming_summary(&pool, "hh", NOW_MS, 30 * DAY_MS)
+        .await
+        .expect("upcoming summary");
+
+    let entries: Vec<(UpcomingKind, &str)> = summary
+        .items
+        .iter()
+        .map(|item| (item.kind, item.id.as_str()))
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (UpcomingKind::Event, "event-1"),
+            (UpcomingKind::VehicleMot, "car-1"),
+            (UpcomingKind::PetMedical, "med-1"),
+            (UpcomingKind::Bill, "bill-1"),
+        ]
+    );
+    assert_eq!(summary.items[3].title, "Bill");
+    assert!(summary
+        .items
+        .windows(2)
+        .all(|pair| pair[0].at <= pair[1].at));
+    assert!(!summary.truncated);
+}
+
+#[tokio::test]
+async fn horizon_limits_every_domain() {
+    let pool = setup_pool().await;
+    seed_domains(&pool).await;
+
+    let summary = due::upcoming_summary(&pool, "hh", NOW_MS, 2 * DAY_MS + 1)
+        .await
+        .expect("upcoming summary");
+
+    let kinds: Vec<UpcomingKind> = summary.items.iter().map(|item| item.kind).collect();
+    assert_eq!(kinds, vec![UpcomingKind::Event, UpcomingKind::VehicleMot]);
+}
@@ -0,0 +1,95 @@
+use arklowdun_lib::{commands, migrate};
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+
+const SEEDED_AT: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO notes (id, household_id, position, created_at, updated_at, z, text, color, x, y)
+         VALUES ('note-1', 'default', 0, ?1, ?1, 0, 'Original', '#FFFF88', 0, 0)",
+    )
+    .bind(SEEDED_AT)
+    .execute(&pool)
+    .await
+    .expect("insert note");
+    pool
+}
+
+fn text_patch(text: &str) -> Map<String, Value> {
+    let mut data = Map::new();
+    data.insert("text".into(), Value::String(text.into()));
+    data
+}
+
+async fn note_state(pool: &SqlitePool) -> (String, i64) {
+    sqlx::query_as("SELECT text, updated_at FROM notes WHERE id = 'note-1'")
+        .fetch_one(pool)
+        .await
+        .expect("fetch note")
+}
+
+#[tokio::test]
+async fn guarded_update_applies_when_updated_at_matches() {
+    let pool = setup_pool().await;
+    commands::update_command(
+        &pool,
+        "notes",
+        "note-1",
+        text_patch("First edit"),
+        Some("default"),
+        None,
+        Some(SEEDED_AT),
+    )
+    .await
+    .expect("guarded update succeeds");
+
+    let (text, updated_at) = note_state(&pool).await;
+    assert_eq!(text, "First edit");
+    assert!(updated_at > SEEDED_AT);
+}
+
+#[tokio::test]
+async fn stale_guarded_update_is_rejected() {
+    let pool = setup_pool().await;
+    commands::update_command(
+        &pool,
+        "notes",
+        "note-1",
+        text_patch("Other window"),
+        Some("default"),
+        None,
+        None,
+    )
+    .await
+    .expect("unguarded update succeeds");
+    let (_, current) = note_state(&pool).await;
+
+    let err = commands::update_command(
+        &pool,
+        "notes",
+        "note-1",
+        text_patch("Stale edit"),
+        Some("default"),
+        None,
+        Some(SEEDED_AT),
+    )
+    .await
+    .expect_err("stale update rejected");
+    assert_eq!(err.code(), commands::STALE_WRITE_CODE);
+    let context = err.context();
+    assert_eq!(
+        context.get("current_updated_at").map(String::as_str),
+        Some(current.to_string().as_str())
+    );
+
+    let (text, updated_at) = note_state(&pool).await;
+    assert_eq!(text, "Other window");
+    assert_eq!(updated_at, current);
+}
@@ -129,3 +129,89 @@ async fn vault_migration_dry_run_and_apply_move_files() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn vault_migration_dry_run_plans_without_touching_files() -> Result<()> {
+    use arklowdun_lib::vault_migration::{
+        run_vault_migration_headless, HeadlessLegacyRoots, ManifestAction,
+    };
+
+    let tmp = tempdir()?;
+    let legacy_root = tmp.path().join("legacy");
+    let attachments_root = tmp.path().join("vault");
+    std::fs::create_dir_all(&legacy_root)?;
+    std::fs::create_dir_all(attachments_root.join("default/bills"))?;
+
+    std::fs::write(legacy_root.join("water.pdf"), b"water")?;
+    std::fs::write(legacy_root.join("power.pdf"), b"power")?;
+    // The vault already holds a different file where power.pdf would land.
+    std::fs::write(attachments_root.join("default/bills/power.pdf"), b"other")?;
+
+    let pool = SqlitePool::connect("sqlite::memory:").await?;
+    migrate::apply_migrations(&pool).await?;
+    let rows = [
+        ("bill-a", "water.pdf", "attachments"),
+        ("bill-b", "power.pdf", "attachments"),
+        ("bill-c", "gas.pdf", "attachments"),
+        ("bill-d", "phone.pdf", "dropbox"),
+    ];
+    for (position, (id, relative, root)) in rows.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO bills (id, amount, due_date, household_id, created_at, updated_at, position, root_key, relative_path, category) \
+             VALUES (?1, 100, 0, 'default', 0, 0, ?2, ?3, ?4, 'bills')",
+        )
+        .bind(id)
+        .bind(position as i64)
+        .bind(root)
+        .bind(relative)
+        .execute(&pool)
+        .await?;
+    }
+
+    let vault = Arc::new(Vault::new(&attachments_root));
+    let manager = Arc::new(VaultMigrationManager::new(&attachments_root)?);
+    let roots = HeadlessLegacyRoots::new(tmp.path().to_path_buf(), legacy_root.clone());
+    let dry =
+        run_vault_migration_headless(pool.clone(), vault, manager, MigrationMode::DryRun, roots)
+            .await?;
+
+    assert!(dry.completed);
+    assert_eq!(dry.counts.processed, 4);
+    assert_eq!(dry.counts.copied, 2, "two files would move");
+    assert_eq!(dry.counts.conflicts, 1);
+    assert_eq!(dry.counts.skipped, 1, "missing source is skipped");
+    assert_eq!(dry.counts.unsupported, 1, "unknown root is unsupported");
+
+    let actions: Vec<(&str, &ManifestAction)> = dry
+        .plan
+        .iter()
+        .map(|entry| (entry.id.as_str(), &entry.action))
+        .collect();
+    assert_eq!(actions.len(), 4);
+    assert!(matches!(actions[0], ("bill-a", ManifestAction::Copy)));
+    assert!(matches!(
+        actions[1],
+        ("bill-b", ManifestAction::ConflictRename)
+    ));
+    assert_eq!(dry.plan[1].conflict_suffix.as_deref(), Some(" (1)"));
+    assert!(matches!(actions[2], ("bill-c", ManifestAction::Skip)));
+    assert!(matches!(actions[3], ("bill-d", ManifestAction::Skip)));
+
+    assert!(legacy_root.join("water.pdf").exists());
+    assert!(legacy_root.join("power.pdf").exists());
+    assert!(!attachments_root.join("default/bills/water.pdf").exists());
+    assert!(!attachments_root
+        .join("default/bills/power (1).pdf")
+        .exists());
+    assert_eq!(
+        std::fs::read(attachments_root.join("default/bills/power.pdf"))?,
+        b"other"
+    );
+    let legacy_roots: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM bills WHERE root_key IS NOT NULL")
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(legacy_roots, 4);
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use arklowdun_lib::{migrate, vehicle_costs};
+use sqlx::SqlitePool;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const BASE_MS: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO vehicles (id, name, household_id, created_at, updated_at, position)
+         VALUES ('car', 'Car', 'default', ?1, ?1, 0)",
+    )
+    .bind(BASE_MS)
+    .execute(&pool)
+    .await
+    .expect("insert vehicle");
+    pool
+}
+
+async fn insert_maintenance(
+    pool: &SqlitePool,
+    id: &str,
+    date: i64,
+    kind: &str,
+    cost: Option<i64>,
+    deleted_at: Option<i64>,
+) {
+    sqlx::query(
+        "INSERT INTO vehicle_maintenance (id, vehicle_id, date, type, cost, household_id, created_at, updated_at, deleted_at)
+         VALUES (?1, 'car', ?2, ?3, ?4, 'default', ?2, ?2, ?5)",
+    )
+    .bind(id)
+    .bind(date)
+    .bind(kind)
+    .bind(cost)
+    .bind(deleted_at)
+    .execute(pool)
+    .await
+    .expect("insert maintenance");
+}
+
+#[tokio::test]
+async fn summary_sums_records_inside_window() {
+    let pool = setup_pool().await;
+    insert_maintenance(&pool, "m1", BASE_MS + DAY_MS, "service", Some(12_000), None).await;
+    insert_maintenance(
+        &pool,
+        "m2",
+        BASE_MS + 2 * DAY_MS,
+        "tyres",
+        Some(30_000),
+        None,
+    )
+    .await;
+    insert_maintenance(&pool, "m3", BASE_MS + 3 * DAY_MS, "service", None, None).await;
+    insert_maintenance(
+        &pool,
+        "old",
+        BASE_MS - 40 * DAY_MS,
+        "service",
+        Some(9_999),
+        None,
+    )
+    .await;
+    insert_maintenance(
+        &pool,
+        "gone",
+        BASE_MS + DAY_MS,
+        "service",
+        Some(5_000),
+        Some(BASE_MS + 5 * DAY_MS),
+    )
+    .await;
+
+    let summary = vehicle_costs::summarize_maintenance(
+        &pool,
+        "default",
+        "car",
+        BASE_MS,
+        BASE_MS + 30 * DAY_MS,
+    )
+    .await
+    .expect("summary");
+    assert_eq!(summary.total_cost, 42_000);
+    assert_eq!(summary.count, 3);
+    assert_eq!(summary.by_kind.len(), 2);
+    assert_eq!(summary.by_kind[0].kind, "tyres");
+    assert_eq!(summary.by_kind[0].total_cost, 30_000);
+    assert_eq!(summary.by_kind[1].kind, "service");
+    assert_eq!(summary.by_kind[1].total_cost, 12_000);
+    assert_eq!(summary.by_kind[1].count, 2);
+}
+
+#[tokio::test]
+async fn summary_rejects_unknown_vehicle_and_inverted_range() {
+    let pool = setup_pool().await;
+    let err = vehicle_costs::summarize_maintenance(&pool, "other", "car", 0, BASE_MS)
+        .await
+        .expect_err("vehicle in another household");
+    assert_eq!(err.code(), vehicle_costs::VEHICLE_NOT_FOUND_CODE);
+
+    let err = vehicle_costs::summarize_maintenance(&pool, "default", "car", BASE_MS, 0)
+        .await
+        .expect_err("inverted range");
+    assert_eq!(err.code(), vehicle_costs::INVALID_RANGE_CODE);
+
+    let empty = vehicle_costs::summarize_maintenance(&pool, "default", "car", 0, BASE_MS)
+        .await
+        .expect("empty summary");
+    assert_eq!(empty.total_cost, 0);
+    assert_eq!(empty.count, 0);
+    assert!(empty.by_kind.is_empty());
+}
@@ -0,0 +1,121 @@
+use arklowdun_lib::{
+    due::{self, VehicleDueKind},
+    migrate,
+};
+use sqlx::SqlitePool;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const NOW_MS: i64 = 1_700_000_000_000;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("connect sqlite memory");
+    migrate::apply_migrations(&pool)
+        .await
+        .expect("apply migrations");
+    sqlx::query(
+        "INSERT INTO household (id, name, created_at, updated_at, deleted_at, tz)
+         VALUES ('hh', 'Household', ?1, ?1, NULL, 'UTC')",
+    )
+    .bind(NOW_MS)
+    .execute(&pool)
+    .await
+    .expect("insert household");
+    pool
+}
+
+async fn insert_vehicle(
+    pool: &SqlitePool,
+    id: &str,
+    position: i64,
+    next_mot_due: Option<i64>,
+    next_service_due: Option<i64>,
+    deleted_at: Option<i64>,
+) {
+    sqlx::query(
+        "INSERT INTO vehicles (id, household_id, name, position, next_mot_due, next_service_due, created_at, updated_at, deleted_at)
+         VALUES (?1, 'hh', ?1, ?2, ?3, ?4, ?5, ?5, ?6)",
+    )
+    .bind(id)
+    .bind(position)
+    .bind(next_mot_due)
+    .bind(next_service_due)
+    .bind(NOW_MS)
+    .bind(deleted_at)
+    .execute(pool)
+    .await
+    .expect("insert vehicle");
+}
+
+#[tokio::test]
+async fn reports_only_items_inside_the_horizon() {
+    let pool = setup_pool().await;
+    insert_vehicle(
+        &pool,
+        "due-soon",
+        0,
+        Some(NOW_MS + 5 * DAY_MS),
+        Some(NOW_MS + 90 * DAY_MS),
+        None,
+    )
+    .await;
+    insert_vehicle(
+        &pool,
+        "later",
+        1,
+        Some(NOW_MS + 60 * DAY_MS),
+        Some(NOW_MS + 120 * DAY_MS),
+        None,
+    )
+    .await;
+    insert_vehicle(&pool, "no-dates", 2, None, None, None).await;
+    insert_vehicle(
+        &pool,
+        "deleted",
+        3,
+        Some(NOW_MS + DAY_MS),
+        None,
+        Some(NOW_MS),
+    )
+    .await;
+
+    let items = due::vehicles_due_soon(&pool, "hh", NOW_MS, 30 * DAY_MS)
+        .await
+        .expect("due query");
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].vehicle_id, "due-soon");
+    assert_eq!(items[0].kind, VehicleDueKind::Mot);
+    assert_eq!(items[0].due_at, NOW_MS + 5 * DAY_MS);
+}
+
+#[tokio::test]
+async fn reports_each_due_item_separately() {
+    let pool = setup_pool().await;
+    insert_vehicle(
+        &pool,
+        "both",
+        0,
+        Some(NOW_MS + 10 * DAY_MS),
+        Some(NOW_MS + 2 * DAY_MS),
+        None,
+    )
+    .await;
+
+    let items = due::vehicles_due_soon(&pool, "hh", NOW_MS, 30 * DAY_MS)
+        .await
+        .expect("due query");
+
+    let kinds: Vec<VehicleDueKind> = items.iter().map(|item| item.kind).collect();
+    assert_eq!(kinds, vec![VehicleDueKind::Service, VehicleDueKind::Mot]);
+}
+
+#[tokio::test]
+async fn negative_horizon_is_rejected() {
+    let pool = setup_pool().await;
+    let err = due::vehicles_due_soon(&pool, "hh", NOW_MS, -1)
+        .await
+        .expect_err("negative horizon");
+    assert_eq!(err.code(), due::DUE_INVALID_HORIZON_CODE);
+}
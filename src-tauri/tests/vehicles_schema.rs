@@ -54,6 +54,7 @@ async fn build_app_state(
         vault,
         vault_migration: Arc::new(VaultMigrationManager::new(&attachments_root)?),
         maintenance: Arc::new(AtomicBool::new(false)),
+        maintenance_reason: Arc::new(Mutex::new(None)),
         files_indexer,
         pet_metrics: Arc::new(PetAttachmentMetrics::new()),
     };
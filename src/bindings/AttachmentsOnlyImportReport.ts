@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of importing an attachments-only bundle into a household vault.
+ */
+export type AttachmentsOnlyImportReport = { dryRun: boolean, householdId: string, 
+/**
+ * Household the bundle was exported from.
+ */
+sourceHouseholdId: string, 
+/**
+ * Bundle files whose hash matched the attachments manifest.
+ */
+verified: number, 
+/**
+ * Files written into the vault (or that would be, for a dry run).
+ */
+copied: number, 
+/**
+ * Files already present in the vault with identical content.
+ */
+skipped: number, 
+/**
+ * `category/relative_path` keys left untouched because the vault holds different content.
+ */
+conflicts: Array<string>, 
+/**
+ * Imported files referenced by a live row in the target household.
+ */
+linked: number, 
+/**
+ * `category/relative_path` keys with no referencing row; they surface via the files index.
+ */
+unlinked: Array<string>, };
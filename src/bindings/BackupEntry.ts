@@ -1,4 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { BackupManifest } from "./BackupManifest";
 
-export type BackupEntry = { directory: string, sqlitePath: string, manifestPath: string, manifest: BackupManifest, totalSizeBytes: number, };
+export type BackupEntry = { directory: string, sqlitePath: string, manifestPath: string, manifest: BackupManifest, totalSizeBytes: number, 
+/**
+ * Whether the full backup a differential backup applies onto is still
+ * on disk. Always true for full backups.
+ */
+basePresent: boolean, };
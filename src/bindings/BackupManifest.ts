@@ -1,3 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupKind } from "./BackupKind";
 
-export type BackupManifest = { appVersion: string, schemaHash: string, dbSizeBytes: number, createdAt: string, sha256: string, };
+export type BackupManifest = { appVersion: string, schemaHash: string, dbSizeBytes: number, createdAt: string, 
+/**
+ * Digest of the full database, also for differential backups where it
+ * is the digest of the rebuilt file.
+ */
+sha256: string, kind: BackupKind, 
+/**
+ * Directory name of the full backup a differential one applies onto.
+ */
+baseDirectory: string | null, baseSha256: string | null, };
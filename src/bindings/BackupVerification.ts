@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupKind } from "./BackupKind";
+import type { BackupVerifyStatus } from "./BackupVerifyStatus";
+
+/**
+ * Outcome of re-checking a backup on disk against its manifest.
+ */
+export type BackupVerification = { status: BackupVerifyStatus, kind: BackupKind, sqlitePath: string, manifestPath: string, expectedSha256: string, actualSha256: string, expectedSizeBytes: number, actualSizeBytes: number, integrityOk: boolean, 
+/**
+ * First problem reported by `PRAGMA integrity_check`, if any.
+ */
+integrityDetails: string | null, 
+/**
+ * Why a differential backup's base could not be used, if it could not.
+ */
+baseDetails: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where the live database sits on disk, for support requests.
+ */
+export type DbPathInfo = { path: string, 
+/**
+ * `path` with the home directory replaced by `~`, safe for screenshots.
+ */
+displayPath: string, sizeBytes: number, walBytes: number, shmBytes: number, writable: boolean, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DeletionAuditEntry = { id: number, household_id: string, table_name: string, row_id: string, operation: string, deleted_at: number, };
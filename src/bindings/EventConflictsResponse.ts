@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventConflict } from "./EventConflict";
+
+export type EventConflictsResponse = { conflicts: Array<EventConflict>, 
+/**
+ * True when the underlying range expansion hit its occurrence limit.
+ */
+truncated: boolean, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExportTableDiff } from "./ExportTableDiff";
+
+export type ExportBundleDiff = { 
+/**
+ * Tables whose presence, row count or content digest differ.
+ */
+tables: Array<ExportTableDiff>, 
+/**
+ * Attachment keys present only in bundle B.
+ */
+attachmentsAdded: Array<string>, 
+/**
+ * Attachment keys present only in bundle A.
+ */
+attachmentsRemoved: Array<string>, 
+/**
+ * Attachment keys present in both bundles with different content.
+ */
+attachmentsChanged: Array<string>, };
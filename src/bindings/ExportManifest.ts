@@ -1,5 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AttachmentsInfo } from "./AttachmentsInfo";
 import type { TableInfo } from "./TableInfo";
+import type { TableSelection } from "./TableSelection";
 
-export type ExportManifest = { appVersion: string, schemaVersion: string, createdAt: string, tables: { [key in string]?: TableInfo }, attachments: AttachmentsInfo, };
+export type ExportManifest = { appVersion: string, schemaVersion: string, createdAt: string, tables: { [key in string]?: TableInfo }, attachments: AttachmentsInfo, 
+/**
+ * Selection the bundle was exported with; absent when every table was.
+ */
+tableSelection?: TableSelection, 
+/**
+ * Household whose files an attachments-only bundle carries. Such bundles
+ * have no `data/` directory and an empty `tables` map.
+ */
+attachmentsOnlyHousehold?: string, };
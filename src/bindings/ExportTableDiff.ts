@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TableDiffStatus } from "./TableDiffStatus";
+
+export type ExportTableDiff = { table: string, status: TableDiffStatus, countA: number | null, countB: number | null, sha256A: string | null, sha256B: string | null, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One index from `sqlite_master` with its shape, on-disk size and a
+ * best-effort redundancy hint for pruning.
+ */
+export type IndexStat = { name: string, table: string, columns: Array<string>, unique: boolean, partial: boolean, 
+/**
+ * Bytes used by the index b-tree. `None` when the `dbstat` virtual table
+ * is not compiled into SQLite.
+ */
+sizeBytes?: number, 
+/**
+ * Another index on the same table whose leading columns already cover
+ * this one, making it a pruning candidate.
+ */
+coveredBy?: string, };
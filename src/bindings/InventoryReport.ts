@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WarrantyExpiringItem } from "./WarrantyExpiringItem";
+
+export type InventoryReport = { 
+/**
+ * Sum of `estimated_value` in minor currency units.
+ */
+total_value: number, count: number, 
+/**
+ * Warranties expiring within the horizon, soonest first.
+ */
+expiring_warranties: Array<WarrantyExpiringItem>, };
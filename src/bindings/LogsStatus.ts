@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Size and retention of the log directory.
+ */
+export type LogsStatus = { directory: string, 
+/**
+ * Size of the active `arklowdun.log`.
+ */
+currentBytes: number, 
+/**
+ * Number of rolled `arklowdun.log.N` files on disk.
+ */
+rolledFiles: number, 
+/**
+ * Combined size of the active and rolled files.
+ */
+totalBytes: number, 
+/**
+ * Modification time of `arklowdun.log.1`, i.e. roughly when the log last
+ * rotated, in milliseconds since the epoch.
+ */
+lastRotatedAt?: number, maxBytes: number, maxFiles: number, };
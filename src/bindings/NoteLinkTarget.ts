@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoteLinkEntityType } from "./NoteLinkEntityType";
+
+/**
+ * One entity to link a newly created note to. Accepts `type`/`id` as
+ * shorthand for `entity_type`/`entity_id`.
+ */
+export type NoteLinkTarget = { entity_type: NoteLinkEntityType, entity_id: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type NoteWithLinksCreated = { note_id: string, 
+/**
+ * Link ids in the same order as the requested targets.
+ */
+link_ids: Array<string>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A migration from the embedded set that has not been recorded in
+ * `schema_migrations` yet.
+ */
+export type PendingMigration = { version: string, 
+/**
+ * True when the migration drops or rewrites existing data.
+ */
+destructive: boolean, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A stored recurrence value rewritten by [`fix_event_recurrence`].
+ */
+export type RecurrenceFix = { event_id: string, 
+/**
+ * Column that changed: `rrule` or `exdates`.
+ */
+field: string, before: string | null, after: string | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A stored event whose recurrence data the editor would reject.
+ */
+export type RecurrenceIssue = { event_id: string, 
+/**
+ * Time error taxonomy code, e.g. `E_EXDATE_INVALID_FORMAT`.
+ */
+code: string, 
+/**
+ * Offending rule or EXDATE token, when one can be pinned down.
+ */
+value: string | null, };
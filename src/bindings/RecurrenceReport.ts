@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecurrenceFix } from "./RecurrenceFix";
+import type { RecurrenceIssue } from "./RecurrenceIssue";
+
+/**
+ * Result of `events_validate_recurrence`.
+ */
+export type RecurrenceReport = { 
+/**
+ * Problems found before any repair ran.
+ */
+issues: Array<RecurrenceIssue>, 
+/**
+ * Changes written when a fix was requested.
+ */
+fixes: Array<RecurrenceFix>, };
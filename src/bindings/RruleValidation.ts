@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RruleValidation = { 
+/**
+ * Rule as the expansion engine prints it, without an `RRULE:` prefix.
+ */
+rrule: string, tz: string, 
+/**
+ * Next occurrences from `dtstart_utc`, as UTC milliseconds.
+ */
+occurrences_utc: Array<number>, };
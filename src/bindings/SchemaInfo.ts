@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Schema fingerprint plus the migrations recorded in `schema_migrations`.
+ */
+export type SchemaInfo = { schemaHash: string, 
+/**
+ * Applied migration versions in ascending order.
+ */
+appliedMigrations: Array<string>, latestVersion?: string, };
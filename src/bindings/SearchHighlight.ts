@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A matched range inside one field of a [`SearchResult`]. Offsets are UTF-16
+ * code units so the UI can slice the JavaScript string directly.
+ */
+export type SearchHighlight = { field: string, start: number, end: number, };
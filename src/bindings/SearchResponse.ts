@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SearchResult } from "./SearchResult";
+
+export type SearchResponse = { results: Array<SearchResult>, 
+/**
+ * Set when a branch ran past the caller's timeout and was abandoned.
+ */
+partial: boolean, timed_out_branches: Array<string>, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Theme } from "./Theme";
+
+export type Settings = { theme: Theme, 
+/**
+ * Window used by the upcoming/due views, in milliseconds.
+ */
+defaultHorizonMs: number, 
+/**
+ * Hours between scheduled backups.
+ */
+backupIntervalHours: number, 
+/**
+ * Override for the attachments vault location. `None` keeps the default
+ * under the app data directory.
+ */
+vaultRoot: string | null, 
+/**
+ * Level for the `arklowdun` target, applied at startup and on update.
+ * Other targets keep whatever the active filter gives them.
+ */
+logLevel: string, 
+/**
+ * IANA zone assumed for events stored without a timezone.
+ */
+fallbackTimezone: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Theme } from "./Theme";
+
+/**
+ * Partial update for [`Settings`]. Omitted fields keep their current value;
+ * `vaultRoot: null` clears the override.
+ */
+export type SettingsPatch = { theme?: Theme, defaultHorizonMs?: number, backupIntervalHours?: number, vaultRoot?: string | null, logLevel?: string, fallbackTimezone?: string, };
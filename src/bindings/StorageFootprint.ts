@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Bytes on disk for each area the app writes to. `total_bytes` is the sum of
+ * the other fields.
+ */
+export type StorageFootprint = { 
+/**
+ * The SQLite file together with its `-wal` and `-shm` sidecars.
+ */
+databaseBytes: number, vaultBytes: number, backupsBytes: number, logsBytes: number, totalBytes: number, };
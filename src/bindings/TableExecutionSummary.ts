@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { TableConflict } from "./TableConflict";
 
-export type TableExecutionSummary = { adds: number, updates: number, skips: number, conflicts: Array<TableConflict>, };
+export type TableExecutionSummary = { adds: number, updates: number, skips: number, conflicts: Array<TableConflict>, 
+/**
+ * Rows whose current columns were derived from legacy aliases.
+ */
+legacyAliased: number, };
@@ -1,3 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type TableInfo = { count: number, sha256: string, };
+export type TableInfo = { count: number, 
+/**
+ * SHA-256 of the uncompressed JSONL content.
+ */
+sha256: string, 
+/**
+ * Compression applied to the data file on disk, e.g. `gzip`.
+ */
+compression?: string, 
+/**
+ * SHA-256 of the compressed file when `compression` is set.
+ */
+compressedSha256?: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Domain tables chosen for an export or import, by logical name. An empty
+ * include list selects every table; exclusions always win.
+ */
+export type TableSelection = { includeTables: Array<string>, excludeTables: Array<string>, };
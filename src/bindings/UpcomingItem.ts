@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UpcomingKind } from "./UpcomingKind";
+
+export type UpcomingItem = { kind: UpcomingKind, id: string, title: string, at: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UpcomingItem } from "./UpcomingItem";
+
+export type UpcomingSummary = { items: Array<UpcomingItem>, 
+/**
+ * Set when event expansion hit its cap and later occurrences were dropped.
+ */
+truncated: boolean, };
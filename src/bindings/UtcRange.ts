@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Half-open `[start_at_utc, end_at_utc)` window in UTC milliseconds.
+ */
+export type UtcRange = { start_at_utc: number, end_at_utc: number, 
+/**
+ * Timezone the local period was resolved in.
+ */
+tz: string, };
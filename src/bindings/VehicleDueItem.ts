@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VehicleDueKind } from "./VehicleDueKind";
+
+export type VehicleDueItem = { vehicle_id: string, name: string, reg?: string, kind: VehicleDueKind, due_at: number, };
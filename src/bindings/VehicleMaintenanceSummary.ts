@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaintenanceKindTotal } from "./MaintenanceKindTotal";
+
+export type VehicleMaintenanceSummary = { vehicle_id: string, from_ms: number, to_ms: number, total_cost: number, count: number, 
+/**
+ * Per-kind totals, largest spend first.
+ */
+by_kind: Array<MaintenanceKindTotal>, };
@@ -2,17 +2,23 @@ import { call } from "@lib/ipc/call";
 import type { ImportMode } from "@bindings/ImportMode";
 import type { ImportPreviewDto } from "@bindings/ImportPreviewDto";
 import type { ImportExecuteDto } from "@bindings/ImportExecuteDto";
+import type { TableSelection } from "@bindings/TableSelection";
 
-export function previewImport(bundlePath: string, mode: ImportMode): Promise<ImportPreviewDto> {
-  return call<ImportPreviewDto>("db_import_preview", { args: { bundlePath, mode } });
+export function previewImport(
+  bundlePath: string,
+  mode: ImportMode,
+  tables: Partial<TableSelection> = {},
+): Promise<ImportPreviewDto> {
+  return call<ImportPreviewDto>("db_import_preview", { args: { bundlePath, mode, ...tables } });
 }
 
 export function executeImport(
   bundlePath: string,
   mode: ImportMode,
   expectedPlanDigest: string,
+  tables: Partial<TableSelection> = {},
 ): Promise<ImportExecuteDto> {
   return call<ImportExecuteDto>("db_import_execute", {
-    args: { bundlePath, mode, expectedPlanDigest },
+    args: { bundlePath, mode, expectedPlanDigest, ...tables },
   });
 }
@@ -16,6 +16,7 @@ export interface MigrationProgress {
   table?: string | null;
   counts: MigrationCounts;
   completed: boolean;
+  cancelled?: boolean;
   manifest_path?: string | null;
   checkpoint_path?: string | null;
 }
@@ -31,3 +32,7 @@ export function runMigration(mode: MigrationMode): Promise<MigrationProgress> {
 export function resumeMigration(): Promise<MigrationProgress> {
   return call("attachments_resume_migration");
 }
+
+export function cancelMigration(): Promise<void> {
+  return call("attachments_migrate_cancel");
+}
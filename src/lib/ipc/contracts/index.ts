@@ -1,22 +1,48 @@
 import { z } from "zod";
 import type { Vehicle } from "@bindings/Vehicle";
 import type { Event } from "@bindings/Event";
+import type { DeletionAuditEntry } from "@bindings/DeletionAuditEntry";
+import type { EventAttendee } from "@bindings/EventAttendee";
+import type { VehicleMaintenanceSummary } from "@bindings/VehicleMaintenanceSummary";
+import type { InventoryReport } from "@bindings/InventoryReport";
+import type { RruleValidation } from "@bindings/RruleValidation";
+import type { RecurrenceReport } from "@bindings/RecurrenceReport";
+import type { UtcRange } from "@bindings/UtcRange";
 import type { NotesPage } from "@bindings/NotesPage";
 import type { ContextNotesPage } from "@bindings/ContextNotesPage";
 import type { Note } from "@bindings/Note";
 import type { NoteLink } from "@bindings/NoteLink";
 import type { NoteLinkList } from "@bindings/NoteLinkList";
+import type { NoteWithLinksCreated } from "@bindings/NoteWithLinksCreated";
 import type { NotesDeadlineRangePage } from "@bindings/NotesDeadlineRangePage";
 import type { EventsListRangeResponse } from "@bindings/EventsListRangeResponse";
+import type { EventConflictsResponse } from "@bindings/EventConflictsResponse";
+import type { MemberBusyResponse } from "@bindings/MemberBusyResponse";
+import type { VehicleDueItem } from "@bindings/VehicleDueItem";
+import type { PetMedicalDueItem } from "@bindings/PetMedicalDueItem";
+import type { PolicyRenewalDueItem } from "@bindings/PolicyRenewalDueItem";
+import type { UpcomingSummary } from "@bindings/UpcomingSummary";
 import type { BackupOverview } from "@bindings/BackupOverview";
 import type { BackupEntry } from "@bindings/BackupEntry";
+import type { BackupVerification } from "@bindings/BackupVerification";
+import type { ExportBundleDiff } from "@bindings/ExportBundleDiff";
 import type { ExportEntryDto } from "@bindings/ExportEntryDto";
 import type { ValidationReport } from "@bindings/ValidationReport";
 import type { ImportPreviewDto } from "@bindings/ImportPreviewDto";
 import type { ImportExecuteDto } from "@bindings/ImportExecuteDto";
+import type { AttachmentsOnlyImportReport } from "@bindings/AttachmentsOnlyImportReport";
 import type { HardRepairOutcome } from "@bindings/HardRepairOutcome";
 import type { DbHealthReport } from "@bindings/DbHealthReport";
-import type { SearchResult } from "@bindings/SearchResult";
+import type { SchemaInfo } from "@bindings/SchemaInfo";
+import type { DbPathInfo } from "@bindings/DbPathInfo";
+import type { IndexStat } from "@bindings/IndexStat";
+import type { LogsStatus } from "@bindings/LogsStatus";
+import type { StorageFootprint } from "@bindings/StorageFootprint";
+import type { Settings } from "@bindings/Settings";
+import type { SettingsPatch } from "@bindings/SettingsPatch";
+import type { PendingMigration } from "@bindings/PendingMigration";
+import type { SearchResponse } from "@bindings/SearchResponse";
+import type { TimezoneList } from "@bindings/TimezoneList";
 import {
   PetMedicalCreateRequestSchema,
   PetMedicalCreateResponseSchema,
@@ -46,6 +72,9 @@ import {
 export type ContractEntry<K extends keyof typeof contracts> = (typeof contracts)[K];
 
 const flexibleRequest = z.object({}).passthrough();
+const getManyRequest = z
+  .object({ householdId: z.string(), ids: z.array(z.string()).max(200) })
+  .passthrough();
 
 const emptyObject = z.object({}).strict();
 
@@ -152,6 +181,108 @@ const fileMoveResponse = z.object({
   renamed: z.boolean(),
 });
 
+const attachmentMoveRequest = z
+  .object({
+    table: z.string(),
+    id: z.string(),
+    newRelativePath: z.string().min(1),
+  })
+  .passthrough();
+
+const attachmentMoveResponse = z.object({
+  relative_path: z.string(),
+});
+
+const attachmentCopyRequest = z
+  .object({
+    srcTable: z.string(),
+    srcId: z.string(),
+    dstTable: z.string(),
+    dstId: z.string(),
+  })
+  .passthrough();
+
+const attachmentCopyResponse = z.object({
+  relative_path: z.string(),
+  deduplicated: z.boolean(),
+});
+
+const rowReassignRequest = z
+  .object({
+    table: z.string(),
+    id: z.string(),
+    fromHousehold: z.string().min(1),
+    toHousehold: z.string().min(1),
+  })
+  .passthrough();
+
+const rowReassignResponse = z.object({
+  household_id: z.string(),
+  relative_path: z.string().nullable(),
+  file_moved: z.boolean(),
+});
+
+const householdIntegrityIssue = z.object({
+  table: z.string(),
+  id: z.string(),
+  household_id: z.string(),
+  code: z.string(),
+  detail: z.string().nullable(),
+});
+
+const entityAttachment = z.object({
+  table: z.string(),
+  id: z.string(),
+  household_id: z.string(),
+  category: z.string(),
+  relative_path: z.string(),
+  exists: z.boolean(),
+  size_bytes: z.number().nullable(),
+});
+
+const attachmentRestoreFromBackupRequest = z
+  .object({
+    table: z.string(),
+    id: z.string(),
+    backupPath: z.string().min(1),
+  })
+  .passthrough();
+
+const attachmentRestoreFromBackupResponse = z.object({
+  relative_path: z.string(),
+  sha256: z.string(),
+  restored: z.boolean(),
+});
+
+const attachmentsBackfillHashesResponse = z.object({
+  hashed: z.number(),
+  missing: z.number(),
+});
+
+const attachmentsImportDirRequest = z
+  .object({
+    table: z.string(),
+    householdId: z.string(),
+    category: attachmentCategory,
+    sourceDir: z.string().min(1),
+  })
+  .passthrough();
+
+const attachmentsImportDirResponse = z.object({
+  imported: z.number(),
+  rejected: z.number(),
+  files: z.array(
+    z.object({
+      source: z.string(),
+      status: z.enum(["imported", "rejected"]),
+      id: z.string().nullable(),
+      relative_path: z.string().nullable(),
+      code: z.string().nullable(),
+      message: z.string().nullable(),
+    }),
+  ),
+});
+
 const attachmentsRepairRequest = z
   .object({
     household_id: z.string(),
@@ -176,6 +307,12 @@ const householdDeleteResponse = z
   .object({ fallbackId: z.string().nullable().optional() })
   .passthrough();
 
+const householdCascadeCounts = z.object({
+  household_id: z.string(),
+  phases: z.array(z.object({ phase: z.string(), rows: z.number() })),
+  total_expected: z.number(),
+});
+
 const mimeHint = z
   .string()
   .regex(/^[a-zA-Z0-9._+-]+\/[a-zA-Z0-9._+-]+$/);
@@ -434,6 +571,16 @@ const notesQuickCreateRequest = z
   })
   .passthrough();
 
+const notesCreateWithLinksRequest = z
+  .object({
+    householdId: z.string(),
+    categoryId: z.string(),
+    text: z.string(),
+    color: z.string().optional(),
+    entities: z.array(z.record(z.string())),
+  })
+  .passthrough();
+
 const notesDeadlineRangeRequest = z
   .object({
     householdId: z.string(),
@@ -614,6 +761,8 @@ const vehicleUpdateData = vehicleCreateBase.partial().extend({
   vin: z.union([z.string().length(17), z.null()]).optional(),
 });
 
+const changesCursor = z.object({ updated_at: z.number(), id: z.string() });
+
 function contract<Req extends z.ZodTypeAny, Res extends z.ZodTypeAny>(config: {
   request: Req;
   response: Res;
@@ -625,6 +774,10 @@ export const contracts = {
   about_metadata: contract({ request: flexibleRequest, response: flexibleRequest }),
   attachment_open: contract({ request: flexibleRequest, response: z.null() }),
   attachment_reveal: contract({ request: flexibleRequest, response: z.null() }),
+  attachments_list_for_entity: contract({
+    request: z.object({ table: z.string(), id: z.string() }).passthrough(),
+    response: z.array(entityAttachment),
+  }),
   files_exists: contract({
     request: petsAttachmentPathRequest,
     response: filesExistsResponse,
@@ -643,20 +796,84 @@ export const contracts = {
   }),
   bills_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   bills_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  bills_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   bills_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   bills_update: contract({ request: flexibleRequest, response: flexibleRequest }),
   bills_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
   bills_restore: contract({ request: flexibleRequest, response: flexibleRequest }),
   bills_list_due_between: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
+  bills_list_due_between_paged: contract({
+    request: flexibleRequest,
+    response: z.object({
+      items: z.array(flexibleRequest),
+      total: z.number(),
+      has_more: z.boolean(),
+    }),
+  }),
   file_move: contract({
     request: fileMoveRequest,
     response: fileMoveResponse,
   }),
+  attachment_move: contract({
+    request: attachmentMoveRequest,
+    response: attachmentMoveResponse,
+  }),
+  attachment_copy: contract({
+    request: attachmentCopyRequest,
+    response: attachmentCopyResponse,
+  }),
+  row_reassign: contract({
+    request: rowReassignRequest,
+    response: rowReassignResponse,
+  }),
+  household_integrity_audit: contract({
+    request: emptyObject,
+    response: z.array(householdIntegrityIssue),
+  }),
+  attachment_restore_from_backup: contract({
+    request: attachmentRestoreFromBackupRequest,
+    response: attachmentRestoreFromBackupResponse,
+  }),
+  attachments_backfill_hashes: contract({
+    request: flexibleRequest,
+    response: attachmentsBackfillHashesResponse,
+  }),
+  attachments_import_dir: contract({
+    request: attachmentsImportDirRequest,
+    response: attachmentsImportDirResponse,
+  }),
   db_backup_create: contract({ request: flexibleRequest, response: z.custom<BackupEntry>() }),
   db_backup_overview: contract({ request: flexibleRequest, response: z.custom<BackupOverview>() }),
   db_backup_reveal: contract({ request: flexibleRequest, response: z.void() }),
   db_backup_reveal_root: contract({ request: flexibleRequest, response: z.void() }),
-  db_export_run: contract({ request: flexibleRequest, response: z.custom<ExportEntryDto>() }),
+  db_backup_verify: contract({
+    request: z.object({ backupPath: z.string() }).passthrough(),
+    response: z.custom<BackupVerification>(),
+  }),
+  db_backup_restore: contract({
+    request: z.object({ backupPath: z.string(), destPath: z.string() }).passthrough(),
+    response: z.string(),
+  }),
+  db_export_diff: contract({
+    request: z.object({ bundleA: z.string(), bundleB: z.string() }).passthrough(),
+    response: z.custom<ExportBundleDiff>(),
+  }),
+  db_export_attachments_only: contract({
+    request: z.object({ outParent: z.string(), householdId: z.string() }).passthrough(),
+    response: z.custom<ExportEntryDto>(),
+  }),
+  db_export_run: contract({
+    request: z
+      .object({
+        outParent: z.string(),
+        compress: z.boolean().optional(),
+        includeDeletionsAudit: z.boolean().optional(),
+        includeTables: z.array(z.string()).optional(),
+        excludeTables: z.array(z.string()).optional(),
+      })
+      .passthrough(),
+    response: z.custom<ExportEntryDto>(),
+  }),
   db_files_index_ready: contract({ request: filesIndexRequest, response: z.boolean() }),
   db_has_files_index: contract({ request: flexibleRequest, response: z.boolean() }),
   files_index_status: contract({
@@ -675,14 +892,51 @@ export const contracts = {
   db_hard_repair_run: contract({ request: flexibleRequest, response: z.custom<HardRepairOutcome>() }),
   db_has_pet_columns: contract({ request: flexibleRequest, response: z.boolean() }),
   db_has_vehicle_columns: contract({ request: flexibleRequest, response: z.boolean() }),
+  db_import_attachments_only: contract({
+    request: z
+      .object({
+        bundlePath: z.string(),
+        householdId: z.string(),
+        dryRun: z.boolean().optional(),
+      })
+      .passthrough(),
+    response: z.custom<AttachmentsOnlyImportReport>(),
+  }),
   db_import_execute: contract({ request: flexibleRequest, response: z.custom<ImportExecuteDto>() }),
   db_import_preview: contract({ request: flexibleRequest, response: z.custom<ImportPreviewDto>() }),
   db_recheck: contract({ request: dbRequest, response: z.custom<DbHealthReport>() }),
+  db_schema_info: contract({ request: flexibleRequest, response: z.custom<SchemaInfo>() }),
+  db_schema_dump: contract({ request: flexibleRequest, response: z.string() }),
+  db_index_stats: contract({
+    request: flexibleRequest,
+    response: z.array(z.custom<IndexStat>()),
+  }),
+  db_path_info: contract({ request: flexibleRequest, response: z.custom<DbPathInfo>() }),
+  db_migrations_pending: contract({
+    request: flexibleRequest,
+    response: z.array(z.custom<PendingMigration>()),
+  }),
   db_repair_run: contract({ request: flexibleRequest, response: z.custom<ValidationReport>() }),
   db_table_exists: contract({ request: flexibleRequest, response: z.boolean() }),
   diagnostics_doc_path: contract({ request: flexibleRequest, response: z.string() }),
   diagnostics_household_stats: contract({ request: flexibleRequest, response: flexibleRequest }),
+  household_stats_refresh: contract({
+    request: flexibleRequest,
+    response: z.array(flexibleRequest),
+  }),
+  household_stats_get: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   diagnostics_summary: contract({ request: flexibleRequest, response: flexibleRequest }),
+  logging_set_filter: contract({
+    request: z.object({ directive: z.string() }).passthrough(),
+    response: z.string(),
+  }),
+  logs_status: contract({ request: flexibleRequest, response: z.custom<LogsStatus>() }),
+  storage_footprint: contract({ request: emptyObject, response: z.custom<StorageFootprint>() }),
+  settings_get: contract({ request: flexibleRequest, response: z.custom<Settings>() }),
+  settings_update: contract({
+    request: z.object({ patch: z.custom<SettingsPatch>() }).passthrough(),
+    response: z.custom<Settings>(),
+  }),
   pets_diagnostics_counters: contract({
     request: flexibleRequest,
     response: petsDiagnosticsCountersResponse,
@@ -696,13 +950,29 @@ export const contracts = {
       .passthrough(),
     response: z.custom<EventsListRangeResponse>(),
   }),
+  events_find_conflicts: contract({
+    request: z
+      .object({
+        householdId: z.string(),
+        start: z.number(),
+        end: z.number(),
+        memberId: z.string().optional().nullable(),
+      })
+      .passthrough(),
+    response: z.custom<EventConflictsResponse>(),
+  }),
   event_create: contract({
     request: z.object({ data: eventCreateData }).passthrough(),
     response: z.custom<Event>(),
   }),
   event_update: contract({
     request: z
-      .object({ id: z.string(), data: eventUpdateData, householdId: z.string() })
+      .object({
+        id: z.string(),
+        data: eventUpdateData,
+        householdId: z.string(),
+        expectedUpdatedAt: z.number().optional(),
+      })
       .passthrough(),
     response: z.null(),
   }),
@@ -718,14 +988,59 @@ export const contracts = {
       .passthrough(),
     response: z.null(),
   }),
+  event_duplicate: contract({
+    request: z
+      .object({ householdId: z.string(), id: z.string(), newStartUtc: z.number() })
+      .passthrough(),
+    response: z.custom<Event>(),
+  }),
+  event_export_one: contract({
+    request: z.object({ householdId: z.string(), id: z.string() }).passthrough(),
+    response: z.string(),
+  }),
+  event_import_one: contract({
+    request: z
+      .object({ householdId: z.string(), json: z.string(), dryRun: z.boolean().optional() })
+      .passthrough(),
+    response: flexibleRequest,
+  }),
+  events_shift: contract({
+    request: z
+      .object({ householdId: z.string(), ids: z.array(z.string()), deltaMs: z.number() })
+      .passthrough(),
+    response: z.number(),
+  }),
+  events_validate_recurrence: contract({
+    request: z.object({ householdId: z.string(), fix: z.boolean().optional() }).passthrough(),
+    response: z.custom<RecurrenceReport>(),
+  }),
   expenses_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   expenses_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  expenses_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   expenses_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   expenses_update: contract({ request: flexibleRequest, response: flexibleRequest }),
   expenses_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
   expenses_restore: contract({ request: flexibleRequest, response: flexibleRequest }),
+  expense_templates_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
+  expense_templates_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  expense_templates_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
+  expense_templates_create: contract({ request: flexibleRequest, response: flexibleRequest }),
+  expense_templates_update: contract({ request: flexibleRequest, response: flexibleRequest }),
+  expense_templates_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
+  expense_templates_restore: contract({ request: flexibleRequest, response: flexibleRequest }),
+  expenses_generate_from_templates: contract({
+    request: z
+      .object({ householdId: z.string(), month: z.string().regex(/^\d{4}-\d{2}$/) })
+      .passthrough(),
+    response: z.number(),
+  }),
+  family_member_busy: contract({
+    request: z.object({ memberId: z.string(), start: z.number(), end: z.number() }).passthrough(),
+    response: z.custom<MemberBusyResponse>(),
+  }),
   family_members_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   family_members_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  family_members_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   family_members_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   // Rust returns () for these, which maps to null over IPC
   family_members_update: contract({ request: flexibleRequest, response: z.null() }),
@@ -762,9 +1077,17 @@ export const contracts = {
   // Rust returns () which maps to null over IPC
   member_renewals_delete: contract({ request: renewalDeleteRequest, response: z.null() }),
   household_create: contract({
-    request: z.object({ args: householdArgs }).passthrough(),
+    request: z
+      .object({ args: householdArgs.extend({ seedDefaults: z.boolean().optional() }) })
+      .passthrough(),
     response: householdRecord,
   }),
+  household_seed_defaults: contract({
+    request: z.object({ householdId: z.string() }).passthrough(),
+    response: z
+      .object({ categories_created: z.number(), budget_categories_created: z.number() })
+      .passthrough(),
+  }),
   household_delete: contract({
     request: idRequest,
     response: householdDeleteResponse,
@@ -780,6 +1103,8 @@ export const contracts = {
   household_resume_delete: contract({ request: idRequest, response: householdRecord }),
   household_restore: contract({ request: idRequest, response: householdRecord }),
   household_vacuum_execute: contract({ request: flexibleRequest, response: flexibleRequest }),
+  household_cascade_counts: contract({ request: idRequest, response: householdCascadeCounts }),
+  household_cascade_reconcile: contract({ request: emptyObject, response: z.array(z.string()) }),
   household_set_active: contract({ request: idRequest, response: z.null() }),
   household_update: contract({
     request: z.object({ args: householdUpdateArgs }).passthrough(),
@@ -788,10 +1113,15 @@ export const contracts = {
   import_run_legacy: contract({ request: flexibleRequest, response: flexibleRequest }),
   inventory_items_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   inventory_items_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  inventory_items_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   inventory_items_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   inventory_items_update: contract({ request: flexibleRequest, response: flexibleRequest }),
   inventory_items_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
   inventory_items_restore: contract({ request: flexibleRequest, response: flexibleRequest }),
+  inventory_report: contract({
+    request: z.object({ householdId: z.string(), horizonMs: z.number() }).passthrough(),
+    response: z.custom<InventoryReport>(),
+  }),
   notes_get: contract({ request: flexibleRequest, response: z.custom<Note>().nullable() }),
   notes_create: contract({
     request: z.object({ data: notesCreateData }).passthrough(),
@@ -804,10 +1134,15 @@ export const contracts = {
         data: notesUpdateData,
         householdId: z.string(),
         household_id: z.string().optional(),
+        expectedUpdatedAt: z.number().optional(),
       })
       .passthrough(),
     response: z.null(),
   }),
+  notes_set_pinned: contract({
+    request: z.object({ householdId: z.string(), id: z.string(), pinned: z.boolean() }).passthrough(),
+    response: z.custom<Note>(),
+  }),
   notes_delete: contract({
     request: z
       .object({
@@ -844,6 +1179,10 @@ export const contracts = {
     request: notesQuickCreateRequest,
     response: z.custom<ContextNotesPage>(),
   }),
+  notes_create_with_links: contract({
+    request: notesCreateWithLinksRequest,
+    response: z.custom<NoteWithLinksCreated>(),
+  }),
   note_links_create: contract({ request: noteLinkRequest, response: z.custom<NoteLink>() }),
   note_links_list_by_entity: contract({
     request: notesEntityRequest,
@@ -852,7 +1191,41 @@ export const contracts = {
   note_links_unlink_entity: contract({ request: noteLinkRequest, response: z.null() }),
   note_links_get_for_note: contract({ request: noteLinkRequest, response: z.custom<NoteLinkList>() }),
   note_links_delete: contract({ request: noteLinkRequest, response: z.null() }),
+  event_attendees_set: contract({
+    request: z
+      .object({ householdId: z.string(), eventId: z.string(), memberIds: z.array(z.string()) })
+      .passthrough(),
+    response: z.array(z.custom<EventAttendee>()),
+  }),
+  event_attendees_list: contract({
+    request: z.object({ householdId: z.string(), eventId: z.string() }).passthrough(),
+    response: z.array(z.custom<EventAttendee>()),
+  }),
+  vehicle_maintenance_summary: contract({
+    request: z
+      .object({
+        householdId: z.string(),
+        vehicleId: z.string(),
+        fromMs: z.number(),
+        toMs: z.number(),
+      })
+      .passthrough(),
+    response: z.custom<VehicleMaintenanceSummary>(),
+  }),
+  deletions_audit_list: contract({
+    request: z
+      .object({ householdId: z.string(), since: z.number().nullable().optional() })
+      .passthrough(),
+    response: z.array(z.custom<DeletionAuditEntry>()),
+  }),
+  maintenance_begin: contract({ request: z.object({ reason: z.string().min(1) }), response: z.null() }),
+  maintenance_end: contract({ request: emptyObject, response: z.null() }),
   open_diagnostics_doc: contract({ request: flexibleRequest, response: z.null() }),
+  open_logs_dir: contract({ request: flexibleRequest, response: z.null() }),
+  diagnostics_export_bundle: contract({
+    request: z.object({ outDir: z.string() }).passthrough(),
+    response: z.string(),
+  }),
   open_path: contract({ request: flexibleRequest, response: z.null() }),
   pet_medical_list: contract({
     request: PetMedicalListRequestSchema,
@@ -862,6 +1235,7 @@ export const contracts = {
     request: PetMedicalGetRequestSchema,
     response: PetMedicalGetResponseSchema,
   }),
+  pet_medical_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   pet_medical_create: contract({
     request: PetMedicalCreateRequestSchema,
     response: PetMedicalCreateResponseSchema,
@@ -878,6 +1252,18 @@ export const contracts = {
     request: PetMedicalRestoreRequestSchema,
     response: PetMedicalMutationResponseSchema,
   }),
+  pet_medical_due: contract({
+    request: z.object({ householdId: z.string(), horizonMs: z.number() }).passthrough(),
+    response: z.array(z.custom<PetMedicalDueItem>()),
+  }),
+  policies_renewal_due: contract({
+    request: z.object({ householdId: z.string(), horizonMs: z.number() }).passthrough(),
+    response: z.array(z.custom<PolicyRenewalDueItem>()),
+  }),
+  upcoming_summary: contract({
+    request: z.object({ householdId: z.string(), horizonMs: z.number() }).passthrough(),
+    response: z.custom<UpcomingSummary>(),
+  }),
   pets_list: contract({
     request: PetsListRequestSchema,
     response: PetsListResponseSchema,
@@ -886,6 +1272,7 @@ export const contracts = {
     request: PetsGetRequestSchema,
     response: PetsGetResponseSchema,
   }),
+  pets_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   pets_create: contract({
     request: PetsCreateRequestSchema,
     response: PetsCreateResponseSchema,
@@ -912,26 +1299,125 @@ export const contracts = {
   }),
   policies_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   policies_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  policies_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   policies_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   policies_update: contract({ request: flexibleRequest, response: flexibleRequest }),
   policies_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
   policies_restore: contract({ request: flexibleRequest, response: flexibleRequest }),
   property_documents_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   property_documents_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  property_documents_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   property_documents_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   property_documents_update: contract({ request: flexibleRequest, response: flexibleRequest }),
   property_documents_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
   property_documents_restore: contract({ request: flexibleRequest, response: flexibleRequest }),
-  search_entities: contract({ request: flexibleRequest, response: z.array(z.custom<SearchResult>()) }),
+  day_bounds_utc: contract({
+    request: z.object({ householdId: z.string(), localDate: z.string() }).passthrough(),
+    response: z.custom<UtcRange>(),
+  }),
+  period_bounds_utc: contract({
+    request: z
+      .object({
+        householdId: z.string(),
+        kind: z.enum(["week", "month"]),
+        anchorLocalDate: z.string(),
+        weekStart: z
+          .enum(["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"])
+          .optional()
+          .nullable(),
+      })
+      .passthrough(),
+    response: z.custom<UtcRange>(),
+  }),
+  rrule_validate: contract({
+    request: z
+      .object({
+        rrule: z.string(),
+        dtstartUtc: z.number(),
+        tz: z.string().nullable().optional(),
+        count: z.number().int().positive().optional(),
+      })
+      .passthrough(),
+    response: z.custom<RruleValidation>(),
+  }),
+  events_next_occurrences: contract({
+    request: z
+      .object({
+        householdId: z.string(),
+        id: z.string(),
+        count: z.number().int().positive().optional(),
+        afterUtc: z.number(),
+      })
+      .passthrough(),
+    response: z.array(z.number()),
+  }),
+  search_entities: contract({ request: flexibleRequest, response: z.custom<SearchResponse>() }),
+  search_entities_stream: contract({ request: flexibleRequest, response: z.number() }),
+  search_entities_stream_cancel: contract({ request: flexibleRequest, response: z.boolean() }),
   shopping_items_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   shopping_items_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  shopping_items_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   shopping_items_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   shopping_items_update: contract({ request: flexibleRequest, response: flexibleRequest }),
   shopping_items_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
   shopping_items_restore: contract({ request: flexibleRequest, response: flexibleRequest }),
+  shopping_items_complete: contract({
+    request: z.object({ householdId: z.string(), ids: z.array(z.string()) }).passthrough(),
+    response: z.number(),
+  }),
+  trash_list: contract({
+    request: z
+      .object({
+        householdId: z.string(),
+        limit: z.number().optional(),
+        offset: z.number().optional(),
+      })
+      .passthrough(),
+    response: z.array(
+      z.object({
+        table: z.string(),
+        id: z.string(),
+        label: z.string().nullable(),
+        deleted_at: z.number(),
+      }),
+    ),
+  }),
+  trash_restore: contract({
+    request: z.object({ table: z.string(), id: z.string() }).passthrough(),
+    response: flexibleRequest,
+  }),
+  changes_since: contract({
+    request: z
+      .object({
+        householdId: z.string(),
+        sinceMs: z.number(),
+        tables: z.array(z.string()).optional(),
+        cursors: z.record(changesCursor).optional(),
+      })
+      .passthrough(),
+    response: z.object({
+      tables: z.record(z.array(flexibleRequest)),
+      truncated: z.boolean(),
+      cursors: z.record(changesCursor),
+    }),
+  }),
+  tables_last_modified: contract({
+    request: z.object({ householdId: z.string() }).passthrough(),
+    response: z.record(z.number().nullable()),
+  }),
   time_invariants_check: contract({ request: flexibleRequest, response: flexibleRequest }),
+  timezones_list: contract({ request: flexibleRequest, response: z.custom<TimezoneList>() }),
+  bindings_emit_json_schema: contract({
+    request: z.object({ outDir: z.string() }).passthrough(),
+    response: z.array(z.string()),
+  }),
+  vehicles_due_soon: contract({
+    request: z.object({ householdId: z.string(), horizonMs: z.number() }).passthrough(),
+    response: z.array(z.custom<VehicleDueItem>()),
+  }),
   vehicle_maintenance_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   vehicle_maintenance_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  vehicle_maintenance_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   vehicle_maintenance_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   vehicle_maintenance_update: contract({ request: flexibleRequest, response: flexibleRequest }),
   vehicle_maintenance_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
@@ -969,6 +1455,7 @@ export const contracts = {
   categories_restore: contract({ request: flexibleRequest, response: flexibleRequest }),
   budget_categories_list: contract({ request: flexibleRequest, response: z.array(flexibleRequest) }),
   budget_categories_get: contract({ request: flexibleRequest, response: flexibleRequest.nullable() }),
+  budget_categories_get_many: contract({ request: getManyRequest, response: z.array(flexibleRequest) }),
   budget_categories_create: contract({ request: flexibleRequest, response: flexibleRequest }),
   budget_categories_update: contract({ request: flexibleRequest, response: flexibleRequest }),
   budget_categories_delete: contract({ request: flexibleRequest, response: flexibleRequest }),
@@ -106,6 +106,7 @@ export const PetsCreateRequestSchema = z
 export const PetsUpdateRequestSchema = withHouseholdId({
   id: z.string(),
   data: petUpdateDataSchema,
+  expectedUpdatedAt: z.number().optional(),
 });
 
 export const PetsDeleteRequestSchema = withHouseholdId({ id: z.string() });
@@ -206,6 +207,7 @@ export const PetMedicalCreateRequestSchema = z
 export const PetMedicalUpdateRequestSchema = withHouseholdId({
   id: z.string(),
   data: petMedicalUpdateDataSchema,
+  expectedUpdatedAt: z.number().optional(),
 });
 
 export const PetMedicalDeleteRequestSchema = withHouseholdId({ id: z.string() });
@@ -153,7 +153,7 @@ if (registry.size === 0) {
       note_links_list_by_entity: () => ({ items: [] }),
       note_links_get_for_note: () => ({ items: [] }),
       categories_list: () => [],
-      search_entities: () => [],
+      search_entities: () => ({ results: [], partial: false, timed_out_branches: [] }),
       vehicles_list: () => [],
       vehicles_get: () => null,
       diagnostics_summary: () => ({ ok: true }),
@@ -178,8 +178,12 @@ if (registry.size === 0) {
           dbSizeBytes: 0,
           createdAt: new Date(0).toISOString(),
           sha256: "",
+          kind: "full",
+          baseDirectory: null,
+          baseSha256: null,
         },
         totalSizeBytes: 0,
+        basePresent: true,
       }),
       db_export_run: () => ({
         directory: "",
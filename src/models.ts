@@ -21,6 +21,9 @@ export interface Policy {
   relative_path: string;
   category: string;
   reminder?: number; // timestamp ms
+  name?: string | null;
+  provider?: string | null;
+  renewal_date?: number | null; // timestamp ms
   household_id?: string;
   position: number;
   created_at: number;
@@ -127,6 +130,7 @@ export interface InventoryItem {
   relative_path: string;
   category: string;
   reminder?: number; // timestamp ms
+  estimated_value?: number | null; // minor currency units
   household_id?: string;
   position: number;
   created_at: number;
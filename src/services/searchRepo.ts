@@ -1,6 +1,7 @@
 import { call } from "@lib/ipc/call";
 import { getHouseholdIdForCalls } from "../db/household";
 import type { SearchResult } from "../bindings/SearchResult";
+import type { SearchResponse } from "../bindings/SearchResponse";
 import { log } from "../utils/logger";
 import { getSafe } from "@utils/object";
 import { on } from "../store/events";
@@ -73,11 +74,15 @@ export async function search(
     limit,
     offset,
   }).then((payload) => {
-    if (!Array.isArray(payload)) {
-      log.debug("[search] IPC non-array", payload);
+    const response = payload as Partial<SearchResponse> | null;
+    if (!response || !Array.isArray(response.results)) {
+      log.debug("[search] IPC unexpected payload", payload);
       return [] as SearchResult[];
     }
-    return payload as SearchResult[];
+    if (response.partial) {
+      log.debug("[search] partial results", response.timed_out_branches);
+    }
+    return response.results;
   });
 
   cache.set(key, { promise, tsStart: now });
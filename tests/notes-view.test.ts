@@ -75,6 +75,7 @@ const makeNote = (overrides: Partial<Note> = {}): Note => ({
   deleted_at: overrides.deleted_at ?? null,
   deadline: overrides.deadline ?? null,
   deadline_tz: overrides.deadline_tz ?? null,
+  pinned: overrides.pinned ?? false,
 });
 
 test("NotesView renders text, color, and deadline", async () => {
@@ -145,6 +145,7 @@ function placeholderNote(householdId: string): Note {
     z: undefined,
     deadline: undefined,
     deadline_tz: undefined,
+    pinned: false,
   };
 }
 
@@ -222,6 +223,7 @@ export function createScenario(config: ScenarioConfig): ScenarioDefinition {
       z: typeof data?.z === "number" ? data.z : undefined,
       deadline: data?.deadline,
       deadline_tz: data?.deadline_tz,
+      pinned: data?.pinned === true,
     };
     state.notes.push(note);
     return { ...note };
@@ -262,6 +264,7 @@ export function createScenario(config: ScenarioConfig): ScenarioDefinition {
         manifestPath: `/tmp/backups/${now}/manifest.json`,
         manifest: { ...state.backups.manifest, createdAt: now },
         totalSizeBytes: 1024,
+        basePresent: true,
       };
       state.backups.entries.push(entry);
       state.backups.overview.backups.push(entry);
@@ -670,7 +673,11 @@ export function createScenario(config: ScenarioConfig): ScenarioDefinition {
     },
     open_diagnostics_doc: () => null,
     open_path: () => null,
-    search_entities: () => state.searchResults.map((result) => ({ ...result })),
+    search_entities: () => ({
+      results: state.searchResults.map((result) => ({ ...result })),
+      partial: false,
+      timed_out_branches: [],
+    }),
     vehicles_list: (payload) => {
       const householdId = (payload as { householdId?: string }).householdId ?? state.activeHouseholdId;
       return state.vehicles.filter((vehicle) => vehicle.household_id === householdId).map((vehicle) => ({ ...vehicle }));
@@ -58,6 +58,7 @@ const notes: Note[] = [
     z: 0,
     deadline: BASE_SECONDS + 604_800,
     deadline_tz: "UTC",
+    pinned: false,
   },
 ];
 
@@ -130,6 +131,9 @@ const scenarioData: ScenarioData = {
       dbSizeBytes: 4096,
       createdAt: TIMESTAMP,
       sha256: "sha",
+      kind: "full",
+      baseDirectory: null,
+      baseSha256: null,
     },
     overview: {
       availableBytes: 6_000_000,
@@ -27,6 +27,9 @@ const manifest: BackupManifest = {
   dbSizeBytes: 2048,
   createdAt: TIMESTAMP,
   sha256: "sha256",
+  kind: "full",
+  baseDirectory: null,
+  baseSha256: null,
 };
 
 const backupEntry: BackupEntry = {
@@ -35,6 +38,7 @@ const backupEntry: BackupEntry = {
   manifestPath: "/tmp/backups/default/manifest.json",
   manifest,
   totalSizeBytes: 4096,
+  basePresent: true,
 };
 
 const overview: BackupOverview = {
@@ -265,6 +269,7 @@ const notes: Note[] = [
     z: 0,
     deadline: BASE_SECONDS + 86400,
     deadline_tz: "UTC",
+    pinned: false,
   },
   {
     id: "note-quick-capture",
@@ -281,6 +286,7 @@ const notes: Note[] = [
     z: 1,
     deadline: undefined,
     deadline_tz: undefined,
+    pinned: false,
   },
 ];
 
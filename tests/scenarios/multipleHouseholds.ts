@@ -212,6 +212,9 @@ const scenarioData: ScenarioData = {
       dbSizeBytes: 4096,
       createdAt: TIMESTAMP,
       sha256: "sha",
+      kind: "full",
+      baseDirectory: null,
+      baseSha256: null,
     },
     overview: {
       availableBytes: 8_000_000,